@@ -32,6 +32,7 @@ use databend_query::api::RpcService;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::local;
 use databend_query::metrics::MetricService;
+use databend_query::pipes::PipeScheduler;
 use databend_query::servers::FlightSQLServer;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
@@ -231,6 +232,9 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // Pipe scheduler.
+    PipeScheduler::instance().start();
+
     // Print information to users.
     println!("Databend Query");
     println!();
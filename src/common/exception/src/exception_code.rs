@@ -172,6 +172,12 @@ build_exceptions! {
     ///
     /// For example: try to with 3 columns into a table with 4 columns.
     TableSchemaMismatch(1303),
+    /// CheckConstraintViolation is used when rows violate a CHECK constraint of the table.
+    CheckConstraintViolation(1304),
+    /// PrimaryKeyViolation is used when inserted rows duplicate the enforced primary key.
+    PrimaryKeyViolation(1305),
+    /// ColumnReferencedByConstraint is used when dropping or renaming a column used by a constraint.
+    ColumnReferencedByConstraint(1306),
 
     // License related errors starts here
 
@@ -332,6 +338,22 @@ build_exceptions! {
     IllegalStream(2733),
     StreamVersionMismatched(2734),
 
+    // Pipe error codes.
+    UnknownPipe(2740),
+    PipeAlreadyExists(2741),
+    IllegalPipe(2742),
+
+    // Sequence error codes.
+    UnknownSequence(2750),
+    SequenceAlreadyExists(2751),
+    OutOfSequenceRange(2752),
+
+    // Procedure error codes.
+    UnknownProcedure(2760),
+    ProcedureAlreadyExists(2761),
+    IllegalProcedureFormat(2762),
+    ScriptSemanticError(2763),
+
     // Variable error codes.
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
@@ -355,6 +377,7 @@ build_exceptions! {
     InvalidOperation(3905),
     StorageOther(4000),
     UnresolvableConflict(4001),
+    CurrentTransactionIsAborted(4002),
 }
 
 // Service errors [5001,6000].
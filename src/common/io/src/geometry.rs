@@ -0,0 +1,796 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use geo_types::Coord;
+use geo_types::Geometry;
+use geo_types::GeometryCollection;
+use geo_types::LineString;
+use geo_types::MultiLineString;
+use geo_types::MultiPoint;
+use geo_types::MultiPolygon;
+use geo_types::Point;
+use geo_types::Polygon;
+use geo_types::Rect;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOINT: u32 = 4;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+const WKB_GEOMETRYCOLLECTION: u32 = 7;
+
+// The flags of PostGIS extended WKB.
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// Parse a geometry from WKT, EWKT, hex encoded (E)WKB or GeoJSON, and encode it as WKB,
+/// which is the storage format of the geometry type.
+pub fn parse_to_wkb(buf: &[u8]) -> Result<Vec<u8>> {
+    let s = std::str::from_utf8(buf)
+        .map_err(|_| invalid_geometry(String::from_utf8_lossy(buf)))?
+        .trim();
+    let geometry = if s.starts_with('{') {
+        geometry_from_geojson(s)?
+    } else if let Some(wkb) = decode_hex(s) {
+        geometry_from_wkb(&wkb)?
+    } else {
+        geometry_from_wkt(s)?
+    };
+    Ok(geometry_to_wkb(&geometry))
+}
+
+/// Convert a WKB encoded geometry to WKT, used to display the geometry type.
+pub fn wkb_to_wkt(wkb: &[u8]) -> Result<String> {
+    Ok(geometry_to_wkt(&geometry_from_wkb(wkb)?))
+}
+
+/// Returns the bounding box of a WKB encoded geometry, `None` if the geometry is empty.
+pub fn wkb_bounding_box(wkb: &[u8]) -> Result<Option<Rect<f64>>> {
+    Ok(geometry_bounding_box(&geometry_from_wkb(wkb)?))
+}
+
+pub fn point_to_wkb(x: f64, y: f64) -> Vec<u8> {
+    geometry_to_wkb(&Geometry::Point(Point::new(x, y)))
+}
+
+/// Decode a WKB encoded point, `None` if the geometry is not a point.
+pub fn wkb_to_point(wkb: &[u8]) -> Result<Option<(f64, f64)>> {
+    match geometry_from_wkb(wkb)? {
+        Geometry::Point(point) => Ok(Some(point.x_y())),
+        _ => Ok(None),
+    }
+}
+
+pub fn geometry_bounding_box(geometry: &Geometry<f64>) -> Option<Rect<f64>> {
+    let mut bbox: Option<Rect<f64>> = None;
+    for_each_coord(geometry, &mut |coord| {
+        bbox = Some(match bbox {
+            None => Rect::new(coord, coord),
+            Some(rect) => Rect::new(
+                Coord {
+                    x: rect.min().x.min(coord.x),
+                    y: rect.min().y.min(coord.y),
+                },
+                Coord {
+                    x: rect.max().x.max(coord.x),
+                    y: rect.max().y.max(coord.y),
+                },
+            ),
+        });
+    });
+    bbox
+}
+
+fn for_each_coord(geometry: &Geometry<f64>, f: &mut impl FnMut(Coord<f64>)) {
+    match geometry {
+        Geometry::Point(point) => f(point.0),
+        Geometry::Line(line) => {
+            f(line.start);
+            f(line.end);
+        }
+        Geometry::LineString(line_string) => line_string.0.iter().for_each(|c| f(*c)),
+        Geometry::Polygon(polygon) => polygon.exterior().0.iter().for_each(|c| f(*c)),
+        Geometry::MultiPoint(multi_point) => multi_point.0.iter().for_each(|p| f(p.0)),
+        Geometry::MultiLineString(multi_line_string) => multi_line_string
+            .0
+            .iter()
+            .for_each(|line_string| line_string.0.iter().for_each(|c| f(*c))),
+        Geometry::MultiPolygon(multi_polygon) => multi_polygon
+            .0
+            .iter()
+            .for_each(|polygon| polygon.exterior().0.iter().for_each(|c| f(*c))),
+        Geometry::GeometryCollection(collection) => collection
+            .0
+            .iter()
+            .for_each(|geometry| for_each_coord(geometry, f)),
+        Geometry::Rect(rect) => {
+            f(rect.min());
+            f(rect.max());
+        }
+        Geometry::Triangle(triangle) => triangle.to_array().into_iter().for_each(f),
+    }
+}
+
+fn invalid_geometry(msg: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::BadBytes(format!("Invalid geometry value: {msg}"))
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    // The first byte of WKB is the byte order, 0x00 or 0x01.
+    if s.len() % 2 != 0 || !(s.starts_with("00") || s.starts_with("01")) {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+pub fn geometry_from_wkb(wkb: &[u8]) -> Result<Geometry<f64>> {
+    let mut reader = WkbReader {
+        buf: wkb,
+        pos: 0,
+        little_endian: true,
+    };
+    let geometry = reader.read_geometry()?;
+    if reader.pos != wkb.len() {
+        return Err(invalid_geometry("unexpected trailing bytes in WKB"));
+    }
+    Ok(geometry)
+}
+
+struct WkbReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    little_endian: bool,
+}
+
+impl WkbReader<'_> {
+    fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes = self
+            .buf
+            .get(self.pos..self.pos + N)
+            .ok_or_else(|| invalid_geometry("unexpected end of WKB"))?;
+        self.pos += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        let bytes = self.read_bytes::<4>()?;
+        Ok(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_f64(&mut self) -> Result<f64> {
+        let bytes = self.read_bytes::<8>()?;
+        Ok(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    // Read the number of elements, each element takes at least `min_size` bytes.
+    fn read_len(&mut self, min_size: usize) -> Result<usize> {
+        let len = self.read_u32()? as usize;
+        if len * min_size > self.buf.len() - self.pos {
+            return Err(invalid_geometry("unexpected end of WKB"));
+        }
+        Ok(len)
+    }
+
+    fn read_coord(&mut self) -> Result<Coord<f64>> {
+        Ok(Coord {
+            x: self.read_f64()?,
+            y: self.read_f64()?,
+        })
+    }
+
+    fn read_line_string(&mut self) -> Result<LineString<f64>> {
+        let len = self.read_len(16)?;
+        let coords = (0..len)
+            .map(|_| self.read_coord())
+            .collect::<Result<Vec<_>>>()?;
+        Ok(LineString::new(coords))
+    }
+
+    fn read_polygon(&mut self) -> Result<Polygon<f64>> {
+        let len = self.read_len(4)?;
+        let mut rings = (0..len)
+            .map(|_| self.read_line_string())
+            .collect::<Result<Vec<_>>>()?;
+        if rings.is_empty() {
+            return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+        }
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    }
+
+    fn read_geometry(&mut self) -> Result<Geometry<f64>> {
+        self.little_endian = match self.read_bytes::<1>()?[0] {
+            0 => false,
+            1 => true,
+            v => return Err(invalid_geometry(format!("invalid WKB byte order {v}"))),
+        };
+        let type_code = self.read_u32()?;
+        if type_code & (EWKB_Z_FLAG | EWKB_M_FLAG) != 0 {
+            return Err(invalid_geometry("only 2D geometries are supported"));
+        }
+        if type_code & EWKB_SRID_FLAG != 0 {
+            // The SRID is ignored.
+            self.read_u32()?;
+        }
+
+        let geometry = match type_code & !EWKB_SRID_FLAG {
+            WKB_POINT => {
+                let coord = self.read_coord()?;
+                if coord.x.is_nan() && coord.y.is_nan() {
+                    return Err(invalid_geometry("empty point is not supported"));
+                }
+                Geometry::Point(Point(coord))
+            }
+            WKB_LINESTRING => Geometry::LineString(self.read_line_string()?),
+            WKB_POLYGON => Geometry::Polygon(self.read_polygon()?),
+            WKB_MULTIPOINT => {
+                let len = self.read_len(21)?;
+                let points = (0..len)
+                    .map(|_| match self.read_geometry()? {
+                        Geometry::Point(point) => Ok(point),
+                        _ => Err(invalid_geometry("MULTIPOINT must contain points only")),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Geometry::MultiPoint(MultiPoint::new(points))
+            }
+            WKB_MULTILINESTRING => {
+                let len = self.read_len(9)?;
+                let line_strings = (0..len)
+                    .map(|_| match self.read_geometry()? {
+                        Geometry::LineString(line_string) => Ok(line_string),
+                        _ => Err(invalid_geometry(
+                            "MULTILINESTRING must contain linestrings only",
+                        )),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Geometry::MultiLineString(MultiLineString::new(line_strings))
+            }
+            WKB_MULTIPOLYGON => {
+                let len = self.read_len(9)?;
+                let polygons = (0..len)
+                    .map(|_| match self.read_geometry()? {
+                        Geometry::Polygon(polygon) => Ok(polygon),
+                        _ => Err(invalid_geometry("MULTIPOLYGON must contain polygons only")),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Geometry::MultiPolygon(MultiPolygon::new(polygons))
+            }
+            WKB_GEOMETRYCOLLECTION => {
+                let len = self.read_len(9)?;
+                let geometries = (0..len)
+                    .map(|_| self.read_geometry())
+                    .collect::<Result<Vec<_>>>()?;
+                Geometry::GeometryCollection(GeometryCollection::new_from(geometries))
+            }
+            _ => {
+                return Err(invalid_geometry(format!(
+                    "unsupported WKB geometry type {type_code}"
+                )));
+            }
+        };
+        Ok(geometry)
+    }
+}
+
+/// Encode the geometry as little endian 2D WKB.
+pub fn geometry_to_wkb(geometry: &Geometry<f64>) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_wkb(&mut buf, geometry);
+    buf
+}
+
+fn write_wkb(buf: &mut Vec<u8>, geometry: &Geometry<f64>) {
+    fn put_u32(buf: &mut Vec<u8>, v: u32) {
+        buf.extend_from_slice(&v.to_le_bytes());
+    }
+    fn put_coords(buf: &mut Vec<u8>, coords: &[Coord<f64>]) {
+        put_u32(buf, coords.len() as u32);
+        for coord in coords {
+            buf.extend_from_slice(&coord.x.to_le_bytes());
+            buf.extend_from_slice(&coord.y.to_le_bytes());
+        }
+    }
+    fn put_rings(buf: &mut Vec<u8>, polygon: &Polygon<f64>) {
+        if polygon.exterior().0.is_empty() {
+            put_u32(buf, 0);
+            return;
+        }
+        put_u32(buf, polygon.interiors().len() as u32 + 1);
+        put_coords(buf, &polygon.exterior().0);
+        for interior in polygon.interiors() {
+            put_coords(buf, &interior.0);
+        }
+    }
+
+    buf.push(1);
+    match geometry {
+        Geometry::Point(point) => {
+            put_u32(buf, WKB_POINT);
+            buf.extend_from_slice(&point.x().to_le_bytes());
+            buf.extend_from_slice(&point.y().to_le_bytes());
+        }
+        Geometry::Line(line) => {
+            put_u32(buf, WKB_LINESTRING);
+            put_coords(buf, &[line.start, line.end]);
+        }
+        Geometry::LineString(line_string) => {
+            put_u32(buf, WKB_LINESTRING);
+            put_coords(buf, &line_string.0);
+        }
+        Geometry::Polygon(polygon) => {
+            put_u32(buf, WKB_POLYGON);
+            put_rings(buf, polygon);
+        }
+        Geometry::MultiPoint(multi_point) => {
+            put_u32(buf, WKB_MULTIPOINT);
+            put_u32(buf, multi_point.0.len() as u32);
+            for point in multi_point.iter() {
+                write_wkb(buf, &Geometry::Point(*point));
+            }
+        }
+        Geometry::MultiLineString(multi_line_string) => {
+            put_u32(buf, WKB_MULTILINESTRING);
+            put_u32(buf, multi_line_string.0.len() as u32);
+            for line_string in multi_line_string.iter() {
+                buf.push(1);
+                put_u32(buf, WKB_LINESTRING);
+                put_coords(buf, &line_string.0);
+            }
+        }
+        Geometry::MultiPolygon(multi_polygon) => {
+            put_u32(buf, WKB_MULTIPOLYGON);
+            put_u32(buf, multi_polygon.0.len() as u32);
+            for polygon in multi_polygon.iter() {
+                buf.push(1);
+                put_u32(buf, WKB_POLYGON);
+                put_rings(buf, polygon);
+            }
+        }
+        Geometry::GeometryCollection(collection) => {
+            put_u32(buf, WKB_GEOMETRYCOLLECTION);
+            put_u32(buf, collection.0.len() as u32);
+            for geometry in collection.iter() {
+                write_wkb(buf, geometry);
+            }
+        }
+        Geometry::Rect(rect) => {
+            put_u32(buf, WKB_POLYGON);
+            put_rings(buf, &rect.to_polygon());
+        }
+        Geometry::Triangle(triangle) => {
+            put_u32(buf, WKB_POLYGON);
+            put_rings(buf, &triangle.to_polygon());
+        }
+    }
+}
+
+/// Parse a geometry from WKT, the SRID prefix of EWKT (e.g. `SRID=4326;POINT(1 2)`) is ignored.
+pub fn geometry_from_wkt(wkt: &str) -> Result<Geometry<f64>> {
+    let mut parser = WktParser { input: wkt, pos: 0 };
+    parser.skip_whitespace();
+    if parser
+        .rest()
+        .get(..5)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("SRID="))
+    {
+        match parser.rest().find(';') {
+            Some(pos) => parser.pos += pos + 1,
+            None => return Err(parser.error()),
+        }
+    }
+    let geometry = parser.parse_geometry()?;
+    parser.skip_whitespace();
+    if !parser.rest().is_empty() {
+        return Err(parser.error());
+    }
+    Ok(geometry)
+}
+
+struct WktParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> WktParser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn error(&self) -> ErrorCode {
+        invalid_geometry(format!(
+            "unexpected WKT at position {}: {:?}",
+            self.pos, self.input
+        ))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn consume(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        if self.consume(c) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    fn word(&mut self) -> String {
+        self.take_while(|c| c.is_ascii_alphabetic())
+            .to_ascii_uppercase()
+    }
+
+    fn number(&mut self) -> Result<f64> {
+        self.take_while(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'e' | 'E'))
+            .parse::<f64>()
+            .map_err(|_| self.error())
+    }
+
+    fn coord(&mut self) -> Result<Coord<f64>> {
+        let x = self.number()?;
+        let y = self.number()?;
+        self.skip_whitespace();
+        if !self.rest().starts_with([',', ')']) {
+            return Err(invalid_geometry("only 2D geometries are supported"));
+        }
+        Ok(Coord { x, y })
+    }
+
+    // Parse a comma separated list in parentheses.
+    fn list<T>(&mut self, mut item: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+        self.expect('(')?;
+        let mut items = vec![item(self)?];
+        while self.consume(',') {
+            items.push(item(self)?);
+        }
+        self.expect(')')?;
+        Ok(items)
+    }
+
+    fn line_string(&mut self) -> Result<LineString<f64>> {
+        Ok(LineString::new(self.list(Self::coord)?))
+    }
+
+    fn polygon(&mut self) -> Result<Polygon<f64>> {
+        let mut rings = self.list(Self::line_string)?;
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    }
+
+    fn point(&mut self) -> Result<Point<f64>> {
+        // The points of MULTIPOINT may or may not be in parentheses.
+        if self.consume('(') {
+            let coord = self.coord()?;
+            self.expect(')')?;
+            Ok(Point(coord))
+        } else {
+            Ok(Point(self.coord()?))
+        }
+    }
+
+    fn parse_geometry(&mut self) -> Result<Geometry<f64>> {
+        let tag = self.word();
+        let empty = match self.word().as_str() {
+            "" => false,
+            "EMPTY" => true,
+            "Z" | "M" | "ZM" => return Err(invalid_geometry("only 2D geometries are supported")),
+            _ => return Err(self.error()),
+        };
+
+        let geometry = match tag.as_str() {
+            "POINT" if empty => return Err(invalid_geometry("empty point is not supported")),
+            "POINT" => {
+                self.expect('(')?;
+                let coord = self.coord()?;
+                self.expect(')')?;
+                Geometry::Point(Point(coord))
+            }
+            "LINESTRING" if empty => Geometry::LineString(LineString::new(vec![])),
+            "LINESTRING" => Geometry::LineString(self.line_string()?),
+            "POLYGON" if empty => Geometry::Polygon(Polygon::new(LineString::new(vec![]), vec![])),
+            "POLYGON" => Geometry::Polygon(self.polygon()?),
+            "MULTIPOINT" if empty => Geometry::MultiPoint(MultiPoint::new(vec![])),
+            "MULTIPOINT" => Geometry::MultiPoint(MultiPoint::new(self.list(Self::point)?)),
+            "MULTILINESTRING" if empty => Geometry::MultiLineString(MultiLineString::new(vec![])),
+            "MULTILINESTRING" => {
+                Geometry::MultiLineString(MultiLineString::new(self.list(Self::line_string)?))
+            }
+            "MULTIPOLYGON" if empty => Geometry::MultiPolygon(MultiPolygon::new(vec![])),
+            "MULTIPOLYGON" => Geometry::MultiPolygon(MultiPolygon::new(self.list(Self::polygon)?)),
+            "GEOMETRYCOLLECTION" if empty => {
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![]))
+            }
+            "GEOMETRYCOLLECTION" => Geometry::GeometryCollection(GeometryCollection::new_from(
+                self.list(Self::parse_geometry)?,
+            )),
+            _ => return Err(self.error()),
+        };
+        Ok(geometry)
+    }
+}
+
+pub fn geometry_to_wkt(geometry: &Geometry<f64>) -> String {
+    let mut buf = String::new();
+    write_wkt(&mut buf, geometry);
+    buf
+}
+
+fn write_wkt(buf: &mut String, geometry: &Geometry<f64>) {
+    fn put_coords(buf: &mut String, coords: &[Coord<f64>]) {
+        buf.push('(');
+        for (i, coord) in coords.iter().enumerate() {
+            if i > 0 {
+                buf.push(',');
+            }
+            write!(buf, "{} {}", coord.x, coord.y).unwrap();
+        }
+        buf.push(')');
+    }
+    fn put_rings(buf: &mut String, polygon: &Polygon<f64>) {
+        buf.push('(');
+        put_coords(buf, &polygon.exterior().0);
+        for interior in polygon.interiors() {
+            buf.push(',');
+            put_coords(buf, &interior.0);
+        }
+        buf.push(')');
+    }
+
+    match geometry {
+        Geometry::Point(point) => write!(buf, "POINT({} {})", point.x(), point.y()).unwrap(),
+        Geometry::Line(line) => {
+            buf.push_str("LINESTRING");
+            put_coords(buf, &[line.start, line.end]);
+        }
+        Geometry::LineString(line_string) if line_string.0.is_empty() => {
+            buf.push_str("LINESTRING EMPTY")
+        }
+        Geometry::LineString(line_string) => {
+            buf.push_str("LINESTRING");
+            put_coords(buf, &line_string.0);
+        }
+        Geometry::Polygon(polygon) if polygon.exterior().0.is_empty() => {
+            buf.push_str("POLYGON EMPTY")
+        }
+        Geometry::Polygon(polygon) => {
+            buf.push_str("POLYGON");
+            put_rings(buf, polygon);
+        }
+        Geometry::MultiPoint(multi_point) if multi_point.0.is_empty() => {
+            buf.push_str("MULTIPOINT EMPTY")
+        }
+        Geometry::MultiPoint(multi_point) => {
+            buf.push_str("MULTIPOINT");
+            let coords = multi_point.iter().map(|point| point.0).collect::<Vec<_>>();
+            put_coords(buf, &coords);
+        }
+        Geometry::MultiLineString(multi_line_string) if multi_line_string.0.is_empty() => {
+            buf.push_str("MULTILINESTRING EMPTY")
+        }
+        Geometry::MultiLineString(multi_line_string) => {
+            buf.push_str("MULTILINESTRING(");
+            for (i, line_string) in multi_line_string.iter().enumerate() {
+                if i > 0 {
+                    buf.push(',');
+                }
+                put_coords(buf, &line_string.0);
+            }
+            buf.push(')');
+        }
+        Geometry::MultiPolygon(multi_polygon) if multi_polygon.0.is_empty() => {
+            buf.push_str("MULTIPOLYGON EMPTY")
+        }
+        Geometry::MultiPolygon(multi_polygon) => {
+            buf.push_str("MULTIPOLYGON(");
+            for (i, polygon) in multi_polygon.iter().enumerate() {
+                if i > 0 {
+                    buf.push(',');
+                }
+                put_rings(buf, polygon);
+            }
+            buf.push(')');
+        }
+        Geometry::GeometryCollection(collection) if collection.0.is_empty() => {
+            buf.push_str("GEOMETRYCOLLECTION EMPTY")
+        }
+        Geometry::GeometryCollection(collection) => {
+            buf.push_str("GEOMETRYCOLLECTION(");
+            for (i, geometry) in collection.iter().enumerate() {
+                if i > 0 {
+                    buf.push(',');
+                }
+                write_wkt(buf, geometry);
+            }
+            buf.push(')');
+        }
+        Geometry::Rect(rect) => write_wkt(buf, &Geometry::Polygon(rect.to_polygon())),
+        Geometry::Triangle(triangle) => write_wkt(buf, &Geometry::Polygon(triangle.to_polygon())),
+    }
+}
+
+pub fn geometry_from_geojson(geojson: &str) -> Result<Geometry<f64>> {
+    let value: JsonValue = serde_json::from_str(geojson).map_err(invalid_geometry)?;
+    geojson_to_geometry(&value)
+}
+
+fn geojson_to_geometry(value: &JsonValue) -> Result<Geometry<f64>> {
+    let invalid = || invalid_geometry(format!("invalid GeoJSON {value}"));
+    let coord = |value: &JsonValue| -> Result<Coord<f64>> {
+        match value.as_array().map(|v| v.as_slice()) {
+            Some([x, y]) => Ok(Coord {
+                x: x.as_f64().ok_or_else(invalid)?,
+                y: y.as_f64().ok_or_else(invalid)?,
+            }),
+            Some([_, _, _, ..]) => Err(invalid_geometry("only 2D geometries are supported")),
+            _ => Err(invalid()),
+        }
+    };
+    let array = |value: &JsonValue| -> Result<Vec<JsonValue>> {
+        value.as_array().cloned().ok_or_else(invalid)
+    };
+    let line_string = |value: &JsonValue| -> Result<LineString<f64>> {
+        Ok(LineString::new(
+            array(value)?.iter().map(coord).collect::<Result<_>>()?,
+        ))
+    };
+    let polygon = |value: &JsonValue| -> Result<Polygon<f64>> {
+        let mut rings = array(value)?
+            .iter()
+            .map(line_string)
+            .collect::<Result<Vec<_>>>()?;
+        if rings.is_empty() {
+            return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+        }
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    };
+
+    let object = value.as_object().ok_or_else(invalid)?;
+    let ty = object
+        .get("type")
+        .and_then(|ty| ty.as_str())
+        .ok_or_else(invalid)?;
+    if ty == "GeometryCollection" {
+        let geometries = object.get("geometries").ok_or_else(invalid)?;
+        return Ok(Geometry::GeometryCollection(GeometryCollection::new_from(
+            array(geometries)?
+                .iter()
+                .map(geojson_to_geometry)
+                .collect::<Result<_>>()?,
+        )));
+    }
+
+    let coordinates = object.get("coordinates").ok_or_else(invalid)?;
+    let geometry = match ty {
+        "Point" => Geometry::Point(Point(coord(coordinates)?)),
+        "LineString" => Geometry::LineString(line_string(coordinates)?),
+        "Polygon" => Geometry::Polygon(polygon(coordinates)?),
+        "MultiPoint" => Geometry::MultiPoint(MultiPoint::new(
+            array(coordinates)?
+                .iter()
+                .map(|v| coord(v).map(Point))
+                .collect::<Result<_>>()?,
+        )),
+        "MultiLineString" => Geometry::MultiLineString(MultiLineString::new(
+            array(coordinates)?
+                .iter()
+                .map(line_string)
+                .collect::<Result<_>>()?,
+        )),
+        "MultiPolygon" => Geometry::MultiPolygon(MultiPolygon::new(
+            array(coordinates)?
+                .iter()
+                .map(polygon)
+                .collect::<Result<_>>()?,
+        )),
+        _ => return Err(invalid()),
+    };
+    Ok(geometry)
+}
+
+pub fn geometry_to_geojson(geometry: &Geometry<f64>) -> String {
+    geometry_to_geojson_value(geometry).to_string()
+}
+
+fn geometry_to_geojson_value(geometry: &Geometry<f64>) -> JsonValue {
+    fn coords(coords: &[Coord<f64>]) -> JsonValue {
+        coords.iter().map(|c| json!([c.x, c.y])).collect()
+    }
+    fn rings(polygon: &Polygon<f64>) -> JsonValue {
+        if polygon.exterior().0.is_empty() {
+            return json!([]);
+        }
+        std::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .map(|ring| coords(&ring.0))
+            .collect()
+    }
+
+    match geometry {
+        Geometry::Point(point) => json!({
+            "type": "Point",
+            "coordinates": [point.x(), point.y()],
+        }),
+        Geometry::Line(line) => json!({
+            "type": "LineString",
+            "coordinates": coords(&[line.start, line.end]),
+        }),
+        Geometry::LineString(line_string) => json!({
+            "type": "LineString",
+            "coordinates": coords(&line_string.0),
+        }),
+        Geometry::Polygon(polygon) => json!({
+            "type": "Polygon",
+            "coordinates": rings(polygon),
+        }),
+        Geometry::MultiPoint(multi_point) => json!({
+            "type": "MultiPoint",
+            "coordinates": multi_point.iter().map(|p| json!([p.x(), p.y()])).collect::<JsonValue>(),
+        }),
+        Geometry::MultiLineString(multi_line_string) => json!({
+            "type": "MultiLineString",
+            "coordinates": multi_line_string.iter().map(|l| coords(&l.0)).collect::<JsonValue>(),
+        }),
+        Geometry::MultiPolygon(multi_polygon) => json!({
+            "type": "MultiPolygon",
+            "coordinates": multi_polygon.iter().map(rings).collect::<JsonValue>(),
+        }),
+        Geometry::GeometryCollection(collection) => json!({
+            "type": "GeometryCollection",
+            "geometries": collection.iter().map(geometry_to_geojson_value).collect::<JsonValue>(),
+        }),
+        Geometry::Rect(rect) => geometry_to_geojson_value(&Geometry::Polygon(rect.to_polygon())),
+        Geometry::Triangle(triangle) => {
+            geometry_to_geojson_value(&Geometry::Polygon(triangle.to_polygon()))
+        }
+    }
+}
@@ -38,6 +38,7 @@ pub mod cursor_ext;
 mod decimal;
 mod escape;
 mod format_settings;
+mod geometry;
 mod position;
 mod stat_buffer;
 
@@ -46,3 +47,15 @@ pub use decimal::display_decimal_128;
 pub use decimal::display_decimal_256;
 pub use escape::escape_string;
 pub use escape::escape_string_with_quote;
+pub use geometry::geometry_bounding_box;
+pub use geometry::geometry_from_geojson;
+pub use geometry::geometry_from_wkb;
+pub use geometry::geometry_from_wkt;
+pub use geometry::geometry_to_geojson;
+pub use geometry::geometry_to_wkb;
+pub use geometry::geometry_to_wkt;
+pub use geometry::parse_to_wkb;
+pub use geometry::point_to_wkb;
+pub use geometry::wkb_bounding_box;
+pub use geometry::wkb_to_point;
+pub use geometry::wkb_to_wkt;
@@ -195,3 +195,38 @@ pub fn metrics_inc_sort_spill_read_milliseconds(c: u64) {
         .get_or_create(labels)
         .observe(c as f64);
 }
+
+// Window spill metrics
+pub fn metrics_inc_window_spill_write_count() {
+    let labels = &vec![("spill", "window_spill".to_string())];
+    SPILL_WRITE_COUNT.get_or_create(labels).inc();
+}
+
+pub fn metrics_inc_window_spill_write_bytes(c: u64) {
+    let labels = &vec![("spill", "window_spill".to_string())];
+    SPILL_WRITE_BYTES.get_or_create(labels).inc_by(c);
+}
+
+pub fn metrics_inc_window_spill_write_milliseconds(c: u64) {
+    let labels = &vec![("spill", "window_spill".to_string())];
+    SPILL_WRITE_MILLISECONDS
+        .get_or_create(labels)
+        .observe(c as f64)
+}
+
+pub fn metrics_inc_window_spill_read_count() {
+    let labels = &vec![("spill", "window_spill".to_string())];
+    SPILL_READ_COUNT.get_or_create(labels).inc();
+}
+
+pub fn metrics_inc_window_spill_read_bytes(c: u64) {
+    let labels = &vec![("spill", "window_spill".to_string())];
+    SPILL_READ_BYTES.get_or_create(labels).inc_by(c);
+}
+
+pub fn metrics_inc_window_spill_read_milliseconds(c: u64) {
+    let labels = &vec![("spill", "window_spill".to_string())];
+    SPILL_READ_MILLISECONDS
+        .get_or_create(labels)
+        .observe(c as f64);
+}
@@ -0,0 +1,172 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use apache_avro::schema::Schema as AvroSchema;
+use apache_avro::Reader;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use opendal::Operator;
+
+/// Read the writer schema of an avro object container file and convert it to a `TableSchema`.
+#[async_backtrace::framed]
+pub async fn read_avro_schema_async(operator: &Operator, path: &str) -> Result<TableSchema> {
+    // The schema is stored in the file header, whose size is not known in advance.
+    let data = operator.read(path).await?;
+    let reader = Reader::new(Cursor::new(data))
+        .map_err(|e| ErrorCode::BadBytes(format!("Invalid avro file {}: {}", path, e)))?;
+    avro_to_table_schema(reader.writer_schema())
+}
+
+/// Convert the top-level record schema of an avro file to a `TableSchema`.
+pub fn avro_to_table_schema(schema: &AvroSchema) -> Result<TableSchema> {
+    let mut names = HashMap::new();
+    collect_named_schemas(schema, &mut names);
+
+    match schema {
+        AvroSchema::Record(record) => {
+            let fields = record
+                .fields
+                .iter()
+                .map(|f| {
+                    Ok(TableField::new(
+                        &f.name,
+                        avro_to_table_type(&f.schema, &names)?,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(TableSchema::new(fields))
+        }
+        _ => Err(ErrorCode::BadArguments(format!(
+            "The top-level schema of an avro file must be a record, but got {:?}",
+            schema
+        ))),
+    }
+}
+
+/// Convert an avro schema to a `TableDataType`.
+///
+/// - `[null, T]` unions become `Nullable(T)`, other unions with multiple branches become `Variant`.
+/// - logical types are mapped to their Databend counterparts, timestamps are stored in microseconds.
+/// - named references are resolved with `names`.
+pub fn avro_to_table_type(
+    schema: &AvroSchema,
+    names: &HashMap<String, AvroSchema>,
+) -> Result<TableDataType> {
+    let ty = match schema {
+        AvroSchema::Null => TableDataType::Null,
+        AvroSchema::Boolean => TableDataType::Boolean,
+        AvroSchema::Int => TableDataType::Number(NumberDataType::Int32),
+        AvroSchema::Long => TableDataType::Number(NumberDataType::Int64),
+        AvroSchema::Float => TableDataType::Number(NumberDataType::Float32),
+        AvroSchema::Double => TableDataType::Number(NumberDataType::Float64),
+        AvroSchema::Bytes | AvroSchema::Fixed(_) => TableDataType::Binary,
+        AvroSchema::String | AvroSchema::Enum(_) | AvroSchema::Uuid => TableDataType::String,
+        AvroSchema::Array(item) => TableDataType::Array(Box::new(avro_to_table_type(item, names)?)),
+        AvroSchema::Map(value) => TableDataType::Map(Box::new(TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![TableDataType::String, avro_to_table_type(value, names)?],
+        })),
+        AvroSchema::Record(record) => {
+            let mut fields_name = Vec::with_capacity(record.fields.len());
+            let mut fields_type = Vec::with_capacity(record.fields.len());
+            for field in &record.fields {
+                fields_name.push(field.name.clone());
+                fields_type.push(avro_to_table_type(&field.schema, names)?);
+            }
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            }
+        }
+        AvroSchema::Union(union) => {
+            let variants = union.variants();
+            let non_null = variants
+                .iter()
+                .filter(|v| !matches!(v, AvroSchema::Null))
+                .collect::<Vec<_>>();
+            match non_null.len() {
+                0 => TableDataType::Null,
+                1 if non_null.len() < variants.len() => {
+                    avro_to_table_type(non_null[0], names)?.wrap_nullable()
+                }
+                1 => avro_to_table_type(non_null[0], names)?,
+                _ if non_null.len() < variants.len() => TableDataType::Variant.wrap_nullable(),
+                _ => TableDataType::Variant,
+            }
+        }
+        AvroSchema::Decimal(decimal) => {
+            let size = DecimalSize {
+                precision: decimal.precision as u8,
+                scale: decimal.scale as u8,
+            };
+            TableDataType::Decimal(DecimalDataType::from_size(size)?)
+        }
+        AvroSchema::Date => TableDataType::Date,
+        AvroSchema::TimeMillis => TableDataType::Number(NumberDataType::Int32),
+        AvroSchema::TimeMicros => TableDataType::Number(NumberDataType::Int64),
+        AvroSchema::TimestampMillis
+        | AvroSchema::TimestampMicros
+        | AvroSchema::LocalTimestampMillis
+        | AvroSchema::LocalTimestampMicros => TableDataType::Timestamp,
+        AvroSchema::Duration => TableDataType::Interval,
+        AvroSchema::Ref { name } => {
+            let fullname = name.fullname(None);
+            match names.get(&fullname) {
+                Some(schema) => avro_to_table_type(schema, names)?,
+                None => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Unresolved avro schema reference {}",
+                        fullname
+                    )));
+                }
+            }
+        }
+    };
+    Ok(ty)
+}
+
+/// Collect the named types (record, enum, fixed) so that `Ref` schemas can be resolved.
+pub fn collect_named_schemas(schema: &AvroSchema, names: &mut HashMap<String, AvroSchema>) {
+    match schema {
+        AvroSchema::Record(record) => {
+            names.insert(record.name.fullname(None), schema.clone());
+            for field in &record.fields {
+                collect_named_schemas(&field.schema, names);
+            }
+        }
+        AvroSchema::Enum(e) => {
+            names.insert(e.name.fullname(None), schema.clone());
+        }
+        AvroSchema::Fixed(f) => {
+            names.insert(f.name.fullname(None), schema.clone());
+        }
+        AvroSchema::Array(item) => collect_named_schemas(item, names),
+        AvroSchema::Map(value) => collect_named_schemas(value, names),
+        AvroSchema::Union(union) => {
+            for variant in union.variants() {
+                collect_named_schemas(variant, names);
+            }
+        }
+        _ => {}
+    }
+}
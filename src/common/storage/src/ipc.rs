@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use arrow_array::RecordBatch;
+use arrow_ipc::reader::FileReader;
+use arrow_ipc::reader::StreamReader;
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use bytes::Bytes;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use opendal::Operator;
+
+/// The magic bytes at the start and the end of an arrow IPC file.
+const ARROW_FILE_MAGIC: &[u8] = b"ARROW1";
+
+/// Read the schema of an arrow IPC file or stream.
+#[async_backtrace::framed]
+pub async fn read_arrow_schema_async(operator: &Operator, path: &str) -> Result<ArrowSchemaRef> {
+    let data = Bytes::from(operator.read(path).await?);
+    Ok(ArrowIpcReader::try_create(path, data)?.schema())
+}
+
+/// Reader of record batches in either variant of arrow IPC.
+///
+/// The file variant starts with the magic `ARROW1`, otherwise the data is read as a stream.
+pub enum ArrowIpcReader {
+    File(FileReader<Cursor<Bytes>>),
+    Stream(StreamReader<Cursor<Bytes>>),
+}
+
+impl ArrowIpcReader {
+    pub fn try_create(location: &str, data: Bytes) -> Result<Self> {
+        let is_file = data.starts_with(ARROW_FILE_MAGIC);
+        let reader = if is_file {
+            FileReader::try_new(Cursor::new(data), None).map(ArrowIpcReader::File)
+        } else {
+            StreamReader::try_new(Cursor::new(data), None).map(ArrowIpcReader::Stream)
+        };
+        reader.map_err(|e| ErrorCode::BadBytes(format!("Invalid arrow file {}: {}", location, e)))
+    }
+
+    pub fn schema(&self) -> ArrowSchemaRef {
+        match self {
+            ArrowIpcReader::File(reader) => reader.schema(),
+            ArrowIpcReader::Stream(reader) => reader.schema(),
+        }
+    }
+}
+
+impl Iterator for ArrowIpcReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch = match self {
+            ArrowIpcReader::File(reader) => reader.next(),
+            ArrowIpcReader::Stream(reader) => reader.next(),
+        };
+        batch.map(|b| b.map_err(ErrorCode::from))
+    }
+}
@@ -39,6 +39,10 @@ mod operator;
 pub use operator::init_operator;
 pub use operator::DataOperator;
 
+mod local_spill;
+pub use local_spill::LocalSpillDisk;
+pub use local_spill::LocalSpillFile;
+
 pub mod metrics;
 pub use crate::metrics::StorageMetrics;
 pub use crate::metrics::StorageMetricsLayer;
@@ -58,6 +62,17 @@ pub mod parquet_rs;
 pub use parquet_rs::read_metadata_async;
 pub use parquet_rs::read_parquet_schema_async_rs;
 
+mod avro;
+pub use avro::avro_to_table_schema;
+pub use avro::read_avro_schema_async;
+
+mod orc;
+pub use orc::read_orc_schema_async;
+
+mod ipc;
+pub use ipc::read_arrow_schema_async;
+pub use ipc::ArrowIpcReader;
+
 mod stage;
 pub use stage::init_stage_operator;
 pub use stage::StageFileInfo;
@@ -0,0 +1,270 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::SeekFrom;
+use std::ops::Range;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use dashmap::DashMap;
+use databend_common_base::base::tokio::fs;
+use databend_common_base::base::tokio::io::AsyncReadExt;
+use databend_common_base::base::tokio::io::AsyncSeekExt;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::base::GlobalUniqName;
+use databend_common_exception::Result;
+use log::info;
+use log::warn;
+
+/// A file spilled to the local disk.
+pub struct LocalSpillFile {
+    pub name: String,
+    pub content_length: u64,
+    pub last_modified: Option<i64>,
+}
+
+/// The local disk directory that spilled data is written to before falling back to the remote storage.
+///
+/// The query node only writes to its own sub directory `databend-spill-<node_id>` of the configured path,
+/// so the path can be shared with other nodes and programs, only the owned sub directory is ever removed.
+/// Every query has its own sub directory in it, which is removed when the query is finished.
+/// The spilled files on the local disk are addressed by their absolute paths,
+/// so they never conflict with the locations on the remote storage.
+pub struct LocalSpillDisk {
+    /// The root directory, `None` if spilling to the local disk is disabled.
+    root: Option<PathBuf>,
+    /// The maximum bytes of the spilled files, 0 means no limit.
+    max_bytes: u64,
+    used_bytes: AtomicU64,
+    /// The spilled bytes of each query.
+    query_bytes: DashMap<String, u64>,
+}
+
+impl LocalSpillDisk {
+    pub fn init(path: &str, node_id: &str, max_bytes: u64) -> Result<()> {
+        GlobalInstance::set(Arc::new(Self::try_create(path, node_id, max_bytes)?));
+
+        Ok(())
+    }
+
+    pub fn try_create(path: &str, node_id: &str, max_bytes: u64) -> Result<LocalSpillDisk> {
+        let root = if path.is_empty() {
+            None
+        } else {
+            let mut path = PathBuf::from(path);
+            if !path.is_absolute() {
+                path = std::env::current_dir()?.join(path);
+            }
+            let root = Self::sub_dir(&path, &format!("databend-spill-{}", node_id));
+
+            // Remove the files left in the directory owned by this node.
+            if root.exists() {
+                std::fs::remove_dir_all(&root)?;
+            }
+            std::fs::create_dir_all(&root)?;
+            info!(
+                "Spill to local disk is enabled, directory: {}",
+                root.display()
+            );
+            Some(root)
+        };
+
+        Ok(LocalSpillDisk {
+            root,
+            max_bytes,
+            used_bytes: AtomicU64::new(0),
+            query_bytes: DashMap::new(),
+        })
+    }
+
+    pub fn instance() -> Arc<LocalSpillDisk> {
+        GlobalInstance::get()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.root.is_some()
+    }
+
+    /// Returns true if the spilled file is on the local disk.
+    pub fn is_local(&self, location: &str) -> bool {
+        self.root
+            .as_ref()
+            .is_some_and(|root| Path::new(location).starts_with(root))
+    }
+
+    pub fn used_bytes(&self) -> u64 {
+        self.used_bytes.load(Ordering::Relaxed)
+    }
+
+    fn try_reserve(&self, bytes: u64) -> bool {
+        self.used_bytes
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| {
+                let used = used + bytes;
+                if self.max_bytes != 0 && used > self.max_bytes {
+                    None
+                } else {
+                    Some(used)
+                }
+            })
+            .is_ok()
+    }
+
+    fn sub_dir(root: &Path, name: &str) -> PathBuf {
+        // The name may be specified by the client, make sure it is a valid directory name.
+        let dir_name = name
+            .chars()
+            .map(
+                |c| match c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    true => c,
+                    false => '_',
+                },
+            )
+            .collect::<String>();
+        root.join(dir_name)
+    }
+
+    /// Write the data into a new spilled file of the query.
+    ///
+    /// Returns the location of the file, or `None` if the data cannot be written to the local disk
+    /// (disabled, out of quota or failed to write), then the caller should write it to the remote storage.
+    pub async fn write(&self, query_id: &str, data: &[Vec<u8>]) -> Option<String> {
+        let root = self.root.as_ref()?;
+        let bytes = data.iter().map(|d| d.len() as u64).sum::<u64>();
+        if !self.try_reserve(bytes) {
+            return None;
+        }
+
+        let dir = Self::sub_dir(root, query_id);
+        let path = dir.join(GlobalUniqName::unique());
+        let res = async {
+            fs::create_dir_all(&dir).await?;
+            let mut file = fs::File::create(&path).await?;
+            for d in data {
+                file.write_all(d).await?;
+            }
+            file.flush().await
+        }
+        .await;
+
+        match res {
+            Ok(_) => {
+                *self.query_bytes.entry(query_id.to_string()).or_default() += bytes;
+                Some(path.display().to_string())
+            }
+            Err(cause) => {
+                self.used_bytes.fetch_sub(bytes, Ordering::SeqCst);
+                let _ = fs::remove_file(&path).await;
+                warn!(
+                    "Failed to spill {} bytes to local disk, fall back to remote storage, cause: {:?}",
+                    bytes, cause
+                );
+                None
+            }
+        }
+    }
+
+    /// Read a spilled file on the local disk, or only the `range` of it if specified.
+    pub async fn read(&self, location: &str, range: Option<Range<u64>>) -> Result<Vec<u8>> {
+        debug_assert!(self.is_local(location));
+        match range {
+            None => Ok(fs::read(location).await?),
+            Some(range) => {
+                let mut file = fs::File::open(location).await?;
+                file.seek(SeekFrom::Start(range.start)).await?;
+                let mut data = vec![0; (range.end - range.start) as usize];
+                file.read_exact(&mut data).await?;
+                Ok(data)
+            }
+        }
+    }
+
+    /// Remove a spilled file of the query and release its quota.
+    pub async fn remove(&self, query_id: &str, location: &str) -> Result<()> {
+        debug_assert!(self.is_local(location));
+        let bytes = fs::metadata(location).await?.len();
+        fs::remove_file(location).await?;
+
+        if let Some(mut query_bytes) = self.query_bytes.get_mut(query_id) {
+            *query_bytes -= bytes;
+            self.used_bytes.fetch_sub(bytes, Ordering::SeqCst);
+        }
+        Ok(())
+    }
+
+    /// Remove all the spilled files of the query and release its quota.
+    pub async fn remove_query_files(&self, query_id: &str) {
+        let Some(root) = &self.root else {
+            return;
+        };
+
+        if let Some((_, bytes)) = self.query_bytes.remove(query_id) {
+            let dir = Self::sub_dir(root, query_id);
+            if let Err(cause) = fs::remove_dir_all(&dir).await {
+                warn!(
+                    "Failed to remove local spill directory {}, cause: {:?}",
+                    dir.display(),
+                    cause
+                );
+            }
+            self.used_bytes.fetch_sub(bytes, Ordering::SeqCst);
+        }
+    }
+
+    /// List the spilled files on the local disk.
+    pub fn list_files(&self) -> Result<Vec<LocalSpillFile>> {
+        let Some(root) = &self.root else {
+            return Ok(vec![]);
+        };
+
+        let mut files = vec![];
+        for query_dir in std::fs::read_dir(root)? {
+            let query_dir = query_dir?;
+            if !query_dir.file_type()?.is_dir() {
+                continue;
+            }
+
+            // The files may be removed while listing.
+            let Ok(entries) = std::fs::read_dir(query_dir.path()) else {
+                continue;
+            };
+            for entry in entries {
+                let Ok(entry) = entry else {
+                    continue;
+                };
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+
+                if metadata.is_file() {
+                    files.push(LocalSpillFile {
+                        name: entry.file_name().to_string_lossy().to_string(),
+                        content_length: metadata.len(),
+                        last_modified: metadata
+                            .modified()
+                            .ok()
+                            .map(|t| DateTime::<Utc>::from(t).timestamp_micros()),
+                    });
+                }
+            }
+        }
+
+        Ok(files)
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use arrow_schema::Schema as ArrowSchema;
+use bytes::Bytes;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use opendal::Operator;
+use orc_rust::ArrowReaderBuilder;
+
+/// Read the schema of an orc file and convert it to an arrow schema.
+#[async_backtrace::framed]
+pub async fn read_orc_schema_async(operator: &Operator, path: &str) -> Result<ArrowSchema> {
+    let data = Bytes::from(operator.read(path).await?);
+    let builder = ArrowReaderBuilder::try_new(data)
+        .map_err(|e| ErrorCode::BadBytes(format!("Invalid orc file {}: {}", path, e)))?;
+    Ok(builder
+        .file_metadata()
+        .root_data_type()
+        .create_arrow_schema(&HashMap::new()))
+}
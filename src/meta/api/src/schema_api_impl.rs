@@ -33,6 +33,8 @@ use databend_common_meta_app::app_error::DropTableWithDropTime;
 use databend_common_meta_app::app_error::DuplicatedUpsertFiles;
 use databend_common_meta_app::app_error::GetIndexWithDropTime;
 use databend_common_meta_app::app_error::IndexAlreadyExists;
+use databend_common_meta_app::app_error::OutOfSequenceRange;
+use databend_common_meta_app::app_error::SequenceAlreadyExists;
 use databend_common_meta_app::app_error::ShareHasNoGrantedPrivilege;
 use databend_common_meta_app::app_error::StreamAlreadyExists;
 use databend_common_meta_app::app_error::StreamVersionMismatched;
@@ -47,6 +49,7 @@ use databend_common_meta_app::app_error::UndropTableWithNoDropTime;
 use databend_common_meta_app::app_error::UnknownCatalog;
 use databend_common_meta_app::app_error::UnknownDatabaseId;
 use databend_common_meta_app::app_error::UnknownIndex;
+use databend_common_meta_app::app_error::UnknownSequence;
 use databend_common_meta_app::app_error::UnknownStreamId;
 use databend_common_meta_app::app_error::UnknownTable;
 use databend_common_meta_app::app_error::UnknownTableId;
@@ -73,6 +76,8 @@ use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReply;
 use databend_common_meta_app::schema::CreateLockRevReq;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateSequenceReply;
+use databend_common_meta_app::schema::CreateSequenceReq;
 use databend_common_meta_app::schema::CreateTableReply;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::CreateVirtualColumnReply;
@@ -95,6 +100,8 @@ use databend_common_meta_app::schema::DropDatabaseReply;
 use databend_common_meta_app::schema::DropDatabaseReq;
 use databend_common_meta_app::schema::DropIndexReply;
 use databend_common_meta_app::schema::DropIndexReq;
+use databend_common_meta_app::schema::DropSequenceReply;
+use databend_common_meta_app::schema::DropSequenceReq;
 use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_meta_app::schema::DropTableReply;
 use databend_common_meta_app::schema::DropVirtualColumnReply;
@@ -109,6 +116,10 @@ use databend_common_meta_app::schema::GetIndexReply;
 use databend_common_meta_app::schema::GetIndexReq;
 use databend_common_meta_app::schema::GetLVTReply;
 use databend_common_meta_app::schema::GetLVTReq;
+use databend_common_meta_app::schema::GetSequenceNextValueReply;
+use databend_common_meta_app::schema::GetSequenceNextValueReq;
+use databend_common_meta_app::schema::GetSequenceReply;
+use databend_common_meta_app::schema::GetSequenceReq;
 use databend_common_meta_app::schema::GetTableCopiedFileReply;
 use databend_common_meta_app::schema::GetTableCopiedFileReq;
 use databend_common_meta_app::schema::GetTableReq;
@@ -134,6 +145,8 @@ use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_meta_app::schema::SequenceMeta;
 use databend_common_meta_app::schema::SetLVTReply;
 use databend_common_meta_app::schema::SetLVTReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyAction;
@@ -158,6 +171,8 @@ use databend_common_meta_app::schema::UndropTableReply;
 use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReply;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
 use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
@@ -2794,59 +2809,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 else_then: vec![get_table_meta],
             };
 
-            if let Some(req) = &req.copied_files {
-                let (conditions, match_operations) =
-                    build_upsert_table_copied_file_info_conditions(
-                        &tbid,
-                        req,
-                        tb_meta_seq,
-                        req.fail_if_duplicated,
-                    )?;
-                txn_req.condition.extend(conditions);
-                txn_req.if_then.extend(match_operations)
-            }
-
-            for req in &req.update_stream_meta {
-                let stream_id = TableId {
-                    table_id: req.stream_id,
-                };
-                let (stream_meta_seq, stream_meta): (_, Option<TableMeta>) =
-                    get_pb_value(self, &stream_id).await?;
-
-                if stream_meta_seq == 0 || stream_meta.is_none() {
-                    return Err(KVAppError::AppError(AppError::UnknownStreamId(
-                        UnknownStreamId::new(req.stream_id, "update_table_meta"),
-                    )));
-                }
-
-                if req.seq.match_seq(stream_meta_seq).is_err() {
-                    return Err(KVAppError::AppError(AppError::from(
-                        StreamVersionMismatched::new(
-                            req.stream_id,
-                            req.seq,
-                            stream_meta_seq,
-                            "update_table_meta",
-                        ),
-                    )));
-                }
-
-                let mut new_stream_meta = stream_meta.unwrap();
-                new_stream_meta.options = req.options.clone();
-                new_stream_meta.updated_on = Utc::now();
-
-                txn_req
-                    .condition
-                    .push(txn_cond_seq(&stream_id, Eq, stream_meta_seq));
-                txn_req
-                    .if_then
-                    .push(txn_op_put(&stream_id, serialize_struct(&new_stream_meta)?));
-            }
-
-            if let Some(deduplicated_label) = req.deduplicated_label.clone() {
-                txn_req
-                    .if_then
-                    .push(build_upsert_table_deduplicated_label(deduplicated_label))
-            }
+            build_update_table_meta_txn(self, &req, tb_meta_seq, &mut txn_req).await?;
 
             let (succ, responses) = send_txn(self, txn_req).await?;
 
@@ -2907,6 +2870,113 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let mut txn_req = TxnRequest {
+                condition: vec![],
+                if_then: vec![],
+                else_then: vec![],
+            };
+            let mut table_metas = Vec::with_capacity(req.update_table_metas.len());
+
+            for req in &req.update_table_metas {
+                let tbid = TableId {
+                    table_id: req.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_pb_value(self, &tbid).await?;
+
+                debug!(ident = as_display!(&tbid); "update_multi_table_meta");
+
+                if tb_meta_seq == 0 || table_meta.is_none() {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(req.table_id, "update_multi_table_meta"),
+                    )));
+                }
+                if req.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            req.table_id,
+                            req.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                // table is not changed
+                txn_req.condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                txn_req
+                    .if_then
+                    .push(txn_op_put(&tbid, serialize_struct(&req.new_table_meta)?));
+
+                build_update_table_meta_txn(self, req, tb_meta_seq, &mut txn_req).await?;
+                table_metas.push(table_meta.unwrap());
+            }
+
+            for deduplicated_label in &req.deduplicated_labels {
+                txn_req.if_then.push(build_upsert_table_deduplicated_label(
+                    deduplicated_label.clone(),
+                ));
+            }
+
+            let (succ, _) = send_txn(self, txn_req).await?;
+
+            debug!(succ = succ; "update_multi_table_meta");
+
+            if succ {
+                let mut share_table_info: Option<Vec<ShareTableInfoMap>> = None;
+                for table_meta in &table_metas {
+                    if let Some(infos) = get_share_table_info_map(self, table_meta).await? {
+                        share_table_info.get_or_insert_with(Vec::new).extend(infos);
+                    }
+                }
+                return Ok(UpdateMultiTableMetaReply { share_table_info });
+            }
+
+            // The txn failed, either because some table was changed, which is reported
+            // by the version check in the next round, or because of the copied files.
+            for req in &req.update_table_metas {
+                let tbid = TableId {
+                    table_id: req.table_id,
+                };
+                let (tb_meta_seq, _): (_, Option<TableMeta>) = get_pb_value(self, &tbid).await?;
+                if req.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            req.table_id,
+                            req.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+            }
+
+            let duplicated = req.update_table_metas.iter().find(|req| {
+                req.copied_files
+                    .as_ref()
+                    .map(|v| v.fail_if_duplicated)
+                    .unwrap_or(false)
+            });
+            if let Some(req) = duplicated {
+                return Err(KVAppError::AppError(AppError::from(
+                    DuplicatedUpsertFiles::new(req.table_id, "update_multi_table_meta"),
+                )));
+            }
+        }
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn set_table_column_mask_policy(
@@ -3792,6 +3862,187 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         })
     }
 
+    #[minitrace::trace]
+    async fn create_sequence(
+        &self,
+        req: CreateSequenceReq,
+    ) -> Result<CreateSequenceReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let (seq, _): (_, Option<SequenceMeta>) = get_pb_value(self, &req.ident).await?;
+
+            if seq > 0 {
+                match req.create_option {
+                    CreateOption::CreateIfNotExists(if_not_exists) => {
+                        return if if_not_exists {
+                            Ok(CreateSequenceReply {})
+                        } else {
+                            Err(KVAppError::AppError(AppError::SequenceAlreadyExists(
+                                SequenceAlreadyExists::new(
+                                    &req.ident.sequence_name,
+                                    format!("create sequence: tenant: {}", req.ident.tenant),
+                                ),
+                            )))
+                        };
+                    }
+                    // The replaced sequence starts from the beginning.
+                    CreateOption::CreateOrReplace => {}
+                }
+            }
+
+            let sequence_meta = SequenceMeta {
+                start: req.start,
+                increment: req.increment,
+                current: req.start,
+                comment: req.comment.clone(),
+                created_on: req.created_on,
+                updated_on: req.created_on,
+            };
+
+            // Create sequence by inserting this record:
+            // (tenant, sequence_name) -> sequence_meta
+            let condition = vec![txn_cond_seq(&req.ident, Eq, seq)];
+            let if_then = vec![txn_op_put(&req.ident, serialize_struct(&sequence_meta)?)];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                ident = as_debug!(&req.ident),
+                succ = succ;
+                "create_sequence"
+            );
+
+            if succ {
+                return Ok(CreateSequenceReply {});
+            }
+        }
+    }
+
+    #[minitrace::trace]
+    async fn get_sequence(&self, req: GetSequenceReq) -> Result<GetSequenceReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let (_, meta) = get_sequence_or_err(self, &req.ident, "get_sequence").await?;
+        Ok(GetSequenceReply { meta })
+    }
+
+    #[minitrace::trace]
+    async fn get_sequence_next_value(
+        &self,
+        req: GetSequenceNextValueReq,
+    ) -> Result<GetSequenceNextValueReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let ctx = func_name!();
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let (seq, mut sequence_meta) = get_sequence_or_err(self, &req.ident, ctx).await?;
+
+            // Allocate `count` values at once, the caller hands them out locally so that a
+            // meta round trip is paid for a batch of values rather than for each of them.
+            let start = sequence_meta.current;
+            let end = req
+                .count
+                .checked_mul(sequence_meta.increment)
+                .and_then(|len| start.checked_add(len))
+                .ok_or_else(|| {
+                    KVAppError::AppError(AppError::OutOfSequenceRange(OutOfSequenceRange::new(
+                        &req.ident.sequence_name,
+                        format!("allocate {} values from {}", req.count, start),
+                    )))
+                })?;
+            sequence_meta.current = end;
+            sequence_meta.updated_on = Utc::now();
+
+            // The version of the sequence is checked, so that the values allocated by
+            // concurrent requests never overlap.
+            let condition = vec![txn_cond_seq(&req.ident, Eq, seq)];
+            let if_then = vec![txn_op_put(&req.ident, serialize_struct(&sequence_meta)?)];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                ident = as_debug!(&req.ident),
+                start = start,
+                end = end,
+                succ = succ;
+                "get_sequence_next_value"
+            );
+
+            if succ {
+                return Ok(GetSequenceNextValueReply {
+                    start,
+                    step: sequence_meta.increment,
+                    end,
+                });
+            }
+        }
+    }
+
+    #[minitrace::trace]
+    async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let (seq, _): (_, Option<SequenceMeta>) = get_pb_value(self, &req.ident).await?;
+
+            if seq == 0 {
+                return if req.if_exists {
+                    Ok(DropSequenceReply { prev: false })
+                } else {
+                    Err(KVAppError::AppError(AppError::UnknownSequence(
+                        UnknownSequence::new(&req.ident.sequence_name, "drop_sequence"),
+                    )))
+                };
+            }
+
+            // Drop sequence by deleting this record:
+            // (tenant, sequence_name) -> sequence_meta
+            let condition = vec![txn_cond_seq(&req.ident, Eq, seq)];
+            let if_then = vec![txn_op_del(&req.ident)];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                ident = as_debug!(&req.ident),
+                succ = succ;
+                "drop_sequence"
+            );
+
+            if succ {
+                return Ok(DropSequenceReply { prev: true });
+            }
+        }
+    }
+
     fn name(&self) -> String {
         "SchemaApiImpl".to_string()
     }
@@ -4473,6 +4724,74 @@ fn build_upsert_table_copied_file_info_conditions(
     Ok((condition, if_then))
 }
 
+/// Build the conditions and operations to update the table copied files, the stream metas
+/// and the deduplicated label along with the table meta.
+async fn build_update_table_meta_txn(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    req: &UpdateTableMetaReq,
+    tb_meta_seq: u64,
+    txn_req: &mut TxnRequest,
+) -> Result<(), KVAppError> {
+    let tbid = TableId {
+        table_id: req.table_id,
+    };
+
+    if let Some(req) = &req.copied_files {
+        let (conditions, match_operations) = build_upsert_table_copied_file_info_conditions(
+            &tbid,
+            req,
+            tb_meta_seq,
+            req.fail_if_duplicated,
+        )?;
+        txn_req.condition.extend(conditions);
+        txn_req.if_then.extend(match_operations)
+    }
+
+    for req in &req.update_stream_meta {
+        let stream_id = TableId {
+            table_id: req.stream_id,
+        };
+        let (stream_meta_seq, stream_meta): (_, Option<TableMeta>) =
+            get_pb_value(kv_api, &stream_id).await?;
+
+        if stream_meta_seq == 0 || stream_meta.is_none() {
+            return Err(KVAppError::AppError(AppError::UnknownStreamId(
+                UnknownStreamId::new(req.stream_id, "update_table_meta"),
+            )));
+        }
+
+        if req.seq.match_seq(stream_meta_seq).is_err() {
+            return Err(KVAppError::AppError(AppError::from(
+                StreamVersionMismatched::new(
+                    req.stream_id,
+                    req.seq,
+                    stream_meta_seq,
+                    "update_table_meta",
+                ),
+            )));
+        }
+
+        let mut new_stream_meta = stream_meta.unwrap();
+        new_stream_meta.options = req.options.clone();
+        new_stream_meta.updated_on = Utc::now();
+
+        txn_req
+            .condition
+            .push(txn_cond_seq(&stream_id, Eq, stream_meta_seq));
+        txn_req
+            .if_then
+            .push(txn_op_put(&stream_id, serialize_struct(&new_stream_meta)?));
+    }
+
+    if let Some(deduplicated_label) = req.deduplicated_label.clone() {
+        txn_req
+            .if_then
+            .push(build_upsert_table_deduplicated_label(deduplicated_label))
+    }
+
+    Ok(())
+}
+
 fn build_upsert_table_deduplicated_label(deduplicated_label: String) -> TxnOp {
     let expire_at = Some(SeqV::<()>::now_ms() / 1000 + 24 * 60 * 60);
     TxnOp::put_with_expire(deduplicated_label, 1_i8.to_le_bytes().to_vec(), expire_at)
@@ -5137,3 +5456,21 @@ fn table_lock_has_to_exist(seq: u64, table_id: u64, msg: impl Display) -> Result
         Ok(())
     }
 }
+
+/// Returns (seq, sequence_meta) if the sequence exists.
+///
+/// Otherwise returns UnknownSequence error
+async fn get_sequence_or_err(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    ident: &SequenceIdent,
+    ctx: impl Display,
+) -> Result<(u64, SequenceMeta), KVAppError> {
+    let (seq, sequence_meta): (_, Option<SequenceMeta>) = get_pb_value(kv_api, ident).await?;
+
+    match sequence_meta {
+        Some(meta) if seq > 0 => Ok((seq, meta)),
+        _ => Err(KVAppError::AppError(AppError::UnknownSequence(
+            UnknownSequence::new(&ident.sequence_name, format!("{}: {}", ctx, ident)),
+        ))),
+    }
+}
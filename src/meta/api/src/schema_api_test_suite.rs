@@ -42,6 +42,7 @@ use databend_common_meta_app::schema::CreateDatabaseReq;
 use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReq;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateSequenceReq;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::CreateVirtualColumnReq;
 use databend_common_meta_app::schema::DBIdTableName;
@@ -57,6 +58,7 @@ use databend_common_meta_app::schema::DeleteLockRevReq;
 use databend_common_meta_app::schema::DropCatalogReq;
 use databend_common_meta_app::schema::DropDatabaseReq;
 use databend_common_meta_app::schema::DropIndexReq;
+use databend_common_meta_app::schema::DropSequenceReq;
 use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_meta_app::schema::DropVirtualColumnReq;
 use databend_common_meta_app::schema::DroppedId;
@@ -65,6 +67,8 @@ use databend_common_meta_app::schema::GcDroppedTableReq;
 use databend_common_meta_app::schema::GetCatalogReq;
 use databend_common_meta_app::schema::GetDatabaseReq;
 use databend_common_meta_app::schema::GetLVTReq;
+use databend_common_meta_app::schema::GetSequenceNextValueReq;
+use databend_common_meta_app::schema::GetSequenceReq;
 use databend_common_meta_app::schema::GetTableCopiedFileReq;
 use databend_common_meta_app::schema::GetTableReq;
 use databend_common_meta_app::schema::IcebergCatalogOption;
@@ -84,6 +88,7 @@ use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockKey;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReq;
+use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::schema::SetLVTReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
@@ -102,6 +107,7 @@ use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_app::schema::TruncateTableReq;
 use databend_common_meta_app::schema::UndropDatabaseReq;
 use databend_common_meta_app::schema::UndropTableReq;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReq;
 use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -295,6 +301,9 @@ impl SchemaApiTestSuite {
             .await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite
+            .table_update_multi_table_meta(&b.build().await)
+            .await?;
         suite.table_update_mask_policy(&b.build().await).await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
@@ -332,6 +341,9 @@ impl SchemaApiTestSuite {
             .virtual_column_create_list_drop(&b.build().await)
             .await?;
         suite.catalog_create_get_list_drop(&b.build().await).await?;
+        suite
+            .sequence_create_get_next_drop(&b.build().await)
+            .await?;
         suite.table_least_visible_time(&b.build().await).await?;
         suite
             .drop_table_without_tableid_to_name(&b.build().await)
@@ -1427,6 +1439,7 @@ impl SchemaApiTestSuite {
                         bucket: "bucket".to_string(),
                         ..Default::default()
                     })),
+                    rest_catalog: None,
                 }),
                 created_on: Utc::now(),
             },
@@ -1463,6 +1476,137 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn sequence_create_get_next_drop<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let ident = SequenceIdent::new("tenant1", "seq1");
+
+        let create_req = |create_option| CreateSequenceReq {
+            create_option,
+            ident: ident.clone(),
+            start: 1,
+            increment: 2,
+            comment: Some("seq".to_string()),
+            created_on: Utc::now(),
+        };
+
+        info!("--- create sequence");
+        {
+            mt.create_sequence(create_req(CreateOption::CreateIfNotExists(false)))
+                .await?;
+
+            let res = mt
+                .create_sequence(create_req(CreateOption::CreateIfNotExists(false)))
+                .await;
+            assert_eq!(
+                ErrorCode::SequenceAlreadyExists("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+
+            mt.create_sequence(create_req(CreateOption::CreateIfNotExists(true)))
+                .await?;
+
+            let got = mt
+                .get_sequence(GetSequenceReq {
+                    ident: ident.clone(),
+                })
+                .await?;
+            assert_eq!(got.meta.start, 1);
+            assert_eq!(got.meta.increment, 2);
+            assert_eq!(got.meta.current, 1);
+            assert_eq!(got.meta.comment, Some("seq".to_string()));
+        }
+
+        info!("--- allocate sequence values in batch");
+        {
+            let got = mt
+                .get_sequence_next_value(GetSequenceNextValueReq {
+                    ident: ident.clone(),
+                    count: 3,
+                })
+                .await?;
+            assert_eq!((got.start, got.step, got.end), (1, 2, 7));
+
+            let got = mt
+                .get_sequence_next_value(GetSequenceNextValueReq {
+                    ident: ident.clone(),
+                    count: 1,
+                })
+                .await?;
+            assert_eq!((got.start, got.step, got.end), (7, 2, 9));
+
+            let got = mt
+                .get_sequence(GetSequenceReq {
+                    ident: ident.clone(),
+                })
+                .await?;
+            assert_eq!(got.meta.current, 9);
+        }
+
+        info!("--- replace sequence restarts it");
+        {
+            mt.create_sequence(create_req(CreateOption::CreateOrReplace))
+                .await?;
+
+            let got = mt
+                .get_sequence_next_value(GetSequenceNextValueReq {
+                    ident: ident.clone(),
+                    count: 2,
+                })
+                .await?;
+            assert_eq!((got.start, got.end), (1, 5));
+        }
+
+        info!("--- drop sequence");
+        {
+            let res = mt
+                .drop_sequence(DropSequenceReq {
+                    if_exists: false,
+                    ident: ident.clone(),
+                })
+                .await?;
+            assert!(res.prev);
+
+            let res = mt
+                .get_sequence(GetSequenceReq {
+                    ident: ident.clone(),
+                })
+                .await;
+            assert_eq!(
+                ErrorCode::UnknownSequence("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+
+            let res = mt
+                .get_sequence_next_value(GetSequenceNextValueReq {
+                    ident: ident.clone(),
+                    count: 1,
+                })
+                .await;
+            assert_eq!(
+                ErrorCode::UnknownSequence("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+
+            let res = mt
+                .drop_sequence(DropSequenceReq {
+                    if_exists: true,
+                    ident: ident.clone(),
+                })
+                .await?;
+            assert!(!res.prev);
+
+            let res = mt
+                .drop_sequence(DropSequenceReq {
+                    if_exists: false,
+                    ident: ident.clone(),
+                })
+                .await;
+            assert!(res.is_err());
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn drop_table_without_tableid_to_name<
         MT: SchemaApi + kvapi::AsKVApi<Error = MetaError>,
@@ -2653,6 +2797,121 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn table_update_multi_table_meta<MT>(&self, mt: &MT) -> anyhow::Result<()>
+    where MT: SchemaApi + kvapi::AsKVApi<Error = MetaError> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_names = ["tb1", "tb2"];
+
+        let table_meta = || TableMeta {
+            schema: Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )])),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            created_on: Utc::now(),
+            ..TableMeta::default()
+        };
+
+        info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                create_option: CreateOption::CreateIfNotExists(false),
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    create_option: CreateOption::CreateIfNotExists(false),
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: table_meta(),
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        let update_req = |table: &TableInfo, data_bytes: u64| {
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.statistics = TableStatistics {
+                data_bytes,
+                ..Default::default()
+            };
+            UpdateTableMetaReq {
+                table_id: table.ident.table_id,
+                seq: MatchSeq::Exact(table.ident.seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            }
+        };
+
+        info!("--- update multi table meta, normal case");
+        {
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+
+            let reqs = vec![update_req(&tb1, 1), update_req(&tb2, 2)];
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: reqs.clone(),
+                deduplicated_labels: vec!["label1".to_string(), "label2".to_string()],
+            })
+            .await?;
+
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(tb1.meta, reqs[0].new_table_meta);
+            assert_eq!(tb2.meta, reqs[1].new_table_meta);
+
+            // all the deduplicated labels are written
+            for label in ["label1", "label2"] {
+                let value = mt.as_kv_api().get_kv(label).await?;
+                assert!(value.is_some(), "label {} is written", label);
+            }
+        }
+
+        info!("--- update multi table meta: one table is changed, nothing is updated");
+        {
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+
+            // tb2 is changed by another writer
+            mt.update_table_meta(update_req(&tb2, 3)).await?;
+
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![update_req(&tb1, 4), update_req(&tb2, 5)],
+                    deduplicated_labels: vec!["label3".to_string()],
+                })
+                .await;
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            let got = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            assert_eq!(tb1.ident.seq, got.ident.seq, "tb1 is not updated");
+            assert_eq!(1, got.meta.statistics.data_bytes);
+            let got = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(3, got.meta.statistics.data_bytes);
+            assert!(mt.as_kv_api().get_kv("label3").await?.is_none());
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn table_update_mask_policy<
         MT: SchemaApi + DatamaskApi + kvapi::AsKVApi<Error = MetaError>,
@@ -871,6 +871,54 @@ impl VirtualColumnNotFound {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("SequenceAlreadyExists: `{name}` while `{context}`")]
+pub struct SequenceAlreadyExists {
+    name: String,
+    context: String,
+}
+
+impl SequenceAlreadyExists {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("UnknownSequence: `{name}` while `{context}`")]
+pub struct UnknownSequence {
+    name: String,
+    context: String,
+}
+
+impl UnknownSequence {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("OutOfSequenceRange: `{name}` while `{context}`")]
+pub struct OutOfSequenceRange {
+    name: String,
+    context: String,
+}
+
+impl OutOfSequenceRange {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 /// Application error.
 ///
 /// The application does not get expected result but there is nothing wrong with meta-service.
@@ -1032,6 +1080,15 @@ pub enum AppError {
 
     #[error(transparent)]
     UnknownStreamId(#[from] UnknownStreamId),
+
+    #[error(transparent)]
+    SequenceAlreadyExists(#[from] SequenceAlreadyExists),
+
+    #[error(transparent)]
+    UnknownSequence(#[from] UnknownSequence),
+
+    #[error(transparent)]
+    OutOfSequenceRange(#[from] OutOfSequenceRange),
 }
 
 impl AppErrorMessage for UnknownBackgroundJob {
@@ -1358,6 +1415,24 @@ impl AppErrorMessage for VirtualColumnAlreadyExists {
     }
 }
 
+impl AppErrorMessage for SequenceAlreadyExists {
+    fn message(&self) -> String {
+        format!("Sequence '{}' already exists", self.name)
+    }
+}
+
+impl AppErrorMessage for UnknownSequence {
+    fn message(&self) -> String {
+        format!("Unknown sequence '{}'", self.name)
+    }
+}
+
+impl AppErrorMessage for OutOfSequenceRange {
+    fn message(&self) -> String {
+        format!("Sequence '{}' is out of range: {}", self.name, self.context)
+    }
+}
+
 impl From<AppError> for ErrorCode {
     fn from(app_err: AppError) -> Self {
         match app_err {
@@ -1450,6 +1525,9 @@ impl From<AppError> for ErrorCode {
             AppError::VirtualColumnAlreadyExists(err) => {
                 ErrorCode::VirtualColumnAlreadyExists(err.message())
             }
+            AppError::SequenceAlreadyExists(err) => ErrorCode::SequenceAlreadyExists(err.message()),
+            AppError::UnknownSequence(err) => ErrorCode::UnknownSequence(err.message()),
+            AppError::OutOfSequenceRange(err) => ErrorCode::OutOfSequenceRange(err.message()),
         }
     }
 }
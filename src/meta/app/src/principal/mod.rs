@@ -19,7 +19,9 @@ mod file_format;
 mod network_policy;
 mod ownership_info;
 mod password_policy;
+mod pipe_info;
 mod principal_identity;
+mod procedure;
 mod role_info;
 mod user_auth;
 mod user_defined_file_format;
@@ -37,7 +39,9 @@ pub use file_format::*;
 pub use network_policy::NetworkPolicy;
 pub use ownership_info::OwnershipInfo;
 pub use password_policy::PasswordPolicy;
+pub use pipe_info::PipeInfo;
 pub use principal_identity::PrincipalIdentity;
+pub use procedure::UserDefinedProcedure;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
 pub use user_auth::AuthInfo;
@@ -0,0 +1,39 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::principal::UserIdentity;
+
+/// A pipe wraps a `COPY INTO <table> FROM <stage>` statement, which is executed
+/// repeatedly to load the new files in the stage. The files have been loaded are
+/// skipped by the copied files of the table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PipeInfo {
+    pub name: String,
+    /// The `COPY INTO` statement in SQL.
+    pub copy_stmt: String,
+    /// Whether the pipe is executed by the scheduler and the uploading to stage.
+    pub auto_ingest: bool,
+    pub execution_paused: bool,
+    pub comment: String,
+    /// The statement is executed as the user created the pipe.
+    pub owner: UserIdentity,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: DateTime<Utc>,
+    pub last_executed_on: Option<DateTime<Utc>>,
+    /// The error of the last execution, `None` if it succeeded.
+    pub last_error: Option<String>,
+}
@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_expression::types::DataType;
+
+/// A stored procedure, whose body is a SQL script that is executed by `CALL`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserDefinedProcedure {
+    pub name: String,
+    pub arg_names: Vec<String>,
+    pub arg_types: Vec<DataType>,
+    /// The type of the returned scalar, `None` if the procedure returns a result set.
+    pub return_type: Option<DataType>,
+    /// The script in `BEGIN ... END` block.
+    pub script: String,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+}
+
+impl Display for UserDefinedProcedure {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}(", self.name)?;
+        for (i, (name, ty)) in self.arg_names.iter().zip(&self.arg_types).enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{name} {ty}")?;
+        }
+        match &self.return_type {
+            Some(ty) => write!(f, ") RETURNS {ty}"),
+            None => write!(f, ") RETURNS TABLE"),
+        }
+    }
+}
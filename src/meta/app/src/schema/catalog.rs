@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Debug;
 use std::fmt::Display;
+use std::fmt::Formatter;
 use std::ops::Deref;
 
 use chrono::DateTime;
 use chrono::Utc;
 
+use crate::storage::mask_string;
 use crate::storage::StorageParams;
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
@@ -71,6 +74,40 @@ pub struct HiveCatalogOption {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct IcebergCatalogOption {
     pub storage_params: Box<StorageParams>,
+    /// Discover namespaces and tables through a REST catalog instead of walking the storage.
+    pub rest_catalog: Option<IcebergRestCatalogOption>,
+}
+
+/// Option for connecting to an iceberg REST catalog.
+///
+/// See [Iceberg REST Catalog API](https://github.com/apache/iceberg/blob/main/open-api/rest-catalog-open-api.yaml).
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct IcebergRestCatalogOption {
+    /// The base uri of the REST catalog, such as `http://127.0.0.1:8181`.
+    pub uri: String,
+    /// The warehouse location or identifier requested from the catalog.
+    pub warehouse: Option<String>,
+    /// The prefix of the catalog endpoints, overridden by the one returned by `/v1/config`.
+    pub prefix: Option<String>,
+    /// The bearer token sent to the catalog.
+    pub token: Option<String>,
+    /// The `<client_id>:<client_secret>` exchanged for a token with the OAuth2 endpoint.
+    pub credential: Option<String>,
+}
+
+impl Debug for IcebergRestCatalogOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IcebergRestCatalogOption")
+            .field("uri", &self.uri)
+            .field("warehouse", &self.warehouse)
+            .field("prefix", &self.prefix)
+            .field("token", &self.token.as_deref().map(|s| mask_string(s, 3)))
+            .field(
+                "credential",
+                &self.credential.as_deref().map(|s| mask_string(s, 3)),
+            )
+            .finish()
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -21,6 +21,7 @@ mod index;
 mod least_visible_time;
 mod lock;
 mod ownership;
+mod sequence;
 mod table;
 mod virtual_column;
 pub use catalog::*;
@@ -63,6 +64,16 @@ pub use lock::LockMeta;
 pub use lock::LockType;
 pub use lock::TableLockKey;
 pub use ownership::Ownership;
+pub use sequence::CreateSequenceReply;
+pub use sequence::CreateSequenceReq;
+pub use sequence::DropSequenceReply;
+pub use sequence::DropSequenceReq;
+pub use sequence::GetSequenceNextValueReply;
+pub use sequence::GetSequenceNextValueReq;
+pub use sequence::GetSequenceReply;
+pub use sequence::GetSequenceReq;
+pub use sequence::SequenceIdent;
+pub use sequence::SequenceMeta;
 pub use table::CountTablesKey;
 pub use table::CountTablesReply;
 pub use table::CountTablesReq;
@@ -87,6 +98,7 @@ pub use table::RenameTableReq;
 pub use table::SetTableColumnMaskPolicyAction;
 pub use table::SetTableColumnMaskPolicyReply;
 pub use table::SetTableColumnMaskPolicyReq;
+pub use table::TableConstraint;
 pub use table::TableCopiedFileInfo;
 pub use table::TableCopiedFileNameIdent;
 pub use table::TableId;
@@ -103,6 +115,8 @@ pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
 pub use table::UndropTableReply;
 pub use table::UndropTableReq;
+pub use table::UpdateMultiTableMetaReply;
+pub use table::UpdateMultiTableMetaReq;
 pub use table::UpdateStreamMetaReq;
 pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
@@ -141,3 +155,4 @@ const PREFIX_INDEX_BY_ID: &str = "__fd_index_by_id";
 const PREFIX_TABLE_LOCK: &str = "__fd_table_lock";
 const PREFIX_TABLE_LVT: &str = "__fd_table_lvt";
 const PREFIX_VIRTUAL_COLUMN: &str = "__fd_virtual_column";
+const PREFIX_SEQUENCE: &str = "__fd_sequence";
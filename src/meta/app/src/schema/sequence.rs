@@ -0,0 +1,172 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::schema::CreateOption;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct SequenceIdent {
+    pub tenant: String,
+    pub sequence_name: String,
+}
+
+impl SequenceIdent {
+    pub fn new(tenant: impl Into<String>, sequence_name: impl Into<String>) -> SequenceIdent {
+        SequenceIdent {
+            tenant: tenant.into(),
+            sequence_name: sequence_name.into(),
+        }
+    }
+}
+
+impl Display for SequenceIdent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'/'{}'", self.tenant, self.sequence_name)
+    }
+}
+
+/// The meta of a sequence.
+///
+/// The values `start, start + increment, ...` are handed out in order, `current` is the
+/// next value that has not been allocated yet.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SequenceMeta {
+    pub start: u64,
+    pub increment: u64,
+    pub current: u64,
+    pub comment: Option<String>,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: DateTime<Utc>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateSequenceReq {
+    pub create_option: CreateOption,
+    pub ident: SequenceIdent,
+    pub start: u64,
+    pub increment: u64,
+    pub comment: Option<String>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl Display for CreateSequenceReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "create_sequence({:?}):{} start={} increment={}",
+            self.create_option, self.ident, self.start, self.increment
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateSequenceReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceReq {
+    pub ident: SequenceIdent,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceReply {
+    pub meta: SequenceMeta,
+}
+
+/// Allocate `count` values of a sequence at once.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceNextValueReq {
+    pub ident: SequenceIdent,
+    pub count: u64,
+}
+
+impl Display for GetSequenceNextValueReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "get_sequence_next_value:{} count={}",
+            self.ident, self.count
+        )
+    }
+}
+
+/// The allocated values are `start, start + step, ...`, and all of them are less than `end`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceNextValueReply {
+    pub start: u64,
+    pub step: u64,
+    pub end: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropSequenceReq {
+    pub if_exists: bool,
+    pub ident: SequenceIdent,
+}
+
+impl Display for DropSequenceReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "drop_sequence(if_exists={}):{}",
+            self.if_exists, self.ident
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropSequenceReply {
+    /// Whether the sequence existed and is dropped.
+    pub prev: bool,
+}
+
+mod kvapi_key_impl {
+    use databend_common_meta_kvapi::kvapi;
+
+    use crate::schema::SequenceIdent;
+    use crate::schema::SequenceMeta;
+    use crate::schema::PREFIX_SEQUENCE;
+
+    /// __fd_sequence/<tenant>/<sequence_name> -> SequenceMeta
+    impl kvapi::Key for SequenceIdent {
+        const PREFIX: &'static str = PREFIX_SEQUENCE;
+
+        type ValueType = SequenceMeta;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .push_str(&self.sequence_name)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            let sequence_name = p.next_str()?;
+            p.done()?;
+
+            Ok(SequenceIdent {
+                tenant,
+                sequence_name,
+            })
+        }
+    }
+}
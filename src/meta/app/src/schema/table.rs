@@ -214,6 +214,49 @@ pub struct TableStatistics {
     pub number_of_blocks: Option<u64>,
 }
 
+/// A constraint declared on the columns of a table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum TableConstraint {
+    /// An enforced primary key is checked on insertion, and it's the default conflict key
+    /// of `REPLACE INTO`.
+    PrimaryKey {
+        columns: Vec<String>,
+        enforced: bool,
+    },
+    /// Unique constraints are informational, they are not checked.
+    Unique { columns: Vec<String> },
+    /// A boolean expression on the columns, in SQL text, that each row must not violate.
+    Check { expr: String },
+}
+
+impl TableConstraint {
+    /// The columns of a primary key or unique constraint.
+    pub fn columns(&self) -> &[String] {
+        match self {
+            TableConstraint::PrimaryKey { columns, .. } | TableConstraint::Unique { columns } => {
+                columns
+            }
+            TableConstraint::Check { .. } => &[],
+        }
+    }
+}
+
+impl Display for TableConstraint {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TableConstraint::PrimaryKey { columns, enforced } => {
+                write!(f, "PRIMARY KEY ({})", columns.join(", "))?;
+                if *enforced {
+                    write!(f, " ENFORCED")?;
+                }
+                Ok(())
+            }
+            TableConstraint::Unique { columns } => write!(f, "UNIQUE ({})", columns.join(", ")),
+            TableConstraint::Check { expr } => write!(f, "CHECK ({expr})"),
+        }
+    }
+}
+
 /// The essential state that defines what a table is.
 ///
 /// It is what a meta store just needs to save.
@@ -243,6 +286,8 @@ pub struct TableMeta {
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
+    // The constraints of the table, keyed by the constraint names.
+    pub constraints: BTreeMap<String, TableConstraint>,
 }
 
 impl TableMeta {
@@ -357,11 +402,24 @@ impl Default for TableMeta {
             statistics: Default::default(),
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
+            constraints: BTreeMap::new(),
         }
     }
 }
 
 impl TableMeta {
+    /// The primary key of the table, and whether it is enforced.
+    pub fn primary_key(&self) -> Option<(&[String], bool)> {
+        self.constraints
+            .values()
+            .find_map(|constraint| match constraint {
+                TableConstraint::PrimaryKey { columns, enforced } => {
+                    Some((columns.as_slice(), *enforced))
+                }
+                _ => None,
+            })
+    }
+
     pub fn push_cluster_key(mut self, cluster_key: String) -> Self {
         self.cluster_keys.push(cluster_key.clone());
         self.default_cluster_key = Some(cluster_key);
@@ -633,6 +691,17 @@ pub struct UpdateTableMetaReq {
     pub deduplicated_label: Option<String>,
 }
 
+/// Update the metas of several tables in one meta-service transaction.
+///
+/// It fails as a whole if any of the tables does not match its `seq`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReq {
+    pub update_table_metas: Vec<UpdateTableMetaReq>,
+    /// The deduplicated labels of all the statements in the transaction, which are written
+    /// along with the table metas.
+    pub deduplicated_labels: Vec<String>,
+}
+
 impl UpsertTableOptionReq {
     pub fn new(
         table_ident: &TableIdent,
@@ -689,6 +758,11 @@ pub struct UpdateTableMetaReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReply {
+    pub share_table_info: Option<Vec<ShareTableInfoMap>>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetTableReq {
     pub inner: TableNameIdent,
@@ -21,6 +21,7 @@ use databend_common_meta_app::schema as mt;
 use databend_common_meta_app::schema::CatalogOption;
 use databend_common_meta_app::schema::HiveCatalogOption;
 use databend_common_meta_app::schema::IcebergCatalogOption;
+use databend_common_meta_app::schema::IcebergRestCatalogOption;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_protos::pb;
 
@@ -91,6 +92,10 @@ impl FromToProto for mt::CatalogMeta {
                                 reason: "CatalogMeta.option.catalog_option.iceberg.StorageParams is None".to_string(),
                             })?,
                         )?),
+                        rest_catalog: v
+                            .rest_catalog
+                            .map(IcebergRestCatalogOption::from_pb)
+                            .transpose()?,
                     })
                 }
             },
@@ -126,6 +131,7 @@ impl FromToProto for mt::CatalogMeta {
                             ver: VER,
                             min_reader_ver: MIN_READER_VER,
                             storage_params: Some(v.storage_params.to_pb()?),
+                            rest_catalog: v.rest_catalog.map(|rest| rest.to_pb()).transpose()?,
                         },
                     )),
                 }),
@@ -136,3 +142,35 @@ impl FromToProto for mt::CatalogMeta {
         Ok(p)
     }
 }
+
+impl FromToProto for IcebergRestCatalogOption {
+    type PB = pb::IcebergRestCatalogOption;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::IcebergRestCatalogOption) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(Self {
+            uri: p.uri,
+            warehouse: p.warehouse,
+            prefix: p.prefix,
+            token: p.token,
+            credential: p.credential,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::IcebergRestCatalogOption, Incompatible> {
+        Ok(pb::IcebergRestCatalogOption {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            uri: self.uri.clone(),
+            warehouse: self.warehouse.clone(),
+            prefix: self.prefix.clone(),
+            token: self.token.clone(),
+            credential: self.credential.clone(),
+        })
+    }
+}
@@ -218,6 +218,11 @@ impl FromToProto for mt::TableMeta {
             } else {
                 Some(p.column_mask_policy)
             },
+            constraints: p
+                .constraints
+                .into_iter()
+                .map(|(name, constraint)| Ok((name, mt::TableConstraint::from_pb(constraint)?)))
+                .collect::<Result<_, Incompatible>>()?,
         };
         Ok(v)
     }
@@ -254,11 +259,69 @@ impl FromToProto for mt::TableMeta {
             statistics: Some(self.statistics.to_pb()?),
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
+            constraints: self
+                .constraints
+                .iter()
+                .map(|(name, constraint)| Ok((name.clone(), constraint.to_pb()?)))
+                .collect::<Result<_, Incompatible>>()?,
         };
         Ok(p)
     }
 }
 
+impl FromToProto for mt::TableConstraint {
+    type PB = pb::TableConstraint;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableConstraint) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let constraint = p.constraint.ok_or_else(|| Incompatible {
+            reason: "Invalid TableConstraint: .constraint can not be None".to_string(),
+        })?;
+
+        let v = match constraint {
+            pb::table_constraint::Constraint::PrimaryKey(pk) => Self::PrimaryKey {
+                columns: pk.columns,
+                enforced: pk.enforced,
+            },
+            pb::table_constraint::Constraint::Unique(unique) => Self::Unique {
+                columns: unique.columns,
+            },
+            pb::table_constraint::Constraint::Check(check) => Self::Check { expr: check.expr },
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableConstraint, Incompatible> {
+        let constraint = match self {
+            mt::TableConstraint::PrimaryKey { columns, enforced } => {
+                pb::table_constraint::Constraint::PrimaryKey(pb::table_constraint::PrimaryKey {
+                    columns: columns.clone(),
+                    enforced: *enforced,
+                })
+            }
+            mt::TableConstraint::Unique { columns } => {
+                pb::table_constraint::Constraint::Unique(pb::table_constraint::Unique {
+                    columns: columns.clone(),
+                })
+            }
+            mt::TableConstraint::Check { expr } => {
+                pb::table_constraint::Constraint::Check(pb::table_constraint::Check {
+                    expr: expr.clone(),
+                })
+            }
+        };
+
+        Ok(pb::TableConstraint {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            constraint: Some(constraint),
+        })
+    }
+}
+
 impl FromToProto for mt::TableStatistics {
     type PB = pb::TableStatistics;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -108,6 +108,15 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (76, "2024-01-18: ADD: ownership.proto and role.proto", ),
     (77, "2024-01-22: Remove: allow_anonymous in S3 Config", ),
     (78, "2024-01-29: Refactor: GrantEntry::UserPrivilegeType and ShareGrantEntry::ShareGrantObjectPrivilege use from_bits_truncate deserialize", ),
+    (79, "2024-02-01: Add: pipe.proto/PipeInfo", ),
+    (80, "2024-02-05: Add: datatype.proto Interval type", ),
+    (81, "2024-02-07: Add: datatype.proto Geometry type", ),
+    (82, "2024-02-09: Add: sequence.proto/SequenceMeta", ),
+    (83, "2024-02-12: Add: procedure.proto/UserDefinedProcedure", ),
+    (84, "2024-02-19: Add: table.proto/TableMeta::constraints and TableConstraint", ),
+    (85, "2024-02-26: Add: file_format.proto/AvroFileFormatParams and OrcFileFormatParams", ),
+    (86, "2024-03-04: Add: file_format.proto/ArrowFileFormatParams", ),
+    (87, "2024-03-11: Add: catalog.proto/IcebergRestCatalogOption", ),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -82,3 +82,12 @@ mod v075_csv_format_params;
 mod v076_role_ownership_info;
 mod v077_s3_remove_allow_anonymous;
 mod v078_grantentry;
+mod v079_pipe_info;
+mod v080_interval_type;
+mod v081_geometry_type;
+mod v082_sequence_meta;
+mod v083_procedure;
+mod v084_table_constraints;
+mod v085_avro_orc_file_format_params;
+mod v086_arrow_file_format_params;
+mod v087_iceberg_rest_catalog_option;
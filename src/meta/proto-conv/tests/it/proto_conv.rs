@@ -211,6 +211,7 @@ fn new_table_meta() -> mt::TableMeta {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        constraints: Default::default(),
     }
 }
 
@@ -319,6 +320,7 @@ fn new_catalog_meta() -> databend_common_meta_app::schema::CatalogMeta {
                     ..Default::default()
                 },
             )),
+            rest_catalog: None,
         }),
         created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
     }
@@ -0,0 +1,57 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::TimeZone;
+use chrono::Utc;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v79_pipe_info() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 5, 112, 105, 112, 101, 49, 18, 21, 67, 79, 80, 89, 32, 73, 78, 84, 79, 32, 116, 49, 32,
+        70, 82, 79, 77, 32, 64, 115, 49, 24, 1, 42, 12, 115, 111, 109, 101, 32, 99, 111, 109, 109,
+        101, 110, 116, 50, 13, 10, 2, 117, 49, 18, 1, 37, 160, 6, 79, 168, 6, 24, 58, 23, 50, 48,
+        49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 66, 23,
+        50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67,
+        74, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85,
+        84, 67, 82, 7, 110, 111, 32, 102, 105, 108, 101, 160, 6, 79, 168, 6, 24,
+    ];
+
+    let want = || databend_common_meta_app::principal::PipeInfo {
+        name: "pipe1".to_string(),
+        copy_stmt: "COPY INTO t1 FROM @s1".to_string(),
+        auto_ingest: true,
+        execution_paused: false,
+        comment: "some comment".to_string(),
+        owner: databend_common_meta_app::principal::UserIdentity::new("u1", "%"),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        last_executed_on: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+        last_error: Some("no file".to_string()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 79, want())
+}
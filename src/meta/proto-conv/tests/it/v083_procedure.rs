@@ -0,0 +1,62 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_meta_app::principal::UserDefinedProcedure;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v83_procedure() -> anyhow::Result<()> {
+    let user_defined_procedure_v83 = vec![
+        10, 7, 97, 100, 100, 95, 111, 110, 101, 18, 1, 97, 26, 17, 154, 2, 8, 58, 0, 160, 6, 83,
+        168, 6, 24, 160, 6, 83, 168, 6, 24, 34, 17, 154, 2, 8, 66, 0, 160, 6, 83, 168, 6, 24, 160,
+        6, 83, 168, 6, 24, 42, 23, 66, 69, 71, 73, 78, 32, 82, 69, 84, 85, 82, 78, 32, 97, 32, 43,
+        32, 49, 59, 32, 69, 78, 68, 50, 7, 97, 100, 100, 32, 111, 110, 101, 58, 23, 50, 48, 50, 52,
+        45, 48, 50, 45, 49, 50, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 83,
+        168, 6, 24,
+    ];
+
+    let want = || UserDefinedProcedure {
+        name: "add_one".to_string(),
+        arg_names: vec!["a".to_string()],
+        arg_types: vec![DataType::Number(NumberDataType::Int32)],
+        return_type: Some(DataType::Number(NumberDataType::Int64)),
+        script: "BEGIN RETURN a + 1; END".to_string(),
+        comment: "add one".to_string(),
+        created_on: Utc.with_ymd_and_hms(2024, 2, 12, 12, 0, 9).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(
+        func_name!(),
+        user_defined_procedure_v83.as_slice(),
+        83,
+        want(),
+    )
+}
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_expression as ce;
+use databend_common_expression::types::NumberDataType;
+use databend_common_meta_app::schema as mt;
+use maplit::btreemap;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v84_table_constraints() -> anyhow::Result<()> {
+    let table_meta_v84 = vec![
+        10, 63, 10, 29, 10, 2, 105, 100, 26, 17, 154, 2, 8, 58, 0, 160, 6, 84, 168, 6, 24, 160, 6,
+        84, 168, 6, 24, 160, 6, 84, 168, 6, 24, 10, 22, 10, 1, 118, 26, 9, 146, 2, 0, 160, 6, 84,
+        168, 6, 24, 32, 1, 160, 6, 84, 168, 6, 24, 24, 2, 160, 6, 84, 168, 6, 24, 50, 4, 70, 85,
+        83, 69, 162, 1, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 57, 32, 49, 50, 58, 48, 48, 58, 48,
+        57, 32, 85, 84, 67, 170, 1, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 57, 32, 49, 50, 58, 48,
+        48, 58, 49, 48, 32, 85, 84, 67, 250, 1, 36, 10, 9, 116, 95, 99, 104, 101, 99, 107, 95, 49,
+        18, 23, 26, 15, 10, 13, 108, 101, 110, 103, 116, 104, 40, 118, 41, 32, 62, 32, 48, 160, 6,
+        84, 168, 6, 24, 250, 1, 24, 10, 6, 116, 95, 112, 107, 101, 121, 18, 14, 10, 6, 10, 2, 105,
+        100, 16, 1, 160, 6, 84, 168, 6, 24, 250, 1, 22, 10, 7, 116, 95, 118, 95, 107, 101, 121, 18,
+        11, 18, 3, 10, 1, 118, 160, 6, 84, 168, 6, 24, 160, 6, 84, 168, 6, 24,
+    ];
+
+    let want = || mt::TableMeta {
+        schema: Arc::new(ce::TableSchema::new_from(
+            vec![
+                ce::TableField::new("id", ce::TableDataType::Number(NumberDataType::Int32)),
+                ce::TableField::new("v", ce::TableDataType::String),
+            ],
+            btreemap! {},
+        )),
+        engine: "FUSE".to_string(),
+        created_on: Utc.with_ymd_and_hms(2024, 2, 19, 12, 0, 9).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2024, 2, 19, 12, 0, 10).unwrap(),
+        constraints: btreemap! {
+            "t_check_1".to_string() => mt::TableConstraint::Check {
+                expr: "length(v) > 0".to_string(),
+            },
+            "t_pkey".to_string() => mt::TableConstraint::PrimaryKey {
+                columns: vec!["id".to_string()],
+                enforced: true,
+            },
+            "t_v_key".to_string() => mt::TableConstraint::Unique {
+                columns: vec!["v".to_string()],
+            },
+        },
+        ..Default::default()
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_meta_v84.as_slice(), 84, want())
+}
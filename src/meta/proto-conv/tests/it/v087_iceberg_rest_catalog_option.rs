@@ -0,0 +1,68 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::schema::CatalogMeta;
+use databend_common_meta_app::schema::CatalogOption;
+use databend_common_meta_app::schema::IcebergCatalogOption;
+use databend_common_meta_app::schema::IcebergRestCatalogOption;
+use databend_common_meta_app::storage::StorageFsConfig;
+use databend_common_meta_app::storage::StorageParams;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v87_iceberg_rest_catalog_option() -> anyhow::Result<()> {
+    let catalog_meta_v87 = vec![
+        18, 105, 26, 103, 10, 22, 18, 20, 10, 12, 47, 116, 109, 112, 47, 105, 99, 101, 98, 101,
+        114, 103, 160, 6, 87, 168, 6, 24, 18, 71, 10, 21, 104, 116, 116, 112, 58, 47, 47, 49, 50,
+        55, 46, 48, 46, 48, 46, 49, 58, 56, 49, 56, 49, 18, 14, 115, 51, 58, 47, 47, 119, 97, 114,
+        101, 104, 111, 117, 115, 101, 26, 2, 119, 115, 34, 5, 116, 111, 107, 101, 110, 42, 13, 99,
+        108, 105, 101, 110, 116, 58, 115, 101, 99, 114, 101, 116, 160, 6, 87, 168, 6, 24, 160, 6,
+        87, 168, 6, 24, 162, 1, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48,
+        58, 48, 57, 32, 85, 84, 67, 160, 6, 87, 168, 6, 24,
+    ];
+
+    let want = || CatalogMeta {
+        catalog_option: CatalogOption::Iceberg(IcebergCatalogOption {
+            storage_params: Box::new(StorageParams::Fs(StorageFsConfig {
+                root: "/tmp/iceberg".to_string(),
+            })),
+            rest_catalog: Some(IcebergRestCatalogOption {
+                uri: "http://127.0.0.1:8181".to_string(),
+                warehouse: Some("s3://warehouse".to_string()),
+                prefix: Some("ws".to_string()),
+                token: Some("token".to_string()),
+                credential: Some("client:secret".to_string()),
+            }),
+        }),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), catalog_meta_v87.as_slice(), 87, want())?;
+
+    Ok(())
+}
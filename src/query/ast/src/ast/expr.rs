@@ -193,7 +193,7 @@ pub enum Expr {
         name: Identifier,
         args: Vec<Expr>,
         params: Vec<Expr>,
-        window: Option<Window>,
+        window: Option<WindowDesc>,
         lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
@@ -253,6 +253,8 @@ pub enum Expr {
         unit: IntervalKind,
         date: Box<Expr>,
     },
+    /// The parameter placeholder `?` of prepared statement
+    Placeholder { span: Span },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -309,6 +311,7 @@ pub enum TypeName {
     },
     Date,
     Timestamp,
+    Interval,
     Binary,
     String,
     Array(Box<TypeName>),
@@ -322,6 +325,7 @@ pub enum TypeName {
         fields_type: Vec<TypeName>,
     },
     Variant,
+    Geometry,
     Nullable(Box<TypeName>),
     NotNull(Box<TypeName>),
 }
@@ -354,6 +358,14 @@ pub enum TrimWhere {
     Trailing,
 }
 
+/// The `OVER` clause of a window function call, with its optional null treatment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowDesc {
+    /// `Some(true)` for `IGNORE NULLS` and `Some(false)` for `RESPECT NULLS`.
+    pub ignore_nulls: Option<bool>,
+    pub window: Window,
+}
+
 #[derive(Debug, Clone, PartialEq, EnumAsInner)]
 pub enum Window {
     WindowReference(WindowRef),
@@ -584,7 +596,8 @@ impl Expr {
             | Expr::Interval { span, .. }
             | Expr::DateAdd { span, .. }
             | Expr::DateSub { span, .. }
-            | Expr::DateTrunc { span, .. } => *span,
+            | Expr::DateTrunc { span, .. }
+            | Expr::Placeholder { span } => *span,
         }
     }
 
@@ -847,6 +860,9 @@ impl Display for TypeName {
             TypeName::Timestamp => {
                 write!(f, "TIMESTAMP")?;
             }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
             TypeName::Binary => {
                 write!(f, "BINARY")?;
             }
@@ -893,6 +909,9 @@ impl Display for TypeName {
             TypeName::Variant => {
                 write!(f, "VARIANT")?;
             }
+            TypeName::Geometry => {
+                write!(f, "GEOMETRY")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -949,6 +968,17 @@ impl Display for WindowDefinition {
     }
 }
 
+impl Display for WindowDesc {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.ignore_nulls {
+            Some(true) => write!(f, "IGNORE NULLS ")?,
+            Some(false) => write!(f, "RESPECT NULLS ")?,
+            None => {}
+        }
+        write!(f, "OVER ({})", self.window)
+    }
+}
+
 impl Display for Window {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let window_fmt = match *self {
@@ -1232,7 +1262,7 @@ impl Display for Expr {
                 write!(f, ")")?;
 
                 if let Some(window) = window {
-                    write!(f, " OVER ({window})")?;
+                    write!(f, " {window}")?;
                 }
             }
             Expr::Case {
@@ -1313,6 +1343,9 @@ impl Display for Expr {
             Expr::DateTrunc { unit, date, .. } => {
                 write!(f, "DATE_TRUNC({unit}, {date})")?;
             }
+            Expr::Placeholder { .. } => {
+                write!(f, "?")?;
+            }
         }
 
         Ok(())
@@ -448,7 +448,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         name: &'ast Identifier,
         args: &'ast [Expr],
         params: &'ast [Expr],
-        _over: &'ast Option<Window>,
+        _over: &'ast Option<WindowDesc>,
         _lambda: &'ast Option<Lambda>,
     ) {
         let mut children = Vec::with_capacity(args.len());
@@ -655,6 +655,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_placeholder(&mut self, _span: Span) {
+        let name = "Placeholder".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_query(&mut self, query: &'ast Query) {
         let mut children = Vec::new();
         if let Some(with) = &query.with {
@@ -1033,6 +1040,22 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         let mut children = Vec::new();
         self.visit_table_ref(&insert.catalog, &insert.database, &insert.table);
         children.push(self.children.pop().unwrap());
+        if !insert.partition.is_empty() {
+            let partition_children = insert
+                .partition
+                .iter()
+                .map(|partition| {
+                    let partition_format_ctx = AstFormatContext::new(partition.to_string());
+                    FormatTreeNode::new(partition_format_ctx)
+                })
+                .collect::<Vec<_>>();
+            let partition_name = "Partition".to_string();
+            let partition_format_ctx =
+                AstFormatContext::with_children(partition_name, partition_children.len());
+            let partition_node =
+                FormatTreeNode::with_children(partition_format_ctx, partition_children);
+            children.push(partition_node);
+        }
         if !insert.columns.is_empty() {
             let mut columns_children = Vec::with_capacity(insert.columns.len());
             for column in insert.columns.iter() {
@@ -1383,12 +1406,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
 
     fn visit_create_table_source(&mut self, source: &'ast CreateTableSource) {
         match source {
-            CreateTableSource::Columns(columns) => {
-                let mut children = Vec::with_capacity(columns.len());
+            CreateTableSource::Columns(columns, constraints) => {
+                let mut children = Vec::with_capacity(columns.len() + constraints.len());
                 for column in columns.iter() {
                     self.visit_column_definition(column);
                     children.push(self.children.pop().unwrap());
                 }
+                for constraint in constraints.iter() {
+                    let format_ctx = AstFormatContext::new(format!("TableConstraint {constraint}"));
+                    children.push(FormatTreeNode::new(format_ctx));
+                }
                 let name = "ColumnsDefinition".to_string();
                 let format_ctx = AstFormatContext::with_children(name, children.len());
                 let node = FormatTreeNode::with_children(format_ctx, children);
@@ -2927,6 +2954,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => {
                 let mut name = String::new();
                 name.push_str("TableIdentifier ");
@@ -2950,6 +2978,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     name.push_str(&unpivot.to_string());
                 }
 
+                if let Some(sample) = sample {
+                    name.push(' ');
+                    name.push_str(&sample.to_string());
+                }
+
                 let mut children = Vec::new();
                 if let Some(travel_point) = travel_point {
                     self.visit_time_travel_point(travel_point);
@@ -3100,6 +3133,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.visit_table_reference(&join.right);
         children.push(self.children.pop().unwrap());
 
+        if let Some(match_condition) = &join.match_condition {
+            self.visit_expr(match_condition);
+            let child = self.children.pop().unwrap();
+            let match_condition_name = "MatchCondition".to_string();
+            let match_condition_format_ctx =
+                AstFormatContext::with_children(match_condition_name, 1);
+            let match_condition_node =
+                FormatTreeNode::with_children(match_condition_format_ctx, vec![child]);
+            children.push(match_condition_node);
+        }
+
         match &join.condition {
             JoinCondition::On(expr) => {
                 self.visit_expr(expr);
@@ -120,11 +120,16 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
 
 fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
     match source {
-        CreateTableSource::Columns(columns) => RcDoc::space().append(parenthesized(
+        CreateTableSource::Columns(columns, constraints) => RcDoc::space().append(parenthesized(
             interweave_comma(
                 columns
                     .into_iter()
-                    .map(|column| RcDoc::text(column.to_string())),
+                    .map(|column| RcDoc::text(column.to_string()))
+                    .chain(
+                        constraints
+                            .into_iter()
+                            .map(|constraint| RcDoc::text(constraint.to_string())),
+                    ),
             )
             .group(),
         )),
@@ -278,9 +278,7 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(inline_comma(args.into_iter().map(pretty_expr)))
             .append(RcDoc::text(")"))
             .append(if let Some(window) = window {
-                RcDoc::text(" OVER (")
-                    .append(RcDoc::text(window.to_string()))
-                    .append(")")
+                RcDoc::space().append(RcDoc::text(window.to_string()))
             } else {
                 RcDoc::nil()
             }),
@@ -399,5 +397,6 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(RcDoc::space())
             .append(pretty_expr(*date))
             .append(RcDoc::text(")")),
+        Expr::Placeholder { .. } => RcDoc::text("?"),
     }
 }
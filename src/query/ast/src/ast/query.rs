@@ -259,6 +259,28 @@ pub struct Unpivot {
     pub names: Vec<Identifier>,
 }
 
+/// `TABLESAMPLE [BLOCK | ROW] (<probability> [PERCENT] | <n> ROWS) [REPEATABLE (<seed>)]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableSample {
+    pub level: SampleLevel,
+    pub config: SampleConfig,
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleLevel {
+    Row,
+    Block,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SampleConfig {
+    // Percentage of rows (or blocks) to be sampled, in `[0, 100]`
+    Probability(f64),
+    // Fixed number of rows to be sampled
+    RowsNum(u64),
+}
+
 /// A table name or a parenthesized subquery with an optional alias
 #[derive(Debug, Clone, PartialEq)]
 pub enum TableReference {
@@ -272,6 +294,7 @@ pub enum TableReference {
         travel_point: Option<TimeTravelPoint>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
+        sample: Option<TableSample>,
     },
     // `TABLE(expr)[ AS alias ]`
     TableFunction {
@@ -338,6 +361,8 @@ pub struct Join {
     pub condition: JoinCondition,
     pub left: Box<TableReference>,
     pub right: Box<TableReference>,
+    // The `MATCH_CONDITION` of ASOF join, e.g. `MATCH_CONDITION (t1.ts >= t2.ts)`
+    pub match_condition: Option<Box<Expr>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -353,6 +378,10 @@ pub enum JoinOperator {
     RightAnti,
     // CrossJoin can only work with `JoinCondition::None`
     CrossJoin,
+    // ASOF joins must work with a `MATCH_CONDITION`, and can only
+    // work with equi conditions in `JoinCondition::On`
+    Asof,
+    LeftAsof,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -444,6 +473,24 @@ impl Display for Unpivot {
     }
 }
 
+impl Display for TableSample {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TABLESAMPLE")?;
+        match self.level {
+            SampleLevel::Row => write!(f, " ROW")?,
+            SampleLevel::Block => write!(f, " BLOCK")?,
+        }
+        match self.config {
+            SampleConfig::Probability(probability) => write!(f, " ({probability})")?,
+            SampleConfig::RowsNum(rows) => write!(f, " ({rows} ROWS)")?,
+        }
+        if let Some(seed) = self.seed {
+            write!(f, " REPEATABLE ({seed})")?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for TableReference {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -456,6 +503,7 @@ impl Display for TableReference {
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => {
                 write_dot_separated_list(
                     f,
@@ -473,6 +521,9 @@ impl Display for TableReference {
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
+                if let Some(sample) = sample {
+                    write!(f, " {sample}")?;
+                }
                 if let Some(pivot) = pivot {
                     write!(f, " {pivot}")?;
                 }
@@ -555,8 +606,17 @@ impl Display for TableReference {
                     JoinOperator::CrossJoin => {
                         write!(f, " CROSS JOIN")?;
                     }
+                    JoinOperator::Asof => {
+                        write!(f, " ASOF JOIN")?;
+                    }
+                    JoinOperator::LeftAsof => {
+                        write!(f, " ASOF LEFT JOIN")?;
+                    }
                 }
                 write!(f, " {}", join.right)?;
+                if let Some(match_condition) = &join.match_condition {
+                    write!(f, " MATCH_CONDITION ({match_condition})")?;
+                }
                 match &join.condition {
                     JoinCondition::On(expr) => {
                         write!(f, " ON {expr}")?;
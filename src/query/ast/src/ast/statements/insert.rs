@@ -21,6 +21,7 @@ use crate::ast::write_comma_separated_map;
 use crate::ast::write_dot_separated_list;
 use crate::ast::Hint;
 use crate::ast::Identifier;
+use crate::ast::Literal;
 use crate::ast::Query;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -29,6 +30,7 @@ pub struct InsertStmt {
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
     pub table: Identifier,
+    pub partition: Vec<InsertPartition>,
     pub columns: Vec<Identifier>,
     pub source: InsertSource,
     pub overwrite: bool,
@@ -52,6 +54,11 @@ impl Display for InsertStmt {
                 .chain(&self.database)
                 .chain(Some(&self.table)),
         )?;
+        if !self.partition.is_empty() {
+            write!(f, " PARTITION (")?;
+            write_comma_separated_list(f, &self.partition)?;
+            write!(f, ")")?;
+        }
         if !self.columns.is_empty() {
             write!(f, " (")?;
             write_comma_separated_list(f, &self.columns)?;
@@ -61,6 +68,26 @@ impl Display for InsertStmt {
     }
 }
 
+/// A partition column in the `PARTITION` clause.
+///
+/// The value is given for a static partition, like `p_date = '20220201'`,
+/// or comes from the source for a dynamic partition, like `p_hour`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsertPartition {
+    pub column: Identifier,
+    pub value: Option<Literal>,
+}
+
+impl Display for InsertPartition {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.column)?;
+        if let Some(value) = &self.value {
+            write!(f, " = {value}")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum InsertSource {
     Streaming {
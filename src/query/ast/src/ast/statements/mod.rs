@@ -31,7 +31,10 @@ mod network_policy;
 mod password_policy;
 mod pipe;
 mod presign;
+mod procedure;
 mod replace;
+mod script;
+mod sequence;
 mod share;
 mod show;
 mod stage;
@@ -65,7 +68,10 @@ pub use network_policy::*;
 pub use password_policy::*;
 pub use pipe::*;
 pub use presign::*;
+pub use procedure::*;
 pub use replace::*;
+pub use script::*;
+pub use sequence::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
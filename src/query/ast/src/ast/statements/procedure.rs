@@ -0,0 +1,111 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_meta_app::schema::CreateOption;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::Identifier;
+use crate::ast::TypeName;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProcedureReturnType {
+    Scalar(TypeName),
+    /// The procedure returns a result set by `RETURN TABLE(...)`.
+    Table,
+}
+
+impl Display for ProcedureReturnType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProcedureReturnType::Scalar(ty) => write!(f, "{ty}"),
+            ProcedureReturnType::Table => write!(f, "TABLE"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcedureArg {
+    pub name: Identifier,
+    pub data_type: TypeName,
+}
+
+impl Display for ProcedureArg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateProcedureStmt {
+    pub create_option: CreateOption,
+    pub name: Identifier,
+    pub args: Vec<ProcedureArg>,
+    pub return_type: ProcedureReturnType,
+    pub comment: Option<String>,
+    /// The body of the procedure, which is a script in `BEGIN ... END` block.
+    pub script: String,
+}
+
+impl Display for CreateProcedureStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE ")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "PROCEDURE ")?;
+        if let CreateOption::CreateIfNotExists(if_not_exists) = self.create_option {
+            if if_not_exists {
+                write!(f, "IF NOT EXISTS ")?;
+            }
+        }
+        write!(f, "{}(", self.name)?;
+        write_comma_separated_list(f, &self.args)?;
+        write!(f, ") RETURNS {} LANGUAGE SQL", self.return_type)?;
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+        write!(f, " AS $${}$$", self.script)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropProcedureStmt {
+    pub if_exists: bool,
+    pub name: Identifier,
+}
+
+impl Display for DropProcedureStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DROP PROCEDURE ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecuteImmediateStmt {
+    /// The script in `BEGIN ... END` block.
+    pub script: String,
+}
+
+impl Display for ExecuteImmediateStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EXECUTE IMMEDIATE $${}$$", self.script)
+    }
+}
@@ -53,9 +53,8 @@ impl Display for ReplaceStmt {
             write_comma_separated_list(f, &self.columns)?;
             write!(f, ") ")?;
         }
-        write!(f, "ON CONFLICT")?;
         if !self.on_conflict_columns.is_empty() {
-            write!(f, "(")?;
+            write!(f, "ON CONFLICT(")?;
             write_comma_separated_list(f, &self.on_conflict_columns)?;
             write!(f, ") ")?;
         }
@@ -0,0 +1,252 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_exception::Span;
+
+use crate::ast::Expr;
+use crate::ast::Identifier;
+use crate::ast::Query;
+use crate::ast::Statement;
+use crate::ast::TypeName;
+
+/// A `BEGIN ... [EXCEPTION WHEN OTHER THEN ...] END` block of a SQL script.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptBlock {
+    pub span: Span,
+    pub body: Vec<ScriptStatement>,
+    /// The statements to run if any statement of the body fails.
+    pub exception_handler: Option<Vec<ScriptStatement>>,
+}
+
+impl Display for ScriptBlock {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BEGIN")?;
+        write_statements(f, &self.body)?;
+        if let Some(handler) = &self.exception_handler {
+            write!(f, " EXCEPTION WHEN OTHER THEN")?;
+            write_statements(f, handler)?;
+        }
+        write!(f, " END")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReturnItem {
+    Expr(Expr),
+    /// `RETURN TABLE(<resultset>)`
+    ResultSet(Identifier),
+    /// `RETURN TABLE(<query>)`
+    Query(Box<Query>),
+}
+
+impl Display for ReturnItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReturnItem::Expr(expr) => write!(f, "{expr}"),
+            ReturnItem::ResultSet(name) => write!(f, "TABLE({name})"),
+            ReturnItem::Query(query) => write!(f, "TABLE({query})"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptStatement {
+    /// `LET <name> [<type>] := <expr>`
+    LetVar {
+        span: Span,
+        name: Identifier,
+        data_type: Option<TypeName>,
+        value: Box<Expr>,
+    },
+    /// `LET <name> RESULTSET := <query>`
+    LetResultSet {
+        span: Span,
+        name: Identifier,
+        query: Box<Query>,
+    },
+    /// `<name> := <expr>`
+    Assign {
+        span: Span,
+        name: Identifier,
+        value: Box<Expr>,
+    },
+    Return {
+        span: Span,
+        value: Option<ReturnItem>,
+    },
+    If {
+        span: Span,
+        conditions: Vec<Expr>,
+        results: Vec<Vec<ScriptStatement>>,
+        else_result: Option<Vec<ScriptStatement>>,
+    },
+    /// `FOR <variable> IN [REVERSE] <lower> TO <upper> DO ... END FOR`
+    ForRange {
+        span: Span,
+        variable: Identifier,
+        is_reverse: bool,
+        lower_bound: Box<Expr>,
+        upper_bound: Box<Expr>,
+        body: Vec<ScriptStatement>,
+    },
+    /// `FOR <variable> IN <resultset> DO ... END FOR`
+    ForIn {
+        span: Span,
+        variable: Identifier,
+        resultset: Identifier,
+        body: Vec<ScriptStatement>,
+    },
+    While {
+        span: Span,
+        condition: Box<Expr>,
+        body: Vec<ScriptStatement>,
+    },
+    Loop {
+        span: Span,
+        body: Vec<ScriptStatement>,
+    },
+    Break {
+        span: Span,
+    },
+    Continue {
+        span: Span,
+    },
+    Block(ScriptBlock),
+    RunStatement {
+        span: Span,
+        stmt: Box<Statement>,
+    },
+}
+
+impl ScriptStatement {
+    pub fn span(&self) -> Span {
+        match self {
+            ScriptStatement::LetVar { span, .. }
+            | ScriptStatement::LetResultSet { span, .. }
+            | ScriptStatement::Assign { span, .. }
+            | ScriptStatement::Return { span, .. }
+            | ScriptStatement::If { span, .. }
+            | ScriptStatement::ForRange { span, .. }
+            | ScriptStatement::ForIn { span, .. }
+            | ScriptStatement::While { span, .. }
+            | ScriptStatement::Loop { span, .. }
+            | ScriptStatement::Break { span }
+            | ScriptStatement::Continue { span }
+            | ScriptStatement::RunStatement { span, .. } => *span,
+            ScriptStatement::Block(block) => block.span,
+        }
+    }
+}
+
+fn write_statements(f: &mut Formatter<'_>, stmts: &[ScriptStatement]) -> std::fmt::Result {
+    for stmt in stmts {
+        write!(f, " {stmt};")?;
+    }
+    Ok(())
+}
+
+impl Display for ScriptStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScriptStatement::LetVar {
+                name,
+                data_type,
+                value,
+                ..
+            } => {
+                write!(f, "LET {name}")?;
+                if let Some(data_type) = data_type {
+                    write!(f, " {data_type}")?;
+                }
+                write!(f, " := {value}")
+            }
+            ScriptStatement::LetResultSet { name, query, .. } => {
+                write!(f, "LET {name} RESULTSET := {query}")
+            }
+            ScriptStatement::Assign { name, value, .. } => write!(f, "{name} := {value}"),
+            ScriptStatement::Return { value, .. } => {
+                write!(f, "RETURN")?;
+                if let Some(value) = value {
+                    write!(f, " {value}")?;
+                }
+                Ok(())
+            }
+            ScriptStatement::If {
+                conditions,
+                results,
+                else_result,
+                ..
+            } => {
+                for (i, (condition, result)) in conditions.iter().zip(results).enumerate() {
+                    if i == 0 {
+                        write!(f, "IF {condition} THEN")?;
+                    } else {
+                        write!(f, " ELSEIF {condition} THEN")?;
+                    }
+                    write_statements(f, result)?;
+                }
+                if let Some(else_result) = else_result {
+                    write!(f, " ELSE")?;
+                    write_statements(f, else_result)?;
+                }
+                write!(f, " END IF")
+            }
+            ScriptStatement::ForRange {
+                variable,
+                is_reverse,
+                lower_bound,
+                upper_bound,
+                body,
+                ..
+            } => {
+                write!(f, "FOR {variable} IN ")?;
+                if *is_reverse {
+                    write!(f, "REVERSE ")?;
+                }
+                write!(f, "{lower_bound} TO {upper_bound} DO")?;
+                write_statements(f, body)?;
+                write!(f, " END FOR")
+            }
+            ScriptStatement::ForIn {
+                variable,
+                resultset,
+                body,
+                ..
+            } => {
+                write!(f, "FOR {variable} IN {resultset} DO")?;
+                write_statements(f, body)?;
+                write!(f, " END FOR")
+            }
+            ScriptStatement::While {
+                condition, body, ..
+            } => {
+                write!(f, "WHILE {condition} DO")?;
+                write_statements(f, body)?;
+                write!(f, " END WHILE")
+            }
+            ScriptStatement::Loop { body, .. } => {
+                write!(f, "LOOP")?;
+                write_statements(f, body)?;
+                write!(f, " END LOOP")
+            }
+            ScriptStatement::Break { .. } => write!(f, "BREAK"),
+            ScriptStatement::Continue { .. } => write!(f, "CONTINUE"),
+            ScriptStatement::Block(block) => write!(f, "{block}"),
+            ScriptStatement::RunStatement { stmt, .. } => write!(f, "{stmt}"),
+        }
+    }
+}
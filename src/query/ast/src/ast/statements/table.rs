@@ -138,6 +138,7 @@ pub struct CreateTableStmt {
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
     pub transient: bool,
+    pub temporary: bool,
 }
 
 impl Display for CreateTableStmt {
@@ -146,6 +147,9 @@ impl Display for CreateTableStmt {
         if let CreateOption::CreateOrReplace = self.create_option {
             write!(f, "OR REPLACE ")?;
         }
+        if self.temporary {
+            write!(f, "TEMPORARY ")?;
+        }
         if self.transient {
             write!(f, "TRANSIENT ")?;
         }
@@ -223,7 +227,7 @@ impl Display for AttachTableStmt {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>),
+    Columns(Vec<ColumnDefinition>, Vec<TableConstraint>),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
@@ -234,9 +238,13 @@ pub enum CreateTableSource {
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, constraints) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
+                if !constraints.is_empty() {
+                    write!(f, ", ")?;
+                    write_comma_separated_list(f, constraints)?;
+                }
                 write!(f, ")")
             }
             CreateTableSource::Like {
@@ -720,6 +728,8 @@ pub enum ColumnExpr {
     Default(Box<Expr>),
     Virtual(Box<Expr>),
     Stored(Box<Expr>),
+    /// The values are generated from an implicit sequence.
+    AutoIncrement,
 }
 
 impl Display for ColumnExpr {
@@ -734,6 +744,9 @@ impl Display for ColumnExpr {
             ColumnExpr::Stored(expr) => {
                 write!(f, " AS ({expr}) STORED")?;
             }
+            ColumnExpr::AutoIncrement => {
+                write!(f, " AUTOINCREMENT")?;
+            }
         }
         Ok(())
     }
@@ -766,6 +779,63 @@ impl Display for ColumnDefinition {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableConstraint {
+    PrimaryKey {
+        name: Option<Identifier>,
+        columns: Vec<Identifier>,
+        /// Reject rows whose key already exists in the table.
+        enforced: bool,
+    },
+    Unique {
+        name: Option<Identifier>,
+        columns: Vec<Identifier>,
+    },
+    Check {
+        name: Option<Identifier>,
+        expr: Box<Expr>,
+    },
+}
+
+impl TableConstraint {
+    pub fn name(&self) -> Option<&Identifier> {
+        match self {
+            TableConstraint::PrimaryKey { name, .. }
+            | TableConstraint::Unique { name, .. }
+            | TableConstraint::Check { name, .. } => name.as_ref(),
+        }
+    }
+}
+
+impl Display for TableConstraint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            write!(f, "CONSTRAINT {name} ")?;
+        }
+        match self {
+            TableConstraint::PrimaryKey {
+                columns, enforced, ..
+            } => {
+                write!(f, "PRIMARY KEY (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")?;
+                if *enforced {
+                    write!(f, " ENFORCED")?;
+                }
+            }
+            TableConstraint::Unique { columns, .. } => {
+                write!(f, "UNIQUE (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")?;
+            }
+            TableConstraint::Check { expr, .. } => {
+                write!(f, "CHECK ({expr})")?;
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ModifyColumnAction {
     // (column name id, masking policy name)
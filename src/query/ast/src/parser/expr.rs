@@ -140,6 +140,14 @@ pub fn subexpr(min_precedence: u32) -> impl FnMut(Input) -> IResult<Expr> {
                         },
                     };
                 }
+
+                // and replace `?` json operator to the parameter placeholder.
+                if let ExprElement::JsonOp {
+                    op: JsonOperator::Question,
+                } = &expr_elements[curr as usize].elem
+                {
+                    expr_elements[curr as usize].elem = ExprElement::Placeholder;
+                }
             }
         }
 
@@ -266,7 +274,7 @@ pub enum ExprElement {
         name: Identifier,
         args: Vec<Expr>,
         params: Vec<Expr>,
-        window: Option<Window>,
+        window: Option<WindowDesc>,
         lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
@@ -331,6 +339,8 @@ pub enum ExprElement {
         unit: IntervalKind,
         date: Expr,
     },
+    /// The parameter placeholder `?` of prepared statement
+    Placeholder,
 }
 
 struct ExprParser;
@@ -597,6 +607,9 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 unit,
                 date: Box::new(date),
             },
+            ExprElement::Placeholder => Expr::Placeholder {
+                span: transform_span(elem.span.0),
+            },
             _ => unreachable!(),
         };
         Ok(expr)
@@ -943,19 +956,33 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             }),
         },
     );
-    let function_call_with_window = map(
+    let null_treatment = alt((
+        value(true, rule! { IGNORE ~ NULLS }),
+        value(false, rule! { RESPECT ~ NULLS }),
+    ));
+    let function_call_with_window = map_res(
         rule! {
             #function_name
-            ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
-            ~ (OVER ~ #window_spec_ident)
+            ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ #null_treatment? ~ ")"
+            ~ #null_treatment? ~ (OVER ~ #window_spec_ident)
         },
-        |(name, _, opt_distinct, opt_args, _, window)| ExprElement::FunctionCall {
-            distinct: opt_distinct.is_some(),
-            name,
-            args: opt_args.unwrap_or_default(),
-            params: vec![],
-            window: Some(window.1),
-            lambda: None,
+        |(name, _, opt_distinct, opt_args, inner_nulls, _, outer_nulls, window)| {
+            if inner_nulls.is_some() && outer_nulls.is_some() {
+                return Err(nom::Err::Failure(ErrorKind::Other(
+                    "null treatment can only be specified once",
+                )));
+            }
+            Ok(ExprElement::FunctionCall {
+                distinct: opt_distinct.is_some(),
+                name,
+                args: opt_args.unwrap_or_default(),
+                params: vec![],
+                window: Some(WindowDesc {
+                    ignore_nulls: inner_nulls.or(outer_nulls),
+                    window: window.1,
+                }),
+                lambda: None,
+            })
         },
     );
     let function_call_with_params = map(
@@ -1166,6 +1193,19 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
     );
 
+    let interval_expr = map(
+        rule! {
+            INTERVAL ~ #consumed(literal_string)
+        },
+        |(_, (span, interval))| ExprElement::Cast {
+            expr: Box::new(Expr::Literal {
+                span: transform_span(span.0),
+                lit: Literal::String(interval),
+            }),
+            target_type: TypeName::Interval,
+        },
+    );
+
     let is_distinct_from = map(
         rule! {
             IS ~ NOT? ~ DISTINCT ~ FROM
@@ -1203,6 +1243,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #date_expr: "`DATE <str_literal>`"
             | #timestamp_expr: "`TIMESTAMP <str_literal>`"
             | #interval: "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)`"
+            | #interval_expr: "`INTERVAL <str_literal>`"
             | #pg_cast : "`::<type_name>`"
             | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK) FROM ...)`"
             | #date_part : "`DATE_PART((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK), ...)`"
@@ -1217,7 +1258,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #list_comprehensions: "[expr for x in ... [if ...]]"
             | #count_all_with_window : "`COUNT(*) OVER ...`"
             | #function_call_with_lambda : "`function(..., x -> ...)`"
-            | #function_call_with_window : "`function(...) OVER ([ PARTITION BY <expr>, ... ] [ ORDER BY <expr>, ... ] [ <window frame> ])`"
+            | #function_call_with_window : "`function(...) [IGNORE | RESPECT NULLS] OVER ([ PARTITION BY <expr>, ... ] [ ORDER BY <expr>, ... ] [ <window frame> ])`"
             | #function_call_with_params : "`function(...)(...)`"
             | #function_call : "`function(...)`"
             | #case : "`CASE ... END`"
@@ -1451,6 +1492,17 @@ pub fn literal_string(i: Input) -> IResult<String> {
     )(i)
 }
 
+/// Parse the body of a script, either written as `$$ ... $$` or as a quoted string.
+pub fn code_string(i: Input) -> IResult<String> {
+    let dollar_string = map(rule! { LiteralCodeString }, |token| {
+        token.text()[2..token.text().len() - 2].to_string()
+    });
+    rule!(
+        #dollar_string
+        | #literal_string
+    )(i)
+}
+
 pub fn literal_string_eq_ignore_case(s: &str) -> impl FnMut(Input) -> IResult<()> + '_ {
     move |i| {
         map_res(rule! { QuotedString }, |token| {
@@ -1572,6 +1624,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         rule! { ( DATETIME | TIMESTAMP ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
         |(_, _)| TypeName::Timestamp,
     );
+    let ty_interval = value(TypeName::Interval, rule! { INTERVAL });
     let ty_binary = value(
         TypeName::Binary,
         rule! { ( BINARY | VARBINARY | LONGBLOB | MEDIUMBLOB |  TINYBLOB| BLOB ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
@@ -1581,6 +1634,12 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
+    let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
+    let ty_geography = map_res(rule! { GEOGRAPHY }, |_| {
+        Err(nom::Err::Failure(ErrorKind::Other(
+            "GEOGRAPHY type is not supported yet, use GEOMETRY instead",
+        )))
+    });
     map_res(
         alt((
             rule! {
@@ -1606,9 +1665,12 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             rule! {
             ( #ty_date
             | #ty_datetime
+            | #ty_interval
             | #ty_binary
             | #ty_string
             | #ty_variant
+            | #ty_geometry
+            | #ty_geography
             | #ty_nullable
             ) ~ #nullable? : "type name" },
         )),
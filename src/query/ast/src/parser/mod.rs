@@ -19,6 +19,7 @@ pub mod expr;
 mod parser;
 pub mod query;
 pub mod quote;
+pub mod script;
 mod share;
 mod stage;
 pub mod statement;
@@ -29,6 +30,7 @@ pub mod unescape;
 pub use parser::parse_comma_separated_exprs;
 pub use parser::parse_comma_separated_idents;
 pub use parser::parse_expr;
+pub use parser::parse_script;
 pub use parser::parse_sql;
 pub use parser::parser_values_with_placeholder;
 pub use parser::run_parser;
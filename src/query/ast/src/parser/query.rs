@@ -15,6 +15,7 @@
 use nom::branch::alt;
 use nom::combinator::consumed;
 use nom::combinator::map;
+use nom::combinator::not;
 use nom::combinator::value;
 use nom::error::context;
 use pratt::Affix;
@@ -557,6 +558,51 @@ pub fn table_alias_without_as(i: Input) -> IResult<TableAlias> {
     )(i)
 }
 
+// `SAMPLE` is not a reserved keyword, so `FROM t sample` is still a table alias.
+// It starts a sample clause only if followed by `[ROW | BLOCK] (<number>`.
+fn table_alias_before_sample(i: Input) -> IResult<TableAlias> {
+    let sample_start =
+        rule! { SAMPLE ~ ( ROW | BLOCK )? ~ "(" ~ ( LiteralInteger | LiteralFloat ) };
+    map(rule! { #not(sample_start) ~ #table_alias }, |(_, alias)| {
+        alias
+    })(i)
+}
+
+pub fn table_sample(i: Input) -> IResult<TableSample> {
+    let level = alt((
+        value(SampleLevel::Row, rule! { ROW }),
+        value(SampleLevel::Block, rule! { BLOCK }),
+    ));
+    let rows_num = map(rule! { #literal_u64 ~ ROWS }, |(rows, _)| {
+        SampleConfig::RowsNum(rows)
+    });
+    let probability = map_res(
+        rule! { ( LiteralInteger | LiteralFloat ) ~ PERCENT? },
+        |(token, _)| {
+            token
+                .text()
+                .parse::<f64>()
+                .map(SampleConfig::Probability)
+                .map_err(|_| nom::Err::Failure(ErrorKind::Other("invalid sample probability")))
+        },
+    );
+    let seed = map(
+        rule! { REPEATABLE ~ ^"(" ~ ^#literal_u64 ~ ^")" },
+        |(_, _, seed, _)| seed,
+    );
+
+    map(
+        rule! {
+            ( TABLESAMPLE | SAMPLE ) ~ #level? ~ "(" ~ ^( #rows_num | #probability ) ~ ^")" ~ #seed?
+        },
+        |(_, level, _, config, _, seed)| TableSample {
+            level: level.unwrap_or(SampleLevel::Row),
+            config,
+            seed,
+        },
+    )(i)
+}
+
 pub fn join_operator(i: Input) -> IResult<JoinOperator> {
     alt((
         value(JoinOperator::Inner, rule! { INNER }),
@@ -568,6 +614,8 @@ pub fn join_operator(i: Input) -> IResult<JoinOperator> {
         value(JoinOperator::RightOuter, rule! { RIGHT ~ OUTER? }),
         value(JoinOperator::FullOuter, rule! { FULL ~ OUTER? }),
         value(JoinOperator::CrossJoin, rule! { CROSS }),
+        value(JoinOperator::LeftAsof, rule! { ASOF ~ LEFT }),
+        value(JoinOperator::Asof, rule! { ASOF }),
     ))(i)
 }
 
@@ -629,6 +677,7 @@ pub enum TableReferenceElement {
         travel_point: Option<TimeTravelPoint>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
+        sample: Option<TableSample>,
     },
     // `TABLE(expr)[ AS alias ]`
     TableFunction {
@@ -652,6 +701,8 @@ pub enum TableReferenceElement {
     },
     // ON expr | USING (ident, ...)
     JoinCondition(JoinCondition),
+    // MATCH_CONDITION (expr)
+    MatchCondition(Expr),
     Group(TableReference),
     Stage {
         location: FileLocation,
@@ -685,9 +736,9 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let aliased_table = map(
         rule! {
-            #dot_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)? ~ #table_alias? ~ #pivot? ~ #unpivot?
+            #dot_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)? ~ #table_alias_before_sample? ~ #table_sample? ~ #pivot? ~ #unpivot?
         },
-        |((catalog, database, table), travel_point_opt, alias, pivot, unpivot)| {
+        |((catalog, database, table), travel_point_opt, alias, sample, pivot, unpivot)| {
             TableReferenceElement::Table {
                 catalog,
                 database,
@@ -696,6 +747,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
                 travel_point: travel_point_opt.map(|p| p.1),
                 pivot: pivot.map(Box::new),
                 unpivot: unpivot.map(Box::new),
+                sample,
             }
         },
     );
@@ -720,6 +772,12 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         },
         |(_, _, idents, _)| TableReferenceElement::JoinCondition(JoinCondition::Using(idents)),
     );
+    let match_condition = map(
+        rule! {
+            MATCH_CONDITION ~ ^"(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, expr, _)| TableReferenceElement::MatchCondition(expr),
+    );
     let table_function = map(
         rule! {
             LATERAL? ~ #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
@@ -773,6 +831,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         | #join
         | #join_condition_on
         | #join_condition_using
+        | #match_condition
     })(i)?;
     Ok((rest, WithSpan { span, elem }))
 }
@@ -790,6 +849,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
         let affix = match &input.elem {
             TableReferenceElement::Join { .. } => Affix::Infix(Precedence(10), Associativity::Left),
             TableReferenceElement::JoinCondition(..) => Affix::Postfix(Precedence(5)),
+            TableReferenceElement::MatchCondition(..) => Affix::Postfix(Precedence(5)),
             _ => Affix::Nilfix,
         };
         Ok(affix)
@@ -806,6 +866,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => TableReference::Table {
                 span: transform_span(input.span.0),
                 catalog,
@@ -815,6 +876,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             },
             TableReferenceElement::TableFunction {
                 lateral,
@@ -893,6 +955,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                         condition,
                         left: Box::new(lhs),
                         right: Box::new(rhs),
+                        match_condition: None,
                     },
                 }
             }
@@ -929,6 +992,24 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 },
                 _ => Err("join condition must apply to a join"),
             },
+            TableReferenceElement::MatchCondition(expr) => match &mut lhs {
+                TableReference::Join {
+                    join:
+                        Join {
+                            op: JoinOperator::Asof | JoinOperator::LeftAsof,
+                            match_condition,
+                            ..
+                        },
+                    ..
+                } => match match_condition {
+                    None => {
+                        *match_condition = Some(Box::new(expr));
+                        Ok(lhs)
+                    }
+                    Some(_) => Err("match condition already set"),
+                },
+                _ => Err("match condition must apply to an ASOF join"),
+            },
             _ => unreachable!(),
         }
     }
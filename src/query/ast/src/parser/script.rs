@@ -0,0 +1,228 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use nom::combinator::consumed;
+use nom::combinator::map;
+use nom::multi::many0;
+use nom::Slice;
+
+use crate::ast::*;
+use crate::input::Input;
+use crate::parser::expr::*;
+use crate::parser::query::*;
+use crate::parser::statement::statement_body;
+use crate::parser::token::*;
+use crate::rule;
+use crate::util::*;
+
+/// Parse a script in `BEGIN ... END` block, the block may be followed by a `;`.
+pub fn script(i: Input) -> IResult<ScriptBlock> {
+    map(
+        rule! {
+            #script_block ~ ";"? ~ &EOI
+        },
+        |(block, _, _)| block,
+    )(i)
+}
+
+pub fn script_block(i: Input) -> IResult<ScriptBlock> {
+    map(
+        consumed(rule! {
+            BEGIN
+            ~ #script_stmts
+            ~ ( EXCEPTION ~ ^WHEN ~ ^OTHER ~ ^THEN ~ #script_stmts )?
+            ~ ^END
+        }),
+        |(span, (_, body, opt_exception, _))| ScriptBlock {
+            span: transform_span(span.0),
+            body,
+            exception_handler: opt_exception.map(|(_, _, _, _, handler)| handler),
+        },
+    )(i)
+}
+
+fn script_stmts(i: Input) -> IResult<Vec<ScriptStatement>> {
+    many0(map(rule! { #script_stmt ~ ";" }, |(stmt, _)| stmt))(i)
+}
+
+pub fn script_stmt(i: Input) -> IResult<ScriptStatement> {
+    let block = map(script_block, ScriptStatement::Block);
+    let let_resultset = map(
+        consumed(rule! {
+            LET ~ #ident ~ RESULTSET ~ ^":=" ~ ^#query
+        }),
+        |(span, (_, name, _, _, query))| ScriptStatement::LetResultSet {
+            span: transform_span(span.0),
+            name,
+            query: Box::new(query),
+        },
+    );
+    let let_var = map(
+        consumed(rule! {
+            LET ~ ^#ident ~ #type_name? ~ ^":=" ~ ^#expr
+        }),
+        |(span, (_, name, data_type, _, value))| ScriptStatement::LetVar {
+            span: transform_span(span.0),
+            name,
+            data_type,
+            value: Box::new(value),
+        },
+    );
+    let assign = map(
+        consumed(rule! {
+            #ident ~ ":=" ~ ^#expr
+        }),
+        |(span, (name, _, value))| ScriptStatement::Assign {
+            span: transform_span(span.0),
+            name,
+            value: Box::new(value),
+        },
+    );
+    let return_table_query = map(
+        rule! {
+            TABLE ~ "(" ~ #query ~ ^")"
+        },
+        |(_, _, query, _)| ReturnItem::Query(Box::new(query)),
+    );
+    let return_table_resultset = map(
+        rule! {
+            TABLE ~ ^"(" ~ ^#ident ~ ^")"
+        },
+        |(_, _, name, _)| ReturnItem::ResultSet(name),
+    );
+    let return_expr = map(expr, ReturnItem::Expr);
+    let return_stmt = map(
+        consumed(rule! {
+            RETURN ~ ( #return_table_query | #return_table_resultset | #return_expr )?
+        }),
+        |(span, (_, value))| ScriptStatement::Return {
+            span: transform_span(span.0),
+            value,
+        },
+    );
+    let if_stmt = map(
+        consumed(rule! {
+            IF ~ ^#expr ~ ^THEN ~ #script_stmts
+            ~ ( ELSEIF ~ ^#expr ~ ^THEN ~ #script_stmts )*
+            ~ ( ELSE ~ #script_stmts )?
+            ~ ^END ~ ^IF
+        }),
+        |(span, (_, condition, _, result, else_ifs, opt_else, _, _))| {
+            let mut conditions = vec![condition];
+            let mut results = vec![result];
+            for (_, condition, _, result) in else_ifs {
+                conditions.push(condition);
+                results.push(result);
+            }
+            ScriptStatement::If {
+                span: transform_span(span.0),
+                conditions,
+                results,
+                else_result: opt_else.map(|(_, result)| result),
+            }
+        },
+    );
+    let for_range = map(
+        consumed(rule! {
+            FOR ~ #ident ~ IN ~ REVERSE? ~ #expr ~ TO ~ ^#expr
+            ~ ^DO ~ #script_stmts ~ ^END ~ ^FOR
+        }),
+        |(span, (_, variable, _, opt_reverse, lower_bound, _, upper_bound, _, body, _, _))| {
+            ScriptStatement::ForRange {
+                span: transform_span(span.0),
+                variable,
+                is_reverse: opt_reverse.is_some(),
+                lower_bound: Box::new(lower_bound),
+                upper_bound: Box::new(upper_bound),
+                body,
+            }
+        },
+    );
+    let for_in = map(
+        consumed(rule! {
+            FOR ~ ^#ident ~ ^IN ~ ^#ident
+            ~ ^DO ~ #script_stmts ~ ^END ~ ^FOR
+        }),
+        |(span, (_, variable, _, resultset, _, body, _, _))| ScriptStatement::ForIn {
+            span: transform_span(span.0),
+            variable,
+            resultset,
+            body,
+        },
+    );
+    let while_stmt = map(
+        consumed(rule! {
+            WHILE ~ ^#expr ~ ^DO ~ #script_stmts ~ ^END ~ ^WHILE
+        }),
+        |(span, (_, condition, _, body, _, _))| ScriptStatement::While {
+            span: transform_span(span.0),
+            condition: Box::new(condition),
+            body,
+        },
+    );
+    let loop_stmt = map(
+        consumed(rule! {
+            LOOP ~ #script_stmts ~ ^END ~ ^LOOP
+        }),
+        |(span, (_, body, _, _))| ScriptStatement::Loop {
+            span: transform_span(span.0),
+            body,
+        },
+    );
+    let break_stmt = map(consumed(rule! { BREAK }), |(span, _)| {
+        ScriptStatement::Break {
+            span: transform_span(span.0),
+        }
+    });
+    let continue_stmt = map(consumed(rule! { CONTINUE }), |(span, _)| {
+        ScriptStatement::Continue {
+            span: transform_span(span.0),
+        }
+    });
+
+    rule!(
+        #block
+        | #let_resultset
+        | #let_var
+        | #assign
+        | #return_stmt
+        | #if_stmt
+        | #for_range
+        | #for_in
+        | #while_stmt
+        | #loop_stmt
+        | #break_stmt
+        | #continue_stmt
+        | #sql_stmt
+    )(i)
+}
+
+/// Parse a plain SQL statement in the script.
+///
+/// The statement is parsed on the tokens up to the next `;`, because some statements
+/// such as `INSERT ... VALUES` take all the rest tokens of the input.
+fn sql_stmt(i: Input) -> IResult<ScriptStatement> {
+    let end = i
+        .iter()
+        .position(|token| token.kind == SemiColon)
+        .map(|pos| pos + 1)
+        .unwrap_or(i.len());
+    let stmt_input = i.slice(..end);
+    let (rest, (span, stmt)) = consumed(statement_body)(stmt_input)?;
+    let consumed_len = stmt_input.len() - rest.len();
+    Ok((i.slice(consumed_len..), ScriptStatement::RunStatement {
+        span: transform_span(span.0),
+        stmt: Box::new(stmt),
+    }))
+}
@@ -60,7 +60,7 @@ pub enum CreateDatabaseOption {
     FromShare(ShareNameIdent),
 }
 
-pub fn statement(i: Input) -> IResult<StatementWithFormat> {
+pub fn statement_body(i: Input) -> IResult<Statement> {
     let explain = map_res(
         rule! {
             EXPLAIN ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | OPTIMIZED | MEMO )? ~ #statement
@@ -212,15 +212,28 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         rule! {
             INSERT ~ #hint? ~ ( INTO | OVERWRITE ) ~ TABLE?
             ~ #dot_separated_idents_1_to_3
+            ~ ( PARTITION ~ "(" ~ #comma_separated_list1(insert_partition) ~ ")" )?
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
             ~ #insert_source
         },
-        |(_, opt_hints, overwrite, _, (catalog, database, table), opt_columns, source)| {
+        |(
+            _,
+            opt_hints,
+            overwrite,
+            _,
+            (catalog, database, table),
+            opt_partition,
+            opt_columns,
+            source,
+        )| {
             Statement::Insert(InsertStmt {
                 hints: opt_hints,
                 catalog,
                 database,
                 table,
+                partition: opt_partition
+                    .map(|(_, _, partition, _)| partition)
+                    .unwrap_or_default(),
                 columns: opt_columns
                     .map(|(_, columns, _)| columns)
                     .unwrap_or_default(),
@@ -235,7 +248,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             REPLACE ~ #hint? ~ INTO?
             ~ #dot_separated_idents_1_to_3
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
-            ~ (ON ~ CONFLICT? ~ "(" ~ #comma_separated_list1(ident) ~ ")")
+            ~ (ON ~ CONFLICT? ~ "(" ~ #comma_separated_list1(ident) ~ ")")?
             ~ (DELETE ~ WHEN ~ ^#expr)?
             ~ #insert_source
         },
@@ -245,7 +258,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             _,
             (catalog, database, table),
             opt_columns,
-            (_, _, _, on_conflict_columns, _),
+            opt_on_conflict,
             opt_delete_when,
             source,
         )| {
@@ -254,7 +267,9 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                 catalog,
                 database,
                 table,
-                on_conflict_columns,
+                on_conflict_columns: opt_on_conflict
+                    .map(|(_, _, _, on_conflict_columns, _)| on_conflict_columns)
+                    .unwrap_or_default(),
                 columns: opt_columns
                     .map(|(_, columns, _)| columns)
                     .unwrap_or_default(),
@@ -706,7 +721,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
     );
     let create_table = map_res(
         rule! {
-            CREATE ~ (OR ~ REPLACE)? ~ TRANSIENT? ~ TABLE ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            CREATE ~ (OR ~ REPLACE)? ~ ( TEMP | TEMPORARY )? ~ TRANSIENT? ~ TABLE ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #dot_separated_idents_1_to_3
             ~ #create_table_source?
             ~ ( #engine )?
@@ -718,6 +733,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         |(
             _,
             opt_or_replace,
+            opt_temporary,
             opt_transient,
             _,
             opt_if_not_exists,
@@ -745,6 +761,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
                 transient: opt_transient.is_some(),
+                temporary: opt_temporary.is_some(),
             }))
         },
     );
@@ -942,6 +959,53 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let create_materialized_view = map(
+        rule! {
+            CREATE ~ MATERIALIZED ~ VIEW ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #dot_separated_idents_1_to_3
+            ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
+            ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, (catalog, database, view), opt_columns, _, query)| {
+            Statement::CreateMaterializedView(CreateMaterializedViewStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                view,
+                columns: opt_columns
+                    .map(|(_, columns, _)| columns)
+                    .unwrap_or_default(),
+                query: Box::new(query),
+            })
+        },
+    );
+    let drop_materialized_view = map(
+        rule! {
+            DROP ~ MATERIALIZED ~ VIEW ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, (catalog, database, view))| {
+            Statement::DropMaterializedView(DropMaterializedViewStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ VIEW ~ #dot_separated_idents_1_to_3 ~ FULL?
+        },
+        |(_, _, _, (catalog, database, view), opt_full)| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+                full: opt_full.is_some(),
+            })
+        },
+    );
+
     let create_index = map(
         rule! {
             CREATE ~ ASYNC? ~ AGGREGATING ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -1781,7 +1845,116 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
-    let statement_body = alt((
+    let create_sequence = map_res(
+        rule! {
+            CREATE ~ (OR ~ REPLACE)? ~ SEQUENCE ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident
+            ~ ( START ~ ( WITH | "=" )? ~ ^#literal_u64 )?
+            ~ ( INCREMENT ~ ( BY | "=" )? ~ ^#literal_u64 )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            opt_if_not_exists,
+            sequence,
+            opt_start,
+            opt_increment,
+            opt_comment,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateSequence(CreateSequenceStmt {
+                create_option,
+                sequence,
+                start: opt_start.map(|(_, _, start)| start),
+                increment: opt_increment.map(|(_, _, increment)| increment),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            }))
+        },
+    );
+
+    let drop_sequence = map(
+        rule! {
+            DROP ~ SEQUENCE ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, sequence)| {
+            Statement::DropSequence(DropSequenceStmt {
+                if_exists: opt_if_exists.is_some(),
+                sequence,
+            })
+        },
+    );
+
+    let create_procedure = map_res(
+        rule! {
+            CREATE ~ (OR ~ REPLACE)? ~ PROCEDURE ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ "(" ~ #comma_separated_list0(procedure_arg) ~ ")"
+            ~ RETURNS ~ #procedure_return_type
+            ~ LANGUAGE ~ ^#ident
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+            ~ AS ~ ^#code_string
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            args,
+            _,
+            _,
+            return_type,
+            _,
+            language,
+            opt_comment,
+            _,
+            script,
+        )| {
+            if !language.name.eq_ignore_ascii_case("sql") {
+                return Err(nom::Err::Failure(ErrorKind::Other(
+                    "only LANGUAGE SQL is supported for procedures",
+                )));
+            }
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateProcedure(CreateProcedureStmt {
+                create_option,
+                name,
+                args,
+                return_type,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+                script,
+            }))
+        },
+    );
+
+    let drop_procedure = map(
+        rule! {
+            DROP ~ PROCEDURE ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropProcedure(DropProcedureStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+
+    let execute_immediate = map(
+        rule! {
+            EXECUTE ~ IMMEDIATE ~ ^#code_string
+        },
+        |(_, _, script)| Statement::ExecuteImmediate(ExecuteImmediateStmt { script }),
+    );
+
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let commit = value(Statement::Commit, rule! { COMMIT });
+    let abort = value(Statement::Abort, rule! { ABORT | ROLLBACK });
+
+    alt((
         // query, explain,show
         rule!(
         #map(query, |query| Statement::Query(Box::new(query)))
@@ -1853,7 +2026,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #exists_table : "`EXISTS TABLE [<database>.]<table>`"
             | #show_table_functions : "`SHOW TABLE_FUNCTIONS [<show_limit>]`"
         ),
-        // view,stream,index
+        // view,stream,index,materialized view
         rule!(
             #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
@@ -1862,6 +2035,9 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            | #create_materialized_view : "`CREATE MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #drop_materialized_view : "`DROP MATERIALIZED VIEW [IF EXISTS] [<database>.]<view>`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view> [FULL]`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -1964,16 +2140,25 @@ AS
             | #drop_pipe : "`DROP PIPE [ IF EXISTS ] <name>`"
             | #alter_pipe : "`ALTER PIPE [ IF EXISTS ] <name> SET <option> = <value>` | REFRESH <option> = <value>`"
             | #desc_pipe : "`DESC | DESCRIBE PIPE <name>`"
-
+            | #begin : "`BEGIN [TRANSACTION]`"
+            | #commit : "`COMMIT`"
+            | #abort : "`ABORT | ROLLBACK`"
         ),
         rule!(
             #create_connection: "`CREATE CONNECTION [IF NOT EXISTS] <connection_name> STORAGE_TYPE = <type> <storage_configs>`"
         | #drop_connection: "`DROP CONNECTION [IF EXISTS] <connection_name>`"
         | #desc_connection: "`DESC | DESCRIBE CONNECTION  <connection_name>`"
         | #show_connections: "`SHOW CONNECTIONS`"
+        | #create_sequence: "`CREATE [OR REPLACE] SEQUENCE [IF NOT EXISTS] <sequence> [START [WITH] <u64>] [INCREMENT [BY] <u64>] [COMMENT = '<string_literal>']`"
+        | #drop_sequence: "`DROP SEQUENCE [IF EXISTS] <sequence>`"
+        | #create_procedure: "`CREATE [OR REPLACE] PROCEDURE [IF NOT EXISTS] <name>(<arg> <type>, ...) RETURNS { <type> | TABLE } LANGUAGE SQL [COMMENT = '<string_literal>'] AS $$ <script> $$`"
+        | #drop_procedure: "`DROP PROCEDURE [IF EXISTS] <name>`"
+        | #execute_immediate: "`EXECUTE IMMEDIATE $$ <script> $$`"
         ),
-    ));
+    ))(i)
+}
 
+pub fn statement(i: Input) -> IResult<StatementWithFormat> {
     map(
         rule! {
             #statement_body ~ ( FORMAT ~ ^#ident )? ~ ";"? ~ &EOI
@@ -2045,6 +2230,18 @@ pub fn insert_source(i: Input) -> IResult<InsertSource> {
     )(i)
 }
 
+pub fn insert_partition(i: Input) -> IResult<InsertPartition> {
+    map(
+        rule! {
+            #ident ~ ( "=" ~ ^#literal )?
+        },
+        |(column, opt_value)| InsertPartition {
+            column,
+            value: opt_value.map(|(_, value)| value),
+        },
+    )(i)
+}
+
 pub fn merge_source(i: Input) -> IResult<MergeSource> {
     let streaming_v2 = map(
         rule! {
@@ -2141,12 +2338,29 @@ pub fn rest_str(i: Input) -> IResult<(String, usize)> {
 }
 
 pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
+    let (rest, (def, constraints)) = column_def_with_constraints(i)?;
+    if !constraints.is_empty() {
+        return Err(nom::Err::Error(Error::from_error_kind(
+            i,
+            ErrorKind::Other("constraints are only allowed in CREATE TABLE"),
+        )));
+    }
+    Ok((rest, def))
+}
+
+/// Parse a column definition in `CREATE TABLE`, which may carry inline
+/// `PRIMARY KEY`, `UNIQUE` and `CHECK` constraints.
+pub fn column_def_with_constraints(i: Input) -> IResult<(ColumnDefinition, Vec<TableConstraint>)> {
     #[derive(Clone)]
     enum ColumnConstraint {
         Nullable(bool),
         DefaultExpr(Box<Expr>),
         VirtualExpr(Box<Expr>),
         StoredExpr(Box<Expr>),
+        AutoIncrement,
+        PrimaryKey(bool),
+        Unique,
+        Check(Box<Expr>),
     }
 
     let nullable = alt((
@@ -2172,6 +2386,22 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             },
             |(_, _, _, stored_expr, _, _)| ColumnConstraint::StoredExpr(Box::new(stored_expr)),
         ),
+        value(
+            ColumnConstraint::AutoIncrement,
+            rule! { AUTOINCREMENT | IDENTITY },
+        ),
+    ));
+    let key = alt((
+        map(rule! { PRIMARY ~ ^KEY ~ ENFORCED? }, |(_, _, enforced)| {
+            ColumnConstraint::PrimaryKey(enforced.is_some())
+        }),
+        value(ColumnConstraint::Unique, rule! { UNIQUE }),
+        map(
+            rule! {
+                CHECK ~ ^"(" ~ ^#expr ~ ^")"
+            },
+            |(_, _, check_expr, _)| ColumnConstraint::Check(Box::new(check_expr)),
+        ),
     ));
 
     let comment = map(
@@ -2185,9 +2415,9 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         rule! {
             #ident
             ~ #type_name
-            ~ ( #nullable | #expr )*
+            ~ ( #nullable | #expr | #key )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [AUTOINCREMENT | IDENTITY] [PRIMARY KEY [ENFORCED] | UNIQUE | CHECK (<expr>)] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let def = ColumnDefinition {
@@ -2200,6 +2430,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         },
     )(i)?;
 
+    let mut table_constraints = vec![];
     for constraint in constraints {
         match constraint {
             ColumnConstraint::Nullable(nullable) => {
@@ -2226,10 +2457,74 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ColumnConstraint::StoredExpr(stored_expr) => {
                 def.expr = Some(ColumnExpr::Stored(stored_expr))
             }
+            ColumnConstraint::AutoIncrement => def.expr = Some(ColumnExpr::AutoIncrement),
+            ColumnConstraint::PrimaryKey(enforced) => {
+                table_constraints.push(TableConstraint::PrimaryKey {
+                    name: None,
+                    columns: vec![def.name.clone()],
+                    enforced,
+                })
+            }
+            ColumnConstraint::Unique => table_constraints.push(TableConstraint::Unique {
+                name: None,
+                columns: vec![def.name.clone()],
+            }),
+            ColumnConstraint::Check(check_expr) => table_constraints.push(TableConstraint::Check {
+                name: None,
+                expr: check_expr,
+            }),
         }
     }
 
-    Ok((i, def))
+    Ok((i, (def, table_constraints)))
+}
+
+pub fn table_constraint(i: Input) -> IResult<TableConstraint> {
+    let primary_key = map(
+        rule! {
+            PRIMARY ~ KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" ~ ENFORCED?
+        },
+        |(_, _, _, columns, _, enforced)| TableConstraint::PrimaryKey {
+            name: None,
+            columns,
+            enforced: enforced.is_some(),
+        },
+    );
+    let unique = map(
+        rule! {
+            UNIQUE ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, columns, _)| TableConstraint::Unique {
+            name: None,
+            columns,
+        },
+    );
+    let check = map(
+        rule! {
+            CHECK ~ "(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, check_expr, _)| TableConstraint::Check {
+            name: None,
+            expr: Box::new(check_expr),
+        },
+    );
+
+    map(
+        rule! {
+            ( CONSTRAINT ~ #ident )?
+            ~ ( #primary_key | #unique | #check )
+        },
+        |(opt_name, mut constraint)| {
+            if let Some((_, constraint_name)) = opt_name {
+                match &mut constraint {
+                    TableConstraint::PrimaryKey { name, .. }
+                    | TableConstraint::Unique { name, .. }
+                    | TableConstraint::Check { name, .. } => *name = Some(constraint_name),
+                }
+            }
+            constraint
+        },
+    )(i)
 }
 
 pub fn role_name(i: Input) -> IResult<String> {
@@ -2539,11 +2834,33 @@ pub fn grant_option(i: Input) -> IResult<PrincipalIdentity> {
 }
 
 pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
+    enum ColumnOrConstraint {
+        Column((ColumnDefinition, Vec<TableConstraint>)),
+        Constraint(TableConstraint),
+    }
+
+    let element = alt((
+        map(table_constraint, ColumnOrConstraint::Constraint),
+        map(column_def_with_constraints, ColumnOrConstraint::Column),
+    ));
     let columns = map(
         rule! {
-            "(" ~ ^#comma_separated_list1(column_def) ~ ^")"
+            "(" ~ ^#comma_separated_list1(element) ~ ^")"
+        },
+        |(_, elements, _)| {
+            let mut columns = Vec::with_capacity(elements.len());
+            let mut constraints = vec![];
+            for element in elements {
+                match element {
+                    ColumnOrConstraint::Column((column, column_constraints)) => {
+                        columns.push(column);
+                        constraints.extend(column_constraints);
+                    }
+                    ColumnOrConstraint::Constraint(constraint) => constraints.push(constraint),
+                }
+            }
+            CreateTableSource::Columns(columns, constraints)
         },
-        |(_, columns, _)| CreateTableSource::Columns(columns),
     );
     let like = map(
         rule! {
@@ -3342,6 +3659,7 @@ pub fn table_reference_with_alias(i: Input) -> IResult<TableReference> {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         },
     )(i)
 }
@@ -3360,6 +3678,7 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         },
     )(i)
 }
@@ -3419,6 +3738,22 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
     )(i)
 }
 
+pub fn procedure_arg(i: Input) -> IResult<ProcedureArg> {
+    map(rule! { #ident ~ #type_name }, |(name, data_type)| {
+        ProcedureArg { name, data_type }
+    })(i)
+}
+
+pub fn procedure_return_type(i: Input) -> IResult<ProcedureReturnType> {
+    let table = value(ProcedureReturnType::Table, rule! { TABLE ~ ( "(" ~ ")" )? });
+    let scalar = map(rule! { #type_name }, ProcedureReturnType::Scalar);
+
+    rule!(
+        #table
+        | #scalar
+    )(i)
+}
+
 pub fn merge_update_expr(i: Input) -> IResult<MergeUpdateExpr> {
     map(
         rule! { #dot_separated_idents_1_to_2 ~ "=" ~ ^#expr },
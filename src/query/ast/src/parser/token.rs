@@ -151,6 +151,9 @@ pub enum TokenKind {
     #[regex(r#"'([^'\\]|\\.|'')*'"#)]
     QuotedString,
 
+    #[regex(r#"\$\$([^\$]|(\$[^\$]))*\$\$"#)]
+    LiteralCodeString,
+
     #[regex(r#"@([^\s`;'"()]|\\\s|\\'|\\"|\\\\)+"#)]
     AtString,
 
@@ -216,6 +219,8 @@ pub enum TokenKind {
     Colon,
     #[token("::")]
     DoubleColon,
+    #[token(":=")]
+    ColonEqual,
     #[token(";")]
     SemiColon,
     #[token("\\")]
@@ -322,6 +327,8 @@ pub enum TokenKind {
     AFTER,
     #[token("AGGREGATING", ignore(ascii_case))]
     AGGREGATING,
+    #[token("ABORT", ignore(ascii_case))]
+    ABORT,
     #[token("ANY", ignore(ascii_case))]
     ANY,
     #[token("APPEND_ONLY", ignore(ascii_case))]
@@ -330,6 +337,8 @@ pub enum TokenKind {
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
     AUTO,
+    #[token("AUTOINCREMENT", ignore(ascii_case))]
+    AUTOINCREMENT,
     #[token("SOME", ignore(ascii_case))]
     SOME,
     #[token("ALTER", ignore(ascii_case))]
@@ -350,6 +359,8 @@ pub enum TokenKind {
     AT,
     #[token("ASC", ignore(ascii_case))]
     ASC,
+    #[token("ASOF", ignore(ascii_case))]
+    ASOF,
     #[token("ANTI", ignore(ascii_case))]
     ANTI,
     #[token("ASYNC", ignore(ascii_case))]
@@ -358,6 +369,8 @@ pub enum TokenKind {
     ATTACH,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
@@ -376,6 +389,8 @@ pub enum TokenKind {
     BINARY_FORMAT,
     #[token("BITMAP", ignore(ascii_case))]
     BITMAP,
+    #[token("BLOCK", ignore(ascii_case))]
+    BLOCK,
     #[token("BLOCKED_IP_LIST", ignore(ascii_case))]
     BLOCKED_IP_LIST,
     #[token("BOOL", ignore(ascii_case))]
@@ -384,6 +399,8 @@ pub enum TokenKind {
     BOOLEAN,
     #[token("BOTH", ignore(ascii_case))]
     BOTH,
+    #[token("BREAK", ignore(ascii_case))]
+    BREAK,
     #[token("BY", ignore(ascii_case))]
     BY,
     #[token("BROTLI", ignore(ascii_case))]
@@ -406,6 +423,8 @@ pub enum TokenKind {
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
     COMMENT,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
     #[token("COMPACT", ignore(ascii_case))]
@@ -414,6 +433,8 @@ pub enum TokenKind {
     CONNECTION,
     #[token("CONNECTIONS", ignore(ascii_case))]
     CONNECTIONS,
+    #[token("CONSTRAINT", ignore(ascii_case))]
+    CONSTRAINT,
     #[token("CONTENT_TYPE", ignore(ascii_case))]
     CONTENT_TYPE,
     #[token("CHAR", ignore(ascii_case))]
@@ -424,10 +445,14 @@ pub enum TokenKind {
     COLUMNS,
     #[token("CHARACTER", ignore(ascii_case))]
     CHARACTER,
+    #[token("CHECK", ignore(ascii_case))]
+    CHECK,
     #[token("CONFLICT", ignore(ascii_case))]
     CONFLICT,
     #[token("COMPRESSION", ignore(ascii_case))]
     COMPRESSION,
+    #[token("CONTINUE", ignore(ascii_case))]
+    CONTINUE,
     #[token("COPY_OPTIONS", ignore(ascii_case))]
     COPY_OPTIONS,
     #[token("COPY", ignore(ascii_case))]
@@ -488,6 +513,8 @@ pub enum TokenKind {
     DISTINCT,
     #[token("DIV", ignore(ascii_case))]
     DIV,
+    #[token("DO", ignore(ascii_case))]
+    DO,
     #[token("DOUBLE_SHA1_PASSWORD", ignore(ascii_case))]
     DOUBLE_SHA1_PASSWORD,
     #[token("DOUBLE", ignore(ascii_case))]
@@ -508,10 +535,14 @@ pub enum TokenKind {
     DRY,
     #[token("EXCEPT", ignore(ascii_case))]
     EXCEPT,
+    #[token("EXCEPTION", ignore(ascii_case))]
+    EXCEPTION,
     #[token("EXCLUDE", ignore(ascii_case))]
     EXCLUDE,
     #[token("ELSE", ignore(ascii_case))]
     ELSE,
+    #[token("ELSEIF", ignore(ascii_case))]
+    ELSEIF,
     #[token("EMPTY_FIELD_AS", ignore(ascii_case))]
     EMPTY_FIELD_AS,
     #[token("ENABLE_VIRTUAL_HOST_STYLE", ignore(ascii_case))]
@@ -520,6 +551,8 @@ pub enum TokenKind {
     END,
     #[token("ENDPOINT", ignore(ascii_case))]
     ENDPOINT,
+    #[token("ENFORCED", ignore(ascii_case))]
+    ENFORCED,
     #[token("ENGINE", ignore(ascii_case))]
     ENGINE,
     #[token("ENGINES", ignore(ascii_case))]
@@ -594,6 +627,10 @@ pub enum TokenKind {
     FUSE,
     #[token("GENERATED", ignore(ascii_case))]
     GENERATED,
+    #[token("GEOGRAPHY", ignore(ascii_case))]
+    GEOGRAPHY,
+    #[token("GEOMETRY", ignore(ascii_case))]
+    GEOMETRY,
     #[token("GLOBAL", ignore(ascii_case))]
     GLOBAL,
     #[token("GRAPH", ignore(ascii_case))]
@@ -618,12 +655,18 @@ pub enum TokenKind {
     INTERSECT,
     #[token("IDENTIFIED", ignore(ascii_case))]
     IDENTIFIED,
+    #[token("IDENTITY", ignore(ascii_case))]
+    IDENTITY,
     #[token("IF", ignore(ascii_case))]
     IF,
+    #[token("IMMEDIATE", ignore(ascii_case))]
+    IMMEDIATE,
     #[token("IN", ignore(ascii_case))]
     IN,
     #[token("INDEX", ignore(ascii_case))]
     INDEX,
+    #[token("INCREMENT", ignore(ascii_case))]
+    INCREMENT,
     #[token("INNER", ignore(ascii_case))]
     INNER,
     #[token("INSERT", ignore(ascii_case))]
@@ -681,12 +724,16 @@ pub enum TokenKind {
     LEADING,
     #[token("LEFT", ignore(ascii_case))]
     LEFT,
+    #[token("LET", ignore(ascii_case))]
+    LET,
     #[token("LIKE", ignore(ascii_case))]
     LIKE,
     #[token("LIMIT", ignore(ascii_case))]
     LIMIT,
     #[token("LIST", ignore(ascii_case))]
     LIST,
+    #[token("LOOP", ignore(ascii_case))]
+    LOOP,
     #[token("LZO", ignore(ascii_case))]
     LZO,
     #[token("MASKING", ignore(ascii_case))]
@@ -753,6 +800,8 @@ pub enum TokenKind {
     OR,
     #[token("ORDER", ignore(ascii_case))]
     ORDER,
+    #[token("OTHER", ignore(ascii_case))]
+    OTHER,
     #[token("OUTPUT_HEADER", ignore(ascii_case))]
     OUTPUT_HEADER,
     #[token("OUTER", ignore(ascii_case))]
@@ -793,6 +842,8 @@ pub enum TokenKind {
     PASSWORD_HISTORY,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
+    #[token("PERCENT", ignore(ascii_case))]
+    PERCENT,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
@@ -803,6 +854,8 @@ pub enum TokenKind {
     POLICY,
     #[token("POSITION", ignore(ascii_case))]
     POSITION,
+    #[token("PROCEDURE", ignore(ascii_case))]
+    PROCEDURE,
     #[token("PROCESSLIST", ignore(ascii_case))]
     PROCESSLIST,
     #[token("PURGE", ignore(ascii_case))]
@@ -831,6 +884,8 @@ pub enum TokenKind {
     REGEXP,
     #[token("RENAME", ignore(ascii_case))]
     RENAME,
+    #[token("REPEATABLE", ignore(ascii_case))]
+    REPEATABLE,
     #[token("REPLACE", ignore(ascii_case))]
     REPLACE,
     #[token("RETURN_FAILED_ONLY", ignore(ascii_case))]
@@ -839,6 +894,8 @@ pub enum TokenKind {
     MERGE,
     #[token("MATCHED", ignore(ascii_case))]
     MATCHED,
+    #[token("MATCH_CONDITION", ignore(ascii_case))]
+    MATCH_CONDITION,
     #[token("MISSING_FIELD_AS", ignore(ascii_case))]
     MISSING_FIELD_AS,
     #[token("NULL_FIELD_AS", ignore(ascii_case))]
@@ -855,20 +912,28 @@ pub enum TokenKind {
     GRANT,
     #[token("ROLE", ignore(ascii_case))]
     ROLE,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
     #[token("PRECEDING", ignore(ascii_case))]
     PRECEDING,
     #[token("PRECISION", ignore(ascii_case))]
     PRECISION,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
+    #[token("PRIMARY", ignore(ascii_case))]
+    PRIMARY,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("QUALIFY", ignore(ascii_case))]
     QUALIFY,
     #[token("REMOVE", ignore(ascii_case))]
     REMOVE,
+    #[token("RESULTSET", ignore(ascii_case))]
+    RESULTSET,
     #[token("RETAIN", ignore(ascii_case))]
     RETAIN,
+    #[token("REVERSE", ignore(ascii_case))]
+    REVERSE,
     #[token("REVOKE", ignore(ascii_case))]
     REVOKE,
     #[token("RECURSIVE", ignore(ascii_case))]
@@ -887,6 +952,8 @@ pub enum TokenKind {
     RLIKE,
     #[token("RAW", ignore(ascii_case))]
     RAW,
+    #[token("SAMPLE", ignore(ascii_case))]
+    SAMPLE,
     #[token("OPTIMIZED", ignore(ascii_case))]
     OPTIMIZED,
     #[token("SCHEMA", ignore(ascii_case))]
@@ -903,6 +970,8 @@ pub enum TokenKind {
     UNPIVOT,
     #[token("SEGMENT", ignore(ascii_case))]
     SEGMENT,
+    #[token("SEQUENCE", ignore(ascii_case))]
+    SEQUENCE,
     #[token("SET", ignore(ascii_case))]
     SET,
     #[token("UNSET", ignore(ascii_case))]
@@ -937,6 +1006,8 @@ pub enum TokenKind {
     SPLIT_SIZE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
+    #[token("START", ignore(ascii_case))]
+    START,
     #[token("SYNTAX", ignore(ascii_case))]
     SYNTAX,
     #[token("USAGE", ignore(ascii_case))]
@@ -975,8 +1046,14 @@ pub enum TokenKind {
     STORAGE_TYPE,
     #[token("TABLE", ignore(ascii_case))]
     TABLE,
+    #[token("TABLESAMPLE", ignore(ascii_case))]
+    TABLESAMPLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TEMP", ignore(ascii_case))]
+    TEMP,
+    #[token("TEMPORARY", ignore(ascii_case))]
+    TEMPORARY,
     #[token("TEXT", ignore(ascii_case))]
     TEXT,
     #[token("LONGTEXT", ignore(ascii_case))]
@@ -1009,6 +1086,8 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
@@ -1029,6 +1108,8 @@ pub enum TokenKind {
     UNBOUNDED,
     #[token("UNION", ignore(ascii_case))]
     UNION,
+    #[token("UNIQUE", ignore(ascii_case))]
+    UNIQUE,
     #[token("UINT16", ignore(ascii_case))]
     UINT16,
     #[token("UINT32", ignore(ascii_case))]
@@ -1071,6 +1152,8 @@ pub enum TokenKind {
     WHEN,
     #[token("WHERE", ignore(ascii_case))]
     WHERE,
+    #[token("WHILE", ignore(ascii_case))]
+    WHILE,
     #[token("WINDOW", ignore(ascii_case))]
     WINDOW,
     #[token("WITH", ignore(ascii_case))]
@@ -1095,6 +1178,10 @@ pub enum TokenKind {
     IFNULL,
     #[token("NULLS", ignore(ascii_case))]
     NULLS,
+    #[token("IGNORE", ignore(ascii_case))]
+    IGNORE,
+    #[token("RESPECT", ignore(ascii_case))]
+    RESPECT,
     #[token("FIRST", ignore(ascii_case))]
     FIRST,
     #[token("LAST", ignore(ascii_case))]
@@ -1160,7 +1247,12 @@ impl TokenKind {
     pub fn is_literal(&self) -> bool {
         matches!(
             self,
-            LiteralInteger | LiteralFloat | QuotedString | PGLiteralHex | MySQLLiteralHex
+            LiteralInteger
+                | LiteralFloat
+                | QuotedString
+                | LiteralCodeString
+                | PGLiteralHex
+                | MySQLLiteralHex
         )
     }
 
@@ -1169,6 +1261,7 @@ impl TokenKind {
             self,
             Ident
                 | QuotedString
+                | LiteralCodeString
                 | PGLiteralHex
                 | MySQLLiteralHex
                 | LiteralInteger
@@ -1196,6 +1289,7 @@ impl TokenKind {
                 | Dot
                 | Colon
                 | DoubleColon
+                | ColonEqual
                 | SemiColon
                 | Backslash
                 | LBracket
@@ -1454,7 +1548,7 @@ impl TokenKind {
             | TokenKind::SEMI
             // | TokenKind::SYMMETRIC
             // | TokenKind::TABLE
-            // | TokenKind::TABLESAMPLE
+            | TokenKind::TABLESAMPLE
             | TokenKind::THEN
             | TokenKind::TRAILING
             | TokenKind::TRUE
@@ -1507,6 +1601,8 @@ impl TokenKind {
             | TokenKind::TASK
             | TokenKind::PIPE
             | TokenKind::STREAM
+            | TokenKind::ASOF
+            | TokenKind::MATCH_CONDITION
             if !after_as => true,
             _ => false
         }
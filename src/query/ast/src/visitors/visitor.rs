@@ -242,7 +242,7 @@ pub trait Visitor<'ast>: Sized {
         _name: &'ast Identifier,
         args: &'ast [Expr],
         params: &'ast [Expr],
-        over: &'ast Option<Window>,
+        over: &'ast Option<WindowDesc>,
         lambda: &'ast Option<Lambda>,
     ) {
         for arg in args {
@@ -253,7 +253,7 @@ pub trait Visitor<'ast>: Sized {
         }
 
         if let Some(over) = over {
-            self.visit_window(over);
+            self.visit_window(&over.window);
         }
         if let Some(lambda) = lambda {
             walk_expr(self, &lambda.expr)
@@ -371,6 +371,8 @@ pub trait Visitor<'ast>: Sized {
         walk_expr(self, date);
     }
 
+    fn visit_placeholder(&mut self, _span: Span) {}
+
     fn visit_statement(&mut self, statement: &'ast Statement) {
         walk_statement(self, statement);
     }
@@ -562,6 +564,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &'ast CreateMaterializedViewStmt) {}
+
+    fn visit_drop_materialized_view(&mut self, _stmt: &'ast DropMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &'ast RefreshMaterializedViewStmt) {}
+
     fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
 
     fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt) {}
@@ -798,12 +806,17 @@ pub trait Visitor<'ast>: Sized {
             left,
             right,
             condition,
+            match_condition,
             ..
         } = join;
 
         walk_table_reference(self, left);
         walk_table_reference(self, right);
 
+        if let Some(match_condition) = match_condition {
+            walk_expr(self, match_condition);
+        }
+
         walk_join_condition(self, condition);
     }
     fn visit_window_definition(&mut self, window_definition: &'ast WindowDefinition) {
@@ -814,4 +827,9 @@ pub trait Visitor<'ast>: Sized {
     fn visit_drop_connection(&mut self, _stmt: &'ast DropConnectionStmt) {}
     fn visit_describe_connection(&mut self, _stmt: &'ast DescribeConnectionStmt) {}
     fn visit_show_connections(&mut self, _stmt: &'ast ShowConnectionsStmt) {}
+    fn visit_create_sequence(&mut self, _stmt: &'ast CreateSequenceStmt) {}
+    fn visit_drop_sequence(&mut self, _stmt: &'ast DropSequenceStmt) {}
+    fn visit_create_procedure(&mut self, _stmt: &'ast CreateProcedureStmt) {}
+    fn visit_drop_procedure(&mut self, _stmt: &'ast DropProcedureStmt) {}
+    fn visit_execute_immediate(&mut self, _stmt: &'ast ExecuteImmediateStmt) {}
 }
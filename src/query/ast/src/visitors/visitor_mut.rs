@@ -257,7 +257,7 @@ pub trait VisitorMut: Sized {
         _name: &mut Identifier,
         args: &mut Vec<Expr>,
         params: &mut Vec<Expr>,
-        over: &mut Option<Window>,
+        over: &mut Option<WindowDesc>,
         lambda: &mut Option<Lambda>,
     ) {
         for arg in args.iter_mut() {
@@ -268,7 +268,7 @@ pub trait VisitorMut: Sized {
         }
 
         if let Some(over) = over {
-            match over {
+            match &mut over.window {
                 Window::WindowReference(reference) => {
                     self.visit_identifier(&mut reference.window_name);
                 }
@@ -385,6 +385,8 @@ pub trait VisitorMut: Sized {
         Self::visit_expr(self, date);
     }
 
+    fn visit_placeholder(&mut self, _span: Span) {}
+
     fn visit_statement(&mut self, statement: &mut Statement) {
         walk_statement_mut(self, statement);
     }
@@ -576,6 +578,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &mut CreateMaterializedViewStmt) {}
+
+    fn visit_drop_materialized_view(&mut self, _stmt: &mut DropMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &mut RefreshMaterializedViewStmt) {}
+
     fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
 
     fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt) {}
@@ -812,12 +820,17 @@ pub trait VisitorMut: Sized {
             left,
             right,
             condition,
+            match_condition,
             ..
         } = join;
 
         self.visit_table_reference(left);
         self.visit_table_reference(right);
 
+        if let Some(match_condition) = match_condition {
+            self.visit_expr(match_condition);
+        }
+
         walk_join_condition_mut(self, condition);
     }
 
@@ -825,4 +838,9 @@ pub trait VisitorMut: Sized {
     fn visit_drop_connection(&mut self, _stmt: &mut DropConnectionStmt) {}
     fn visit_describe_connection(&mut self, _stmt: &mut DescribeConnectionStmt) {}
     fn visit_show_connections(&mut self, _stmt: &mut ShowConnectionsStmt) {}
+    fn visit_create_sequence(&mut self, _stmt: &mut CreateSequenceStmt) {}
+    fn visit_drop_sequence(&mut self, _stmt: &mut DropSequenceStmt) {}
+    fn visit_create_procedure(&mut self, _stmt: &mut CreateProcedureStmt) {}
+    fn visit_drop_procedure(&mut self, _stmt: &mut DropProcedureStmt) {}
+    fn visit_execute_immediate(&mut self, _stmt: &mut ExecuteImmediateStmt) {}
 }
@@ -146,6 +146,7 @@ fn test_statement() {
         r#"CREATE TABLE t(c1 int not null, c2 bigint not null, c3 varchar not null);"#,
         r#"CREATE TABLE t(c1 varbinary, c2 binary(10));"#,
         r#"CREATE TABLE t(c1 int default 1);"#,
+        r#"CREATE TEMP TABLE t(c1 int default 1);"#,
         r#"create table abc as (select * from xyz limit 10)"#,
         r#"ALTER USER u1 IDENTIFIED BY '123456';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = role1;"#,
@@ -189,6 +190,7 @@ fn test_statement() {
         r#"insert into t (c1, c2) values (1, 2);   "#,
         r#"insert into table t format json;"#,
         r#"insert into table t select * from t2;"#,
+        r#"insert overwrite t partition (dt = '2024-01-01', hr) values (1, 2);"#,
         r#"select parse_json('{"k1": [0, 1, 2]}').k1[0];"#,
         r#"CREATE STAGE ~"#,
         r#"CREATE STAGE IF NOT EXISTS test_stage 's3://load/files/' credentials=(aws_key_id='1a2b3c', aws_secret_key='4x5y6z') file_format=(type = CSV, compression = GZIP record_delimiter=',')"#,
@@ -545,6 +547,23 @@ fn test_statement() {
         r#"ALTER PIPE mypipe SET PIPE_EXECUTION_PAUSED = true"#,
         r#"DROP PIPE mypipe"#,
         r#"DESC PIPE mypipe"#,
+        r#"BEGIN"#,
+        r#"BEGIN TRANSACTION"#,
+        r#"COMMIT"#,
+        r#"ROLLBACK"#,
+        r#"ABORT"#,
+        r#"CREATE SEQUENCE IF NOT EXISTS seq START WITH 10 INCREMENT BY 2 COMMENT = 'ids'"#,
+        r#"CREATE OR REPLACE SEQUENCE seq INCREMENT = 5"#,
+        r#"DROP SEQUENCE IF EXISTS seq"#,
+        r#"CREATE TABLE t(id bigint IDENTITY, c1 int AUTOINCREMENT)"#,
+        r#"create materialized view mv as select number % 3 as a from numbers(1000);"#,
+        r#"DROP MATERIALIZED VIEW IF EXISTS db.mv"#,
+        r#"REFRESH MATERIALIZED VIEW mv FULL"#,
+        r#"CREATE PROCEDURE add_one(a INT) RETURNS BIGINT LANGUAGE SQL COMMENT = 'add one' AS $$BEGIN RETURN a + 1; END$$"#,
+        r#"CREATE OR REPLACE PROCEDURE p1() RETURNS TABLE LANGUAGE SQL AS 'BEGIN RETURN TABLE(SELECT 1); END'"#,
+        r#"DROP PROCEDURE IF EXISTS add_one"#,
+        r#"EXECUTE IMMEDIATE $$BEGIN LET x := 1; RETURN x; END$$"#,
+        r#"CREATE TABLE t(id INT PRIMARY KEY ENFORCED, v STRING UNIQUE, CONSTRAINT t_check CHECK (id > 0))"#,
         "--各环节转各环节转各环节转各环节转各\n  select 34343",
         "-- 96477300355	31379974136	3.074486292973661\nselect 34343",
         "-- xxxxx\n  select 34343;",
@@ -715,6 +734,11 @@ fn test_query() {
         r#"VALUES(1,'a'),(2,'b'),(null,'c') order by col0 limit 2"#,
         r#"select * from t left join lateral(select 1) on true, lateral(select 2)"#,
         r#"select * from t, lateral flatten(input => u.col) f"#,
+        r#"select * from t sample row (10 rows)"#,
+        r#"select * from t tablesample block (50) repeatable (1)"#,
+        r#"select * from t sample"#,
+        r#"select * from t1 asof join t2 match_condition (t1.ts >= t2.ts) on t1.id = t2.id"#,
+        r#"select * from t1 asof left join t2 match_condition (t1.ts < t2.ts)"#,
     ];
 
     for case in cases {
@@ -789,6 +813,7 @@ fn test_expr() {
         r#"CAST(col1 AS BIGINT UNSIGNED)"#,
         r#"TRY_CAST(col1 AS BIGINT UNSIGNED)"#,
         r#"TRY_CAST(col1 AS TUPLE(BIGINT UNSIGNED NULL, BOOLEAN))"#,
+        r#"INTERVAL '1 day'"#,
         r#"trim(leading 'abc' from 'def')"#,
         r#"extract(year from d)"#,
         r#"date_part(year, d)"#,
@@ -828,10 +853,13 @@ fn test_expr() {
         r#"COUNT() OVER (ORDER BY hire_date ROWS UNBOUNDED PRECEDING)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS CURRENT ROW)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS 3 PRECEDING)"#,
+        r#"LAG(salary, 2) IGNORE NULLS OVER (ORDER BY hire_date)"#,
+        r#"LAST_VALUE(salary IGNORE NULLS) OVER (ORDER BY hire_date)"#,
         r#"ARRAY_APPLY([1,2,3], x -> x + 1)"#,
         r#"ARRAY_FILTER(col, y -> y % 2 = 0)"#,
         r#"(current_timestamp, current_timestamp(), now())"#,
         r#"ARRAY_REDUCE([1,2,3], (acc,t) -> acc + t)"#,
+        r#"a = ? and b ? 'k'"#,
     ];
 
     for case in cases {
@@ -19,12 +19,15 @@ pub mod catalog_kind;
 pub mod cluster_info;
 pub mod database;
 pub mod lock;
+pub mod materialized_view;
 pub mod plan;
 pub mod query_kind;
 pub mod statistics;
 pub mod table_args;
 pub mod table_context;
 pub mod table_function;
+pub mod temp_table;
+pub mod txn_manager;
 
 pub mod merge_into_join;
 pub mod runtime_filter_info;
@@ -0,0 +1,56 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCES;
+
+use crate::catalog::Catalog;
+use crate::table::Table;
+
+/// The name of the stream that an incremental materialized view is refreshed from.
+///
+/// The stream is created in the database of the view, and dropped with the view.
+pub fn materialized_view_stream_name(view_id: u64) -> String {
+    format!("_mv_stream_{}", view_id)
+}
+
+/// Check whether a materialized view is out of date, `None` is returned if the table
+/// is not a materialized view.
+///
+/// The view is stale if any of its source tables has been changed or dropped since
+/// the view was refreshed last time.
+pub async fn is_materialized_view_stale(
+    catalog: &dyn Catalog,
+    table: &dyn Table,
+) -> Result<Option<bool>> {
+    let Some(sources) = table.options().get(OPT_KEY_MATERIALIZED_VIEW_SOURCES) else {
+        return Ok(None);
+    };
+
+    let refreshed_on = table.get_table_info().meta.updated_on;
+    for source in sources.split(',').filter(|v| !v.is_empty()) {
+        let table_id = source.parse::<u64>()?;
+        match catalog.get_table_meta_by_id(table_id).await {
+            Ok((_, meta)) => {
+                if meta.drop_on.is_some() || meta.updated_on > refreshed_on {
+                    return Ok(Some(true));
+                }
+            }
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => return Ok(Some(true)),
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(Some(false))
+}
@@ -68,8 +68,20 @@ pub struct PrewhereInfo {
     pub virtual_columns: Option<Vec<VirtualColumnInfo>>,
 }
 
+/// Information about table sampling, generated from the `TABLESAMPLE` clause.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SampleInfo {
+    /// Whether to sample blocks instead of rows.
+    /// If the table engine doesn't support block level sampling, rows will be sampled.
+    pub block_level: bool,
+    /// The probability of each block (or row) to be sampled, in `[0.0, 1.0]`.
+    pub probability: f64,
+    /// Optional seed to make the sampling result repeatable.
+    pub seed: Option<u64>,
+}
+
 /// Extras is a wrapper for push down items.
-#[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug, PartialEq)]
 pub struct PushDownInfo {
     /// Optional column indices to use as a projection.
     /// It represents the columns to be read from the source.
@@ -96,6 +108,8 @@ pub struct PushDownInfo {
     pub agg_index: Option<AggIndexInfo>,
     /// Identifies the type of data change we are looking for
     pub change_type: Option<ChangeType>,
+    /// Optional sampling information.
+    pub sample: Option<SampleInfo>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
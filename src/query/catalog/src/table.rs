@@ -48,6 +48,7 @@ use crate::plan::StreamColumn;
 use crate::statistics::BasicColumnStatistics;
 use crate::table_args::TableArgs;
 use crate::table_context::TableContext;
+use crate::temp_table::TempTblMgr;
 
 #[async_trait::async_trait]
 pub trait Table: Sync + Send {
@@ -111,6 +112,25 @@ pub trait Table: Sync + Send {
         vec![]
     }
 
+    /// The columns the table is partitioned by, which can be specified by
+    /// the `PARTITION` clause of `INSERT`. Only Hive Engine supports this.
+    fn partition_keys(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Returns the table to commit `INSERT OVERWRITE ... PARTITION (...)` with the static
+    /// `values` of all the partition columns, which overwrites the partition even if no rows
+    /// are inserted into it.
+    fn with_overwrite_partition(&self, values: Vec<(String, Scalar)>) -> Result<Arc<dyn Table>> {
+        let _ = values;
+
+        Err(ErrorCode::Unimplemented(format!(
+            "Overwriting a static partition is not supported for the table '{}', which uses the '{}' engine.",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
     fn change_tracking_enabled(&self) -> bool {
         false
     }
@@ -145,6 +165,18 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// Whether the table engine supports sampling at block level.
+    /// only Fuse Engine supports this.
+    fn support_block_sample(&self) -> bool {
+        false
+    }
+
+    /// Whether the files loaded by COPY INTO are recorded when the insertion is committed,
+    /// otherwise COPY INTO the table is only allowed with `FORCE = TRUE`.
+    fn support_copied_files(&self) -> bool {
+        true
+    }
+
     #[async_backtrace::framed]
     async fn alter_table_cluster_keys(
         &self,
@@ -403,6 +435,17 @@ pub trait TableExt: Table {
         let name = table_info.name.clone();
         let tid = table_info.ident.table_id;
         let catalog = ctx.get_catalog(table_info.catalog()).await?;
+        let buffered = ctx.txn_mgr().lock().get_table_from_buffer(table_info);
+        if let Some(table_info) = buffered {
+            return catalog.get_table_by_info(&table_info);
+        }
+        if TempTblMgr::is_temp_table(tid) {
+            let table_info = ctx.temp_tbl_mgr().lock().get_table_by_id(tid);
+            let table_info = table_info.ok_or_else(|| {
+                ErrorCode::UnknownTableId(format!("Unknown temporary table id {}", tid))
+            })?;
+            return catalog.get_table_by_info(&table_info);
+        }
         let (ident, meta) = catalog.get_table_meta_by_id(tid).await?;
         let table_info = TableInfo {
             ident,
@@ -60,6 +60,8 @@ use crate::query_kind::QueryKind;
 use crate::runtime_filter_info::RuntimeFilterInfo;
 use crate::statistics::data_cache_statistics::DataCacheMetrics;
 use crate::table::Table;
+use crate::temp_table::TempTblMgrRef;
+use crate::txn_manager::TxnManagerRef;
 
 pub type MaterializedCtesBlocks = Arc<RwLock<HashMap<(usize, usize), Arc<RwLock<Vec<DataBlock>>>>>>;
 
@@ -240,6 +242,12 @@ pub trait TableContext: Send + Sync {
     /// Get license key from context, return empty if license is not found or error happened.
     fn get_license_key(&self) -> String;
 
+    /// Get the transaction manager of the current session.
+    fn txn_mgr(&self) -> TxnManagerRef;
+
+    /// Get the temporary table manager of the current session.
+    fn temp_tbl_mgr(&self) -> TempTblMgrRef;
+
     fn add_query_profiles(&self, profiles: &[PlanProfile]);
 
     fn get_query_profiles(&self) -> Vec<PlanProfile>;
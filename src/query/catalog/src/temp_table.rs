@@ -0,0 +1,168 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateTableReply;
+use databend_common_meta_app::schema::CreateTableReq;
+use databend_common_meta_app::schema::DatabaseType;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeqExt;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use parking_lot::Mutex;
+
+/// The ids of temporary tables are allocated from this value, so that they never
+/// collide with the ids allocated by the meta service.
+pub const TEMP_TBL_ID_BEGIN: u64 = 0xFFFF_FFFF_0000_0000;
+
+pub type TempTblMgrRef = Arc<Mutex<TempTblMgr>>;
+
+/// Session scoped manager of the temporary tables.
+///
+/// The meta of temporary tables is only kept in the session that creates them, and
+/// they shadow the permanent tables with the same name in this session.
+#[derive(Debug)]
+pub struct TempTblMgr {
+    /// The id of the session that owns the temporary tables, it is also used as the
+    /// storage prefix of the table data.
+    session_id: String,
+    /// Table id keyed by (database name, table name).
+    name_to_id: BTreeMap<(String, String), u64>,
+    tables: BTreeMap<u64, TableInfo>,
+    next_id: u64,
+}
+
+impl TempTblMgr {
+    pub fn init(session_id: String) -> TempTblMgrRef {
+        Arc::new(Mutex::new(TempTblMgr {
+            session_id,
+            name_to_id: BTreeMap::new(),
+            tables: BTreeMap::new(),
+            next_id: TEMP_TBL_ID_BEGIN,
+        }))
+    }
+
+    pub fn is_temp_table(table_id: u64) -> bool {
+        table_id >= TEMP_TBL_ID_BEGIN
+    }
+
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+
+    /// Create a temporary table, the existing one must have been dropped by the caller
+    /// if `CREATE OR REPLACE` is used.
+    pub fn create_table(&mut self, req: CreateTableReq) -> Result<CreateTableReply> {
+        let CreateTableReq {
+            create_option,
+            name_ident,
+            mut table_meta,
+        } = req;
+
+        let key = (name_ident.db_name.clone(), name_ident.table_name.clone());
+        if let Some(table_id) = self.name_to_id.get(&key) {
+            match create_option {
+                CreateOption::CreateIfNotExists(true) => {
+                    return Ok(CreateTableReply {
+                        table_id: *table_id,
+                        new_table: false,
+                        spec_vec: None,
+                    });
+                }
+                CreateOption::CreateIfNotExists(false) | CreateOption::CreateOrReplace => {
+                    return Err(ErrorCode::TableAlreadyExists(format!(
+                        "Temporary table '{}' already exists",
+                        name_ident.table_name
+                    )));
+                }
+            }
+        }
+
+        let table_id = self.next_id;
+        self.next_id += 1;
+
+        table_meta
+            .options
+            .insert(OPT_KEY_TEMP_PREFIX.to_string(), self.session_id.clone());
+        let table_info = TableInfo {
+            ident: TableIdent { table_id, seq: 1 },
+            desc: name_ident.to_string(),
+            name: name_ident.table_name.clone(),
+            meta: table_meta,
+            tenant: name_ident.tenant.clone(),
+            db_type: DatabaseType::NormalDB,
+        };
+        self.name_to_id.insert(key, table_id);
+        self.tables.insert(table_id, table_info);
+
+        Ok(CreateTableReply {
+            table_id,
+            new_table: true,
+            spec_vec: None,
+        })
+    }
+
+    pub fn get_table(&self, database: &str, table: &str) -> Option<TableInfo> {
+        self.name_to_id
+            .get(&(database.to_string(), table.to_string()))
+            .and_then(|table_id| self.tables.get(table_id))
+            .cloned()
+    }
+
+    pub fn get_table_by_id(&self, table_id: u64) -> Option<TableInfo> {
+        self.tables.get(&table_id).cloned()
+    }
+
+    pub fn update_table_meta(&mut self, req: UpdateTableMetaReq) -> Result<()> {
+        let table_info = self.tables.get_mut(&req.table_id).ok_or_else(|| {
+            ErrorCode::UnknownTableId(format!(
+                "Unknown temporary table id {}, it may have been dropped",
+                req.table_id
+            ))
+        })?;
+
+        if req.seq.match_seq(table_info.ident.seq).is_err() {
+            return Err(ErrorCode::TableVersionMismatched(format!(
+                "Temporary table {} version mismatched, expect {}, got {}",
+                req.table_id, req.seq, table_info.ident.seq
+            )));
+        }
+
+        table_info.ident.seq += 1;
+        table_info.meta = req.new_table_meta;
+        Ok(())
+    }
+
+    pub fn drop_table(&mut self, database: &str, table: &str) -> Option<TableInfo> {
+        self.name_to_id
+            .remove(&(database.to_string(), table.to_string()))
+            .and_then(|table_id| self.tables.remove(&table_id))
+    }
+
+    /// Drop all the temporary tables, used when the session ends.
+    pub fn drop_all(&mut self) -> Vec<TableInfo> {
+        self.name_to_id.clear();
+        std::mem::take(&mut self.tables).into_values().collect()
+    }
+}
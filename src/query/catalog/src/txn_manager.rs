@@ -0,0 +1,181 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TxnState {
+    /// Each statement is committed by itself.
+    AutoCommit,
+    /// In an explicit transaction started by `BEGIN`, the table metas are buffered
+    /// until `COMMIT`.
+    Active,
+    /// A statement failed in the explicit transaction, the following statements are
+    /// rejected until `COMMIT` or `ROLLBACK`.
+    Fail,
+}
+
+/// The uncommitted changes of an explicit transaction.
+#[derive(Clone, Debug, Default)]
+struct TxnBuffer {
+    /// The uncommitted table infos, keyed by table id. They are visible to the
+    /// following statements in the same transaction.
+    mutated_tables: BTreeMap<u64, TableInfo>,
+    /// The requests to commit the mutated tables, keyed by table id.
+    update_table_metas: BTreeMap<u64, UpdateTableMetaReq>,
+    /// The uncommitted options of the consumed streams, keyed by stream id. The stream
+    /// offsets are visible to the following statements in the same transaction.
+    stream_options: BTreeMap<u64, BTreeMap<String, String>>,
+    /// The deduplicated labels of the statements in this transaction.
+    deduplicated_labels: Vec<String>,
+}
+
+/// Session scoped manager of the explicit transaction.
+#[derive(Clone, Debug)]
+pub struct TxnManager {
+    state: TxnState,
+    txn_buffer: TxnBuffer,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager {
+            state: TxnState::AutoCommit,
+            txn_buffer: TxnBuffer::default(),
+        }))
+    }
+
+    pub fn begin(&mut self) {
+        if self.state == TxnState::AutoCommit {
+            self.state = TxnState::Active;
+        }
+    }
+
+    pub fn set_fail(&mut self) {
+        if self.state == TxnState::Active {
+            self.state = TxnState::Fail;
+        }
+    }
+
+    /// Discard the buffered changes and go back to auto commit, used by both
+    /// `COMMIT` and `ROLLBACK` once they are done.
+    ///
+    /// Only the table metas are discarded. The snapshots, segments and blocks written by
+    /// a rolled back or failed transaction are not referenced by any table, they are left
+    /// as orphan files to be removed by `VACUUM TABLE`.
+    pub fn clear(&mut self) {
+        self.state = TxnState::AutoCommit;
+        self.txn_buffer = TxnBuffer::default();
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    /// Buffer the new meta of a table instead of committing it to the meta service.
+    ///
+    /// The version of the first request is kept, so that the commit fails if the table
+    /// is changed by others since it is read in this transaction.
+    pub fn update_table_meta(&mut self, mut req: UpdateTableMetaReq, table_info: &TableInfo) {
+        let mut new_table_info = table_info.clone();
+        new_table_info.meta = req.new_table_meta.clone();
+        self.txn_buffer
+            .mutated_tables
+            .insert(req.table_id, new_table_info);
+
+        // the labels are written at once on commit
+        if let Some(label) = req.deduplicated_label.take() {
+            self.txn_buffer.deduplicated_labels.push(label);
+        }
+        for stream_req in req.update_stream_meta.iter() {
+            self.txn_buffer
+                .stream_options
+                .insert(stream_req.stream_id, stream_req.options.clone());
+        }
+
+        match self.txn_buffer.update_table_metas.get_mut(&req.table_id) {
+            None => {
+                self.txn_buffer.update_table_metas.insert(req.table_id, req);
+            }
+            Some(buffered) => {
+                buffered.new_table_meta = req.new_table_meta;
+
+                if let Some(copied_files) = req.copied_files {
+                    match &mut buffered.copied_files {
+                        None => buffered.copied_files = Some(copied_files),
+                        Some(buffered_files) => {
+                            buffered_files.file_info.extend(copied_files.file_info);
+                            buffered_files.fail_if_duplicated |= copied_files.fail_if_duplicated;
+                            if copied_files.expire_at.is_some() {
+                                buffered_files.expire_at = copied_files.expire_at;
+                            }
+                        }
+                    }
+                }
+
+                for stream_req in req.update_stream_meta {
+                    match buffered
+                        .update_stream_meta
+                        .iter_mut()
+                        .find(|v| v.stream_id == stream_req.stream_id)
+                    {
+                        // keep the version that the stream is read at first
+                        Some(v) => v.options = stream_req.options,
+                        None => buffered.update_stream_meta.push(stream_req),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Get the uncommitted table info of the table, or the stream, that is mutated in
+    /// this transaction.
+    pub fn get_table_from_buffer(&self, table_info: &TableInfo) -> Option<TableInfo> {
+        let table_id = table_info.ident.table_id;
+        if let Some(table_info) = self.txn_buffer.mutated_tables.get(&table_id) {
+            return Some(table_info.clone());
+        }
+
+        // the version of the stream is kept, which is checked on commit
+        let options = self.txn_buffer.stream_options.get(&table_id)?;
+        let mut stream_info = table_info.clone();
+        stream_info.meta.options = options.clone();
+        Some(stream_info)
+    }
+
+    /// The request to commit all the buffered table metas at once.
+    pub fn req(&self) -> UpdateMultiTableMetaReq {
+        UpdateMultiTableMetaReq {
+            update_table_metas: self
+                .txn_buffer
+                .update_table_metas
+                .values()
+                .cloned()
+                .collect(),
+            deduplicated_labels: self.txn_buffer.deduplicated_labels.clone(),
+        }
+    }
+}
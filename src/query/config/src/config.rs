@@ -1649,6 +1649,16 @@ pub struct QueryConfig {
     #[clap(long)]
     pub cloud_control_grpc_server_address: Option<String>,
 
+    /// The local directory for spilling data, spilling to local disk is disabled if empty.
+    /// The query node only uses its own sub directory `databend-spill-<node_id>` in it.
+    /// The spilled data will be written to the remote storage if the local disk is full.
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub spill_local_disk_path: String,
+
+    /// Max bytes of the spilled data on the local disk, 0 means no limit.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub spill_local_disk_max_bytes: u64,
+
     #[clap(skip)]
     pub settings: HashMap<String, SettingValue>,
 }
@@ -1729,6 +1739,8 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             enable_udf_server: self.enable_udf_server,
             udf_server_allow_list: self.udf_server_allow_list,
             cloud_control_grpc_server_address: self.cloud_control_grpc_server_address,
+            spill_local_disk_path: self.spill_local_disk_path,
+            spill_local_disk_max_bytes: self.spill_local_disk_max_bytes,
             settings: self
                 .settings
                 .into_iter()
@@ -1824,6 +1836,8 @@ impl From<InnerQueryConfig> for QueryConfig {
             enable_udf_server: inner.enable_udf_server,
             udf_server_allow_list: inner.udf_server_allow_list,
             cloud_control_grpc_server_address: inner.cloud_control_grpc_server_address,
+            spill_local_disk_path: inner.spill_local_disk_path,
+            spill_local_disk_max_bytes: inner.spill_local_disk_max_bytes,
             settings: HashMap::new(),
         }
     }
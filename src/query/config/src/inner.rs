@@ -228,6 +228,11 @@ pub struct QueryConfig {
 
     pub cloud_control_grpc_server_address: Option<String>,
 
+    /// The local directory for spilling data, empty means spilling to the remote storage only.
+    pub spill_local_disk_path: String,
+    /// Max bytes of the spilled data on the local disk, 0 means no limit.
+    pub spill_local_disk_max_bytes: u64,
+
     pub settings: HashMap<String, UserSettingValue>,
 }
 
@@ -296,6 +301,8 @@ impl Default for QueryConfig {
             enable_udf_server: false,
             udf_server_allow_list: Vec::new(),
             cloud_control_grpc_server_address: None,
+            spill_local_disk_path: "".to_string(),
+            spill_local_disk_max_bytes: 0,
             data_retention_time_in_days_max: 90,
             settings: HashMap::new(),
         }
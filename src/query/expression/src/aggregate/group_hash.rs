@@ -23,6 +23,8 @@ use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
 use crate::types::DecimalDataType;
+use crate::types::GeometryType;
+use crate::types::IntervalType;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -84,8 +86,20 @@ pub fn group_hash_column(c: &Column) -> Vec<u64> {
                 .map(|x| x.fast_hash())
                 .collect()
         }
+        DataType::Geometry => {
+            let c = GeometryType::try_downcast_column(c).unwrap();
+            GeometryType::iter_column(&c)
+                .map(|x| x.fast_hash())
+                .collect()
+        }
         DataType::Timestamp => group_hash_type_column::<TimestampType>(c),
         DataType::Date => group_hash_type_column::<DateType>(c),
+        DataType::Interval => {
+            let c = IntervalType::try_downcast_column(c).unwrap();
+            IntervalType::iter_column(&c)
+                .map(|x| x.total_micros().fast_hash())
+                .collect()
+        }
         DataType::Nullable(_) => {
             let col = c.as_nullable().unwrap();
             let mut values = group_hash_column(&col.column);
@@ -27,6 +27,7 @@ use crate::types::BinaryType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::NumberColumn;
 use crate::types::NumberType;
 use crate::types::TimestampType;
@@ -40,7 +41,11 @@ pub fn rowformat_size(data_type: &DataType) -> usize {
         DataType::Null | DataType::EmptyArray | DataType::EmptyMap => 0,
         DataType::Boolean => 1,
         // use address instead
-        DataType::Binary | DataType::String | DataType::Bitmap | DataType::Variant => 4 + 8, /* u32 len + address */
+        DataType::Binary
+        | DataType::String
+        | DataType::Bitmap
+        | DataType::Variant
+        | DataType::Geometry => 4 + 8, // u32 len + address
         DataType::Number(n) => n.bit_width() as usize / 8,
         DataType::Decimal(n) => match n {
             crate::types::DecimalDataType::Decimal128(_) => 16,
@@ -48,6 +53,7 @@ pub fn rowformat_size(data_type: &DataType) -> usize {
         },
         DataType::Timestamp => 8,
         DataType::Date => 4,
+        DataType::Interval => 16,
         DataType::Nullable(_) => 4,
         DataType::Array(_) => todo!(),
         DataType::Map(_) => todo!(),
@@ -92,7 +98,7 @@ pub unsafe fn serialize_column_to_rowformat(
                 store(&v.get_bit(index), address[index].add(offset) as *mut u8);
             }
         }
-        Column::Binary(v) | Column::Bitmap(v) | Column::Variant(v) => {
+        Column::Binary(v) | Column::Bitmap(v) | Column::Variant(v) | Column::Geometry(v) => {
             for i in 0..rows {
                 let index = select_index.get_index(i);
                 let data = arena.alloc_slice_copy(v.index_unchecked(index));
@@ -130,6 +136,12 @@ pub unsafe fn serialize_column_to_rowformat(
                 store(&buffer[index], address[index].add(offset) as *mut u8);
             }
         }
+        Column::Interval(buffer) => {
+            for i in 0..rows {
+                let index = select_index.get_index(i);
+                store(&buffer[index], address[index].add(offset) as *mut u8);
+            }
+        }
         Column::Nullable(c) => serialize_column_to_rowformat(
             arena,
             &c.column,
@@ -271,8 +283,8 @@ pub unsafe fn row_match_column(
             no_match,
             no_match_count,
         ),
-        Column::Binary(v) | Column::Bitmap(v) | Column::Variant(v) => row_match_binary_column(
-            v,
+        Column::Interval(_) => row_match_column_type::<IntervalType>(
+            col,
             validity,
             address,
             select_index,
@@ -282,6 +294,19 @@ pub unsafe fn row_match_column(
             no_match,
             no_match_count,
         ),
+        Column::Binary(v) | Column::Bitmap(v) | Column::Variant(v) | Column::Geometry(v) => {
+            row_match_binary_column(
+                v,
+                validity,
+                address,
+                select_index,
+                count,
+                validity_offset,
+                col_offset,
+                no_match,
+                no_match_count,
+            )
+        }
         Column::String(v) => row_match_binary_column(
             &v.clone().into(),
             validity,
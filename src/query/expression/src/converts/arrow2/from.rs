@@ -24,10 +24,13 @@ use databend_common_exception::Result;
 use super::ARROW_EXT_TYPE_BITMAP;
 use super::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use super::ARROW_EXT_TYPE_EMPTY_MAP;
+use super::ARROW_EXT_TYPE_GEOMETRY;
+use super::ARROW_EXT_TYPE_INTERVAL;
 use super::ARROW_EXT_TYPE_VARIANT;
 use crate::types::array::ArrayColumn;
 use crate::types::binary::BinaryColumn;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::months_days_micros;
 use crate::types::nullable::NullableColumn;
 use crate::types::string::StringColumn;
 use crate::types::DataType;
@@ -141,7 +144,9 @@ fn arrow_type_to_table_type(ty: &ArrowDataType, is_nullable: bool) -> Result<Tab
             ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
             ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
             ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
+            ARROW_EXT_TYPE_GEOMETRY => TableDataType::Geometry,
             ARROW_EXT_TYPE_VARIANT => TableDataType::Variant,
+            ARROW_EXT_TYPE_INTERVAL => TableDataType::Interval,
             _ => arrow_type_to_table_type(data_type, is_nullable)?,
         },
         _ => {
@@ -518,6 +523,19 @@ impl Column {
                         .values()
                         .clone(),
                 ),
+                (DataType::Interval, ArrowDataType::Extension(name, _, None))
+                    if name == ARROW_EXT_TYPE_INTERVAL =>
+                {
+                    let values = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::Int128Array>()
+                        .expect("fail to read `Interval` from arrow: array should be `Int128Array`")
+                        .values()
+                        .clone();
+                    Column::Interval(unsafe {
+                        std::mem::transmute::<Buffer<i128>, Buffer<months_days_micros>>(values)
+                    })
+                }
                 (
                     DataType::Variant,
                     ArrowDataType::Extension(name, box ArrowDataType::Binary, None),
@@ -708,6 +726,21 @@ impl Column {
                         unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
                     Column::Bitmap(BinaryColumn::new(arrow_col.values().clone(), offsets))
                 }
+                (
+                    DataType::Geometry,
+                    ArrowDataType::Extension(name, box ArrowDataType::LargeBinary, None),
+                ) if name == ARROW_EXT_TYPE_GEOMETRY => {
+                    let arrow_col = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::BinaryArray<i64>>()
+                        .expect(
+                            "fail to read `Geometry` from arrow: array should be `BinaryArray<i64>`",
+                        );
+                    let offsets = arrow_col.offsets().clone().into_inner();
+                    let offsets =
+                        unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                    Column::Geometry(BinaryColumn::new(arrow_col.values().clone(), offsets))
+                }
                 (data_type, ArrowDataType::Extension(_, arrow_type, _)) => {
                     from_arrow_with_arrow_type(arrow_col, arrow_type, data_type)?
                 }
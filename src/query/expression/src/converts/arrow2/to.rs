@@ -23,8 +23,11 @@ use databend_common_arrow::arrow::offset::OffsetsBuffer;
 use super::ARROW_EXT_TYPE_BITMAP;
 use super::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use super::ARROW_EXT_TYPE_EMPTY_MAP;
+use super::ARROW_EXT_TYPE_GEOMETRY;
+use super::ARROW_EXT_TYPE_INTERVAL;
 use super::ARROW_EXT_TYPE_VARIANT;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::months_days_micros;
 use crate::types::DecimalDataType;
 use crate::types::NumberColumn;
 use crate::types::NumberDataType;
@@ -111,6 +114,11 @@ fn table_type_to_arrow_type(
         }
         TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
         TableDataType::Date => ArrowDataType::Date32,
+        TableDataType::Interval => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_INTERVAL.to_string(),
+            Box::new(ArrowDataType::Decimal(38, 0)),
+            None,
+        ),
         TableDataType::Nullable(ty) => {
             table_type_to_arrow_type(ty.as_ref(), true, ignore_inside_nullable)
         }
@@ -155,6 +163,11 @@ fn table_type_to_arrow_type(
             Box::new(ArrowDataType::LargeBinary),
             None,
         ),
+        TableDataType::Geometry => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_GEOMETRY.to_string(),
+            Box::new(ArrowDataType::LargeBinary),
+            None,
+        ),
         TableDataType::Tuple {
             fields_name,
             fields_type,
@@ -348,6 +361,17 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::Interval(col) => {
+                let values = unsafe {
+                    std::mem::transmute::<Buffer<months_days_micros>, Buffer<i128>>(col.clone())
+                };
+                Box::new(
+                    databend_common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
             Column::Array(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
@@ -388,7 +412,7 @@ impl Column {
                     .unwrap(),
                 )
             }
-            Column::Bitmap(col) => {
+            Column::Bitmap(col) | Column::Geometry(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets().iter().map(|offset| *offset as i64).collect();
                 Box::new(
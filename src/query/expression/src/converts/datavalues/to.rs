@@ -36,6 +36,7 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
             crate::types::number::NumberScalar::Float64(x) => DataValue::Float64((*x).into()),
         },
         Scalar::Decimal(_) => unimplemented!("decimal type is not supported"),
+        Scalar::Interval(_) => unimplemented!("interval type is not supported"),
         Scalar::Timestamp(x) => DataValue::Int64(*x),
         Scalar::Date(x) => DataValue::Int64(*x as i64),
         Scalar::Boolean(x) => DataValue::Boolean(*x),
@@ -51,7 +52,11 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
             let values = x.iter().map(scalar_to_datavalue).collect();
             DataValue::Struct(values)
         }
-        Scalar::EmptyMap | Scalar::Binary(_) | Scalar::Map(_) | Scalar::Bitmap(_) => {
+        Scalar::EmptyMap
+        | Scalar::Binary(_)
+        | Scalar::Map(_)
+        | Scalar::Bitmap(_)
+        | Scalar::Geometry(_) => {
             unimplemented!()
         }
     }
@@ -150,6 +150,8 @@ impl From<Scalar> for LegacyScalar {
             Scalar::Decimal(dec_scalar) => LegacyScalar::Decimal(dec_scalar),
             Scalar::Timestamp(ts) => LegacyScalar::Timestamp(ts),
             Scalar::Date(date) => LegacyScalar::Date(date),
+            Scalar::Interval(_) => unreachable!(),
+            Scalar::Geometry(_) => unreachable!(),
             Scalar::Boolean(b) => LegacyScalar::Boolean(b),
             Scalar::Binary(_) => unreachable!(),
             Scalar::String(string) => LegacyScalar::String(string.as_bytes().to_vec()),
@@ -175,6 +177,8 @@ impl From<Column> for LegacyColumn {
             Column::String(str_col) => LegacyColumn::String(str_col.into()),
             Column::Timestamp(buf) => LegacyColumn::Timestamp(buf),
             Column::Date(buf) => LegacyColumn::Date(buf),
+            Column::Interval(_) => unreachable!(),
+            Column::Geometry(_) => unreachable!(),
             Column::Array(arr_col) => LegacyColumn::Array(Box::new(LegacyArrayColumn {
                 values: arr_col.values.into(),
                 offsets: arr_col.offsets,
@@ -75,9 +75,11 @@ impl TryFrom<Scalar> for IndexScalar {
                     .collect::<Result<_, _>>()?,
             ),
             Scalar::Array(_)
+            | Scalar::Interval(_)
             | Scalar::Map(_)
             | Scalar::Bitmap(_)
             | Scalar::Variant(_)
+            | Scalar::Geometry(_)
             | Scalar::EmptyArray
             | Scalar::EmptyMap => return Err(()),
         })
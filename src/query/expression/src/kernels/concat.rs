@@ -38,6 +38,7 @@ use crate::types::ArrayType;
 use crate::types::BinaryType;
 use crate::types::BitmapType;
 use crate::types::BooleanType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -281,6 +282,13 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(_) => {
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_interval().unwrap()),
+                    capacity,
+                );
+                Column::Interval(builder.into())
+            }
             Column::Array(col) => {
                 let mut offsets = Vec::with_capacity(capacity + 1);
                 offsets.push(0);
@@ -309,6 +317,10 @@ impl Column {
                 columns.map(|col| col.into_bitmap().unwrap()),
                 capacity,
             )),
+            Column::Geometry(_) => GeometryType::upcast_column(Self::concat_binary_types(
+                columns.map(|col| col.into_geometry().unwrap()),
+                capacity,
+            )),
             Column::Nullable(_) => {
                 let column: Vec<Column> = columns
                     .clone()
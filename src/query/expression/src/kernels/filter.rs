@@ -139,6 +139,10 @@ impl Column {
                 let d = Self::filter_primitive_types(column, filter);
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let i = Self::filter_primitive_types(column, filter);
+                Column::Interval(i)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -168,6 +172,10 @@ impl Column {
                 let column = Self::filter_binary_scalars(column, filter);
                 Column::Bitmap(column)
             }
+            Column::Geometry(column) => {
+                let column = Self::filter_binary_scalars(column, filter);
+                Column::Geometry(column)
+            }
 
             Column::Nullable(c) => {
                 let column = Self::filter(&c.column, filter);
@@ -52,7 +52,11 @@ impl DataBlock {
         if hash_key_types.len() == 1
             && matches!(
                 hash_key_types[0],
-                DataType::Binary | DataType::String | DataType::Variant | DataType::Bitmap
+                DataType::Binary
+                    | DataType::String
+                    | DataType::Variant
+                    | DataType::Bitmap
+                    | DataType::Geometry
             )
         {
             return Ok(HashMethodKind::SingleBinary(
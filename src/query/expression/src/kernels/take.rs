@@ -25,6 +25,7 @@ use crate::types::array::ArrayColumnBuilder;
 use crate::types::binary::BinaryColumn;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -141,6 +142,10 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let builder = Self::take_primitive_types(column, indices);
+                Column::Interval(builder.into())
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(indices.len() + 1);
                 offsets.push(0);
@@ -171,6 +176,11 @@ impl Column {
                 indices,
                 string_items_buf.as_mut(),
             )),
+            Column::Geometry(column) => GeometryType::upcast_column(Self::take_binary_types(
+                column,
+                indices,
+                string_items_buf.as_mut(),
+            )),
             Column::Nullable(c) => {
                 let column = c.column.take(indices, string_items_buf);
                 let validity = Column::Boolean(Self::take_boolean_types(&c.validity, indices));
@@ -28,6 +28,7 @@ use crate::types::binary::BinaryColumn;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalColumnVec;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnVec;
@@ -40,6 +41,7 @@ use crate::types::BinaryType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberColumnVec;
 use crate::types::NumberType;
@@ -283,6 +285,10 @@ impl Column {
                 let builder = DateType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<DateType>(columns, builder, indices)
             }
+            Column::Interval(_) => {
+                let builder = IntervalType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<IntervalType>(columns, builder, indices)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(result_size + 1);
                 offsets.push(0);
@@ -311,6 +317,10 @@ impl Column {
                 let builder = BitmapType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<BitmapType>(columns, builder, indices)
             }
+            Column::Geometry(_) => {
+                let builder = GeometryType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<GeometryType>(columns, builder, indices)
+            }
             Column::Nullable(_) => {
                 let inner_ty = datatype.as_nullable().unwrap();
                 let inner_columns = columns
@@ -503,6 +513,13 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Date(columns)
             }
+            Column::Interval(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| IntervalType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Interval(columns)
+            }
             Column::Array(_) => {
                 let columns = columns
                     .iter()
@@ -524,6 +541,13 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Bitmap(columns)
             }
+            Column::Geometry(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| GeometryType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Geometry(columns)
+            }
             Column::Nullable(_) => {
                 let inner_ty = datatype.as_nullable().unwrap();
                 let inner_columns = columns
@@ -646,6 +670,10 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            ColumnVec::Interval(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                Column::Interval(builder.into())
+            }
             ColumnVec::Array(columns) => {
                 let data_type = data_type.as_array().unwrap();
                 let mut offsets = Vec::with_capacity(result_size + 1);
@@ -677,6 +705,9 @@ impl Column {
             ColumnVec::Bitmap(columns) => BitmapType::upcast_column(
                 Self::take_block_vec_binary_types(columns, indices, binary_items_buf.as_mut()),
             ),
+            ColumnVec::Geometry(columns) => GeometryType::upcast_column(
+                Self::take_block_vec_binary_types(columns, indices, binary_items_buf.as_mut()),
+            ),
             ColumnVec::Nullable(columns) => {
                 let inner_data_type = data_type.as_nullable().unwrap();
                 let inner_column = Self::take_column_vec_indices(
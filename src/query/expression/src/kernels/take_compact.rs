@@ -23,6 +23,7 @@ use crate::types::array::ArrayColumnBuilder;
 use crate::types::binary::BinaryColumn;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -135,6 +136,10 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let builder = Self::take_compacted_primitive_types(column, indices, num_rows);
+                Column::Interval(builder.into())
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(num_rows + 1);
                 offsets.push(0);
@@ -165,6 +170,9 @@ impl Column {
             Column::Bitmap(column) => BitmapType::upcast_column(Self::take_compact_binary_types(
                 column, indices, num_rows,
             )),
+            Column::Geometry(column) => GeometryType::upcast_column(
+                Self::take_compact_binary_types(column, indices, num_rows),
+            ),
             Column::Nullable(c) => {
                 let column = c.column.take_compacted_indices(indices, num_rows);
                 let validity =
@@ -722,6 +722,8 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_float64",
     "to_timestamp",
     "to_date",
+    "to_interval",
+    "to_geometry",
     "to_variant",
     "to_boolean",
     "to_decimal",
@@ -0,0 +1,262 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use databend_common_io::wkb_bounding_box;
+use databend_common_io::wkb_to_wkt;
+
+use super::binary::BinaryColumn;
+use super::binary::BinaryColumnBuilder;
+use super::binary::BinaryIterator;
+use crate::property::Domain;
+use crate::types::number::F64;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// The geometry type, stored as 2D WKB.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeometryType;
+
+/// The bounding box of the geometries, used to prune blocks by spatial predicates.
+///
+/// The bounding box is unbounded if it's unknown, and it is inverted (min > max)
+/// if there is no coordinate, e.g. all geometries are empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeometryDomain {
+    pub min_x: F64,
+    pub min_y: F64,
+    pub max_x: F64,
+    pub max_y: F64,
+}
+
+impl GeometryDomain {
+    pub fn full() -> Self {
+        GeometryDomain {
+            min_x: F64::from(f64::NEG_INFINITY),
+            min_y: F64::from(f64::NEG_INFINITY),
+            max_x: F64::from(f64::INFINITY),
+            max_y: F64::from(f64::INFINITY),
+        }
+    }
+
+    pub fn empty() -> Self {
+        GeometryDomain {
+            min_x: F64::from(f64::INFINITY),
+            min_y: F64::from(f64::INFINITY),
+            max_x: F64::from(f64::NEG_INFINITY),
+            max_y: F64::from(f64::NEG_INFINITY),
+        }
+    }
+
+    /// The bounding box of a WKB encoded geometry, invalid geometries are treated as unknown.
+    pub fn from_wkb(wkb: &[u8]) -> Self {
+        match wkb_bounding_box(wkb) {
+            Ok(Some(rect)) => GeometryDomain {
+                min_x: F64::from(rect.min().x),
+                min_y: F64::from(rect.min().y),
+                max_x: F64::from(rect.max().x),
+                max_y: F64::from(rect.max().y),
+            },
+            Ok(None) => GeometryDomain::empty(),
+            Err(_) => GeometryDomain::full(),
+        }
+    }
+
+    pub fn merge(&self, other: &Self) -> Self {
+        GeometryDomain {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+}
+
+impl ValueType for GeometryType {
+    type Scalar = Vec<u8>;
+    type ScalarRef<'a> = &'a [u8];
+    type Column = BinaryColumn;
+    type Domain = GeometryDomain;
+    type ColumnIterator<'a> = BinaryIterator<'a>;
+    type ColumnBuilder = BinaryColumnBuilder;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: Self::ScalarRef<'long>) -> Self::ScalarRef<'short> {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar.to_vec()
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        scalar.as_geometry().cloned()
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        col.as_geometry().cloned()
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Geometry(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Geometry(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(builder: Self::ColumnBuilder) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Geometry(builder))
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        domain.as_geometry().cloned()
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Geometry(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Geometry(col)
+    }
+
+    fn upcast_domain(domain: Self::Domain) -> Domain {
+        Domain::Geometry(domain)
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.index(index)
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        col.index_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.slice(range)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        BinaryColumnBuilder::from_column(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>) {
+        builder.put_slice(item);
+        builder.commit_row();
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.commit_row();
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.append_column(other)
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.build()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        builder.build_scalar()
+    }
+
+    fn scalar_memory_size(scalar: &Self::ScalarRef<'_>) -> usize {
+        scalar.len()
+    }
+
+    fn column_memory_size(col: &Self::Column) -> usize {
+        col.data().len() + col.offsets().len() * 8
+    }
+}
+
+impl ArgType for GeometryType {
+    fn data_type() -> DataType {
+        DataType::Geometry
+    }
+
+    fn full_domain() -> Self::Domain {
+        GeometryDomain::full()
+    }
+
+    fn create_builder(capacity: usize, _: &GenericMap) -> Self::ColumnBuilder {
+        BinaryColumnBuilder::with_capacity(capacity, 0)
+    }
+}
+
+impl GeometryType {
+    pub fn column_domain(col: &BinaryColumn) -> GeometryDomain {
+        col.iter()
+            .filter(|wkb| !wkb.is_empty())
+            .fold(GeometryDomain::empty(), |acc, wkb| {
+                acc.merge(&GeometryDomain::from_wkb(wkb))
+            })
+    }
+}
+
+/// Display a WKB encoded geometry as WKT.
+pub fn geometry_to_string(wkb: &[u8]) -> impl Display + '_ {
+    struct GeometryDisplay<'a>(&'a [u8]);
+
+    impl Display for GeometryDisplay<'_> {
+        fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+            match wkb_to_wkt(self.0) {
+                Ok(wkt) => write!(f, "{wkt}"),
+                Err(_) => write!(f, "{:?}", self.0),
+            }
+        }
+    }
+
+    GeometryDisplay(wkb)
+}
@@ -0,0 +1,529 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Range;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::buffer::Buffer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_IN_A_DAY: i64 = 24 * 3600 * MICROS_IN_A_SEC;
+/// The number of days in a month when comparing intervals or splitting fractional months.
+pub const DAYS_IN_A_MONTH: i64 = 30;
+
+/// The value of interval, composed of months, days and microseconds, which are packed
+/// into an `i128` as `months (32 bits) | days (32 bits) | micros (64 bits)`.
+///
+/// The parts are kept separately since the length of months and days varies with the
+/// date they are added to. Intervals are compared by the total length taking a month
+/// as 30 days, so `1 month` is equal to `30 days`.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[repr(transparent)]
+pub struct months_days_micros(pub i128);
+
+impl months_days_micros {
+    pub fn new(months: i32, days: i32, micros: i64) -> Self {
+        let months = (months as i128) << 96;
+        let days = (days as u32 as i128) << 64;
+        let micros = micros as u64 as i128;
+        months_days_micros(months | days | micros)
+    }
+
+    #[inline]
+    pub fn months(&self) -> i32 {
+        (self.0 >> 96) as i32
+    }
+
+    #[inline]
+    pub fn days(&self) -> i32 {
+        (self.0 >> 64) as i32
+    }
+
+    #[inline]
+    pub fn micros(&self) -> i64 {
+        self.0 as i64
+    }
+
+    /// The total length in microseconds, taking a month as 30 days.
+    #[inline]
+    pub fn total_micros(&self) -> i128 {
+        (self.months() as i128 * DAYS_IN_A_MONTH as i128 + self.days() as i128)
+            * MICROS_IN_A_DAY as i128
+            + self.micros() as i128
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_add(other.months())?,
+            self.days().checked_add(other.days())?,
+            self.micros().checked_add(other.micros())?,
+        ))
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_sub(other.months())?,
+            self.days().checked_sub(other.days())?,
+            self.micros().checked_sub(other.micros())?,
+        ))
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_neg()?,
+            self.days().checked_neg()?,
+            self.micros().checked_neg()?,
+        ))
+    }
+
+    pub fn checked_mul(&self, factor: i64) -> Option<Self> {
+        Some(Self::new(
+            i32::try_from((self.months() as i64).checked_mul(factor)?).ok()?,
+            i32::try_from((self.days() as i64).checked_mul(factor)?).ok()?,
+            self.micros().checked_mul(factor)?,
+        ))
+    }
+
+    /// Divide each part by the divisor, the remainder of months and days
+    /// is carried down to days and microseconds.
+    pub fn checked_div(&self, divisor: i64) -> Option<Self> {
+        if divisor == 0 {
+            return None;
+        }
+        let months = self.months() as i64;
+        let days = self.days() as i64 + (months % divisor) * DAYS_IN_A_MONTH;
+        let micros = self.micros() as i128 + (days % divisor) as i128 * MICROS_IN_A_DAY as i128;
+        Some(Self::new(
+            i32::try_from(months / divisor).ok()?,
+            i32::try_from(days / divisor).ok()?,
+            i64::try_from(micros / divisor as i128).ok()?,
+        ))
+    }
+}
+
+impl PartialEq for months_days_micros {
+    fn eq(&self, other: &Self) -> bool {
+        self.total_micros() == other.total_micros()
+    }
+}
+
+impl Eq for months_days_micros {}
+
+impl PartialOrd for months_days_micros {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for months_days_micros {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_micros().cmp(&other.total_micros())
+    }
+}
+
+impl Hash for months_days_micros {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.total_micros().hash(state)
+    }
+}
+
+/// Display the interval like `1 year 2 months 3 days 04:05:06.789`.
+impl Display for months_days_micros {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let mut parts = vec![];
+        let mut push_part = |n: i64, unit: &str| {
+            if n != 0 {
+                let plural = if n.abs() == 1 { "" } else { "s" };
+                parts.push(format!("{n} {unit}{plural}"));
+            }
+        };
+        push_part(self.months() as i64 / 12, "year");
+        push_part(self.months() as i64 % 12, "month");
+        push_part(self.days() as i64, "day");
+
+        let micros = self.micros();
+        if micros != 0 || parts.is_empty() {
+            let sign = if micros < 0 { "-" } else { "" };
+            let secs = micros.unsigned_abs() / MICROS_IN_A_SEC as u64;
+            let frac = micros.unsigned_abs() % MICROS_IN_A_SEC as u64;
+            let mut time = format!(
+                "{sign}{:02}:{:02}:{:02}",
+                secs / 3600,
+                secs / 60 % 60,
+                secs % 60
+            );
+            if frac != 0 {
+                let frac = format!("{frac:06}");
+                time.push('.');
+                time.push_str(frac.trim_end_matches('0'));
+            }
+            parts.push(time);
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = months_days_micros;
+    type ScalarRef<'a> = months_days_micros;
+    type Column = Buffer<months_days_micros>;
+    type Domain = SimpleDomain<months_days_micros>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, months_days_micros>>;
+    type ColumnBuilder = Vec<months_days_micros>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: months_days_micros) -> months_days_micros {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<months_days_micros>> {
+        domain.as_interval().cloned()
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(builder: Self::ColumnBuilder) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Interval(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<months_days_micros>) -> Domain {
+        Domain::Interval(domain)
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.get(index).cloned()
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        debug_assert!(index < col.len());
+
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+
+    #[inline(always)]
+    fn equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left == right
+    }
+
+    #[inline(always)]
+    fn not_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left != right
+    }
+
+    #[inline(always)]
+    fn greater_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left > right
+    }
+
+    #[inline(always)]
+    fn greater_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left >= right
+    }
+
+    #[inline(always)]
+    fn less_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left < right
+    }
+
+    #[inline(always)]
+    fn less_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left <= right
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: months_days_micros::new(i32::MIN, i32::MIN, i64::MIN),
+            max: months_days_micros::new(i32::MAX, i32::MAX, i64::MAX),
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+enum IntervalUnit {
+    Months(i64),
+    Days(i64),
+    Micros(i64),
+}
+
+fn interval_unit(unit: &str) -> Option<IntervalUnit> {
+    let unit = match unit {
+        "millennium" | "millennia" | "millenniums" => IntervalUnit::Months(12000),
+        "century" | "centuries" => IntervalUnit::Months(1200),
+        "decade" | "decades" => IntervalUnit::Months(120),
+        "year" | "years" | "yr" | "yrs" | "y" => IntervalUnit::Months(12),
+        "quarter" | "quarters" => IntervalUnit::Months(3),
+        "month" | "months" | "mon" | "mons" => IntervalUnit::Months(1),
+        "week" | "weeks" | "w" => IntervalUnit::Days(7),
+        "day" | "days" | "d" => IntervalUnit::Days(1),
+        "hour" | "hours" | "hr" | "hrs" | "h" => IntervalUnit::Micros(3600 * MICROS_IN_A_SEC),
+        "minute" | "minutes" | "min" | "mins" | "m" => IntervalUnit::Micros(60 * MICROS_IN_A_SEC),
+        "second" | "seconds" | "sec" | "secs" | "s" => IntervalUnit::Micros(MICROS_IN_A_SEC),
+        "millisecond" | "milliseconds" | "msec" | "msecs" | "ms" => IntervalUnit::Micros(1000),
+        "microsecond" | "microseconds" | "usec" | "usecs" | "us" => IntervalUnit::Micros(1),
+        _ => return None,
+    };
+    Some(unit)
+}
+
+/// Parse the time part like `[-]hh:mm[:ss[.ffffff]]` into microseconds.
+fn parse_time(time: &str) -> Option<i128> {
+    let (negative, time) = match time.strip_prefix('-') {
+        Some(time) => (true, time),
+        None => (false, time.strip_prefix('+').unwrap_or(time)),
+    };
+    let parts = time.split(':').collect::<Vec<_>>();
+    if parts.len() < 2 || parts.len() > 3 {
+        return None;
+    }
+    let hours = parts[0].parse::<u32>().ok()? as i128;
+    let minutes = parts[1].parse::<u32>().ok()? as i128;
+    let (seconds, frac) = match parts.get(2) {
+        Some(seconds) => match seconds.split_once('.') {
+            Some((seconds, frac)) => (seconds, frac),
+            None => (*seconds, ""),
+        },
+        None => ("0", ""),
+    };
+    let seconds = seconds.parse::<u32>().ok()? as i128;
+    if minutes >= 60 || seconds >= 60 || frac.len() > 6 {
+        return None;
+    }
+    let frac = if frac.is_empty() {
+        0
+    } else {
+        format!("{frac:0<6}").parse::<u32>().ok()? as i128
+    };
+    let micros = ((hours * 60 + minutes) * 60 + seconds) * MICROS_IN_A_SEC as i128 + frac;
+    Some(if negative { -micros } else { micros })
+}
+
+/// Parse interval from strings like `1 year 2 months 3 days 04:05:06.789`, `2 hours ago`
+/// or `1.5 days`, a number without unit is taken as seconds.
+pub fn string_to_interval(interval_str: &str) -> Result<months_days_micros, String> {
+    let invalid = || format!("invalid interval '{interval_str}'");
+
+    let mut tokens = interval_str
+        .split_whitespace()
+        .map(|token| token.to_ascii_lowercase())
+        .collect::<Vec<_>>();
+    if tokens.first().is_some_and(|token| token == "@") {
+        tokens.remove(0);
+    }
+    let ago = tokens.last().is_some_and(|token| token == "ago");
+    if ago {
+        tokens.pop();
+    }
+    if tokens.is_empty() {
+        return Err(invalid());
+    }
+
+    let (mut months, mut days, mut micros) = (0i128, 0i128, 0i128);
+    let mut tokens = tokens.iter().peekable();
+    while let Some(token) = tokens.next() {
+        if token.contains(':') {
+            micros += parse_time(token).ok_or_else(invalid)?;
+            continue;
+        }
+
+        // The unit may be attached to the number, such as `10s`.
+        let pos = token
+            .find(|c: char| c.is_ascii_alphabetic())
+            .unwrap_or(token.len());
+        let (number, unit) = token.split_at(pos);
+        let unit = match unit {
+            "" => match tokens.peek() {
+                Some(next) if next.chars().all(|c| c.is_ascii_alphabetic()) => {
+                    tokens.next().unwrap().as_str()
+                }
+                _ => "second",
+            },
+            unit => unit,
+        };
+        let unit = interval_unit(unit).ok_or_else(invalid)?;
+
+        if let Ok(n) = number.parse::<i64>() {
+            match unit {
+                IntervalUnit::Months(factor) => months += n as i128 * factor as i128,
+                IntervalUnit::Days(factor) => days += n as i128 * factor as i128,
+                IntervalUnit::Micros(factor) => micros += n as i128 * factor as i128,
+            }
+            continue;
+        }
+        // The fractional part of months and days are carried down to the smaller parts.
+        let n = number.parse::<f64>().map_err(|_| invalid())?;
+        if !n.is_finite() {
+            return Err(invalid());
+        }
+        let mut frac_days = match unit {
+            IntervalUnit::Months(factor) => {
+                let total = n * factor as f64;
+                months += total.trunc() as i128;
+                total.fract() * DAYS_IN_A_MONTH as f64
+            }
+            IntervalUnit::Days(factor) => n * factor as f64,
+            IntervalUnit::Micros(factor) => {
+                micros += (n * factor as f64).round() as i128;
+                0.0
+            }
+        };
+        days += frac_days.trunc() as i128;
+        frac_days = frac_days.fract();
+        micros += (frac_days * MICROS_IN_A_DAY as f64).round() as i128;
+    }
+
+    if ago {
+        (months, days, micros) = (-months, -days, -micros);
+    }
+    let months = i32::try_from(months).map_err(|_| invalid())?;
+    let days = i32::try_from(days).map_err(|_| invalid())?;
+    let micros = i64::try_from(micros).map_err(|_| invalid())?;
+    Ok(months_days_micros::new(months, days, micros))
+}
+
+#[inline]
+pub fn interval_to_string(interval: months_days_micros) -> impl Display {
+    interval
+}
@@ -15,12 +15,15 @@
 use core::cmp::Ordering;
 use std::ops::Range;
 
+use databend_common_io::geometry_from_wkb;
+use databend_common_io::geometry_to_geojson;
 use roaring::RoaringTreemap;
 
 use super::binary::BinaryColumn;
 use super::binary::BinaryColumnBuilder;
 use super::binary::BinaryIterator;
 use super::date::date_to_string;
+use super::interval::interval_to_string;
 use super::number::NumberScalar;
 use super::timestamp::timestamp_to_string;
 use crate::date_helper::TzLUT;
@@ -210,6 +213,7 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
         ScalarRef::String(s) => jsonb::Value::String(s.into()),
         ScalarRef::Timestamp(ts) => timestamp_to_string(ts, inner_tz).to_string().into(),
         ScalarRef::Date(d) => date_to_string(d, inner_tz).to_string().into(),
+        ScalarRef::Interval(i) => interval_to_string(i).to_string().into(),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
             jsonb::build_array(items.iter(), buf).expect("failed to build jsonb array");
@@ -227,6 +231,7 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
                         ScalarRef::Boolean(v) => v.to_string(),
                         ScalarRef::Timestamp(v) => timestamp_to_string(v, inner_tz).to_string(),
                         ScalarRef::Date(v) => date_to_string(v, inner_tz).to_string(),
+                        ScalarRef::Interval(v) => interval_to_string(v).to_string(),
                         _ => unreachable!(),
                     };
                     let mut val = vec![];
@@ -265,6 +270,14 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
             buf.extend_from_slice(bytes);
             return;
         }
+        // Geometries are cast to GeoJSON objects.
+        ScalarRef::Geometry(bytes) => match geometry_from_wkb(bytes) {
+            Ok(geometry) => {
+                let geojson = geometry_to_geojson(&geometry);
+                jsonb::parse_value(geojson.as_bytes()).expect("failed to parse GeoJSON")
+            }
+            Err(_) => jsonb::Value::Null,
+        },
     };
     value.write_to_vec(buf);
 }
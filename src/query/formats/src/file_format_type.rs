@@ -20,6 +20,7 @@ use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_settings::Settings;
 
+use crate::output_format::ArrowOutputFormat;
 use crate::output_format::CSVOutputFormat;
 use crate::output_format::CSVWithNamesAndTypesOutputFormat;
 use crate::output_format::CSVWithNamesOutputFormat;
@@ -46,6 +47,7 @@ pub struct FileFormatOptionsExt {
     pub timezone: Tz,
     pub is_select: bool,
     pub is_clickhouse: bool,
+    pub arrow_stream: bool,
 }
 
 impl FileFormatOptionsExt {
@@ -63,6 +65,7 @@ impl FileFormatOptionsExt {
             timezone,
             is_select,
             is_clickhouse: false,
+            arrow_stream: false,
         };
         Ok(options)
     }
@@ -81,9 +84,11 @@ impl FileFormatOptionsExt {
             timezone,
             is_select: false,
             is_clickhouse: true,
+            arrow_stream: false,
         };
         let suf = &clickhouse_type.suffixes;
         options.headers = suf.headers;
+        options.arrow_stream = suf.is_stream;
         if let Some(json) = &suf.json {
             options.json_compact = json.is_compact;
             options.json_strings = json.is_strings;
@@ -168,6 +173,7 @@ impl FileFormatOptionsExt {
             }
             FileFormatParams::Parquet(_) => Box::new(ParquetOutputFormat::create(schema, self)),
             FileFormatParams::Json(_) => Box::new(JSONOutputFormat::create(schema, self)),
+            FileFormatParams::Arrow(_) => Box::new(ArrowOutputFormat::create(schema, self)),
             others => {
                 return Err(ErrorCode::InvalidArgument(format!(
                     "Unsupported output file format:{:?}",
@@ -184,7 +190,7 @@ impl FileFormatTypeExt for StageFileFormatType {
         match self {
             StageFileFormatType::Tsv => "text/tab-separated-values; charset=UTF-8",
             StageFileFormatType::Csv => "text/csv; charset=UTF-8",
-            StageFileFormatType::Parquet => "application/octet-stream",
+            StageFileFormatType::Parquet | StageFileFormatType::Arrow => "application/octet-stream",
             StageFileFormatType::NdJson => "application/x-ndjson; charset=UTF-8",
             StageFileFormatType::Json => "application/json; charset=UTF-8",
             _ => "text/plain; charset=UTF-8",
@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_ipc::writer::DictionaryTracker;
+use arrow_ipc::writer::FileWriter;
+use arrow_ipc::writer::IpcDataGenerator;
+use arrow_ipc::writer::IpcWriteOptions;
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use databend_common_exception::Result;
+use databend_common_expression::converts::arrow::table_schema_to_arrow_schema_ignore_inside_nullable;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
+
+use crate::output_format::OutputFormat;
+use crate::FileFormatOptionsExt;
+
+/// The end-of-stream marker of the arrow IPC streaming format:
+/// a continuation token followed by a zero metadata length.
+const ARROW_STREAM_EOS: [u8; 8] = [0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0];
+
+/// Output format of arrow IPC.
+///
+/// The file variant needs a footer which indexes all the record batches, so the blocks are
+/// buffered and written in `finalize`, like parquet. The stream variant writes one message
+/// per block, which fits the results sent over http.
+pub struct ArrowOutputFormat {
+    arrow_schema: ArrowSchemaRef,
+    data_schema: DataSchema,
+    stream: bool,
+    data_blocks: Vec<DataBlock>,
+    options: IpcWriteOptions,
+    data_gen: IpcDataGenerator,
+    dictionary_tracker: DictionaryTracker,
+}
+
+impl ArrowOutputFormat {
+    pub fn create(schema: TableSchemaRef, options: &FileFormatOptionsExt) -> Self {
+        let arrow_schema = Arc::new(table_schema_to_arrow_schema_ignore_inside_nullable(&schema));
+        Self {
+            arrow_schema,
+            data_schema: DataSchema::from(schema.as_ref()),
+            stream: options.arrow_stream,
+            data_blocks: vec![],
+            options: IpcWriteOptions::default(),
+            data_gen: IpcDataGenerator::default(),
+            dictionary_tracker: DictionaryTracker::new(false),
+        }
+    }
+}
+
+impl OutputFormat for ArrowOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        if !self.stream {
+            self.data_blocks.push(block.clone());
+            return Ok(vec![]);
+        }
+
+        let batch = block.clone().to_record_batch(&self.data_schema)?;
+        let (encoded_dictionaries, encoded_batch) =
+            self.data_gen
+                .encoded_batch(&batch, &mut self.dictionary_tracker, &self.options)?;
+        let mut buf = Vec::with_capacity(block.memory_size());
+        for encoded in encoded_dictionaries {
+            arrow_ipc::writer::write_message(&mut buf, encoded, &self.options)?;
+        }
+        arrow_ipc::writer::write_message(&mut buf, encoded_batch, &self.options)?;
+        Ok(buf)
+    }
+
+    fn serialize_prefix(&self) -> Result<Vec<u8>> {
+        if !self.stream {
+            return Ok(vec![]);
+        }
+        let mut buf = vec![];
+        let encoded = self
+            .data_gen
+            .schema_to_bytes(&self.arrow_schema, &self.options);
+        arrow_ipc::writer::write_message(&mut buf, encoded, &self.options)?;
+        Ok(buf)
+    }
+
+    fn buffer_size(&mut self) -> usize {
+        self.data_blocks.iter().map(|b| b.memory_size()).sum()
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        if self.stream {
+            return Ok(ARROW_STREAM_EOS.to_vec());
+        }
+
+        // Unlike parquet, a file without any record batch is still written,
+        // so that an empty result is a valid arrow file with the schema.
+        let blocks = std::mem::take(&mut self.data_blocks);
+        let mut buf = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
+        let mut writer = FileWriter::try_new(&mut buf, &self.arrow_schema)?;
+        for block in blocks {
+            let batch = block.to_record_batch(&self.data_schema)?;
+            writer.write(&batch)?;
+        }
+        writer.finish()?;
+        Ok(buf)
+    }
+}
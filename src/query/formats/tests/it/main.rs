@@ -21,6 +21,7 @@ use databend_common_settings::Settings;
 
 mod field_decoder;
 mod field_encoder;
+mod output_format_arrow;
 mod output_format_json_each_row;
 mod output_format_tcsv;
 mod output_format_utils;
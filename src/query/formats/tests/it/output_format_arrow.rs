@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::io::Cursor;
+
+use arrow_ipc::reader::FileReader;
+use arrow_ipc::reader::StreamReader;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::TableSchema;
+use databend_common_formats::FileFormatOptionsExt;
+use databend_common_meta_app::principal::FileFormatOptionsAst;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_settings::Settings;
+use pretty_assertions::assert_eq;
+
+use crate::get_output_format_clickhouse;
+use crate::output_format_utils::get_simple_block;
+
+fn field_names(schema: &TableSchema) -> Vec<String> {
+    schema.fields().iter().map(|f| f.name().clone()).collect()
+}
+
+fn assert_block_eq(got: &DataBlock, want: &DataBlock) {
+    assert_eq!(got.num_rows(), want.num_rows());
+    assert_eq!(got.num_columns(), want.num_columns());
+    for (got, want) in got.columns().iter().zip(want.columns()) {
+        assert_eq!(got.value.as_column(), want.value.as_column());
+    }
+}
+
+fn test_arrow_file(is_nullable: bool) -> Result<()> {
+    let (schema, block) = get_simple_block(is_nullable);
+
+    let settings = Settings::create("default".to_string());
+    let mut options = BTreeMap::<String, String>::new();
+    options.insert("type".to_string(), "arrow".to_string());
+    let params = FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options), false)?;
+    let mut options = FileFormatOptionsExt::create_from_settings(&settings, false)?;
+    let mut output_format = options.get_output_format(schema.clone(), params)?;
+
+    // The blocks are buffered until the footer is written.
+    assert!(output_format.serialize_prefix()?.is_empty());
+    assert!(output_format.serialize_block(&block)?.is_empty());
+    assert!(output_format.serialize_block(&block)?.is_empty());
+    let buffer = output_format.finalize()?;
+
+    let reader = FileReader::try_new(Cursor::new(buffer), None)?;
+    let file_schema = TableSchema::try_from(reader.schema().as_ref())?;
+    assert_eq!(field_names(&file_schema), field_names(&schema));
+
+    let data_schema = DataSchema::from(&file_schema);
+    let mut num_batches = 0;
+    for batch in reader {
+        let (got, _) = DataBlock::from_record_batch(&data_schema, &batch?)?;
+        assert_block_eq(&got, &block);
+        num_batches += 1;
+    }
+    assert_eq!(num_batches, 2);
+    Ok(())
+}
+
+fn test_arrow_stream(is_nullable: bool) -> Result<()> {
+    let (schema, block) = get_simple_block(is_nullable);
+
+    let mut output_format = get_output_format_clickhouse("ArrowStream", schema.clone())?;
+    let mut buffer = output_format.serialize_prefix()?;
+    // Each block is written out as a message immediately.
+    let message = output_format.serialize_block(&block)?;
+    assert!(!message.is_empty());
+    buffer.extend(message);
+    buffer.extend(output_format.serialize_block(&block)?);
+    buffer.extend(output_format.finalize()?);
+
+    let reader = StreamReader::try_new(Cursor::new(buffer), None)?;
+    let file_schema = TableSchema::try_from(reader.schema().as_ref())?;
+    assert_eq!(field_names(&file_schema), field_names(&schema));
+
+    let data_schema = DataSchema::from(&file_schema);
+    let mut num_batches = 0;
+    for batch in reader {
+        let (got, _) = DataBlock::from_record_batch(&data_schema, &batch?)?;
+        assert_block_eq(&got, &block);
+        num_batches += 1;
+    }
+    assert_eq!(num_batches, 2);
+    Ok(())
+}
+
+#[test]
+fn test_arrow_file_nullable() -> Result<()> {
+    test_arrow_file(true)
+}
+
+#[test]
+fn test_arrow_file_not_nullable() -> Result<()> {
+    test_arrow_file(false)
+}
+
+#[test]
+fn test_arrow_stream_nullable() -> Result<()> {
+    test_arrow_stream(true)
+}
+
+#[test]
+fn test_arrow_stream_not_nullable() -> Result<()> {
+    test_arrow_stream(false)
+}
+
+#[test]
+fn test_arrow_file_without_blocks() -> Result<()> {
+    let (schema, _) = get_simple_block(false);
+
+    let mut output_format = get_output_format_clickhouse("Arrow", schema.clone())?;
+    let buffer = output_format.finalize()?;
+
+    let reader = FileReader::try_new(Cursor::new(buffer), None)?;
+    let file_schema = TableSchema::try_from(reader.schema().as_ref())?;
+    assert_eq!(field_names(&file_schema), field_names(&schema));
+    assert_eq!(reader.num_batches(), 0);
+    Ok(())
+}
@@ -0,0 +1,407 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use databend_common_exception::Result;
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::boolean::BooleanDomain;
+use databend_common_expression::types::geometry::geometry_to_string;
+use databend_common_expression::types::geometry::GeometryDomain;
+use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::number::Float64Type;
+use databend_common_expression::types::number::F64;
+use databend_common_expression::types::string::StringDomain;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::GeometryType;
+use databend_common_expression::types::NullableType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::VariantType;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+use databend_common_io::geometry_from_geojson;
+use databend_common_io::geometry_from_wkb;
+use databend_common_io::geometry_from_wkt;
+use databend_common_io::geometry_to_geojson;
+use databend_common_io::geometry_to_wkb;
+use databend_common_io::parse_to_wkb;
+use databend_common_io::point_to_wkb;
+use geo::Area;
+use geo::ConvexHull;
+use geo::Coord;
+use geo::CoordsIter;
+use geo::EuclideanDistance;
+use geo::Geometry;
+use geo::Line;
+use geo::LinesIter;
+use geo::MultiPoint;
+use geo::Point;
+use geo::Relate;
+
+/// The number of segments used to approximate a circle in `st_buffer`.
+const BUFFER_CIRCLE_SEGMENTS: usize = 32;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS geometry)
+    // to_geometry(xx)
+    register_to_geometry(registry);
+
+    // cast(geometry AS string)
+    // to_string(geometry)
+    register_geometry_to_string(registry);
+
+    // st_geomfromtext, st_geomfromwkb, st_geomfromgeojson, st_makepoint
+    register_geometry_constructors(registry);
+
+    // st_astext, st_aswkb, st_asgeojson
+    register_geometry_outputs(registry);
+
+    // st_contains, st_intersects
+    register_spatial_predicates(registry);
+
+    // st_distance, st_area, st_buffer
+    register_spatial_measures(registry);
+}
+
+fn register_to_geometry(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "to_geometry",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_geometry,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "try_to_geometry",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_geometry),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, GeometryType, _, _>(
+        "to_geometry",
+        |_, _| FunctionDomain::MayThrow,
+        eval_wkb_to_geometry,
+    );
+    registry.register_combine_nullable_1_arg::<BinaryType, GeometryType, _, _>(
+        "try_to_geometry",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_wkb_to_geometry),
+    );
+}
+
+fn register_geometry_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|val, output, _| {
+            write!(output.data, "{}", geometry_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "try_to_string",
+        |_, _| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: "".to_string(),
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<GeometryType, NullableType<StringType>>(|val, output, _| {
+            write!(output.builder.data, "{}", geometry_to_string(val)).unwrap();
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
+}
+
+fn register_geometry_constructors(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromtext",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|val, output, ctx| {
+            let wkb = geometry_from_wkt(val).map(|geometry| geometry_to_wkb(&geometry));
+            push_wkb(wkb, output, ctx)
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, GeometryType, _, _>(
+        "st_geomfromwkb",
+        |_, _| FunctionDomain::MayThrow,
+        eval_wkb_to_geometry,
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|val, output, ctx| {
+            let wkb = geometry_from_geojson(val).map(|geometry| geometry_to_wkb(&geometry));
+            push_wkb(wkb, output, ctx)
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<Float64Type, Float64Type, GeometryType, _, _>(
+        "st_makepoint",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<Float64Type, Float64Type, GeometryType>(
+            |x, y, output, _| {
+                output.put_slice(&point_to_wkb(x.0, y.0));
+                output.commit_row();
+            },
+        ),
+    );
+}
+
+fn register_geometry_outputs(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_astext",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|val, output, _| {
+            write!(output.data, "{}", geometry_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, BinaryType, _, _>(
+        "st_aswkb",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<GeometryType, BinaryType>(|val, output, _| {
+            output.put_slice(val);
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, VariantType, _, _>(
+        "st_asgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, VariantType>(|val, output, ctx| {
+            if let Some(geometry) = read_geometry(val, output.len(), ctx) {
+                let geojson = geometry_to_geojson(&geometry);
+                let value = jsonb::parse_value(geojson.as_bytes()).unwrap();
+                value.write_to_vec(&mut output.data);
+            }
+            output.commit_row();
+        }),
+    );
+}
+
+fn register_spatial_predicates(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_contains",
+        |_, a, b| calc_spatial_predicate_domain(a, b),
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |a, b, output, ctx| {
+                let row = output.len();
+                let res = match (read_geometry(a, row, ctx), read_geometry(b, row, ctx)) {
+                    (Some(a), Some(b)) => a.relate(&b).is_contains(),
+                    _ => false,
+                };
+                output.push(res);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_intersects",
+        |_, a, b| calc_spatial_predicate_domain(a, b),
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |a, b, output, ctx| {
+                let row = output.len();
+                let res = match (read_geometry(a, row, ctx), read_geometry(b, row, ctx)) {
+                    (Some(a), Some(b)) => a.relate(&b).is_intersects(),
+                    _ => false,
+                };
+                output.push(res);
+            },
+        ),
+    );
+}
+
+fn register_spatial_measures(registry: &mut FunctionRegistry) {
+    registry.register_combine_nullable_2_arg::<GeometryType, GeometryType, Float64Type, _, _>(
+        "st_distance",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, NullableType<Float64Type>>(
+            |a, b, output, ctx| {
+                let row = output.len();
+                match (read_geometry(a, row, ctx), read_geometry(b, row, ctx)) {
+                    (Some(a), Some(b)) => match geometry_distance(&a, &b) {
+                        Some(distance) => output.push(F64::from(distance)),
+                        None => output.push_null(),
+                    },
+                    _ => output.push_null(),
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Float64Type, _, _>(
+        "st_area",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, Float64Type>(|val, output, ctx| {
+            let area = read_geometry(val, output.len(), ctx)
+                .map(|geometry| geometry.unsigned_area())
+                .unwrap_or_default();
+            output.push(F64::from(area));
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, Float64Type, GeometryType, _, _>(
+        "st_buffer",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, Float64Type, GeometryType>(
+            |val, distance, output, ctx| {
+                if !distance.0.is_finite() || distance.0 < 0.0 {
+                    ctx.set_error(
+                        output.len(),
+                        "st_buffer distance must be a non-negative number",
+                    );
+                } else if let Some(geometry) = read_geometry(val, output.len(), ctx) {
+                    let buffer = geometry_buffer(&geometry, distance.0);
+                    output.put_slice(&geometry_to_wkb(&buffer));
+                }
+                output.commit_row();
+            },
+        ),
+    );
+}
+
+fn eval_string_to_geometry(
+    val: ValueRef<StringType>,
+    ctx: &mut EvalContext,
+) -> Value<GeometryType> {
+    vectorize_with_builder_1_arg::<StringType, GeometryType>(|val, output, ctx| {
+        push_wkb(parse_to_wkb(val.as_bytes()), output, ctx)
+    })(val, ctx)
+}
+
+fn eval_wkb_to_geometry(val: ValueRef<BinaryType>, ctx: &mut EvalContext) -> Value<GeometryType> {
+    vectorize_with_builder_1_arg::<BinaryType, GeometryType>(|val, output, ctx| {
+        let wkb = geometry_from_wkb(val).map(|geometry| geometry_to_wkb(&geometry));
+        push_wkb(wkb, output, ctx)
+    })(val, ctx)
+}
+
+/// The spatial predicates can only be true if the bounding boxes intersect,
+/// which is used to prune the blocks by the bounding box statistics.
+fn calc_spatial_predicate_domain(
+    a: &GeometryDomain,
+    b: &GeometryDomain,
+) -> FunctionDomain<BooleanType> {
+    if a.intersects(b) {
+        FunctionDomain::MayThrow
+    } else {
+        FunctionDomain::Domain(BooleanDomain {
+            has_false: true,
+            has_true: false,
+        })
+    }
+}
+
+fn read_geometry(wkb: &[u8], row: usize, ctx: &mut EvalContext) -> Option<Geometry<f64>> {
+    match geometry_from_wkb(wkb) {
+        Ok(geometry) => Some(geometry),
+        Err(err) => {
+            ctx.set_error(row, err.message());
+            None
+        }
+    }
+}
+
+fn push_wkb(wkb: Result<Vec<u8>>, output: &mut BinaryColumnBuilder, ctx: &mut EvalContext) {
+    match wkb {
+        Ok(wkb) => output.put_slice(&wkb),
+        Err(err) => ctx.set_error(output.len(), err.message()),
+    }
+    output.commit_row();
+}
+
+/// The minimum euclidean distance between two geometries, or `None` if any of them is empty.
+///
+/// If the geometries don't intersect, the distance is reached at a vertex of one of them,
+/// so it's the minimum distance from the vertices to the points and segments of the other.
+fn geometry_distance(a: &Geometry<f64>, b: &Geometry<f64>) -> Option<f64> {
+    let points_a = a.coords_iter().map(Point::from).collect::<Vec<_>>();
+    let points_b = b.coords_iter().map(Point::from).collect::<Vec<_>>();
+    if points_a.is_empty() || points_b.is_empty() {
+        return None;
+    }
+    if a.relate(b).is_intersects() {
+        return Some(0.0);
+    }
+
+    let lines_a = geometry_lines(a);
+    let lines_b = geometry_lines(b);
+    let mut distance = f64::INFINITY;
+    for point in &points_a {
+        for other in &points_b {
+            distance = distance.min(point.euclidean_distance(other));
+        }
+        for line in &lines_b {
+            distance = distance.min(point.euclidean_distance(line));
+        }
+    }
+    for point in &points_b {
+        for line in &lines_a {
+            distance = distance.min(point.euclidean_distance(line));
+        }
+    }
+    Some(distance)
+}
+
+fn geometry_lines(geometry: &Geometry<f64>) -> Vec<Line<f64>> {
+    match geometry {
+        Geometry::Point(_) | Geometry::MultiPoint(_) => vec![],
+        Geometry::Line(line) => vec![*line],
+        Geometry::LineString(line_string) => line_string.lines_iter().collect(),
+        Geometry::Polygon(polygon) => polygon.lines_iter().collect(),
+        Geometry::MultiLineString(lines) => lines.lines_iter().collect(),
+        Geometry::MultiPolygon(polygons) => polygons.lines_iter().collect(),
+        Geometry::Rect(rect) => rect.lines_iter().collect(),
+        Geometry::Triangle(triangle) => triangle.lines_iter().collect(),
+        Geometry::GeometryCollection(collection) => {
+            collection.iter().flat_map(geometry_lines).collect()
+        }
+    }
+}
+
+/// The buffer is approximated by the convex hull of the circles around the vertices,
+/// which is exact (up to the circle segments) for points, segments and convex polygons,
+/// and covers the exact buffer of the other geometries.
+fn geometry_buffer(geometry: &Geometry<f64>, distance: f64) -> Geometry<f64> {
+    if distance == 0.0 || geometry.coords_iter().next().is_none() {
+        return geometry.clone();
+    }
+    let points = geometry
+        .coords_iter()
+        .flat_map(|center| {
+            (0..BUFFER_CIRCLE_SEGMENTS).map(move |i| {
+                let angle = 2.0 * std::f64::consts::PI * i as f64 / BUFFER_CIRCLE_SEGMENTS as f64;
+                Point::from(Coord {
+                    x: center.x + distance * angle.cos(),
+                    y: center.y + distance * angle.sin(),
+                })
+            })
+        })
+        .collect::<MultiPoint<f64>>();
+    Geometry::Polygon(points.convex_hull())
+}
@@ -0,0 +1,293 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::interval::interval_to_string;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
+use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::number::Int64Type;
+use databend_common_expression::types::string::StringDomain;
+use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::timestamp::MICROS_IN_A_SEC;
+use databend_common_expression::types::IntervalType;
+use databend_common_expression::types::NullableType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::utils::date_helper::AddDaysImpl;
+use databend_common_expression::utils::date_helper::AddMonthsImpl;
+use databend_common_expression::utils::date_helper::TzLUT;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+const INTERVAL_OUT_OF_RANGE: &str = "interval is out of range";
+
+/// The functions to build an interval from a number of units, used by `INTERVAL <expr> <unit>`.
+const INTERVAL_CONSTRUCTORS: &[(&str, fn(i64) -> Option<months_days_micros>)] = &[
+    ("to_years", |n| months_interval(n.checked_mul(12)?)),
+    ("to_quarters", |n| months_interval(n.checked_mul(3)?)),
+    ("to_months", months_interval),
+    ("to_weeks", |n| days_interval(n.checked_mul(7)?)),
+    ("to_days", days_interval),
+    ("to_hours", |n| {
+        micros_interval(n.checked_mul(3600 * MICROS_IN_A_SEC)?)
+    }),
+    ("to_minutes", |n| {
+        micros_interval(n.checked_mul(60 * MICROS_IN_A_SEC)?)
+    }),
+    ("to_seconds", |n| {
+        micros_interval(n.checked_mul(MICROS_IN_A_SEC)?)
+    }),
+];
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS interval)
+    // to_interval(xx)
+    register_string_to_interval(registry);
+
+    // cast(interval AS string)
+    // to_string(interval)
+    register_interval_to_string(registry);
+
+    // to_[years | quarters | months | weeks | days | hours | minutes | seconds](number)
+    register_interval_constructors(registry);
+
+    // interval [+ | -] interval, -interval, interval [* | /] number
+    register_interval_arithmetic(registry);
+
+    // timestamp [+ | -] interval
+    register_timestamp_add_sub_interval(registry);
+}
+
+fn register_string_to_interval(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    fn eval_string_to_interval(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<IntervalType> {
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(interval) => output.push(interval),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(months_days_micros::default());
+                }
+            }
+        })(val, ctx)
+    }
+}
+
+fn register_interval_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            write!(output.data, "{}", interval_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "try_to_string",
+        |_, _| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: "".to_string(),
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<IntervalType, NullableType<StringType>>(|val, output, _| {
+            write!(output.builder.data, "{}", interval_to_string(val)).unwrap();
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
+}
+
+fn register_interval_constructors(registry: &mut FunctionRegistry) {
+    for (name, constructor) in INTERVAL_CONSTRUCTORS.iter().copied() {
+        registry.register_passthrough_nullable_1_arg::<Int64Type, IntervalType, _, _>(
+            name,
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_1_arg::<Int64Type, IntervalType>(move |val, output, ctx| {
+                push_interval(constructor(val), output, ctx)
+            }),
+        );
+    }
+}
+
+fn register_interval_arithmetic(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| push_interval(a.checked_add(&b), output, ctx),
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| push_interval(a.checked_sub(&b), output, ctx),
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|a, output, ctx| {
+            push_interval(a.checked_neg(), output, ctx)
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, Int64Type, IntervalType, _, _>(
+        "multiply",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, Int64Type, IntervalType>(
+            |a, b, output, ctx| push_interval(a.checked_mul(b), output, ctx),
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<Int64Type, IntervalType, IntervalType, _, _>(
+        "multiply",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<Int64Type, IntervalType, IntervalType>(
+            |a, b, output, ctx| push_interval(b.checked_mul(a), output, ctx),
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, Int64Type, IntervalType, _, _>(
+        "divide",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, Int64Type, IntervalType>(
+            |a, b, output, ctx| {
+                if b == 0 {
+                    ctx.set_error(output.len(), "divided by zero");
+                    output.push(months_days_micros::default());
+                } else {
+                    push_interval(a.checked_div(b), output, ctx)
+                }
+            },
+        ),
+    );
+}
+
+fn register_timestamp_add_sub_interval(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    push_timestamp(add_interval(ts, interval, ctx.func_ctx.tz), output, ctx)
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |interval, ts, output, ctx| {
+                    push_timestamp(add_interval(ts, interval, ctx.func_ctx.tz), output, ctx)
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    let res = interval
+                        .checked_neg()
+                        .ok_or_else(|| INTERVAL_OUT_OF_RANGE.to_string())
+                        .and_then(|interval| add_interval(ts, interval, ctx.func_ctx.tz));
+                    push_timestamp(res, output, ctx)
+                },
+            ),
+        );
+}
+
+/// Add the months part in the session timezone first, so that the day of month is kept,
+/// then the days and the micros.
+fn add_interval(ts: i64, interval: months_days_micros, tz: TzLUT) -> Result<i64, String> {
+    let ts = if interval.months() != 0 {
+        AddMonthsImpl::eval_timestamp(ts, tz, interval.months())?
+    } else {
+        ts
+    };
+    let ts = AddDaysImpl::eval_timestamp(ts, interval.days())?;
+    ts.checked_add(interval.micros())
+        .ok_or_else(|| "timestamp is out of range".to_string())
+        .and_then(check_timestamp)
+}
+
+fn months_interval(months: i64) -> Option<months_days_micros> {
+    Some(months_days_micros::new(months.try_into().ok()?, 0, 0))
+}
+
+fn days_interval(days: i64) -> Option<months_days_micros> {
+    Some(months_days_micros::new(0, days.try_into().ok()?, 0))
+}
+
+fn micros_interval(micros: i64) -> Option<months_days_micros> {
+    Some(months_days_micros::new(0, 0, micros))
+}
+
+fn push_interval(
+    interval: Option<months_days_micros>,
+    output: &mut Vec<months_days_micros>,
+    ctx: &mut EvalContext,
+) {
+    match interval {
+        Some(interval) => output.push(interval),
+        None => {
+            ctx.set_error(output.len(), INTERVAL_OUT_OF_RANGE);
+            output.push(months_days_micros::default());
+        }
+    }
+}
+
+fn push_timestamp(ts: Result<i64, String>, output: &mut Vec<i64>, ctx: &mut EvalContext) {
+    match ts {
+        Ok(ts) => output.push(ts),
+        Err(err) => {
+            ctx.set_error(output.len(), err);
+            output.push(0);
+        }
+    }
+}
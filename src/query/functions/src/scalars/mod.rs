@@ -26,7 +26,9 @@ mod datetime;
 mod decimal;
 mod geo;
 mod geo_h3;
+mod geometry;
 mod hash;
+mod interval;
 mod map;
 mod math;
 mod other;
@@ -62,4 +64,6 @@ pub fn register(registry: &mut FunctionRegistry) {
     decimal::register_to_decimal(registry);
     vector::register(registry);
     bitmap::register(registry);
+    interval::register(registry);
+    geometry::register(registry);
 }
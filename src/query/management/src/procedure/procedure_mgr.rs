@@ -0,0 +1,172 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserDefinedProcedure;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MatchSeqExt;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::SeqV;
+
+use crate::procedure::procedure_api::ProcedureApi;
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+
+static PROCEDURE_API_KEY_PREFIX: &str = "__fd_procedures";
+
+pub struct ProcedureMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    procedure_prefix: String,
+}
+
+impl ProcedureMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create procedure)",
+            ));
+        }
+
+        Ok(ProcedureMgr {
+            kv_api,
+            procedure_prefix: format!("{}/{}", PROCEDURE_API_KEY_PREFIX, escape_for_key(tenant)?),
+        })
+    }
+
+    fn make_procedure_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.procedure_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl ProcedureApi for ProcedureMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_procedure(&self, procedure: UserDefinedProcedure) -> Result<u64> {
+        let match_seq = MatchSeq::Exact(0);
+        let key = self.make_procedure_key(procedure.name.as_str())?;
+        let value = Operation::Update(serialize_struct(
+            &procedure,
+            ErrorCode::IllegalProcedureFormat,
+            || "",
+        )?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api.upsert_kv(UpsertKVReq::new(&key, match_seq, value, None));
+
+        let res_seq = upsert_kv.await?.added_seq_or_else(|_v| {
+            ErrorCode::ProcedureAlreadyExists(format!(
+                "Procedure '{}' already exists.",
+                procedure.name
+            ))
+        })?;
+
+        Ok(res_seq)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn update_procedure(
+        &self,
+        procedure: UserDefinedProcedure,
+        match_seq: MatchSeq,
+    ) -> Result<u64> {
+        let key = self.make_procedure_key(procedure.name.as_str())?;
+        let value = Operation::Update(serialize_struct(
+            &procedure,
+            ErrorCode::IllegalProcedureFormat,
+            || "",
+        )?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, match_seq, value, None))
+            .await?;
+
+        match upsert_kv.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownProcedure(format!(
+                "Procedure '{}' does not exist.",
+                procedure.name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_procedure(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_procedure_key(name)?;
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownProcedure(format!(
+                "Procedure '{}' does not exist.",
+                name
+            )))
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_procedure(&self, name: &str, seq: MatchSeq) -> Result<SeqV<UserDefinedProcedure>> {
+        let key = self.make_procedure_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| {
+            ErrorCode::UnknownProcedure(format!("Procedure '{}' does not exist.", name))
+        })?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalProcedureFormat, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownProcedure(format!(
+                "Procedure '{}' does not exist.",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_procedures(&self) -> Result<Vec<UserDefinedProcedure>> {
+        let values = self.kv_api.prefix_list_kv(&self.procedure_prefix).await?;
+
+        let mut procedures = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let procedure =
+                deserialize_struct(&value.data, ErrorCode::IllegalProcedureFormat, || "")?;
+            procedures.push(procedure);
+        }
+        Ok(procedures)
+    }
+}
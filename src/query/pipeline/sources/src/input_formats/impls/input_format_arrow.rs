@@ -0,0 +1,217 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_settings::Settings;
+use databend_common_storage::ArrowIpcReader;
+use databend_common_storage::StageFileInfo;
+use log::info;
+use opendal::Operator;
+use serde::Deserializer;
+use serde::Serializer;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+/// Input format of arrow IPC, both the file and the streaming variant, for streaming load only.
+pub struct InputFormatArrow;
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatArrow {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        _file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        unimplemented!("InputFormatArrow::get_splits")
+    }
+
+    fn exec_copy(&self, _ctx: Arc<InputContext>, _pipeline: &mut Pipeline) -> Result<()> {
+        unimplemented!("ArrowFormatPipe::exec_copy")
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        ArrowFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct ArrowFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for ArrowFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = ArrowRecordBatch;
+    type AligningState = ArrowAligningState;
+    type BlockBuilder = ArrowBlockBuilder;
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<ArrowAligningState> {
+        Ok(ArrowAligningState {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(_ctx: &Arc<InputContext>) -> Result<ArrowBlockBuilder> {
+        Ok(ArrowBlockBuilder {})
+    }
+}
+
+/// A record batch decoded from the arrow data, with the columns in the order of the insert schema.
+pub struct ArrowRecordBatch {
+    block: DataBlock,
+}
+
+impl Debug for ArrowRecordBatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ArrowRecordBatch")
+    }
+}
+
+impl serde::Serialize for ArrowRecordBatch {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        unimplemented!()
+    }
+}
+
+impl<'a> serde::Deserialize<'a> for ArrowRecordBatch {
+    fn deserialize<D: Deserializer<'a>>(_deserializer: D) -> Result<Self, D::Error> {
+        unimplemented!()
+    }
+}
+
+impl RowBatchTrait for ArrowRecordBatch {
+    fn size(&self) -> usize {
+        self.block.memory_size()
+    }
+
+    fn rows(&self) -> usize {
+        self.block.num_rows()
+    }
+}
+
+#[typetag::serde(name = "row_batch_arrow")]
+impl BlockMetaInfo for ArrowRecordBatch {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("ArrowRecordBatch as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("ArrowRecordBatch as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+pub struct ArrowBlockBuilder {}
+
+impl BlockBuilderTrait for ArrowBlockBuilder {
+    type Pipe = ArrowFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<ArrowRecordBatch>) -> Result<Vec<DataBlock>> {
+        Ok(batch.map(|b| vec![b.block]).unwrap_or_default())
+    }
+}
+
+pub struct ArrowAligningState {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for ArrowAligningState {
+    type Pipe = ArrowFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<ArrowRecordBatch>> {
+        if let Some(b) = read_batch {
+            self.buffers.push(b);
+            return Ok(vec![]);
+        }
+
+        // The schema of the file variant is in the footer, so the whole data is needed,
+        // and the record batches are cheap to decode once the data is in memory.
+        let data = Bytes::from(self.buffers.concat());
+        let size = data.len();
+        let path = &self.split_info.file.path;
+        let reader = ArrowIpcReader::try_create(path, data)?;
+        let file_schema = TableSchema::try_from(reader.schema().as_ref())?;
+        let data_schema = DataSchema::from(&file_schema);
+        let field_indexes = get_used_field_indexes(&file_schema, &self.ctx)?;
+
+        let mut row_batches = vec![];
+        for batch in reader {
+            let (block, _) = DataBlock::from_record_batch(&data_schema, &batch?)?;
+            let num_rows = block.num_rows();
+            let columns = field_indexes
+                .iter()
+                .map(|i| block.get_by_offset(*i).clone())
+                .collect::<Vec<BlockEntry>>();
+            row_batches.push(ArrowRecordBatch {
+                block: DataBlock::new(columns, num_rows),
+            });
+        }
+        info!(
+            "align arrow file {} of {} bytes to {} record batches",
+            path,
+            size,
+            row_batches.len()
+        );
+        Ok(row_batches)
+    }
+}
+
+fn get_used_field_indexes(file_schema: &TableSchema, ctx: &InputContext) -> Result<Vec<usize>> {
+    let mut indexes = Vec::with_capacity(ctx.schema.num_fields());
+    for f in ctx.schema.fields().iter() {
+        if let Some((i, _)) = file_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.name().eq_ignore_ascii_case(f.name()))
+            .last()
+        {
+            indexes.push(i);
+        } else {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "schema field size mismatch, expected to find column: {}",
+                f.name()
+            )));
+        }
+    }
+    Ok(indexes)
+}
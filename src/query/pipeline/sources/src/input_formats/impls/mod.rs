@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod input_format_arrow;
 mod input_format_csv;
 mod input_format_ndjson;
 mod input_format_parquet;
 mod input_format_tsv;
 mod input_format_xml;
 
+pub use input_format_arrow::InputFormatArrow;
 pub use input_format_csv::InputFormatCSV;
 pub use input_format_ndjson::InputFormatNDJson;
 pub use input_format_parquet::InputFormatParquet;
@@ -27,6 +27,7 @@ use databend_common_exception::Result;
 use databend_common_meta_app::schema::CatalogType;
 use databend_common_sharing::ShareEndpointManager;
 use databend_common_storage::DataOperator;
+use databend_common_storage::LocalSpillDisk;
 use databend_common_storage::ShareTableConfig;
 use databend_common_storages_hive::HiveCreator;
 use databend_common_storages_iceberg::IcebergCreator;
@@ -40,6 +41,7 @@ use crate::auth::AuthMgr;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
 use crate::locks::LockManager;
+use crate::pipes::PipeScheduler;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
 
@@ -108,9 +110,15 @@ impl GlobalServices {
         )
         .await?;
         RoleCacheManager::init()?;
+        PipeScheduler::init(config)?;
         ShareEndpointManager::init()?;
 
         DataOperator::init(&config.storage).await?;
+        LocalSpillDisk::init(
+            &config.query.spill_local_disk_path,
+            &config.query.node_id,
+            config.query.spill_local_disk_max_bytes,
+        )?;
         ShareTableConfig::init(
             &config.query.share_endpoint_address,
             &config.query.share_endpoint_auth_token_file,
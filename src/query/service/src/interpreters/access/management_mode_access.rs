@@ -80,6 +80,8 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::DropTable(_)
                 | Plan::DropView(_)
                 | Plan::CreateView(_)
+                | Plan::CreateMaterializedView(_)
+                | Plan::DropMaterializedView(_)
                 | Plan::CreateStream(_)
                 | Plan::DropStream(_)
 
@@ -114,6 +116,10 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreateUDF(_)
                 | Plan::AlterUDF(_)
                 | Plan::DropUDF(_)
+
+                // Procedure.
+                | Plan::CreateProcedure(_)
+                | Plan::DropProcedure(_)
                 | Plan::UseDatabase(_) => true,
                 Plan::DescribeTable(plan) => {
                     let catalog = &plan.catalog;
@@ -15,6 +15,7 @@
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
 use databend_common_catalog::plan::DataSourceInfo;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -186,6 +187,17 @@ impl PrivilegeAccess {
         if catalog.exists_table_function(table_name) {
             return Ok(());
         }
+        // the temporary table is only visible to the session which creates it.
+        if catalog_name == CATALOG_DEFAULT
+            && self
+                .ctx
+                .temp_tbl_mgr()
+                .lock()
+                .get_table(db_name, table_name)
+                .is_some()
+        {
+            return Ok(());
+        }
         // to keep compatibility with the legacy privileges which granted by table name,
         // we'd both check the privileges by name and id.
         // we'll completely move to the id side in the future.
@@ -802,6 +814,17 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropView(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Drop]).await?
             }
+            Plan::CreateMaterializedView(plan) => {
+                let create_table = &plan.create_table;
+                self.validate_db_access(&create_table.catalog, &create_table.database, vec![UserPrivilegeType::Create]).await?;
+                self.check(ctx, &plan.query).await?;
+            }
+            Plan::DropMaterializedView(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Drop]).await?
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.view_name, vec![UserPrivilegeType::Insert]).await?
+            }
             Plan::CreateStream(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Create]).await?
             }
@@ -909,7 +932,15 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::DescribeTask(_) // TODO: need to build ownership info for task
             | Plan::ExecuteTask(_)  // TODO: need to build ownership info for task
             | Plan::DropTask(_)     // TODO: need to build ownership info for task
-            | Plan::AlterTask(_) => {
+            | Plan::AlterTask(_)
+            | Plan::CreatePipe(_)
+            | Plan::AlterPipe(_)
+            | Plan::DropPipe(_)
+            | Plan::DescPipe(_)
+            | Plan::CreateSequence(_)
+            | Plan::DropSequence(_)
+            | Plan::CreateProcedure(_)
+            | Plan::DropProcedure(_) => {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
             }
@@ -924,6 +955,10 @@ impl AccessChecker for PrivilegeAccess {
             // SET ROLE & SHOW ROLES is a session-local statement (have same semantic with the SET ROLE in postgres), no need to check privileges
             Plan::SetRole(_) => {}
             Plan::SetSecondaryRoles(_) => {}
+            // Transaction control only takes effect on the session itself
+            Plan::Begin | Plan::Commit | Plan::Abort => {}
+            // The statements of a script are checked one by one when they are executed
+            Plan::CallProcedure(_) | Plan::ExecuteImmediate(_) => {}
             Plan::ShowRoles(_) => {}
             Plan::Presign(plan) => {
                 let privilege = match &plan.action {
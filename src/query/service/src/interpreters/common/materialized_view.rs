@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::VisitorMut;
+use databend_common_catalog::materialized_view::materialized_view_stream_name;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_common_storages_stream::stream_table::StreamTable;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_VER;
+
+use crate::interpreters::InsertInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+/// Refresh the materialized view by inserting the result of its query.
+///
+/// If the view is incremental, the query reads the stream on the source table instead,
+/// and the new rows are appended to the view. The offset of the stream is advanced in
+/// the same commit. The view is fully refreshed if `full` is set, or if the rows of the
+/// source table are not only appended since the last refresh.
+#[async_backtrace::framed]
+pub async fn refresh_materialized_view(
+    ctx: Arc<QueryContext>,
+    catalog_name: &str,
+    database: &str,
+    view_name: &str,
+    full: bool,
+) -> Result<PipelineBuildResult> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog(catalog_name).await?;
+    let view = catalog.get_table(&tenant, database, view_name).await?;
+    let Some(query) = view.options().get(OPT_KEY_MATERIALIZED_VIEW_QUERY) else {
+        return Err(ErrorCode::TableEngineNotSupported(format!(
+            "{}.{} is not a materialized view",
+            database, view_name
+        )));
+    };
+
+    let tokens = tokenize_sql(query)?;
+    let (stmt, _) = parse_sql(&tokens, ctx.get_settings().get_sql_dialect()?)?;
+    let Statement::Query(mut query) = stmt else {
+        return Err(ErrorCode::Internal(format!(
+            "Invalid query of materialized view {}.{}",
+            database, view_name
+        )));
+    };
+
+    let mut overwrite = true;
+    if view
+        .options()
+        .contains_key(OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL)
+    {
+        let stream_name = materialized_view_stream_name(view.get_id());
+        let stream = catalog.get_table(&tenant, database, &stream_name).await?;
+        let stream_table = StreamTable::try_from_table(stream.as_ref())?;
+        overwrite = full
+            || stream_table.snapshot_loc().is_none()
+            || stream_table.has_removed_blocks(ctx.clone()).await?;
+        ctx.evict_table_from_cache(catalog_name, database, &stream_name)?;
+        if overwrite {
+            // Read the stream from the beginning, so that all the rows of the source table are
+            // read. The reset offset is not persisted, the offset of the stream is advanced in
+            // the same commit as the overwrite, which keeps the view and the stream consistent
+            // if the refresh fails.
+            let mut stream_info = stream.get_table_info().clone();
+            stream_info.meta.options.remove(OPT_KEY_SNAPSHOT_LOCATION);
+            stream_info
+                .meta
+                .options
+                .insert(OPT_KEY_TABLE_VER.to_string(), "0".to_string());
+            let reset_stream: Arc<dyn Table> = StreamTable::try_create(stream_info)?.into();
+            ctx.attach_table(catalog_name, database, &stream_name, reset_stream);
+        }
+
+        let mut rewriter = StreamRewriter {
+            catalog: catalog_name.to_string(),
+            database: database.to_string(),
+            stream_name,
+        };
+        rewriter.visit_query(&mut query);
+    }
+
+    let sql = format!(
+        "INSERT {}INTO `{}`.`{}` {}",
+        if overwrite { "OVERWRITE " } else { "" },
+        database,
+        view_name,
+        query
+    );
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&sql).await?;
+    let Plan::Insert(insert) = plan else {
+        return Err(ErrorCode::Internal(format!(
+            "Invalid refresh plan of materialized view {}.{}",
+            database, view_name
+        )));
+    };
+    InsertInterpreter::try_create(ctx, *insert)?
+        .execute2()
+        .await
+}
+
+/// Replace the source table of an incremental materialized view with its stream.
+///
+/// The query of an incremental view reads exactly one table, the name of the table is
+/// kept as the alias, so that the qualified column references are still valid.
+struct StreamRewriter {
+    catalog: String,
+    database: String,
+    stream_name: String,
+}
+
+impl VisitorMut for StreamRewriter {
+    fn visit_table_reference(&mut self, table_ref: &mut TableReference) {
+        if let TableReference::Table {
+            catalog,
+            database,
+            table,
+            alias,
+            ..
+        } = table_ref
+        {
+            if alias.is_none() {
+                *alias = Some(TableAlias {
+                    name: table.clone(),
+                    columns: vec![],
+                });
+            }
+            *catalog = Some(Identifier::from_name(self.catalog.clone()));
+            *database = Some(Identifier::from_name(self.database.clone()));
+            *table = Identifier::from_name(self.stream_name.clone());
+        }
+    }
+}
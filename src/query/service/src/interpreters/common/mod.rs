@@ -13,17 +13,26 @@
 // limitations under the License.
 
 mod grant;
+mod materialized_view;
 mod metrics;
 mod query_log;
+mod script;
 mod stream;
 mod table;
 mod task;
 mod util;
 
 pub use grant::validate_grant_object_exists;
+pub use materialized_view::refresh_materialized_view;
 pub use query_log::InterpreterQueryLog;
+pub use script::ScriptExecutor;
+pub use script::ScriptResult;
 pub use stream::build_update_stream_meta_seq;
+pub use table::check_enforced_primary_key;
 pub use table::check_referenced_computed_columns;
+pub use table::check_referenced_constraints;
+pub use table::drop_auto_increment_sequences;
+pub use table::purge_temp_table;
 pub use task::get_client_config;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
@@ -0,0 +1,671 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono_tz::Tz;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::InsertSource;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::ReturnItem;
+use databend_common_ast::ast::ScriptBlock;
+use databend_common_ast::ast::ScriptStatement;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TypeName;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::walk_expr_mut;
+use databend_common_ast::VisitorMut;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::Span;
+use databend_common_expression::block_debug::box_render;
+use databend_common_expression::types::date::date_to_string;
+use databend_common_expression::types::timestamp::timestamp_to_string;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_sql::normalize_identifier;
+use databend_common_sql::NameResolutionContext;
+use databend_common_sql::Planner;
+use futures_util::future::BoxFuture;
+use futures_util::FutureExt;
+use futures_util::TryStreamExt;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The value returned by a script.
+pub enum ScriptResult {
+    /// The script ends without `RETURN <value>`.
+    None,
+    Scalar(DataType, Scalar),
+    Table(DataSchemaRef, Vec<DataBlock>),
+}
+
+enum Variable {
+    Scalar {
+        /// The type in `LET <name> <type> := ...`, the assigned values are cast to it.
+        declared_type: Option<TypeName>,
+        data_type: DataType,
+        value: Scalar,
+    },
+    ResultSet {
+        schema: DataSchemaRef,
+        blocks: Vec<DataBlock>,
+    },
+    /// The current row of `FOR <row> IN <resultset>`, keyed by the column names.
+    Row(HashMap<String, (DataType, Scalar)>),
+}
+
+enum ControlFlow {
+    Normal,
+    Break(Span),
+    Continue(Span),
+    Return(ScriptResult),
+}
+
+/// Executes a SQL script, such as the body of a procedure or `EXECUTE IMMEDIATE`.
+///
+/// Each SQL statement of the script is planned and executed with a new query context of
+/// the current session, so that the privileges are checked for each of them. The script
+/// variables are referenced by name in the statements, and they are replaced by their
+/// values before planning, so a variable shadows the column with the same name.
+pub struct ScriptExecutor {
+    ctx: Arc<QueryContext>,
+    name_resolution_ctx: NameResolutionContext,
+    tz: Tz,
+    scopes: Vec<HashMap<String, Variable>>,
+}
+
+impl ScriptExecutor {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        let name_resolution_ctx = NameResolutionContext::try_from(ctx.get_settings().as_ref())?;
+        let tz = ctx.get_function_context()?.tz.tz;
+        Ok(ScriptExecutor {
+            ctx,
+            name_resolution_ctx,
+            tz,
+            scopes: vec![HashMap::new()],
+        })
+    }
+
+    /// Declare a parameter of the procedure, the argument is cast to the parameter type.
+    #[async_backtrace::framed]
+    pub async fn declare_param(
+        &mut self,
+        name: &str,
+        data_type: &DataType,
+        arg: &str,
+    ) -> Result<()> {
+        let target_type = data_type_to_type_name(data_type)?;
+        let expr = cast_expr(string_literal(arg.to_string()), target_type.clone());
+        let (data_type, value) = self.eval_expr(&expr).await?;
+        self.scopes[0].insert(name.to_string(), Variable::Scalar {
+            declared_type: Some(target_type),
+            data_type,
+            value,
+        });
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn run(&mut self, block: &ScriptBlock) -> Result<ScriptResult> {
+        match self.run_block(block).await? {
+            ControlFlow::Normal => Ok(ScriptResult::None),
+            ControlFlow::Return(result) => Ok(result),
+            ControlFlow::Break(span) => Err(ErrorCode::ScriptSemanticError(
+                "BREAK is not allowed outside of a loop",
+            )
+            .set_span(span)),
+            ControlFlow::Continue(span) => Err(ErrorCode::ScriptSemanticError(
+                "CONTINUE is not allowed outside of a loop",
+            )
+            .set_span(span)),
+        }
+    }
+
+    /// Cast the value to the type, used to convert the value returned by a procedure to
+    /// its declared return type.
+    #[async_backtrace::framed]
+    pub async fn cast_value(
+        &mut self,
+        data_type: &DataType,
+        value: &Scalar,
+        target_type: &DataType,
+    ) -> Result<(DataType, Scalar)> {
+        let expr = cast_expr(
+            value_to_expr(data_type, value, self.tz)?,
+            data_type_to_type_name(target_type)?,
+        );
+        self.eval_expr(&expr).await
+    }
+
+    /// Render the value as the text that is displayed to users.
+    pub fn display_value(&self, value: &Scalar) -> String {
+        match value {
+            Scalar::String(s) => s.clone(),
+            Scalar::Timestamp(ts) => timestamp_to_string(*ts, self.tz).to_string(),
+            Scalar::Date(d) => date_to_string(*d, self.tz).to_string(),
+            other => other.to_string(),
+        }
+    }
+
+    /// Convert the result of the script to the blocks of the `Result` column, a result set
+    /// is rendered as a table.
+    pub fn result_to_blocks(&self, result: &ScriptResult) -> Result<Vec<DataBlock>> {
+        let text = match result {
+            ScriptResult::None => return Ok(vec![]),
+            ScriptResult::Scalar(_, value) => self.display_value(value),
+            ScriptResult::Table(schema, blocks) => {
+                let num_rows = blocks.iter().map(|block| block.num_rows()).sum();
+                box_render(schema, blocks, num_rows, 4096, 1024, true)?
+            }
+        };
+        Ok(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![text]),
+        ])])
+    }
+
+    fn run_block<'a>(&'a mut self, block: &'a ScriptBlock) -> BoxFuture<'a, Result<ControlFlow>> {
+        async move {
+            self.scopes.push(HashMap::new());
+            let mut res = self.run_stmts(&block.body).await;
+            if let (Err(err), Some(handler)) = (&res, &block.exception_handler) {
+                // The error is exposed to the handler by `sqlcode` and `sqlerrm`.
+                let scope = self.scopes.last_mut().unwrap();
+                scope.insert("sqlcode".to_string(), Variable::Scalar {
+                    declared_type: None,
+                    data_type: DataType::Number(NumberDataType::UInt16),
+                    value: Scalar::Number(NumberScalar::UInt16(err.code())),
+                });
+                scope.insert("sqlerrm".to_string(), Variable::Scalar {
+                    declared_type: None,
+                    data_type: DataType::String,
+                    value: Scalar::String(err.message()),
+                });
+                res = self.run_stmts(handler).await;
+            }
+            self.scopes.pop();
+            res
+        }
+        .boxed()
+    }
+
+    async fn run_scoped(&mut self, stmts: &[ScriptStatement]) -> Result<ControlFlow> {
+        self.scopes.push(HashMap::new());
+        let res = self.run_stmts(stmts).await;
+        self.scopes.pop();
+        res
+    }
+
+    fn run_stmts<'a>(
+        &'a mut self,
+        stmts: &'a [ScriptStatement],
+    ) -> BoxFuture<'a, Result<ControlFlow>> {
+        async move {
+            for stmt in stmts {
+                match self.run_stmt(stmt).await? {
+                    ControlFlow::Normal => {}
+                    flow => return Ok(flow),
+                }
+            }
+            Ok(ControlFlow::Normal)
+        }
+        .boxed()
+    }
+
+    async fn run_stmt(&mut self, stmt: &ScriptStatement) -> Result<ControlFlow> {
+        self.ctx.check_aborting()?;
+        match stmt {
+            ScriptStatement::LetVar {
+                name,
+                data_type,
+                value,
+                ..
+            } => {
+                let expr = match data_type {
+                    Some(ty) => cast_expr((**value).clone(), ty.clone()),
+                    None => (**value).clone(),
+                };
+                let (data_type_of_value, value) = self.eval_expr(&expr).await?;
+                let name = self.normalize(name);
+                self.declare(name, Variable::Scalar {
+                    declared_type: data_type.clone(),
+                    data_type: data_type_of_value,
+                    value,
+                });
+            }
+            ScriptStatement::LetResultSet { name, query, .. } => {
+                let (schema, blocks) = self.run_statement(Statement::Query(query.clone())).await?;
+                let name = self.normalize(name);
+                self.declare(name, Variable::ResultSet { schema, blocks });
+            }
+            ScriptStatement::Assign { span, name, value } => {
+                let name = self.normalize(name);
+                let declared_type = match self.lookup(&name) {
+                    Some(Variable::Scalar { declared_type, .. }) => declared_type.clone(),
+                    _ => {
+                        return Err(ErrorCode::ScriptSemanticError(format!(
+                            "`{name}` is not a declared variable"
+                        ))
+                        .set_span(*span));
+                    }
+                };
+                let expr = match &declared_type {
+                    Some(ty) => cast_expr((**value).clone(), ty.clone()),
+                    None => (**value).clone(),
+                };
+                let (data_type, value) = self.eval_expr(&expr).await?;
+                if let Some(variable) = self.lookup_mut(&name) {
+                    *variable = Variable::Scalar {
+                        declared_type,
+                        data_type,
+                        value,
+                    };
+                }
+            }
+            ScriptStatement::Return { value, .. } => {
+                let result = match value {
+                    None => ScriptResult::None,
+                    Some(ReturnItem::Expr(expr)) => {
+                        let (data_type, value) = self.eval_expr(expr).await?;
+                        ScriptResult::Scalar(data_type, value)
+                    }
+                    Some(ReturnItem::ResultSet(name)) => {
+                        let (schema, blocks) = self.get_result_set(name)?;
+                        ScriptResult::Table(schema, blocks)
+                    }
+                    Some(ReturnItem::Query(query)) => {
+                        let (schema, blocks) =
+                            self.run_statement(Statement::Query(query.clone())).await?;
+                        ScriptResult::Table(schema, blocks)
+                    }
+                };
+                return Ok(ControlFlow::Return(result));
+            }
+            ScriptStatement::If {
+                conditions,
+                results,
+                else_result,
+                ..
+            } => {
+                for (condition, result) in conditions.iter().zip(results) {
+                    if self.eval_condition(condition).await? {
+                        return self.run_scoped(result).await;
+                    }
+                }
+                if let Some(else_result) = else_result {
+                    return self.run_scoped(else_result).await;
+                }
+            }
+            ScriptStatement::ForRange {
+                variable,
+                is_reverse,
+                lower_bound,
+                upper_bound,
+                body,
+                ..
+            } => {
+                let lower_bound = self.eval_integer(lower_bound).await?;
+                let upper_bound = self.eval_integer(upper_bound).await?;
+                let values: Box<dyn Iterator<Item = i64> + Send> = if *is_reverse {
+                    Box::new((lower_bound..=upper_bound).rev())
+                } else {
+                    Box::new(lower_bound..=upper_bound)
+                };
+                let name = self.normalize(variable);
+                for value in values {
+                    self.ctx.check_aborting()?;
+                    self.scopes
+                        .push(HashMap::from([(name.clone(), Variable::Scalar {
+                            declared_type: None,
+                            data_type: DataType::Number(NumberDataType::Int64),
+                            value: Scalar::Number(NumberScalar::Int64(value)),
+                        })]));
+                    let flow = self.run_scoped(body).await;
+                    self.scopes.pop();
+                    match flow? {
+                        ControlFlow::Break(_) => break,
+                        ControlFlow::Normal | ControlFlow::Continue(_) => {}
+                        flow @ ControlFlow::Return(_) => return Ok(flow),
+                    }
+                }
+            }
+            ScriptStatement::ForIn {
+                variable,
+                resultset,
+                body,
+                ..
+            } => {
+                let (schema, blocks) = self.get_result_set(resultset)?;
+                let name = self.normalize(variable);
+                'outer: for block in blocks {
+                    for row in 0..block.num_rows() {
+                        self.ctx.check_aborting()?;
+                        let mut columns = HashMap::with_capacity(schema.num_fields());
+                        for (field, entry) in schema.fields().iter().zip(block.columns()) {
+                            let value = entry.value.index(row).unwrap().to_owned();
+                            columns.insert(field.name().clone(), (entry.data_type.clone(), value));
+                        }
+                        self.scopes
+                            .push(HashMap::from([(name.clone(), Variable::Row(columns))]));
+                        let flow = self.run_scoped(body).await;
+                        self.scopes.pop();
+                        match flow? {
+                            ControlFlow::Break(_) => break 'outer,
+                            ControlFlow::Normal | ControlFlow::Continue(_) => {}
+                            flow @ ControlFlow::Return(_) => return Ok(flow),
+                        }
+                    }
+                }
+            }
+            ScriptStatement::While {
+                condition, body, ..
+            } => {
+                while self.eval_condition(condition).await? {
+                    self.ctx.check_aborting()?;
+                    match self.run_scoped(body).await? {
+                        ControlFlow::Break(_) => break,
+                        ControlFlow::Normal | ControlFlow::Continue(_) => {}
+                        flow @ ControlFlow::Return(_) => return Ok(flow),
+                    }
+                }
+            }
+            ScriptStatement::Loop { body, .. } => loop {
+                self.ctx.check_aborting()?;
+                match self.run_scoped(body).await? {
+                    ControlFlow::Break(_) => break,
+                    ControlFlow::Normal | ControlFlow::Continue(_) => {}
+                    flow @ ControlFlow::Return(_) => return Ok(flow),
+                }
+            },
+            ScriptStatement::Break { span } => return Ok(ControlFlow::Break(*span)),
+            ScriptStatement::Continue { span } => return Ok(ControlFlow::Continue(*span)),
+            ScriptStatement::Block(block) => return self.run_block(block).await,
+            ScriptStatement::RunStatement { stmt, .. } => {
+                self.run_statement((**stmt).clone()).await?;
+            }
+        }
+        Ok(ControlFlow::Normal)
+    }
+
+    fn normalize(&self, ident: &Identifier) -> String {
+        normalize_identifier(ident, &self.name_resolution_ctx).name
+    }
+
+    fn declare(&mut self, name: String, variable: Variable) {
+        self.scopes.last_mut().unwrap().insert(name, variable);
+    }
+
+    fn lookup(&self, name: &str) -> Option<&Variable> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    fn lookup_mut(&mut self, name: &str) -> Option<&mut Variable> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(name))
+    }
+
+    fn get_result_set(&self, name: &Identifier) -> Result<(DataSchemaRef, Vec<DataBlock>)> {
+        match self.lookup(&self.normalize(name)) {
+            Some(Variable::ResultSet { schema, blocks }) => Ok((schema.clone(), blocks.clone())),
+            _ => Err(ErrorCode::ScriptSemanticError(format!(
+                "`{name}` is not a declared RESULTSET"
+            ))
+            .set_span(name.span)),
+        }
+    }
+
+    /// Get the value of the variable referenced by `<name>` or `<row>.<column>`.
+    fn lookup_value(
+        &self,
+        table: Option<&Identifier>,
+        column: &Identifier,
+    ) -> Option<(DataType, Scalar)> {
+        let column = self.normalize(column);
+        match table {
+            None => match self.lookup(&column)? {
+                Variable::Scalar {
+                    data_type, value, ..
+                } => Some((data_type.clone(), value.clone())),
+                _ => None,
+            },
+            Some(table) => match self.lookup(&self.normalize(table))? {
+                Variable::Row(columns) => columns.get(&column).cloned(),
+                _ => None,
+            },
+        }
+    }
+
+    async fn eval_condition(&mut self, condition: &Expr) -> Result<bool> {
+        let expr = cast_expr(condition.clone(), TypeName::Boolean);
+        let (_, value) = self.eval_expr(&expr).await?;
+        Ok(matches!(value, Scalar::Boolean(true)))
+    }
+
+    async fn eval_integer(&mut self, expr: &Expr) -> Result<i64> {
+        let expr = cast_expr(expr.clone(), TypeName::Int64);
+        match self.eval_expr(&expr).await? {
+            (_, Scalar::Number(NumberScalar::Int64(value))) => Ok(value),
+            _ => Err(
+                ErrorCode::ScriptSemanticError("The bound of FOR loop must not be NULL")
+                    .set_span(expr.span()),
+            ),
+        }
+    }
+
+    async fn eval_expr(&mut self, expr: &Expr) -> Result<(DataType, Scalar)> {
+        let mut expr = expr.clone();
+        self.replace_variables(|rewriter| rewriter.visit_expr(&mut expr))?;
+        let (schema, blocks) = self.run_sql(&format!("SELECT {expr}")).await?;
+        let data_type = schema.field(0).data_type().clone();
+        let value = blocks
+            .iter()
+            .find(|block| block.num_rows() > 0)
+            .map(|block| block.get_by_offset(0).value.index(0).unwrap().to_owned())
+            .unwrap_or(Scalar::Null);
+        Ok((data_type, value))
+    }
+
+    fn replace_variables(&self, f: impl FnOnce(&mut VariableRewriter)) -> Result<()> {
+        let mut rewriter = VariableRewriter {
+            executor: self,
+            error: None,
+        };
+        f(&mut rewriter);
+        match rewriter.error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    async fn run_sql(&mut self, sql: &str) -> Result<(DataSchemaRef, Vec<DataBlock>)> {
+        let tokens = tokenize_sql(sql)?;
+        let (stmt, _) = parse_sql(&tokens, self.ctx.get_settings().get_sql_dialect()?)?;
+        self.execute_statement(&stmt).await
+    }
+
+    async fn run_statement(
+        &mut self,
+        mut stmt: Statement,
+    ) -> Result<(DataSchemaRef, Vec<DataBlock>)> {
+        match &mut stmt {
+            // The values of `INSERT ... VALUES` are not parsed yet, rewrite them as a query
+            // so that the variables in them can be replaced.
+            Statement::Insert(insert) => {
+                if let InsertSource::Values { rest_str, .. } = &insert.source {
+                    let tokens = tokenize_sql(&format!("VALUES {rest_str}"))?;
+                    let dialect = self.ctx.get_settings().get_sql_dialect()?;
+                    if let Ok((Statement::Query(query), _)) = parse_sql(&tokens, dialect) {
+                        insert.source = InsertSource::Select { query };
+                    }
+                }
+            }
+            // The arguments of `CALL` are plain strings, the ones with the name of a
+            // variable are replaced by the value of it.
+            Statement::Call(call) => {
+                for arg in call.args.iter_mut() {
+                    if let Some((_, value)) =
+                        self.lookup_value(None, &Identifier::from_name(arg.clone()))
+                    {
+                        *arg = self.display_value(&value);
+                    }
+                }
+            }
+            _ => {}
+        }
+        self.replace_variables(|rewriter| rewriter.visit_statement(&mut stmt))?;
+        self.execute_statement(&stmt).await
+    }
+
+    async fn execute_statement(
+        &mut self,
+        stmt: &Statement,
+    ) -> Result<(DataSchemaRef, Vec<DataBlock>)> {
+        let ctx = self
+            .ctx
+            .get_current_session()
+            .create_query_context()
+            .await?;
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_stmt(stmt).await?;
+        ctx.attach_query_str(plan.kind(), stmt.to_mask_sql());
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx).await?;
+        let blocks = stream.try_collect::<Vec<DataBlock>>().await?;
+        Ok((plan.schema(), blocks))
+    }
+}
+
+/// Replace the references of the variables with their values.
+struct VariableRewriter<'a> {
+    executor: &'a ScriptExecutor,
+    error: Option<ErrorCode>,
+}
+
+impl VisitorMut for VariableRewriter<'_> {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        let replaced = match expr {
+            Expr::ColumnRef {
+                database: None,
+                table,
+                column: ColumnID::Name(column),
+                ..
+            } => self
+                .executor
+                .lookup_value(table.as_ref(), column)
+                .map(|(data_type, value)| value_to_expr(&data_type, &value, self.executor.tz)),
+            _ => None,
+        };
+        match replaced {
+            Some(Ok(new_expr)) => *expr = new_expr,
+            Some(Err(err)) => self.error = Some(err),
+            None => walk_expr_mut(self, expr),
+        }
+    }
+}
+
+fn string_literal(value: String) -> Expr {
+    Expr::Literal {
+        span: None,
+        lit: Literal::String(value),
+    }
+}
+
+fn cast_expr(expr: Expr, target_type: TypeName) -> Expr {
+    Expr::Cast {
+        span: expr.span(),
+        expr: Box::new(expr),
+        target_type,
+        pg_style: false,
+    }
+}
+
+/// Convert the value of a variable to an expression, the value of a type that has no
+/// literal is written as a string cast to the type.
+fn value_to_expr(data_type: &DataType, value: &Scalar, tz: Tz) -> Result<Expr> {
+    let lit = match value {
+        Scalar::Null => Literal::Null,
+        Scalar::Boolean(v) => Literal::Boolean(*v),
+        Scalar::String(v) => Literal::String(v.clone()),
+        Scalar::Number(_) | Scalar::Decimal(_) | Scalar::Variant(_) => {
+            return Ok(cast_expr(
+                string_literal(value.to_string()),
+                data_type_to_type_name(data_type)?,
+            ));
+        }
+        Scalar::Timestamp(ts) => {
+            return Ok(cast_expr(
+                string_literal(timestamp_to_string(*ts, tz).to_string()),
+                TypeName::Timestamp,
+            ));
+        }
+        Scalar::Date(d) => {
+            return Ok(cast_expr(
+                string_literal(date_to_string(*d, tz).to_string()),
+                TypeName::Date,
+            ));
+        }
+        _ => {
+            return Err(ErrorCode::ScriptSemanticError(format!(
+                "Unsupported type {data_type} of script variable"
+            )));
+        }
+    };
+    Ok(Expr::Literal { span: None, lit })
+}
+
+fn data_type_to_type_name(data_type: &DataType) -> Result<TypeName> {
+    let type_name = match data_type.remove_nullable() {
+        DataType::Boolean => TypeName::Boolean,
+        DataType::String => TypeName::String,
+        DataType::Number(ty) => match ty {
+            NumberDataType::UInt8 => TypeName::UInt8,
+            NumberDataType::UInt16 => TypeName::UInt16,
+            NumberDataType::UInt32 => TypeName::UInt32,
+            NumberDataType::UInt64 => TypeName::UInt64,
+            NumberDataType::Int8 => TypeName::Int8,
+            NumberDataType::Int16 => TypeName::Int16,
+            NumberDataType::Int32 => TypeName::Int32,
+            NumberDataType::Int64 => TypeName::Int64,
+            NumberDataType::Float32 => TypeName::Float32,
+            NumberDataType::Float64 => TypeName::Float64,
+        },
+        DataType::Decimal(ty) => TypeName::Decimal {
+            precision: ty.size().precision,
+            scale: ty.size().scale,
+        },
+        DataType::Timestamp => TypeName::Timestamp,
+        DataType::Date => TypeName::Date,
+        DataType::Variant => TypeName::Variant,
+        _ => {
+            return Err(ErrorCode::ScriptSemanticError(format!(
+                "Unsupported type {data_type} of script variable"
+            )));
+        }
+    };
+    Ok(type_name)
+}
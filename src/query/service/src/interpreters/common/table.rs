@@ -12,14 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::schema::DropSequenceReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_sql::auto_increment_sequences;
 use databend_common_sql::parse_computed_expr;
+use databend_common_storage::DataOperator;
+use databend_common_storages_fuse::FuseTable;
 
 pub fn check_referenced_computed_columns(
     ctx: Arc<dyn TableContext>,
@@ -55,3 +65,74 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+/// Check the constraints are still valid after `column` is dropped or renamed,
+/// `schema` is the new schema of the table.
+pub fn check_referenced_constraints(
+    ctx: Arc<dyn TableContext>,
+    schema: DataSchemaRef,
+    constraints: &BTreeMap<String, TableConstraint>,
+    column: &str,
+) -> Result<()> {
+    for (name, constraint) in constraints.iter() {
+        let referenced = match constraint {
+            TableConstraint::PrimaryKey { columns, .. } | TableConstraint::Unique { columns } => {
+                columns.iter().any(|c| c == column)
+            }
+            TableConstraint::Check { expr } => {
+                parse_computed_expr(ctx.clone(), schema.clone(), expr).is_err()
+            }
+        };
+        if referenced {
+            return Err(ErrorCode::ColumnReferencedByConstraint(format!(
+                "column `{}` is referenced by constraint `{}`",
+                column, name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Reject a write operation that bypasses the uniqueness check of the enforced primary key
+/// of the table.
+pub fn check_enforced_primary_key(table: &dyn Table, operation: &str) -> Result<()> {
+    if let Some((columns, true)) = table.get_table_info().meta.primary_key() {
+        return Err(ErrorCode::Unimplemented(format!(
+            "{} is not supported on table '{}' with the enforced primary key ({})",
+            operation,
+            table.name(),
+            columns.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+/// Remove all the data of a dropped temporary table.
+pub async fn purge_temp_table(table_info: &TableInfo) -> Result<()> {
+    let prefix = FuseTable::parse_storage_prefix(table_info)?;
+    let operator = DataOperator::instance().operator();
+    operator.remove_all(&format!("{}/", prefix)).await?;
+    Ok(())
+}
+
+/// Drop the implicit sequences of the AUTOINCREMENT columns of a dropped or replaced table.
+pub async fn drop_auto_increment_sequences(
+    ctx: &dyn TableContext,
+    schema: &TableSchema,
+) -> Result<()> {
+    let sequences = auto_increment_sequences(schema);
+    if sequences.is_empty() {
+        return Ok(());
+    }
+
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_default_catalog()?;
+    for sequence in sequences {
+        let req = DropSequenceReq {
+            if_exists: true,
+            ident: SequenceIdent::new(&tenant, sequence),
+        };
+        let _ = catalog.drop_sequence(req).await?;
+    }
+    Ok(())
+}
@@ -39,6 +39,7 @@ use log::debug;
 
 use crate::interpreters::common::build_update_stream_meta_seq;
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_enforced_primary_key;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
@@ -301,6 +302,18 @@ impl Interpreter for CopyIntoTableInterpreter {
         if self.plan.no_file_to_copy {
             return Ok(PipelineBuildResult::create());
         }
+
+        // The copied rows are not deduplicated, which would break the enforced primary key.
+        let to_table = self
+            .ctx
+            .get_table(
+                self.plan.catalog_info.catalog_name(),
+                &self.plan.database_name,
+                &self.plan.table_name,
+            )
+            .await?;
+        check_enforced_primary_key(to_table.as_ref(), "COPY INTO")?;
+
         let (physical_plan, files, update_stream_meta) =
             self.build_physical_plan(&self.plan).await?;
         let mut build_res =
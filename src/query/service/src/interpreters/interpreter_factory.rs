@@ -15,6 +15,9 @@
 use std::sync::Arc;
 
 use databend_common_ast::ast::ExplainKind;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::txn_manager::TxnState;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use log::error;
 
@@ -74,9 +77,30 @@ impl InterpreterFactory {
             error!("Access.denied(v2): {:?}", e);
             e
         })?;
+        Self::check_txn(&ctx, plan)?;
         Self::get_inner(ctx, plan)
     }
 
+    fn check_txn(ctx: &QueryContext, plan: &Plan) -> Result<()> {
+        let txn_mgr = ctx.txn_mgr();
+        let mut txn_mgr = txn_mgr.lock();
+        match txn_mgr.state() {
+            TxnState::AutoCommit => Ok(()),
+            TxnState::Fail if !matches!(plan, Plan::Commit | Plan::Abort) => {
+                Err(ErrorCode::CurrentTransactionIsAborted(
+                    "current transaction is aborted, commands ignored until end of transaction block",
+                ))
+            }
+            TxnState::Active if !plan.is_allowed_in_txn() => {
+                txn_mgr.set_fail();
+                Err(ErrorCode::Unimplemented(
+                    "only queries and DML statements are supported in an explicit transaction",
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     pub fn get_inner(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
         match plan {
             Plan::Query {
@@ -238,6 +262,17 @@ impl InterpreterFactory {
                 *drop_view.clone(),
             )?)),
 
+            // Materialized views
+            Plan::CreateMaterializedView(create_view) => Ok(Arc::new(
+                CreateMaterializedViewInterpreter::try_create(ctx, *create_view.clone())?,
+            )),
+            Plan::DropMaterializedView(drop_view) => Ok(Arc::new(
+                DropMaterializedViewInterpreter::try_create(ctx, *drop_view.clone())?,
+            )),
+            Plan::RefreshMaterializedView(refresh_view) => Ok(Arc::new(
+                RefreshMaterializedViewInterpreter::try_create(ctx, *refresh_view.clone())?,
+            )),
+
             // Streams
             Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
                 ctx,
@@ -502,6 +537,44 @@ impl InterpreterFactory {
             )?)),
             Plan::ShowTasks(p) => Ok(Arc::new(ShowTasksInterpreter::try_create(ctx, *p.clone())?)),
 
+            Plan::CreatePipe(p) => Ok(Arc::new(CreatePipeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::AlterPipe(p) => Ok(Arc::new(AlterPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DropPipe(p) => Ok(Arc::new(DropPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DescPipe(p) => Ok(Arc::new(DescPipeInterpreter::try_create(ctx, *p.clone())?)),
+
+            Plan::CreateSequence(p) => Ok(Arc::new(CreateSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropSequence(p) => Ok(Arc::new(DropSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+
+            Plan::CreateProcedure(p) => Ok(Arc::new(CreateProcedureInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropProcedure(p) => Ok(Arc::new(DropProcedureInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::CallProcedure(p) => Ok(Arc::new(CallProcedureInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ExecuteImmediate(p) => Ok(Arc::new(ExecuteImmediateInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
+
             Plan::CreateConnection(p) => Ok(Arc::new(CreateConnectionInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -12,15 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::str::FromStr;
 use std::sync::Arc;
 
 use databend_common_catalog::table::AppendMode;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
-use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::executor::physical_plans::DistributedInsertSelect;
 use databend_common_sql::executor::PhysicalPlan;
@@ -28,13 +27,16 @@ use databend_common_sql::executor::PhysicalPlanBuilder;
 use databend_common_sql::plans::Insert;
 use databend_common_sql::plans::InsertInputSource;
 use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::Replace;
 use databend_common_sql::NameResolutionContext;
 
 use crate::interpreters::common::build_update_stream_meta_seq;
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_enforced_primary_key;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::interpreters::ReplaceInterpreter;
 use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
@@ -70,6 +72,40 @@ impl InsertInterpreter {
         let cast_needed = select_schema != DataSchema::from(output_schema.as_ref()).into();
         Ok(cast_needed)
     }
+
+    /// The rows inserted into a table with an enforced primary key are deduplicated against
+    /// the existing rows like `REPLACE INTO`, but a conflict is reported as an error.
+    fn enforced_primary_key_replace(&self, table: &dyn Table) -> Result<Option<Replace>> {
+        let columns = match table.get_table_info().meta.primary_key() {
+            Some((columns, true)) => columns,
+            _ => return Ok(None),
+        };
+        if self.plan.overwrite {
+            check_enforced_primary_key(table, "INSERT OVERWRITE")?;
+        }
+        if !matches!(
+            self.plan.source,
+            InsertInputSource::Values { .. } | InsertInputSource::SelectPlan(_)
+        ) {
+            check_enforced_primary_key(table, "Streaming INSERT")?;
+        }
+        let schema = table.schema();
+        let on_conflict_fields = columns
+            .iter()
+            .map(|column| schema.field_with_name(column).cloned())
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(Replace {
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: self.plan.table.clone(),
+            table_id: self.plan.table_id,
+            on_conflict_fields,
+            schema: self.plan.schema.clone(),
+            source: self.plan.source.clone(),
+            delete_when: None,
+            error_on_conflict: true,
+        }))
+    }
 }
 
 #[async_trait::async_trait]
@@ -91,6 +127,16 @@ impl Interpreter for InsertInterpreter {
         // check mutability
         table.check_mutable()?;
 
+        let table = match &self.plan.overwrite_partition {
+            Some(values) => table.with_overwrite_partition(values.clone())?,
+            None => table,
+        };
+
+        if let Some(replace) = self.enforced_primary_key_replace(table.as_ref())? {
+            let interpreter = ReplaceInterpreter::try_create(self.ctx.clone(), replace)?;
+            return interpreter.execute2().await;
+        }
+
         let mut build_res = PipelineBuildResult::create();
 
         match &self.plan.source {
@@ -116,29 +162,32 @@ impl Interpreter for InsertInterpreter {
                     1,
                 )?;
             }
-            InsertInputSource::StreamingWithFormat(format, _, input_context) => {
+            InsertInputSource::StreamingWithFormat(_, _, input_context) => {
                 let input_context = input_context.as_ref().expect("must success").clone();
                 input_context
                     .format
                     .exec_stream(input_context.clone(), &mut build_res.main_pipeline)?;
 
-                match StageFileFormatType::from_str(format) {
-                    Ok(f) if f.has_inner_schema() => {
-                        let dest_schema = self.plan.schema();
-                        let func_ctx = self.ctx.get_function_context()?;
+                // The format name may carry a clickhouse suffix such as `ArrowStream`,
+                // use the parsed params instead of the name.
+                if input_context
+                    .file_format_params
+                    .get_type()
+                    .has_inner_schema()
+                {
+                    let dest_schema = self.plan.schema();
+                    let func_ctx = self.ctx.get_function_context()?;
 
-                        build_res.main_pipeline.add_transform(
-                            |transform_input_port, transform_output_port| {
-                                TransformRuntimeCastSchema::try_create(
-                                    transform_input_port,
-                                    transform_output_port,
-                                    dest_schema.clone(),
-                                    func_ctx.clone(),
-                                )
-                            },
-                        )?;
-                    }
-                    _ => {}
+                    build_res.main_pipeline.add_transform(
+                        |transform_input_port, transform_output_port| {
+                            TransformRuntimeCastSchema::try_create(
+                                transform_input_port,
+                                transform_output_port,
+                                dest_schema.clone(),
+                                func_ctx.clone(),
+                            )
+                        },
+                    )?;
                 }
             }
             InsertInputSource::StreamingWithFileFormat {
@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_catalog::materialized_view::materialized_view_stream_name;
+use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_sql::binder::invalidate_materialized_view_candidates;
+use databend_common_sql::plans::CreateMaterializedViewPlan;
+use databend_common_sql::plans::CreateStreamPlan;
+use databend_enterprise_stream_handler::get_stream_handler;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL;
+
+use crate::interpreters::common::refresh_materialized_view;
+use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let mut create_table = self.plan.create_table.clone();
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&create_table.catalog).await?;
+
+        if let CreateOption::CreateIfNotExists(true) = create_table.create_option {
+            if catalog
+                .get_table(&tenant, &create_table.database, &create_table.table)
+                .await
+                .is_ok()
+            {
+                return Ok(PipelineBuildResult::create());
+            }
+        }
+
+        // The view is refreshed incrementally only if streams are enabled by the license,
+        // otherwise it is always fully refreshed.
+        let license_manager = get_license_manager();
+        let incremental_source = self.plan.incremental_source.clone().filter(|_| {
+            license_manager
+                .manager
+                .check_enterprise_enabled(self.ctx.get_license_key(), Feature::Stream)
+                .is_ok()
+        });
+        if incremental_source.is_some() {
+            create_table.options.insert(
+                OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL.to_string(),
+                "true".to_string(),
+            );
+        }
+
+        let catalog_name = create_table.catalog.clone();
+        let database = create_table.database.clone();
+        let view_name = create_table.table.clone();
+        CreateTableInterpreter::try_create(self.ctx.clone(), create_table)?
+            .execute2()
+            .await?;
+
+        if let Some((table_database, table_name)) = incremental_source {
+            let view = catalog.get_table(&tenant, &database, &view_name).await?;
+            let plan = CreateStreamPlan {
+                if_not_exists: false,
+                tenant,
+                catalog: catalog_name.clone(),
+                database: database.clone(),
+                stream_name: materialized_view_stream_name(view.get_id()),
+                table_database,
+                table_name,
+                navigation: None,
+                append_only: true,
+                comment: Some(format!("Change set of materialized view {}", view_name)),
+            };
+            get_stream_handler()
+                .do_create_stream(self.ctx.clone(), &plan)
+                .await?;
+        }
+
+        let res =
+            refresh_materialized_view(self.ctx.clone(), &catalog_name, &database, &view_name, true)
+                .await?;
+        invalidate_materialized_view_candidates(&self.ctx.get_tenant(), &catalog_name, &database);
+        Ok(res)
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::binder::invalidate_materialized_view_candidates;
+use databend_common_sql::plans::DropMaterializedViewPlan;
+use databend_common_sql::plans::DropTablePlan;
+use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+
+use crate::interpreters::DropTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropMaterializedViewPlan,
+}
+
+impl DropMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropMaterializedViewPlan) -> Result<Self> {
+        Ok(DropMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "DropMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.clone();
+        let db_name = self.plan.database.clone();
+        let view_name = self.plan.view_name.clone();
+        let tbl = self
+            .ctx
+            .get_table(&catalog_name, &db_name, &view_name)
+            .await
+            .ok();
+
+        let Some(table) = tbl else {
+            if self.plan.if_exists {
+                return Ok(PipelineBuildResult::create());
+            }
+            return Err(ErrorCode::UnknownTable(format!(
+                "unknown materialized view `{}`.`{}` in catalog '{}'",
+                db_name, view_name, &catalog_name
+            )));
+        };
+
+        if !table
+            .options()
+            .contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+        {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is not MATERIALIZED VIEW, please use `DROP {} {}.{}`",
+                &db_name,
+                &view_name,
+                match table.engine() {
+                    VIEW_ENGINE => "VIEW",
+                    STREAM_ENGINE => "STREAM",
+                    _ => "TABLE",
+                },
+                &db_name,
+                &view_name
+            )));
+        }
+
+        // The stream of the incremental view is dropped with the table.
+        let plan = DropTablePlan {
+            if_exists: self.plan.if_exists,
+            tenant: self.plan.tenant.clone(),
+            catalog: catalog_name.clone(),
+            database: db_name.clone(),
+            table: view_name,
+            all: false,
+        };
+        let res = DropTableInterpreter::try_create(self.ctx.clone(), plan)?
+            .execute2()
+            .await?;
+        invalidate_materialized_view_candidates(&self.plan.tenant, &catalog_name, &db_name);
+        Ok(res)
+    }
+}
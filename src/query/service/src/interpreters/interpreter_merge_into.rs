@@ -60,6 +60,7 @@ use databend_storages_common_table_meta::meta::TableSnapshot;
 use itertools::Itertools;
 
 use crate::interpreters::common::build_update_stream_meta_seq;
+use crate::interpreters::common::check_enforced_primary_key;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -154,6 +155,7 @@ impl MergeIntoInterpreter {
                 table.get_table_info().engine(),
             ))
         })?;
+        check_enforced_primary_key(table.as_ref(), "MERGE INTO")?;
 
         // attentation!! for now we have some strategies:
         // 1. target_build_optimization, this is enabled in standalone mode and in this case we don't need rowid column anymore.
@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_ast::parser::parse_script;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::CallProcedurePlan;
+
+use crate::interpreters::common::ScriptExecutor;
+use crate::interpreters::common::ScriptResult;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CallProcedureInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CallProcedurePlan,
+}
+
+impl CallProcedureInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CallProcedurePlan) -> Result<Self> {
+        Ok(CallProcedureInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CallProcedureInterpreter {
+    fn name(&self) -> &str {
+        "CallProcedureInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let procedure = &self.plan.procedure;
+        let tokens = tokenize_sql(&procedure.script)?;
+        let script = parse_script(&tokens, self.ctx.get_settings().get_sql_dialect()?)?;
+
+        let mut executor = ScriptExecutor::try_create(self.ctx.clone())?;
+        for ((name, data_type), arg) in procedure
+            .arg_names
+            .iter()
+            .zip(&procedure.arg_types)
+            .zip(&self.plan.args)
+        {
+            executor.declare_param(name, data_type, arg).await?;
+        }
+
+        let result = match (executor.run(&script).await?, &procedure.return_type) {
+            (ScriptResult::Scalar(data_type, value), Some(return_type)) => {
+                let (data_type, value) =
+                    executor.cast_value(&data_type, &value, return_type).await?;
+                ScriptResult::Scalar(data_type, value)
+            }
+            (ScriptResult::Table(..), Some(return_type)) => {
+                return Err(ErrorCode::ScriptSemanticError(format!(
+                    "Procedure {} returns a table, but its return type is {return_type}",
+                    procedure.name
+                )));
+            }
+            (result, _) => result,
+        };
+
+        let blocks = executor.result_to_blocks(&result)?;
+        if blocks.is_empty() {
+            return Ok(PipelineBuildResult::create());
+        }
+        PipelineBuildResult::from_blocks(blocks)
+    }
+}
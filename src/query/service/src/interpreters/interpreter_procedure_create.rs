@@ -0,0 +1,57 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_sql::plans::CreateProcedurePlan;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateProcedureInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateProcedurePlan,
+}
+
+impl CreateProcedureInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateProcedurePlan) -> Result<Self> {
+        Ok(CreateProcedureInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateProcedureInterpreter {
+    fn name(&self) -> &str {
+        "CreateProcedureInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_procedure_execute");
+
+        let _ = UserApiProvider::instance()
+            .add_procedure(
+                &self.plan.tenant,
+                self.plan.procedure.clone(),
+                &self.plan.create_option,
+            )
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
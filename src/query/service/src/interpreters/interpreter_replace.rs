@@ -48,6 +48,7 @@ use parking_lot::RwLock;
 
 use crate::interpreters::common::build_update_stream_meta_seq;
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_enforced_primary_key;
 use crate::interpreters::interpreter_copy_into_table::CopyIntoTableInterpreter;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
@@ -136,6 +137,18 @@ impl ReplaceInterpreter {
             )));
         }
 
+        // only the rows conflicting on the enforced primary key itself are replaced
+        if let Some((columns, true)) = table.get_table_info().meta.primary_key() {
+            let same_key = plan.on_conflict_fields.len() == columns.len()
+                && plan
+                    .on_conflict_fields
+                    .iter()
+                    .all(|f| columns.iter().any(|c| c == f.name()));
+            if !same_key {
+                check_enforced_primary_key(table.as_ref(), "REPLACE INTO on other columns")?;
+            }
+        }
+
         let catalog = self.ctx.get_catalog(&plan.catalog).await?;
         let schema = table.schema();
         let mut on_conflicts = Vec::with_capacity(plan.on_conflict_fields.len());
@@ -295,6 +308,7 @@ impl ReplaceInterpreter {
                 table_level_range_index,
                 need_insert: true,
                 delete_when,
+                error_on_conflict: plan.error_on_conflict,
             },
         )));
         root = Box::new(PhysicalPlan::ReplaceInto(Box::new(ReplaceInto {
@@ -312,6 +326,7 @@ impl ReplaceInterpreter {
                 .collect(),
             block_slots: None,
             need_insert: true,
+            error_on_conflict: plan.error_on_conflict,
         })));
         if is_distributed {
             root = Box::new(PhysicalPlan::Exchange(Exchange {
@@ -340,7 +355,7 @@ impl ReplaceInterpreter {
     fn check_on_conflicts(&self) -> Result<()> {
         if self.plan.on_conflict_fields.is_empty() {
             Err(ErrorCode::BadArguments(
-                "at least one column must be specified in the replace into .. on [conflict] statement",
+                "at least one column must be specified in the replace into .. on [conflict] statement, if the table has no primary key",
             ))
         } else {
             Ok(())
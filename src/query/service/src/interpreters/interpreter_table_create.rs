@@ -17,6 +17,7 @@ use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::LazyLock;
 
+use chrono::Utc;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -28,7 +29,12 @@ use databend_common_license::license::Feature::ComputedColumn;
 use databend_common_license::license_manager::get_license_manager;
 use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::principal::RoleInfo;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateSequenceReq;
+use databend_common_meta_app::schema::CreateTableReply;
 use databend_common_meta_app::schema::CreateTableReq;
+use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_app::schema::TableStatistics;
@@ -58,13 +64,19 @@ use databend_storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCES;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::error;
 
+use crate::interpreters::common::drop_auto_increment_sequences;
+use crate::interpreters::common::purge_temp_table;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -164,17 +176,36 @@ impl CreateTableInterpreter {
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
 
         // TODO: maybe the table creation and insertion should be a transaction, but it may require create_table support 2pc.
-        let reply = catalog.create_table(self.build_request(None)?).await?;
+        let reply = self.do_create_table(self.build_request(None)?).await?;
         if !reply.new_table {
             return Ok(PipelineBuildResult::create());
         }
 
-        let table = catalog
-            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
-            .await?;
+        let table = if self.is_temp() {
+            let table_info = self
+                .ctx
+                .temp_tbl_mgr()
+                .lock()
+                .get_table_by_id(reply.table_id);
+            let table_info = table_info.ok_or_else(|| {
+                ErrorCode::UnknownTable(format!("Unknown temporary table {}", self.plan.table))
+            })?;
+            // the new temporary table shadows the permanent one which may be cached
+            // when binding the select query.
+            self.ctx.evict_table_from_cache(
+                &self.plan.catalog,
+                &self.plan.database,
+                &self.plan.table,
+            )?;
+            catalog.get_table_by_info(&table_info)?
+        } else {
+            catalog
+                .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+                .await?
+        };
 
         // grant the ownership of the table to the current role.
-        let current_role = self.ctx.get_current_role();
+        let current_role = self.current_owner_role();
         if let Some(current_role) = current_role {
             let db = catalog
                 .get_database(tenant.as_str(), &self.plan.database)
@@ -211,6 +242,7 @@ impl CreateTableInterpreter {
             table_id: table.get_id(),
             schema: self.plan.schema.clone(),
             overwrite: false,
+            overwrite_partition: None,
             source: InsertInputSource::SelectPlan(select_plan),
         };
 
@@ -263,10 +295,10 @@ impl CreateTableInterpreter {
             self.build_request(stat)
         }?;
 
-        let reply = catalog.create_table(req.clone()).await?;
+        let reply = self.do_create_table(req).await?;
 
         // grant the ownership of the table to the current role, the above req.table_meta.owner could be removed in future.
-        if let Some(current_role) = self.ctx.get_current_role() {
+        if let Some(current_role) = self.current_owner_role() {
             let tenant = self.ctx.get_tenant();
             let db = catalog
                 .get_database(tenant.as_str(), &self.plan.database)
@@ -301,6 +333,79 @@ impl CreateTableInterpreter {
         Ok(PipelineBuildResult::create())
     }
 
+    fn is_temp(&self) -> bool {
+        self.plan.options.contains_key(OPT_KEY_TEMP_PREFIX)
+    }
+
+    /// The role which owns the new table, temporary tables are owned by the session.
+    fn current_owner_role(&self) -> Option<RoleInfo> {
+        match self.is_temp() {
+            true => None,
+            false => self.ctx.get_current_role(),
+        }
+    }
+
+    /// Create the table in the meta service, or in the session if it is temporary.
+    ///
+    /// The implicit sequences of the AUTOINCREMENT columns are created only if the
+    /// table is actually created, and those of a replaced table are dropped.
+    async fn do_create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
+        let reply = if !self.is_temp() {
+            let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+            let replaced = match req.create_option {
+                CreateOption::CreateOrReplace => catalog
+                    .get_table(&self.plan.tenant, &self.plan.database, &self.plan.table)
+                    .await
+                    .ok()
+                    .map(|table| table.schema()),
+                _ => None,
+            };
+            let reply = catalog.create_table(req).await?;
+            if let Some(schema) = replaced {
+                drop_auto_increment_sequences(self.ctx.as_ref(), &schema).await?;
+            }
+            reply
+        } else {
+            let temp_tbl_mgr = self.ctx.temp_tbl_mgr();
+            if let CreateOption::CreateOrReplace = req.create_option {
+                let replaced = temp_tbl_mgr
+                    .lock()
+                    .drop_table(&self.plan.database, &self.plan.table);
+                if let Some(table_info) = replaced {
+                    purge_temp_table(&table_info).await?;
+                    drop_auto_increment_sequences(self.ctx.as_ref(), &table_info.schema()).await?;
+                }
+            }
+            temp_tbl_mgr.lock().create_table(req)?
+        };
+
+        if reply.new_table {
+            self.create_auto_increment_sequences().await?;
+        }
+        Ok(reply)
+    }
+
+    /// Create the implicit sequences which generate the values of the AUTOINCREMENT columns.
+    async fn create_auto_increment_sequences(&self) -> Result<()> {
+        if self.plan.auto_increment_sequences.is_empty() {
+            return Ok(());
+        }
+
+        let catalog = self.ctx.get_default_catalog()?;
+        for sequence in &self.plan.auto_increment_sequences {
+            let req = CreateSequenceReq {
+                create_option: CreateOption::CreateIfNotExists(false),
+                ident: SequenceIdent::new(&self.plan.tenant, sequence),
+                start: 1,
+                increment: 1,
+                comment: Some(format!("AUTOINCREMENT of table {}", self.plan.table)),
+                created_on: Utc::now(),
+            };
+            let _ = catalog.create_sequence(req).await?;
+        }
+        Ok(())
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
@@ -338,6 +443,7 @@ impl CreateTableInterpreter {
                 Default::default()
             },
             comment: comment.unwrap_or_default(),
+            constraints: self.plan.constraints.clone(),
             ..Default::default()
         };
 
@@ -465,6 +571,11 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(OPT_KEY_CONNECTION_NAME);
 
     r.insert("transient");
+    r.insert(OPT_KEY_TEMP_PREFIX);
+
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCES);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL);
     r
 });
 
@@ -14,7 +14,9 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::materialized_view::materialized_view_stream_name;
 use databend_common_catalog::table::TableExt;
+use databend_common_catalog::temp_table::TempTblMgr;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_management::RoleApi;
@@ -27,7 +29,10 @@ use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL;
 
+use crate::interpreters::common::drop_auto_increment_sequences;
+use crate::interpreters::common::purge_temp_table;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -82,6 +87,16 @@ impl Interpreter for DropTableInterpreter {
                 &self.plan.table
             )));
         }
+        // the temporary table is dropped from the session, and its data is purged at once.
+        if TempTblMgr::is_temp_table(tbl.get_id()) {
+            let dropped = self.ctx.temp_tbl_mgr().lock().drop_table(db_name, tbl_name);
+            if let Some(table_info) = dropped {
+                purge_temp_table(&table_info).await?;
+                drop_auto_increment_sequences(self.ctx.as_ref(), &table_info.schema()).await?;
+            }
+            return Ok(PipelineBuildResult::create());
+        }
+
         let catalog = self.ctx.get_catalog(catalog_name).await?;
 
         // Although even if data is in READ_ONLY mode,
@@ -107,6 +122,28 @@ impl Interpreter for DropTableInterpreter {
             })
             .await?;
 
+        // the stream that an incremental materialized view is refreshed from is dropped with it.
+        if tbl
+            .options()
+            .contains_key(OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL)
+        {
+            let stream_name = materialized_view_stream_name(tbl.get_id());
+            if let Ok(stream) = catalog.get_table(&tenant, db_name, &stream_name).await {
+                catalog
+                    .drop_table_by_id(DropTableByIdReq {
+                        if_exists: true,
+                        tenant: tenant.clone(),
+                        table_name: stream_name,
+                        tb_id: stream.get_id(),
+                        db_id: db.get_db_info().ident.db_id,
+                    })
+                    .await?;
+            }
+        }
+
+        // the implicit sequences of the AUTOINCREMENT columns are dropped with the table.
+        drop_auto_increment_sequences(self.ctx.as_ref(), &tbl.schema()).await?;
+
         // we should do `drop ownership` after actually drop table, otherwise when we drop the ownership,
         // but the table still exists, in the interval maybe some unexpected things will happen.
         // drop the ownership
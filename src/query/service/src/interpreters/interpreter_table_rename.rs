@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::TableNameIdent;
@@ -46,6 +47,18 @@ impl Interpreter for RenameTableInterpreter {
         // TODO check privileges
         // You must have ALTER and DROP privileges for the original table,
         // and CREATE and INSERT privileges for the new table.
+        let temp_table = self
+            .ctx
+            .temp_tbl_mgr()
+            .lock()
+            .get_table(&self.plan.database, &self.plan.table);
+        if temp_table.is_some() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Rename temporary table {} is not supported",
+                self.plan.table
+            )));
+        }
+
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
         catalog
             .rename_table(RenameTableReq {
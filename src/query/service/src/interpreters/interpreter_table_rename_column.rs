@@ -19,6 +19,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
 use databend_common_meta_app::schema::DatabaseType;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::RenameTableColumnPlan;
@@ -29,6 +30,7 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_constraints;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -91,17 +93,39 @@ impl Interpreter for RenameTableColumnInterpreter {
 
             let mut schema: DataSchema = table_info.schema().into();
             let field = schema.field_with_name(self.plan.old_column.as_str())?;
-            if field.computed_expr().is_none() {
-                let index = schema.index_of(self.plan.old_column.as_str())?;
-                schema.rename_field(index, self.plan.new_column.as_str());
+            let is_computed = field.computed_expr().is_some();
+            let index = schema.index_of(self.plan.old_column.as_str())?;
+            schema.rename_field(index, self.plan.new_column.as_str());
+            let schema = Arc::new(schema);
+            if !is_computed {
                 // Check if old column is referenced by computed columns.
                 check_referenced_computed_columns(
                     self.ctx.clone(),
-                    Arc::new(schema),
+                    schema.clone(),
                     self.plan.old_column.as_str(),
                 )?;
             }
 
+            // The key constraints follow the renamed column, but the check constraints
+            // referring to the old column are no longer valid.
+            for constraint in new_table_meta.constraints.values_mut() {
+                if let TableConstraint::PrimaryKey { columns, .. }
+                | TableConstraint::Unique { columns } = constraint
+                {
+                    for column in columns.iter_mut() {
+                        if column == &self.plan.old_column {
+                            *column = self.plan.new_column.clone();
+                        }
+                    }
+                }
+            }
+            check_referenced_constraints(
+                self.ctx.clone(),
+                schema,
+                &new_table_meta.constraints,
+                self.plan.old_column.as_str(),
+            )?;
+
             new_table_meta.schema = Arc::new(self.plan.schema.clone());
 
             // update table options
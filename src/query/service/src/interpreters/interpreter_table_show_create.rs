@@ -32,6 +32,7 @@ use databend_storages_common_table_meta::table::is_internal_opt_key;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::debug;
 
 use crate::interpreters::Interpreter;
@@ -88,6 +89,9 @@ impl ShowCreateTableInterpreter {
         if table.options().contains_key("TRANSIENT") {
             table_create_sql = format!("CREATE TRANSIENT TABLE `{}` (\n", name)
         }
+        if table.options().contains_key(OPT_KEY_TEMP_PREFIX) {
+            table_create_sql = format!("CREATE TEMPORARY TABLE `{}` (\n", name)
+        }
 
         // Append columns.
         {
@@ -136,6 +140,9 @@ impl ShowCreateTableInterpreter {
 
                 columns.push(column);
             }
+            for (name, constraint) in table.get_table_info().meta.constraints.iter() {
+                columns.push(format!("  CONSTRAINT `{name}` {constraint}"));
+            }
             // Format is:
             //  (
             //      x,
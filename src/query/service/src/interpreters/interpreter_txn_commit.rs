@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::temp_table::TempTblMgr;
+use databend_common_catalog::txn_manager::TxnState;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let txn_mgr = self.ctx.txn_mgr();
+        let (state, mut req) = {
+            let txn_mgr = txn_mgr.lock();
+            (txn_mgr.state(), txn_mgr.req())
+        };
+        // the temporary tables are committed to the session, instead of the meta service
+        let (temp_table_metas, update_table_metas): (Vec<_>, Vec<_>) = req
+            .update_table_metas
+            .into_iter()
+            .partition(|req| TempTblMgr::is_temp_table(req.table_id));
+        req.update_table_metas = update_table_metas;
+
+        match state {
+            // COMMIT without a transaction takes no effect.
+            TxnState::AutoCommit => Ok(PipelineBuildResult::create()),
+            TxnState::Fail => {
+                txn_mgr.lock().clear();
+                Err(ErrorCode::CurrentTransactionIsAborted(
+                    "current transaction is aborted, it is rolled back",
+                ))
+            }
+            TxnState::Active => {
+                // All the mutated tables are committed at once, it fails if any of them
+                // has been changed by others since it is read in the transaction.
+                let res = if req.update_table_metas.is_empty() {
+                    Ok(())
+                } else {
+                    info!(
+                        "committing explicit transaction, mutated tables: {}",
+                        req.update_table_metas.len()
+                    );
+                    let catalog = self.ctx.get_default_catalog()?;
+                    catalog.update_multi_table_meta(req).await.map(|_| ())
+                };
+                txn_mgr.lock().clear();
+                res?;
+
+                let temp_tbl_mgr = self.ctx.temp_tbl_mgr();
+                let mut temp_tbl_mgr = temp_tbl_mgr.lock();
+                for req in temp_table_metas {
+                    temp_tbl_mgr.update_table_meta(req)?;
+                }
+                Ok(PipelineBuildResult::create())
+            }
+        }
+    }
+}
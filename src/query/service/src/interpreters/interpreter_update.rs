@@ -47,6 +47,7 @@ use databend_storages_common_table_meta::meta::TableSnapshot;
 use log::debug;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_enforced_primary_key;
 use crate::interpreters::common::create_push_down_filters;
 use crate::interpreters::interpreter_delete::replace_subquery;
 use crate::interpreters::interpreter_delete::subquery_filter;
@@ -144,6 +145,18 @@ impl UpdateInterpreter {
         let tbl = tbl.refresh(self.ctx.as_ref()).await?;
         // check mutability
         tbl.check_mutable()?;
+        // the updated rows are not deduplicated, the primary key columns can't be assigned
+        if let Some((columns, true)) = tbl.get_table_info().meta.primary_key() {
+            let schema = tbl.schema();
+            if self
+                .plan
+                .update_list
+                .keys()
+                .any(|index| columns.iter().any(|c| c == schema.field(*index).name()))
+            {
+                check_enforced_primary_key(tbl.as_ref(), "Updating the primary key columns")?;
+            }
+        }
 
         let selection = if !self.plan.subquery_desc.is_empty() {
             let support_row_id = tbl.supported_internal_column(ROW_ID_COLUMN_ID);
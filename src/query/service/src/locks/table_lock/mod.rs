@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_catalog::lock::Lock;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::temp_table::TempTblMgr;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::LockKey;
 use databend_common_meta_app::schema::LockType;
@@ -70,7 +71,8 @@ impl Lock for TableLock {
 
     async fn try_lock(&self, ctx: Arc<dyn TableContext>) -> Result<Option<LockGuard>> {
         let enabled_table_lock = ctx.get_settings().get_enable_table_lock().unwrap_or(false);
-        if enabled_table_lock {
+        // the temporary table is only visible to its session, no need to lock it.
+        if enabled_table_lock && !TempTblMgr::is_temp_table(self.get_table_id()) {
             self.lock_mgr.try_lock(ctx, self).await
         } else {
             Ok(None)
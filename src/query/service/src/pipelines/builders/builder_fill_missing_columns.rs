@@ -16,10 +16,19 @@ use std::sync::Arc;
 
 use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_sql::executor::physical_plans::AsyncFunctionDesc;
+use databend_common_sql::field_default_sequence;
 
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformAsyncFunction;
+use crate::pipelines::processors::transforms::TransformCheckConstraints;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
@@ -37,6 +46,40 @@ impl PipelineBuilder {
         let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
         let computed_schema: DataSchemaRef = Arc::new(table_computed_schema.into());
 
+        // Generate the values of the missing columns whose default values are allocated
+        // from sequences, they are appended to the source columns.
+        let mut source_fields = source_schema.fields().clone();
+        let mut async_func_descs = vec![];
+        for field in table_default_schema.fields() {
+            if source_schema.has_field(field.name()) {
+                continue;
+            }
+            if let Some(sequence) = field_default_sequence(field) {
+                let data_type = DataType::Number(NumberDataType::UInt64);
+                async_func_descs.push(AsyncFunctionDesc {
+                    func_name: "nextval".to_string(),
+                    display_name: format!("nextval({sequence})"),
+                    output_column: source_fields.len(),
+                    arguments: vec![sequence],
+                    data_type: Box::new(data_type.clone()),
+                });
+                source_fields.push(DataField::new(field.name(), data_type));
+            }
+        }
+        let source_schema = if async_func_descs.is_empty() {
+            source_schema
+        } else {
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                Ok(ProcessorPtr::create(TransformAsyncFunction::try_create(
+                    ctx.clone(),
+                    async_func_descs.clone(),
+                    transform_input_port,
+                    transform_output_port,
+                )?))
+            })?;
+            DataSchemaRefExt::create(source_fields)
+        };
+
         // Fill missing default columns and resort the columns.
         if source_schema != default_schema {
             pipeline.add_transform(|transform_input_port, transform_output_port| {
@@ -64,6 +107,20 @@ impl PipelineBuilder {
             })?;
         }
 
+        // Reject the rows violating the check constraints.
+        let constraints = &table.get_table_info().meta.constraints;
+        if TransformCheckConstraints::has_check_constraints(constraints) {
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                TransformCheckConstraints::try_create(
+                    ctx.clone(),
+                    transform_input_port,
+                    transform_output_port,
+                    computed_schema.clone(),
+                    constraints,
+                )
+            })?;
+        }
+
         Ok(())
     }
 }
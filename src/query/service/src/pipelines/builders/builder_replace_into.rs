@@ -100,6 +100,7 @@ impl PipelineBuilder {
             segments,
             block_slots,
             need_insert,
+            error_on_conflict,
         } = replace;
         let max_threads = self.settings.get_max_threads()?;
         let segment_partition_num = std::cmp::min(segments.len(), max_threads as usize);
@@ -149,6 +150,7 @@ impl PipelineBuilder {
                 segments,
                 block_slots.clone(),
                 io_request_semaphore,
+                *error_on_conflict,
             )?;
             self.main_pipeline.add_pipe(Pipe::create(
                 segment_partition_num,
@@ -232,6 +234,7 @@ impl PipelineBuilder {
                 segments,
                 block_slots.clone(),
                 io_request_semaphore,
+                *error_on_conflict,
             )?;
             assert_eq!(
                 segment_partition_num,
@@ -262,6 +265,7 @@ impl PipelineBuilder {
             table_schema,
             need_insert,
             delete_when,
+            error_on_conflict,
         } = deduplicate;
 
         let tbl = self
@@ -363,6 +367,7 @@ impl PipelineBuilder {
                 *table_is_empty,
                 table_level_range_index.clone(),
                 delete_when.map(|(expr, _)| (expr, delete_column_idx)),
+                *error_on_conflict,
             )?;
             self.main_pipeline
                 .add_pipe(replace_into_processor.into_pipe());
@@ -376,6 +381,7 @@ impl PipelineBuilder {
                 *table_is_empty,
                 table_level_range_index.clone(),
                 delete_when.map(|_| delete_column_idx),
+                *error_on_conflict,
             )?;
             self.main_pipeline
                 .add_pipe(replace_into_processor.into_pipe());
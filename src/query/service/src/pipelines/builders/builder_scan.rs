@@ -12,19 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
+use std::sync::Arc;
+
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::BlockEntry;
 use databend_common_expression::DataBlock;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::BlocksSource;
 use databend_common_pipeline_sources::OneBlockSource;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
 use databend_common_sql::executor::physical_plans::ConstantTableScan;
 use databend_common_sql::executor::physical_plans::CteScan;
+use databend_common_sql::executor::physical_plans::RecursiveCte;
+use databend_common_sql::executor::physical_plans::RecursiveCteScan;
 use databend_common_sql::executor::physical_plans::TableScan;
+use parking_lot::Mutex;
 
 use crate::pipelines::processors::transforms::MaterializedCteSource;
 use crate::pipelines::processors::transforms::TransformAddInternalColumns;
+use crate::pipelines::processors::transforms::TransformRecursiveCteSource;
+use crate::pipelines::processors::transforms::TransformSample;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -45,6 +55,20 @@ impl PipelineBuilder {
             })?;
         }
 
+        // Sample rows, also as the fallback of block level sampling.
+        if let Some(sample) = scan
+            .source
+            .push_downs
+            .as_ref()
+            .and_then(|push_downs| push_downs.sample.as_ref())
+        {
+            if !sample.block_level || !table.support_block_sample() {
+                self.main_pipeline.add_transform(|input, output| {
+                    TransformSample::try_create(input, output, sample)
+                })?;
+            }
+        }
+
         let schema = scan.source.schema();
         let mut projection = scan
             .name_mapping
@@ -87,6 +111,39 @@ impl PipelineBuilder {
         )
     }
 
+    pub(crate) fn build_recursive_cte(&mut self, r_cte: &RecursiveCte) -> Result<()> {
+        self.main_pipeline.add_source(
+            |output| {
+                TransformRecursiveCteSource::try_create(self.ctx.clone(), output, r_cte.clone())
+            },
+            1,
+        )
+    }
+
+    pub(crate) fn build_recursive_cte_scan(&mut self, r_cte_scan: &RecursiveCteScan) -> Result<()> {
+        // The pipeline of recursive term is rebuilt in each iteration,
+        // so the working table can be read when building the pipeline.
+        let blocks = self
+            .ctx
+            .get_recursive_cte_table(r_cte_scan.r_cte_id)
+            .into_iter()
+            .map(|block| {
+                let num_rows = block.num_rows();
+                let columns = r_cte_scan
+                    .offsets
+                    .iter()
+                    .map(|offset| block.get_by_offset(*offset).clone())
+                    .collect::<Vec<BlockEntry>>();
+                DataBlock::new(columns, num_rows)
+            })
+            .collect::<VecDeque<_>>();
+        let blocks = Arc::new(Mutex::new(blocks));
+        self.main_pipeline.add_source(
+            |output| BlocksSource::create(self.ctx.clone(), output, blocks.clone()),
+            1,
+        )
+    }
+
     pub(crate) fn build_constant_table_scan(&mut self, scan: &ConstantTableScan) -> Result<()> {
         self.main_pipeline.add_source(
             |output| {
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
 use databend_common_pipeline_sources::EmptySource;
 use databend_common_sql::evaluator::CompoundBlockOperator;
 use databend_common_sql::executor::physical_plans::MutationKind;
@@ -23,6 +26,7 @@ use databend_common_sql::gen_mutation_stream_operator;
 use databend_common_storages_fuse::operations::TransformSerializeBlock;
 use databend_common_storages_fuse::FuseTable;
 
+use crate::pipelines::processors::transforms::TransformCheckConstraints;
 use crate::pipelines::processors::TransformAddStreamColumns;
 use crate::pipelines::PipelineBuilder;
 
@@ -48,6 +52,22 @@ impl PipelineBuilder {
             &mut self.main_pipeline,
         )?;
 
+        let constraints = &table.get_table_info().meta.constraints;
+        if TransformCheckConstraints::has_check_constraints(constraints) {
+            let table_schema = &table.schema_with_stream().remove_virtual_computed_fields();
+            let schema: DataSchemaRef = Arc::new(table_schema.into());
+            self.main_pipeline
+                .add_transform(|transform_input_port, transform_output_port| {
+                    TransformCheckConstraints::try_create(
+                        self.ctx.clone(),
+                        transform_input_port,
+                        transform_output_port,
+                        schema.clone(),
+                        constraints,
+                    )
+                })?;
+        }
+
         if table.change_tracking_enabled() {
             let func_ctx = self.ctx.get_function_context()?;
             let (stream, operators) = gen_mutation_stream_operator(
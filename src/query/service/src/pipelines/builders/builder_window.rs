@@ -19,12 +19,22 @@ use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::SortColumnDescription;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::query_spill_prefix;
+use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
 use databend_common_sql::executor::physical_plans::Window;
+use databend_common_storage::DataOperator;
+use databend_common_storages_fuse::TableContext;
 
 use crate::pipelines::processors::transforms::FrameBound;
+use crate::pipelines::processors::transforms::TransformWindowPartitionBucket;
+use crate::pipelines::processors::transforms::TransformWindowPartitionScatter;
+use crate::pipelines::processors::transforms::TransformWindowPartitionSort;
 use crate::pipelines::processors::transforms::WindowFunctionInfo;
 use crate::pipelines::processors::TransformWindow;
 use crate::pipelines::PipelineBuilder;
+use crate::spillers::Spiller;
+use crate::spillers::SpillerConfig;
+use crate::spillers::SpillerType;
 
 impl PipelineBuilder {
     pub(crate) fn build_window(&mut self, window: &Window) -> Result<()> {
@@ -56,24 +66,88 @@ impl PipelineBuilder {
             .collect::<Result<Vec<_>>>()?;
 
         let old_output_len = self.main_pipeline.output_len();
-        if !partition_by.is_empty() || !order_by.is_empty() {
-            let mut sort_desc = Vec::with_capacity(partition_by.len() + order_by.len());
-
-            for offset in &partition_by {
-                sort_desc.push(SortColumnDescription {
-                    offset: *offset,
-                    asc: true,
-                    nulls_first: true,
-                    is_nullable: input_schema.field(*offset).is_nullable(),  // This information is not needed here.
-                })
-            }
+        let mut sort_desc = Vec::with_capacity(partition_by.len() + order_by.len());
+        for offset in &partition_by {
+            sort_desc.push(SortColumnDescription {
+                offset: *offset,
+                asc: true,
+                nulls_first: true,
+                is_nullable: input_schema.field(*offset).is_nullable(),  // This information is not needed here.
+            })
+        }
+        sort_desc.extend(order_by.clone());
 
-            sort_desc.extend(order_by.clone());
+        let max_threads = self.settings.get_max_threads()? as usize;
+        let (max_memory_usage, spilling_bytes_threshold) =
+            self.get_window_memory_settings(max_threads)?;
+        // Only the data with `PARTITION BY` can be spilled by partitions.
+        // The window without `PARTITION BY` is one partition, it relies on the sort spilling.
+        let may_spill = max_memory_usage != 0
+            && spilling_bytes_threshold != 0
+            && window.limit.is_none()
+            && !partition_by.is_empty()
+            && partition_by.iter().all(|offset| {
+                !matches!(
+                    input_schema.field(*offset).data_type().remove_nullable(),
+                    DataType::Tuple(_) | DataType::Array(_) | DataType::Map(_)
+                )
+            });
 
-            self.build_sort_pipeline(input_schema.clone(), sort_desc, window.limit, None)?;
+        if may_spill {
+            let max_block_size = self.settings.get_max_block_size()? as usize;
+            let config = SpillerConfig::create(query_spill_prefix(&self.ctx.get_tenant()));
+            let create_spiller = || {
+                let op = DataOperator::instance().operator();
+                Spiller::create(self.ctx.clone(), op, config.clone(), SpillerType::Window)
+            };
+
+            // Scatter the rows into hash partitions by `PARTITION BY` in parallel.
+            self.main_pipeline.add_transform(|input, output| {
+                Ok(ProcessorPtr::create(Box::new(
+                    TransformWindowPartitionScatter::create(
+                        input,
+                        output,
+                        partition_by.clone(),
+                        create_spiller(),
+                        max_memory_usage,
+                        spilling_bytes_threshold,
+                    ),
+                )))
+            })?;
+
+            // Gather the hash partitions of all the processors.
+            self.main_pipeline.try_resize(1)?;
+            self.main_pipeline.add_transform(|input, output| {
+                Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                    input,
+                    output,
+                    TransformWindowPartitionBucket::create(),
+                )))
+            })?;
+
+            // Every hash partition holds whole window partitions,
+            // so the hash partitions are sorted and evaluated in parallel.
+            self.main_pipeline.try_resize(max_threads)?;
+            self.main_pipeline.add_transform(|input, output| {
+                Ok(ProcessorPtr::create(Box::new(
+                    TransformWindowPartitionSort::create(
+                        input,
+                        output,
+                        partition_by.clone(),
+                        sort_desc.clone(),
+                        max_block_size,
+                        create_spiller(),
+                        spilling_bytes_threshold,
+                    ),
+                )))
+            })?;
+        } else {
+            if !sort_desc.is_empty() {
+                self.build_sort_pipeline(input_schema.clone(), sort_desc, window.limit, None)?;
+            }
+            // `TransformWindow` is a pipeline breaker.
+            self.main_pipeline.try_resize(1)?;
         }
-        // `TransformWindow` is a pipeline breaker.
-        self.main_pipeline.try_resize(1)?;
         let func = WindowFunctionInfo::try_create(&window.func, &input_schema)?;
         // Window
         self.main_pipeline.add_transform(|input, output| {
@@ -136,4 +210,32 @@ impl PipelineBuilder {
 
         self.main_pipeline.try_resize(old_output_len)
     }
+
+    fn get_window_memory_settings(&self, num_threads: usize) -> Result<(usize, usize)> {
+        let memory_ratio = self.settings.get_window_spilling_memory_ratio()?;
+        let bytes_limit_per_proc = self
+            .settings
+            .get_window_spilling_bytes_threshold_per_proc()?;
+        if memory_ratio == 0 && bytes_limit_per_proc == 0 {
+            // If these two settings are not set, do not enable window spill.
+            return Ok((0, 0));
+        }
+        let memory_ratio = (memory_ratio as f64 / 100_f64).min(1_f64);
+        let max_memory_usage = match self.settings.get_max_memory_usage()? {
+            0 => usize::MAX,
+            max_memory_usage => {
+                if memory_ratio == 0_f64 {
+                    usize::MAX
+                } else {
+                    (max_memory_usage as f64 * memory_ratio) as usize
+                }
+            }
+        };
+        let spill_threshold = match bytes_limit_per_proc {
+            0 => max_memory_usage / num_threads,
+            bytes => bytes,
+        };
+
+        Ok((max_memory_usage, spill_threshold))
+    }
 }
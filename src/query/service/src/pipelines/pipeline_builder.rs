@@ -142,6 +142,7 @@ impl PipelineBuilder {
             }
             PhysicalPlan::ProjectSet(project_set) => self.build_project_set(project_set),
             PhysicalPlan::Udf(udf) => self.build_udf(udf),
+            PhysicalPlan::AsyncFunction(async_func) => self.build_async_function(async_func),
             PhysicalPlan::Exchange(_) => Err(ErrorCode::Internal(
                 "Invalid physical plan with PhysicalPlan::Exchange",
             )),
@@ -149,6 +150,8 @@ impl PipelineBuilder {
             PhysicalPlan::MaterializedCte(materialized_cte) => {
                 self.build_materialized_cte(materialized_cte)
             }
+            PhysicalPlan::RecursiveCte(r_cte) => self.build_recursive_cte(r_cte),
+            PhysicalPlan::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan),
 
             // Copy into.
             PhysicalPlan::CopyIntoTable(copy) => self.build_copy_into_table(copy),
@@ -186,7 +186,8 @@ pub fn spilling_group_by_payload<Method: HashMethodBounds>(
     mut payload: HashTablePayload<PartitionedHashMethod<Method>, ()>,
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let unique_name = GlobalUniqName::unique();
-    let location = format!("{}/{}", location_prefix, unique_name);
+    let mut location = format!("{}/{}", location_prefix, unique_name);
+    let local_disk = ctx.get_local_spill_disk();
 
     let mut write_size = 0;
     let mut write_data = Vec::with_capacity(256);
@@ -234,18 +235,29 @@ pub fn spilling_group_by_payload<Method: HashMethodBounds>(
 
         let mut write_bytes = 0;
         if !write_data.is_empty() {
-            let mut writer = operator
-                .writer_with(&location)
-                .buffer(8 * 1024 * 1024)
-                .await?;
-            for write_bucket_data in write_data.into_iter() {
-                for data in write_bucket_data.into_iter() {
-                    write_bytes += data.len();
-                    writer.write(data).await?;
+            let write_data = write_data.into_iter().flatten().collect::<Vec<_>>();
+            write_bytes = write_data.iter().map(|data| data.len()).sum::<usize>();
+
+            // Spill to the local disk first, fall back to the remote storage if it is not available.
+            match local_disk.write(&ctx.get_id(), &write_data).await {
+                Some(local_location) => {
+                    for bucket_payload in spilled_buckets_payloads.iter_mut() {
+                        bucket_payload.location = local_location.clone();
+                    }
+                    location = local_location;
+                }
+                None => {
+                    let mut writer = operator
+                        .writer_with(&location)
+                        .buffer(8 * 1024 * 1024)
+                        .await?;
+                    for data in write_data.into_iter() {
+                        writer.write(data).await?;
+                    }
+
+                    writer.close().await?;
                 }
             }
-
-            writer.close().await?;
         }
 
         // perf
@@ -14,6 +14,7 @@
 
 use std::any::Any;
 use std::collections::VecDeque;
+use std::ops::Range;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -31,6 +32,7 @@ use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::processors::Profile;
 use databend_common_pipeline_core::processors::ProfileStatisticsName;
+use databend_common_storage::LocalSpillDisk;
 use itertools::Itertools;
 use log::info;
 use opendal::Operator;
@@ -47,6 +49,7 @@ pub struct TransformSpillReader<Method: HashMethodBounds, V: Send + Sync + 'stat
     output: Arc<OutputPort>,
 
     operator: Operator,
+    local_disk: Arc<LocalSpillDisk>,
     deserialized_meta: Option<BlockMetaInfoPtr>,
     reading_meta: Option<AggregateMeta<Method, V>>,
     deserializing_meta: Option<DeserializingMeta<Method, V>>,
@@ -180,11 +183,13 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
                 AggregateMeta::Serialized(_) => unreachable!(),
                 AggregateMeta::BucketSpilled(payload) => {
                     let instant = Instant::now();
-                    let data = self
-                        .operator
-                        .read_with(&payload.location)
-                        .range(payload.data_range.clone())
-                        .await?;
+                    let data = read_spilled_data(
+                        &self.operator,
+                        &self.local_disk,
+                        &payload.location,
+                        payload.data_range.clone(),
+                    )
+                    .await?;
 
                     info!(
                         "Read aggregate spill {} successfully, elapsed: {:?}",
@@ -200,12 +205,18 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
                         if let AggregateMeta::BucketSpilled(payload) = meta {
                             let location = payload.location.clone();
                             let operator = self.operator.clone();
+                            let local_disk = self.local_disk.clone();
                             let data_range = payload.data_range.clone();
                             read_data.push(databend_common_base::base::tokio::spawn(
                                 async_backtrace::frame!(async move {
                                     let instant = Instant::now();
-                                    let data =
-                                        operator.read_with(&location).range(data_range).await?;
+                                    let data = read_spilled_data(
+                                        &operator,
+                                        &local_disk,
+                                        &location,
+                                        data_range,
+                                    )
+                                    .await?;
 
                                     // perf
                                     {
@@ -235,7 +246,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
                                         instant.elapsed()
                                     );
 
-                                    Ok(data)
+                                    Ok::<_, ErrorCode>(data)
                                 }),
                             ));
                         }
@@ -246,7 +257,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
                             return Err(ErrorCode::TokioError("Cannot join tokio job"));
                         }
                         Ok(read_data) => {
-                            let read_data: Result<VecDeque<Vec<u8>>, opendal::Error> =
+                            let read_data: Result<VecDeque<Vec<u8>>> =
                                 read_data.into_iter().try_collect();
 
                             self.deserializing_meta = Some((block_meta, read_data?));
@@ -265,6 +276,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> TransformSpillReader<Me
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
         operator: Operator,
+        local_disk: Arc<LocalSpillDisk>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(TransformSpillReader::<
             Method,
@@ -273,6 +285,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> TransformSpillReader<Me
             input,
             output,
             operator,
+            local_disk,
             deserialized_meta: None,
             reading_meta: None,
             deserializing_meta: None,
@@ -305,3 +318,17 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> TransformSpillReader<Me
 
 pub type TransformGroupBySpillReader<Method> = TransformSpillReader<Method, ()>;
 pub type TransformAggregateSpillReader<Method> = TransformSpillReader<Method, usize>;
+
+/// Read the spilled data from the local disk or the remote storage where it was written.
+async fn read_spilled_data(
+    operator: &Operator,
+    local_disk: &LocalSpillDisk,
+    location: &str,
+    data_range: Range<u64>,
+) -> Result<Vec<u8>> {
+    if local_disk.is_local(location) {
+        return local_disk.read(location, Some(data_range)).await;
+    }
+
+    Ok(operator.read_with(location).range(data_range).await?)
+}
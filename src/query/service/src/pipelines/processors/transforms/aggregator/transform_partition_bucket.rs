@@ -34,6 +34,7 @@ use databend_common_pipeline_core::Pipe;
 use databend_common_pipeline_core::PipeItem;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::DataOperator;
+use databend_common_storage::LocalSpillDisk;
 
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::HashTablePayload;
@@ -455,11 +456,17 @@ pub fn build_partition_bucket<Method: HashMethodBounds, V: Copy + Send + Sync +
     pipeline.try_resize(input_nums)?;
 
     let operator = DataOperator::instance().operator();
+    let local_disk = LocalSpillDisk::instance();
     pipeline.add_transform(|input, output| {
         let operator = operator.clone();
+        let local_disk = local_disk.clone();
         match params.aggregate_functions.is_empty() {
-            true => TransformGroupBySpillReader::<Method>::create(input, output, operator),
-            false => TransformAggregateSpillReader::<Method>::create(input, output, operator),
+            true => {
+                TransformGroupBySpillReader::<Method>::create(input, output, operator, local_disk)
+            }
+            false => {
+                TransformAggregateSpillReader::<Method>::create(input, output, operator, local_disk)
+            }
         }
     })?;
 
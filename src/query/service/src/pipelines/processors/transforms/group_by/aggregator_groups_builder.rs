@@ -84,7 +84,9 @@ pub struct SerializedKeysGroupColumnsBuilder<'a> {
 impl<'a> SerializedKeysGroupColumnsBuilder<'a> {
     pub fn create(capacity: usize, data_capacity: usize, params: &AggregatorParams) -> Self {
         let (single_builder, data) = if params.group_data_types.len() == 1
-            && (params.group_data_types[0].is_string() || params.group_data_types[0].is_variant())
+            && (params.group_data_types[0].is_string()
+                || params.group_data_types[0].is_variant()
+                || params.group_data_types[0] == DataType::Geometry)
         {
             (
                 Some(BinaryColumnBuilder::with_capacity(capacity, data_capacity)),
@@ -125,6 +127,7 @@ impl<'a> GroupColumnsBuilder for SerializedKeysGroupColumnsBuilder<'a> {
                     })]);
                 }
                 DataType::Variant => return Ok(vec![Column::Variant(col)]),
+                DataType::Geometry => return Ok(vec![Column::Geometry(col)]),
                 _ => {}
             }
         }
@@ -171,6 +171,7 @@ impl HashJoinProbeState {
             | JoinType::Right
             | JoinType::Full => self.probe_join(input, probe_state),
             JoinType::Cross => self.cross_join(input, probe_state),
+            JoinType::Asof | JoinType::LeftAsof => unreachable!(),
         }
     }
 
@@ -23,15 +23,19 @@ mod transform_add_computed_columns;
 mod transform_add_const_columns;
 mod transform_add_internal_columns;
 mod transform_add_stream_columns;
+mod transform_async_function;
 mod transform_cast_schema;
+mod transform_check_constraints;
 mod transform_create_sets;
 mod transform_filter;
 mod transform_limit;
 mod transform_materialized_cte;
 mod transform_merge_block;
+mod transform_recursive_cte;
 mod transform_resort_addon;
 mod transform_resort_addon_without_source_schema;
 mod transform_runtime_cast_schema;
+mod transform_sample;
 mod transform_sort_spill;
 mod transform_srf;
 mod transform_udf;
@@ -45,7 +49,9 @@ pub use transform_add_computed_columns::TransformAddComputedColumns;
 pub use transform_add_const_columns::TransformAddConstColumns;
 pub use transform_add_internal_columns::TransformAddInternalColumns;
 pub use transform_add_stream_columns::TransformAddStreamColumns;
+pub use transform_async_function::TransformAsyncFunction;
 pub use transform_cast_schema::TransformCastSchema;
+pub use transform_check_constraints::TransformCheckConstraints;
 pub use transform_create_sets::TransformCreateSets;
 pub use transform_filter::TransformFilter;
 pub use transform_limit::TransformLimit;
@@ -53,12 +59,17 @@ pub use transform_materialized_cte::MaterializedCteSink;
 pub use transform_materialized_cte::MaterializedCteSource;
 pub use transform_materialized_cte::MaterializedCteState;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_recursive_cte::TransformRecursiveCteSource;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithoutSourceSchema;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
+pub use transform_sample::TransformSample;
 pub use transform_sort_spill::create_transform_sort_spill;
 pub use transform_srf::TransformSRF;
 pub use transform_udf::TransformUdf;
 pub use window::FrameBound;
 pub use window::TransformWindow;
+pub use window::TransformWindowPartitionBucket;
+pub use window::TransformWindowPartitionScatter;
+pub use window::TransformWindowPartitionSort;
 pub use window::WindowFunctionInfo;
@@ -0,0 +1,326 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NullableColumn;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt32Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::SortColumnDescription;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::executor::physical_plans::RangeJoin;
+use databend_common_sql::plans::JoinType;
+use parking_lot::RwLock;
+
+use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+
+pub struct AsofJoinState {
+    // Data types of the right table, used to build the null columns of left asof join
+    right_data_types: Vec<DataType>,
+    // The whole right table
+    right_block: RwLock<Option<DataBlock>>,
+    // The equi keys and the match key of right table, sorted by (equi keys, match key).
+    // Rows containing null keys are removed since they never match.
+    right_sorted_keys: RwLock<Vec<Column>>,
+    // The row index in `right_block` of each sorted key
+    right_sorted_indices: RwLock<Vec<u32>>,
+}
+
+impl AsofJoinState {
+    pub(crate) fn new(asof_join: &RangeJoin) -> Result<Self> {
+        let right_data_types = asof_join
+            .right
+            .output_schema()?
+            .fields()
+            .iter()
+            .map(|field| field.data_type().clone())
+            .collect();
+        Ok(Self {
+            right_data_types,
+            right_block: RwLock::new(None),
+            right_sorted_keys: Default::default(),
+            right_sorted_indices: Default::default(),
+        })
+    }
+}
+
+impl RangeJoinState {
+    // Sort the right table by the keys, and add a task for each left block.
+    pub(crate) fn asof_partition(&self) -> Result<()> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        let right_table = self.right_table.read();
+        if !right_table.is_empty() {
+            let right_block = DataBlock::concat(&right_table)?;
+            let right_exprs = self
+                .equi_conditions
+                .iter()
+                .map(|(_, right)| right)
+                .chain(std::iter::once(&self.conditions[0].right_expr))
+                .collect::<Vec<_>>();
+            let mut keys_block = evaluate_keys(&right_block, &right_exprs)?;
+            let num_keys = keys_block.num_columns();
+
+            // Append the row index, and remove the rows containing null keys
+            let mut validity = MutableBitmap::from_len_set(keys_block.num_rows());
+            for entry in keys_block.columns() {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, keys_block.num_rows());
+                if let Some(nullable) = column.as_nullable() {
+                    for (idx, valid) in nullable.validity.iter().enumerate() {
+                        if !valid {
+                            validity.set(idx, false);
+                        }
+                    }
+                }
+            }
+            keys_block.add_column(BlockEntry::new(
+                DataType::Number(NumberDataType::UInt32),
+                Value::Column(UInt32Type::from_data(
+                    (0..right_block.num_rows() as u32).collect::<Vec<_>>(),
+                )),
+            ));
+            let keys_block = keys_block.filter_with_bitmap(&validity.into())?;
+
+            let sort_descriptions = keys_block.columns()[0..num_keys]
+                .iter()
+                .enumerate()
+                .map(|(offset, entry)| SortColumnDescription {
+                    offset,
+                    asc: true,
+                    nulls_first: true,
+                    is_nullable: entry.data_type.is_nullable(),
+                })
+                .collect::<Vec<_>>();
+            let sorted_block = DataBlock::sort(&keys_block, &sort_descriptions, None)?;
+            let mut sorted_columns = sorted_block
+                .columns()
+                .iter()
+                .map(|entry| {
+                    entry
+                        .value
+                        .convert_to_full_column(&entry.data_type, sorted_block.num_rows())
+                })
+                .collect::<Vec<_>>();
+            let indices = sorted_columns.pop().unwrap();
+            let indices = UInt32Type::try_downcast_column(&indices).unwrap();
+
+            *asof_join_state.right_sorted_indices.write() = indices.to_vec();
+            *asof_join_state.right_sorted_keys.write() = sorted_columns;
+            *asof_join_state.right_block.write() = Some(right_block);
+        }
+
+        let left_table = self.left_table.read();
+        let mut tasks = self.tasks.write();
+        for left_idx in 0..left_table.len() {
+            tasks.push((left_idx, 0));
+        }
+        Ok(())
+    }
+
+    pub fn asof_join(&self, task_id: usize) -> Result<Vec<DataBlock>> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        let (left_idx, _) = self.tasks.read()[task_id];
+        let left_table = self.left_table.read();
+        let left_block = &left_table[left_idx];
+        let num_rows = left_block.num_rows();
+
+        let right_block = asof_join_state.right_block.read();
+        let right_sorted_keys = asof_join_state.right_sorted_keys.read();
+        let right_sorted_indices = asof_join_state.right_sorted_indices.read();
+
+        // Find the nearest match of each left row
+        let mut matches = Vec::with_capacity(num_rows);
+        if !right_sorted_indices.is_empty() {
+            let left_exprs = self
+                .equi_conditions
+                .iter()
+                .map(|(left, _)| left)
+                .chain(std::iter::once(&self.conditions[0].left_expr))
+                .collect::<Vec<_>>();
+            let left_keys = evaluate_keys(left_block, &left_exprs)?
+                .columns()
+                .iter()
+                .map(|entry| {
+                    entry
+                        .value
+                        .convert_to_full_column(&entry.data_type, num_rows)
+                })
+                .collect::<Vec<_>>();
+            let op = self.conditions[0].operator.as_str();
+            for row in 0..num_rows {
+                let keys = left_keys
+                    .iter()
+                    .map(|column| unsafe { column.index_unchecked(row) })
+                    .collect::<Vec<_>>();
+                matches.push(
+                    nearest_match(&right_sorted_keys, &keys, op)
+                        .map(|pos| right_sorted_indices[pos]),
+                );
+            }
+        } else {
+            matches.resize(num_rows, None);
+        }
+
+        let result = match self.join_type {
+            JoinType::LeftAsof => {
+                let mut result = left_block.clone();
+                match right_block.as_ref() {
+                    Some(right_block) => {
+                        let indices = matches
+                            .iter()
+                            .map(|pos| pos.unwrap_or(0))
+                            .collect::<Vec<_>>();
+                        let validity: Bitmap = matches.iter().map(|pos| pos.is_some()).collect();
+                        let right_result = right_block.take(&indices, &mut None)?;
+                        for entry in right_result.columns() {
+                            result.add_column(wrap_validity(entry, num_rows, &validity));
+                        }
+                    }
+                    None => {
+                        for data_type in asof_join_state.right_data_types.iter() {
+                            result.add_column(BlockEntry::new(
+                                data_type.wrap_nullable(),
+                                Value::Scalar(Scalar::Null),
+                            ));
+                        }
+                    }
+                }
+                result
+            }
+            _ => {
+                let (left_indices, right_indices): (Vec<u32>, Vec<u32>) = matches
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(row, pos)| pos.map(|pos| (row as u32, pos)))
+                    .unzip();
+                if left_indices.is_empty() {
+                    return Ok(vec![]);
+                }
+                let mut result = left_block.take(&left_indices, &mut None)?;
+                let right_result = right_block
+                    .as_ref()
+                    .unwrap()
+                    .take(&right_indices, &mut None)?;
+                for entry in right_result.columns() {
+                    result.add_column(entry.clone());
+                }
+                result
+            }
+        };
+        Ok(vec![result])
+    }
+}
+
+fn evaluate_keys(block: &DataBlock, exprs: &[&RemoteExpr]) -> Result<DataBlock> {
+    let func_ctx = FunctionContext::default();
+    let evaluator = Evaluator::new(block, &func_ctx, &BUILTIN_FUNCTIONS);
+    let mut columns = Vec::with_capacity(exprs.len());
+    for expr in exprs {
+        let expr = expr.as_expr(&BUILTIN_FUNCTIONS);
+        let column = evaluator
+            .run(&expr)?
+            .convert_to_full_column(expr.data_type(), block.num_rows());
+        columns.push(column);
+    }
+    Ok(DataBlock::new_from_columns(columns))
+}
+
+// Binary search the position of the nearest match in the sorted keys, the last key is the match key
+// compared by `op`, and the others are equi keys.
+fn nearest_match(sorted_keys: &[Column], keys: &[ScalarRef], op: &str) -> Option<usize> {
+    if keys.iter().any(|key| key.is_null()) {
+        return None;
+    }
+    let num_equi_keys = keys.len() - 1;
+    let cmp_equi_keys = |pos: usize| {
+        for (column, key) in sorted_keys[0..num_equi_keys].iter().zip(keys.iter()) {
+            let ordering = unsafe { column.index_unchecked(pos) }.cmp(key);
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    };
+    let len = sorted_keys[num_equi_keys].len();
+    let start = partition_point(0, len, |pos| cmp_equi_keys(pos) == Ordering::Less);
+    let end = partition_point(start, len, |pos| cmp_equi_keys(pos) != Ordering::Greater);
+
+    let match_key = &keys[num_equi_keys];
+    let cmp_match_key =
+        |pos: usize| unsafe { sorted_keys[num_equi_keys].index_unchecked(pos) }.cmp(match_key);
+    let include_equal = matches!(op, "gte" | "lt");
+    let pos = partition_point(start, end, |pos| match cmp_match_key(pos) {
+        Ordering::Less => true,
+        Ordering::Equal => include_equal,
+        Ordering::Greater => false,
+    });
+    match op {
+        // The largest right key less than (or equal to) the left key
+        "gt" | "gte" if pos > start => Some(pos - 1),
+        // The smallest right key greater than (or equal to) the left key
+        "lt" | "lte" if pos < end => Some(pos),
+        _ => None,
+    }
+}
+
+// Returns the first position in `[start, end)` where `pred` is false,
+// `pred` must be true for a prefix of the range and false for the rest.
+fn partition_point(mut start: usize, mut end: usize, pred: impl Fn(usize) -> bool) -> usize {
+    while start < end {
+        let mid = start + (end - start) / 2;
+        if pred(mid) {
+            start = mid + 1;
+        } else {
+            end = mid;
+        }
+    }
+    start
+}
+
+fn wrap_validity(entry: &BlockEntry, num_rows: usize, validity: &Bitmap) -> BlockEntry {
+    let column = entry
+        .value
+        .convert_to_full_column(&entry.data_type, num_rows);
+    let column = match column {
+        Column::Null { .. } => return entry.clone(),
+        Column::Nullable(column) => NullableColumn {
+            validity: &column.validity & validity,
+            column: column.column,
+        },
+        column => NullableColumn {
+            column,
+            validity: validity.clone(),
+        },
+    };
+    BlockEntry::new(
+        entry.data_type.wrap_nullable(),
+        Value::Column(Column::Nullable(Box::new(column))),
+    )
+}
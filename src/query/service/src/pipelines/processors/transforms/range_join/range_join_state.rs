@@ -31,10 +31,12 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_sql::executor::physical_plans::RangeJoin;
 use databend_common_sql::executor::physical_plans::RangeJoinCondition;
 use databend_common_sql::executor::physical_plans::RangeJoinType;
+use databend_common_sql::plans::JoinType;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 
 use crate::pipelines::executor::WatchNotify;
+use crate::pipelines::processors::transforms::range_join::AsofJoinState;
 use crate::pipelines::processors::transforms::range_join::IEJoinState;
 use crate::sessions::QueryContext;
 
@@ -47,8 +49,9 @@ pub struct RangeJoinState {
     // For iejoin, it's L1: sort by the first join key
     pub(crate) left_sorted_blocks: RwLock<Vec<DataBlock>>,
     pub(crate) conditions: Vec<RangeJoinCondition>,
-    // pub(crate) join_type: JoinType,
+    pub(crate) join_type: JoinType,
     pub(crate) other_conditions: Vec<RemoteExpr>,
+    pub(crate) equi_conditions: Vec<(RemoteExpr, RemoteExpr)>,
     // Pipeline event related
     pub(crate) partition_finished: Mutex<bool>,
     pub(crate) finished_notify: Arc<WatchNotify>,
@@ -61,25 +64,33 @@ pub struct RangeJoinState {
     pub(crate) finished_tasks: AtomicU64,
     // IEJoin state
     pub(crate) ie_join_state: Option<IEJoinState>,
+    // AsofJoin state
+    pub(crate) asof_join_state: Option<AsofJoinState>,
 }
 
 impl RangeJoinState {
-    pub fn new(ctx: Arc<QueryContext>, range_join: &RangeJoin) -> Self {
+    pub fn new(ctx: Arc<QueryContext>, range_join: &RangeJoin) -> Result<Self> {
         let ie_join_state = if matches!(range_join.range_join_type, RangeJoinType::IEJoin) {
             Some(IEJoinState::new(range_join))
         } else {
             None
         };
+        let asof_join_state = if matches!(range_join.range_join_type, RangeJoinType::Asof) {
+            Some(AsofJoinState::new(range_join)?)
+        } else {
+            None
+        };
 
-        Self {
+        Ok(Self {
             ctx,
             left_table: RwLock::new(vec![]),
             right_table: RwLock::new(vec![]),
             right_sorted_blocks: Default::default(),
             left_sorted_blocks: Default::default(),
             conditions: range_join.conditions.clone(),
-            // join_type: range_join.join_type.clone(),
+            join_type: range_join.join_type.clone(),
             other_conditions: range_join.other_conditions.clone(),
+            equi_conditions: range_join.equi_conditions.clone(),
             partition_finished: Mutex::new(false),
             finished_notify: Arc::new(WatchNotify::new()),
             left_sinker_count: RwLock::new(0),
@@ -88,7 +99,8 @@ impl RangeJoinState {
             row_offset: RwLock::new(vec![]),
             finished_tasks: AtomicU64::new(0),
             ie_join_state,
-        }
+            asof_join_state,
+        })
     }
 
     pub(crate) fn sink_right(&self, block: DataBlock) -> Result<()> {
@@ -172,6 +184,9 @@ impl RangeJoinState {
     }
 
     pub(crate) fn partition(&self) -> Result<()> {
+        if self.asof_join_state.is_some() {
+            return self.asof_partition();
+        }
         let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
         let left_table = self.left_table.read();
         // Right table is bigger than left table
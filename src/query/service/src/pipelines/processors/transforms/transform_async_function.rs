@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Value;
+use databend_common_meta_app::schema::GetSequenceNextValueReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_common_pipeline_transforms::processors::AsyncTransformer;
+use databend_common_sql::executor::physical_plans::AsyncFunctionDesc;
+
+use crate::pipelines::processors::InputPort;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::Processor;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct TransformAsyncFunction {
+    ctx: Arc<QueryContext>,
+    async_func_descs: Vec<AsyncFunctionDesc>,
+}
+
+impl TransformAsyncFunction {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        async_func_descs: Vec<AsyncFunctionDesc>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(AsyncTransformer::create(input, output, Self {
+            ctx,
+            async_func_descs,
+        }))
+    }
+
+    /// Allocate the values of all the rows with one request to meta service.
+    #[async_backtrace::framed]
+    async fn transform_sequence(
+        &self,
+        data_block: &mut DataBlock,
+        sequence_name: &str,
+    ) -> Result<()> {
+        let count = data_block.num_rows() as u64;
+        let values = if count == 0 {
+            vec![]
+        } else {
+            let req = GetSequenceNextValueReq {
+                ident: SequenceIdent::new(self.ctx.get_tenant(), sequence_name),
+                count,
+            };
+            let catalog = self.ctx.get_default_catalog()?;
+            let reply = catalog.get_sequence_next_value(req).await?;
+            (0..count)
+                .map(|i| reply.start + i * reply.step)
+                .collect::<Vec<_>>()
+        };
+
+        let column = UInt64Type::from_data(values);
+        data_block.add_column(BlockEntry::new(column.data_type(), Value::Column(column)));
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformAsyncFunction {
+    const NAME: &'static str = "AsyncFunction";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        for async_func_desc in &self.async_func_descs {
+            match async_func_desc.func_name.as_str() {
+                "nextval" => {
+                    self.transform_sequence(&mut data_block, &async_func_desc.arguments[0])
+                        .await?;
+                }
+                _ => {
+                    return Err(ErrorCode::Internal(format!(
+                        "Unknown async function: {}",
+                        async_func_desc.func_name
+                    )));
+                }
+            }
+        }
+        Ok(data_block)
+    }
+}
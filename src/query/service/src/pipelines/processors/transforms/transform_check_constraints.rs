@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::filter_helper::FilterHelpers;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_pipeline_transforms::processors::Transformer;
+use databend_common_sql::parse_computed_expr;
+
+use crate::pipelines::processors::InputPort;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::ProcessorPtr;
+use crate::sessions::QueryContext;
+
+struct CheckConstraint {
+    name: String,
+    check: String,
+    /// Evaluates to true on the rows violating the constraint,
+    /// the rows whose check expression is NULL are not violations.
+    violated: Expr,
+}
+
+/// Rejects the blocks containing rows that violate the CHECK constraints of the table.
+pub struct TransformCheckConstraints {
+    func_ctx: FunctionContext,
+    constraints: Vec<CheckConstraint>,
+}
+
+impl TransformCheckConstraints
+where Self: Transform
+{
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        table_constraints: &BTreeMap<String, TableConstraint>,
+    ) -> Result<ProcessorPtr> {
+        let mut constraints = Vec::new();
+        for (name, constraint) in table_constraints.iter() {
+            if let TableConstraint::Check { expr } = constraint {
+                let check_expr = parse_computed_expr(ctx.clone(), schema.clone(), expr)?;
+                let not_expr = check_function(None, "not", &[], &[check_expr], &BUILTIN_FUNCTIONS)?;
+                let violated =
+                    check_function(None, "is_true", &[], &[not_expr], &BUILTIN_FUNCTIONS)?;
+                constraints.push(CheckConstraint {
+                    name: name.clone(),
+                    check: expr.clone(),
+                    violated,
+                });
+            }
+        }
+
+        let func_ctx = ctx.get_function_context()?;
+        Ok(ProcessorPtr::create(Transformer::create(
+            input,
+            output,
+            Self {
+                func_ctx,
+                constraints,
+            },
+        )))
+    }
+
+    pub fn has_check_constraints(table_constraints: &BTreeMap<String, TableConstraint>) -> bool {
+        table_constraints
+            .values()
+            .any(|constraint| matches!(constraint, TableConstraint::Check { .. }))
+    }
+}
+
+impl Transform for TransformCheckConstraints {
+    const NAME: &'static str = "CheckConstraintsTransform";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        for constraint in self.constraints.iter() {
+            let violated = evaluator
+                .run(&constraint.violated)?
+                .try_downcast::<BooleanType>()
+                .unwrap();
+            if !FilterHelpers::is_all_unset(&violated) {
+                return Err(ErrorCode::CheckConstraintViolation(format!(
+                    "new row violates check constraint `{}`: CHECK ({})",
+                    constraint.name, constraint.check
+                )));
+            }
+        }
+        Ok(block)
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_sql::executor::physical_plans::RecursiveCte;
+use databend_common_sql::executor::PhysicalPlan;
+use futures_util::TryStreamExt;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::schedulers::build_query_pipeline_without_render_result_set;
+use crate::sessions::QueryContext;
+use crate::stream::PullingExecutorStream;
+
+/// Source of `RecursiveCte`.
+///
+/// Each call of `generate` evaluates one iteration: the anchor plan in the first call,
+/// the recursive plan in the following calls. The rows produced by an iteration become
+/// the working table read by `RecursiveCteScan` in the next iteration.
+pub struct TransformRecursiveCteSource {
+    ctx: Arc<QueryContext>,
+    r_cte_id: u32,
+    cte_name: String,
+    left: PhysicalPlan,
+    right: PhysicalPlan,
+    left_offsets: Vec<usize>,
+    right_offsets: Vec<usize>,
+    distinct: bool,
+    // Rows have been produced, only used for `UNION`
+    produced_rows: HashSet<Vec<Scalar>>,
+    max_depth: usize,
+    depth: usize,
+    finished: bool,
+}
+
+impl TransformRecursiveCteSource {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        r_cte: RecursiveCte,
+    ) -> Result<ProcessorPtr> {
+        let left_schema = r_cte.left.output_schema()?;
+        let right_schema = r_cte.right.output_schema()?;
+        let mut left_offsets = Vec::with_capacity(r_cte.pairs.len());
+        let mut right_offsets = Vec::with_capacity(r_cte.pairs.len());
+        for (left, right) in r_cte.pairs.iter() {
+            left_offsets.push(left_schema.index_of(left)?);
+            right_offsets.push(right_schema.index_of(right)?);
+        }
+        let max_depth = ctx.get_settings().get_max_cte_recursive_depth()? as usize;
+
+        AsyncSourcer::create(ctx.clone(), output_port, TransformRecursiveCteSource {
+            ctx,
+            r_cte_id: r_cte.plan_id,
+            cte_name: r_cte.cte_name,
+            left: *r_cte.left,
+            right: *r_cte.right,
+            left_offsets,
+            right_offsets,
+            distinct: r_cte.distinct,
+            produced_rows: HashSet::new(),
+            max_depth,
+            depth: 0,
+            finished: false,
+        })
+    }
+
+    async fn execute_plan(&self, plan: &PhysicalPlan) -> Result<Vec<DataBlock>> {
+        let ctx = QueryContext::create_from(self.ctx.clone());
+        let build_res = build_query_pipeline_without_render_result_set(&ctx, plan).await?;
+        let settings = ExecutorSettings::try_create(&ctx.get_settings(), ctx.get_id())?;
+        let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        PullingExecutorStream::create(pulling_executor)?
+            .try_collect::<Vec<DataBlock>>()
+            .await
+    }
+
+    // Remove the rows which have been produced by previous iterations or the current block.
+    fn deduplicate(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let mut bitmap = MutableBitmap::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let row_values = block
+                .columns()
+                .iter()
+                .map(|entry| entry.value.index(row).unwrap().to_owned())
+                .collect::<Vec<_>>();
+            bitmap.push(self.produced_rows.insert(row_values));
+        }
+        block.filter_with_bitmap(&bitmap.into())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for TransformRecursiveCteSource {
+    const NAME: &'static str = "RecursiveCteSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let (blocks, offsets) = if self.depth == 0 {
+            (
+                self.execute_plan(&self.left).await?,
+                self.left_offsets.clone(),
+            )
+        } else {
+            (
+                self.execute_plan(&self.right).await?,
+                self.right_offsets.clone(),
+            )
+        };
+
+        let mut working_table = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            let num_rows = block.num_rows();
+            let columns = offsets
+                .iter()
+                .map(|offset| block.get_by_offset(*offset).clone())
+                .collect::<Vec<BlockEntry>>();
+            let mut block = DataBlock::new(columns, num_rows);
+            if self.distinct {
+                block = self.deduplicate(block)?;
+            }
+            if !block.is_empty() {
+                working_table.push(block);
+            }
+        }
+
+        if working_table.is_empty() {
+            self.finished = true;
+            self.ctx.set_recursive_cte_table(self.r_cte_id, vec![]);
+            return Ok(None);
+        }
+
+        // the iteration producing rows beyond the max depth fails the query
+        self.depth += 1;
+        if self.depth > self.max_depth {
+            return Err(ErrorCode::Overflow(format!(
+                "Recursive cte '{}' exceeds the max recursive depth {}, please check the termination condition or increase `max_cte_recursive_depth`",
+                self.cte_name, self.max_depth
+            )));
+        }
+
+        let output = DataBlock::concat(&working_table)?;
+        self.ctx
+            .set_recursive_cte_table(self.r_cte_id, working_table);
+        Ok(Some(output))
+    }
+}
@@ -95,11 +95,17 @@ pub fn build_expression_transform(
         } else {
             let field = input_schema.field_with_name(f.name()).unwrap();
             let id = input_schema.index_of(f.name()).unwrap();
-            Expr::ColumnRef {
+            let expr = Expr::ColumnRef {
                 span: None,
                 id,
                 data_type: field.data_type().clone(),
                 display_name: field.name().clone(),
+            };
+            // e.g. the values generated from a sequence are always UInt64.
+            if field.data_type() != f.data_type() {
+                check_cast(None, false, expr, f.data_type(), &BUILTIN_FUNCTIONS)?
+            } else {
+                expr
             }
         };
         exprs.push(expr);
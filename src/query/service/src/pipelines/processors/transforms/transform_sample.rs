@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_catalog::plan::SampleInfo;
+use databend_common_exception::Result;
+use databend_common_expression::group_hash_columns;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_pipeline_transforms::processors::Transformer;
+use rand::rngs::SmallRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+/// Row level (Bernoulli) sampling, each row is kept with the sample probability.
+///
+/// If the seed is given, the random generator of each block is seeded by the seed
+/// and the content of the block, so the result is repeatable on the same data.
+pub struct TransformSample {
+    probability: f64,
+    seed: Option<u64>,
+    rng: SmallRng,
+}
+
+impl TransformSample
+where Self: Transform
+{
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        sample: &SampleInfo,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Transformer::create(
+            input,
+            output,
+            Self {
+                probability: sample.probability,
+                seed: sample.seed,
+                rng: SmallRng::from_entropy(),
+            },
+        )))
+    }
+
+    fn block_rng(seed: u64, block: &DataBlock) -> SmallRng {
+        let num_rows = block.num_rows();
+        let columns = block
+            .columns()
+            .iter()
+            .map(|entry| {
+                entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows)
+            })
+            .collect::<Vec<Column>>();
+
+        let mut hash = seed ^ num_rows as u64;
+        if !columns.is_empty() {
+            for row_hash in group_hash_columns(&columns) {
+                hash = hash.rotate_left(5) ^ row_hash;
+            }
+        }
+        SmallRng::seed_from_u64(hash)
+    }
+}
+
+impl Transform for TransformSample {
+    const NAME: &'static str = "SampleTransform";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        if num_rows == 0 || self.probability >= 1.0 {
+            return Ok(block);
+        }
+
+        let mut block_rng = self.seed.map(|seed| Self::block_rng(seed, &block));
+        let rng = block_rng.as_mut().unwrap_or(&mut self.rng);
+        let mut bitmap = MutableBitmap::with_capacity(num_rows);
+        for _ in 0..num_rows {
+            bitmap.push(rng.gen::<f64>() < self.probability);
+        }
+        block.filter_with_bitmap(&bitmap.into())
+    }
+}
@@ -14,8 +14,16 @@
 
 mod frame_bound;
 mod transform_window;
+mod transform_window_partition_bucket;
+mod transform_window_partition_scatter;
+mod transform_window_partition_sort;
 mod window_function;
+mod window_partition_buffer;
+mod window_partition_meta;
 
 pub use frame_bound::FrameBound;
 pub use transform_window::TransformWindow;
+pub use transform_window_partition_bucket::TransformWindowPartitionBucket;
+pub use transform_window_partition_scatter::TransformWindowPartitionScatter;
+pub use transform_window_partition_sort::TransformWindowPartitionSort;
 pub use window_function::WindowFunctionInfo;
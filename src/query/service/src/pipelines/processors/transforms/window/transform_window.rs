@@ -139,6 +139,11 @@ pub struct TransformWindow<T: Number> {
     is_empty_frame: bool,
     // If window function is ranking function
     is_ranking: bool,
+
+    // Used for `IGNORE NULLS`: the non-NULL argument values collected from the rows before `non_null_scanned_end`.
+    // The collected values slide with the frame, so each row of the partition is scanned only once.
+    non_null_values: VecDeque<(RowPtr, Scalar)>,
+    non_null_scanned_end: RowPtr,
 }
 
 impl<T: Number> TransformWindow<T> {
@@ -534,17 +539,23 @@ impl<T: Number> TransformWindow<T> {
                 builder.push(ScalarRef::Number(NumberScalar::Float64(percent.into())));
             }
             WindowFunctionImpl::LagLead(ll) => {
-                let value = if self.frame_start == self.frame_end {
-                    let default_value = match ll.default.clone() {
-                        LagLeadDefault::Null => Scalar::Null,
-                        LagLeadDefault::Index(col) => {
-                            let block =
-                                &self.blocks[self.current_row.block - self.first_block].block;
-                            let value = &block.get_by_offset(col).value;
-                            value.index(self.current_row.row).unwrap().to_owned()
+                let value = if ll.ignore_null {
+                    let value = if ll.is_lag {
+                        // Only the last `offset` non-NULL values are kept.
+                        if self.non_null_values.len() == ll.offset {
+                            self.non_null_values.front()
+                        } else {
+                            None
                         }
+                    } else {
+                        self.non_null_values.get(ll.offset - 1)
                     };
-                    default_value
+                    match value {
+                        Some((_, value)) => value.clone(),
+                        None => self.lag_lead_default_value(&ll.default),
+                    }
+                } else if self.frame_start == self.frame_end {
+                    self.lag_lead_default_value(&ll.default)
                 } else {
                     let block = &self
                         .blocks
@@ -559,7 +570,16 @@ impl<T: Number> TransformWindow<T> {
                 builder.push(value.as_ref());
             }
             WindowFunctionImpl::NthValue(func) => {
-                let value = if self.frame_start == self.frame_end {
+                let value = if func.ignore_null {
+                    let value = if self.is_empty_frame && !self.is_null_frame {
+                        None
+                    } else if let Some(n) = func.n {
+                        self.non_null_values.get(n as usize - 1)
+                    } else {
+                        self.non_null_values.back()
+                    };
+                    value.map_or(Scalar::Null, |(_, value)| value.clone())
+                } else if self.frame_start == self.frame_end {
                     Scalar::Null
                 } else if let Some(mut n) = func.n {
                     let mut cur = self.frame_start;
@@ -627,6 +647,51 @@ impl<T: Number> TransformWindow<T> {
         Ok(())
     }
 
+    fn lag_lead_default_value(&self, default: &LagLeadDefault) -> Scalar {
+        match default {
+            LagLeadDefault::Null => Scalar::Null,
+            LagLeadDefault::Index(col) => {
+                let block = &self.blocks[self.current_row.block - self.first_block].block;
+                let value = &block.get_by_offset(*col).value;
+                value.index(self.current_row.row).unwrap().to_owned()
+            }
+        }
+    }
+
+    /// Drop the collected non-NULL values which have slid out of the frame.
+    fn pop_non_null_values(&mut self) {
+        while matches!(self.non_null_values.front(), Some((row, _)) if *row < self.frame_start) {
+            self.non_null_values.pop_front();
+        }
+        self.non_null_scanned_end = self.non_null_scanned_end.max(self.frame_start);
+    }
+
+    /// Collect the non-NULL values of column `arg` from the rows in [`non_null_scanned_end`, `frame_end`).
+    ///
+    /// Stop scanning once `limit` values are collected; if `keep_last` is set, only the last `keep_last` values are kept.
+    fn collect_non_null_values(
+        &mut self,
+        arg: usize,
+        limit: Option<usize>,
+        keep_last: Option<usize>,
+    ) {
+        while self.non_null_scanned_end < self.frame_end {
+            if limit.is_some_and(|limit| self.non_null_values.len() >= limit) {
+                return;
+            }
+            let row = self.non_null_scanned_end;
+            let value = unsafe { self.column_at(&row, arg).index_unchecked(row.row) };
+            if !value.is_null() {
+                let value = value.to_owned();
+                self.non_null_values.push_back((row, value));
+                if keep_last.is_some_and(|keep_last| self.non_null_values.len() > keep_last) {
+                    self.non_null_values.pop_front();
+                }
+            }
+            self.non_null_scanned_end = self.advance_row(row);
+        }
+    }
+
     #[inline]
     fn if_need_check_null_frame(&self) -> bool {
         self.frame_unit.is_range() && self.order_by.len() == 1 && self.order_by[0].is_nullable
@@ -711,6 +776,8 @@ impl TransformWindow<u64> {
             input_is_finished: false,
             is_empty_frame,
             is_ranking,
+            non_null_values: VecDeque::new(),
+            non_null_scanned_end: RowPtr::default(),
         })
     }
 }
@@ -788,6 +855,8 @@ where T: Number + ResultTypeOfUnary
             input_is_finished: false,
             is_empty_frame,
             is_ranking,
+            non_null_values: VecDeque::new(),
+            non_null_scanned_end: RowPtr::default(),
         })
     }
 
@@ -912,6 +981,27 @@ where T: Number + ResultTypeOfUnary
     fn compute_on_frame(&mut self) -> Result<()> {
         match &self.func {
             WindowFunctionImpl::Aggregate(agg) => self.apply_aggregate(agg),
+            WindowFunctionImpl::LagLead(ll) if ll.ignore_null => {
+                let (arg, offset) = (ll.arg, ll.offset);
+                if ll.is_lag {
+                    // The frame is the preceding row, keep the last `offset` non-NULL values of the partition.
+                    self.collect_non_null_values(arg, None, Some(offset));
+                } else {
+                    // The frame is all the following rows.
+                    self.pop_non_null_values();
+                    self.collect_non_null_values(arg, Some(offset), None);
+                }
+                Ok(())
+            }
+            WindowFunctionImpl::NthValue(func) if func.ignore_null => {
+                let (arg, n) = (func.arg, func.n);
+                self.pop_non_null_values();
+                match n {
+                    Some(n) => self.collect_non_null_values(arg, Some(n as usize), None),
+                    None => self.collect_non_null_values(arg, None, Some(1)),
+                }
+                Ok(())
+            }
             _ => Ok(()),
         }
     }
@@ -1064,6 +1154,10 @@ where T: Number + ResultTypeOfUnary
                 self.peer_group_start = self.partition_start;
                 self.peer_group_end = self.partition_start;
 
+                // reset collected non-NULL values
+                self.non_null_values.clear();
+                self.non_null_scanned_end = self.partition_start;
+
                 // reset row number, rank, ...
                 self.current_row_in_partition = 1;
                 self.current_rank = 1;
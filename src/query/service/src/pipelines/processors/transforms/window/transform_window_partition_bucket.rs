@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+
+use super::window_partition_buffer::WINDOW_SPILL_PARTITIONS;
+use super::window_partition_meta::WindowPartitionMeta;
+use super::window_partition_meta::WindowPayload;
+
+/// Gathers the payloads of every hash partition from all the [`super::TransformWindowPartitionScatter`],
+/// then outputs the hash partitions one by one, so that they can be sorted in parallel.
+pub struct TransformWindowPartitionBucket {
+    partitions: Vec<Vec<WindowPayload>>,
+}
+
+impl TransformWindowPartitionBucket {
+    pub fn create() -> Self {
+        Self {
+            partitions: (0..WINDOW_SPILL_PARTITIONS).map(|_| vec![]).collect(),
+        }
+    }
+}
+
+impl AccumulatingTransform for TransformWindowPartitionBucket {
+    const NAME: &'static str = "TransformWindowPartitionBucket";
+
+    fn transform(&mut self, mut data: DataBlock) -> Result<Vec<DataBlock>> {
+        let meta = data
+            .take_meta()
+            .and_then(WindowPartitionMeta::downcast_from)
+            .ok_or_else(|| ErrorCode::Internal("It's a bug: expect WindowPartitionMeta"))?;
+        self.partitions[meta.partition].extend(meta.payloads);
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output {
+            return Ok(vec![]);
+        }
+
+        Ok(std::mem::take(&mut self.partitions)
+            .into_iter()
+            .enumerate()
+            .filter(|(_, payloads)| !payloads.is_empty())
+            .map(|(partition, payloads)| {
+                DataBlock::empty_with_meta(WindowPartitionMeta::create(partition, payloads))
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_base::runtime::GLOBAL_MEM_STAT;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+
+use super::window_partition_buffer::WindowPartitionBuffer;
+use super::window_partition_meta::WindowPartitionMeta;
+use crate::spillers::Spiller;
+
+enum State {
+    /// Collecting input blocks into the hash partitions.
+    Collect,
+    /// The buffered partitions exceed the memory limit and need to be spilled.
+    Spill,
+    /// The input is finished, outputting the payloads of the hash partitions.
+    Finish,
+}
+
+/// The first step of window spilling, it runs in parallel.
+///
+/// Every input row is scattered into a hash partition by the `PARTITION BY` columns,
+/// when the buffered data exceeds the memory limit, all the buffered partitions are spilled to storage.
+/// After the input is finished, the payloads of every hash partition are output as a [`WindowPartitionMeta`],
+/// which are gathered by [`super::TransformWindowPartitionBucket`].
+pub struct TransformWindowPartitionScatter {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    state: State,
+    input_data: Option<DataBlock>,
+    output_data: VecDeque<DataBlock>,
+
+    buffer: Option<WindowPartitionBuffer>,
+    spiller: Spiller,
+    max_memory_usage: usize,
+    spilling_bytes_threshold: usize,
+}
+
+impl TransformWindowPartitionScatter {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        partition_indices: Vec<usize>,
+        spiller: Spiller,
+        max_memory_usage: usize,
+        spilling_bytes_threshold: usize,
+    ) -> Self {
+        Self {
+            input,
+            output,
+            state: State::Collect,
+            input_data: None,
+            output_data: VecDeque::new(),
+            buffer: Some(WindowPartitionBuffer::create(partition_indices, 0)),
+            spiller,
+            max_memory_usage,
+            spilling_bytes_threshold,
+        }
+    }
+
+    fn collect(&mut self, block: DataBlock) -> Result<()> {
+        let buffer = self.buffer.as_mut().unwrap();
+        buffer.add_block(block)?;
+
+        if buffer.buffered_bytes() >= self.spilling_bytes_threshold
+            || GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.max_memory_usage
+        {
+            self.state = State::Spill;
+        }
+        Ok(())
+    }
+
+    fn output_partitions(&mut self) {
+        let buffer = self.buffer.take().unwrap();
+        for (partition, payloads) in buffer.into_partitions().into_iter().enumerate() {
+            if !payloads.is_empty() {
+                self.output_data.push_back(DataBlock::empty_with_meta(
+                    WindowPartitionMeta::create(partition, payloads),
+                ));
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformWindowPartitionScatter {
+    fn name(&self) -> String {
+        String::from("TransformWindowPartitionScatter")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_data.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.state {
+            State::Collect => {
+                if self.input_data.is_some() {
+                    return Ok(Event::Sync);
+                }
+
+                if self.input.has_data() {
+                    self.input_data = Some(self.input.pull_data().unwrap()?);
+                    return Ok(Event::Sync);
+                }
+
+                if self.input.is_finished() {
+                    self.state = State::Finish;
+                    self.output_partitions();
+                    return self.event();
+                }
+
+                self.input.set_need_data();
+                Ok(Event::NeedData)
+            }
+            State::Spill => Ok(Event::Async),
+            State::Finish => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(block) = self.input_data.take() {
+            self.collect(block)?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let State::Spill = self.state {
+            let buffer = self.buffer.as_mut().unwrap();
+            buffer.spill(&mut self.spiller).await?;
+            self.state = State::Collect;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::SortColumnDescription;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use log::warn;
+
+use super::window_partition_buffer::restore_window_payload;
+use super::window_partition_buffer::WindowPartitionBuffer;
+use super::window_partition_buffer::WINDOW_SPILL_MAX_LEVEL;
+use super::window_partition_meta::WindowPartitionMeta;
+use super::window_partition_meta::WindowPayload;
+use crate::spillers::Spiller;
+
+/// A hash partition waiting to be restored.
+struct PendingPartition {
+    /// The level the partition is split on if it is too large to restore.
+    level: usize,
+    payloads: Vec<WindowPayload>,
+}
+
+/// The last step of window spilling, it runs in parallel and each hash partition is handled by one processor.
+///
+/// The hash partition is restored, sorted by the `PARTITION BY` and `ORDER BY` columns
+/// and streamed to [`super::TransformWindow`].
+/// If the hash partition is larger than the memory limit, it is split into smaller hash partitions
+/// by the next bits of the hash, which are spilled and restored recursively.
+pub struct TransformWindowPartitionSort {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    pending: VecDeque<PendingPartition>,
+    output_data: VecDeque<DataBlock>,
+
+    partition_indices: Vec<usize>,
+    sort_desc: Vec<SortColumnDescription>,
+    max_block_size: usize,
+
+    spiller: Spiller,
+    spilling_bytes_threshold: usize,
+}
+
+impl TransformWindowPartitionSort {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        partition_indices: Vec<usize>,
+        sort_desc: Vec<SortColumnDescription>,
+        max_block_size: usize,
+        spiller: Spiller,
+        spilling_bytes_threshold: usize,
+    ) -> Self {
+        Self {
+            input,
+            output,
+            pending: VecDeque::new(),
+            output_data: VecDeque::new(),
+            partition_indices,
+            sort_desc,
+            max_block_size,
+            spiller,
+            spilling_bytes_threshold,
+        }
+    }
+
+    async fn restore(&mut self, partition: PendingPartition) -> Result<()> {
+        let mut blocks = Vec::with_capacity(partition.payloads.len());
+        for payload in partition.payloads {
+            blocks.push(restore_window_payload(&mut self.spiller, payload).await?);
+        }
+        if blocks.is_empty() {
+            return Ok(());
+        }
+
+        let block = DataBlock::concat(&blocks)?;
+        let block = DataBlock::sort(&block, &self.sort_desc, None)?;
+        self.output_data
+            .extend(block.split_by_rows_no_tail(self.max_block_size));
+        Ok(())
+    }
+
+    /// Split the partition by the next bits of the hash, the buffered rows are spilled
+    /// whenever they exceed the memory limit, so only a part of the partition is in memory.
+    async fn repartition(&mut self, partition: PendingPartition) -> Result<()> {
+        let mut buffer =
+            WindowPartitionBuffer::create(self.partition_indices.clone(), partition.level);
+        for payload in partition.payloads {
+            let block = restore_window_payload(&mut self.spiller, payload).await?;
+            buffer.add_block(block)?;
+            if buffer.buffered_bytes() >= self.spilling_bytes_threshold {
+                buffer.spill(&mut self.spiller).await?;
+            }
+        }
+
+        let partitions = buffer
+            .into_partitions()
+            .into_iter()
+            .filter(|payloads| !payloads.is_empty())
+            .collect::<Vec<_>>();
+        if partitions.len() == 1 {
+            // All the rows have the same hash, it is most likely a single window partition,
+            // which cannot be split any more.
+            let payloads = partitions.into_iter().next().unwrap();
+            warn!(
+                "Window partition of {} bytes cannot be split, restore it in memory",
+                payloads.iter().map(WindowPayload::bytes).sum::<usize>()
+            );
+            return self
+                .restore(PendingPartition {
+                    level: WINDOW_SPILL_MAX_LEVEL,
+                    payloads,
+                })
+                .await;
+        }
+
+        for payloads in partitions.into_iter().rev() {
+            self.pending.push_front(PendingPartition {
+                level: partition.level + 1,
+                payloads,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformWindowPartitionSort {
+    fn name(&self) -> String {
+        String::from("TransformWindowPartitionSort")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_data.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if !self.pending.is_empty() {
+            return Ok(Event::Async);
+        }
+
+        if self.input.has_data() {
+            let mut block = self.input.pull_data().unwrap()?;
+            let meta = block
+                .take_meta()
+                .and_then(WindowPartitionMeta::downcast_from)
+                .ok_or_else(|| ErrorCode::Internal("It's a bug: expect WindowPartitionMeta"))?;
+            // The hash partitions are scattered on level 0.
+            self.pending.push_back(PendingPartition {
+                level: 1,
+                payloads: meta.payloads,
+            });
+            return Ok(Event::Async);
+        }
+
+        if self.input.is_finished() {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let Some(partition) = self.pending.pop_front() {
+            let bytes = partition
+                .payloads
+                .iter()
+                .map(WindowPayload::bytes)
+                .sum::<usize>();
+            if bytes <= self.spilling_bytes_threshold || partition.level >= WINDOW_SPILL_MAX_LEVEL {
+                self.restore(partition).await?;
+            } else {
+                self.repartition(partition).await?;
+            }
+        }
+        Ok(())
+    }
+}
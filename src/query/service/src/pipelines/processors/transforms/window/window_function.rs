@@ -95,9 +95,12 @@ impl Drop for WindowFuncAggImpl {
 
 #[derive(Clone)]
 pub struct WindowFuncLagLeadImpl {
+    pub is_lag: bool,
+    pub offset: usize,
     pub arg: usize,
     pub default: LagLeadDefault,
     pub return_type: DataType,
+    pub ignore_null: bool,
 }
 
 #[derive(Clone)]
@@ -105,6 +108,7 @@ pub struct WindowFuncNthValueImpl {
     pub n: Option<u64>,
     pub arg: usize,
     pub return_type: DataType,
+    pub ignore_null: bool,
 }
 
 #[derive(Clone)]
@@ -211,9 +215,12 @@ impl WindowFunctionInfo {
                     }
                 };
                 Self::LagLead(WindowFuncLagLeadImpl {
+                    is_lag: ll.is_lag,
+                    offset: ll.offset as usize,
                     arg: new_arg,
                     default: new_default,
                     return_type: ll.return_type.clone(),
+                    ignore_null: ll.ignore_null,
                 })
             }
             WindowFunction::NthValue(func) => {
@@ -222,6 +229,7 @@ impl WindowFunctionInfo {
                     n: func.n,
                     arg: new_arg,
                     return_type: func.return_type.clone(),
+                    ignore_null: func.ignore_null,
                 })
             }
             WindowFunction::Ntile(func) => Self::Ntile(WindowFuncNtileImpl {
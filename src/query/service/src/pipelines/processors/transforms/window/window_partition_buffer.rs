@@ -0,0 +1,208 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Instant;
+
+use databend_common_exception::Result;
+use databend_common_expression::group_hash_columns;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_metrics::transform::metrics_inc_window_spill_read_bytes;
+use databend_common_metrics::transform::metrics_inc_window_spill_read_count;
+use databend_common_metrics::transform::metrics_inc_window_spill_read_milliseconds;
+use databend_common_metrics::transform::metrics_inc_window_spill_write_bytes;
+use databend_common_metrics::transform::metrics_inc_window_spill_write_count;
+use databend_common_metrics::transform::metrics_inc_window_spill_write_milliseconds;
+use databend_common_pipeline_core::processors::Profile;
+use databend_common_pipeline_core::processors::ProfileStatisticsName;
+use log::info;
+
+use super::window_partition_meta::WindowPayload;
+use crate::spillers::Spiller;
+
+/// Every level of partitioning takes these bits of the hash of the `PARTITION BY` columns.
+const WINDOW_SPILL_PARTITION_BITS: usize = 6;
+
+/// The number of hash partitions the rows are scattered into on every level.
+pub const WINDOW_SPILL_PARTITIONS: usize = 1 << WINDOW_SPILL_PARTITION_BITS;
+
+/// The bits of the hash are used up after this level, the partition cannot be split any more.
+pub const WINDOW_SPILL_MAX_LEVEL: usize = u64::BITS as usize / WINDOW_SPILL_PARTITION_BITS;
+
+/// Buffers the rows scattered into hash partitions by the `PARTITION BY` columns,
+/// all the rows of one window partition are always in the same hash partition.
+///
+/// The buffered rows are spilled when they exceed the memory limit,
+/// the partition of a row on `level` is taken from the bits of the hash next to the previous level,
+/// so a hash partition that is too large can be split again on the next level.
+pub struct WindowPartitionBuffer {
+    partition_indices: Vec<usize>,
+    level: usize,
+
+    buffered_blocks: Vec<Vec<DataBlock>>,
+    buffered_bytes: usize,
+    spilled_payloads: Vec<Vec<WindowPayload>>,
+}
+
+impl WindowPartitionBuffer {
+    pub fn create(partition_indices: Vec<usize>, level: usize) -> Self {
+        debug_assert!(!partition_indices.is_empty());
+        debug_assert!(level < WINDOW_SPILL_MAX_LEVEL);
+        Self {
+            partition_indices,
+            level,
+            buffered_blocks: vec![vec![]; WINDOW_SPILL_PARTITIONS],
+            buffered_bytes: 0,
+            spilled_payloads: (0..WINDOW_SPILL_PARTITIONS).map(|_| vec![]).collect(),
+        }
+    }
+
+    pub fn buffered_bytes(&self) -> usize {
+        self.buffered_bytes
+    }
+
+    pub fn add_block(&mut self, block: DataBlock) -> Result<()> {
+        if block.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let block = block.convert_to_full();
+        let columns = self
+            .partition_indices
+            .iter()
+            .map(|index| {
+                block
+                    .get_by_offset(*index)
+                    .value
+                    .as_column()
+                    .unwrap()
+                    .clone()
+            })
+            .collect::<Vec<Column>>();
+        let shift = self.level * WINDOW_SPILL_PARTITION_BITS;
+        let indices = group_hash_columns(&columns)
+            .into_iter()
+            .map(|hash| (hash >> shift) % WINDOW_SPILL_PARTITIONS as u64)
+            .collect::<Vec<_>>();
+
+        for (partition, block) in block
+            .scatter(&indices, WINDOW_SPILL_PARTITIONS)?
+            .into_iter()
+            .enumerate()
+        {
+            if block.num_rows() > 0 {
+                self.buffered_bytes += block.memory_size();
+                self.buffered_blocks[partition].push(block);
+            }
+        }
+        Ok(())
+    }
+
+    /// Spill all the buffered rows, one file for each hash partition.
+    pub async fn spill(&mut self, spiller: &mut Spiller) -> Result<()> {
+        let mut spilled_rows = 0;
+        for (partition, blocks) in self.buffered_blocks.iter_mut().enumerate() {
+            if blocks.is_empty() {
+                continue;
+            }
+
+            let block = DataBlock::concat(&std::mem::take(blocks))?;
+            spilled_rows += block.num_rows();
+            let payload = spill_window_payload(spiller, block).await?;
+            self.spilled_payloads[partition].push(payload);
+        }
+        self.buffered_bytes = 0;
+
+        info!("Window spilled {} rows data", spilled_rows);
+        Ok(())
+    }
+
+    /// The payloads of every hash partition, including the spilled and the buffered ones.
+    pub fn into_partitions(self) -> Vec<Vec<WindowPayload>> {
+        self.spilled_payloads
+            .into_iter()
+            .zip(self.buffered_blocks)
+            .map(|(mut payloads, blocks)| {
+                payloads.extend(blocks.into_iter().map(WindowPayload::Memory));
+                payloads
+            })
+            .collect()
+    }
+}
+
+async fn spill_window_payload(spiller: &mut Spiller, block: DataBlock) -> Result<WindowPayload> {
+    let ins = Instant::now();
+    let (location, bytes) = spiller.spill_block(block).await?;
+    let columns_layout = spiller.columns_layout.remove(&location).unwrap();
+
+    // perf
+    {
+        metrics_inc_window_spill_write_count();
+        metrics_inc_window_spill_write_bytes(bytes);
+        metrics_inc_window_spill_write_milliseconds(ins.elapsed().as_millis() as u64);
+
+        Profile::record_usize_profile(ProfileStatisticsName::SpillWriteCount, 1);
+        Profile::record_usize_profile(ProfileStatisticsName::SpillWriteBytes, bytes as usize);
+        Profile::record_usize_profile(
+            ProfileStatisticsName::SpillWriteTime,
+            ins.elapsed().as_millis() as usize,
+        );
+    }
+
+    Ok(WindowPayload::Spilled {
+        location,
+        bytes: bytes as usize,
+        columns_layout,
+    })
+}
+
+/// Read the rows of the payload, the spilled file is removed after it is read.
+pub async fn restore_window_payload(
+    spiller: &mut Spiller,
+    payload: WindowPayload,
+) -> Result<DataBlock> {
+    let (location, columns_layout) = match payload {
+        WindowPayload::Memory(block) => return Ok(block),
+        WindowPayload::Spilled {
+            location,
+            columns_layout,
+            ..
+        } => (location, columns_layout),
+    };
+
+    // The file may be spilled by the spiller of another processor.
+    spiller
+        .columns_layout
+        .insert(location.clone(), columns_layout);
+
+    let ins = Instant::now();
+    let (block, bytes) = spiller.read_spilled(&location).await?;
+    spiller.remove_spilled(&location).await?;
+
+    // perf
+    {
+        metrics_inc_window_spill_read_count();
+        metrics_inc_window_spill_read_bytes(bytes);
+        metrics_inc_window_spill_read_milliseconds(ins.elapsed().as_millis() as u64);
+
+        Profile::record_usize_profile(ProfileStatisticsName::SpillReadCount, 1);
+        Profile::record_usize_profile(ProfileStatisticsName::SpillReadBytes, bytes as usize);
+        Profile::record_usize_profile(
+            ProfileStatisticsName::SpillReadTime,
+            ins.elapsed().as_millis() as usize,
+        );
+    }
+
+    Ok(block)
+}
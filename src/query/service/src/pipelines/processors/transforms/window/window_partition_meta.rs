@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoPtr;
+use databend_common_expression::DataBlock;
+
+/// The rows of a hash partition, kept in memory or spilled to storage.
+pub enum WindowPayload {
+    Memory(DataBlock),
+    Spilled {
+        location: String,
+        bytes: usize,
+        columns_layout: Vec<usize>,
+    },
+}
+
+impl WindowPayload {
+    pub fn bytes(&self) -> usize {
+        match self {
+            WindowPayload::Memory(block) => block.memory_size(),
+            WindowPayload::Spilled { bytes, .. } => *bytes,
+        }
+    }
+}
+
+/// All the rows of one hash partition collected from a processor.
+pub struct WindowPartitionMeta {
+    pub partition: usize,
+    pub payloads: Vec<WindowPayload>,
+}
+
+impl WindowPartitionMeta {
+    pub fn create(partition: usize, payloads: Vec<WindowPayload>) -> BlockMetaInfoPtr {
+        Box::new(WindowPartitionMeta {
+            partition,
+            payloads,
+        })
+    }
+}
+
+impl serde::Serialize for WindowPartitionMeta {
+    fn serialize<S>(&self, _: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        unreachable!("WindowPartitionMeta does not support exchanging between multiple nodes")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for WindowPartitionMeta {
+    fn deserialize<D>(_: D) -> Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        unreachable!("WindowPartitionMeta does not support exchanging between multiple nodes")
+    }
+}
+
+impl Debug for WindowPartitionMeta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WindowPartitionMeta")
+            .field("partition", &self.partition)
+            .field("payloads", &self.payloads.len())
+            .finish()
+    }
+}
+
+impl BlockMetaInfo for WindowPartitionMeta {
+    fn typetag_deserialize(&self) {
+        unimplemented!("WindowPartitionMeta does not support exchanging between multiple nodes")
+    }
+
+    fn typetag_name(&self) -> &'static str {
+        unimplemented!("WindowPartitionMeta does not support exchanging between multiple nodes")
+    }
+
+    fn equals(&self, _: &Box<dyn BlockMetaInfo>) -> bool {
+        unimplemented!("Unimplemented equals for WindowPartitionMeta")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unimplemented!("Unimplemented clone for WindowPartitionMeta")
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::Utc;
+use databend_common_ast::ast::CopyIntoTableStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::Dialect;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_sql::plans::pipe_stage_location;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use futures_util::TryStreamExt;
+use log::info;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+// The COPY INTO statement of pipe is stored in the display form of AST,
+// which quotes the strings with `'` and is always parsed by the PostgreSQL dialect.
+fn parse_copy_stmt(pipe: &PipeInfo) -> Result<CopyIntoTableStmt> {
+    let tokens = tokenize_sql(&pipe.copy_stmt)?;
+    let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+    match stmt {
+        Statement::CopyIntoTable(copy_stmt) => Ok(copy_stmt),
+        _ => Err(ErrorCode::IllegalPipe(format!(
+            "Pipe '{}' is not defined by COPY INTO <table> statement",
+            pipe.name
+        ))),
+    }
+}
+
+/// The name of stage that the pipe loads files from.
+pub fn pipe_stage_name(pipe: &PipeInfo) -> Result<String> {
+    let mut copy_stmt = parse_copy_stmt(pipe)?;
+    let location = pipe_stage_location(&mut copy_stmt).ok_or_else(|| {
+        ErrorCode::IllegalPipe(format!("Pipe '{}' does not load from stage", pipe.name))
+    })?;
+    Ok(location.split('/').next().unwrap_or_default().to_string())
+}
+
+/// Run the COPY INTO statement of pipe once as the owner of pipe, the files already loaded
+/// are skipped by the load history of the target table.
+///
+/// If `prefix` is given, only the files under the sub path of the stage location are loaded.
+/// The execution time and error are recorded in the pipe.
+#[async_backtrace::framed]
+pub async fn run_pipe(tenant: &str, pipe: &PipeInfo, prefix: Option<&str>) -> Result<()> {
+    let result = execute_pipe(tenant, pipe, prefix).await;
+
+    let executed_on = Utc::now();
+    let last_error = result.as_ref().err().map(|e| e.message());
+    UserApiProvider::instance()
+        .update_pipe(tenant, &pipe.name, true, |pipe| {
+            pipe.last_executed_on = Some(executed_on);
+            pipe.last_error = last_error;
+        })
+        .await?;
+    result
+}
+
+async fn execute_pipe(tenant: &str, pipe: &PipeInfo, prefix: Option<&str>) -> Result<()> {
+    let mut copy_stmt = parse_copy_stmt(pipe)?;
+    if let Some(prefix) = prefix {
+        let location = pipe_stage_location(&mut copy_stmt).ok_or_else(|| {
+            ErrorCode::IllegalPipe(format!("Pipe '{}' does not load from stage", pipe.name))
+        })?;
+        *location = format!(
+            "{}/{}",
+            location.trim_end_matches('/'),
+            prefix.trim_start_matches('/')
+        );
+    }
+
+    let user = UserApiProvider::instance()
+        .get_user(tenant, pipe.owner.clone())
+        .await?;
+    let session = SessionManager::instance()
+        .create_session(SessionType::Pipe)
+        .await?;
+    session.set_authed_user(user, None).await?;
+    let ctx = session.create_query_context().await?;
+
+    info!("run pipe {}: {}", pipe.name, pipe.copy_stmt);
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, extras) = planner
+        .plan_stmt(&Statement::CopyIntoTable(copy_stmt))
+        .await?;
+    ctx.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx).await?;
+    stream.try_collect::<Vec<_>>().await?;
+    Ok(())
+}
@@ -0,0 +1,124 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_base::GLOBAL_TASK;
+use databend_common_config::GlobalConfig;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_users::UserApiProvider;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::clusters::ClusterDiscovery;
+use crate::pipes::pipe_stage_name;
+use crate::pipes::run_pipe;
+
+const PIPE_SCHEDULE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Run the auto ingest pipes of the tenant in background.
+///
+/// The pipes are scheduled periodically by the node with the smallest id in the cluster,
+/// and triggered on the node that receives the files uploaded to the stage of pipes.
+pub struct PipeScheduler {
+    tenant: String,
+    // Names of the pipes running on this node, a pipe is not run concurrently.
+    running: Mutex<HashSet<String>>,
+}
+
+impl PipeScheduler {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(PipeScheduler {
+            tenant: conf.query.tenant_id.clone(),
+            running: Mutex::new(HashSet::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<PipeScheduler> {
+        GlobalInstance::get()
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            loop {
+                sleep(PIPE_SCHEDULE_INTERVAL).await;
+                if let Err(cause) = scheduler.schedule().await {
+                    warn!("schedule pipes failed: {:?}", cause);
+                }
+            }
+        });
+    }
+
+    /// Trigger the auto ingest pipes loading from the stage, after the files are uploaded.
+    pub fn trigger(self: &Arc<Self>, stage_name: &str) {
+        let scheduler = self.clone();
+        let stage_name = stage_name.to_string();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            let pipes = match scheduler.auto_ingest_pipes().await {
+                Ok(pipes) => pipes,
+                Err(cause) => {
+                    warn!("list pipes failed: {:?}", cause);
+                    return;
+                }
+            };
+            for pipe in pipes {
+                if pipe_stage_name(&pipe).is_ok_and(|name| name == stage_name) {
+                    scheduler.run(&pipe).await;
+                }
+            }
+        });
+    }
+
+    async fn schedule(&self) -> Result<()> {
+        let cluster = ClusterDiscovery::instance()
+            .discover(&GlobalConfig::instance())
+            .await?;
+        let leader = cluster.nodes.iter().map(|node| &node.id).min();
+        if leader.is_some_and(|id| id != &cluster.local_id) {
+            return Ok(());
+        }
+
+        for pipe in self.auto_ingest_pipes().await? {
+            self.run(&pipe).await;
+        }
+        Ok(())
+    }
+
+    async fn auto_ingest_pipes(&self) -> Result<Vec<PipeInfo>> {
+        let pipes = UserApiProvider::instance().get_pipes(&self.tenant).await?;
+        Ok(pipes
+            .into_iter()
+            .filter(|pipe| pipe.auto_ingest && !pipe.execution_paused)
+            .collect())
+    }
+
+    async fn run(&self, pipe: &PipeInfo) {
+        if !self.running.lock().insert(pipe.name.clone()) {
+            return;
+        }
+        if let Err(cause) = run_pipe(&self.tenant, pipe, None).await {
+            warn!("run pipe {} failed: {:?}", pipe.name, cause);
+        }
+        self.running.lock().remove(&pipe.name);
+    }
+}
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
 use crate::pipelines::PipelineBuildResult;
@@ -86,6 +87,12 @@ pub async fn build_distributed_pipeline(
     ctx: &Arc<QueryContext>,
     plan: &PhysicalPlan,
 ) -> Result<PipelineBuildResult> {
+    if plan.contains_recursive_cte() {
+        return Err(ErrorCode::Unimplemented(
+            "Recursive CTE can not be executed in distributed mode",
+        ));
+    }
+
     let fragmenter = Fragmenter::try_create(ctx.clone())?;
 
     let root_fragment = fragmenter.build_fragment(plan)?;
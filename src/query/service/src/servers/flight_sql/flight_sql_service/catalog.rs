@@ -14,23 +14,52 @@
 
 use std::sync::Arc;
 
+use arrow_array::builder::BinaryBuilder;
 use arrow_array::builder::StringBuilder;
 use arrow_array::ArrayRef;
+use arrow_array::Int32Array;
 use arrow_array::RecordBatch;
 use arrow_flight::utils::batches_to_flight_data;
+use arrow_flight::IpcMessage;
+use arrow_flight::SchemaAsIpc;
+use arrow_ipc::writer::IpcWriteOptions;
 use arrow_schema::DataType;
 use arrow_schema::Field;
 use arrow_schema::Schema;
+use arrow_schema::SchemaRef;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
+use databend_common_meta_app::schema::TableConstraint;
 use futures_util::stream;
 use log::warn;
 use tonic::Status;
 
 use crate::servers::flight_sql::flight_sql_service::DoGetStream;
 
+const TABLE_TYPE_TABLE: &str = "TABLE";
+const TABLE_TYPE_VIEW: &str = "VIEW";
+
+#[derive(Default)]
+struct TablesInfo {
+    catalog_names: Vec<String>,
+    database_names: Vec<String>,
+    table_names: Vec<String>,
+    table_types: Vec<String>,
+    table_schemas: Vec<Vec<u8>>,
+}
+
+#[derive(Default)]
+struct PrimaryKeysInfo {
+    catalog_names: Vec<String>,
+    database_names: Vec<String>,
+    table_names: Vec<String>,
+    column_names: Vec<String>,
+    key_names: Vec<String>,
+    key_sequences: Vec<i32>,
+}
+
 pub(super) struct CatalogInfoProvider {}
 
 impl CatalogInfoProvider {
@@ -45,86 +74,296 @@ impl CatalogInfoProvider {
         Ok(Box::pin(stream))
     }
 
-    async fn get_tables_internal(
-        ctx: Arc<dyn TableContext>,
+    async fn list_catalogs(
+        ctx: &Arc<dyn TableContext>,
         catalog_name: Option<String>,
-        database_name: Option<String>,
-    ) -> databend_common_exception::Result<(Vec<String>, Vec<String>, Vec<String>, Vec<String>)>
-    {
+    ) -> databend_common_exception::Result<Vec<(String, Arc<dyn Catalog>)>> {
         let tenant = ctx.get_tenant();
         let catalog_mgr = CatalogManager::instance();
-        let catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name {
-            vec![(
+        if let Some(catalog_name) = catalog_name {
+            Ok(vec![(
                 catalog_name.clone(),
                 catalog_mgr.get_catalog(&tenant, &catalog_name).await?,
-            )]
+            )])
         } else {
-            catalog_mgr
+            Ok(catalog_mgr
                 .list_catalogs(&tenant)
                 .await?
                 .iter()
                 .map(|r| (r.name(), r.clone()))
-                .collect()
-        };
-
-        let mut catalog_names = vec![];
-        let mut database_names = vec![];
-        let mut table_names = vec![];
-        let mut table_types = vec![];
-        let table_type = "table".to_string();
-        for (catalog_name, catalog) in catalogs.into_iter() {
-            let dbs = if let Some(database_name) = &database_name {
-                vec![catalog.get_database(tenant.as_str(), database_name).await?]
-            } else {
-                catalog.list_databases(tenant.as_str()).await?
+                .collect())
+        }
+    }
+
+    async fn get_schemas_internal(
+        ctx: &Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<&str>,
+    ) -> databend_common_exception::Result<Vec<(String, Arc<dyn Catalog>, String)>> {
+        let tenant = ctx.get_tenant();
+        let mut schemas = vec![];
+        for (catalog_name, catalog) in Self::list_catalogs(ctx, catalog_name).await? {
+            for db in catalog.list_databases(tenant.as_str()).await? {
+                let db_name = db.name().to_string();
+                if db_schema_filter_pattern.map_or(true, |pattern| like_match(pattern, &db_name)) {
+                    schemas.push((catalog_name.clone(), catalog.clone(), db_name));
+                }
+            }
+        }
+        Ok(schemas)
+    }
+
+    async fn get_tables_internal(
+        ctx: &Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<&str>,
+        table_name_filter_pattern: Option<&str>,
+        table_types: &[String],
+        include_schema: bool,
+    ) -> databend_common_exception::Result<TablesInfo> {
+        let tenant = ctx.get_tenant();
+        let schemas =
+            Self::get_schemas_internal(ctx, catalog_name, db_schema_filter_pattern).await?;
+
+        let mut info = TablesInfo::default();
+        for (catalog_name, catalog, db_name) in schemas {
+            let tables = match catalog.list_tables(tenant.as_str(), &db_name).await {
+                Ok(tables) => tables,
+                Err(err) if err.code() == ErrorCode::EMPTY_SHARE_ENDPOINT_CONFIG => {
+                    warn!("list tables failed on db {}: {}", db_name, err);
+                    continue;
+                }
+                Err(err) => return Err(err),
             };
-            for db in dbs {
-                let db_name = db.name().to_string().into_boxed_str();
-                let db_name: &str = Box::leak(db_name);
-                let tables = match catalog.list_tables(tenant.as_str(), db_name).await {
-                    Ok(tables) => tables,
-                    Err(err) if err.code() == ErrorCode::EMPTY_SHARE_ENDPOINT_CONFIG => {
-                        warn!("list tables failed on db {}: {}", db.name(), err);
-                        continue;
-                    }
-                    Err(err) => return Err(err),
+            for table in tables {
+                if !table_name_filter_pattern
+                    .map_or(true, |pattern| like_match(pattern, table.name()))
+                {
+                    continue;
+                }
+                let table_type = if table.engine() == "VIEW" {
+                    TABLE_TYPE_VIEW
+                } else {
+                    TABLE_TYPE_TABLE
                 };
-                for table in tables {
-                    catalog_names.push(catalog_name.clone());
-                    database_names.push(db_name.to_string());
-                    table_names.push(table.name().to_string());
-                    table_types.push(table_type.clone());
+                if !table_types.is_empty()
+                    && !table_types
+                        .iter()
+                        .any(|t| t.eq_ignore_ascii_case(table_type))
+                {
+                    continue;
                 }
+                if include_schema {
+                    let schema = Schema::from(table.schema().as_ref());
+                    let IpcMessage(schema_bytes) =
+                        SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+                            .try_into()
+                            .map_err(|e| ErrorCode::Internal(format!("{e:?}")))?;
+                    info.table_schemas.push(schema_bytes.to_vec());
+                }
+                info.catalog_names.push(catalog_name.clone());
+                info.database_names.push(db_name.clone());
+                info.table_names.push(table.name().to_string());
+                info.table_types.push(table_type.to_string());
             }
         }
-        Ok((catalog_names, database_names, table_names, table_types))
+        Ok(info)
     }
 
-    pub(crate) async fn get_tables(
+    pub(crate) async fn get_catalogs(ctx: Arc<dyn TableContext>) -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "catalog_name",
+            DataType::Utf8,
+            false,
+        )]));
+        let catalog_names = Self::list_catalogs(&ctx, None)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(catalog_names)])
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) async fn get_schemas(
         ctx: Arc<dyn TableContext>,
         catalog_name: Option<String>,
-        database_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
     ) -> Result<DoGetStream, Status> {
         let schema = Arc::new(Schema::new(vec![
             Field::new("catalog_name", DataType::Utf8, false),
             Field::new("db_schema_name", DataType::Utf8, false),
+        ]));
+        let (catalog_names, db_schema_names) =
+            Self::get_schemas_internal(&ctx, catalog_name, db_schema_filter_pattern.as_deref())
+                .await
+                .map_err(|e| Status::internal(format!("{e:?}")))?
+                .into_iter()
+                .map(|(catalog_name, _, db_name)| (catalog_name, db_name))
+                .unzip();
+        let batch = RecordBatch::try_new(schema, vec![
+            Self::string_array(catalog_names),
+            Self::string_array(db_schema_names),
+        ])
+        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) async fn get_tables(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+        table_name_filter_pattern: Option<String>,
+        table_types: Vec<String>,
+        include_schema: bool,
+    ) -> Result<DoGetStream, Status> {
+        let mut fields = vec![
+            Field::new("catalog_name", DataType::Utf8, false),
+            Field::new("db_schema_name", DataType::Utf8, false),
             Field::new("table_name", DataType::Utf8, false),
             Field::new("table_type", DataType::Utf8, false),
+        ];
+        if include_schema {
+            fields.push(Field::new("table_schema", DataType::Binary, false));
+        }
+        let schema = Arc::new(Schema::new(fields));
+        let info = Self::get_tables_internal(
+            &ctx,
+            catalog_name,
+            db_schema_filter_pattern.as_deref(),
+            table_name_filter_pattern.as_deref(),
+            &table_types,
+            include_schema,
+        )
+        .await
+        .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let mut columns = vec![
+            Self::string_array(info.catalog_names),
+            Self::string_array(info.database_names),
+            Self::string_array(info.table_names),
+            Self::string_array(info.table_types),
+        ];
+        if include_schema {
+            let mut builder = BinaryBuilder::new();
+            for v in &info.table_schemas {
+                builder.append_value(v);
+            }
+            columns.push(Arc::new(builder.finish()));
+        }
+        let batch = RecordBatch::try_new(schema, columns)
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) fn get_table_types() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "table_type",
+            DataType::Utf8,
+            false,
+        )]));
+        let table_types = vec![TABLE_TYPE_TABLE.to_string(), TABLE_TYPE_VIEW.to_string()];
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(table_types)])
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    async fn get_primary_keys_internal(
+        ctx: &Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema: Option<&str>,
+        table_name: &str,
+    ) -> databend_common_exception::Result<PrimaryKeysInfo> {
+        let tenant = ctx.get_tenant();
+        let schemas = Self::get_schemas_internal(ctx, catalog_name, None).await?;
+
+        let mut info = PrimaryKeysInfo::default();
+        for (catalog_name, catalog, db_name) in schemas {
+            if db_schema.map_or(false, |db_schema| db_schema != db_name) {
+                continue;
+            }
+            let table = match catalog
+                .get_table(tenant.as_str(), &db_name, table_name)
+                .await
+            {
+                Ok(table) => table,
+                Err(err) if err.code() == ErrorCode::UNKNOWN_TABLE => continue,
+                Err(err) => return Err(err),
+            };
+            let constraints = &table.get_table_info().meta.constraints;
+            for (key_name, constraint) in constraints {
+                let TableConstraint::PrimaryKey { columns, .. } = constraint else {
+                    continue;
+                };
+                for (i, column) in columns.iter().enumerate() {
+                    info.catalog_names.push(catalog_name.clone());
+                    info.database_names.push(db_name.clone());
+                    info.table_names.push(table.name().to_string());
+                    info.column_names.push(column.clone());
+                    info.key_names.push(key_name.clone());
+                    info.key_sequences.push(i as i32 + 1);
+                }
+            }
+        }
+        Ok(info)
+    }
+
+    /// The columns of the primary key declared on the table.
+    pub(crate) async fn get_primary_keys(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema: Option<String>,
+        table_name: String,
+    ) -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_name", DataType::Utf8, true),
+            Field::new("key_sequence", DataType::Int32, false),
         ]));
-        let (catalog_name, db_schema_name, table_name, table_type) =
-            Self::get_tables_internal(ctx.clone(), catalog_name, database_name)
+        let info =
+            Self::get_primary_keys_internal(&ctx, catalog_name, db_schema.as_deref(), &table_name)
                 .await
                 .map_err(|e| Status::internal(format!("{e:?}")))?;
         let batch = RecordBatch::try_new(schema, vec![
-            Self::string_array(catalog_name),
-            Self::string_array(db_schema_name),
-            Self::string_array(table_name),
-            Self::string_array(table_type),
+            Self::string_array(info.catalog_names),
+            Self::string_array(info.database_names),
+            Self::string_array(info.table_names),
+            Self::string_array(info.column_names),
+            Self::string_array(info.key_names),
+            Arc::new(Int32Array::from(info.key_sequences)),
         ])
         .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
         Self::batch_to_get_stream(batch)
     }
 
+    /// Foreign keys are not supported, always returns an empty result for exported keys,
+    /// imported keys and cross reference.
+    pub(crate) fn get_foreign_keys() -> Result<DoGetStream, Status> {
+        Self::batch_to_get_stream(RecordBatch::new_empty(Self::foreign_keys_schema()))
+    }
+
+    fn foreign_keys_schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("pk_catalog_name", DataType::Utf8, true),
+            Field::new("pk_db_schema_name", DataType::Utf8, true),
+            Field::new("pk_table_name", DataType::Utf8, false),
+            Field::new("pk_column_name", DataType::Utf8, false),
+            Field::new("fk_catalog_name", DataType::Utf8, true),
+            Field::new("fk_db_schema_name", DataType::Utf8, true),
+            Field::new("fk_table_name", DataType::Utf8, false),
+            Field::new("fk_column_name", DataType::Utf8, false),
+            Field::new("key_sequence", DataType::Int32, false),
+            Field::new("fk_key_name", DataType::Utf8, true),
+            Field::new("pk_key_name", DataType::Utf8, true),
+            Field::new("update_rule", DataType::UInt8, false),
+            Field::new("delete_rule", DataType::UInt8, false),
+        ]))
+    }
+
     fn string_array(values: Vec<String>) -> ArrayRef {
         let mut builder = StringBuilder::new();
         for v in &values {
@@ -133,3 +372,22 @@ impl CatalogInfoProvider {
         Arc::new(builder.finish())
     }
 }
+
+// Match the filter pattern of Flight SQL, `%` matches any substring, `_` matches any
+// character, and `\` escapes the next character.
+fn like_match(pattern: &str, value: &str) -> bool {
+    fn matches(pattern: &[char], value: &[char]) -> bool {
+        match pattern.split_first() {
+            None => value.is_empty(),
+            Some(('%', rest)) => (0..=value.len()).any(|i| matches(rest, &value[i..])),
+            Some(('_', rest)) => !value.is_empty() && matches(rest, &value[1..]),
+            Some(('\\', rest)) if !rest.is_empty() => {
+                value.first() == Some(&rest[0]) && matches(&rest[1..], &value[1..])
+            }
+            Some((c, rest)) => value.first() == Some(c) && matches(rest, &value[1..]),
+        }
+    }
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let value = value.chars().collect::<Vec<_>>();
+    matches(&pattern, &value)
+}
@@ -15,10 +15,12 @@
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
 mod catalog;
+mod parameters;
 mod query;
 mod service;
 mod session;
 mod sql_info;
+mod type_info;
 
 use std::pin::Pin;
 use std::sync::Arc;
@@ -26,12 +28,15 @@ use std::sync::Arc;
 use arrow_flight::FlightData;
 use catalog::CatalogInfoProvider;
 use dashmap::DashMap;
+use databend_common_expression::DataSchemaRef;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
+use databend_common_sql::PreparedStatement;
 use futures::Stream;
 use parking_lot::Mutex;
 use sql_info::SqlInfoProvider;
 use tonic::Status;
+use type_info::XdbcTypeInfoProvider;
 use uuid::Uuid;
 
 use crate::servers::http::v1::ExpiringMap;
@@ -51,7 +56,22 @@ type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send
 
 pub struct FlightSqlServiceImpl {
     pub sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
-    statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    statements: Arc<DashMap<Uuid, FlightSqlStatement>>,
+}
+
+/// A statement waiting to be executed, created by `get_flight_info_statement` or
+/// `do_action_create_prepared_statement`.
+#[derive(Clone)]
+pub(crate) struct FlightSqlStatement {
+    // The token of session which creates the statement, the statement is only visible to
+    // the session, and it's removed after the session expires.
+    session_token: String,
+    // The parsed statement with placeholders, `None` for the statement query.
+    prepared: Option<PreparedStatement>,
+    // `None` until the parameters of the prepared statement are bound.
+    plan: Option<(Plan, PlanExtras)>,
+    // The result schema described when the statement is created.
+    schema: DataSchemaRef,
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
@@ -0,0 +1,204 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::Array;
+use arrow_array::ArrayRef;
+use arrow_array::RecordBatch;
+use arrow_flight::sql::server::PeekableFlightDataStream;
+use arrow_flight::utils::flight_data_to_batches;
+use arrow_flight::FlightData;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Schema;
+use arrow_schema::TimeUnit;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::TypeName;
+use databend_common_ast::ast::UnaryOperator;
+use futures::TryStreamExt;
+use tonic::Status;
+
+/// The parameter schema of prepared statement, parameters are described as nullable strings
+/// since their types are not inferred, the values of any type are accepted on binding.
+pub(super) fn parameter_schema(num_params: usize) -> Schema {
+    let fields = (0..num_params)
+        .map(|i| Field::new(format!("${}", i + 1), DataType::Utf8, true))
+        .collect::<Vec<_>>();
+    Schema::new(fields)
+}
+
+/// Decode the parameter batches sent by `DoPut`, each row is a set of parameters.
+pub(super) async fn decode_parameters(
+    stream: PeekableFlightDataStream,
+) -> Result<Vec<Vec<Expr>>, Status> {
+    let flight_data = stream.try_collect::<Vec<FlightData>>().await?;
+    if flight_data.is_empty() {
+        return Ok(vec![]);
+    }
+    let batches = flight_data_to_batches(&flight_data)
+        .map_err(|e| Status::invalid_argument(format!("Invalid parameters: {e}")))?;
+
+    let mut rows = vec![];
+    for batch in batches.iter() {
+        rows.extend(batch_to_parameters(batch)?);
+    }
+    Ok(rows)
+}
+
+fn batch_to_parameters(batch: &RecordBatch) -> Result<Vec<Vec<Expr>>, Status> {
+    (0..batch.num_rows())
+        .map(|row| {
+            batch
+                .columns()
+                .iter()
+                .map(|column| value_to_expr(column, row))
+                .collect()
+        })
+        .collect()
+}
+
+fn value_to_expr(array: &ArrayRef, row: usize) -> Result<Expr, Status> {
+    let literal = |lit| Expr::Literal { span: None, lit };
+    let cast = |value: String, target_type| Expr::Cast {
+        span: None,
+        expr: Box::new(literal(Literal::String(value))),
+        target_type,
+        pg_style: false,
+    };
+    let signed = |v: i64| {
+        if v < 0 {
+            Expr::UnaryOp {
+                span: None,
+                op: UnaryOperator::Minus,
+                expr: Box::new(literal(Literal::UInt64(v.unsigned_abs()))),
+            }
+        } else {
+            literal(Literal::UInt64(v as u64))
+        }
+    };
+
+    if array.is_null(row) {
+        return Ok(literal(Literal::Null));
+    }
+    let expr = match array.data_type() {
+        DataType::Null => literal(Literal::Null),
+        DataType::Boolean => literal(Literal::Boolean(array.as_boolean().value(row))),
+        DataType::Int8 => signed(array.as_primitive::<Int8Type>().value(row) as i64),
+        DataType::Int16 => signed(array.as_primitive::<Int16Type>().value(row) as i64),
+        DataType::Int32 => signed(array.as_primitive::<Int32Type>().value(row) as i64),
+        DataType::Int64 => signed(array.as_primitive::<Int64Type>().value(row)),
+        DataType::UInt8 => literal(Literal::UInt64(
+            array.as_primitive::<UInt8Type>().value(row) as u64,
+        )),
+        DataType::UInt16 => literal(Literal::UInt64(
+            array.as_primitive::<UInt16Type>().value(row) as u64,
+        )),
+        DataType::UInt32 => literal(Literal::UInt64(
+            array.as_primitive::<UInt32Type>().value(row) as u64,
+        )),
+        DataType::UInt64 => literal(Literal::UInt64(
+            array.as_primitive::<UInt64Type>().value(row),
+        )),
+        DataType::Float32 => literal(Literal::Float64(
+            array.as_primitive::<Float32Type>().value(row) as f64,
+        )),
+        DataType::Float64 => literal(Literal::Float64(
+            array.as_primitive::<Float64Type>().value(row),
+        )),
+        DataType::Utf8 => literal(Literal::String(
+            array.as_string::<i32>().value(row).to_string(),
+        )),
+        DataType::LargeUtf8 => literal(Literal::String(
+            array.as_string::<i64>().value(row).to_string(),
+        )),
+        DataType::Binary => binary_to_expr(array.as_binary::<i32>().value(row)),
+        DataType::LargeBinary => binary_to_expr(array.as_binary::<i64>().value(row)),
+        DataType::Decimal128(precision, scale) if *scale >= 0 => cast(
+            array.as_primitive::<Decimal128Type>().value_as_string(row),
+            TypeName::Decimal {
+                precision: *precision,
+                scale: *scale as u8,
+            },
+        ),
+        DataType::Date32 => {
+            let date = array.as_primitive::<Date32Type>().value_as_date(row);
+            cast(invalid_value(date, array)?.to_string(), TypeName::Date)
+        }
+        DataType::Date64 => {
+            let date = array.as_primitive::<Date64Type>().value_as_date(row);
+            cast(invalid_value(date, array)?.to_string(), TypeName::Date)
+        }
+        DataType::Timestamp(unit, _) => {
+            let datetime = match unit {
+                TimeUnit::Second => array
+                    .as_primitive::<TimestampSecondType>()
+                    .value_as_datetime(row),
+                TimeUnit::Millisecond => array
+                    .as_primitive::<TimestampMillisecondType>()
+                    .value_as_datetime(row),
+                TimeUnit::Microsecond => array
+                    .as_primitive::<TimestampMicrosecondType>()
+                    .value_as_datetime(row),
+                TimeUnit::Nanosecond => array
+                    .as_primitive::<TimestampNanosecondType>()
+                    .value_as_datetime(row),
+            };
+            let datetime = invalid_value(datetime, array)?;
+            cast(
+                datetime.format("%Y-%m-%d %H:%M:%S%.6f").to_string(),
+                TypeName::Timestamp,
+            )
+        }
+        data_type => {
+            return Err(Status::invalid_argument(format!(
+                "Unsupported parameter type {data_type}"
+            )));
+        }
+    };
+    Ok(expr)
+}
+
+// Binary values may be not valid UTF-8, so they are passed by `FROM_HEX('...')`.
+fn binary_to_expr(value: &[u8]) -> Expr {
+    let mut hex = String::with_capacity(value.len() * 2);
+    for b in value {
+        let _ = write!(hex, "{b:02x}");
+    }
+    Expr::FunctionCall {
+        span: None,
+        distinct: false,
+        name: Identifier::from_name("from_hex"),
+        args: vec![Expr::Literal {
+            span: None,
+            lit: Literal::String(hex),
+        }],
+        params: vec![],
+        window: None,
+        lambda: None,
+    }
+}
+
+fn invalid_value<T>(value: Option<T>, array: &ArrayRef) -> Result<T, Status> {
+    value.ok_or_else(|| {
+        Status::invalid_argument(format!(
+            "Invalid parameter value of type {}",
+            array.data_type()
+        ))
+    })
+}
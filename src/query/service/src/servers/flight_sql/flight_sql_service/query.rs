@@ -21,6 +21,7 @@ use arrow_flight::SchemaAsIpc;
 use arrow_ipc::writer;
 use arrow_ipc::writer::IpcWriteOptions;
 use arrow_schema::Schema as ArrowSchema;
+use databend_common_ast::ast::Statement;
 use databend_common_base::base::tokio;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -83,6 +84,21 @@ impl FlightSqlServiceImpl {
         planner.plan_sql(query).await
     }
 
+    #[async_backtrace::framed]
+    pub async fn plan_stmt(
+        &self,
+        session: &Arc<Session>,
+        stmt: &Statement,
+    ) -> Result<(Plan, PlanExtras)> {
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+
+        let mut planner = Planner::new(context.clone());
+        planner.plan_stmt(stmt).await
+    }
+
     #[async_backtrace::framed]
     pub(super) async fn execute_update(
         &self,
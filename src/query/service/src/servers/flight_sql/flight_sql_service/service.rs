@@ -18,6 +18,8 @@ use std::time::Duration;
 
 use arrow_flight::flight_descriptor::DescriptorType;
 use arrow_flight::flight_service_server::FlightService;
+use arrow_flight::sql::action_cancel_query_result::CancelResult;
+use arrow_flight::sql::action_end_transaction_request::EndTransaction;
 use arrow_flight::sql::server::FlightSqlService;
 use arrow_flight::sql::server::PeekableFlightDataStream;
 use arrow_flight::sql::ActionBeginSavepointRequest;
@@ -48,7 +50,6 @@ use arrow_flight::sql::CommandPreparedStatementUpdate;
 use arrow_flight::sql::CommandStatementQuery;
 use arrow_flight::sql::CommandStatementSubstraitPlan;
 use arrow_flight::sql::CommandStatementUpdate;
-use arrow_flight::sql::DoPutUpdateResult;
 use arrow_flight::sql::ProstMessageExt;
 use arrow_flight::sql::SqlInfo;
 use arrow_flight::sql::TicketStatementQuery;
@@ -64,9 +65,16 @@ use arrow_flight::PutResult;
 use arrow_flight::SchemaAsIpc;
 use arrow_flight::Ticket;
 use arrow_ipc::writer::IpcWriteOptions;
+use databend_common_ast::ast::Expr;
 use databend_common_base::base::uuid::Uuid;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::txn_manager::TxnState;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::PlanExtras;
+use databend_common_sql::PreparedStatement;
 use futures::Stream;
 use log::info;
 use prost::Message;
@@ -77,8 +85,12 @@ use tonic::Response;
 use tonic::Status;
 use tonic::Streaming;
 
+use super::parameters::decode_parameters;
+use super::parameters::parameter_schema;
 use super::status;
+use super::FlightSqlStatement;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use crate::sessions::Session;
 
 fn try_unpack_any<T: ProstMessageExt>(message: Any) -> std::result::Result<T, Status> {
     message
@@ -122,6 +134,91 @@ fn simple_flight_info<T: ProstMessageExt>(message: T) -> Response<FlightInfo> {
     Response::new(info)
 }
 
+fn schema_flight_info<T: ProstMessageExt>(
+    message: T,
+    schema: &DataSchema,
+) -> Result<Response<FlightInfo>, Status> {
+    let schema = schema.into();
+    let IpcMessage(schema_bytes) = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+        .try_into()
+        .map_err(|e| status!("Unable to serialize schema", e))?;
+    let mut resp = simple_flight_info(message);
+    resp.get_mut().schema = schema_bytes;
+    Ok(resp)
+}
+
+fn decode_handle(handle: &[u8]) -> Result<Uuid, Status> {
+    Uuid::from_slice(handle).map_err(|e| Status::internal(format!("Error decoding handle: {e}")))
+}
+
+impl FlightSqlServiceImpl {
+    async fn execute_txn_statement(&self, session: Arc<Session>, sql: &str) -> Result<(), Status> {
+        let (plan, plan_extras) = self
+            .plan_sql(&session, sql)
+            .await
+            .map_err(|e| status!("Error getting result schema", e))?;
+        self.execute_update(session, &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(())
+    }
+
+    fn get_statement<T>(
+        &self,
+        request: &Request<T>,
+        handle: &Uuid,
+    ) -> Result<FlightSqlStatement, Status> {
+        let token = Self::get_session_token(request)?;
+        self.statements
+            .get(handle)
+            .filter(|statement| statement.session_token == token)
+            .map(|statement| statement.value().clone())
+            .ok_or_else(|| Status::not_found(format!("Unknown statement handle {handle}")))
+    }
+
+    fn get_plan<T>(
+        &self,
+        request: &Request<T>,
+        handle: &Uuid,
+    ) -> Result<(Plan, PlanExtras), Status> {
+        self.get_statement(request, handle)?.plan.ok_or_else(|| {
+            Status::invalid_argument(format!(
+                "Parameters of prepared statement {handle} are not bound"
+            ))
+        })
+    }
+
+    // The statements are not always closed or fetched by the clients, so the statements of
+    // expired sessions are removed when a new statement is created.
+    fn insert_statement(&self, handle: Uuid, statement: FlightSqlStatement) {
+        {
+            let sessions = self.sessions.lock();
+            self.statements
+                .retain(|_, statement| sessions.get(&statement.session_token).is_some());
+        }
+        self.statements.insert(handle, statement);
+    }
+
+    async fn bind_and_plan(
+        &self,
+        session: &Arc<Session>,
+        statement: &FlightSqlStatement,
+        params: Vec<Expr>,
+    ) -> Result<(Plan, PlanExtras), Status> {
+        let Some(prepared) = &statement.prepared else {
+            return Err(Status::invalid_argument(
+                "Parameters are only accepted by prepared statement",
+            ));
+        };
+        let stmt = prepared
+            .bind(params)
+            .map_err(|e| Status::invalid_argument(e.message()))?;
+        self.plan_stmt(session, &stmt)
+            .await
+            .map_err(|e| status!("Error getting result schema", e))
+    }
+}
+
 impl NamedService for FlightSqlServiceImpl {
     const NAME: &'static str = "FlightSqlService";
 }
@@ -192,9 +289,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_get_fallback with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let (plan, plan_extras) = self.get_plan(&request, &handle)?;
         let stream = self
-            .execute_query(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_query(session, &plan, &plan_extras)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let resp = Response::new(stream);
@@ -207,9 +304,24 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: CommandStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        info!("get_flight_info_sql_info(query={})", query.query);
-        let _session = self.get_session(&request)?;
-        Ok(simple_flight_info(query))
+        info!("get_flight_info_statement(query={})", query.query);
+        let session = self.get_session(&request)?;
+        let plan = self
+            .plan_sql(&session, &query.query)
+            .await
+            .map_err(|e| status!("Error getting result schema", e))?;
+        let schema = plan.0.schema();
+        let handle = Uuid::new_v4();
+        self.insert_statement(handle, FlightSqlStatement {
+            session_token: Self::get_session_token(&request)?,
+            prepared: None,
+            plan: Some(plan),
+            schema: schema.clone(),
+        });
+        let ticket = TicketStatementQuery {
+            statement_handle: handle.as_bytes().to_vec().into(),
+        };
+        schema_flight_info(ticket, &schema)
     }
 
     #[async_backtrace::framed]
@@ -218,47 +330,23 @@ impl FlightSqlService for FlightSqlServiceImpl {
         cmd: CommandPreparedStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        let _session = self.get_session(&request);
-        let handle = Uuid::from_slice(cmd.prepared_statement_handle.as_ref())
-            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+        let _session = self.get_session(&request)?;
+        let handle = decode_handle(cmd.prepared_statement_handle.as_ref())?;
 
         info!("get_flight_info_prepared_statement with handle={handle}");
 
-        let handle_plan_ref = self.statements.get(&handle).unwrap();
-        let schema = handle_plan_ref.value().0.schema().as_ref().into();
-        let loc = Location {
-            uri: "grpc+tcp://127.0.0.1".to_string(),
+        let statement = self.get_statement(&request, &handle)?;
+        let schema = match &statement.plan {
+            Some((plan, _)) => plan.schema(),
+            None => statement.schema,
         };
         let fetch = FetchResults {
             handle: handle.to_string(),
         };
-        let buf = fetch.as_any().encode_to_vec().into();
-        let ticket = Ticket { ticket: buf };
-        let endpoint = FlightEndpoint {
-            ticket: Some(ticket),
-            location: vec![loc],
-        };
-        let endpoints = vec![endpoint];
-
-        let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
-            .try_into()
-            .map_err(|e| status!("Unable to serialize schema", e))?;
-        let IpcMessage(schema_bytes) = message;
-
-        let flight_desc = FlightDescriptor {
-            r#type: DescriptorType::Cmd.into(),
-            cmd: Default::default(),
-            path: vec![],
-        };
-        let info = FlightInfo {
-            schema: schema_bytes,
-            flight_descriptor: Some(flight_desc),
-            endpoint: endpoints,
-            total_records: -1,
-            total_bytes: -1,
-            ordered: false,
-        };
-        let resp = Response::new(info);
+        let mut resp = schema_flight_info(fetch, &schema)?;
+        resp.get_mut().endpoint[0].location = vec![Location {
+            uri: "grpc+tcp://127.0.0.1".to_string(),
+        }];
         Ok(resp)
     }
 
@@ -321,91 +409,119 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn get_flight_info_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_primary_keys({query:?})",);
-        Err(Status::unimplemented(
-            "get_flight_info_primary_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_exported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
-    // do_get
     #[async_backtrace::framed]
     async fn do_get_statement(
         &self,
         ticket: TicketStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_statement({ticket:?}");
-        Err(Status::unimplemented("do_get_statement not implemented"))
+        let session = self.get_session(&request)?;
+        let handle = decode_handle(ticket.statement_handle.as_ref())?;
+        // The statement query is executed only once.
+        let (plan, plan_extras) = self.get_plan(&request, &handle)?;
+        self.statements.remove(&handle);
+        let stream = self
+            .execute_query(session, &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_prepared_statement(
         &self,
         query: CommandPreparedStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_prepared_statement({query:?}");
-        Err(Status::unimplemented(
-            "do_get_prepared_statement not implemented",
-        ))
+        let session = self.get_session(&request)?;
+        let handle = decode_handle(query.prepared_statement_handle.as_ref())?;
+        let (plan, plan_extras) = self.get_plan(&request, &handle)?;
+        let stream = self
+            .execute_query(session, &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_catalogs(
         &self,
         _query: CommandGetCatalogs,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_catalogs()");
-        Err(Status::unimplemented("do_get_catalogs not implemented"))
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_catalogs(context).await?,
+        ))
     }
 
     #[async_backtrace::framed]
     async fn do_get_schemas(
         &self,
         query: CommandGetDbSchemas,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_schemas({query:?}");
-        Err(Status::unimplemented("do_get_schemas not implemented"))
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_schemas(
+                context,
+                query.catalog,
+                query.db_schema_filter_pattern,
+            )
+            .await?,
+        ))
     }
 
     #[async_backtrace::framed]
@@ -421,8 +537,15 @@ impl FlightSqlService for FlightSqlServiceImpl {
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
         Ok(Response::new(
-            super::CatalogInfoProvider::get_tables(context.clone(), query.catalog.clone(), None)
-                .await?,
+            super::CatalogInfoProvider::get_tables(
+                context,
+                query.catalog,
+                query.db_schema_filter_pattern,
+                query.table_name_filter_pattern,
+                query.table_types,
+                query.include_schema,
+            )
+            .await?,
         ))
     }
 
@@ -430,19 +553,21 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_table_types(
         &self,
         _query: CommandGetTableTypes,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_table_types()");
-        Err(Status::unimplemented("do_get_table_types not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(super::CatalogInfoProvider::get_table_types()?))
     }
 
     #[async_backtrace::framed]
     async fn do_get_sql_info(
         &self,
         query: CommandGetSqlInfo,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_sql_info({query:?})");
+        let _session = self.get_session(&request)?;
         Ok(Response::new(super::SqlInfoProvider::all_info()?))
     }
 
@@ -450,21 +575,35 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_primary_keys({query:?})");
-        Err(Status::unimplemented("do_get_primary_keys not implemented"))
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_primary_keys(
+                context,
+                query.catalog,
+                query.db_schema,
+                query.table,
+            )
+            .await?,
+        ))
     }
 
     #[async_backtrace::framed]
     async fn do_get_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_exported_keys not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -472,11 +611,12 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_imported_keys not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -484,11 +624,12 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "do_get_cross_reference not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -514,6 +655,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         Ok(res)
     }
 
+    // bind the parameters of prepared statement, which are used by the following `do_get`.
     #[async_backtrace::framed]
     async fn do_put_prepared_statement_query(
         &self,
@@ -521,22 +663,26 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<PeekableFlightDataStream>,
     ) -> Result<Response<<Self as FlightService>::DoPutStream>, Status> {
         let session = self.get_session(&request)?;
-        let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
-            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+        let handle = decode_handle(query.prepared_statement_handle.as_ref())?;
 
         info!("do_put_prepared_statement_query with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
-        let record_count = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
-            .await
-            .map_err(|e| status!("fail to execute", e))?;
-        let result = DoPutUpdateResult { record_count };
-        let result = PutResult {
-            app_metadata: result.as_any().encode_to_vec().into(),
-        };
-        let result = futures::stream::iter(vec![Ok(result)]);
-        return Ok(Response::new(Box::pin(result)));
+        let statement = self.get_statement(&request, &handle)?;
+        let mut rows = decode_parameters(request.into_inner()).await?;
+        if rows.len() > 1 {
+            return Err(Status::invalid_argument(format!(
+                "Prepared statement query accepts only one row of parameters, but got {}",
+                rows.len()
+            )));
+        }
+        let params = rows.pop().unwrap_or_default();
+        let plan = self.bind_and_plan(&session, &statement, params).await?;
+        if let Some(mut statement) = self.statements.get_mut(&handle) {
+            statement.plan = Some(plan);
+        }
+
+        let result = futures::stream::iter(Vec::<Result<PutResult, Status>>::new());
+        Ok(Response::new(Box::pin(result)))
     }
 
     // called by JDBC
@@ -547,16 +693,33 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<PeekableFlightDataStream>,
     ) -> Result<i64, Status> {
         let session = self.get_session(&request)?;
-        let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
-            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+        let handle = decode_handle(query.prepared_statement_handle.as_ref())?;
 
         info!("do_put_prepared_statement_update with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
-        let res = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
-            .await
-            .map_err(|e| status!("fail to execute", e))?;
+        let statement = self.get_statement(&request, &handle)?;
+        let rows = decode_parameters(request.into_inner()).await?;
+        let mut res = 0;
+        if rows.is_empty() {
+            let (plan, plan_extras) = statement.plan.clone().ok_or_else(|| {
+                Status::invalid_argument(format!(
+                    "Parameters of prepared statement {handle} are not bound"
+                ))
+            })?;
+            res = self
+                .execute_update(session, &plan, &plan_extras)
+                .await
+                .map_err(|e| status!("fail to execute", e))?;
+        } else {
+            // Each row of parameters is executed once, like the batch of JDBC.
+            for params in rows {
+                let (plan, plan_extras) = self.bind_and_plan(&session, &statement, params).await?;
+                res += self
+                    .execute_update(session.clone(), &plan, &plan_extras)
+                    .await
+                    .map_err(|e| status!("fail to execute", e))?;
+            }
+        }
 
         info!("do_put_prepared_statement_update with handle={handle} return {res}");
         Ok(res)
@@ -571,34 +734,61 @@ impl FlightSqlService for FlightSqlServiceImpl {
         let session = self.get_session(&request)?;
         let sql = query.query.clone();
         let handle = Uuid::new_v4();
-        let plan = self
-            .plan_sql(&session, &sql)
+        let context = session
+            .create_query_context()
             .await
-            .map_err(|e| status!("Error getting result schema", e))?;
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        let sql_dialect = context
+            .get_settings()
+            .get_sql_dialect()
+            .map_err(|e| status!("Could not get sql dialect", e))?;
+        let prepared = PreparedStatement::try_create(&sql, sql_dialect)
+            .map_err(|e| status!("Error parsing statement", e))?;
+        let num_params = prepared.num_params();
+
+        // Describe the result schema with `NULL` parameters, which may be rejected
+        // by the binder, so the schema is only required if there is no parameter.
+        let stmt = prepared
+            .bind_nulls()
+            .map_err(|e| status!("Error binding parameters", e))?;
+        let plan = match self.plan_stmt(&session, &stmt).await {
+            Ok(plan) => Some(plan),
+            Err(e) if num_params == 0 => return Err(status!("Error getting result schema", e)),
+            Err(_) => None,
+        };
         info!(
             "do_action_create_prepared_statement with handler={handle} query={:?}",
             query.query
         );
         // JDBC client use call put when schema.fields == 0
-        let data_schema = if plan.0.has_result_set() {
-            plan.0.schema()
-        } else {
-            Arc::new(DataSchema::empty())
+        let data_schema = match &plan {
+            Some((plan, _)) if plan.has_result_set() => plan.schema(),
+            _ => Arc::new(DataSchema::empty()),
         };
         info!(
             "do_action_create_prepared_statement with handler={handle}, query={:?}, return schema={data_schema:?}",
             query.query
         );
         let schema = (&*data_schema).into();
-        self.statements.insert(handle, plan);
+        self.insert_statement(handle, FlightSqlStatement {
+            session_token: Self::get_session_token(&request)?,
+            prepared: Some(prepared),
+            // The plan with `NULL` parameters can't be executed.
+            plan: plan.filter(|_| num_params == 0),
+            schema: data_schema,
+        });
         let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
             .try_into()
             .map_err(|e| status!("Unable to serialize schema", e))?;
         let IpcMessage(schema_bytes) = message;
+        let message = SchemaAsIpc::new(&parameter_schema(num_params), &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e| status!("Unable to serialize schema", e))?;
+        let IpcMessage(parameter_schema_bytes) = message;
         let res = ActionCreatePreparedStatementResult {
             prepared_statement_handle: handle.as_bytes().to_vec().into(),
             dataset_schema: schema_bytes,
-            parameter_schema: Default::default(), // TODO: parameters
+            parameter_schema: parameter_schema_bytes,
         };
         Ok(res)
     }
@@ -609,24 +799,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: ActionClosePreparedStatementRequest,
         request: Request<Action>,
     ) -> Result<(), Status> {
-        let handle = query.prepared_statement_handle.as_ref();
-        if let Ok(handle) = std::str::from_utf8(handle) {
-            info!(
-                "do_action_close_prepared_statement with handle {:?}",
-                handle
-            );
-            match Uuid::try_parse(handle) {
-                Ok(handle) => {
-                    if self.get_session(&request).is_ok() {
-                        self.statements.remove(&handle);
-                    }
-                }
-                Err(e) => {
-                    return Err(Status::internal(format!(
-                        "do_action_close_prepared_statement Error decoding handle: {e} {handle:?}"
-                    )));
-                }
-            }
+        let handle = decode_handle(query.prepared_statement_handle.as_ref())?;
+        info!("do_action_close_prepared_statement with handle {handle}");
+        if let Ok(token) = Self::get_session_token(&request) {
+            self.statements
+                .remove_if(&handle, |_, statement| statement.session_token == token);
         }
         Ok(())
     }
@@ -637,21 +814,29 @@ impl FlightSqlService for FlightSqlServiceImpl {
     }
 
     /// Get a FlightInfo to extract information about the supported XDBC types.
+    #[async_backtrace::framed]
     async fn get_flight_info_xdbc_type_info(
         &self,
-        _query: CommandGetXdbcTypeInfo,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetXdbcTypeInfo,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        unimplemented!()
+        info!("get_flight_info_xdbc_type_info({query:?})");
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     /// Get a FlightDataStream containing the data related to the supported XDBC types.
+    #[async_backtrace::framed]
     async fn do_get_xdbc_type_info(
         &self,
-        _query: CommandGetXdbcTypeInfo,
-        _request: Request<Ticket>,
+        query: CommandGetXdbcTypeInfo,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        unimplemented!()
+        info!("do_get_xdbc_type_info({query:?})");
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(super::XdbcTypeInfoProvider::type_infos(
+            query,
+        )?))
     }
 
     async fn get_flight_info_substrait_plan(
@@ -659,7 +844,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: CommandStatementSubstraitPlan,
         _request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
-        unimplemented!()
+        Err(Status::unimplemented(
+            "get_flight_info_substrait_plan not implemented",
+        ))
     }
 
     async fn do_put_substrait_plan(
@@ -667,7 +854,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: CommandStatementSubstraitPlan,
         _request: Request<PeekableFlightDataStream>,
     ) -> std::result::Result<i64, Status> {
-        unimplemented!()
+        Err(Status::unimplemented(
+            "do_put_substrait_plan not implemented",
+        ))
     }
 
     async fn do_action_create_prepared_substrait_plan(
@@ -675,23 +864,56 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionCreatePreparedSubstraitPlanRequest,
         _request: Request<Action>,
     ) -> std::result::Result<ActionCreatePreparedStatementResult, Status> {
-        unimplemented!()
+        Err(Status::unimplemented(
+            "do_action_create_prepared_substrait_plan not implemented",
+        ))
     }
 
+    // A session has at most one explicit transaction, which is identified by the session token.
+    #[async_backtrace::framed]
     async fn do_action_begin_transaction(
         &self,
         _query: ActionBeginTransactionRequest,
-        _request: Request<Action>,
+        request: Request<Action>,
     ) -> std::result::Result<ActionBeginTransactionResult, Status> {
-        unimplemented!()
+        info!("do_action_begin_transaction()");
+        let session = self.get_session(&request)?;
+        if session.txn_mgr().lock().state() != TxnState::AutoCommit {
+            return Err(Status::failed_precondition(
+                "A transaction is already started in the session",
+            ));
+        }
+        self.execute_txn_statement(session, "BEGIN").await?;
+        let token = Self::get_session_token(&request)?;
+        Ok(ActionBeginTransactionResult {
+            transaction_id: token.into_bytes().into(),
+        })
     }
 
+    #[async_backtrace::framed]
     async fn do_action_end_transaction(
         &self,
-        _query: ActionEndTransactionRequest,
-        _request: Request<Action>,
+        query: ActionEndTransactionRequest,
+        request: Request<Action>,
     ) -> std::result::Result<(), Status> {
-        unimplemented!()
+        info!("do_action_end_transaction({:?})", query.action());
+        let session = self.get_session(&request)?;
+        let token = Self::get_session_token(&request)?;
+        if query.transaction_id.as_ref() != token.as_bytes()
+            || session.txn_mgr().lock().state() == TxnState::AutoCommit
+        {
+            return Err(Status::not_found("Unknown transaction"));
+        }
+        let sql = match query.action() {
+            EndTransaction::Commit => "COMMIT",
+            EndTransaction::Rollback => "ROLLBACK",
+            EndTransaction::Unspecified => {
+                return Err(Status::invalid_argument(
+                    "The action to end the transaction is not specified",
+                ));
+            }
+        };
+        self.execute_txn_statement(session, sql).await
     }
 
     async fn do_action_begin_savepoint(
@@ -699,7 +921,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionBeginSavepointRequest,
         _request: Request<Action>,
     ) -> std::result::Result<ActionBeginSavepointResult, Status> {
-        unimplemented!()
+        Err(Status::unimplemented(
+            "do_action_begin_savepoint not implemented",
+        ))
     }
 
     async fn do_action_end_savepoint(
@@ -707,15 +931,35 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionEndSavepointRequest,
         _request: Request<Action>,
     ) -> std::result::Result<(), Status> {
-        unimplemented!()
+        Err(Status::unimplemented(
+            "do_action_end_savepoint not implemented",
+        ))
     }
 
+    // The running query of the session is killed, the queries are executed one by one in
+    // a session.
+    #[async_backtrace::framed]
     async fn do_action_cancel_query(
         &self,
-        _query: ActionCancelQueryRequest,
-        _request: Request<Action>,
+        query: ActionCancelQueryRequest,
+        request: Request<Action>,
     ) -> std::result::Result<ActionCancelQueryResult, Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        FlightInfo::decode(query.info)
+            .map_err(|e| Status::invalid_argument(format!("Error decoding FlightInfo: {e}")))?;
+        let Some(query_id) = session.get_current_query_id() else {
+            return Ok(ActionCancelQueryResult {
+                result: CancelResult::NotCancellable as i32,
+            });
+        };
+
+        info!("do_action_cancel_query with query id {query_id}");
+        session.force_kill_query(ErrorCode::AbortedQuery(
+            "Aborted query, because it is cancelled by the client",
+        ));
+        Ok(ActionCancelQueryResult {
+            result: CancelResult::Cancelled as i32,
+        })
     }
 }
 
@@ -31,6 +31,19 @@ use crate::sessions::SessionType;
 
 impl FlightSqlServiceImpl {
     pub(super) fn get_session<T>(&self, req: &Request<T>) -> Result<Arc<Session>, Status> {
+        let session_id = Self::get_session_token(req)?;
+        if let Some(session) = self.sessions.lock().get(&session_id) {
+            Ok(session)
+        } else {
+            Err(Status::unauthenticated(format!(
+                "session_id not found: {session_id}"
+            )))?
+        }
+    }
+
+    /// The token of session returned by the handshake, which is sent in the bearer
+    /// authorization header.
+    pub(super) fn get_session_token<T>(req: &Request<T>) -> Result<String, Status> {
         let auth = req
             .metadata()
             .get("authorization")
@@ -43,15 +56,7 @@ impl FlightSqlServiceImpl {
         if !authorization.starts_with(bearer) {
             Err(Status::unauthenticated("Invalid auth header!"))?;
         }
-        let session_id = authorization[bearer.len()..].to_string();
-
-        if let Some(session) = self.sessions.lock().get(&session_id) {
-            Ok(session)
-        } else {
-            Err(Status::unauthenticated(format!(
-                "session_id not found: {session_id}"
-            )))?
-        }
+        Ok(authorization[bearer.len()..].to_string())
     }
 
     pub(super) fn get_header_value(metadata: &MetadataMap, key: &str) -> Option<String> {
@@ -0,0 +1,170 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arrow_flight::sql::metadata::XdbcTypeInfo;
+use arrow_flight::sql::metadata::XdbcTypeInfoData;
+use arrow_flight::sql::metadata::XdbcTypeInfoDataBuilder;
+use arrow_flight::sql::CommandGetXdbcTypeInfo;
+use arrow_flight::sql::Nullable;
+use arrow_flight::sql::Searchable;
+use arrow_flight::sql::XdbcDataType;
+use arrow_flight::sql::XdbcDatetimeSubcode;
+use arrow_flight::utils::batches_to_flight_data;
+use arrow_flight::FlightData;
+use arrow_schema::ArrowError;
+use futures_util::stream;
+use tonic::Status;
+
+use crate::servers::flight_sql::flight_sql_service::DoGetStream;
+
+// The max precision of DECIMAL.
+const MAX_DECIMAL_PRECISION: i32 = 76;
+
+/// The XDBC type infos of the data types supported by Databend.
+pub(super) struct XdbcTypeInfoProvider {}
+
+impl XdbcTypeInfoProvider {
+    fn type_info(type_name: &str, data_type: XdbcDataType) -> XdbcTypeInfo {
+        XdbcTypeInfo {
+            type_name: type_name.to_string(),
+            data_type,
+            column_size: None,
+            literal_prefix: None,
+            literal_suffix: None,
+            create_params: None,
+            nullable: Nullable::NullabilityNullable,
+            case_sensitive: false,
+            searchable: Searchable::Basic,
+            unsigned_attribute: None,
+            fixed_prec_scale: false,
+            auto_increment: None,
+            local_type_name: Some(type_name.to_string()),
+            minimum_scale: None,
+            maximum_scale: None,
+            sql_data_type: data_type,
+            datetime_subcode: None,
+            num_prec_radix: None,
+            interval_precision: None,
+        }
+    }
+
+    fn numeric_type_info(
+        type_name: &str,
+        data_type: XdbcDataType,
+        precision: i32,
+        unsigned: bool,
+    ) -> XdbcTypeInfo {
+        XdbcTypeInfo {
+            column_size: Some(precision),
+            unsigned_attribute: Some(unsigned),
+            auto_increment: Some(false),
+            num_prec_radix: Some(10),
+            ..Self::type_info(type_name, data_type)
+        }
+    }
+
+    fn datetime_type_info(
+        type_name: &str,
+        data_type: XdbcDataType,
+        subcode: XdbcDatetimeSubcode,
+        column_size: i32,
+    ) -> XdbcTypeInfo {
+        XdbcTypeInfo {
+            column_size: Some(column_size),
+            literal_prefix: Some("'".to_string()),
+            literal_suffix: Some("'".to_string()),
+            sql_data_type: XdbcDataType::XdbcDatetime,
+            datetime_subcode: Some(subcode),
+            ..Self::type_info(type_name, data_type)
+        }
+    }
+
+    fn all_type_infos() -> Result<XdbcTypeInfoData, ArrowError> {
+        let mut builder = XdbcTypeInfoDataBuilder::new();
+        builder.append(Self::type_info("BOOLEAN", XdbcDataType::XdbcBit));
+        for (type_name, data_type, precision, unsigned) in [
+            ("TINYINT", XdbcDataType::XdbcTinyint, 3, false),
+            ("SMALLINT", XdbcDataType::XdbcSmallint, 5, false),
+            ("INT", XdbcDataType::XdbcInteger, 10, false),
+            ("BIGINT", XdbcDataType::XdbcBigint, 19, false),
+            ("TINYINT UNSIGNED", XdbcDataType::XdbcTinyint, 3, true),
+            ("SMALLINT UNSIGNED", XdbcDataType::XdbcSmallint, 5, true),
+            ("INT UNSIGNED", XdbcDataType::XdbcInteger, 10, true),
+            ("BIGINT UNSIGNED", XdbcDataType::XdbcBigint, 20, true),
+            ("FLOAT", XdbcDataType::XdbcFloat, 7, false),
+            ("DOUBLE", XdbcDataType::XdbcDouble, 15, false),
+        ] {
+            builder.append(Self::numeric_type_info(
+                type_name, data_type, precision, unsigned,
+            ));
+        }
+        builder.append(XdbcTypeInfo {
+            create_params: Some(vec!["precision".to_string(), "scale".to_string()]),
+            fixed_prec_scale: true,
+            minimum_scale: Some(0),
+            maximum_scale: Some(MAX_DECIMAL_PRECISION),
+            ..Self::numeric_type_info(
+                "DECIMAL",
+                XdbcDataType::XdbcDecimal,
+                MAX_DECIMAL_PRECISION,
+                false,
+            )
+        });
+        builder.append(XdbcTypeInfo {
+            literal_prefix: Some("'".to_string()),
+            literal_suffix: Some("'".to_string()),
+            case_sensitive: true,
+            searchable: Searchable::Full,
+            ..Self::type_info("VARCHAR", XdbcDataType::XdbcVarchar)
+        });
+        builder.append(Self::type_info("BINARY", XdbcDataType::XdbcVarbinary));
+        builder.append(Self::datetime_type_info(
+            "DATE",
+            XdbcDataType::XdbcDate,
+            XdbcDatetimeSubcode::XdbcSubcodeDate,
+            10,
+        ));
+        builder.append(Self::datetime_type_info(
+            "TIMESTAMP",
+            XdbcDataType::XdbcTimestamp,
+            XdbcDatetimeSubcode::XdbcSubcodeTimestamp,
+            26,
+        ));
+        // The types without a XDBC counterpart.
+        for type_name in ["ARRAY", "MAP", "TUPLE", "VARIANT", "BITMAP", "GEOMETRY"] {
+            builder.append(XdbcTypeInfo {
+                searchable: Searchable::None,
+                ..Self::type_info(type_name, XdbcDataType::XdbcUnknownType)
+            });
+        }
+        builder.build()
+    }
+
+    fn type_info_flight_data(query: CommandGetXdbcTypeInfo) -> Result<Vec<FlightData>, ArrowError> {
+        let type_infos = Self::all_type_infos()?;
+        let batch = query.into_builder(&type_infos).build()?;
+        let schema = (*batch.schema()).clone();
+        batches_to_flight_data(&schema, vec![batch])
+    }
+
+    /// The type infos of the requested data type, or all the type infos if it's not specified.
+    pub fn type_infos(query: CommandGetXdbcTypeInfo) -> Result<DoGetStream, Status> {
+        let flight_data = Self::type_info_flight_data(query)
+            .map_err(|e| Status::internal(format!("{e:?}")))?
+            .into_iter()
+            .map(Ok);
+        let stream = stream::iter(flight_data);
+        Ok(Box::pin(stream))
+    }
+}
@@ -18,6 +18,7 @@ use std::time::SystemTime;
 use databend_common_base::base::tokio::sync::RwLock;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::CatchUnwindFuture;
+use databend_common_catalog::txn_manager::TxnState;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -129,6 +130,8 @@ pub struct ExecutorSessionState {
     pub current_role: Option<String>,
     pub secondary_roles: Option<Vec<String>>,
     pub settings: Arc<Settings>,
+    pub txn_state: TxnState,
+    pub has_temp_table: bool,
 }
 
 impl ExecutorSessionState {
@@ -138,6 +141,8 @@ impl ExecutorSessionState {
             current_role: session.get_current_role().map(|r| r.name),
             secondary_roles: session.get_secondary_roles(),
             settings: session.get_settings(),
+            txn_state: session.txn_mgr().lock().state(),
+            has_temp_table: !session.temp_tbl_mgr().lock().is_empty(),
         }
     }
 }
@@ -24,8 +24,11 @@ use databend_common_base::base::tokio::sync::RwLock;
 use databend_common_base::runtime::GlobalQueryRuntime;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::table_context::StageAttachment;
+use databend_common_catalog::txn_manager::TxnState;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_sql::plans::Plan;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::info;
 use log::warn;
 use minitrace::prelude::*;
@@ -142,6 +145,11 @@ pub struct HttpSessionConf {
     pub keep_server_session_secs: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub settings: Option<BTreeMap<String, String>>,
+    /// The id of the server side session which holds the explicit transaction or the temporary
+    /// tables, the following queries are attached to it until the transaction ends and the
+    /// temporary tables are dropped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_session_id: Option<String>,
 }
 
 impl HttpSessionConf {}
@@ -209,7 +217,14 @@ impl HttpQuery {
         let http_query_manager = HttpQueryManager::instance();
 
         // If session_id is specified, the new query will be attached in the same session.
-        let session = if let Some(id) = &request.session_id {
+        // So does the query in an explicit transaction or using temporary tables.
+        let request_session_id = request.session_id.clone().or_else(|| {
+            request
+                .session
+                .as_ref()
+                .and_then(|conf| conf.server_session_id.clone())
+        });
+        let session = if let Some(id) = &request_session_id {
             let session = http_query_manager.get_session(id).await.ok_or_else(|| {
                 ErrorCode::UnknownSession(format!("unknown session-id {}, maybe expired", id))
             })?;
@@ -277,7 +292,7 @@ impl HttpQuery {
                 }
             }
             if let Some(secs) = session_conf.keep_server_session_secs {
-                if secs > 0 && request.session_id.is_none() {
+                if secs > 0 && request_session_id.is_none() {
                     http_query_manager
                         .add_session(session.clone(), Duration::from_secs(secs))
                         .await;
@@ -345,6 +360,23 @@ impl HttpQuery {
         let (plan, plan_extras) = ExecuteState::plan_sql(&sql, ctx.clone()).await?;
         let schema = plan.schema();
 
+        // Keep the session on the server during the explicit transaction or while it has
+        // temporary tables, it is dropped with the uncommitted changes and the temporary
+        // tables if idle for longer than the result timeout.
+        let in_txn = session.txn_mgr().lock().state() != TxnState::AutoCommit;
+        let has_temp_table = !session.temp_tbl_mgr().lock().is_empty();
+        let create_temp_table = match &plan {
+            Plan::CreateTable(plan) => plan.options.contains_key(OPT_KEY_TEMP_PREFIX),
+            _ => false,
+        };
+        if (in_txn || has_temp_table || create_temp_table || matches!(plan, Plan::Begin))
+            && http_query_manager.get_session(&session_id).await.is_none()
+        {
+            http_query_manager
+                .add_session(session.clone(), Duration::from_secs(result_timeout_secs))
+                .await;
+        }
+
         let span = if let Some(parent) = SpanContext::current_local_parent() {
             Span::root(std::any::type_name::<ExecuteState>(), parent)
                 .with_properties(|| http_ctx.to_minitrace_properties())
@@ -469,6 +501,7 @@ impl HttpQuery {
         // - role: updated by SET ROLE;
         // - secondary_roles: updated by SET SECONDARY ROLES ALL|NONE;
         // - settings: updated by SET XXX = YYY;
+        // - server_session_id: updated by BEGIN, COMMIT, ROLLBACK and CREATE/DROP TEMP TABLE;
         let executor = self.state.read().await;
         let session_state = executor.get_session_state();
 
@@ -482,6 +515,12 @@ impl HttpQuery {
         let database = session_state.current_database.clone();
         let role = session_state.current_role.clone();
         let secondary_roles = session_state.secondary_roles.clone();
+        let server_session_id =
+            if session_state.txn_state != TxnState::AutoCommit || session_state.has_temp_table {
+                Some(self.session_id.clone())
+            } else {
+                None
+            };
 
         HttpSessionConf {
             database: Some(database),
@@ -489,6 +528,7 @@ impl HttpQuery {
             secondary_roles,
             keep_server_session_secs,
             settings: Some(settings),
+            server_session_id,
         }
     }
 
@@ -25,6 +25,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::HttpQueryContext;
+use crate::pipes::PipeScheduler;
 use crate::sessions::SessionType;
 use crate::sessions::TableContext;
 
@@ -127,6 +128,11 @@ pub async fn upload_to_stage(
         files.push(name.clone());
     }
 
+    // Load the uploaded files by the auto ingest pipes of the stage.
+    if args.stage_name != "~" && !files.is_empty() {
+        PipeScheduler::instance().trigger(&args.stage_name);
+    }
+
     let mut id = uuid::Uuid::new_v4().to_string();
     Ok(Json(UploadToStageResponse {
         id,
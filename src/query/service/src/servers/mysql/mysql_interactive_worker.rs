@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -29,7 +30,10 @@ use databend_common_expression::SendableDataBlockStream;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_metrics::mysql::*;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
+use databend_common_sql::PreparedStatement;
 use databend_common_users::CertifiedInfo;
 use databend_common_users::UserApiProvider;
 use futures_util::StreamExt;
@@ -48,6 +52,11 @@ use rand::RngCore;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
+use crate::servers::mysql::mysql_prepared_statement::param_column;
+use crate::servers::mysql::mysql_prepared_statement::param_to_expr;
+use crate::servers::mysql::mysql_prepared_statement::plan_cache_key;
+use crate::servers::mysql::mysql_prepared_statement::MySQLPreparedStatement;
+use crate::servers::mysql::writers::convert_binary_schema;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
@@ -61,6 +70,8 @@ use crate::stream::DataBlockStream;
 
 struct InteractiveWorkerBase {
     session: Arc<Session>,
+    prepared_statements: HashMap<u32, MySQLPreparedStatement>,
+    next_statement_id: u32,
 }
 
 pub struct InteractiveWorker {
@@ -282,36 +293,156 @@ impl InteractiveWorkerBase {
     #[async_backtrace::framed]
     async fn do_prepare<W: AsyncWrite + Unpin>(
         &mut self,
-        _: &str,
+        query: &str,
         writer: StatementMetaWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+        match self.prepare_statement(query).await {
+            Ok(prepared) => {
+                let id = self.next_statement_id;
+                self.next_statement_id = self.next_statement_id.wrapping_add(1);
+
+                writer
+                    .reply(id, &prepared.params, &prepared.columns)
+                    .await?;
+                self.prepared_statements.insert(id, prepared);
+            }
+            Err(error) => {
+                let error = error.display_with_sql(query);
+                writer
+                    .error(ErrorKind::ER_UNKNOWN_ERROR, error.to_string().as_bytes())
+                    .await?;
+            }
+        }
         Ok(())
     }
 
+    #[async_backtrace::framed]
+    async fn prepare_statement(&mut self, query: &str) -> Result<MySQLPreparedStatement> {
+        if let Some((schema, _)) = self.federated_server_command_check(query) {
+            info!("Federated prepare: {}", query);
+            return Ok(MySQLPreparedStatement::create(
+                query.to_string(),
+                None,
+                vec![],
+                convert_binary_schema(&schema)?,
+            ));
+        }
+
+        info!("Prepare query: {}", query);
+        let context = self.session.create_query_context().await?;
+        let sql_dialect = context.get_settings().get_sql_dialect()?;
+        let stmt = PreparedStatement::try_create(query, sql_dialect)?;
+        let mut params = vec![param_column(None); stmt.num_params()];
+        let mut columns = vec![];
+        let mut plan = None;
+
+        // Describe the parameters and result columns by planning the statement with the
+        // placeholders, which may be rejected by the binder in some places, e.g. `LIMIT ?`,
+        // so they are only required to be resolved if there is no parameter.
+        match Planner::new(context.clone())
+            .plan_prepared_stmt(stmt.statement())
+            .await
+        {
+            Ok((bound, extras)) => {
+                params = stmt
+                    .param_types(&bound, &extras.metadata)
+                    .into_iter()
+                    .map(param_column)
+                    .collect();
+                if bound.has_result_set() {
+                    columns = convert_binary_schema(&bound.schema())?;
+                }
+                if stmt.num_params() == 0 {
+                    plan = Some((bound, extras));
+                }
+            }
+            Err(error) if stmt.num_params() == 0 => return Err(error),
+            Err(_) => {}
+        }
+
+        // The statement without parameters is planned only once if nothing is changed.
+        let key = plan_cache_key(&context, stmt.statement());
+        let mut prepared =
+            MySQLPreparedStatement::create(query.to_string(), Some(stmt), params, columns);
+        if let Some((plan, extras)) = plan {
+            prepared.cache_plan(&context, key, &plan, &extras);
+        }
+        Ok(prepared)
+    }
+
     #[async_backtrace::framed]
     async fn do_execute<W: AsyncWrite + Unpin>(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
+        id: u32,
+        params: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+        let mut writer = DFQueryResultWriter::create_binary(writer);
+
+        let instant = Instant::now();
+        let query = self
+            .prepared_statements
+            .get(&id)
+            .map(|prepared| prepared.query.clone())
+            .unwrap_or_default();
+        let query_result = self
+            .execute_statement(id, params)
+            .await
+            .map_err(|err| err.display_with_sql(&query));
+
+        let format = self.session.get_format_settings();
+
+        let mut write_result = writer.write(query_result, &format).await;
+
+        if let Err(cause) = write_result {
+            let suffix = format!("(while in prepared statement {})", query);
+            write_result = Err(cause.add_message_back(suffix));
+        }
+        observe_mysql_process_request_duration(instant.elapsed());
+
+        write_result
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_statement(
+        &mut self,
+        id: u32,
+        params: ParamParser<'_>,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        let prepared = self.prepared_statements.get_mut(&id).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("Unknown prepared statement id {}", id))
+        })?;
+        let Some(stmt) = &prepared.stmt else {
+            let query = prepared.query.clone();
+            return self.do_query(&query).await;
+        };
+
+        let params = params
+            .into_iter()
+            .map(param_to_expr)
+            .collect::<Result<Vec<_>>>()?;
+        let stmt = stmt.bind(params)?;
+        let query = stmt.to_string();
+        info!("Execute prepared query: {}", query);
+
+        let context = self.session.create_query_context().await?;
+        let key = plan_cache_key(&context, &stmt);
+        let (plan, extras) = match prepared.cached_plan(&context, &key).await {
+            Some(cached) => cached,
+            None => {
+                let mut planner = Planner::new(context.clone());
+                let (plan, extras) = planner.plan_stmt(&stmt).await?;
+                prepared.cache_plan(&context, key, &plan, &extras);
+                (plan, extras)
+            }
+        };
+        Self::exec_plan(context, plan, extras, &query).await
     }
 
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, id: u32) {
+        self.prepared_statements.remove(&id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -356,34 +487,42 @@ impl InteractiveWorkerBase {
 
                 let mut planner = Planner::new(context.clone());
                 let (plan, extras) = planner.plan_sql(query).await?;
+                Self::exec_plan(context, plan, extras, query).await
+            }
+        }
+    }
 
-                context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
-                let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
-
-                let has_result_set = plan.has_result_set();
-
-                match interpreter {
-                    Ok(interpreter) => {
-                        let (blocks, extra_info) =
-                            Self::exec_query(interpreter.clone(), &context).await?;
-                        let schema = plan.schema();
-                        let format = context.get_format_settings()?;
-                        Ok((
-                            QueryResult::create(
-                                blocks,
-                                extra_info,
-                                has_result_set,
-                                schema,
-                                query.to_string(),
-                            ),
-                            Some(format),
-                        ))
-                    }
-                    Err(e) => {
-                        InterpreterQueryLog::fail_to_start(context, e.clone());
-                        Err(e)
-                    }
-                }
+    #[async_backtrace::framed]
+    async fn exec_plan(
+        context: Arc<QueryContext>,
+        plan: Plan,
+        extras: PlanExtras,
+        query: &str,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
+
+        let has_result_set = plan.has_result_set();
+
+        match interpreter {
+            Ok(interpreter) => {
+                let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
+                let schema = plan.schema();
+                let format = context.get_format_settings()?;
+                Ok((
+                    QueryResult::create(
+                        blocks,
+                        extra_info,
+                        has_result_set,
+                        schema,
+                        query.to_string(),
+                    ),
+                    Some(format),
+                ))
+            }
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                Err(e)
             }
         }
     }
@@ -457,7 +596,11 @@ impl InteractiveWorker {
         }
 
         InteractiveWorker {
-            base: InteractiveWorkerBase { session },
+            base: InteractiveWorkerBase {
+                session,
+                prepared_statements: HashMap::new(),
+                next_statement_id: 1,
+            },
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
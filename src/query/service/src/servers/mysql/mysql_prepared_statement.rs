@@ -0,0 +1,294 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Lambda;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TypeName;
+use databend_common_ast::ast::UnaryOperator;
+use databend_common_ast::ast::WindowDesc;
+use databend_common_ast::walk_expr;
+use databend_common_ast::walk_query;
+use databend_common_ast::Visitor;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::Span;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::PlanExtras;
+use databend_common_sql::PreparedStatement;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
+use opensrv_mysql::ParamValue;
+use opensrv_mysql::ValueInner;
+
+use crate::sessions::QueryContext;
+
+/// A server side prepared statement created by `COM_STMT_PREPARE`.
+///
+/// `COM_STMT_SEND_LONG_DATA` and `COM_STMT_RESET` only touch the buffered parameters,
+/// which are maintained by the protocol layer and merged into the `ParamParser`.
+pub struct MySQLPreparedStatement {
+    pub query: String,
+    // `None` for the federated commands, which are answered without planning.
+    pub stmt: Option<PreparedStatement>,
+    pub params: Vec<Column>,
+    pub columns: Vec<Column>,
+    // The bound plan of the last execution, reused if the next execution has the same
+    // parameters and current database.
+    plan: Option<CachedPlan>,
+}
+
+struct CachedPlan {
+    key: String,
+    plan: Plan,
+    extras: PlanExtras,
+    // Whether the result of query can be cached, which is decided when it's planned.
+    cacheable: bool,
+}
+
+impl MySQLPreparedStatement {
+    pub fn create(
+        query: String,
+        stmt: Option<PreparedStatement>,
+        params: Vec<Column>,
+        columns: Vec<Column>,
+    ) -> MySQLPreparedStatement {
+        MySQLPreparedStatement {
+            query,
+            stmt,
+            params,
+            columns,
+            plan: None,
+        }
+    }
+
+    /// Get the cached plan of the bound statement, the plan is discarded if any of the tables
+    /// it reads has been changed since it was planned.
+    pub async fn cached_plan(
+        &mut self,
+        ctx: &QueryContext,
+        key: &str,
+    ) -> Option<(Plan, PlanExtras)> {
+        let cached = self.plan.as_ref().filter(|cached| cached.key == key)?;
+        let tables = cached.extras.metadata.read().tables().to_vec();
+        for entry in tables {
+            let table = ctx
+                .get_table(entry.catalog(), entry.database(), entry.name())
+                .await;
+            match table {
+                Ok(table)
+                    if table.get_table_info().ident == entry.table().get_table_info().ident => {}
+                _ => {
+                    self.plan = None;
+                    return None;
+                }
+            }
+        }
+        ctx.set_cacheable(cached.cacheable);
+        Some((cached.plan.clone(), cached.extras.clone()))
+    }
+
+    /// Cache the plan of a query for the next execution. Plans which depend on the state of
+    /// session other than the current database are not cached, nor are the plans of views,
+    /// whose definition is not tracked by the plan.
+    pub fn cache_plan(
+        &mut self,
+        ctx: &QueryContext,
+        key: String,
+        plan: &Plan,
+        extras: &PlanExtras,
+    ) {
+        let Statement::Query(query) = &extras.statement else {
+            return;
+        };
+        if !matches!(plan, Plan::Query { .. })
+            || extras
+                .metadata
+                .read()
+                .tables()
+                .iter()
+                .any(|table| table.is_source_of_view() || table.is_source_of_stage())
+        {
+            return;
+        }
+        let mut checker = SessionFunctionChecker::default();
+        walk_query(&mut checker, query);
+        if checker.found {
+            return;
+        }
+        self.plan = Some(CachedPlan {
+            key,
+            plan: plan.clone(),
+            extras: extras.clone(),
+            cacheable: ctx.get_cacheable(),
+        });
+    }
+}
+
+/// The key of the cached plan, the bound statement is planned within the current database.
+pub fn plan_cache_key(ctx: &QueryContext, stmt: &Statement) -> String {
+    format!(
+        "{}.{}:{}",
+        ctx.get_current_catalog(),
+        ctx.get_current_database(),
+        stmt
+    )
+}
+
+// Find the functions resolved by the state of session when the statement is planned.
+#[derive(Default)]
+struct SessionFunctionChecker {
+    found: bool,
+}
+
+impl<'ast> Visitor<'ast> for SessionFunctionChecker {
+    fn visit_function_call(
+        &mut self,
+        _span: Span,
+        _distinct: bool,
+        name: &'ast Identifier,
+        args: &'ast [Expr],
+        params: &'ast [Expr],
+        _over: &'ast Option<WindowDesc>,
+        _lambda: &'ast Option<Lambda>,
+    ) {
+        let name = name.name.to_lowercase();
+        if matches!(
+            name.as_str(),
+            "current_role" | "timezone" | "last_query_id" | "user" | "currentuser" | "current_user"
+        ) {
+            self.found = true;
+        }
+        for expr in args.iter().chain(params) {
+            walk_expr(self, expr);
+        }
+    }
+}
+
+/// The type of a parameter inferred from the prepared statement. Like MySQL, clients send
+/// the parameters with their own types anyway and the parameters are cast when binding.
+pub fn param_column(data_type: Option<DataType>) -> Column {
+    let data_type = data_type.map(|ty| ty.remove_nullable());
+    let coltype = match &data_type {
+        Some(DataType::Boolean) => ColumnType::MYSQL_TYPE_TINY,
+        Some(DataType::Number(ty)) => match ty {
+            NumberDataType::Int8 | NumberDataType::UInt8 => ColumnType::MYSQL_TYPE_TINY,
+            NumberDataType::Int16 | NumberDataType::UInt16 => ColumnType::MYSQL_TYPE_SHORT,
+            NumberDataType::Int32 | NumberDataType::UInt32 => ColumnType::MYSQL_TYPE_LONG,
+            NumberDataType::Int64 | NumberDataType::UInt64 => ColumnType::MYSQL_TYPE_LONGLONG,
+            NumberDataType::Float32 => ColumnType::MYSQL_TYPE_FLOAT,
+            NumberDataType::Float64 => ColumnType::MYSQL_TYPE_DOUBLE,
+        },
+        Some(DataType::Decimal(_)) => ColumnType::MYSQL_TYPE_NEWDECIMAL,
+        Some(DataType::Date) => ColumnType::MYSQL_TYPE_DATE,
+        Some(DataType::Timestamp) => ColumnType::MYSQL_TYPE_DATETIME,
+        Some(DataType::Binary) => ColumnType::MYSQL_TYPE_BLOB,
+        _ => ColumnType::MYSQL_TYPE_VAR_STRING,
+    };
+    let colflags = match &data_type {
+        Some(DataType::Number(ty)) if ty.is_integer() && !ty.is_signed() => {
+            ColumnFlags::UNSIGNED_FLAG
+        }
+        _ => ColumnFlags::empty(),
+    };
+    Column {
+        table: "".to_string(),
+        column: "?".to_string(),
+        coltype,
+        colflags,
+    }
+}
+
+/// Convert a parameter of `COM_STMT_EXECUTE` to the literal expression.
+pub fn param_to_expr(param: ParamValue) -> Result<Expr> {
+    let literal = |lit| Expr::Literal { span: None, lit };
+    let expr = match param.value.into_inner() {
+        ValueInner::NULL => literal(Literal::Null),
+        ValueInner::Int(v) if v < 0 => Expr::UnaryOp {
+            span: None,
+            op: UnaryOperator::Minus,
+            expr: Box::new(literal(Literal::UInt64(v.unsigned_abs()))),
+        },
+        ValueInner::Int(v) => literal(Literal::UInt64(v as u64)),
+        ValueInner::UInt(v) => literal(Literal::UInt64(v)),
+        ValueInner::Double(v) => literal(Literal::Float64(v)),
+        ValueInner::Bytes(v) => literal(Literal::String(String::from_utf8_lossy(v).to_string())),
+        ValueInner::Date(v) | ValueInner::Datetime(v) => {
+            let target_type = match param.coltype {
+                ColumnType::MYSQL_TYPE_DATE => TypeName::Date,
+                _ => TypeName::Timestamp,
+            };
+            Expr::Cast {
+                span: None,
+                expr: Box::new(literal(Literal::String(decode_datetime(v)?))),
+                target_type,
+                pg_style: false,
+            }
+        }
+        ValueInner::Time(v) => literal(Literal::String(decode_time(v)?)),
+    };
+    Ok(expr)
+}
+
+// The binary protocol value of DATE, DATETIME and TIMESTAMP.
+// See https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_binary_resultset.html
+fn decode_datetime(v: &[u8]) -> Result<String> {
+    match v.len() {
+        0 => Ok("0000-00-00 00:00:00".to_string()),
+        4 | 7 | 11 => {
+            let year = u16::from_le_bytes([v[0], v[1]]);
+            let mut datetime = format!("{:04}-{:02}-{:02}", year, v[2], v[3]);
+            if v.len() >= 7 {
+                datetime.push_str(&format!(" {:02}:{:02}:{:02}", v[4], v[5], v[6]));
+            }
+            if v.len() == 11 {
+                let micros = u32::from_le_bytes([v[7], v[8], v[9], v[10]]);
+                datetime.push_str(&format!(".{:06}", micros));
+            }
+            Ok(datetime)
+        }
+        len => Err(ErrorCode::BadArguments(format!(
+            "Invalid length {} of datetime parameter",
+            len
+        ))),
+    }
+}
+
+// The binary protocol value of TIME.
+fn decode_time(v: &[u8]) -> Result<String> {
+    match v.len() {
+        0 => Ok("00:00:00".to_string()),
+        8 | 12 => {
+            let sign = if v[0] == 1 { "-" } else { "" };
+            let days = u32::from_le_bytes([v[1], v[2], v[3], v[4]]);
+            let hours = days * 24 + v[5] as u32;
+            let mut time = format!("{}{:02}:{:02}:{:02}", sign, hours, v[6], v[7]);
+            if v.len() == 12 {
+                let micros = u32::from_le_bytes([v[8], v[9], v[10], v[11]]);
+                time.push_str(&format!(".{:06}", micros));
+            }
+            Ok(time)
+        }
+        len => Err(ErrorCode::BadArguments(format!(
+            "Invalid length {} of time parameter",
+            len
+        ))),
+    }
+}
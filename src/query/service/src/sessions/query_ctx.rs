@@ -48,6 +48,8 @@ use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::StageAttachment;
+use databend_common_catalog::temp_table::TempTblMgrRef;
+use databend_common_catalog::txn_manager::TxnManagerRef;
 use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
@@ -78,6 +80,7 @@ use databend_common_sql::IndexType;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::DataOperator;
 use databend_common_storage::FileStatus;
+use databend_common_storage::LocalSpillDisk;
 use databend_common_storage::MergeStatus;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StorageMetrics;
@@ -301,6 +304,20 @@ impl QueryContext {
         *self.shared.finish_time.write() = Some(time)
     }
 
+    pub fn set_recursive_cte_table(&self, r_cte_id: u32, blocks: Vec<DataBlock>) {
+        let mut tables = self.shared.recursive_cte_tables.write();
+        tables.insert(r_cte_id, blocks);
+    }
+
+    pub fn get_recursive_cte_table(&self, r_cte_id: u32) -> Vec<DataBlock> {
+        let tables = self.shared.recursive_cte_tables.read();
+        tables.get(&r_cte_id).cloned().unwrap_or_default()
+    }
+
+    pub fn get_local_spill_disk(&self) -> Arc<LocalSpillDisk> {
+        self.shared.local_spill_disk.clone()
+    }
+
     pub fn evict_table_from_cache(&self, catalog: &str, database: &str, table: &str) -> Result<()> {
         self.shared.evict_table_from_cache(catalog, database, table)
     }
@@ -887,6 +904,14 @@ impl TableContext for QueryContext {
         }
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.txn_mgr()
+    }
+
+    fn temp_tbl_mgr(&self) -> TempTblMgrRef {
+        self.shared.temp_tbl_mgr()
+    }
+
     fn add_query_profiles(&self, profiles: &[PlanProfile]) {
         let mut merged_profiles = self.shared.query_profiles.write();
 
@@ -22,16 +22,23 @@ use std::time::SystemTime;
 
 use dashmap::DashMap;
 use databend_common_base::base::Progress;
+use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_base::runtime::Runtime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_base::GLOBAL_TASK;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::StageAttachment;
+use databend_common_catalog::temp_table::TempTblMgrRef;
+use databend_common_catalog::txn_manager::TxnManagerRef;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
@@ -42,6 +49,7 @@ use databend_common_settings::Settings;
 use databend_common_sql::IndexType;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::DataOperator;
+use databend_common_storage::LocalSpillDisk;
 use databend_common_storage::MergeStatus;
 use databend_common_storage::StorageMetrics;
 use databend_common_users::UserApiProvider;
@@ -86,6 +94,7 @@ pub struct QueryContextShared {
     pub(in crate::sessions) affect: Arc<Mutex<Option<QueryAffect>>>,
     pub(in crate::sessions) catalog_manager: Arc<CatalogManager>,
     pub(in crate::sessions) data_operator: DataOperator,
+    pub(in crate::sessions) local_spill_disk: Arc<LocalSpillDisk>,
     pub(in crate::sessions) executor: Arc<RwLock<Weak<PipelineExecutor>>>,
     pub(in crate::sessions) stage_attachment: Arc<RwLock<Option<StageAttachment>>>,
     pub(in crate::sessions) created_time: SystemTime,
@@ -111,6 +120,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) user_agent: Arc<RwLock<String>>,
     /// Key is (cte index, used_count), value contains cte's materialized blocks
     pub(in crate::sessions) materialized_cte_tables: MaterializedCtesBlocks,
+    /// Key is the plan id of recursive cte, value is the working table of current iteration
+    pub(in crate::sessions) recursive_cte_tables: Arc<RwLock<HashMap<u32, Vec<DataBlock>>>>,
 
     pub(in crate::sessions) query_profiles: Arc<RwLock<HashMap<Option<u32>, PlanProfile>>>,
 
@@ -132,6 +143,7 @@ impl QueryContextShared {
             cluster_cache,
             catalog_manager: CatalogManager::instance(),
             data_operator: DataOperator::instance(),
+            local_spill_disk: LocalSpillDisk::instance(),
             init_query_id: Arc::new(RwLock::new(Uuid::new_v4().to_string())),
             total_scan_values: Arc::new(Progress::create()),
             scan_progress: Arc::new(Progress::create()),
@@ -160,6 +172,7 @@ impl QueryContextShared {
             status: Arc::new(RwLock::new("null".to_string())),
             user_agent: Arc::new(RwLock::new("null".to_string())),
             materialized_cte_tables: Arc::new(Default::default()),
+            recursive_cte_tables: Arc::new(Default::default()),
             join_spill_progress: Arc::new(Progress::create()),
             agg_spill_progress: Arc::new(Progress::create()),
             group_by_spill_progress: Arc::new(Progress::create()),
@@ -261,6 +274,14 @@ impl QueryContextShared {
         self.session.get_current_role()
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.session.txn_mgr()
+    }
+
+    pub fn temp_tbl_mgr(&self) -> TempTblMgrRef {
+        self.session.temp_tbl_mgr()
+    }
+
     pub fn set_current_tenant(&self, tenant: String) {
         self.session.set_current_tenant(tenant);
     }
@@ -327,8 +348,26 @@ impl QueryContextShared {
     ) -> Result<Arc<dyn Table>> {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
+        let is_default_catalog = catalog == CATALOG_DEFAULT;
         let catalog = self.catalog_manager.get_catalog(&tenant, catalog).await?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        // the temporary table shadows the permanent table with the same name
+        let temp_table = match is_default_catalog {
+            true => self.temp_tbl_mgr().lock().get_table(database, table),
+            false => None,
+        };
+        let cache_table = match temp_table {
+            Some(table_info) => catalog.get_table_by_info(&table_info)?,
+            None => catalog.get_table(tenant.as_str(), database, table).await?,
+        };
+        // the uncommitted table meta of the explicit transaction is visible to the session
+        let buffered = self
+            .txn_mgr()
+            .lock()
+            .get_table_from_buffer(cache_table.get_table_info());
+        let cache_table = match buffered {
+            Some(table_info) => catalog.get_table_by_info(&table_info)?,
+            None => cache_table,
+        };
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -457,7 +496,15 @@ impl Drop for QueryContextShared {
         // to avoid returning the query_id of the current statement.
         self.session
             .session_ctx
-            .update_query_ids_results(self.init_query_id.read().clone(), None)
+            .update_query_ids_results(self.init_query_id.read().clone(), None);
+
+        // The spilled files on the local disk are useless after the query is finished,
+        // remove them in background to avoid blocking the thread dropping the context.
+        let local_spill_disk = self.local_spill_disk.clone();
+        let query_id = self.init_query_id.read().clone();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            local_spill_disk.remove_query_files(&query_id).await;
+        });
     }
 }
 
@@ -15,6 +15,12 @@
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_base::GLOBAL_TASK;
+use databend_common_catalog::temp_table::TempTblMgr;
+use databend_common_catalog::temp_table::TempTblMgrRef;
+use databend_common_catalog::txn_manager::TxnManagerRef;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -25,8 +31,12 @@ use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::principal::UserPrivilegeType;
 use databend_common_settings::Settings;
+use databend_common_storage::DataOperator;
 use databend_common_users::GrantObjectVisibilityChecker;
+use databend_storages_common_table_meta::table::temp_session_storage_prefix;
 use log::debug;
+use log::info;
+use log::warn;
 use parking_lot::RwLock;
 
 use crate::clusters::ClusterDiscovery;
@@ -48,6 +58,8 @@ pub struct Session {
     status: Arc<RwLock<SessionStatus>>,
     pub(in crate::sessions) mysql_connection_id: Option<u32>,
     format_settings: FormatSettings,
+    // The temporary tables created in the session, they are dropped when the session ends.
+    temp_tbl_mgr: TempTblMgrRef,
 }
 
 impl Session {
@@ -59,6 +71,7 @@ impl Session {
     ) -> Result<Arc<Session>> {
         let status = Arc::new(Default::default());
         let privilege_mgr = SessionPrivilegeManagerImpl::new(session_ctx.clone());
+        let temp_tbl_mgr = TempTblMgr::init(id.clone());
         Ok(Arc::new(Session {
             id,
             typ: RwLock::new(typ),
@@ -67,6 +80,7 @@ impl Session {
             privilege_mgr,
             mysql_connection_id,
             format_settings: FormatSettings::default(),
+            temp_tbl_mgr,
         }))
     }
 
@@ -185,6 +199,14 @@ impl Session {
         self.session_ctx.get_current_catalog()
     }
 
+    pub fn txn_mgr(self: &Arc<Self>) -> TxnManagerRef {
+        self.session_ctx.txn_mgr()
+    }
+
+    pub fn temp_tbl_mgr(self: &Arc<Self>) -> TempTblMgrRef {
+        self.temp_tbl_mgr.clone()
+    }
+
     pub fn get_current_tenant(self: &Arc<Self>) -> String {
         self.session_ctx.get_current_tenant()
     }
@@ -313,6 +335,37 @@ impl Session {
 impl Drop for Session {
     fn drop(&mut self) {
         debug!("Drop session {}", self.id.clone());
+        drop_temp_tables(&self.temp_tbl_mgr);
         SessionManager::instance().destroy_session(&self.id.clone());
     }
 }
+
+/// Purge the data of the temporary tables in background when the session ends.
+fn drop_temp_tables(temp_tbl_mgr: &TempTblMgrRef) {
+    let (session_id, tables) = {
+        let mut temp_tbl_mgr = temp_tbl_mgr.lock();
+        (
+            temp_tbl_mgr.session_id().to_string(),
+            temp_tbl_mgr.drop_all(),
+        )
+    };
+    if tables.is_empty() {
+        return;
+    }
+
+    info!(
+        "Drop {} temporary tables of session {}",
+        tables.len(),
+        session_id
+    );
+    let operator = DataOperator::instance().operator();
+    let prefix = format!("{}/", temp_session_storage_prefix(&session_id));
+    GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+        if let Err(cause) = operator.remove_all(&prefix).await {
+            warn!(
+                "Purge temporary tables of session {} failed: {:?}",
+                session_id, cause
+            );
+        }
+    });
+}
@@ -19,6 +19,8 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use databend_common_catalog::txn_manager::TxnManager;
+use databend_common_catalog::txn_manager::TxnManagerRef;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::RoleInfo;
@@ -63,6 +65,9 @@ pub struct SessionContext {
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
     typ: SessionType,
+    // The explicit transaction of the session, the table metas mutated in the transaction
+    // are buffered here until COMMIT.
+    txn_mgr: TxnManagerRef,
 }
 
 impl SessionContext {
@@ -82,6 +87,7 @@ impl SessionContext {
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
             typ,
+            txn_mgr: TxnManager::init(),
         }))
     }
 
@@ -231,6 +237,10 @@ impl SessionContext {
             .map(|shared| shared.init_query_id.read().clone())
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
+
     pub fn get_query_context_shared(&self) -> Option<Arc<QueryContextShared>> {
         let lock = self.query_context_shared.read();
         lock.upgrade()
@@ -27,13 +27,14 @@ pub enum SessionType {
     Dummy,
     Fuzz,
     Local,
+    Pipe,
 }
 
 impl SessionType {
     pub fn is_user_session(&self) -> bool {
         !matches!(
             self,
-            SessionType::HTTPAPI(_) | SessionType::Dummy | SessionType::Fuzz
+            SessionType::HTTPAPI(_) | SessionType::Dummy | SessionType::Fuzz | SessionType::Pipe
         )
     }
 }
@@ -52,6 +53,7 @@ impl fmt::Display for SessionType {
             SessionType::HTTPAPI(usage) => format!("HTTPAPI({})", usage),
             SessionType::Fuzz => "Fuzz".to_string(),
             SessionType::Local => "Local".to_string(),
+            SessionType::Pipe => "Pipe".to_string(),
         };
         write!(f, "{}", name)
     }
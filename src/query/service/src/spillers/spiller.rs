@@ -26,6 +26,7 @@ use databend_common_expression::arrow::deserialize_column;
 use databend_common_expression::arrow::serialize_column;
 use databend_common_expression::DataBlock;
 use databend_common_hashtable::hash2bucket;
+use databend_common_storage::LocalSpillDisk;
 use log::info;
 use opendal::Operator;
 
@@ -36,8 +37,9 @@ use crate::sessions::QueryContext;
 pub enum SpillerType {
     HashJoinBuild,
     HashJoinProbe,
-    OrderBy, /* Todo: Add more spillers type
-              * Aggregation */
+    OrderBy,
+    Window, /* Todo: Add more spillers type
+             * Aggregation */
 }
 
 impl Display for SpillerType {
@@ -46,6 +48,7 @@ impl Display for SpillerType {
             SpillerType::HashJoinBuild => write!(f, "HashJoinBuild"),
             SpillerType::HashJoinProbe => write!(f, "HashJoinProbe"),
             SpillerType::OrderBy => write!(f, "OrderBy"),
+            SpillerType::Window => write!(f, "Window"),
         }
     }
 }
@@ -67,11 +70,13 @@ impl SpillerConfig {
 /// 1. Collection data that needs to be spilled.
 /// 2. Partition data by the specified algorithm which specifies by operator
 /// 3. Serialization and deserialization input data
-/// 4. Interact with the underlying storage engine to write and read spilled data
+/// 4. Interact with the underlying storage engine to write and read spilled data,
+///    the local disk is preferred if it is configured and has enough space.
 #[derive(Clone)]
 pub struct Spiller {
     ctx: Arc<QueryContext>,
     operator: Operator,
+    local_disk: Arc<LocalSpillDisk>,
     config: SpillerConfig,
     spiller_type: SpillerType,
     /// Partition set, which records there are how many partitions.
@@ -97,6 +102,7 @@ impl Spiller {
         Self {
             ctx,
             operator,
+            local_disk: LocalSpillDisk::instance(),
             config,
             spiller_type,
             partition_set: vec![0, 1, 2, 3, 4, 5, 6, 7],
@@ -110,7 +116,11 @@ impl Spiller {
     /// We should guarantee that the file is managed by this spiller.
     pub async fn read_spilled(&self, file: &str) -> Result<(DataBlock, u64)> {
         debug_assert!(self.columns_layout.contains_key(file));
-        let data = self.operator.read(file).await?;
+        let data = if self.local_disk.is_local(file) {
+            self.local_disk.read(file, None).await?
+        } else {
+            self.operator.read(file).await?
+        };
         let bytes = data.len() as u64;
 
         let mut begin = 0;
@@ -124,35 +134,52 @@ impl Spiller {
         Ok((block, bytes))
     }
 
+    /// Remove a spilled file that is no longer needed.
+    pub async fn remove_spilled(&mut self, file: &str) -> Result<()> {
+        self.columns_layout.remove(file);
+        if self.local_disk.is_local(file) {
+            self.local_disk.remove(&self.ctx.get_id(), file).await
+        } else {
+            Ok(self.operator.delete(file).await?)
+        }
+    }
+
     /// Write a [`DataBlock`] to storage.
+    /// The local disk is preferred, and the remote storage is used if the local disk is not available.
     pub async fn spill_block(&mut self, data: DataBlock) -> Result<(String, u64)> {
-        let unique_name = GlobalUniqName::unique();
-        let location = format!("{}/{}", self.config.location_prefix, unique_name);
-        let mut write_bytes = 0;
-
-        let mut writer = self
-            .operator
-            .writer_with(&location)
-            .buffer(8 * 1024 * 1024)
-            .await?;
         let columns = data.columns().to_vec();
         let mut columns_data = Vec::with_capacity(columns.len());
+        let mut columns_layout = Vec::with_capacity(columns.len());
         for column in columns.into_iter() {
             let column = column.value.as_column().unwrap();
             let column_data = serialize_column(column);
-            self.columns_layout
-                .entry(location.clone())
-                .and_modify(|layouts| {
-                    layouts.push(column_data.len());
-                })
-                .or_insert(vec![column_data.len()]);
-            write_bytes += column_data.len() as u64;
+            columns_layout.push(column_data.len());
             columns_data.push(column_data);
         }
-        for data in columns_data.into_iter() {
-            writer.write(data).await?;
-        }
-        writer.close().await?;
+        let write_bytes = columns_layout.iter().sum::<usize>() as u64;
+
+        let location = match self
+            .local_disk
+            .write(&self.ctx.get_id(), &columns_data)
+            .await
+        {
+            Some(location) => location,
+            None => {
+                let unique_name = GlobalUniqName::unique();
+                let location = format!("{}/{}", self.config.location_prefix, unique_name);
+                let mut writer = self
+                    .operator
+                    .writer_with(&location)
+                    .buffer(8 * 1024 * 1024)
+                    .await?;
+                for data in columns_data.into_iter() {
+                    writer.write(data).await?;
+                }
+                writer.close().await?;
+                location
+            }
+        };
+        self.columns_layout.insert(location.clone(), columns_layout);
 
         Ok((location, write_bytes))
     }
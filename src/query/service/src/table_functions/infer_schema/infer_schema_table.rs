@@ -46,6 +46,9 @@ use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::binder::resolve_file_location;
 use databend_common_storage::init_stage_operator;
+use databend_common_storage::read_arrow_schema_async;
+use databend_common_storage::read_avro_schema_async;
+use databend_common_storage::read_orc_schema_async;
 use databend_common_storage::read_parquet_schema_async;
 use databend_common_storage::read_parquet_schema_async_rs;
 use databend_common_storage::StageFilesInfo;
@@ -255,9 +258,20 @@ impl AsyncSource for InferSchemaSource {
                     TableSchema::try_from(&arrow_schema)?
                 }
             }
+            StageFileFormatType::Avro => {
+                read_avro_schema_async(&operator, &first_file.path).await?
+            }
+            StageFileFormatType::Orc => {
+                let arrow_schema = read_orc_schema_async(&operator, &first_file.path).await?;
+                TableSchema::try_from(&arrow_schema)?
+            }
+            StageFileFormatType::Arrow => {
+                let arrow_schema = read_arrow_schema_async(&operator, &first_file.path).await?;
+                TableSchema::try_from(arrow_schema.as_ref())?
+            }
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet, Avro, ORC and Arrow",
                 ));
             }
         };
@@ -21,6 +21,7 @@ use databend_common_exception::Result;
 use databend_common_meta_types::MetaId;
 use databend_common_storages_fuse::table_functions::FuseColumnTable;
 use databend_common_storages_fuse::table_functions::FuseEncodingTable;
+use databend_common_storages_iceberg::IcebergSnapshotsTable;
 use databend_common_storages_stream::stream_status_table_func::StreamStatusTable;
 use itertools::Itertools;
 use parking_lot::RwLock;
@@ -134,6 +135,11 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(FuseStatisticTable::create)),
         );
 
+        creators.insert(
+            "iceberg_snapshots".to_string(),
+            (next_id(), Arc::new(IcebergSnapshotsTable::create)),
+        );
+
         creators.insert(
             "clustering_information".to_string(),
             (next_id(), Arc::new(ClusteringInformationTable::create)),
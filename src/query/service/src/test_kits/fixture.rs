@@ -315,6 +315,8 @@ impl TestFixture {
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
             cluster_key: Some("(id)".to_string()),
+            auto_increment_sequences: vec![],
+            constraints: Default::default(),
         }
     }
 
@@ -340,6 +342,8 @@ impl TestFixture {
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
             cluster_key: None,
+            auto_increment_sequences: vec![],
+            constraints: Default::default(),
         }
     }
 
@@ -376,6 +380,8 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            auto_increment_sequences: vec![],
+            constraints: Default::default(),
         }
     }
 
@@ -421,6 +427,8 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            auto_increment_sequences: vec![],
+            constraints: Default::default(),
         }
     }
 
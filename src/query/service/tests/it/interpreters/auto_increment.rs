@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::GetSequenceReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_sql::auto_increment_sequences;
+use databend_query::test_kits::TestFixture;
+
+async fn table_sequences(fixture: &TestFixture, table: &str) -> Result<Vec<String>> {
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx
+        .get_table("default", &fixture.default_db_name(), table)
+        .await?;
+    Ok(auto_increment_sequences(&table.schema()))
+}
+
+async fn sequence_exists(fixture: &TestFixture, sequence: &str) -> Result<bool> {
+    let ctx = fixture.new_query_ctx().await?;
+    let req = GetSequenceReq {
+        ident: SequenceIdent::new(fixture.default_tenant(), sequence),
+    };
+    match ctx.get_default_catalog()?.get_sequence(req).await {
+        Ok(_) => Ok(true),
+        Err(e) if e.code() == ErrorCode::UNKNOWN_SEQUENCE => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_auto_increment_sequences_follow_table() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    fixture
+        .execute_command(&format!("create table {db}.t(id int autoincrement, c int)"))
+        .await?;
+    let sequences = table_sequences(&fixture, "t").await?;
+    assert_eq!(sequences.len(), 1);
+    assert!(sequence_exists(&fixture, &sequences[0]).await?);
+
+    // the existing table keeps its sequence.
+    fixture
+        .execute_command(&format!(
+            "create table if not exists {db}.t(id int autoincrement, c int)"
+        ))
+        .await?;
+    assert_eq!(table_sequences(&fixture, "t").await?, sequences);
+    assert!(sequence_exists(&fixture, &sequences[0]).await?);
+
+    // the sequence of the replaced table is dropped.
+    fixture
+        .execute_command(&format!(
+            "create or replace table {db}.t(id int autoincrement, c int)"
+        ))
+        .await?;
+    let replaced = table_sequences(&fixture, "t").await?;
+    assert_eq!(replaced.len(), 1);
+    assert_ne!(replaced, sequences);
+    assert!(!sequence_exists(&fixture, &sequences[0]).await?);
+    assert!(sequence_exists(&fixture, &replaced[0]).await?);
+
+    fixture
+        .execute_command(&format!("drop table {db}.t"))
+        .await?;
+    assert!(!sequence_exists(&fixture, &replaced[0]).await?);
+
+    // the user defined sequences referenced by the default exprs are not owned by the table.
+    fixture.execute_command("create sequence seq_t1").await?;
+    fixture
+        .execute_command(&format!(
+            "create table {db}.t1(id int default nextval(seq_t1), c int)"
+        ))
+        .await?;
+    assert!(table_sequences(&fixture, "t1").await?.is_empty());
+    fixture
+        .execute_command(&format!("drop table {db}.t1 all"))
+        .await?;
+    assert!(sequence_exists(&fixture, "seq_t1").await?);
+
+    Ok(())
+}
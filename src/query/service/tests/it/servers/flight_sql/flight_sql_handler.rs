@@ -16,12 +16,24 @@
 
 use std::fs;
 use std::io::Write;
+use std::sync::Arc;
 
+use arrow_array::ArrayRef;
+use arrow_array::Int64Array;
+use arrow_array::RecordBatch;
+use arrow_array::StringArray;
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_service_server::FlightServiceServer;
+use arrow_flight::sql::action_end_transaction_request::EndTransaction;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::CommandGetDbSchemas;
+use arrow_flight::sql::CommandGetPrimaryKeys;
+use arrow_flight::sql::CommandGetTables;
+use arrow_flight::sql::CommandGetXdbcTypeInfo;
+use arrow_flight::sql::XdbcDataType;
 use arrow_flight::utils::flight_data_to_batches;
 use arrow_flight::FlightData;
+use arrow_flight::FlightInfo;
 use arrow_schema::ArrowError;
 use databend_common_base::base::tokio;
 use databend_common_config::InnerConfig;
@@ -66,15 +78,22 @@ async fn run_query(
         affected_rows.to_string()
     } else {
         let flight_info = stmt.execute().await?;
-        let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
-        let flight_data = client.do_get(ticket).await?;
-        let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
-        let batches = flight_data_to_batches(&flight_data)?;
-        pretty_format_batches(batches.as_slice())?.to_string()
+        fetch_results(client, flight_info).await?
     };
     Ok(res)
 }
 
+async fn fetch_results(
+    client: &mut FlightSqlServiceClient<Channel>,
+    flight_info: FlightInfo,
+) -> std::result::Result<String, ArrowError> {
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await?;
+    let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
+    let batches = flight_data_to_batches(&flight_data)?;
+    Ok(pretty_format_batches(batches.as_slice())?.to_string())
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -148,3 +167,153 @@ async fn test_query() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_metadata_and_parameters() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    let stream = UnixListenerStream::new(uds);
+
+    let service = FlightSqlServiceImpl::create();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(service))
+        .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+    let request_future = async {
+        let mut client = client_with_uds(path).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        client
+            .execute_update(
+                "create table test_meta(a int not null, b int not null, primary key (b, a))"
+                    .to_string(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let flight_info = client.get_catalogs().await.unwrap();
+        let res = fetch_results(&mut client, flight_info).await.unwrap();
+        assert!(res.contains("default"), "{res}");
+
+        let flight_info = client
+            .get_db_schemas(CommandGetDbSchemas {
+                catalog: None,
+                db_schema_filter_pattern: Some("def%".to_string()),
+            })
+            .await
+            .unwrap();
+        let res = fetch_results(&mut client, flight_info).await.unwrap();
+        assert!(res.contains("default"), "{res}");
+        assert!(!res.contains("system"), "{res}");
+
+        let flight_info = client
+            .get_tables(CommandGetTables {
+                catalog: None,
+                db_schema_filter_pattern: Some("default".to_string()),
+                table_name_filter_pattern: Some("test\\_m%".to_string()),
+                table_types: vec!["TABLE".to_string()],
+                include_schema: false,
+            })
+            .await
+            .unwrap();
+        let res = fetch_results(&mut client, flight_info).await.unwrap();
+        let expected = "\
++--------------+----------------+------------+------------+
+| catalog_name | db_schema_name | table_name | table_type |
++--------------+----------------+------------+------------+
+| default      | default        | test_meta  | TABLE      |
++--------------+----------------+------------+------------+";
+        assert_eq!(res, expected);
+
+        let flight_info = client.get_table_types().await.unwrap();
+        let res = fetch_results(&mut client, flight_info).await.unwrap();
+        assert!(res.contains("TABLE") && res.contains("VIEW"), "{res}");
+
+        let flight_info = client
+            .get_primary_keys(CommandGetPrimaryKeys {
+                catalog: None,
+                db_schema: Some("default".to_string()),
+                table: "test_meta".to_string(),
+            })
+            .await
+            .unwrap();
+        let res = fetch_results(&mut client, flight_info).await.unwrap();
+        let expected = "\
++--------------+----------------+------------+-------------+----------------+--------------+
+| catalog_name | db_schema_name | table_name | column_name | key_name       | key_sequence |
++--------------+----------------+------------+-------------+----------------+--------------+
+| default      | default        | test_meta  | b           | test_meta_pkey | 1            |
+| default      | default        | test_meta  | a           | test_meta_pkey | 2            |
++--------------+----------------+------------+-------------+----------------+--------------+";
+        assert_eq!(res, expected);
+
+        let mut stmt = client
+            .prepare("select ? + 1, concat(?, 'c')".to_string(), None)
+            .await
+            .unwrap();
+        assert_eq!(stmt.parameter_schema().unwrap().fields().len(), 2);
+        let params = RecordBatch::try_from_iter(vec![
+            ("$1", Arc::new(Int64Array::from(vec![41])) as ArrayRef),
+            ("$2", Arc::new(StringArray::from(vec!["ab"])) as ArrayRef),
+        ])
+        .unwrap();
+        stmt.set_parameters(params).unwrap();
+        let flight_info = stmt.execute().await.unwrap();
+        let res = fetch_results(&mut client, flight_info).await.unwrap();
+        assert!(res.contains("| 42 ") && res.contains("| abc "), "{res}");
+
+        let flight_info = client
+            .get_xdbc_type_info(CommandGetXdbcTypeInfo {
+                data_type: Some(XdbcDataType::XdbcVarchar as i32),
+            })
+            .await
+            .unwrap();
+        let res = fetch_results(&mut client, flight_info).await.unwrap();
+        assert!(res.contains("| VARCHAR "), "{res}");
+        assert!(!res.contains("| INT "), "{res}");
+
+        let transaction_id = client.begin_transaction().await.unwrap();
+        run_query(&mut client, "insert into test_meta values (1, 2)")
+            .await
+            .unwrap();
+        client
+            .end_transaction(transaction_id, EndTransaction::Rollback)
+            .await
+            .unwrap();
+        let res = run_query(&mut client, "select count(*) from test_meta")
+            .await
+            .unwrap();
+        assert!(res.contains("| 0 "), "{res}");
+
+        let transaction_id = client.begin_transaction().await.unwrap();
+        run_query(&mut client, "insert into test_meta values (1, 2)")
+            .await
+            .unwrap();
+        client
+            .end_transaction(transaction_id, EndTransaction::Commit)
+            .await
+            .unwrap();
+        let res = run_query(&mut client, "select count(*) from test_meta")
+            .await
+            .unwrap();
+        assert!(res.contains("| 1 "), "{res}");
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+
+    Ok(())
+}
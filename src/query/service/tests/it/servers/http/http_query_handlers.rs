@@ -1445,6 +1445,7 @@ async fn test_affect() -> Result<()> {
                     ("max_threads".to_string(), "1".to_string()),
                     ("timezone".to_string(), "Asia/Shanghai".to_string()),
                 ])),
+                server_session_id: None,
             }),
         ),
         (
@@ -1464,6 +1465,7 @@ async fn test_affect() -> Result<()> {
                     "max_threads".to_string(),
                     "6".to_string(),
                 )])),
+                server_session_id: None,
             }),
         ),
         (
@@ -1478,6 +1480,7 @@ async fn test_affect() -> Result<()> {
                     "max_threads".to_string(),
                     "6".to_string(),
                 )])),
+                server_session_id: None,
             }),
         ),
         (
@@ -1494,6 +1497,7 @@ async fn test_affect() -> Result<()> {
                     "max_threads".to_string(),
                     "6".to_string(),
                 )])),
+                server_session_id: None,
             }),
         ),
     ];
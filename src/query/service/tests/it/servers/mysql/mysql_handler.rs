@@ -28,11 +28,16 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
+use mysql_async::consts::ColumnFlags;
+use mysql_async::consts::ColumnType;
 use mysql_async::prelude::FromRow;
 use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
 use mysql_async::Row;
 use mysql_async::SslOpts;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpStream;
 use tokio::sync::Barrier;
 
 use crate::tests::tls_constants::*;
@@ -73,6 +78,104 @@ async fn test_connect_with_tls() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port(), false).await?;
+
+    let stmt = connection
+        .prep("SELECT number, number + ? FROM numbers(10) WHERE number > ? ORDER BY number")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    assert_eq!(stmt.num_params(), 2);
+    assert_eq!(stmt.num_columns(), 2);
+    // The parameters have the type of `number`, which is inferred from the bound plan.
+    for param in stmt.params() {
+        assert_eq!(param.column_type(), ColumnType::MYSQL_TYPE_LONGLONG);
+        assert!(param.flags().contains(ColumnFlags::UNSIGNED_FLAG));
+    }
+
+    let rows: Vec<(u64, u64)> = connection
+        .exec(&stmt, (10u64, 7u64))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(rows, vec![(8, 18), (9, 19)]);
+
+    // The prepared statement can be executed again with other parameters.
+    let rows: Vec<(u64, u64)> = connection
+        .exec(&stmt, (-1i64, 8u64))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(rows, vec![(9, 8)]);
+
+    connection
+        .close(stmt)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Close failed")?;
+
+    connection
+        .query_drop("CREATE TABLE t_prepared(a INT, b STRING)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Create table failed")?;
+    let insert = connection
+        .prep("INSERT INTO t_prepared VALUES (?, ?), (?, 'x')")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    let param_types = insert
+        .params()
+        .iter()
+        .map(|param| param.column_type())
+        .collect::<Vec<_>>();
+    assert_eq!(param_types, vec![
+        ColumnType::MYSQL_TYPE_LONG,
+        ColumnType::MYSQL_TYPE_VAR_STRING,
+        ColumnType::MYSQL_TYPE_LONG
+    ]);
+    connection
+        .exec_drop(&insert, (1, "a'b", 2))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Insert failed")?;
+    let rows: Vec<(i32, String)> = connection
+        .exec("SELECT a, b FROM t_prepared WHERE b <> ? ORDER BY a", ("",))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Select failed")?;
+    assert_eq!(rows, vec![(1, "a'b".to_string()), (2, "x".to_string())]);
+
+    Ok(())
+}
+
+// `COM_STMT_SEND_LONG_DATA` and `COM_STMT_RESET` are not exposed by the client library,
+// so they are tested with the raw packets of the protocol.
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement_long_data() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut conn = RawConnection::connect(runnable_server.port()).await?;
+
+    let stmt_id = conn.prepare("SELECT LENGTH(?)").await?;
+    conn.send_long_data(stmt_id, 0, b"abc").await?;
+    conn.send_long_data(stmt_id, 0, b"def").await?;
+    assert_eq!(conn.execute_long_data(stmt_id).await?, 6);
+
+    // The buffered long data is discarded by the reset.
+    conn.reset(stmt_id).await?;
+    conn.send_long_data(stmt_id, 0, b"xy").await?;
+    assert_eq!(conn.execute_long_data(stmt_id).await?, 2);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     // TestFixture will create a default session, so we should limit the max_active_sessions to 2.
@@ -217,3 +320,140 @@ impl FromRow for EmptyRow {
         Ok(EmptyRow)
     }
 }
+
+const COM_STMT_PREPARE: u8 = 0x16;
+const COM_STMT_EXECUTE: u8 = 0x17;
+const COM_STMT_SEND_LONG_DATA: u8 = 0x18;
+const COM_STMT_RESET: u8 = 0x1a;
+
+// A minimal client of the MySQL protocol, which authenticates as `root` without password.
+struct RawConnection {
+    stream: TcpStream,
+    seq: u8,
+}
+
+impl RawConnection {
+    async fn connect(port: u16) -> Result<RawConnection> {
+        let stream = TcpStream::connect(("127.0.0.1", port)).await?;
+        let mut conn = RawConnection { stream, seq: 0 };
+        // Initial handshake of the server.
+        conn.read_packet().await?;
+
+        // CLIENT_LONG_PASSWORD | CLIENT_PROTOCOL_41 | CLIENT_TRANSACTIONS
+        // | CLIENT_SECURE_CONNECTION | CLIENT_PLUGIN_AUTH
+        let capabilities: u32 = 0x1 | 0x200 | 0x2000 | 0x8000 | 0x80000;
+        let mut response = vec![];
+        response.extend_from_slice(&capabilities.to_le_bytes());
+        response.extend_from_slice(&(16 * 1024 * 1024u32).to_le_bytes());
+        response.push(33);
+        response.extend_from_slice(&[0; 23]);
+        response.extend_from_slice(b"root\0");
+        response.push(0);
+        response.extend_from_slice(b"mysql_native_password\0");
+        conn.write_packet(&response).await?;
+        conn.expect_ok().await?;
+        Ok(conn)
+    }
+
+    async fn prepare(&mut self, query: &str) -> Result<u32> {
+        self.command(COM_STMT_PREPARE, query.as_bytes()).await?;
+        let packet = self.read_packet().await?;
+        assert_eq!(
+            packet[0],
+            0,
+            "prepare failed: {:?}",
+            String::from_utf8_lossy(&packet)
+        );
+        let stmt_id = u32::from_le_bytes([packet[1], packet[2], packet[3], packet[4]]);
+        let num_columns = u16::from_le_bytes([packet[5], packet[6]]);
+        let num_params = u16::from_le_bytes([packet[7], packet[8]]);
+        // The definitions of parameters and columns, each followed by EOF.
+        for num in [num_params, num_columns] {
+            if num > 0 {
+                for _ in 0..=num {
+                    self.read_packet().await?;
+                }
+            }
+        }
+        Ok(stmt_id)
+    }
+
+    async fn send_long_data(&mut self, stmt_id: u32, param: u16, data: &[u8]) -> Result<()> {
+        let mut payload = stmt_id.to_le_bytes().to_vec();
+        payload.extend_from_slice(&param.to_le_bytes());
+        payload.extend_from_slice(data);
+        // No response is sent by the server.
+        self.command(COM_STMT_SEND_LONG_DATA, &payload).await
+    }
+
+    // Execute the statement with a single string parameter sent as long data,
+    // returns the unsigned integer of the single row result.
+    async fn execute_long_data(&mut self, stmt_id: u32) -> Result<u64> {
+        let mut payload = stmt_id.to_le_bytes().to_vec();
+        payload.push(0);
+        payload.extend_from_slice(&1u32.to_le_bytes());
+        // NULL bitmap, new params bound flag and the type of parameter.
+        payload.extend_from_slice(&[0, 1, 0xfd, 0]);
+        self.command(COM_STMT_EXECUTE, &payload).await?;
+
+        let packet = self.read_packet().await?;
+        assert_ne!(
+            packet[0],
+            0xff,
+            "execute failed: {:?}",
+            String::from_utf8_lossy(&packet)
+        );
+        // The definitions of columns followed by EOF.
+        for _ in 0..=packet[0] {
+            self.read_packet().await?;
+        }
+        let row = self.read_packet().await?;
+        assert_eq!(row[0], 0);
+        let value = u64::from_le_bytes(row[2..10].try_into().unwrap());
+        let eof = self.read_packet().await?;
+        assert_eq!(eof[0], 0xfe);
+        Ok(value)
+    }
+
+    async fn reset(&mut self, stmt_id: u32) -> Result<()> {
+        self.command(COM_STMT_RESET, &stmt_id.to_le_bytes()).await?;
+        self.expect_ok().await
+    }
+
+    async fn command(&mut self, command: u8, payload: &[u8]) -> Result<()> {
+        self.seq = 0;
+        let mut packet = vec![command];
+        packet.extend_from_slice(payload);
+        self.write_packet(&packet).await
+    }
+
+    async fn expect_ok(&mut self) -> Result<()> {
+        let packet = self.read_packet().await?;
+        assert_eq!(
+            packet[0],
+            0,
+            "expect OK: {:?}",
+            String::from_utf8_lossy(&packet)
+        );
+        Ok(())
+    }
+
+    async fn write_packet(&mut self, payload: &[u8]) -> Result<()> {
+        let mut packet = (payload.len() as u32).to_le_bytes()[..3].to_vec();
+        packet.push(self.seq);
+        packet.extend_from_slice(payload);
+        self.seq = self.seq.wrapping_add(1);
+        self.stream.write_all(&packet).await?;
+        Ok(())
+    }
+
+    async fn read_packet(&mut self) -> Result<Vec<u8>> {
+        let mut header = [0; 4];
+        self.stream.read_exact(&mut header).await?;
+        self.seq = header[3].wrapping_add(1);
+        let len = u32::from_le_bytes([header[0], header[1], header[2], 0]) as usize;
+        let mut payload = vec![0; len];
+        self.stream.read_exact(&mut payload).await?;
+        Ok(payload)
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::Path;
+
 use databend_common_base::base::tokio;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
@@ -24,9 +26,11 @@ use databend_common_expression::FromData;
 use databend_common_expression::ScalarRef;
 use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_storage::DataOperator;
+use databend_common_storage::LocalSpillDisk;
 use databend_query::spillers::Spiller;
 use databend_query::spillers::SpillerConfig;
 use databend_query::spillers::SpillerType;
+use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -75,3 +79,97 @@ async fn test_spill_with_partition() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_local_spill_disk_quota_and_cleanup() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().display().to_string();
+    // The files in the configured path are not owned by the query node.
+    let other = dir.path().join("other");
+    std::fs::write(&other, b"other")?;
+
+    let disk = LocalSpillDisk::try_create(&path, "node-1", 10)?;
+    assert!(other.exists());
+    assert!(dir.path().join("databend-spill-node-1").is_dir());
+
+    let location = disk.write("query-1", &[vec![1; 6]]).await.unwrap();
+    assert!(disk.is_local(&location));
+    assert!(location.starts_with(&path));
+    assert_eq!(disk.read(&location, Some(2..4)).await?, vec![1; 2]);
+
+    // Out of quota, the caller falls back to the remote storage.
+    assert!(disk.write("query-2", &[vec![2; 6]]).await.is_none());
+    assert_eq!(disk.used_bytes(), 6);
+
+    let location_2 = disk
+        .write("query-2", &[vec![2; 2], vec![2; 2]])
+        .await
+        .unwrap();
+    assert_eq!(disk.used_bytes(), 10);
+    assert_eq!(disk.list_files()?.len(), 2);
+
+    // The quota is released when the files are removed.
+    disk.remove("query-2", &location_2).await?;
+    assert!(!Path::new(&location_2).exists());
+    assert_eq!(disk.used_bytes(), 6);
+
+    disk.remove_query_files("query-1").await;
+    assert!(!Path::new(&location).exists());
+    assert_eq!(disk.used_bytes(), 0);
+
+    // The files left by the last run of the node are removed, but only in its own directory.
+    disk.write("query-3", &[vec![3; 1]]).await.unwrap();
+    drop(disk);
+    let disk = LocalSpillDisk::try_create(&path, "node-1", 10)?;
+    assert!(disk.list_files()?.is_empty());
+    assert!(other.exists());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_spill_to_local_disk_with_fallback() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let mut config = ConfigBuilder::create().config();
+    config.query.spill_local_disk_path = dir.path().display().to_string();
+    config.query.spill_local_disk_max_bytes = 64 * 1024;
+    let fixture = TestFixture::setup_with_config(&config).await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let tenant = ctx.get_tenant();
+    let spiller_config = SpillerConfig::create(query_spill_prefix(&tenant));
+    let operator = DataOperator::instance().operator();
+    let local_disk = ctx.get_local_spill_disk();
+    let mut spiller = Spiller::create(ctx, operator, spiller_config, SpillerType::OrderBy);
+
+    let small =
+        DataBlock::new_from_columns(vec![Int32Type::from_data((0..100).collect::<Vec<_>>())]);
+    let (local, _) = spiller.spill_block(small).await?;
+    assert!(local_disk.is_local(&local));
+    assert!(local.starts_with(&config.query.spill_local_disk_path));
+
+    // The block is larger than the quota of the local disk.
+    let large =
+        DataBlock::new_from_columns(vec![Int32Type::from_data((0..100_000).collect::<Vec<_>>())]);
+    let (remote, _) = spiller.spill_block(large).await?;
+    assert!(!local_disk.is_local(&remote));
+    assert!(remote.starts_with("_query_spill"));
+
+    let (block, _) = spiller.read_spilled(&local).await?;
+    assert_eq!(block.num_rows(), 100);
+    let (block, _) = spiller.read_spilled(&remote).await?;
+    assert_eq!(block.num_rows(), 100_000);
+
+    spiller.remove_spilled(&local).await?;
+    spiller.remove_spilled(&remote).await?;
+    assert_eq!(local_disk.used_bytes(), 0);
+    assert!(!Path::new(&local).exists());
+    assert!(
+        !DataOperator::instance()
+            .operator()
+            .is_exist(&remote)
+            .await?
+    );
+
+    Ok(())
+}
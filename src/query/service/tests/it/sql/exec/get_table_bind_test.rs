@@ -36,6 +36,8 @@ use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::ProcessInfo;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::temp_table::TempTblMgrRef;
+use databend_common_catalog::txn_manager::TxnManagerRef;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
@@ -735,6 +737,14 @@ impl TableContext for CtxDelegation {
         self.ctx.get_license_key()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
+
+    fn temp_tbl_mgr(&self) -> TempTblMgrRef {
+        self.ctx.temp_tbl_mgr()
+    }
+
     fn get_queries_profile(&self) -> HashMap<String, Vec<Arc<Profile>>> {
         todo!()
     }
@@ -85,6 +85,8 @@ fn create_table_plan(fixture: &TestFixture, format: &str) -> CreateTablePlan {
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        auto_increment_sequences: vec![],
+        constraints: Default::default(),
     }
 }
 
@@ -58,6 +58,8 @@ async fn test_fuse_alter_table_cluster_key() -> databend_common_exception::Resul
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        auto_increment_sequences: vec![],
+        constraints: Default::default(),
     };
 
     // create test table
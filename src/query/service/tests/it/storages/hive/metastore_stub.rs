@@ -0,0 +1,487 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An in-process hive metastore speaking the thrift binary protocol.
+//!
+//! Only the calls issued by the hive catalog and the hive table writer are served,
+//! other calls are answered with an application exception.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::io::AsyncReadExt;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_base::base::tokio::net::TcpListener;
+use databend_common_base::base::tokio::net::TcpStream;
+
+const T_STOP: u8 = 0;
+const T_BOOL: u8 = 2;
+const T_BYTE: u8 = 3;
+const T_DOUBLE: u8 = 4;
+const T_I16: u8 = 6;
+const T_I32: u8 = 8;
+const T_I64: u8 = 10;
+const T_STRING: u8 = 11;
+const T_STRUCT: u8 = 12;
+const T_MAP: u8 = 13;
+const T_SET: u8 = 14;
+const T_LIST: u8 = 15;
+
+const MESSAGE_VERSION: u32 = 0x8001_0000;
+const MESSAGE_REPLY: u32 = 2;
+const MESSAGE_EXCEPTION: u32 = 3;
+// the type of TApplicationException
+const INTERNAL_ERROR: i32 = 6;
+
+const DATABASE: &str = "default";
+const PARQUET_INPUT_FORMAT: &str = "org.apache.hadoop.hive.ql.io.parquet.MapredParquetInputFormat";
+
+#[derive(Debug, Clone)]
+enum Value {
+    Bool(bool),
+    Byte(i8),
+    Double(f64),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    String(String),
+    Struct(BTreeMap<i16, Value>),
+    List(u8, Vec<Value>),
+    Map(u8, u8, Vec<(Value, Value)>),
+}
+
+impl Value {
+    fn type_id(&self) -> u8 {
+        match self {
+            Value::Bool(_) => T_BOOL,
+            Value::Byte(_) => T_BYTE,
+            Value::Double(_) => T_DOUBLE,
+            Value::I16(_) => T_I16,
+            Value::I32(_) => T_I32,
+            Value::I64(_) => T_I64,
+            Value::String(_) => T_STRING,
+            Value::Struct(_) => T_STRUCT,
+            Value::List(_, _) => T_LIST,
+            Value::Map(_, _, _) => T_MAP,
+        }
+    }
+
+    fn as_str(&self) -> Result<&str, String> {
+        match self {
+            Value::String(v) => Ok(v),
+            _ => Err(format!("expect a string, got {:?}", self)),
+        }
+    }
+
+    fn as_list(&self) -> Result<&[Value], String> {
+        match self {
+            Value::List(_, v) => Ok(v),
+            _ => Err(format!("expect a list, got {:?}", self)),
+        }
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        match self {
+            Value::Bool(v) => out.push(*v as u8),
+            Value::Byte(v) => out.push(*v as u8),
+            Value::Double(v) => out.extend_from_slice(&v.to_bits().to_be_bytes()),
+            Value::I16(v) => out.extend_from_slice(&v.to_be_bytes()),
+            Value::I32(v) => out.extend_from_slice(&v.to_be_bytes()),
+            Value::I64(v) => out.extend_from_slice(&v.to_be_bytes()),
+            Value::String(v) => {
+                out.extend_from_slice(&(v.len() as i32).to_be_bytes());
+                out.extend_from_slice(v.as_bytes());
+            }
+            Value::Struct(fields) => {
+                for (id, field) in fields {
+                    out.push(field.type_id());
+                    out.extend_from_slice(&id.to_be_bytes());
+                    field.write(out);
+                }
+                out.push(T_STOP);
+            }
+            Value::List(elem_type, items) => {
+                out.push(*elem_type);
+                out.extend_from_slice(&(items.len() as i32).to_be_bytes());
+                for item in items {
+                    item.write(out);
+                }
+            }
+            Value::Map(key_type, value_type, entries) => {
+                out.push(*key_type);
+                out.push(*value_type);
+                out.extend_from_slice(&(entries.len() as i32).to_be_bytes());
+                for (key, value) in entries {
+                    key.write(out);
+                    value.write(out);
+                }
+            }
+        }
+    }
+}
+
+fn string(v: impl Into<String>) -> Value {
+    Value::String(v.into())
+}
+
+fn strings<'a>(values: impl IntoIterator<Item = &'a str>) -> Value {
+    Value::List(T_STRING, values.into_iter().map(string).collect())
+}
+
+fn structure(fields: impl IntoIterator<Item = (i16, Value)>) -> Value {
+    Value::Struct(fields.into_iter().collect())
+}
+
+// Decode from a buffer which may not hold a complete message yet, None means more bytes are needed.
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, n: usize) -> Option<&'a [u8]> {
+        let end = self.pos.checked_add(n)?;
+        let bytes = self.buf.get(self.pos..end)?;
+        self.pos = end;
+        Some(bytes)
+    }
+
+    fn i8(&mut self) -> Option<i8> {
+        Some(self.bytes(1)?[0] as i8)
+    }
+
+    fn i16(&mut self) -> Option<i16> {
+        Some(i16::from_be_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        Some(i32::from_be_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> Option<i64> {
+        Some(i64::from_be_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+
+    fn size(&mut self) -> Option<usize> {
+        usize::try_from(self.i32()?).ok()
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let len = self.size()?;
+        Some(String::from_utf8_lossy(self.bytes(len)?).into_owned())
+    }
+
+    fn value(&mut self, value_type: u8) -> Option<Value> {
+        let value = match value_type {
+            T_BOOL => Value::Bool(self.i8()? != 0),
+            T_BYTE => Value::Byte(self.i8()?),
+            T_DOUBLE => Value::Double(f64::from_bits(self.i64()? as u64)),
+            T_I16 => Value::I16(self.i16()?),
+            T_I32 => Value::I32(self.i32()?),
+            T_I64 => Value::I64(self.i64()?),
+            T_STRING => Value::String(self.string()?),
+            T_STRUCT => {
+                let mut fields = BTreeMap::new();
+                loop {
+                    let field_type = self.i8()? as u8;
+                    if field_type == T_STOP {
+                        break;
+                    }
+                    let id = self.i16()?;
+                    fields.insert(id, self.value(field_type)?);
+                }
+                Value::Struct(fields)
+            }
+            T_MAP => {
+                let key_type = self.i8()? as u8;
+                let value_type = self.i8()? as u8;
+                let len = self.size()?;
+                let mut entries = Vec::with_capacity(len.min(1024));
+                for _ in 0..len {
+                    entries.push((self.value(key_type)?, self.value(value_type)?));
+                }
+                Value::Map(key_type, value_type, entries)
+            }
+            T_SET | T_LIST => {
+                let elem_type = self.i8()? as u8;
+                let len = self.size()?;
+                let mut items = Vec::with_capacity(len.min(1024));
+                for _ in 0..len {
+                    items.push(self.value(elem_type)?);
+                }
+                Value::List(elem_type, items)
+            }
+            _ => panic!("unknown thrift type {}", value_type),
+        };
+        Some(value)
+    }
+}
+
+struct Message {
+    name: String,
+    seq_id: i32,
+    args: BTreeMap<i16, Value>,
+    framed: bool,
+}
+
+// Return the message and the number of bytes it takes.
+fn read_message(buf: &[u8]) -> Option<(Message, usize)> {
+    if buf.len() < 6 {
+        return None;
+    }
+    // the framed transport prefixes the message with its length
+    let framed = buf[0] & 0x80 == 0 && buf[4..6] == [0x80, 0x01];
+
+    let mut reader = Reader { buf, pos: 0 };
+    if framed {
+        reader.i32()?;
+    }
+    let version = reader.i32()? as u32;
+    assert_eq!(
+        version & 0xffff_0000,
+        MESSAGE_VERSION,
+        "only the strict binary protocol is supported"
+    );
+    let name = reader.string()?;
+    let seq_id = reader.i32()?;
+    let Value::Struct(args) = reader.value(T_STRUCT)? else {
+        unreachable!()
+    };
+    let message = Message {
+        name,
+        seq_id,
+        args,
+        framed,
+    };
+    Some((message, reader.pos))
+}
+
+fn write_message(
+    name: &str,
+    seq_id: i32,
+    message_type: u32,
+    body: &Value,
+    framed: bool,
+) -> Vec<u8> {
+    let mut out = vec![];
+    out.extend_from_slice(&((MESSAGE_VERSION | message_type) as i32).to_be_bytes());
+    string(name).write(&mut out);
+    out.extend_from_slice(&seq_id.to_be_bytes());
+    body.write(&mut out);
+    if framed {
+        let mut frame = (out.len() as i32).to_be_bytes().to_vec();
+        frame.extend(out);
+        return frame;
+    }
+    out
+}
+
+fn field_schemas(fields: &[(String, String)]) -> Value {
+    let fields = fields
+        .iter()
+        .map(|(name, data_type)| structure([(1, string(name)), (2, string(data_type))]))
+        .collect();
+    Value::List(T_STRUCT, fields)
+}
+
+fn storage_descriptor(columns: &[(String, String)], location: &str) -> Value {
+    structure([
+        (1, field_schemas(columns)),
+        (2, string(location)),
+        (3, string(PARQUET_INPUT_FORMAT)),
+    ])
+}
+
+/// A table in the `default` database of the stub.
+#[derive(Debug, Clone)]
+pub struct StubTable {
+    pub name: String,
+    // like hdfs://namenode/warehouse/t
+    pub location: String,
+    // the (name, type) of the data columns
+    pub columns: Vec<(String, String)>,
+    pub partition_keys: Vec<(String, String)>,
+    // the registered partitions, from the name like 'p=a' to the location
+    pub partitions: BTreeMap<String, String>,
+}
+
+impl StubTable {
+    fn to_thrift(&self) -> Value {
+        structure([
+            (1, string(&self.name)),
+            (2, string(DATABASE)),
+            (7, storage_descriptor(&self.columns, &self.location)),
+            (8, field_schemas(&self.partition_keys)),
+            (12, string("MANAGED_TABLE")),
+        ])
+    }
+
+    fn partition_to_thrift(&self, name: &str, location: &str) -> Value {
+        let values = name.split('/').map(|kv| kv.split_once('=').unwrap().1);
+        structure([
+            (1, strings(values)),
+            (2, string(DATABASE)),
+            (3, string(&self.name)),
+            (6, storage_descriptor(&self.columns, location)),
+        ])
+    }
+}
+
+#[derive(Default)]
+struct State {
+    tables: BTreeMap<String, StubTable>,
+    // the (table, partition) registered by append_partition_by_name
+    added_partitions: Vec<(String, String)>,
+}
+
+impl State {
+    fn table(&self, args: &BTreeMap<i16, Value>) -> Result<&StubTable, String> {
+        let name = arg(args, 2)?.as_str()?;
+        self.tables
+            .get(name)
+            .ok_or_else(|| format!("table {} is not found", name))
+    }
+
+    fn handle(&mut self, method: &str, args: &BTreeMap<i16, Value>) -> Result<Value, String> {
+        match method {
+            "get_all_databases" => Ok(strings([DATABASE])),
+            "get_database" => Ok(structure([(1, string(arg(args, 1)?.as_str()?))])),
+            "get_all_tables" => Ok(strings(self.tables.keys().map(|k| k.as_str()))),
+            "get_table" => Ok(self.table(args)?.to_thrift()),
+            "get_schema" => {
+                let table = self.table(args)?;
+                let mut fields = table.columns.clone();
+                fields.extend(table.partition_keys.iter().cloned());
+                Ok(field_schemas(&fields))
+            }
+            "get_partition_names" => {
+                let table = self.table(args)?;
+                Ok(strings(table.partitions.keys().map(|k| k.as_str())))
+            }
+            "get_partitions_by_names" => {
+                let table = self.table(args)?;
+                let mut partitions = vec![];
+                for name in arg(args, 3)?.as_list()? {
+                    let name = name.as_str()?;
+                    if let Some(location) = table.partitions.get(name) {
+                        partitions.push(table.partition_to_thrift(name, location));
+                    }
+                }
+                Ok(Value::List(T_STRUCT, partitions))
+            }
+            "append_partition_by_name" => {
+                let name = arg(args, 3)?.as_str()?.to_string();
+                let table = self.table(args)?.name.clone();
+                let table = self.tables.get_mut(&table).unwrap();
+                if table.partitions.contains_key(&name) {
+                    return Err(format!("partition {} already exists", name));
+                }
+                let location = format!("{}/{}", table.location.trim_end_matches('/'), name);
+                let partition = table.partition_to_thrift(&name, &location);
+                table.partitions.insert(name.clone(), location);
+                self.added_partitions.push((table.name.clone(), name));
+                Ok(partition)
+            }
+            _ => Err(format!("{} is not served by the metastore stub", method)),
+        }
+    }
+}
+
+fn arg(args: &BTreeMap<i16, Value>, id: i16) -> Result<&Value, String> {
+    args.get(&id)
+        .ok_or_else(|| format!("argument {} is missing", id))
+}
+
+#[derive(Clone)]
+pub struct MetastoreStub {
+    address: String,
+    state: Arc<Mutex<State>>,
+}
+
+impl MetastoreStub {
+    pub async fn start(tables: Vec<StubTable>) -> std::io::Result<MetastoreStub> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let state = State {
+            tables: tables.into_iter().map(|t| (t.name.clone(), t)).collect(),
+            added_partitions: vec![],
+        };
+        let stub = MetastoreStub {
+            address: listener.local_addr()?.to_string(),
+            state: Arc::new(Mutex::new(state)),
+        };
+
+        let server = stub.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(server.clone().serve(stream));
+            }
+        });
+        Ok(stub)
+    }
+
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    pub fn added_partitions(&self) -> Vec<(String, String)> {
+        self.state.lock().unwrap().added_partitions.clone()
+    }
+
+    pub fn partition_names(&self, table: &str) -> Vec<String> {
+        let state = self.state.lock().unwrap();
+        state.tables[table].partitions.keys().cloned().collect()
+    }
+
+    async fn serve(self, mut stream: TcpStream) {
+        let mut buf = vec![];
+        let mut chunk = [0; 4096];
+        loop {
+            while let Some((message, len)) = read_message(&buf) {
+                buf.drain(..len);
+                let reply = self.reply(message);
+                if stream.write_all(&reply).await.is_err() {
+                    return;
+                }
+            }
+            match stream.read(&mut chunk).await {
+                Ok(0) | Err(_) => return,
+                Ok(n) => buf.extend_from_slice(&chunk[..n]),
+            }
+        }
+    }
+
+    fn reply(&self, message: Message) -> Vec<u8> {
+        let res = self
+            .state
+            .lock()
+            .unwrap()
+            .handle(&message.name, &message.args);
+        let (message_type, body) = match res {
+            Ok(value) => (MESSAGE_REPLY, structure([(0, value)])),
+            Err(e) => (
+                MESSAGE_EXCEPTION,
+                structure([(1, string(e)), (2, Value::I32(INTERNAL_ERROR))]),
+            ),
+        };
+        write_message(
+            &message.name,
+            message.seq_id,
+            message_type,
+            &body,
+            message.framed,
+        )
+    }
+}
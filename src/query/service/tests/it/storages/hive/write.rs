@@ -0,0 +1,206 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_query::test_kits::TestFixture;
+use tempfile::TempDir;
+
+use crate::storages::hive::metastore_stub::MetastoreStub;
+use crate::storages::hive::metastore_stub::StubTable;
+
+// the data files directly under the directory
+fn parquet_files(dir: &Path) -> Result<Vec<String>> {
+    let mut files = vec![];
+    if !dir.exists() {
+        return Ok(files);
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let name = entry?.file_name().to_string_lossy().to_string();
+        if name.ends_with(".parquet") {
+            files.push(name);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+// the staging directories left in the table directory
+fn staging_dirs(dir: &Path) -> Result<Vec<String>> {
+    let mut dirs = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let name = entry?.file_name().to_string_lossy().to_string();
+        if name.starts_with(".databend-staging-") {
+            dirs.push(name);
+        }
+    }
+    Ok(dirs)
+}
+
+fn columns(fields: &[(&str, &str)]) -> Vec<(String, String)> {
+    fields
+        .iter()
+        .map(|(name, data_type)| (name.to_string(), data_type.to_string()))
+        .collect()
+}
+
+// Create the catalog `hive` on the stub, with the data stored in a temporary directory.
+async fn setup(tables: Vec<StubTable>) -> Result<(TestFixture, MetastoreStub, TempDir)> {
+    let root = tempfile::tempdir()?;
+    let metastore = MetastoreStub::start(tables).await?;
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command(&format!(
+            "create catalog hive type = hive connection = (metastore_address = '{}' url = 'fs://{}/')",
+            metastore.address(),
+            root.path().display()
+        ))
+        .await?;
+    Ok((fixture, metastore, root))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_hive_insert_partitions() -> Result<()> {
+    let table = StubTable {
+        name: "t".to_string(),
+        location: "hdfs://namenode/warehouse/t".to_string(),
+        columns: columns(&[("id", "int"), ("name", "string")]),
+        partition_keys: columns(&[("p", "string")]),
+        // the existing partition is out of the table directory
+        partitions: BTreeMap::from([(
+            "p=a".to_string(),
+            "hdfs://namenode/warehouse/t_a".to_string(),
+        )]),
+    };
+    let (fixture, metastore, root) = setup(vec![table]).await?;
+    let dir_a = root.path().join("warehouse/t_a");
+    let dir_b = root.path().join("warehouse/t/p=b");
+    let dir_c = root.path().join("warehouse/t/p=c");
+    std::fs::create_dir_all(&dir_a)?;
+    std::fs::write(dir_a.join("old.parquet"), b"")?;
+
+    // the existing partition is written to its location, the new one is registered.
+    fixture
+        .execute_command("insert into hive.default.t values (1, 'x', 'a'), (2, 'y', 'b')")
+        .await?;
+    assert_eq!(parquet_files(&dir_a)?.len(), 2);
+    assert_eq!(parquet_files(&dir_b)?.len(), 1);
+    assert_eq!(metastore.added_partitions(), vec![(
+        "t".to_string(),
+        "p=b".to_string()
+    )]);
+
+    // static partition, which is registered already.
+    fixture
+        .execute_command("insert into hive.default.t partition (p = 'b') values (3, 'z')")
+        .await?;
+    assert_eq!(parquet_files(&dir_b)?.len(), 2);
+
+    // dynamic partition.
+    fixture
+        .execute_command("insert into hive.default.t partition (p) values (4, 'w', 'c')")
+        .await?;
+    assert_eq!(parquet_files(&dir_c)?.len(), 1);
+    assert_eq!(metastore.added_partitions(), vec![
+        ("t".to_string(), "p=b".to_string()),
+        ("t".to_string(), "p=c".to_string()),
+    ]);
+    assert_eq!(metastore.partition_names("t"), vec!["p=a", "p=b", "p=c"]);
+
+    // the staged files are moved into place.
+    assert!(staging_dirs(&root.path().join("warehouse/t"))?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_hive_insert_overwrite() -> Result<()> {
+    let partitioned = StubTable {
+        name: "t".to_string(),
+        location: "hdfs://namenode/warehouse/t".to_string(),
+        columns: columns(&[("id", "int")]),
+        partition_keys: columns(&[("p", "string")]),
+        partitions: BTreeMap::new(),
+    };
+    let unpartitioned = StubTable {
+        name: "u".to_string(),
+        location: "hdfs://namenode/warehouse/u".to_string(),
+        columns: columns(&[("id", "int")]),
+        partition_keys: vec![],
+        partitions: BTreeMap::new(),
+    };
+    let (fixture, metastore, root) = setup(vec![partitioned, unpartitioned]).await?;
+
+    // only the written partitions are overwritten.
+    let dir_a = root.path().join("warehouse/t/p=a");
+    let dir_b = root.path().join("warehouse/t/p=b");
+    fixture
+        .execute_command("insert into hive.default.t values (1, 'a'), (2, 'b')")
+        .await?;
+    fixture
+        .execute_command("insert into hive.default.t values (3, 'a'), (4, 'b')")
+        .await?;
+    assert_eq!(parquet_files(&dir_a)?.len(), 2);
+    assert_eq!(parquet_files(&dir_b)?.len(), 2);
+
+    let old_files = parquet_files(&dir_a)?;
+    fixture
+        .execute_command("insert overwrite hive.default.t partition (p = 'a') values (5)")
+        .await?;
+    let new_files = parquet_files(&dir_a)?;
+    assert_eq!(new_files.len(), 1);
+    assert!(!old_files.contains(&new_files[0]));
+    assert_eq!(parquet_files(&dir_b)?.len(), 2);
+    assert_eq!(metastore.partition_names("t"), vec!["p=a", "p=b"]);
+
+    // the static partition is cleared even if no rows are written to it.
+    fixture
+        .execute_command(
+            "insert overwrite hive.default.t partition (p = 'b') select 1 from numbers(1) where false",
+        )
+        .await?;
+    assert!(parquet_files(&dir_b)?.is_empty());
+    assert_eq!(parquet_files(&dir_a)?, new_files);
+    assert!(staging_dirs(&root.path().join("warehouse/t"))?.is_empty());
+
+    // the whole directory of the unpartitioned table is overwritten.
+    let dir_u = root.path().join("warehouse/u");
+    fixture
+        .execute_command("insert into hive.default.u values (1)")
+        .await?;
+    fixture
+        .execute_command("insert into hive.default.u values (2)")
+        .await?;
+    let old_files = parquet_files(&dir_u)?;
+    assert_eq!(old_files.len(), 2);
+
+    fixture
+        .execute_command("insert overwrite hive.default.u values (3)")
+        .await?;
+    let new_files = parquet_files(&dir_u)?;
+    assert_eq!(new_files.len(), 1);
+    assert!(!old_files.contains(&new_files[0]));
+
+    // even if no rows are written.
+    fixture
+        .execute_command("insert overwrite hive.default.u select 1 from numbers(1) where false")
+        .await?;
+    assert!(parquet_files(&dir_u)?.is_empty());
+    assert!(staging_dirs(&dir_u)?.is_empty());
+
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod fuse;
+mod hive;
 mod null;
 mod statistics;
 mod system;
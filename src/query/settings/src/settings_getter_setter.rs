@@ -255,6 +255,10 @@ impl Settings {
         Ok(self.try_get_u64("quoted_ident_case_sensitive")? != 0)
     }
 
+    pub fn get_max_cte_recursive_depth(&self) -> Result<u64> {
+        self.try_get_u64("max_cte_recursive_depth")
+    }
+
     pub fn get_max_result_rows(&self) -> Result<u64> {
         self.try_get_u64("max_result_rows")
     }
@@ -360,6 +364,14 @@ impl Settings {
         Ok(self.try_get_u64("sort_spilling_memory_ratio")? as usize)
     }
 
+    pub fn get_window_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
+        Ok(self.try_get_u64("window_spilling_bytes_threshold_per_proc")? as usize)
+    }
+
+    pub fn get_window_spilling_memory_ratio(&self) -> Result<usize> {
+        Ok(self.try_get_u64("window_spilling_memory_ratio")? as usize)
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
@@ -447,6 +459,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_aggregating_index_scan")? != 0)
     }
 
+    pub fn get_enable_materialized_view_rewrite(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_materialized_view_rewrite")? != 0)
+    }
+
     pub fn get_enable_compact_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_compact_after_write")? != 0)
     }
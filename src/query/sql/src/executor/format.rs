@@ -29,6 +29,7 @@ use crate::executor::physical_plans::AggregateExpand;
 use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregateFunctionDesc;
 use crate::executor::physical_plans::AggregatePartial;
+use crate::executor::physical_plans::AsyncFunction;
 use crate::executor::physical_plans::CommitSink;
 use crate::executor::physical_plans::ConstantTableScan;
 use crate::executor::physical_plans::CopyIntoTable;
@@ -48,6 +49,8 @@ use crate::executor::physical_plans::ProjectSet;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::RangeJoinType;
 use crate::executor::physical_plans::ReclusterSink;
+use crate::executor::physical_plans::RecursiveCte;
+use crate::executor::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plans::RowFetch;
 use crate::executor::physical_plans::Sort;
 use crate::executor::physical_plans::TableScan;
@@ -214,6 +217,7 @@ fn to_format_tree(
         PhysicalPlan::CommitSink(plan) => commit_sink_to_format_tree(plan, metadata, profs),
         PhysicalPlan::ProjectSet(plan) => project_set_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Udf(plan) => udf_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::AsyncFunction(plan) => async_function_to_format_tree(plan, metadata, profs),
         PhysicalPlan::RangeJoin(plan) => range_join_to_format_tree(plan, metadata, profs),
         PhysicalPlan::CopyIntoTable(plan) => copy_into_table(plan),
         PhysicalPlan::ReplaceAsyncSourcer(_) => {
@@ -236,6 +240,8 @@ fn to_format_tree(
             materialized_cte_to_format_tree(plan, metadata, profs)
         }
         PhysicalPlan::ConstantTableScan(plan) => constant_table_scan_to_format_tree(plan, metadata),
+        PhysicalPlan::RecursiveCte(plan) => recursive_cte_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::RecursiveCteScan(plan) => recursive_cte_scan_to_format_tree(plan, metadata),
     }
 }
 
@@ -344,6 +350,19 @@ fn table_scan_to_format_tree(
         }
     };
     children.push(FormatTreeNode::new(push_downs));
+    // Sample
+    if let Some(sample) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.sample.as_ref())
+    {
+        children.push(FormatTreeNode::new(format!(
+            "sample: [level: {}, probability: {}]",
+            if sample.block_level { "block" } else { "row" },
+            sample.probability
+        )));
+    }
     // Aggregating index
     if let Some(agg_index) = agg_index {
         let (_, agg_index_sql, _) = metadata
@@ -401,6 +420,26 @@ fn cte_scan_to_format_tree(plan: &CteScan) -> Result<FormatTreeNode<String>> {
     ))
 }
 
+fn recursive_cte_scan_to_format_tree(
+    plan: &RecursiveCteScan,
+    metadata: &Metadata,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("recursive cte id: {}", plan.r_cte_id)),
+    ];
+    let items = plan_stats_info_to_format_tree(&plan.stat);
+    children.extend(items);
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCteScan".to_string(),
+        children,
+    ))
+}
+
 fn constant_table_scan_to_format_tree(
     plan: &ConstantTableScan,
     metadata: &Metadata,
@@ -871,6 +910,24 @@ fn range_join_to_format_tree(
         FormatTreeNode::new(format!("other conditions: [{other_conditions}]")),
     ];
 
+    if !plan.equi_conditions.is_empty() {
+        let equi_conditions = plan
+            .equi_conditions
+            .iter()
+            .map(|(left, right)| {
+                format!(
+                    "{} = {}",
+                    left.as_expr(&BUILTIN_FUNCTIONS).sql_display(),
+                    right.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        children.push(FormatTreeNode::new(format!(
+            "equi conditions: [{equi_conditions}]"
+        )));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
@@ -885,6 +942,7 @@ fn range_join_to_format_tree(
         match plan.range_join_type {
             RangeJoinType::IEJoin => "IEJoin".to_string(),
             RangeJoinType::Merge => "MergeJoin".to_string(),
+            RangeJoinType::Asof => "AsofJoin".to_string(),
         },
         children,
     ))
@@ -1002,6 +1060,38 @@ fn union_all_to_format_tree(
     ))
 }
 
+fn recursive_cte_to_format_tree(
+    plan: &RecursiveCte,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("cte name: {}", plan.cte_name)),
+        FormatTreeNode::new(format!("distinct: {}", plan.distinct)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.extend(vec![
+        to_format_tree(&plan.left, metadata, profs)?,
+        to_format_tree(&plan.right, metadata, profs)?,
+    ]);
+
+    Ok(FormatTreeNode::with_children(
+        format!("RecursiveCte(id: {})", plan.plan_id),
+        children,
+    ))
+}
+
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
     let mut items = vec![
         FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
@@ -1180,6 +1270,40 @@ fn udf_to_format_tree(
     Ok(FormatTreeNode::with_children("Udf".to_string(), children))
 }
 
+fn async_function_to_format_tree(
+    plan: &AsyncFunction,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "output columns: [{}]",
+        format_output_columns(plan.output_schema()?, metadata, true)
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.extend(vec![FormatTreeNode::new(format!(
+        "async functions: {}",
+        plan.async_func_descs
+            .iter()
+            .map(|func| func.display_name.clone())
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))]);
+
+    children.extend(vec![to_format_tree(&plan.input, metadata, profs)?]);
+
+    Ok(FormatTreeNode::with_children(
+        "AsyncFunction".to_string(),
+        children,
+    ))
+}
+
 fn materialized_cte_to_format_tree(
     plan: &MaterializedCte,
     metadata: &Metadata,
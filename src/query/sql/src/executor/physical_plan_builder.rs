@@ -38,6 +38,8 @@ pub struct PhysicalPlanBuilder {
     pub(crate) dry_run: bool,
     // Record cte_idx and the cte's output columns
     pub(crate) cte_output_columns: HashMap<IndexType, Vec<ColumnBinding>>,
+    // Record the name of recursive cte which is being built and its plan id
+    pub(crate) r_cte_ids: HashMap<String, u32>,
 }
 
 impl PhysicalPlanBuilder {
@@ -50,6 +52,7 @@ impl PhysicalPlanBuilder {
             func_ctx,
             dry_run,
             cte_output_columns: Default::default(),
+            r_cte_ids: Default::default(),
         }
     }
 
@@ -107,11 +110,21 @@ impl PhysicalPlanBuilder {
             RelOperator::MaterializedCte(cte) => {
                 self.build_materialized_cte(s_expr, cte, required).await
             }
+            RelOperator::RecursiveCte(r_cte) => {
+                self.build_recursive_cte(s_expr, r_cte, stat_info).await
+            }
+            RelOperator::RecursiveCteScan(r_cte_scan) => {
+                self.build_recursive_cte_scan(r_cte_scan, required).await
+            }
             RelOperator::ConstantTableScan(scan) => {
                 self.build_constant_table_scan(scan, required).await
             }
             RelOperator::AddRowNumber(_) => self.build_add_row_number(s_expr, required).await,
             RelOperator::Udf(udf) => self.build_udf(s_expr, udf, required, stat_info).await,
+            RelOperator::AsyncFunction(async_func) => {
+                self.build_async_function(s_expr, async_func, required, stat_info)
+                    .await
+            }
             _ => Err(ErrorCode::Internal(format!(
                 "Unsupported physical plan: {:?}",
                 s_expr.plan()
@@ -18,6 +18,7 @@ use crate::executor::physical_plan::PhysicalPlan;
 use crate::executor::physical_plans::AggregateExpand;
 use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregatePartial;
+use crate::executor::physical_plans::AsyncFunction;
 use crate::executor::physical_plans::CommitSink;
 use crate::executor::physical_plans::CompactSource;
 use crate::executor::physical_plans::ConstantTableScan;
@@ -44,6 +45,8 @@ use crate::executor::physical_plans::QuerySource;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::ReclusterSink;
 use crate::executor::physical_plans::ReclusterSource;
+use crate::executor::physical_plans::RecursiveCte;
+use crate::executor::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plans::ReplaceAsyncSourcer;
 use crate::executor::physical_plans::ReplaceDeduplicate;
 use crate::executor::physical_plans::ReplaceInto;
@@ -97,6 +100,9 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::ReclusterSink(plan) => self.replace_recluster_sink(plan),
             PhysicalPlan::UpdateSource(plan) => self.replace_update_source(plan),
             PhysicalPlan::Udf(plan) => self.replace_udf(plan),
+            PhysicalPlan::AsyncFunction(plan) => self.replace_async_function(plan),
+            PhysicalPlan::RecursiveCte(plan) => self.replace_recursive_cte(plan),
+            PhysicalPlan::RecursiveCteScan(plan) => self.replace_recursive_cte_scan(plan),
         }
     }
 
@@ -120,6 +126,10 @@ pub trait PhysicalPlanReplacer {
         Ok(PhysicalPlan::CteScan(plan.clone()))
     }
 
+    fn replace_recursive_cte_scan(&mut self, plan: &RecursiveCteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::RecursiveCteScan(plan.clone()))
+    }
+
     fn replace_constant_table_scan(&mut self, plan: &ConstantTableScan) -> Result<PhysicalPlan> {
         Ok(PhysicalPlan::ConstantTableScan(plan.clone()))
     }
@@ -262,6 +272,7 @@ pub trait PhysicalPlanReplacer {
             right: Box::new(right),
             conditions: plan.conditions.clone(),
             other_conditions: plan.other_conditions.clone(),
+            equi_conditions: plan.equi_conditions.clone(),
             join_type: plan.join_type.clone(),
             range_join_type: plan.range_join_type.clone(),
             stat_info: plan.stat_info.clone(),
@@ -357,6 +368,16 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_recursive_cte(&mut self, plan: &RecursiveCte) -> Result<PhysicalPlan> {
+        let left = self.replace(&plan.left)?;
+        let right = self.replace(&plan.right)?;
+        Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+            left: Box::new(left),
+            right: Box::new(right),
+            ..plan.clone()
+        }))
+    }
+
     fn replace_copy_into_table(&mut self, plan: &CopyIntoTable) -> Result<PhysicalPlan> {
         match &plan.source {
             CopyIntoTableSource::Stage(_) => {
@@ -493,6 +514,16 @@ pub trait PhysicalPlanReplacer {
             stat_info: plan.stat_info.clone(),
         }))
     }
+
+    fn replace_async_function(&mut self, plan: &AsyncFunction) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::AsyncFunction(AsyncFunction {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            async_func_descs: plan.async_func_descs.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
 }
 
 impl PhysicalPlan {
@@ -508,6 +539,7 @@ impl PhysicalPlan {
                 PhysicalPlan::TableScan(_)
                 | PhysicalPlan::ReplaceAsyncSourcer(_)
                 | PhysicalPlan::CteScan(_)
+                | PhysicalPlan::RecursiveCteScan(_)
                 | PhysicalPlan::ConstantTableScan(_)
                 | PhysicalPlan::ReclusterSource(_)
                 | PhysicalPlan::ExchangeSource(_)
@@ -605,6 +637,13 @@ impl PhysicalPlan {
                 PhysicalPlan::Udf(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::AsyncFunction(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::RecursiveCte(plan) => {
+                    Self::traverse(&plan.left, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.right, pre_visit, visit, post_visit);
+                }
             }
             post_visit(plan);
         }
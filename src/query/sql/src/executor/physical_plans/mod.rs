@@ -20,6 +20,9 @@ mod physical_aggregate_final;
 pub use physical_aggregate_final::AggregateFinal;
 mod physical_aggregate_partial;
 pub use physical_aggregate_partial::AggregatePartial;
+mod physical_async_function;
+pub use physical_async_function::AsyncFunction;
+pub use physical_async_function::AsyncFunctionDesc;
 mod physical_commit_sink;
 pub use physical_commit_sink::CommitSink;
 mod physical_compact_source;
@@ -65,7 +68,11 @@ pub use physical_project_set::ProjectSet;
 mod physical_range_join;
 pub use physical_range_join::*;
 mod physical_recluster_sink;
+mod physical_recursive_cte;
+pub use physical_recursive_cte::RecursiveCte;
+mod physical_recursive_cte_scan;
 pub use physical_recluster_sink::ReclusterSink;
+pub use physical_recursive_cte_scan::RecursiveCteScan;
 mod physical_recluster_source;
 pub use physical_recluster_source::*;
 mod physical_refresh_index;
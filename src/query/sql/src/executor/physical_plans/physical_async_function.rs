@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::SExpr;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::ScalarExpr;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AsyncFunction {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub async_func_descs: Vec<AsyncFunctionDesc>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl AsyncFunction {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        for async_func_desc in self.async_func_descs.iter() {
+            let name = async_func_desc.output_column.to_string();
+            let data_type = async_func_desc.data_type.clone();
+            fields.push(DataField::new(&name, *data_type));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AsyncFunctionDesc {
+    pub func_name: String,
+    pub display_name: String,
+    pub output_column: IndexType,
+    pub arguments: Vec<String>,
+    pub data_type: Box<DataType>,
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_async_function(
+        &mut self,
+        s_expr: &SExpr,
+        async_func: &crate::plans::AsyncFunction,
+        required: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. Prune unused Columns.
+        let used = async_func
+            .items
+            .iter()
+            .filter(|item| required.contains(&item.index))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // 2. Build physical plan.
+        if used.is_empty() {
+            return self.build(s_expr.child(0)?, required).await;
+        }
+        let input = self.build(s_expr.child(0)?, required).await?;
+        let async_func_descs = used
+            .iter()
+            .map(|item| {
+                if let ScalarExpr::AsyncFunctionCall(func) = &item.scalar {
+                    Ok(AsyncFunctionDesc {
+                        func_name: func.func_name.clone(),
+                        display_name: func.display_name.clone(),
+                        output_column: item.index,
+                        arguments: func.arguments.clone(),
+                        data_type: func.return_type.clone(),
+                    })
+                } else {
+                    Err(ErrorCode::Internal("Expected async function".to_string()))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(PhysicalPlan::AsyncFunction(AsyncFunction {
+            plan_id: self.next_plan_id(),
+            input: Box::new(input),
+            async_func_descs,
+            stat_info: Some(stat_info),
+        }))
+    }
+}
@@ -413,6 +413,8 @@ impl PhysicalPlanBuilder {
                 ));
                 probe_fields
             }
+            // Asof joins are executed by range join
+            JoinType::Asof | JoinType::LeftAsof => unreachable!(),
         };
         let mut projections = ColumnSet::new();
         let projected_schema = DataSchemaRefExt::create(merged_fields.clone());
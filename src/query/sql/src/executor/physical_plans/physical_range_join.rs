@@ -15,6 +15,7 @@
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::type_check::common_super_type;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::RemoteExpr;
@@ -29,6 +30,7 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
 use crate::optimizer::SExpr;
+use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::ScalarExpr;
 use crate::TypeCheck;
@@ -44,7 +46,9 @@ pub struct RangeJoin {
     pub conditions: Vec<RangeJoinCondition>,
     // The other conditions
     pub other_conditions: Vec<RemoteExpr>,
-    // Now only support inner join, will support left/right join later
+    // The equi conditions of asof join, the nearest match is searched in the rows with equal keys
+    pub equi_conditions: Vec<(RemoteExpr, RemoteExpr)>,
+    // Now only support inner join and asof join, will support left/right join later
     pub join_type: JoinType,
     pub range_join_type: RangeJoinType,
 
@@ -55,7 +59,17 @@ pub struct RangeJoin {
 impl RangeJoin {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         let mut fields = self.left.output_schema()?.fields().clone();
-        fields.extend(self.right.output_schema()?.fields().clone());
+        if self.join_type == JoinType::LeftAsof {
+            fields.extend(
+                self.right
+                    .output_schema()?
+                    .fields()
+                    .iter()
+                    .map(|field| DataField::new(field.name(), field.data_type().wrap_nullable())),
+            );
+        } else {
+            fields.extend(self.right.output_schema()?.fields().clone());
+        }
         Ok(DataSchemaRefExt::create(fields))
     }
 }
@@ -64,6 +78,8 @@ impl RangeJoin {
 pub enum RangeJoinType {
     IEJoin,
     Merge,
+    // Find the nearest match of each left row by binary search in the sorted right table
+    Asof,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -135,11 +151,90 @@ impl PhysicalPlanBuilder {
                 .iter()
                 .map(|scalar| resolve_scalar(scalar, &merged_schema))
                 .collect::<Result<_>>()?,
+            equi_conditions: vec![],
             join_type: JoinType::Inner,
             range_join_type,
             stat_info: Some(self.build_plan_stat_info(s_expr)?),
         }))
     }
+
+    pub async fn build_asof_join(
+        &mut self,
+        s_expr: &SExpr,
+        join: &Join,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+    ) -> Result<PhysicalPlan> {
+        // Unlike other range joins, the left side of asof join is always the left child,
+        // whose rows are kept to find the nearest match in the right child.
+        let left_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+        let right_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
+
+        // The match condition is the only non-equi condition of asof join, see `Binder::bind_join`
+        debug_assert_eq!(join.non_equi_conditions.len(), 1);
+
+        let left_side = self.build(s_expr.child(0)?, left_required).await?;
+        let right_side = self.build(s_expr.child(1)?, right_required).await?;
+
+        let left_schema = left_side.output_schema()?;
+        let right_schema = right_side.output_schema()?;
+
+        Ok(PhysicalPlan::RangeJoin(RangeJoin {
+            plan_id: self.next_plan_id(),
+            left: Box::new(left_side),
+            right: Box::new(right_side),
+            conditions: vec![resolve_range_condition(
+                &join.non_equi_conditions[0],
+                &left_schema,
+                &right_schema,
+                &left_prop,
+                &right_prop,
+            )?],
+            other_conditions: vec![],
+            equi_conditions: join
+                .left_conditions
+                .iter()
+                .zip(join.right_conditions.iter())
+                .map(|(left, right)| {
+                    resolve_equi_condition(left, right, &left_schema, &right_schema)
+                })
+                .collect::<Result<_>>()?,
+            join_type: join.join_type.clone(),
+            range_join_type: RangeJoinType::Asof,
+            stat_info: Some(self.build_plan_stat_info(s_expr)?),
+        }))
+    }
+}
+
+fn resolve_equi_condition(
+    left: &ScalarExpr,
+    right: &ScalarExpr,
+    left_schema: &DataSchemaRef,
+    right_schema: &DataSchemaRef,
+) -> Result<(RemoteExpr, RemoteExpr)> {
+    let mut left = left.clone();
+    let mut right = right.clone();
+    // The keys are compared directly, so cast them to the common type
+    let left_data_type = left.data_type()?;
+    let right_data_type = right.data_type()?;
+    if left_data_type.ne(&right_data_type) {
+        let common_type = common_super_type(
+            left_data_type.clone(),
+            right_data_type.clone(),
+            &BUILTIN_FUNCTIONS.default_cast_rules,
+        )
+        .ok_or_else(|| {
+            ErrorCode::IllegalDataType(format!(
+                "Cannot find common type for {left_data_type} and {right_data_type}"
+            ))
+        })?;
+        left = wrap_cast(&left, &common_type);
+        right = wrap_cast(&right, &common_type);
+    }
+    Ok((
+        resolve_scalar(&left, left_schema)?,
+        resolve_scalar(&right, right_schema)?,
+    ))
 }
 
 fn resolve_range_condition(
@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::SExpr;
+use crate::ColumnSet;
+
+/// `RecursiveCte` evaluates the anchor plan once, then evaluates the recursive plan
+/// repeatedly with the rows produced by the previous iteration until no rows are produced.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCte {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub left: Box<PhysicalPlan>,
+    pub right: Box<PhysicalPlan>,
+    pub cte_name: String,
+    pub distinct: bool,
+    pub pairs: Vec<(String, String)>,
+    pub schema: DataSchemaRef,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_recursive_cte(
+        &mut self,
+        s_expr: &SExpr,
+        r_cte: &crate::plans::RecursiveCte,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. Prune unused Columns.
+        // The working table is fed back to the recursive term, so all the columns are required.
+        let left_required: ColumnSet = r_cte.pairs.iter().map(|(l, _)| *l).collect();
+        let right_required: ColumnSet = r_cte.pairs.iter().map(|(_, r)| *r).collect();
+
+        // 2. Build physical plan.
+        // Allocate the plan id before building the children, so `RecursiveCteScan` can refer to it.
+        let plan_id = self.next_plan_id();
+        let prev_id = self.r_cte_ids.insert(r_cte.cte_name.clone(), plan_id);
+        let left_plan = self.build(s_expr.child(0)?, left_required).await?;
+        let right_plan = self.build(s_expr.child(1)?, right_required).await;
+        match prev_id {
+            Some(prev_id) => self.r_cte_ids.insert(r_cte.cte_name.clone(), prev_id),
+            None => self.r_cte_ids.remove(&r_cte.cte_name),
+        };
+        let right_plan = right_plan?;
+
+        let left_schema = left_plan.output_schema()?;
+        let fields = r_cte
+            .pairs
+            .iter()
+            .map(|(l, _)| {
+                let field = left_schema.field_with_name(&l.to_string())?;
+                Ok(DataField::new(&l.to_string(), field.data_type().clone()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let pairs = r_cte
+            .pairs
+            .iter()
+            .map(|(l, r)| (l.to_string(), r.to_string()))
+            .collect::<Vec<_>>();
+
+        Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+            plan_id,
+            left: Box::new(left_plan),
+            right: Box::new(right_plan),
+            cte_name: r_cte.cte_name.clone(),
+            distinct: r_cte.distinct,
+            pairs,
+            schema: DataSchemaRefExt::create(fields),
+
+            stat_info: Some(stat_info),
+        }))
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::ColumnSet;
+use crate::IndexType;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCteScan {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    // The plan id of the `RecursiveCte` which produces the working table
+    pub r_cte_id: u32,
+    pub output_schema: DataSchemaRef,
+    pub offsets: Vec<IndexType>,
+    pub stat: PlanStatsInfo,
+}
+
+impl RecursiveCteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.output_schema.clone())
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_recursive_cte_scan(
+        &mut self,
+        r_cte_scan: &crate::plans::RecursiveCteScan,
+        required: ColumnSet,
+    ) -> Result<PhysicalPlan> {
+        let r_cte_id = *self.r_cte_ids.get(&r_cte_scan.cte_name).ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "Cannot find recursive cte '{}' for RecursiveCteScan",
+                r_cte_scan.cte_name
+            ))
+        })?;
+
+        // 1. Prune unused Columns.
+        let mut pruned_fields = vec![];
+        let mut pruned_offsets = vec![];
+        for (offset, field) in r_cte_scan.fields.iter().enumerate() {
+            if required.contains(&field.name().parse()?) {
+                pruned_fields.push(field.clone());
+                pruned_offsets.push(offset);
+            }
+        }
+
+        let plan_stat = PlanStatsInfo {
+            estimated_rows: r_cte_scan.stat.cardinality,
+        };
+
+        // 2. Build physical plan.
+        Ok(PhysicalPlan::RecursiveCteScan(RecursiveCteScan {
+            plan_id: self.next_plan_id(),
+            r_cte_id,
+            output_schema: DataSchemaRefExt::create(pruned_fields),
+            offsets: pruned_offsets,
+            stat: plan_stat,
+        }))
+    }
+}
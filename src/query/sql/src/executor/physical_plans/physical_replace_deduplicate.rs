@@ -40,6 +40,8 @@ pub struct ReplaceDeduplicate {
     pub table_level_range_index: HashMap<ColumnId, ColumnStatistics>,
     pub need_insert: bool,
     pub delete_when: Option<(RemoteExpr, String)>,
+    /// Raise an error instead of replacing the conflicting rows, used by the enforced primary key.
+    pub error_on_conflict: bool,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -33,4 +33,6 @@ pub struct ReplaceInto {
     pub segments: Vec<(usize, Location)>,
     pub block_slots: Option<BlockSlotDescription>,
     pub need_insert: bool,
+    /// Raise an error instead of replacing the conflicting rows, used by the enforced primary key.
+    pub error_on_conflict: bool,
 }
@@ -485,6 +485,7 @@ impl PhysicalPlanBuilder {
             lazy_materialization: !metadata.lazy_columns().is_empty(),
             agg_index: None,
             change_type: scan.change_type.clone(),
+            sample: scan.sample.clone(),
         })
     }
 
@@ -151,6 +151,8 @@ pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub query: Query,
     pub materialized: bool,
+    // If the cte is declared in `WITH RECURSIVE`
+    pub recursive: bool,
     pub cte_idx: IndexType,
     // Record how many times this cte is used
     pub used_count: usize,
@@ -160,6 +162,16 @@ pub struct CteInfo {
     pub columns: Vec<ColumnBinding>,
 }
 
+/// The working table of a recursive cte which is being bound.
+#[derive(Clone, Debug)]
+pub struct RecursiveCteInfo {
+    // Columns of the working table, they are derived from the anchor term
+    pub columns: Vec<ColumnBinding>,
+    pub stat_info: Arc<StatInfo>,
+    // If the working table has been referenced by the recursive term
+    pub referenced: bool,
+}
+
 impl BindContext {
     pub fn new() -> Self {
         Self {
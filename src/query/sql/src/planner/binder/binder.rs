@@ -41,6 +41,7 @@ use super::Finder;
 use crate::binder::wrap_cast;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
+use crate::binder::RecursiveCteInfo;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::plans::CreateFileFormatPlan;
@@ -94,6 +95,8 @@ pub struct Binder {
     /// Use `IndexMap` because need to keep the insertion order
     /// Then wrap materialized ctes to main plan.
     pub ctes_map: Box<IndexMap<String, CteInfo>>,
+    // Save the working tables of recursive ctes which are being bound, the key is cte name
+    pub r_cte_infos: HashMap<String, RecursiveCteInfo>,
 }
 
 impl<'a> Binder {
@@ -114,6 +117,7 @@ impl<'a> Binder {
             eq_scalars: vec![],
             m_cte_bound_s_expr: Default::default(),
             ctes_map: Box::default(),
+            r_cte_infos: Default::default(),
         }
     }
 
@@ -191,6 +195,12 @@ impl<'a> Binder {
     ) -> Result<Plan> {
         let plan = match stmt {
             Statement::Query(query) => {
+                let rewritten = if self.ctx.get_settings().get_enable_materialized_view_rewrite()? {
+                    self.rewrite_by_materialized_view(query).await?
+                } else {
+                    None
+                };
+                let query = rewritten.as_ref().unwrap_or(query);
                 let (mut s_expr, bind_context) = self.bind_query(bind_context, query).await?;
                 // Wrap `LogicalMaterializedCte` to `s_expr`
                 for (_, cte_info) in self.ctes_map.iter().rev() {
@@ -318,6 +328,11 @@ impl<'a> Binder {
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
 
+            // Materialized views
+            Statement::CreateMaterializedView(stmt) => self.bind_create_materialized_view(stmt).await?,
+            Statement::DropMaterializedView(stmt) => self.bind_drop_materialized_view(stmt).await?,
+            Statement::RefreshMaterializedView(stmt) => self.bind_refresh_materialized_view(stmt).await?,
+
             // Indexes
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
@@ -602,18 +617,23 @@ impl<'a> Binder {
             Statement::ShowStreams(stmt) => self.bind_show_streams(bind_context, stmt).await?,
             Statement::DescribeStream(stmt) => self.bind_describe_stream(bind_context, stmt).await?,
 
-            Statement::CreatePipe(_) => {
-                todo!()
-            }
-            Statement::DescribePipe(_) => {
-                todo!()
-            }
-            Statement::AlterPipe(_) => {
-                todo!()
-            }
-            Statement::DropPipe(_) => {
-                todo!()
-            }
+            Statement::CreatePipe(stmt) => self.bind_create_pipe(stmt).await?,
+            Statement::DescribePipe(stmt) => self.bind_desc_pipe(stmt).await?,
+            Statement::AlterPipe(stmt) => self.bind_alter_pipe(stmt).await?,
+            Statement::DropPipe(stmt) => self.bind_drop_pipe(stmt).await?,
+
+            // Sequences
+            Statement::CreateSequence(stmt) => self.bind_create_sequence(stmt).await?,
+            Statement::DropSequence(stmt) => self.bind_drop_sequence(stmt).await?,
+
+            // Procedures
+            Statement::CreateProcedure(stmt) => self.bind_create_procedure(stmt).await?,
+            Statement::DropProcedure(stmt) => self.bind_drop_procedure(stmt).await?,
+            Statement::ExecuteImmediate(stmt) => self.bind_execute_immediate(stmt).await?,
+
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Abort => Plan::Abort,
         };
         Ok(plan)
     }
@@ -688,6 +708,7 @@ impl<'a> Binder {
                     | ScalarExpr::AggregateFunction(_)
                     | ScalarExpr::UDFServerCall(_)
                     | ScalarExpr::SubqueryExpr(_)
+                    | ScalarExpr::AsyncFunctionCall(_)
             )
         };
         let mut finder = Finder::new(&f);
@@ -810,6 +831,7 @@ impl<'a> Binder {
                 ScalarExpr::WindowFunction(_)
                     | ScalarExpr::AggregateFunction(_)
                     | ScalarExpr::UDFServerCall(_)
+                    | ScalarExpr::AsyncFunctionCall(_)
             )
         };
         let mut finder = Finder::new(&f);
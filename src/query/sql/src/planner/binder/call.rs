@@ -15,19 +15,28 @@
 use databend_common_ast::ast::CallStmt;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_users::UserApiProvider;
 
 use crate::binder::Binder;
+use crate::plans::CallProcedurePlan;
 use crate::plans::Plan;
 use crate::BindContext;
 
 impl Binder {
-    /// Rewrite call stmt to table functions
+    /// Call the stored procedure with the name, or rewrite call stmt to table functions
+    /// if there is no such procedure.
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_call(
         &mut self,
         bind_context: &mut BindContext,
         stmt: &CallStmt,
     ) -> Result<Plan> {
+        if !stmt.name.contains('$') {
+            if let Some(plan) = self.bind_call_procedure(stmt).await? {
+                return Ok(plan);
+            }
+        }
+
         let table_function_name = stmt.name.split('$').last().unwrap();
 
         let query = if table_function_name.eq_ignore_ascii_case("search_tables") {
@@ -56,4 +65,30 @@ impl Binder {
         self.bind_rewrite_to_query(bind_context, &query, crate::plans::RewriteKind::Call)
             .await
     }
+
+    #[async_backtrace::framed]
+    async fn bind_call_procedure(&mut self, stmt: &CallStmt) -> Result<Option<Plan>> {
+        let procedure = match UserApiProvider::instance()
+            .get_procedure(&self.ctx.get_tenant(), &stmt.name.to_lowercase())
+            .await
+        {
+            Ok(procedure) => procedure,
+            Err(e) if e.code() == ErrorCode::UNKNOWN_PROCEDURE => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        if stmt.args.len() != procedure.arg_names.len() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Incorrect number of arguments to procedure {}. Expected {}, got {}",
+                procedure.name,
+                procedure.arg_names.len(),
+                stmt.args.len()
+            )));
+        }
+
+        Ok(Some(Plan::CallProcedure(Box::new(CallProcedurePlan {
+            procedure,
+            args: stmt.args.clone(),
+        }))))
+    }
 }
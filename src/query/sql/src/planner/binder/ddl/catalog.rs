@@ -34,6 +34,7 @@ use databend_common_meta_app::schema::CatalogOption;
 use databend_common_meta_app::schema::CatalogType;
 use databend_common_meta_app::schema::HiveCatalogOption;
 use databend_common_meta_app::schema::IcebergCatalogOption;
+use databend_common_meta_app::schema::IcebergRestCatalogOption;
 use databend_common_meta_app::storage::StorageParams;
 
 use crate::binder::parse_storage_params_from_uri;
@@ -160,7 +161,12 @@ impl Binder {
                 })
             }
             CatalogType::Iceberg => {
-                let sp = parse_catalog_url(ctx,options.clone()).await?.ok_or_else(|| {
+                let mut options = options.clone();
+
+                // Remove REST catalog options to avoid unexpected field error in uri location.
+                let rest_catalog = parse_iceberg_rest_catalog(&mut options)?;
+
+                let sp = parse_catalog_url(ctx, options).await?.ok_or_else(|| {
                     ErrorCode::InvalidArgument(
                         "expect storage connection but failed to find, seems the url is missing",
                     )
@@ -168,6 +174,7 @@ impl Binder {
 
                 let opt = IcebergCatalogOption {
                     storage_params: Box::new(sp),
+                    rest_catalog,
                 };
                 CatalogOption::Iceberg(opt)
             }
@@ -180,6 +187,41 @@ impl Binder {
     }
 }
 
+/// Parse the options of an iceberg REST catalog, which is enabled by `REST_URI`.
+///
+/// The storage connection is still required to read the data files of the tables.
+fn parse_iceberg_rest_catalog(
+    options: &mut BTreeMap<String, String>,
+) -> Result<Option<IcebergRestCatalogOption>> {
+    let uri = options.remove("rest_uri");
+    let warehouse = options.remove("rest_warehouse");
+    let prefix = options.remove("rest_prefix");
+    let token = options.remove("rest_token");
+    let credential = options.remove("rest_credential");
+
+    let Some(uri) = uri else {
+        if warehouse.is_some() || prefix.is_some() || token.is_some() || credential.is_some() {
+            return Err(ErrorCode::InvalidArgument(
+                "expected field: REST_URI for iceberg REST catalog",
+            ));
+        }
+        return Ok(None);
+    };
+    if token.is_some() && credential.is_some() {
+        return Err(ErrorCode::InvalidArgument(
+            "REST_TOKEN and REST_CREDENTIAL cannot be both specified",
+        ));
+    }
+
+    Ok(Some(IcebergRestCatalogOption {
+        uri,
+        warehouse,
+        prefix,
+        token,
+        credential,
+    }))
+}
+
 async fn parse_catalog_url(
     ctx: &Arc<dyn TableContext>,
     options: BTreeMap<String, String>,
@@ -21,7 +21,10 @@ mod database;
 mod index;
 mod network_policy;
 mod password_policy;
+mod pipe;
+mod procedure;
 mod role;
+mod sequence;
 mod share;
 mod stage;
 mod stream;
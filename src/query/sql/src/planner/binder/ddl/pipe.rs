@@ -0,0 +1,151 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_ast::ast::*;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::binder::resolve_stage_location;
+use crate::binder::Binder;
+use crate::plans::pipe_stage_location;
+use crate::plans::AlterPipePlan;
+use crate::plans::CreatePipePlan;
+use crate::plans::DescPipePlan;
+use crate::plans::DropPipePlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_pipe(
+        &mut self,
+        stmt: &CreatePipeStmt,
+    ) -> Result<Plan> {
+        let CreatePipeStmt {
+            if_not_exists,
+            name,
+            auto_ingest,
+            comments,
+            copy_stmt,
+        } = stmt;
+
+        // The pipe loads the new files of the stage every time it runs, the copied files
+        // are skipped by the load history of the table, so the files must not be fixed
+        // and the load history must not be ignored.
+        if copy_stmt.files.is_some() {
+            return Err(ErrorCode::IllegalPipe(
+                "COPY INTO statement of pipe can not specify FILES",
+            ));
+        }
+        if copy_stmt.force {
+            return Err(ErrorCode::IllegalPipe(
+                "COPY INTO statement of pipe can not specify FORCE = true",
+            ));
+        }
+        if !copy_stmt.validation_mode.is_empty() {
+            return Err(ErrorCode::IllegalPipe(
+                "COPY INTO statement of pipe can not specify VALIDATION_MODE",
+            ));
+        }
+
+        let mut copy_stmt = copy_stmt.clone();
+        let location = pipe_stage_location(&mut copy_stmt).ok_or_else(|| {
+            ErrorCode::IllegalPipe("COPY INTO statement of pipe must load from a stage")
+        })?;
+        resolve_stage_location(self.ctx.as_ref(), location).await?;
+
+        let (catalog, database, table) = self.normalize_object_identifier_triple(
+            &copy_stmt.dst.catalog,
+            &copy_stmt.dst.database,
+            &copy_stmt.dst.table,
+        );
+        self.ctx.get_table(&catalog, &database, &table).await?;
+
+        // Qualify the target table, so that the pipe does not depend on the current database.
+        copy_stmt.dst.catalog = Some(Identifier::from_name_with_quoted(catalog, Some('"')));
+        copy_stmt.dst.database = Some(Identifier::from_name_with_quoted(database, Some('"')));
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreatePipePlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            name: name.to_string(),
+            auto_ingest: *auto_ingest,
+            comment: comments.to_string(),
+            copy_stmt: copy_stmt.to_string(),
+            owner: self.ctx.get_current_user()?.identity(),
+        };
+        Ok(Plan::CreatePipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_pipe(
+        &mut self,
+        stmt: &AlterPipeStmt,
+    ) -> Result<Plan> {
+        let AlterPipeStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+
+        if let AlterPipeOptions::Refresh {
+            modified_after: Some(_),
+            ..
+        } = options
+        {
+            return Err(ErrorCode::Unimplemented(
+                "ALTER PIPE REFRESH with MODIFIED_AFTER is not supported yet, use PREFIX instead",
+            ));
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let plan = AlterPipePlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+            options: options.clone(),
+        };
+        Ok(Plan::AlterPipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_pipe(
+        &mut self,
+        stmt: &DropPipeStmt,
+    ) -> Result<Plan> {
+        let DropPipeStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropPipePlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropPipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_pipe(
+        &mut self,
+        stmt: &DescribePipeStmt,
+    ) -> Result<Plan> {
+        let DescribePipeStmt { name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DescPipePlan {
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DescPipe(Box::new(plan)))
+    }
+}
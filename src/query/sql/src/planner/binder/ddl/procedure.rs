@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use databend_common_ast::ast::CreateProcedureStmt;
+use databend_common_ast::ast::DropProcedureStmt;
+use databend_common_ast::ast::ExecuteImmediateStmt;
+use databend_common_ast::ast::ProcedureReturnType;
+use databend_common_ast::parser::parse_script;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_meta_app::principal::UserDefinedProcedure;
+
+use crate::normalize_identifier;
+use crate::planner::resolve_type_name;
+use crate::plans::CreateProcedurePlan;
+use crate::plans::DropProcedurePlan;
+use crate::plans::ExecuteImmediatePlan;
+use crate::plans::Plan;
+use crate::Binder;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_procedure(
+        &mut self,
+        stmt: &CreateProcedureStmt,
+    ) -> Result<Plan> {
+        let CreateProcedureStmt {
+            create_option,
+            name,
+            args,
+            return_type,
+            comment,
+            script,
+        } = stmt;
+
+        // Check the syntax of the script, it's parsed again when the procedure is called.
+        let tokens = tokenize_sql(script)?;
+        parse_script(&tokens, self.dialect)?;
+
+        let mut arg_names = Vec::with_capacity(args.len());
+        let mut arg_types = Vec::with_capacity(args.len());
+        for arg in args {
+            arg_names.push(normalize_identifier(&arg.name, &self.name_resolution_ctx).name);
+            arg_types.push(DataType::from(&resolve_type_name(&arg.data_type, true)?));
+        }
+        let return_type = match return_type {
+            ProcedureReturnType::Scalar(ty) => Some(DataType::from(&resolve_type_name(ty, true)?)),
+            ProcedureReturnType::Table => None,
+        };
+
+        Ok(Plan::CreateProcedure(Box::new(CreateProcedurePlan {
+            create_option: create_option.clone(),
+            tenant: self.ctx.get_tenant(),
+            procedure: UserDefinedProcedure {
+                name: normalize_identifier(name, &self.name_resolution_ctx).name,
+                arg_names,
+                arg_types,
+                return_type,
+                script: script.clone(),
+                comment: comment.clone().unwrap_or_default(),
+                created_on: Utc::now(),
+            },
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_procedure(
+        &mut self,
+        stmt: &DropProcedureStmt,
+    ) -> Result<Plan> {
+        let DropProcedureStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropProcedure(Box::new(DropProcedurePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_execute_immediate(
+        &mut self,
+        stmt: &ExecuteImmediateStmt,
+    ) -> Result<Plan> {
+        let tokens = tokenize_sql(&stmt.script)?;
+        let script = parse_script(&tokens, self.dialect)?;
+
+        Ok(Plan::ExecuteImmediate(Box::new(ExecuteImmediatePlan {
+            script,
+        })))
+    }
+}
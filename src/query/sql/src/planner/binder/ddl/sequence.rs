@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::CreateSequenceStmt;
+use databend_common_ast::ast::DropSequenceStmt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::normalize_identifier;
+use crate::plans::CreateSequencePlan;
+use crate::plans::DropSequencePlan;
+use crate::plans::Plan;
+use crate::Binder;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_sequence(
+        &mut self,
+        stmt: &CreateSequenceStmt,
+    ) -> Result<Plan> {
+        let CreateSequenceStmt {
+            create_option,
+            sequence,
+            start,
+            increment,
+            comment,
+        } = stmt;
+
+        let increment = increment.unwrap_or(1);
+        if increment == 0 {
+            return Err(ErrorCode::SemanticError(
+                "INCREMENT of a sequence must be greater than 0",
+            ));
+        }
+
+        Ok(Plan::CreateSequence(Box::new(CreateSequencePlan {
+            create_option: create_option.clone(),
+            tenant: self.ctx.get_tenant(),
+            sequence: normalize_identifier(sequence, &self.name_resolution_ctx).name,
+            start: start.unwrap_or(1),
+            increment,
+            comment: comment.clone(),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_sequence(
+        &mut self,
+        stmt: &DropSequenceStmt,
+    ) -> Result<Plan> {
+        let DropSequenceStmt {
+            if_exists,
+            sequence,
+        } = stmt;
+
+        Ok(Plan::DropSequence(Box::new(DropSequencePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            sequence: normalize_identifier(sequence, &self.name_resolution_ctx).name,
+        })))
+    }
+}
@@ -42,6 +42,7 @@ use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::ShowTablesStatusStmt;
 use databend_common_ast::ast::ShowTablesStmt;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableConstraint as AstTableConstraint;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TruncateTableStmt;
 use databend_common_ast::ast::UndropTableStmt;
@@ -51,6 +52,8 @@ use databend_common_ast::ast::VacuumTableStmt;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::walk_expr_mut;
+use databend_common_base::base::GlobalUniqName;
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -66,6 +69,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::DataOperator;
 use databend_common_storages_delta::DeltaTable;
@@ -79,6 +83,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::debug;
 use log::error;
 
@@ -88,8 +93,10 @@ use crate::binder::scalar::ScalarBinder;
 use crate::binder::Binder;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
+use crate::field_default_sequence;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
+use crate::parse_check_constraint_to_string;
 use crate::parse_computed_expr_to_string;
 use crate::parse_default_expr_to_string;
 use crate::planner::semantic::normalize_identifier;
@@ -126,6 +133,7 @@ use crate::plans::VacuumTablePlan;
 use crate::BindContext;
 use crate::Planner;
 use crate::SelectBuilder;
+use crate::AUTO_INCREMENT_SEQUENCE_PREFIX;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -409,6 +417,7 @@ impl Binder {
             cluster_by,
             as_query,
             transient,
+            temporary,
             engine,
             uri_location,
         } = stmt;
@@ -468,6 +477,19 @@ impl Binder {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
         }
 
+        // If table is TEMPORARY, it is owned by the current session
+        if *temporary {
+            if catalog != CATALOG_DEFAULT || engine != Engine::Fuse || uri_location.is_some() {
+                return Err(ErrorCode::BadArguments(
+                    "Incorrect CREATE query: TEMPORARY table is only supported for FUSE engine in the default catalog, without external location",
+                ));
+            }
+            options.insert(
+                OPT_KEY_TEMP_PREFIX.to_owned(),
+                self.ctx.get_current_session_id(),
+            );
+        }
+
         // Build table schema
         let (schema, field_comments) = match (&source, &as_query) {
             (Some(source), None) => {
@@ -547,6 +569,36 @@ impl Binder {
             }
         };
 
+        // The implicit sequences of the AUTOINCREMENT columns are created with the table.
+        let auto_increment_sequences = match &source {
+            Some(CreateTableSource::Columns(columns, _)) => columns
+                .iter()
+                .zip(schema.fields())
+                .filter(|(column, _)| matches!(column.expr, Some(ColumnExpr::AutoIncrement)))
+                .filter_map(|(_, field)| field_default_sequence(field))
+                .collect(),
+            _ => vec![],
+        };
+
+        let constraints = match &source {
+            Some(CreateTableSource::Columns(_, constraints)) => {
+                self.analyze_table_constraints(&table, &schema, constraints)?
+            }
+            _ => BTreeMap::new(),
+        };
+        if engine != Engine::Fuse
+            && constraints.values().any(|constraint| {
+                matches!(constraint, TableConstraint::PrimaryKey {
+                    enforced: true,
+                    ..
+                })
+            })
+        {
+            return Err(ErrorCode::BadArguments(
+                "Incorrect CREATE query: ENFORCED PRIMARY KEY is only supported for FUSE engine",
+            ));
+        }
+
         // for fuse engine, we will insert database_id, so if we check it in execute phase,
         // we can't distinct user key and our internal key.
         if options.contains_key(&OPT_KEY_DATABASE_ID.to_lowercase()) {
@@ -641,6 +693,8 @@ impl Binder {
             options,
             field_comments,
             cluster_key,
+            auto_increment_sequences,
+            constraints,
             as_select: if let Some(query) = as_query {
                 let mut bind_context = BindContext::new();
                 let stmt = Statement::Query(Box::new(*query.clone()));
@@ -723,6 +777,8 @@ impl Binder {
             options,
             field_comments: vec![],
             cluster_key: None,
+            auto_increment_sequences: vec![],
+            constraints: BTreeMap::new(),
             as_select: None,
         })))
     }
@@ -1269,6 +1325,11 @@ impl Binder {
                         "can't add a stored computed column".to_string(),
                     ));
                 }
+                ColumnExpr::AutoIncrement => {
+                    return Err(ErrorCode::SemanticError(
+                        "can't add an autoincrement column".to_string(),
+                    ));
+                }
             }
         }
         let comment = column.comment.clone().unwrap_or_default();
@@ -1301,6 +1362,22 @@ impl Binder {
                         )?;
                         field = field.with_default_expr(Some(expr));
                     }
+                    ColumnExpr::AutoIncrement => {
+                        if !DataType::from(&schema_data_type)
+                            .remove_nullable()
+                            .is_integer()
+                        {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "autoincrement column `{name}` must be an integer type"
+                            )));
+                        }
+                        // The sequence is created together with the table.
+                        let sequence = format!(
+                            "{AUTO_INCREMENT_SEQUENCE_PREFIX}{}",
+                            GlobalUniqName::unique().to_lowercase()
+                        );
+                        field = field.with_default_expr(Some(format!("nextval({sequence})")));
+                    }
                     _ => has_computed = true,
                 }
             }
@@ -1362,7 +1439,7 @@ impl Binder {
         source: &CreateTableSource,
     ) -> Result<(TableSchemaRef, Vec<String>)> {
         match source {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, _) => {
                 self.analyze_create_table_schema_by_columns(columns).await
             }
             CreateTableSource::Like {
@@ -1392,7 +1469,9 @@ impl Binder {
     }
 
     /// Validate the schema of the table to be created.
-    fn validate_create_table_schema(schema: &TableSchemaRef) -> Result<()> {
+    pub(in crate::planner::binder) fn validate_create_table_schema(
+        schema: &TableSchemaRef,
+    ) -> Result<()> {
         // Check if there are duplicated column names
         let mut name_set = HashSet::new();
         for field in schema.fields() {
@@ -1407,6 +1486,95 @@ impl Binder {
         Ok(())
     }
 
+    /// Validate the constraints of the table to be created, the constraints
+    /// without a name are named after the table and their columns.
+    fn analyze_table_constraints(
+        &self,
+        table: &str,
+        schema: &TableSchemaRef,
+        constraints: &[AstTableConstraint],
+    ) -> Result<BTreeMap<String, TableConstraint>> {
+        // The virtual computed columns are not stored, so they can't be referred by the check expressions.
+        let source_schema = Arc::new(schema.remove_virtual_computed_fields());
+        let mut table_constraints = BTreeMap::new();
+        let mut has_primary_key = false;
+        let mut check_num = 0;
+        for constraint in constraints {
+            let (default_name, table_constraint) = match constraint {
+                AstTableConstraint::PrimaryKey {
+                    columns, enforced, ..
+                } => {
+                    if has_primary_key {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "multiple primary keys for table `{table}` are not allowed"
+                        )));
+                    }
+                    has_primary_key = true;
+                    let columns = self.analyze_constraint_columns(&source_schema, columns)?;
+                    (format!("{table}_pkey"), TableConstraint::PrimaryKey {
+                        columns,
+                        enforced: *enforced,
+                    })
+                }
+                AstTableConstraint::Unique { columns, .. } => {
+                    let columns = self.analyze_constraint_columns(&source_schema, columns)?;
+                    (
+                        format!("{table}_{}_key", columns.join("_")),
+                        TableConstraint::Unique { columns },
+                    )
+                }
+                AstTableConstraint::Check { expr, .. } => {
+                    check_num += 1;
+                    let expr = parse_check_constraint_to_string(
+                        self.ctx.clone(),
+                        source_schema.clone(),
+                        expr,
+                    )?;
+                    (
+                        format!("{table}_check_{check_num}"),
+                        TableConstraint::Check { expr },
+                    )
+                }
+            };
+            let name = match constraint.name() {
+                Some(name) => normalize_identifier(name, &self.name_resolution_ctx).name,
+                None => default_name,
+            };
+            if table_constraints
+                .insert(name.clone(), table_constraint)
+                .is_some()
+            {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicated constraint name `{name}`"
+                )));
+            }
+        }
+        Ok(table_constraints)
+    }
+
+    fn analyze_constraint_columns(
+        &self,
+        schema: &TableSchemaRef,
+        columns: &[Identifier],
+    ) -> Result<Vec<String>> {
+        let mut names = Vec::with_capacity(columns.len());
+        for column in columns {
+            let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+            if !schema.fields().iter().any(|field| field.name() == &name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "constraint column `{name}` does not exist or is a virtual computed column"
+                )));
+            }
+            if names.contains(&name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicated constraint column `{name}`"
+                )));
+            }
+            names.push(name);
+        }
+        Ok(names)
+    }
+
     fn insert_table_option_with_validation(
         &self,
         options: &mut BTreeMap<String, String>,
@@ -12,18 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
 use databend_common_ast::ast::AlterViewStmt;
+use databend_common_ast::ast::CreateMaterializedViewStmt;
+use databend_common_ast::ast::CreateTableStmt;
 use databend_common_ast::ast::CreateViewStmt;
+use databend_common_ast::ast::DropMaterializedViewStmt;
 use databend_common_ast::ast::DropViewStmt;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::RefreshMaterializedViewStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::TableReference;
 use databend_common_ast::VisitorMut;
+use databend_common_catalog::temp_table::TempTblMgr;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCES;
 
 use crate::binder::Binder;
+use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::AlterViewPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateViewPlan;
+use crate::plans::DropMaterializedViewPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::plans::RelOperator;
+use crate::IndexType;
 use crate::ViewRewriter;
 
 impl Binder {
@@ -128,4 +150,222 @@ impl Binder {
         };
         Ok(Plan::DropView(plan.into()))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            if_not_exists,
+            catalog,
+            database,
+            view,
+            columns,
+            query,
+        } = stmt;
+
+        let mut query = *query.clone();
+        let (_, database_name, _) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let mut visitor = ViewRewriter {
+            current_database: database_name,
+        };
+        visitor.visit_query(&mut query);
+
+        // The materialized view is stored as a table created by `CREATE TABLE ... AS SELECT`,
+        // so that the schema and the storage options are the same as a normal table.
+        let create_table_stmt = CreateTableStmt {
+            create_option: CreateOption::CreateIfNotExists(*if_not_exists),
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: view.clone(),
+            source: None,
+            engine: None,
+            uri_location: None,
+            cluster_by: vec![],
+            table_options: Default::default(),
+            as_query: Some(Box::new(query.clone())),
+            transient: false,
+            temporary: false,
+        };
+        let Plan::CreateTable(mut create_table) =
+            self.bind_create_table(&create_table_stmt).await?
+        else {
+            unreachable!()
+        };
+        let select_plan = create_table
+            .as_select
+            .take()
+            .ok_or_else(|| ErrorCode::Internal("The query of materialized view is not bound"))?;
+
+        if !columns.is_empty() {
+            if columns.len() != create_table.schema.num_fields() {
+                return Err(ErrorCode::BadArguments("Number of columns does not match"));
+            }
+            let mut schema = create_table.schema.as_ref().clone();
+            for (i, ident) in columns.iter().enumerate() {
+                let name = normalize_identifier(ident, &self.name_resolution_ctx).name;
+                schema.rename_field(i, &name);
+            }
+            let schema = Arc::new(schema);
+            Self::validate_create_table_schema(&schema)?;
+            create_table.schema = schema;
+        }
+
+        let Plan::Query {
+            s_expr, metadata, ..
+        } = select_plan.as_ref()
+        else {
+            unreachable!()
+        };
+        let (sources, incremental_source) = {
+            let metadata = metadata.read();
+            let sources = metadata
+                .tables()
+                .iter()
+                .filter(|entry| entry.table().engine() == "FUSE")
+                .map(|entry| entry.table().get_id())
+                .collect::<BTreeSet<_>>();
+            if sources.iter().any(|id| TempTblMgr::is_temp_table(*id)) {
+                return Err(ErrorCode::BadArguments(
+                    "Materialized view can't be created on temporary tables",
+                ));
+            }
+
+            let mut scans = vec![];
+            let incremental_source = if Self::is_incremental_query(&query)
+                && Self::collect_incremental_scans(s_expr, &mut scans)
+                && scans.len() == 1
+            {
+                let entry = metadata.table(scans[0]);
+                let table = entry.table();
+                (!entry.is_source_of_view()
+                    && table.engine() == "FUSE"
+                    && !table.options().contains_key("TRANSIENT"))
+                .then(|| (entry.database().to_string(), entry.name().to_string()))
+            } else {
+                None
+            };
+            (sources, incremental_source)
+        };
+
+        create_table.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            format!("{}", query),
+        );
+        create_table.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_SOURCES.to_string(),
+            sources
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+
+        let plan = CreateMaterializedViewPlan {
+            create_table: *create_table,
+            query: select_plan,
+            incremental_source,
+        };
+        Ok(Plan::CreateMaterializedView(plan.into()))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_materialized_view(
+        &mut self,
+        stmt: &DropMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let DropMaterializedViewStmt {
+            if_exists,
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = DropMaterializedViewPlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::DropMaterializedView(plan.into()))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+            full,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = RefreshMaterializedViewPlan {
+            tenant,
+            catalog,
+            database,
+            view_name,
+            full: *full,
+        };
+        Ok(Plan::RefreshMaterializedView(plan.into()))
+    }
+
+    /// A query can be refreshed incrementally if it only filters and projects the rows
+    /// of a single table, so that the new rows of the table can be appended to the view
+    /// without reading the existing ones.
+    fn is_incremental_query(query: &Query) -> bool {
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+        {
+            return false;
+        }
+        let SetExpr::Select(select) = &query.body else {
+            return false;
+        };
+        // `*` is not allowed, since the stream that the view is refreshed from has
+        // additional change columns.
+        !select.distinct
+            && select.group_by.is_none()
+            && select.having.is_none()
+            && select.window_list.is_none()
+            && select.qualify.is_none()
+            && select
+                .select_list
+                .iter()
+                .all(|target| matches!(target, SelectTarget::AliasedExpr { .. }))
+            && matches!(select.from.as_slice(), [TableReference::Table {
+                travel_point: None,
+                pivot: None,
+                unpivot: None,
+                sample: None,
+                ..
+            }])
+    }
+
+    fn collect_incremental_scans(s_expr: &SExpr, scans: &mut Vec<IndexType>) -> bool {
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => {
+                scans.push(scan.table_index);
+                true
+            }
+            RelOperator::Filter(_) | RelOperator::EvalScalar(_) => s_expr
+                .children()
+                .iter()
+                .all(|child| Self::collect_incremental_scans(child, scans)),
+            _ => false,
+        }
+    }
 }
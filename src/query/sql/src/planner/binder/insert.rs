@@ -12,24 +12,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::str::FromStr;
 use std::sync::Arc;
 
 use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::InsertPartition;
 use databend_common_ast::ast::InsertSource;
 use databend_common_ast::ast::InsertStmt;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table::Table;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRefExt;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::principal::FileFormatOptionsAst;
 use databend_common_meta_app::principal::OnErrorMode;
 
+use crate::binder::wrap_cast;
 use crate::binder::Binder;
 use crate::normalize_identifier;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
+use crate::planner::semantic::TypeChecker;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::Insert;
 use crate::plans::InsertInputSource;
@@ -69,6 +81,164 @@ impl Binder {
         Ok(TableSchemaRefExt::create(fields))
     }
 
+    /// Resolve the `PARTITION` clause into the target columns and the source of `INSERT`.
+    ///
+    /// The partition columns are appended to the target columns, the dynamic ones first and
+    /// then the static ones, whose values are appended to the rows of the source as constants.
+    fn bind_insert_partition(
+        &self,
+        table: &Arc<dyn Table>,
+        partition: &[InsertPartition],
+        columns: &[Identifier],
+        source: &InsertSource,
+    ) -> Result<(Vec<Identifier>, InsertSource)> {
+        if partition.is_empty() {
+            return Ok((columns.to_vec(), source.clone()));
+        }
+
+        let partition_keys = table.partition_keys();
+        if partition_keys.is_empty() {
+            return Err(ErrorCode::SemanticError(format!(
+                "PARTITION clause is not supported by table '{}', which is not partitioned",
+                table.name()
+            )));
+        }
+
+        let mut dynamic_columns = vec![];
+        let mut static_columns = vec![];
+        let mut specified = HashSet::new();
+        for item in partition {
+            let name = normalize_identifier(&item.column, &self.name_resolution_ctx).name;
+            if !partition_keys.contains(&name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "'{}' is not a partition column of table '{}'",
+                    name,
+                    table.name()
+                )));
+            }
+            if !specified.insert(name.clone()) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Duplicate partition column '{}' in PARTITION clause",
+                    name
+                )));
+            }
+            match &item.value {
+                Some(value) => static_columns.push((name, value)),
+                None => dynamic_columns.push(name),
+            }
+        }
+        let missing = partition_keys
+            .iter()
+            .filter(|key| !specified.contains(*key))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(ErrorCode::SemanticError(format!(
+                "Partition columns {:?} are missing in PARTITION clause",
+                missing
+            )));
+        }
+
+        let mut target_columns = if columns.is_empty() {
+            table
+                .schema()
+                .fields()
+                .iter()
+                .filter(|f| f.computed_expr().is_none() && !partition_keys.contains(f.name()))
+                .map(|f| Identifier::from_name_with_quoted(f.name(), Some('"')))
+                .collect::<Vec<_>>()
+        } else {
+            for column in columns {
+                let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+                if partition_keys.contains(&name) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Partition column '{}' should be specified in PARTITION clause only",
+                        name
+                    )));
+                }
+            }
+            columns.to_vec()
+        };
+        target_columns.extend(
+            dynamic_columns
+                .iter()
+                .chain(static_columns.iter().map(|(name, _)| name))
+                .map(|name| Identifier::from_name_with_quoted(name, Some('"'))),
+        );
+        if static_columns.is_empty() {
+            return Ok((target_columns, source.clone()));
+        }
+
+        let static_values = static_columns
+            .iter()
+            .map(|(_, value)| value.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let subquery = match source {
+            InsertSource::Select { query } => {
+                format!("SELECT *, {static_values} FROM ({query})")
+            }
+            InsertSource::Values { rest_str, .. } if self.ctx.get_stage_attachment().is_none() => {
+                let values_str = rest_str.trim_end_matches(';').trim();
+                format!("SELECT *, {static_values} FROM (VALUES {values_str})")
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "Static partition values are only supported by INSERT with VALUES or SELECT",
+                ));
+            }
+        };
+        let tokens = tokenize_sql(&subquery)?;
+        match parse_sql(&tokens, self.dialect)?.0 {
+            Statement::Query(query) => Ok((target_columns, InsertSource::Select { query })),
+            _ => Err(ErrorCode::SemanticError(format!(
+                "Invalid INSERT source with static partition values: {subquery}"
+            ))),
+        }
+    }
+
+    /// Evaluate the values of `INSERT OVERWRITE ... PARTITION (...)`, if all the partition
+    /// columns are static, then the partition is known even if no rows are inserted.
+    #[async_backtrace::framed]
+    async fn bind_overwrite_partition(
+        &self,
+        bind_context: &mut BindContext,
+        table: &Arc<dyn Table>,
+        partition: &[InsertPartition],
+    ) -> Result<Option<Vec<(String, Scalar)>>> {
+        if partition.is_empty() || partition.iter().any(|item| item.value.is_none()) {
+            return Ok(None);
+        }
+
+        let schema = table.schema();
+        let mut values = Vec::with_capacity(partition.len());
+        for item in partition {
+            let name = normalize_identifier(&item.column, &self.name_resolution_ctx).name;
+            let data_type = DataType::from(schema.field_with_name(&name)?.data_type());
+            let mut type_checker = TypeChecker::try_create(
+                bind_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+                false,
+            )?;
+            let (scalar, _) = *type_checker.resolve(item.value.as_ref().unwrap()).await?;
+            let expr = wrap_cast(&scalar, &data_type).as_expr()?;
+            let (expr, _) =
+                ConstantFolder::fold(&expr, &self.ctx.get_function_context()?, &BUILTIN_FUNCTIONS);
+            match expr {
+                Expr::Constant { scalar, .. } => values.push((name, scalar)),
+                _ => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Static value of partition column '{name}' must be constant"
+                    )));
+                }
+            }
+        }
+        Ok(Some(values))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_insert(
         &mut self,
@@ -79,6 +249,7 @@ impl Binder {
             catalog,
             database,
             table,
+            partition,
             columns,
             source,
             overwrite,
@@ -91,9 +262,17 @@ impl Binder {
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
         let table_id = table.get_id();
-        let schema = self.schema_project(&table.schema(), columns)?;
+        let (columns, source) = self.bind_insert_partition(&table, partition, columns, source)?;
+        let overwrite_partition = match *overwrite {
+            true => {
+                self.bind_overwrite_partition(bind_context, &table, partition)
+                    .await?
+            }
+            false => None,
+        };
+        let schema = self.schema_project(&table.schema(), &columns)?;
 
-        let input_source: Result<InsertInputSource> = match source.clone() {
+        let input_source: Result<InsertInputSource> = match source {
             InsertSource::Streaming {
                 format,
                 rest_str,
@@ -172,6 +351,7 @@ impl Binder {
             table_id,
             schema,
             overwrite: *overwrite,
+            overwrite_partition,
             source: input_source?,
         };
 
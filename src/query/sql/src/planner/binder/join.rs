@@ -86,6 +86,11 @@ impl Binder {
                     "cross join should not contain join conditions".to_string(),
                 ));
             }
+            JoinOperator::Asof | JoinOperator::LeftAsof if join.match_condition.is_none() => {
+                return Err(ErrorCode::SemanticError(
+                    "asof join should contain match condition".to_string(),
+                ));
+            }
             _ => (),
         };
 
@@ -114,6 +119,16 @@ impl Binder {
                 &join.op,
             )
             .await?;
+        if let Some(match_condition) = &join.match_condition {
+            if !non_equi_conditions.is_empty() || !other_conditions.is_empty() {
+                return Err(ErrorCode::SemanticError(
+                    "asof join only supports equi conditions in ON clause".to_string(),
+                ));
+            }
+            join_condition_resolver
+                .resolve_match_condition(match_condition, &mut non_equi_conditions)
+                .await?;
+        }
 
         let join_conditions = JoinConditions {
             left_conditions: left_join_conditions,
@@ -174,6 +189,15 @@ impl Binder {
                     right_child,
                 )
             }
+            JoinOperator::Asof => {
+                self.bind_join_with_type(JoinType::Asof, join_conditions, left_child, right_child)
+            }
+            JoinOperator::LeftAsof => self.bind_join_with_type(
+                JoinType::LeftAsof,
+                join_conditions,
+                left_child,
+                right_child,
+            ),
         }?;
         Ok((s_expr, bind_context))
     }
@@ -291,7 +315,9 @@ impl Binder {
                         need_push_down = true;
                         left_push_down.push(predicate.clone());
                     }
-                    JoinType::Full => non_equi_conditions.push(predicate.clone()),
+                    JoinType::Full | JoinType::Asof | JoinType::LeftAsof => {
+                        non_equi_conditions.push(predicate.clone())
+                    }
                 },
                 JoinPredicate::Left(_) => {
                     need_push_down = true;
@@ -347,7 +373,7 @@ fn wrap_nullable_for_column(
     bind_context: &mut BindContext,
 ) {
     match join_type {
-        JoinOperator::LeftOuter => {
+        JoinOperator::LeftOuter | JoinOperator::LeftAsof => {
             for column in left_context.all_column_bindings() {
                 bind_context.add_column_binding(column.clone());
             }
@@ -799,6 +825,70 @@ impl<'a> JoinConditionResolver<'a> {
         Ok(false)
     }
 
+    // The match condition of asof join must be a comparison between the left and right side,
+    // such as `t1.ts >= t2.ts`, it's normalized to put the left side expression first.
+    #[async_backtrace::framed]
+    async fn resolve_match_condition(
+        &mut self,
+        match_condition: &Expr,
+        non_equi_conditions: &mut Vec<ScalarExpr>,
+    ) -> Result<()> {
+        let mut join_context = (*self.join_context).clone();
+        wrap_nullable_for_column(
+            &JoinOperator::Inner,
+            self.left_context,
+            self.right_context,
+            &mut join_context,
+        );
+        let mut scalar_binder = ScalarBinder::new(
+            &mut join_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (predicate, _) = scalar_binder.bind(match_condition).await?;
+        self.check_join_allowed_scalar_expr(&vec![predicate.clone()])
+            .await?;
+
+        let (left_columns, right_columns) = self.left_right_columns()?;
+        let is_side = |scalar: &ScalarExpr, columns: &ColumnSet| {
+            let used_columns = scalar.used_columns();
+            !used_columns.is_empty() && used_columns.is_subset(columns)
+        };
+        if let ScalarExpr::FunctionCall(func) = &predicate {
+            if func.arguments.len() == 2
+                && matches!(func.func_name.as_str(), "gt" | "gte" | "lt" | "lte")
+            {
+                let (arg0, arg1) = (&func.arguments[0], &func.arguments[1]);
+                if is_side(arg0, &left_columns) && is_side(arg1, &right_columns) {
+                    non_equi_conditions.push(predicate.clone());
+                    return Ok(());
+                }
+                if is_side(arg0, &right_columns) && is_side(arg1, &left_columns) {
+                    let mut func = func.clone();
+                    func.func_name = match func.func_name.as_str() {
+                        "gt" => "lt",
+                        "gte" => "lte",
+                        "lt" => "gt",
+                        "lte" => "gte",
+                        _ => unreachable!(),
+                    }
+                    .to_string();
+                    func.arguments.swap(0, 1);
+                    non_equi_conditions.push(ScalarExpr::FunctionCall(func));
+                    return Ok(());
+                }
+            }
+        }
+        Err(ErrorCode::SemanticError(
+            "match condition of asof join must be a comparison of `>`, `>=`, `<` or `<=` between the left and right tables".to_string(),
+        )
+        .set_span(match_condition.span()))
+    }
+
     fn left_right_columns(&self) -> Result<(ColumnSet, ColumnSet)> {
         let left_columns: ColumnSet =
             self.left_context
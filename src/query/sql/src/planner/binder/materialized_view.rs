@@ -0,0 +1,692 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use dashmap::DashMap;
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::GroupBy;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Indirection;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::walk_expr;
+use databend_common_ast::walk_expr_mut;
+use databend_common_ast::Visitor;
+use databend_common_ast::VisitorMut;
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::materialized_view::is_materialized_view_stale;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::Span;
+use databend_common_expression::types::DataType;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use parking_lot::RwLock;
+
+use crate::binder::BindContext;
+use crate::binder::Binder;
+use crate::binder::Visibility;
+use crate::planner::semantic::normalize_identifier;
+use crate::Metadata;
+use crate::NameResolutionContext;
+use crate::ViewRewriter;
+
+/// The candidate materialized views of a database are cached, so that the tables of the
+/// database are not listed for every query. The views created or dropped on other nodes
+/// are seen after the cache expires.
+const MATERIALIZED_VIEW_CANDIDATES_TTL: Duration = Duration::from_secs(30);
+
+/// (tenant, catalog, database)
+type CandidatesKey = (String, String, String);
+
+type CachedCandidates = (Instant, Arc<Vec<MaterializedViewCandidate>>);
+
+static MATERIALIZED_VIEW_CANDIDATES: LazyLock<DashMap<CandidatesKey, CachedCandidates>> =
+    LazyLock::new(DashMap::new);
+
+/// Invalidate the cached candidate materialized views of a database, it's called after
+/// a materialized view of the database is created or dropped.
+pub fn invalidate_materialized_view_candidates(tenant: &str, catalog: &str, database: &str) {
+    MATERIALIZED_VIEW_CANDIDATES.remove(&(
+        tenant.to_string(),
+        catalog.to_string(),
+        database.to_string(),
+    ));
+}
+
+/// A materialized view whose query may subsume the queries on its source table.
+struct MaterializedViewCandidate {
+    view_id: u64,
+    view_name: String,
+    definition: SingleTableQuery,
+}
+
+/// A query reading a single table, whose column references are unqualified.
+struct SingleTableQuery {
+    /// The catalog, database and name of the table.
+    table: (String, String, String),
+    query: Query,
+    /// Whether the rows are grouped, by `GROUP BY`, `DISTINCT` or aggregate functions.
+    aggregating: bool,
+}
+
+impl SingleTableQuery {
+    fn select(&self) -> &SelectStmt {
+        match &self.query.body {
+            SetExpr::Select(select) => select,
+            _ => unreachable!("SingleTableQuery must be a SELECT"),
+        }
+    }
+
+    /// `SELECT * FROM t`, the columns of the view are the columns of the table.
+    fn is_select_star(&self) -> bool {
+        matches!(self.select().select_list.as_slice(), [SelectTarget::StarColumns {
+            qualified,
+            column_filter: None,
+        }] if matches!(qualified.as_slice(), [Indirection::Star(_)]))
+    }
+}
+
+impl Binder {
+    /// Rewrite the query to read an up to date materialized view in the current database,
+    /// if the query of the view subsumes the query.
+    ///
+    /// Both queries must read the same single table. A view without aggregation subsumes
+    /// the query if its predicates are a subset of the predicates of the query, and all the
+    /// expressions of the query can be computed from the columns of the view. A view with
+    /// aggregation additionally requires the same predicates and the same `GROUP BY` keys.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn rewrite_by_materialized_view(
+        &self,
+        query: &Query,
+    ) -> Result<Option<Box<Query>>> {
+        let Some(target) = self.single_table_query(query) else {
+            return Ok(None);
+        };
+
+        let tenant = self.ctx.get_tenant();
+        let catalog_name = self.ctx.get_current_catalog();
+        let database = self.ctx.get_current_database();
+        let catalog = self.ctx.get_catalog(&catalog_name).await?;
+        let candidates = self
+            .materialized_view_candidates(catalog.as_ref(), &tenant, &catalog_name, &database)
+            .await?;
+
+        for candidate in candidates.iter() {
+            if !Self::may_subsume(&candidate.definition, &target) {
+                continue;
+            }
+
+            let view = match catalog
+                .get_table(&tenant, &database, &candidate.view_name)
+                .await
+            {
+                Ok(view) if view.get_id() == candidate.view_id => view,
+                Ok(_) => continue,
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => continue,
+                Err(e) => return Err(e),
+            };
+            if is_materialized_view_stale(catalog.as_ref(), view.as_ref()).await? != Some(false) {
+                continue;
+            }
+
+            let view_schema = view.schema();
+            let view_fields = view_schema
+                .fields()
+                .iter()
+                .map(|field| field.name().clone())
+                .collect::<Vec<_>>();
+            let mut columns = HashMap::new();
+            let mut exprs = HashMap::new();
+            if candidate.definition.is_select_star() {
+                // The columns of the view may be renamed.
+                let (table_catalog, table_database, table_name) = &target.table;
+                let table = self
+                    .ctx
+                    .get_table(table_catalog, table_database, table_name)
+                    .await?;
+                let table_fields = table
+                    .schema()
+                    .fields()
+                    .iter()
+                    .map(|field| field.name().clone())
+                    .collect::<Vec<_>>();
+                if table_fields != view_fields {
+                    continue;
+                }
+                columns.extend(view_fields.iter().map(|name| (name.clone(), name.clone())));
+            } else {
+                for (select_target, field) in candidate
+                    .definition
+                    .select()
+                    .select_list
+                    .iter()
+                    .zip(view_fields.iter())
+                {
+                    if let SelectTarget::AliasedExpr { expr, .. } = select_target {
+                        exprs.insert(expr.to_string(), field.clone());
+                    }
+                }
+            }
+
+            let Some(mut rewritten) =
+                self.rewrite_query(&candidate.definition, &target, &view_fields, columns, exprs)
+            else {
+                continue;
+            };
+            rewritten.body = match rewritten.body {
+                SetExpr::Select(mut select) => {
+                    select.from = vec![TableReference::Table {
+                        span: None,
+                        catalog: Some(quoted_identifier(&catalog_name)),
+                        database: Some(quoted_identifier(&database)),
+                        table: quoted_identifier(view.name()),
+                        alias: None,
+                        travel_point: None,
+                        pivot: None,
+                        unpivot: None,
+                        sample: None,
+                    }];
+                    SetExpr::Select(select)
+                }
+                body => body,
+            };
+
+            // The rewritten query must output the same columns as the query.
+            let Some(output) = self.bind_output(query).await else {
+                continue;
+            };
+            let Some(rewritten_output) = self.bind_output(&rewritten).await else {
+                continue;
+            };
+            if output.len() != rewritten_output.len()
+                || output
+                    .iter()
+                    .zip(rewritten_output.iter())
+                    .any(|((_, ty), (_, rewritten_ty))| ty != rewritten_ty)
+            {
+                continue;
+            }
+            if let SetExpr::Select(select) = &mut rewritten.body {
+                for (target, (name, _)) in select.select_list.iter_mut().zip(output.iter()) {
+                    if let SelectTarget::AliasedExpr { alias, .. } = target {
+                        *alias = Some(quoted_identifier(name));
+                    }
+                }
+            }
+            rewritten.ignore_result = query.ignore_result;
+            return Ok(Some(Box::new(rewritten)));
+        }
+        Ok(None)
+    }
+
+    async fn materialized_view_candidates(
+        &self,
+        catalog: &dyn Catalog,
+        tenant: &str,
+        catalog_name: &str,
+        database: &str,
+    ) -> Result<Arc<Vec<MaterializedViewCandidate>>> {
+        let key = (
+            tenant.to_string(),
+            catalog_name.to_string(),
+            database.to_string(),
+        );
+        if let Some(cached) = MATERIALIZED_VIEW_CANDIDATES.get(&key) {
+            let (loaded_on, candidates) = cached.value();
+            if loaded_on.elapsed() < MATERIALIZED_VIEW_CANDIDATES_TTL {
+                return Ok(candidates.clone());
+            }
+        }
+
+        let mut candidates = vec![];
+        for table in catalog.list_tables(tenant, database).await? {
+            let Some(definition) = table.options().get(OPT_KEY_MATERIALIZED_VIEW_QUERY) else {
+                continue;
+            };
+            let Ok(tokens) = tokenize_sql(definition) else {
+                continue;
+            };
+            let Ok((Statement::Query(query), _)) = parse_sql(&tokens, self.dialect) else {
+                continue;
+            };
+            let Some(definition) = self.single_table_query(&query) else {
+                continue;
+            };
+            let select = definition.select();
+            if !definition.query.order_by.is_empty()
+                || !definition.query.limit.is_empty()
+                || definition.query.offset.is_some()
+                || select.distinct
+                || (!definition.is_select_star()
+                    && select
+                        .select_list
+                        .iter()
+                        .any(|target| matches!(target, SelectTarget::StarColumns { .. })))
+            {
+                continue;
+            }
+            candidates.push(MaterializedViewCandidate {
+                view_id: table.get_id(),
+                view_name: table.name().to_string(),
+                definition,
+            });
+        }
+
+        let candidates = Arc::new(candidates);
+        MATERIALIZED_VIEW_CANDIDATES.insert(key, (Instant::now(), candidates.clone()));
+        Ok(candidates)
+    }
+
+    /// Normalize a query reading a single table, `None` is returned if the query is not
+    /// supported by the rewrite.
+    fn single_table_query(&self, query: &Query) -> Option<SingleTableQuery> {
+        if query.with.is_some() {
+            return None;
+        }
+        let mut query = query.clone();
+        let mut visitor = ViewRewriter {
+            current_database: self.ctx.get_current_database(),
+        };
+        visitor.visit_query(&mut query);
+        ColumnQualifierEraser.visit_query(&mut query);
+
+        let SetExpr::Select(select) = &query.body else {
+            return None;
+        };
+        let [
+            TableReference::Table {
+                catalog,
+                database,
+                table,
+                travel_point: None,
+                pivot: None,
+                unpivot: None,
+                sample: None,
+                ..
+            },
+        ] = select.from.as_slice()
+        else {
+            return None;
+        };
+        if select.window_list.is_some()
+            || select.qualify.is_some()
+            || !matches!(select.group_by, None | Some(GroupBy::Normal(_)))
+        {
+            return None;
+        }
+
+        let mut checker = ExprChecker::default();
+        for target in &select.select_list {
+            if let SelectTarget::AliasedExpr { expr, .. } = target {
+                checker.visit_expr(expr);
+            }
+        }
+        for expr in select.selection.iter().chain(select.having.iter()) {
+            checker.visit_expr(expr);
+        }
+        if let Some(GroupBy::Normal(exprs)) = &select.group_by {
+            for expr in exprs {
+                checker.visit_expr(expr);
+            }
+        }
+        for order_by in &query.order_by {
+            checker.visit_expr(&order_by.expr);
+        }
+        if checker.unsupported {
+            return None;
+        }
+
+        let aggregating = checker.aggregating
+            || select.distinct
+            || select.group_by.is_some()
+            || select.having.is_some();
+        let table = self.normalize_object_identifier_triple(catalog, database, table);
+        Some(SingleTableQuery {
+            table,
+            query,
+            aggregating,
+        })
+    }
+
+    /// The cheap checks of the subsumption, before the view is loaded.
+    fn may_subsume(view: &SingleTableQuery, query: &SingleTableQuery) -> bool {
+        if view.table != query.table || (view.aggregating && !query.aggregating) {
+            return false;
+        }
+        let view_select = view.select();
+        let query_select = query.select();
+        let view_predicates = conjunct_strings(&view_select.selection);
+        let query_predicates = conjunct_strings(&query_select.selection);
+        if !view.aggregating {
+            return view_predicates.is_subset(&query_predicates);
+        }
+        view_predicates == query_predicates
+            && group_by_strings(&view_select.group_by) == group_by_strings(&query_select.group_by)
+            && conjunct_strings(&view_select.having)
+                .is_subset(&conjunct_strings(&query_select.having))
+    }
+
+    /// Rewrite the expressions of the query with the columns of the view, `None` is returned
+    /// if any expression can't be computed from the columns of the view.
+    fn rewrite_query(
+        &self,
+        view: &SingleTableQuery,
+        target: &SingleTableQuery,
+        view_fields: &[String],
+        columns: HashMap<String, String>,
+        exprs: HashMap<String, String>,
+    ) -> Option<Query> {
+        let view_select = view.select();
+        let mut query = target.query.clone();
+        let SetExpr::Select(select) = &mut query.body else {
+            return None;
+        };
+
+        let aliases: HashSet<String> = select
+            .select_list
+            .iter()
+            .filter_map(|target| match target {
+                SelectTarget::AliasedExpr {
+                    alias: Some(alias), ..
+                } => Some(normalize_identifier(alias, &self.name_resolution_ctx).name),
+                _ => None,
+            })
+            .collect();
+        let mut rewriter = ViewColumnRewriter {
+            name_resolution_ctx: self.name_resolution_ctx.clone(),
+            columns,
+            exprs,
+            aliases: HashSet::new(),
+            failed: false,
+        };
+
+        // The `*` is expanded to the columns of the view, which are the columns of the table.
+        let mut select_list = Vec::with_capacity(select.select_list.len());
+        for target in select.select_list.drain(..) {
+            match target {
+                SelectTarget::AliasedExpr { mut expr, alias } => {
+                    rewriter.visit_expr(&mut expr);
+                    select_list.push(SelectTarget::AliasedExpr { expr, alias });
+                }
+                SelectTarget::StarColumns {
+                    qualified,
+                    column_filter: None,
+                } if view.is_select_star()
+                    && matches!(qualified.as_slice(), [Indirection::Star(_)]) =>
+                {
+                    select_list.extend(view_fields.iter().map(|field| SelectTarget::AliasedExpr {
+                        expr: Box::new(column_ref(field)),
+                        alias: None,
+                    }));
+                }
+                SelectTarget::StarColumns { .. } => return None,
+            }
+        }
+        select.select_list = select_list;
+
+        // The other clauses may refer to the aliases of the select list.
+        rewriter.aliases = aliases;
+        let view_predicates = conjunct_strings(&view_select.selection);
+        let mut predicates = conjuncts(&select.selection)
+            .into_iter()
+            .filter(|expr| !view_predicates.contains(&expr.to_string()))
+            .collect::<Vec<_>>();
+        if view.aggregating {
+            // The rows of the view are grouped, the `HAVING` of the query filters the groups.
+            let view_having = conjunct_strings(&view_select.having);
+            predicates.extend(
+                conjuncts(&select.having)
+                    .into_iter()
+                    .filter(|expr| !view_having.contains(&expr.to_string())),
+            );
+            select.group_by = None;
+            select.having = None;
+        } else {
+            if let Some(GroupBy::Normal(exprs)) = &mut select.group_by {
+                for expr in exprs.iter_mut() {
+                    rewriter.visit_expr(expr);
+                }
+            }
+            if let Some(having) = &mut select.having {
+                rewriter.visit_expr(having);
+            }
+        }
+        for predicate in predicates.iter_mut() {
+            rewriter.visit_expr(predicate);
+        }
+        select.selection = predicates.into_iter().reduce(|left, right| Expr::BinaryOp {
+            span: None,
+            op: BinaryOperator::And,
+            left: Box::new(left),
+            right: Box::new(right),
+        });
+        for order_by in query.order_by.iter_mut() {
+            rewriter.visit_expr(&mut order_by.expr);
+        }
+        if rewriter.failed {
+            return None;
+        }
+
+        // The aggregate functions of the query must be answered by the columns of a view
+        // with aggregation, instead of aggregating the groups again.
+        if view.aggregating {
+            let mut checker = ExprChecker::default();
+            let SetExpr::Select(select) = &query.body else {
+                return None;
+            };
+            for target in &select.select_list {
+                if let SelectTarget::AliasedExpr { expr, .. } = target {
+                    checker.visit_expr(expr);
+                }
+            }
+            for expr in select.selection.iter() {
+                checker.visit_expr(expr);
+            }
+            for order_by in &query.order_by {
+                checker.visit_expr(&order_by.expr);
+            }
+            if checker.aggregating {
+                return None;
+            }
+        }
+        Some(query)
+    }
+
+    /// The names and the types of the output columns of the query, `None` is returned if
+    /// the query can't be bound.
+    async fn bind_output(&self, query: &Query) -> Option<Vec<(String, DataType)>> {
+        let mut binder = Binder::new(
+            self.ctx.clone(),
+            self.catalogs.clone(),
+            self.name_resolution_ctx.clone(),
+            Arc::new(RwLock::new(Metadata::default())),
+        );
+        let (_, bind_context) = binder
+            .bind_query(&mut BindContext::new(), query)
+            .await
+            .ok()?;
+        Some(
+            bind_context
+                .columns
+                .iter()
+                .filter(|column| column.visibility == Visibility::Visible)
+                .map(|column| (column.column_name.clone(), *column.data_type.clone()))
+                .collect(),
+        )
+    }
+}
+
+fn quoted_identifier(name: &str) -> Identifier {
+    Identifier {
+        name: name.to_string(),
+        quote: Some('`'),
+        span: None,
+    }
+}
+
+fn column_ref(name: &str) -> Expr {
+    Expr::ColumnRef {
+        span: None,
+        database: None,
+        table: None,
+        column: ColumnID::Name(quoted_identifier(name)),
+    }
+}
+
+fn conjuncts(expr: &Option<Expr>) -> Vec<Expr> {
+    fn split(expr: &Expr, conjuncts: &mut Vec<Expr>) {
+        match expr {
+            Expr::BinaryOp {
+                op: BinaryOperator::And,
+                left,
+                right,
+                ..
+            } => {
+                split(left, conjuncts);
+                split(right, conjuncts);
+            }
+            _ => conjuncts.push(expr.clone()),
+        }
+    }
+
+    let mut result = vec![];
+    if let Some(expr) = expr {
+        split(expr, &mut result);
+    }
+    result
+}
+
+fn conjunct_strings(expr: &Option<Expr>) -> HashSet<String> {
+    conjuncts(expr)
+        .iter()
+        .map(|expr| expr.to_string())
+        .collect()
+}
+
+fn group_by_strings(group_by: &Option<GroupBy>) -> HashSet<String> {
+    match group_by {
+        Some(GroupBy::Normal(exprs)) => exprs.iter().map(|expr| expr.to_string()).collect(),
+        _ => HashSet::new(),
+    }
+}
+
+/// Remove the qualifiers of the column references, the queries read a single table.
+struct ColumnQualifierEraser;
+
+impl VisitorMut for ColumnQualifierEraser {
+    fn visit_column_ref(
+        &mut self,
+        _span: Span,
+        database: &mut Option<Identifier>,
+        table: &mut Option<Identifier>,
+        _column: &mut ColumnID,
+    ) {
+        *database = None;
+        *table = None;
+    }
+}
+
+/// Check whether the expressions contain aggregate functions, and whether they are
+/// supported by the rewrite.
+#[derive(Default)]
+struct ExprChecker {
+    aggregating: bool,
+    unsupported: bool,
+}
+
+impl<'ast> Visitor<'ast> for ExprChecker {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        match expr {
+            Expr::Subquery { .. } | Expr::Exists { .. } | Expr::InSubquery { .. } => {
+                self.unsupported = true;
+                return;
+            }
+            Expr::CountAll { window, .. } => {
+                self.aggregating |= window.is_none();
+                self.unsupported |= window.is_some();
+            }
+            Expr::FunctionCall {
+                name,
+                window,
+                lambda,
+                ..
+            } => {
+                self.unsupported |= window.is_some()
+                    || lambda.is_some()
+                    || BUILTIN_FUNCTIONS
+                        .get_property(&name.name)
+                        .map(|p| p.non_deterministic)
+                        .unwrap_or(false);
+                self.aggregating |= AggregateFunctionFactory::instance().contains(&name.name);
+            }
+            _ => {}
+        }
+        walk_expr(self, expr);
+    }
+}
+
+/// Replace the expressions of the query with the columns of the view.
+struct ViewColumnRewriter {
+    name_resolution_ctx: NameResolutionContext,
+    /// The columns of the table to the columns of the view.
+    columns: HashMap<String, String>,
+    /// The expressions of the view query to the columns of the view.
+    exprs: HashMap<String, String>,
+    /// The aliases of the select list of the query.
+    aliases: HashSet<String>,
+    failed: bool,
+}
+
+impl VisitorMut for ViewColumnRewriter {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        if self.failed {
+            return;
+        }
+        if let Some(field) = self.exprs.get(&expr.to_string()) {
+            *expr = column_ref(field);
+            return;
+        }
+        match expr {
+            Expr::ColumnRef {
+                column: ColumnID::Name(ident),
+                ..
+            } => {
+                let name = normalize_identifier(ident, &self.name_resolution_ctx).name;
+                if let Some(field) = self.columns.get(&name) {
+                    *expr = column_ref(field);
+                } else if !self.aliases.contains(&name) {
+                    self.failed = true;
+                }
+            }
+            Expr::ColumnRef { .. } => self.failed = true,
+            _ => walk_expr_mut(self, expr),
+        }
+    }
+}
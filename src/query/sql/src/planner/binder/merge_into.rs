@@ -211,6 +211,7 @@ impl Binder {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
 
         // get_source_table_reference
@@ -334,6 +335,7 @@ impl Binder {
             left: Box::new(target_table),
             // use source as build table
             right: Box::new(source_data.clone()),
+            match_condition: None,
         };
 
         let (join_sexpr, mut bind_ctx) = self
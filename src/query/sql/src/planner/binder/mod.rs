@@ -32,6 +32,7 @@ mod join;
 mod kill;
 mod limit;
 mod location;
+mod materialized_view;
 mod merge_into;
 mod presign;
 mod project;
@@ -65,6 +66,7 @@ pub use internal_column_factory::INTERNAL_COLUMN_FACTORY;
 pub use location::get_storage_params_from_options;
 pub use location::parse_storage_params_from_uri;
 pub use location::parse_uri_location;
+pub use materialized_view::invalidate_materialized_view_candidates;
 pub use merge_into::MergeIntoType;
 pub use scalar::ScalarBinder;
 pub use scalar_common::*;
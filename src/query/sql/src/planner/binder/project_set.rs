@@ -17,7 +17,7 @@ use std::sync::Arc;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Lambda;
-use databend_common_ast::ast::Window;
+use databend_common_ast::ast::WindowDesc;
 use databend_common_ast::Visitor;
 use databend_common_exception::Result;
 use databend_common_exception::Span;
@@ -52,7 +52,7 @@ impl<'a> Visitor<'a> for SrfCollector {
         name: &'a Identifier,
         args: &'a [Expr],
         params: &'a [Expr],
-        over: &'a Option<Window>,
+        over: &'a Option<WindowDesc>,
         lambda: &'a Option<Lambda>,
     ) {
         if BUILTIN_FUNCTIONS
@@ -72,14 +72,27 @@ impl Binder {
             Arc::new(schema.project(&field_indexes))
         };
 
-        let on_conflict_fields = on_conflict_columns
-            .iter()
-            .map(|ident| {
-                schema
-                    .field_with_name(&normalize_identifier(ident, &self.name_resolution_ctx).name)
-                    .cloned()
-            })
-            .collect::<Result<Vec<_>>>()?;
+        let on_conflict_fields = if on_conflict_columns.is_empty() {
+            // Without ON CONFLICT, the primary key of the table is the conflict key.
+            match table.get_table_info().meta.primary_key() {
+                Some((columns, _)) => columns
+                    .iter()
+                    .map(|column| schema.field_with_name(column).cloned())
+                    .collect::<Result<Vec<_>>>()?,
+                None => vec![],
+            }
+        } else {
+            on_conflict_columns
+                .iter()
+                .map(|ident| {
+                    schema
+                        .field_with_name(
+                            &normalize_identifier(ident, &self.name_resolution_ctx).name,
+                        )
+                        .cloned()
+                })
+                .collect::<Result<Vec<_>>>()?
+        };
 
         let input_source: Result<InsertInputSource> = match source.clone() {
             InsertSource::Streaming {
@@ -159,6 +172,7 @@ impl Binder {
             schema,
             source: input_source?,
             delete_when: delete_when.clone(),
+            error_on_conflict: false,
         };
 
         Ok(Plan::Replace(Box::new(plan)))
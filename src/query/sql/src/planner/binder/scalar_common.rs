@@ -243,7 +243,8 @@ pub fn wrap_cast_scalar(
             | DataType::Timestamp
             | DataType::Date
             | DataType::Bitmap
-            | DataType::Variant => wrap_cast(scalar, target_type),
+            | DataType::Variant
+            | DataType::Geometry => wrap_cast(scalar, target_type),
             DataType::String => {
                 // parse string to JSON value
                 let func = ScalarExpr::FunctionCall(FunctionCall {
@@ -33,6 +33,7 @@ use databend_common_ast::ast::Query;
 use databend_common_ast::ast::SelectStmt;
 use databend_common_ast::ast::SelectTarget;
 use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::SetOperation;
 use databend_common_ast::ast::SetOperator;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::Visitor;
@@ -41,6 +42,9 @@ use databend_common_exception::Result;
 use databend_common_exception::Span;
 use databend_common_expression::type_check::common_super_type;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
 use databend_common_expression::ROW_ID_COLUMN_ID;
 use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -54,21 +58,32 @@ use crate::binder::scalar_common::split_conjunctions;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
 use crate::binder::ExprContext;
+use crate::binder::RecursiveCteInfo;
 use crate::binder::INTERNAL_COLUMN_FACTORY;
 use crate::normalize_identifier;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner::binder::scalar::ScalarBinder;
 use crate::planner::binder::BindContext;
 use crate::planner::binder::Binder;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
+use crate::plans::ConstantExpr;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::JoinType;
+use crate::plans::ProjectSet;
+use crate::plans::RecursiveCte;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
+use crate::plans::SrfItem;
 use crate::plans::UnionAll;
 use crate::plans::Visitor as _;
+use crate::AsyncFunctionRewriter;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
 use crate::IndexType;
@@ -76,6 +91,9 @@ use crate::UdfRewriter;
 use crate::VirtualColumnRewriter;
 use crate::Visibility;
 
+// Max times to rebind the recursive term of a recursive cte to get stable column types.
+const MAX_R_CTE_BIND_PASSES: usize = 8;
+
 // A normalized IR for `SELECT` clause.
 #[derive(Debug, Default)]
 pub struct SelectList<'a> {
@@ -127,6 +145,7 @@ impl Binder {
                         condition: JoinCondition::None,
                         left: Box::new(left),
                         right: Box::new(right),
+                        match_condition: None,
                     },
                 })
                 .unwrap();
@@ -296,6 +315,10 @@ impl Binder {
         let mut udf_rewriter = UdfRewriter::new(self.metadata.clone());
         s_expr = udf_rewriter.rewrite(&s_expr)?;
 
+        // rewrite async function
+        let mut async_func_rewriter = AsyncFunctionRewriter::new(self.metadata.clone());
+        s_expr = async_func_rewriter.rewrite(&s_expr)?;
+
         // rewrite variant inner fields as virtual columns
         let mut virtual_column_rewriter =
             VirtualColumnRewriter::new(self.ctx.clone(), self.metadata.clone());
@@ -365,6 +388,7 @@ impl Binder {
                         .collect(),
                     query: *cte.query.clone(),
                     materialized: cte.materialized,
+                    recursive: with.recursive,
                     cte_idx: idx,
                     used_count: 0,
                     stat_info: None,
@@ -505,6 +529,22 @@ impl Binder {
                     right_expr,
                 )
             }
+            (SetOperator::Intersect, true) => self.bind_intersect_all(
+                left.span(),
+                right.span(),
+                left_bind_context,
+                right_bind_context,
+                left_expr,
+                right_expr,
+            ),
+            (SetOperator::Except, true) => self.bind_except_all(
+                left.span(),
+                right.span(),
+                left_bind_context,
+                right_bind_context,
+                left_expr,
+                right_expr,
+            ),
             (SetOperator::Union, true) => self.bind_union(
                 left.span(),
                 right.span(),
@@ -523,12 +563,155 @@ impl Binder {
                 right_expr,
                 true,
             ),
-            _ => Err(ErrorCode::Unimplemented(
-                "Unsupported query type, currently, databend only support intersect distinct and except distinct",
-            )),
         }
     }
 
+    /// Bind the body of a recursive cte, which is `anchor UNION [ALL] recursive`.
+    ///
+    /// The recursive term reads the working table through `RecursiveCteScan`, the types of
+    /// the working table are derived from the anchor term and widened until they are stable.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_r_cte(
+        &mut self,
+        span: Span,
+        bind_context: &mut BindContext,
+        cte_name: &str,
+        cte_info: &CteInfo,
+        set_operation: &SetOperation,
+    ) -> Result<(SExpr, BindContext)> {
+        let query = &cte_info.query;
+        if !query.order_by.is_empty() || !query.limit.is_empty() || query.offset.is_some() {
+            return Err(ErrorCode::SemanticError(format!(
+                "ORDER BY, LIMIT and OFFSET are not allowed in recursive cte '{cte_name}'"
+            ))
+            .set_span(span));
+        }
+
+        let (left_expr, left_bind_context) = self
+            .bind_set_expr(bind_context, &set_operation.left, &[], 0)
+            .await?;
+        let stat_info = RelExpr::with_s_expr(&left_expr).derive_cardinality()?;
+        let mut column_types = left_bind_context
+            .columns
+            .iter()
+            .map(|column| *column.data_type.clone())
+            .collect::<Vec<_>>();
+
+        // The recursive term may produce wider types than the anchor term, e.g. `n + 1`,
+        // so rebind it with the common super types until the types don't change.
+        let mut pass = 0;
+        let (right_expr, right_bind_context) = loop {
+            let mut columns = Vec::with_capacity(column_types.len());
+            for (idx, (column, data_type)) in left_bind_context
+                .columns
+                .iter()
+                .zip(column_types.iter())
+                .enumerate()
+            {
+                let column_name = cte_info
+                    .columns_alias
+                    .get(idx)
+                    .cloned()
+                    .unwrap_or_else(|| column.column_name.clone());
+                let index = self
+                    .metadata
+                    .write()
+                    .add_derived_column(column_name.clone(), data_type.clone());
+                columns.push(
+                    ColumnBindingBuilder::new(
+                        column_name,
+                        index,
+                        Box::new(data_type.clone()),
+                        Visibility::Visible,
+                    )
+                    .table_name(Some(cte_name.to_string()))
+                    .build(),
+                );
+            }
+            self.r_cte_infos
+                .insert(cte_name.to_string(), RecursiveCteInfo {
+                    columns,
+                    stat_info: stat_info.clone(),
+                    referenced: false,
+                });
+            let res = self
+                .bind_set_expr(bind_context, &set_operation.right, &[], 0)
+                .await;
+            let r_cte_info = self.r_cte_infos.remove(cte_name);
+            let (right_expr, right_bind_context) = res?;
+
+            if !r_cte_info.is_some_and(|info| info.referenced) {
+                // The cte doesn't reference itself, bind it as a normal union.
+                return self.bind_union(
+                    set_operation.left.span(),
+                    set_operation.right.span(),
+                    left_bind_context,
+                    right_bind_context,
+                    left_expr,
+                    right_expr,
+                    !set_operation.all,
+                );
+            }
+            if left_bind_context.columns.len() != right_bind_context.columns.len() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The anchor and recursive terms of cte '{cte_name}' must have the same number of columns"
+                ))
+                .set_span(span));
+            }
+
+            let mut new_column_types = Vec::with_capacity(column_types.len());
+            for (data_type, right_col) in column_types.iter().zip(right_bind_context.columns.iter())
+            {
+                match common_super_type(
+                    data_type.clone(),
+                    *right_col.data_type.clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                ) {
+                    Some(data_type) => new_column_types.push(data_type),
+                    None => {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "Recursive cte '{cte_name}' types cannot be matched, column {:?}, type: {:?}, recursive type: {:?}",
+                            right_col.column_name, data_type, right_col.data_type
+                        ))
+                        .set_span(span));
+                    }
+                }
+            }
+            if new_column_types == column_types {
+                break (right_expr, right_bind_context);
+            }
+            pass += 1;
+            if pass >= MAX_R_CTE_BIND_PASSES {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Cannot determine the column types of recursive cte '{cte_name}', please cast the recursive term explicitly"
+                ))
+                .set_span(span));
+            }
+            column_types = new_column_types;
+        };
+
+        let (new_bind_context, pairs, left_expr, right_expr) = self.coercion_union_type(
+            set_operation.left.span(),
+            set_operation.right.span(),
+            left_bind_context,
+            right_bind_context,
+            left_expr,
+            right_expr,
+            column_types,
+        )?;
+        let r_cte = RecursiveCte {
+            cte_name: cte_name.to_string(),
+            distinct: !set_operation.all,
+            pairs,
+        };
+        let s_expr = SExpr::create_binary(
+            Arc::new(r_cte.into()),
+            Arc::new(left_expr),
+            Arc::new(right_expr),
+        );
+        Ok((s_expr, new_bind_context))
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn bind_union(
         &mut self,
@@ -540,32 +723,7 @@ impl Binder {
         right_expr: SExpr,
         distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
-        let mut coercion_types = Vec::with_capacity(left_context.columns.len());
-        for (left_col, right_col) in left_context
-            .columns
-            .iter()
-            .zip(right_context.columns.iter())
-        {
-            if left_col.data_type != right_col.data_type {
-                if let Some(data_type) = common_super_type(
-                    *left_col.data_type.clone(),
-                    *right_col.data_type.clone(),
-                    &BUILTIN_FUNCTIONS.default_cast_rules,
-                ) {
-                    coercion_types.push(data_type);
-                } else {
-                    return Err(ErrorCode::SemanticError(format!(
-                        "SetOperation's types cannot be matched, left column {:?}, type: {:?}, right column {:?}, type: {:?}",
-                        left_col.column_name,
-                        left_col.data_type,
-                        right_col.column_name,
-                        right_col.data_type
-                    )));
-                }
-            } else {
-                coercion_types.push(*left_col.data_type.clone());
-            }
-        }
+        let coercion_types = Self::union_coercion_types(&left_context, &right_context)?;
         let (new_bind_context, pairs, left_expr, right_expr) = self.coercion_union_type(
             left_span,
             right_span,
@@ -687,6 +845,301 @@ impl Binder {
         Ok((s_expr, left_context))
     }
 
+    pub fn bind_intersect_all(
+        &mut self,
+        left_span: Span,
+        right_span: Span,
+        left_context: BindContext,
+        right_context: BindContext,
+        left_expr: SExpr,
+        right_expr: SExpr,
+    ) -> Result<(SExpr, BindContext)> {
+        self.bind_intersect_or_except_all(
+            left_span,
+            right_span,
+            left_context,
+            right_context,
+            left_expr,
+            right_expr,
+            &SetOperator::Intersect,
+        )
+    }
+
+    pub fn bind_except_all(
+        &mut self,
+        left_span: Span,
+        right_span: Span,
+        left_context: BindContext,
+        right_context: BindContext,
+        left_expr: SExpr,
+        right_expr: SExpr,
+    ) -> Result<(SExpr, BindContext)> {
+        self.bind_intersect_or_except_all(
+            left_span,
+            right_span,
+            left_context,
+            right_context,
+            left_expr,
+            right_expr,
+            &SetOperator::Except,
+        )
+    }
+
+    /// Bind `INTERSECT ALL` and `EXCEPT ALL` by counting the occurrences of each row:
+    ///
+    /// ```sql
+    /// SELECT c1, ..., cn FROM (
+    ///     SELECT c1, ..., cn, count(l) AS lc, count(r) AS rc FROM (
+    ///         SELECT c1, ..., cn, 1 AS l, NULL AS r FROM left
+    ///         UNION ALL
+    ///         SELECT c1, ..., cn, NULL AS l, 1 AS r FROM right
+    ///     ) GROUP BY c1, ..., cn
+    /// ), unnest(range(start, end)) WHERE condition
+    /// ```
+    ///
+    /// For `INTERSECT ALL`, the condition is `lc > 0 AND rc > 0` and the range is `[0, least(lc, rc))`.
+    /// For `EXCEPT ALL`, the condition is `lc > rc` and the range is `[rc, lc)`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn bind_intersect_or_except_all(
+        &mut self,
+        left_span: Span,
+        right_span: Span,
+        mut left_context: BindContext,
+        mut right_context: BindContext,
+        left_expr: SExpr,
+        right_expr: SExpr,
+        op: &SetOperator,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut coercion_types = Self::union_coercion_types(&left_context, &right_context)?;
+        let num_columns = coercion_types.len();
+
+        // Add the markers of which side the rows come from.
+        let marker_type = DataType::Number(NumberDataType::UInt8).wrap_nullable();
+        let one = Scalar::Number(NumberScalar::UInt8(1));
+        let left_expr = self
+            .add_set_operation_markers(&mut left_context, left_expr, [one.clone(), Scalar::Null])?;
+        let right_expr =
+            self.add_set_operation_markers(&mut right_context, right_expr, [Scalar::Null, one])?;
+        coercion_types.push(marker_type.clone());
+        coercion_types.push(marker_type);
+
+        let (mut new_bind_context, pairs, left_expr, right_expr) = self.coercion_union_type(
+            left_span,
+            right_span,
+            left_context,
+            right_context,
+            left_expr,
+            right_expr,
+            coercion_types,
+        )?;
+        let mut s_expr = SExpr::create_binary(
+            Arc::new(UnionAll { pairs }.into()),
+            Arc::new(left_expr),
+            Arc::new(right_expr),
+        );
+
+        // Count the occurrences of each row in both sides.
+        let markers = new_bind_context.columns.split_off(num_columns);
+        let group_items = new_bind_context
+            .columns
+            .iter()
+            .map(|column| ScalarItem {
+                scalar: BoundColumnRef {
+                    span: left_span,
+                    column: column.clone(),
+                }
+                .into(),
+                index: column.index,
+            })
+            .collect();
+        let mut counts = Vec::with_capacity(markers.len());
+        let mut aggregate_functions = Vec::with_capacity(markers.len());
+        for marker in markers {
+            let display_name = format!("count({})", marker.column_name);
+            let return_type = DataType::Number(NumberDataType::UInt64);
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(display_name.clone(), return_type.clone());
+            aggregate_functions.push(ScalarItem {
+                scalar: AggregateFunction {
+                    func_name: "count".to_string(),
+                    distinct: false,
+                    params: vec![],
+                    args: vec![
+                        BoundColumnRef {
+                            span: left_span,
+                            column: marker,
+                        }
+                        .into(),
+                    ],
+                    return_type: Box::new(return_type.clone()),
+                    display_name: display_name.clone(),
+                }
+                .into(),
+                index,
+            });
+            counts.push(ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: left_span,
+                column: ColumnBindingBuilder::new(
+                    display_name,
+                    index,
+                    Box::new(return_type),
+                    Visibility::Visible,
+                )
+                .build(),
+            }));
+        }
+        s_expr = SExpr::create_unary(
+            Arc::new(
+                Aggregate {
+                    mode: AggregateMode::Initial,
+                    group_items,
+                    aggregate_functions,
+                    from_distinct: false,
+                    limit: None,
+                    grouping_sets: None,
+                }
+                .into(),
+            ),
+            Arc::new(s_expr),
+        );
+
+        // Keep the rows satisfying the condition, and repeat them by the length of the range.
+        let call = |func_name: &str, arguments: Vec<ScalarExpr>| -> ScalarExpr {
+            FunctionCall {
+                span: left_span,
+                func_name: func_name.to_string(),
+                params: vec![],
+                arguments,
+            }
+            .into()
+        };
+        let zero: ScalarExpr = ConstantExpr {
+            span: left_span,
+            value: Scalar::Number(NumberScalar::UInt64(0)),
+        }
+        .into();
+        let (left_count, right_count) = (counts[0].clone(), counts[1].clone());
+        let (predicate, start, end) = match op {
+            SetOperator::Intersect => (
+                call("and", vec![
+                    call("gt", vec![left_count.clone(), zero.clone()]),
+                    call("gt", vec![right_count.clone(), zero.clone()]),
+                ]),
+                zero,
+                call("if", vec![
+                    call("lt", vec![left_count.clone(), right_count.clone()]),
+                    left_count,
+                    right_count,
+                ]),
+            ),
+            _ => (
+                call("gt", vec![left_count.clone(), right_count.clone()]),
+                right_count,
+                left_count,
+            ),
+        };
+        s_expr = SExpr::create_unary(
+            Arc::new(
+                Filter {
+                    predicates: vec![predicate],
+                }
+                .into(),
+            ),
+            Arc::new(s_expr),
+        );
+
+        let srf = call("unnest", vec![call("range", vec![start, end])]);
+        let srf_type = srf.data_type()?;
+        let srf_index = self
+            .metadata
+            .write()
+            .add_derived_column("unnest".to_string(), srf_type);
+        s_expr = SExpr::create_unary(
+            Arc::new(
+                ProjectSet {
+                    srfs: vec![SrfItem {
+                        scalar: srf,
+                        index: srf_index,
+                    }],
+                }
+                .into(),
+            ),
+            Arc::new(s_expr),
+        );
+
+        Ok((s_expr, new_bind_context))
+    }
+
+    // Append the constant markers to the output columns of a set operation child.
+    fn add_set_operation_markers(
+        &self,
+        bind_context: &mut BindContext,
+        s_expr: SExpr,
+        markers: [Scalar; 2],
+    ) -> Result<SExpr> {
+        let mut items = Vec::with_capacity(markers.len());
+        for (name, value) in ["l", "r"].into_iter().zip(markers) {
+            let data_type = value.as_ref().infer_data_type();
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(name.to_string(), data_type.clone());
+            items.push(ScalarItem {
+                scalar: ConstantExpr { span: None, value }.into(),
+                index,
+            });
+            bind_context.add_column_binding(
+                ColumnBindingBuilder::new(
+                    name.to_string(),
+                    index,
+                    Box::new(data_type),
+                    Visibility::Visible,
+                )
+                .build(),
+            );
+        }
+
+        Ok(SExpr::create_unary(
+            Arc::new(EvalScalar { items }.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
+    fn union_coercion_types(
+        left_context: &BindContext,
+        right_context: &BindContext,
+    ) -> Result<Vec<DataType>> {
+        let mut coercion_types = Vec::with_capacity(left_context.columns.len());
+        for (left_col, right_col) in left_context
+            .columns
+            .iter()
+            .zip(right_context.columns.iter())
+        {
+            if left_col.data_type != right_col.data_type {
+                if let Some(data_type) = common_super_type(
+                    *left_col.data_type.clone(),
+                    *right_col.data_type.clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                ) {
+                    coercion_types.push(data_type);
+                } else {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "SetOperation's types cannot be matched, left column {:?}, type: {:?}, right column {:?}, type: {:?}",
+                        left_col.column_name,
+                        left_col.data_type,
+                        right_col.column_name,
+                        right_col.data_type
+                    )));
+                }
+            } else {
+                coercion_types.push(*left_col.data_type.clone());
+            }
+        }
+        Ok(coercion_types)
+    }
+
     #[allow(clippy::type_complexity)]
     #[allow(clippy::too_many_arguments)]
     fn coercion_union_type(
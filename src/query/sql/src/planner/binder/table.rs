@@ -30,18 +30,24 @@ use databend_common_ast::ast::Indirection;
 use databend_common_ast::ast::Join;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SampleConfig;
+use databend_common_ast::ast::SampleLevel;
 use databend_common_ast::ast::SelectStageOptions;
 use databend_common_ast::ast::SelectStmt;
 use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::SetOperator;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::TableSample;
 use databend_common_ast::ast::TimeTravelPoint;
 use databend_common_ast::ast::UriLocation;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
 use databend_common_catalog::plan::ParquetReadOptions;
+use databend_common_catalog::plan::SampleInfo;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
@@ -69,6 +75,10 @@ use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_types::MetaId;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::read_arrow_schema_async;
+use databend_common_storage::read_avro_schema_async;
+use databend_common_storage::read_orc_schema_async;
 use databend_common_storage::DataOperator;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
@@ -94,6 +104,7 @@ use crate::binder::Binder;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
 use crate::binder::ExprContext;
+use crate::binder::RecursiveCteInfo;
 use crate::binder::Visibility;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
@@ -103,6 +114,8 @@ use crate::plans::CteScan;
 use crate::plans::DummyTableScan;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::Limit;
+use crate::plans::RecursiveCteScan;
 use crate::plans::RelOperator;
 use crate::plans::ScalarItem;
 use crate::plans::Scan;
@@ -183,6 +196,19 @@ impl Binder {
         } else {
             None
         };
+        // Check and bind the working table of recursive cte
+        if let Some(r_cte_info) = self.r_cte_infos.get_mut(&table_name) {
+            if r_cte_info.referenced {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Recursive reference to cte '{table_name}' must not appear more than once"
+                ))
+                .set_span(*span));
+            }
+            r_cte_info.referenced = true;
+            let r_cte_info = r_cte_info.clone();
+            return self.bind_r_cte_scan(bind_context, &table_name, alias, &r_cte_info);
+        }
+
         let mut bind_cte = true;
         if let Some(cte_name) = &bind_context.cte_name {
             // If table name equals to cte name, then skip bind cte and find table from catalog
@@ -918,17 +944,25 @@ impl Binder {
                 travel_point,
                 pivot: _,
                 unpivot: _,
+                sample,
             } => {
-                self.bind_table(
-                    bind_context,
-                    span,
-                    catalog,
-                    database,
-                    table,
-                    alias,
-                    travel_point,
-                )
-                .await
+                let (s_expr, bind_context) = self
+                    .bind_table(
+                        bind_context,
+                        span,
+                        catalog,
+                        database,
+                        table,
+                        alias,
+                        travel_point,
+                    )
+                    .await?;
+                match sample {
+                    Some(sample) => {
+                        Ok((self.bind_table_sample(span, s_expr, sample)?, bind_context))
+                    }
+                    None => Ok((s_expr, bind_context)),
+                }
             }
             TableReference::TableFunction {
                 span,
@@ -1078,9 +1112,42 @@ impl Binder {
                 };
                 StageTable::try_create(info)?
             }
+            FileFormatParams::Avro(..)
+            | FileFormatParams::Orc(..)
+            | FileFormatParams::Arrow(..) => {
+                let operator = init_stage_operator(&stage_info)?;
+                let first_file = match &files_to_copy {
+                    Some(files) if !files.is_empty() => files[0].clone(),
+                    _ => files_info.first_file(&operator).await?,
+                };
+                let schema = match stage_info.file_format_params {
+                    FileFormatParams::Avro(..) => {
+                        read_avro_schema_async(&operator, &first_file.path).await?
+                    }
+                    FileFormatParams::Arrow(..) => {
+                        let arrow_schema =
+                            read_arrow_schema_async(&operator, &first_file.path).await?;
+                        TableSchema::try_from(arrow_schema.as_ref())?
+                    }
+                    _ => {
+                        let arrow_schema =
+                            read_orc_schema_async(&operator, &first_file.path).await?;
+                        TableSchema::try_from(&arrow_schema)?
+                    }
+                };
+                let info = StageTableInfo {
+                    schema: Arc::new(schema),
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    is_select: true,
+                    default_values: None,
+                };
+                StageTable::try_create(info)?
+            }
             _ => {
                 return Err(ErrorCode::Unimplemented(format!(
-                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, NDJson, CSV, and TSV. Provided format: '{}'.",
+                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, NDJson, CSV, TSV, Avro, ORC and Arrow. Provided format: '{}'.",
                     stage_info.file_format_params
                 )));
             }
@@ -1226,6 +1293,42 @@ impl Binder {
         Ok(cte_scan)
     }
 
+    fn bind_r_cte_scan(
+        &mut self,
+        bind_context: &BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+        r_cte_info: &RecursiveCteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let alias_table_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| table_name.to_string());
+        let mut fields = Vec::with_capacity(r_cte_info.columns.len());
+        for (idx, column) in r_cte_info.columns.iter().enumerate() {
+            let mut column = column.clone();
+            column.table_name = Some(alias_table_name.clone());
+            if let Some(col_alias) = alias.as_ref().and_then(|alias| alias.columns.get(idx)) {
+                column.column_name = col_alias.name.clone();
+            }
+            fields.push(DataField::new(
+                column.index.to_string().as_str(),
+                *column.data_type.clone(),
+            ));
+            new_bind_context.add_column_binding(column);
+        }
+        let r_cte_scan = SExpr::create_leaf(Arc::new(
+            RecursiveCteScan {
+                cte_name: table_name.to_string(),
+                fields,
+                stat: r_cte_info.stat_info.clone(),
+            }
+            .into(),
+        ));
+        Ok((r_cte_scan, new_bind_context))
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn bind_cte(
         &mut self,
@@ -1252,9 +1355,24 @@ impl Binder {
             window_definitions: DashMap::new(),
         };
 
-        let (s_expr, mut res_bind_context) = self
-            .bind_query(&mut new_bind_context, &cte_info.query)
-            .await?;
+        let (s_expr, mut res_bind_context) = match &cte_info.query.body {
+            SetExpr::SetOperation(set_operation)
+                if cte_info.recursive && set_operation.op == SetOperator::Union =>
+            {
+                self.bind_r_cte(
+                    span,
+                    &mut new_bind_context,
+                    table_name,
+                    cte_info,
+                    set_operation,
+                )
+                .await?
+            }
+            _ => {
+                self.bind_query(&mut new_bind_context, &cte_info.query)
+                    .await?
+            }
+        };
         let mut cols_alias = cte_info.columns_alias.clone();
         if let Some(alias) = alias {
             for (idx, col_alias) in alias.columns.iter().enumerate() {
@@ -1427,6 +1545,71 @@ impl Binder {
         ))
     }
 
+    /// Attach the sampling information of `TABLESAMPLE` clause to the scan of base table.
+    fn bind_table_sample(&self, span: &Span, s_expr: SExpr, sample: &TableSample) -> Result<SExpr> {
+        let mut scan = match s_expr.plan() {
+            RelOperator::Scan(scan) => scan.clone(),
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "TABLESAMPLE can only be applied to base tables".to_string(),
+                )
+                .set_span(*span));
+            }
+        };
+
+        let probability = match sample.config {
+            SampleConfig::Probability(probability) => {
+                if !(0.0..=100.0).contains(&probability) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Sample probability must be in range [0, 100], but got {probability}"
+                    ))
+                    .set_span(*span));
+                }
+                probability / 100.0
+            }
+            SampleConfig::RowsNum(rows) => {
+                // Without the row count, the sampled rows can't be spread over the table.
+                let num_rows = scan
+                    .statistics
+                    .statistics
+                    .and_then(|stat| stat.num_rows)
+                    .ok_or_else(|| {
+                        ErrorCode::SemanticError(
+                            "TABLESAMPLE (<n> ROWS) is not supported by tables without row count statistics, use TABLESAMPLE (<probability>) instead".to_string(),
+                        )
+                        .set_span(*span)
+                    })?;
+                if rows >= num_rows {
+                    1.0
+                } else {
+                    rows as f64 / num_rows as f64
+                }
+            }
+        };
+        scan.sample = Some(SampleInfo {
+            block_level: sample.level == SampleLevel::Block,
+            probability,
+            seed: sample.seed,
+        });
+        let s_expr = SExpr::create_leaf(Arc::new(scan.into()));
+
+        match sample.config {
+            // The number of sampled rows is not exact, make sure it won't exceed the required one.
+            SampleConfig::RowsNum(rows) => Ok(SExpr::create_unary(
+                Arc::new(
+                    Limit {
+                        before_exchange: false,
+                        limit: Some(rows as usize),
+                        offset: 0,
+                    }
+                    .into(),
+                ),
+                Arc::new(s_expr),
+            )),
+            SampleConfig::Probability(_) => Ok(s_expr),
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn resolve_data_source(
         &self,
@@ -65,6 +65,7 @@ impl Dataframe {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
 
         let settings = query_ctx.get_settings();
@@ -479,6 +480,7 @@ impl Dataframe {
                 travel_point: None,
                 pivot: None,
                 unpivot: None,
+                sample: None,
             };
             table_ref.push(table);
         }
@@ -492,6 +494,7 @@ impl Dataframe {
                     condition: condition.clone(),
                     left: Box::new(left),
                     right: Box::new(right),
+                    match_condition: None,
                 },
             })
             .unwrap();
@@ -14,10 +14,12 @@
 
 use std::sync::Arc;
 
+use databend_common_ast::ast::ColumnID;
 use databend_common_ast::ast::Expr as AExpr;
 use databend_common_ast::parser::parse_comma_separated_exprs;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::walk_expr_mut;
+use databend_common_ast::Dialect;
 use databend_common_base::base::tokio::runtime::Handle;
 use databend_common_base::base::tokio::task::block_in_place;
 use databend_common_catalog::catalog::CATALOG_DEFAULT;
@@ -40,6 +42,7 @@ use databend_common_expression::FunctionContext;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableInfo;
@@ -51,6 +54,7 @@ use crate::binder::ExprContext;
 use crate::planner::binder::BindContext;
 use crate::planner::semantic::NameResolutionContext;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::AsyncFunctionCall;
 use crate::plans::CastExpr;
 use crate::BaseTableColumn;
 use crate::ColumnEntry;
@@ -259,6 +263,15 @@ pub fn parse_default_expr_to_string(
 
     let (mut scalar, data_type) =
         *block_in_place(|| Handle::current().block_on(type_checker.resolve(ast)))?;
+    // The values of `nextval(<sequence>)` are allocated at insertion.
+    if let ScalarExpr::AsyncFunctionCall(AsyncFunctionCall { display_name, .. }) = &scalar {
+        if is_add_column {
+            return Err(ErrorCode::SemanticError(format!(
+                "default expression `{display_name}` is not a valid constant. Please provide a valid constant expression as the default value.",
+            )));
+        }
+        return Ok(display_name.clone());
+    }
     let schema_data_type = DataType::from(field.data_type());
     let is_try = schema_data_type.is_nullable();
     if data_type != schema_data_type {
@@ -356,6 +369,72 @@ pub fn parse_computed_expr_to_string(
     Ok(format!("{:#}", ast))
 }
 
+/// Check the expression of a `CHECK` constraint is a deterministic boolean
+/// expression over the table columns, and return its normalized string.
+pub fn parse_check_constraint_to_string(
+    ctx: Arc<dyn TableContext>,
+    table_schema: TableSchemaRef,
+    ast: &AExpr,
+) -> Result<String> {
+    let settings = Settings::create("".to_string());
+    let mut bind_context = BindContext::new();
+    let mut metadata = Metadata::default();
+    for (index, field) in table_schema.fields().iter().enumerate() {
+        bind_context.add_column_binding(
+            ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(field.data_type().into()),
+                Visibility::Visible,
+            )
+            .build(),
+        );
+        metadata.add_base_table_column(
+            field.name().clone(),
+            field.data_type().clone(),
+            0,
+            None,
+            None,
+            None,
+            None,
+        );
+    }
+
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let mut type_checker = TypeChecker::try_create(
+        &mut bind_context,
+        ctx,
+        &name_resolution_ctx,
+        Arc::new(RwLock::new(metadata)),
+        &[],
+        false,
+    )?;
+
+    let (scalar, data_type) =
+        *block_in_place(|| Handle::current().block_on(type_checker.resolve(ast)))?;
+    if data_type.remove_nullable() != DataType::Boolean {
+        return Err(ErrorCode::SemanticError(format!(
+            "expected check constraint expression have type Boolean, but `{}` has type {}.",
+            ast, data_type,
+        )));
+    }
+    let check_expr = scalar.as_expr()?;
+    if !check_expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        return Err(ErrorCode::SemanticError(format!(
+            "check constraint expression `{}` is not deterministic.",
+            check_expr.sql_display(),
+        )));
+    }
+    let mut ast = ast.clone();
+    walk_expr_mut(
+        &mut IdentifierNormalizer {
+            ctx: &name_resolution_ctx,
+        },
+        &mut ast,
+    );
+    Ok(format!("{:#}", ast))
+}
+
 pub fn parse_lambda_expr(
     ctx: Arc<dyn TableContext>,
     columns: &[(String, DataType)],
@@ -409,10 +488,52 @@ impl Table for DummyTable {
     }
 }
 
+/// The name prefix of the implicit sequences of the AUTOINCREMENT columns.
+pub const AUTO_INCREMENT_SEQUENCE_PREFIX: &str = "_autoinc_";
+
+/// Get the implicit sequences of the AUTOINCREMENT columns, which are owned by the table.
+pub fn auto_increment_sequences(schema: &TableSchema) -> Vec<String> {
+    schema
+        .fields()
+        .iter()
+        .filter_map(field_default_sequence)
+        .filter(|sequence| sequence.starts_with(AUTO_INCREMENT_SEQUENCE_PREFIX))
+        .collect()
+}
+
+/// Get the sequence name if the default expr of the field is `nextval(<sequence>)`.
+pub fn field_default_sequence(field: &TableField) -> Option<String> {
+    let default_expr = field.default_expr()?;
+    let tokens = tokenize_sql(default_expr).ok()?;
+    let ast_exprs = parse_comma_separated_exprs(&tokens, Dialect::PostgreSQL).ok()?;
+    match ast_exprs.as_slice() {
+        [AExpr::FunctionCall { name, args, .. }] if name.name.eq_ignore_ascii_case("nextval") => {
+            match args.as_slice() {
+                [
+                    AExpr::ColumnRef {
+                        database: None,
+                        table: None,
+                        column: ColumnID::Name(ident),
+                        ..
+                    },
+                ] => Some(ident.name.clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 pub fn field_default_value(ctx: Arc<dyn TableContext>, field: &TableField) -> Result<Scalar> {
     let data_type = field.data_type();
     let data_type = DataType::from(data_type);
 
+    // The values of the sequence are allocated at insertion, the existing rows
+    // read the default value of the type.
+    if field_default_sequence(field).is_some() {
+        return Ok(Scalar::default_value(&data_type));
+    }
+
     match field.default_expr() {
         Some(default_expr) => {
             let table: Arc<dyn Table> = Arc::new(DummyTable::default());
@@ -77,9 +77,12 @@ impl Display for FormatContext {
                 RelOperator::ProjectSet(_) => write!(f, "ProjectSet"),
                 RelOperator::CteScan(_) => write!(f, "CteScan"),
                 RelOperator::MaterializedCte(_) => write!(f, "MaterializedCte"),
+                RelOperator::RecursiveCte(_) => write!(f, "RecursiveCte"),
+                RelOperator::RecursiveCteScan(_) => write!(f, "RecursiveCteScan"),
                 RelOperator::ConstantTableScan(_) => write!(f, "ConstantTableScan"),
                 RelOperator::AddRowNumber(_) => write!(f, "AddRowNumber"),
                 RelOperator::Udf(_) => write!(f, "Udf"),
+                RelOperator::AsyncFunction(_) => write!(f, "AsyncFunction"),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
@@ -149,6 +152,7 @@ pub fn format_scalar(scalar: &ScalarExpr) -> String {
         ScalarExpr::UDFLambdaCall(udf) => {
             format!("{}({})", &udf.func_name, format_scalar(&udf.scalar))
         }
+        ScalarExpr::AsyncFunctionCall(async_func) => async_func.display_name.clone(),
     }
 }
 
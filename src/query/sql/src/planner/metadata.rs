@@ -62,6 +62,9 @@ pub struct Metadata {
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
     max_column_position: usize, // for CSV
+    /// The types of parameter placeholders `?` inferred by binding a prepared statement,
+    /// keyed by the start positions of placeholders. `None` if placeholders are not allowed.
+    placeholder_types: Option<HashMap<u32, DataType>>,
 }
 
 impl Metadata {
@@ -395,6 +398,25 @@ impl Metadata {
     pub fn get_max_column_position(&self) -> usize {
         self.max_column_position
     }
+
+    pub fn allow_placeholders(&mut self) {
+        self.placeholder_types = Some(HashMap::default());
+    }
+
+    pub fn placeholders_allowed(&self) -> bool {
+        self.placeholder_types.is_some()
+    }
+
+    /// Record the type of the placeholder starting at `position`, the first inferred type wins.
+    pub fn set_placeholder_type(&mut self, position: u32, data_type: DataType) {
+        if let Some(types) = &mut self.placeholder_types {
+            types.entry(position).or_insert(data_type);
+        }
+    }
+
+    pub fn placeholder_type(&self, position: u32) -> Option<&DataType> {
+        self.placeholder_types.as_ref()?.get(&position)
+    }
 }
 
 #[derive(Clone)]
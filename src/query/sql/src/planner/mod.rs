@@ -17,6 +17,7 @@ mod format;
 mod metadata;
 #[allow(clippy::module_inception)]
 mod planner;
+mod prepared_statement;
 mod semantic;
 
 pub mod binder;
@@ -46,5 +47,6 @@ pub use plans::ScalarExpr;
 pub use plans::DELETE_NAME;
 pub use plans::INSERT_NAME;
 pub use plans::UPDATE_NAME;
+pub use prepared_statement::PreparedStatement;
 pub use semantic::*;
 pub use stream_column::*;
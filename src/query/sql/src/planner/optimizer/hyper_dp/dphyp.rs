@@ -161,6 +161,7 @@ impl DPhpy {
                         | RelOperator::ProjectSet(_)
                         | RelOperator::Window(_)
                         | RelOperator::Udf(_)
+                        | RelOperator::AsyncFunction(_)
                 ) {
                     left_is_subquery = true;
                 }
@@ -173,6 +174,7 @@ impl DPhpy {
                         | RelOperator::ProjectSet(_)
                         | RelOperator::Window(_)
                         | RelOperator::Udf(_)
+                        | RelOperator::AsyncFunction(_)
                 ) {
                     right_is_subquery = true;
                 }
@@ -217,6 +219,7 @@ impl DPhpy {
             | RelOperator::EvalScalar(_)
             | RelOperator::Window(_)
             | RelOperator::Udf(_)
+            | RelOperator::AsyncFunction(_)
             | RelOperator::Filter(_) => {
                 if join_child {
                     // If plan is filter, save it
@@ -255,7 +258,9 @@ impl DPhpy {
             RelOperator::DummyTableScan(_)
             | RelOperator::ConstantTableScan(_)
             | RelOperator::CteScan(_)
-            | RelOperator::MaterializedCte(_) => Ok((s_expr, true)),
+            | RelOperator::MaterializedCte(_)
+            | RelOperator::RecursiveCte(_)
+            | RelOperator::RecursiveCteScan(_) => Ok((s_expr, true)),
         }
     }
 
@@ -706,6 +706,7 @@ pub struct RewriteInfomartion<'a> {
     pub predicates: Option<&'a [ScalarExpr]>,
     pub sort_items: Option<&'a [SortItem]>,
     pub aggregation: Option<AggregationInfo<'a>>,
+    sampled: bool,
 }
 
 impl RewriteInfomartion<'_> {
@@ -721,6 +722,10 @@ impl RewriteInfomartion<'_> {
     }
 
     fn can_apply_index(&self) -> bool {
+        if self.sampled {
+            // Index data is not sampled.
+            return false;
+        }
         if let Some((agg, _)) = self.aggregation {
             if agg.grouping_sets.is_some() {
                 // Grouping sets is not supported.
@@ -846,6 +851,7 @@ fn collect_information(s_expr: &SExpr) -> Result<RewriteInfomartion<'_>> {
             predicates: None,
             sort_items: None,
             aggregation: None,
+            sampled: false,
         };
         collect_information_impl(s_expr.child(0)?, &mut info)?;
         return Ok(info);
@@ -888,6 +894,7 @@ fn collect_information_impl<'a>(
                 info.predicates.replace(&prewhere.predicates);
             }
             info.table_index = scan.table_index;
+            info.sampled = scan.sample.is_some();
             // Finish the recursion.
             Ok(())
         }
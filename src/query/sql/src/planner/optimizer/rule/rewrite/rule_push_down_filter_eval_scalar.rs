@@ -125,6 +125,7 @@ impl RulePushDownFilterEvalScalar {
                             offset: ll.offset,
                             default: new_default,
                             return_type: ll.return_type.clone(),
+                            ignore_null: ll.ignore_null,
                         })
                     }
                     WindowFuncType::NthValue(func) => {
@@ -133,6 +134,7 @@ impl RulePushDownFilterEvalScalar {
                             n: func.n,
                             arg: Box::new(new_arg),
                             return_type: func.return_type.clone(),
+                            ignore_null: func.ignore_null,
                         })
                     }
                     func => func.clone(),
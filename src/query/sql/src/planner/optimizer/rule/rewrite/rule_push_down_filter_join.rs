@@ -163,9 +163,14 @@ pub fn try_push_down_filter_join(
                 left_push_down.push(predicate);
             }
             JoinPredicate::Right(_) => {
+                // Filtering the right side of asof join changes the nearest match.
                 if matches!(
                     join.join_type,
-                    JoinType::Left | JoinType::LeftSingle | JoinType::Full
+                    JoinType::Left
+                        | JoinType::LeftSingle
+                        | JoinType::Full
+                        | JoinType::Asof
+                        | JoinType::LeftAsof
                 ) {
                     original_predicates.push(predicate);
                     continue;
@@ -180,7 +185,9 @@ pub fn try_push_down_filter_join(
                 right,
                 is_equal_op,
             } => {
-                if is_equal_op {
+                if join.join_type.is_asof_join() {
+                    original_predicates.push(predicate);
+                } else if is_equal_op {
                     if matches!(join.join_type, JoinType::Inner | JoinType::Cross) {
                         join.join_type = JoinType::Inner;
                         join.left_conditions.push(left.clone());
@@ -73,6 +73,10 @@ impl Rule for RulePushDownLimitScan {
         if let Some(mut count) = limit.limit {
             let child = s_expr.child(0)?;
             let mut get: Scan = child.plan().clone().try_into()?;
+            if get.sample.is_some() {
+                // The rows are sampled after reading, can't stop reading early.
+                return Ok(());
+            }
             count += limit.offset;
             get.limit = Some(get.limit.map_or(count, |c| cmp::max(c, count)));
             let get = SExpr::create_leaf(Arc::new(RelOperator::Scan(get)));
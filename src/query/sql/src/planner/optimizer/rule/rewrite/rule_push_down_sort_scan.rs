@@ -72,6 +72,10 @@ impl Rule for RulePushDownSortScan {
         let sort: Sort = s_expr.plan().clone().try_into()?;
         let child = s_expr.child(0)?;
         let mut get: Scan = child.plan().clone().try_into()?;
+        if get.sample.is_some() {
+            // The rows are sampled after reading, can't stop reading early.
+            return Ok(());
+        }
         if get.order_by.is_none() {
             get.order_by = Some(sort.items);
         }
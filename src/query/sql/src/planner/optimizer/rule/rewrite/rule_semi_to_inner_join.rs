@@ -141,8 +141,11 @@ fn find_group_by_keys(child: &SExpr, group_by_keys: &mut HashSet<IndexType>) ->
         | RelOperator::DummyTableScan(_)
         | RelOperator::ProjectSet(_)
         | RelOperator::MaterializedCte(_)
+        | RelOperator::RecursiveCte(_)
+        | RelOperator::RecursiveCteScan(_)
         | RelOperator::ConstantTableScan(_)
         | RelOperator::Udf(_)
+        | RelOperator::AsyncFunction(_)
         | RelOperator::Scan(_)
         | RelOperator::CteScan(_)
         | RelOperator::Join(_)
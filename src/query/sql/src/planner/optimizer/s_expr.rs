@@ -362,8 +362,11 @@ impl SExpr {
             | RelOperator::DummyTableScan(_)
             | RelOperator::CteScan(_)
             | RelOperator::AddRowNumber(_)
+            | RelOperator::AsyncFunction(_)
             | RelOperator::Pattern(_)
             | RelOperator::MaterializedCte(_)
+            | RelOperator::RecursiveCte(_)
+            | RelOperator::RecursiveCteScan(_)
             | RelOperator::ConstantTableScan(_) => {}
         };
         for child in &self.children {
@@ -430,7 +433,10 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::AddRowNumber(_)
         | RelOperator::Pattern(_)
         | RelOperator::MaterializedCte(_)
-        | RelOperator::ConstantTableScan(_) => false,
+        | RelOperator::RecursiveCte(_)
+        | RelOperator::RecursiveCteScan(_)
+        | RelOperator::ConstantTableScan(_)
+        | RelOperator::AsyncFunction(_) => false,
         RelOperator::Join(op) => {
             op.left_conditions.iter().any(find_subquery_in_expr)
                 || op.right_conditions.iter().any(find_subquery_in_expr)
@@ -110,6 +110,23 @@ pub struct CopyIntoTablePlan {
 
 impl CopyIntoTablePlan {
     pub async fn collect_files(&self, ctx: &dyn TableContext) -> Result<Vec<StageFileInfo>> {
+        if !self.force {
+            let table = ctx
+                .get_table(
+                    self.catalog_info.catalog_name(),
+                    &self.database_name,
+                    &self.table_name,
+                )
+                .await?;
+            if !table.support_copied_files() {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "COPY INTO {} table '{}' requires FORCE = TRUE, the copied files are not recorded",
+                    table.engine(),
+                    table.name()
+                )));
+            }
+        }
+
         ctx.set_status_info("begin to list files");
         let start = Instant::now();
 
@@ -18,6 +18,9 @@ mod connection;
 mod database;
 mod file_format;
 mod index;
+mod pipe;
+mod procedure;
+mod sequence;
 mod stage;
 mod stream;
 mod table;
@@ -32,6 +35,9 @@ pub use connection::*;
 pub use database::*;
 pub use file_format::*;
 pub use index::*;
+pub use pipe::*;
+pub use procedure::*;
+pub use sequence::*;
 pub use stage::*;
 pub use stream::*;
 pub use table::*;
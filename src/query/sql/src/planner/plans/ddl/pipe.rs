@@ -0,0 +1,117 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_ast::ast::AlterPipeOptions;
+use databend_common_ast::ast::CopyIntoTableSource;
+use databend_common_ast::ast::CopyIntoTableStmt;
+use databend_common_ast::ast::FileLocation;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::TableReference;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_meta_app::principal::UserIdentity;
+
+pub fn pipe_schema() -> DataSchemaRef {
+    Arc::new(DataSchema::new(vec![
+        DataField::new("name", DataType::String),
+        DataField::new("definition", DataType::String),
+        DataField::new("auto_ingest", DataType::Boolean),
+        DataField::new("execution_paused", DataType::Boolean),
+        DataField::new("comment", DataType::String),
+        DataField::new("owner", DataType::String),
+        DataField::new("created_on", DataType::Timestamp),
+        DataField::new("updated_on", DataType::Timestamp),
+        DataField::new("last_executed_on", DataType::Timestamp.wrap_nullable()),
+        DataField::new("last_error", DataType::String.wrap_nullable()),
+    ]))
+}
+
+/// The stage location that the `COPY INTO` statement of pipe loads from,
+/// which is in the form of `<stage_name>[/<path>]`.
+pub fn pipe_stage_location(stmt: &mut CopyIntoTableStmt) -> Option<&mut String> {
+    let location = match &mut stmt.src {
+        CopyIntoTableSource::Location(location) => location,
+        CopyIntoTableSource::Query(query) => match &mut query.body {
+            SetExpr::Select(select) => match select.from.first_mut() {
+                Some(TableReference::Location { location, .. }) => location,
+                _ => return None,
+            },
+            _ => return None,
+        },
+    };
+    match location {
+        FileLocation::Stage(location) => Some(location),
+        FileLocation::Uri(_) => None,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreatePipePlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub auto_ingest: bool,
+    pub comment: String,
+    pub copy_stmt: String,
+    pub owner: UserIdentity,
+}
+
+impl CreatePipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterPipePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub options: AlterPipeOptions,
+}
+
+impl AlterPipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropPipePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DropPipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescPipePlan {
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DescPipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        pipe_schema()
+    }
+}
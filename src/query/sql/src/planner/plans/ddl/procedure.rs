@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::ScriptBlock;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_meta_app::principal::UserDefinedProcedure;
+use databend_common_meta_app::schema::CreateOption;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateProcedurePlan {
+    pub create_option: CreateOption,
+    pub tenant: String,
+    pub procedure: UserDefinedProcedure,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropProcedurePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallProcedurePlan {
+    pub procedure: UserDefinedProcedure,
+    /// The arguments are cast to the types of the parameters when the procedure is called.
+    pub args: Vec<String>,
+}
+
+impl CallProcedurePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        script_result_schema()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecuteImmediatePlan {
+    pub script: ScriptBlock,
+}
+
+impl ExecuteImmediatePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        script_result_schema()
+    }
+}
+
+/// The value returned by a script is displayed as a string, a result set is rendered
+/// as a table.
+fn script_result_schema() -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![DataField::new("Result", DataType::String)])
+}
@@ -27,6 +27,7 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::storage::StorageParams;
@@ -52,6 +53,10 @@ pub struct CreateTablePlan {
     pub options: TableOptions,
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
+    /// The implicit sequences of the AUTOINCREMENT columns.
+    pub auto_increment_sequences: Vec<String>,
+    /// The PRIMARY KEY, UNIQUE and CHECK constraints keyed by constraint name.
+    pub constraints: BTreeMap<String, TableConstraint>,
     pub as_select: Option<Box<Plan>>,
 }
 
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::plans::CreateTablePlan;
+use crate::plans::Plan;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateViewPlan {
     pub if_not_exists: bool,
@@ -41,3 +44,33 @@ pub struct DropViewPlan {
     pub database: String,
     pub view_name: String,
 }
+
+#[derive(Clone, Debug)]
+pub struct CreateMaterializedViewPlan {
+    /// The materialized view is stored as a fuse table, the view query is kept in
+    /// the table options.
+    pub create_table: CreateTablePlan,
+    /// The plan of the view query, used to check the privileges of the source tables.
+    pub query: Box<Plan>,
+    /// The database and name of the source table, if the view can be refreshed
+    /// incrementally from a stream on it.
+    pub incremental_source: Option<(String, String)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropMaterializedViewPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshMaterializedViewPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+    pub full: bool,
+}
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::OnErrorMode;
@@ -59,6 +60,9 @@ pub struct Insert {
     pub table_id: MetaId,
     pub schema: TableSchemaRef,
     pub overwrite: bool,
+    /// The values of `INSERT OVERWRITE ... PARTITION (...)` with only static partition columns,
+    /// the partition is overwritten even if no rows are inserted.
+    pub overwrite_partition: Option<Vec<(String, Scalar)>>,
     pub source: InsertInputSource,
 }
 
@@ -89,6 +93,7 @@ impl std::fmt::Debug for Insert {
             .field("table", &self.table)
             .field("schema", &self.schema)
             .field("overwrite", &self.overwrite)
+            .field("overwrite_partition", &self.overwrite_partition)
             .finish()
     }
 }
@@ -14,6 +14,7 @@
 
 mod add_row_number;
 mod aggregate;
+mod async_function;
 mod call;
 mod constant_table_scan;
 mod copy_into_table;
@@ -40,6 +41,8 @@ mod plan;
 mod presign;
 mod project_set;
 mod recluster_table;
+mod recursive_cte;
+mod recursive_cte_scan;
 mod replace;
 mod revert_table;
 mod scalar_expr;
@@ -53,6 +56,7 @@ mod window;
 
 pub use add_row_number::AddRowNumber;
 pub use aggregate::*;
+pub use async_function::AsyncFunction;
 pub use call::CallPlan;
 pub use constant_table_scan::ConstantTableScan;
 pub use copy_into_location::*;
@@ -84,6 +88,8 @@ pub use plan::*;
 pub use presign::*;
 pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
+pub use recursive_cte::RecursiveCte;
+pub use recursive_cte_scan::RecursiveCteScan;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
 pub use scalar_expr::*;
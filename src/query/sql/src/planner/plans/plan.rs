@@ -30,6 +30,7 @@ use crate::plans::copy_into_location::CopyIntoLocationPlan;
 use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterNetworkPolicyPlan;
 use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::AlterPipePlan;
 use crate::plans::AlterShareTenantsPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterTaskPlan;
@@ -38,6 +39,7 @@ use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CallProcedurePlan;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::CreateCatalogPlan;
@@ -46,9 +48,13 @@ use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateDatamaskPolicyPlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateNetworkPolicyPlan;
 use crate::plans::CreatePasswordPolicyPlan;
+use crate::plans::CreatePipePlan;
+use crate::plans::CreateProcedurePlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateSequencePlan;
 use crate::plans::CreateShareEndpointPlan;
 use crate::plans::CreateSharePlan;
 use crate::plans::CreateStagePlan;
@@ -64,6 +70,7 @@ use crate::plans::DescConnectionPlan;
 use crate::plans::DescDatamaskPolicyPlan;
 use crate::plans::DescNetworkPolicyPlan;
 use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DescPipePlan;
 use crate::plans::DescSharePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DescribeTaskPlan;
@@ -73,9 +80,13 @@ use crate::plans::DropDatabasePlan;
 use crate::plans::DropDatamaskPolicyPlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropMaterializedViewPlan;
 use crate::plans::DropNetworkPolicyPlan;
 use crate::plans::DropPasswordPolicyPlan;
+use crate::plans::DropPipePlan;
+use crate::plans::DropProcedurePlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropSequencePlan;
 use crate::plans::DropShareEndpointPlan;
 use crate::plans::DropSharePlan;
 use crate::plans::DropStagePlan;
@@ -88,6 +99,7 @@ use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::DropVirtualColumnPlan;
+use crate::plans::ExecuteImmediatePlan;
 use crate::plans::ExecuteTaskPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::GrantPrivilegePlan;
@@ -101,6 +113,7 @@ use crate::plans::OptimizeTablePlan;
 use crate::plans::PresignPlan;
 use crate::plans::ReclusterTablePlan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshMaterializedViewPlan;
 use crate::plans::RefreshVirtualColumnPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
@@ -218,6 +231,11 @@ pub enum Plan {
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
 
+    // Materialized views
+    CreateMaterializedView(Box<CreateMaterializedViewPlan>),
+    DropMaterializedView(Box<DropMaterializedViewPlan>),
+    RefreshMaterializedView(Box<RefreshMaterializedViewPlan>),
+
     // Streams
     CreateStream(Box<CreateStreamPlan>),
     DropStream(Box<DropStreamPlan>),
@@ -318,6 +336,27 @@ pub enum Plan {
     DescribeTask(Box<DescribeTaskPlan>),
     ShowTasks(Box<ShowTasksPlan>),
     ExecuteTask(Box<ExecuteTaskPlan>),
+
+    // Pipe
+    CreatePipe(Box<CreatePipePlan>),
+    AlterPipe(Box<AlterPipePlan>),
+    DropPipe(Box<DropPipePlan>),
+    DescPipe(Box<DescPipePlan>),
+
+    // Sequence
+    CreateSequence(Box<CreateSequencePlan>),
+    DropSequence(Box<DropSequencePlan>),
+
+    // Procedure
+    CreateProcedure(Box<CreateProcedurePlan>),
+    DropProcedure(Box<DropProcedurePlan>),
+    CallProcedure(Box<CallProcedurePlan>),
+    ExecuteImmediate(Box<ExecuteImmediatePlan>),
+
+    // Transaction
+    Begin,
+    Commit,
+    Abort,
 }
 
 #[derive(Clone, Debug)]
@@ -374,6 +413,27 @@ impl Plan {
             _ => QueryKind::Other,
         }
     }
+
+    /// Whether the plan can run in an explicit transaction. The mutations of fuse tables
+    /// are buffered until COMMIT, while the DDL statements are not transactional.
+    pub fn is_allowed_in_txn(&self) -> bool {
+        match self {
+            // PURGE removes the historical data which is still needed if rolled back
+            Plan::OptimizeTable(_) => false,
+            Plan::Begin
+            | Plan::Commit
+            | Plan::Abort
+            | Plan::UseDatabase(_)
+            | Plan::SetVariable(_)
+            | Plan::UnSetVariable(_)
+            | Plan::SetRole(_)
+            | Plan::SetSecondaryRoles(_)
+            | Plan::ShowCreateDatabase(_)
+            | Plan::ShowCreateTable(_)
+            | Plan::DescribeTable(_) => true,
+            _ => self.kind() != QueryKind::Other,
+        }
+    }
 }
 
 impl Display for Plan {
@@ -429,10 +489,14 @@ impl Plan {
             Plan::DescribeTask(plan) => plan.schema(),
             Plan::ShowTasks(plan) => plan.schema(),
             Plan::ExecuteTask(plan) => plan.schema(),
+            Plan::DescPipe(plan) => plan.schema(),
 
             Plan::DescConnection(plan) => plan.schema(),
             Plan::ShowConnections(plan) => plan.schema(),
 
+            Plan::CallProcedure(plan) => plan.schema(),
+            Plan::ExecuteImmediate(plan) => plan.schema(),
+
             other => {
                 debug_assert!(!other.has_result_set());
                 Arc::new(DataSchema::empty())
@@ -471,9 +535,12 @@ impl Plan {
                 | Plan::CopyIntoLocation(_)
                 | Plan::ShowTasks(_)
                 | Plan::DescribeTask(_)
+                | Plan::DescPipe(_)
                 | Plan::DescConnection(_)
                 | Plan::ShowConnections(_)
                 | Plan::MergeInto(_)
+                | Plan::CallProcedure(_)
+                | Plan::ExecuteImmediate(_)
         )
     }
 }
@@ -32,6 +32,9 @@ pub struct Replace {
     pub schema: TableSchemaRef,
     pub source: InsertInputSource,
     pub delete_when: Option<Expr>,
+    /// Set when an INSERT into a table with an enforced primary key is executed as a replace,
+    /// the conflicting rows are rejected instead of replaced.
+    pub error_on_conflict: bool,
 }
 
 impl PartialEq for Replace {
@@ -45,6 +45,7 @@ pub enum ScalarExpr {
     SubqueryExpr(SubqueryExpr),
     UDFServerCall(UDFServerCall),
     UDFLambdaCall(UDFLambdaCall),
+    AsyncFunctionCall(AsyncFunctionCall),
 }
 
 impl ScalarExpr {
@@ -119,6 +120,7 @@ impl ScalarExpr {
             ScalarExpr::SubqueryExpr(expr) => expr.span,
             ScalarExpr::UDFServerCall(expr) => expr.span,
             ScalarExpr::UDFLambdaCall(expr) => expr.span,
+            ScalarExpr::AsyncFunctionCall(expr) => expr.span,
             _ => None,
         }
     }
@@ -150,6 +152,10 @@ impl ScalarExpr {
                 self.evaluable = false;
                 Ok(())
             }
+            fn visit_async_function_call(&mut self, _: &'a AsyncFunctionCall) -> Result<()> {
+                self.evaluable = false;
+                Ok(())
+            }
         }
 
         let mut visitor = EvaluableVisitor { evaluable: true };
@@ -364,6 +370,25 @@ impl TryFrom<ScalarExpr> for UDFLambdaCall {
     }
 }
 
+impl From<AsyncFunctionCall> for ScalarExpr {
+    fn from(v: AsyncFunctionCall) -> Self {
+        Self::AsyncFunctionCall(v)
+    }
+}
+
+impl TryFrom<ScalarExpr> for AsyncFunctionCall {
+    type Error = ErrorCode;
+    fn try_from(value: ScalarExpr) -> Result<Self> {
+        if let ScalarExpr::AsyncFunctionCall(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast Scalar to AsyncFunctionCall",
+            ))
+        }
+    }
+}
+
 #[derive(Clone, Debug, Educe)]
 #[educe(PartialEq, Eq, Hash)]
 pub struct BoundColumnRef {
@@ -467,6 +492,8 @@ pub struct LagLeadFunction {
     pub offset: u64,
     pub default: Option<Box<ScalarExpr>>,
     pub return_type: Box<DataType>,
+    /// Skip the NULL values of `arg` when counting the offset, set by `IGNORE NULLS`.
+    pub ignore_null: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -479,6 +506,8 @@ pub struct NthValueFunction {
     pub n: Option<u64>,
     pub arg: Box<ScalarExpr>,
     pub return_type: Box<DataType>,
+    /// Only count the non-NULL values of `arg` in the frame, set by `IGNORE NULLS`.
+    pub ignore_null: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -609,6 +638,20 @@ pub struct UDFLambdaCall {
     pub scalar: Box<ScalarExpr>,
 }
 
+/// A function that can't be evaluated row by row, it is evaluated in an async
+/// transform for each data block, e.g. `nextval` allocates values from meta service.
+#[derive(Clone, Debug, Educe)]
+#[educe(PartialEq, Eq, Hash)]
+pub struct AsyncFunctionCall {
+    #[educe(Hash(ignore), PartialEq(ignore), Eq(ignore))]
+    pub span: Span,
+    pub func_name: String,
+    pub display_name: String,
+    pub return_type: Box<DataType>,
+    /// The constant arguments, e.g. the sequence name of `nextval`.
+    pub arguments: Vec<String>,
+}
+
 pub trait Visitor<'a>: Sized {
     fn visit(&mut self, expr: &'a ScalarExpr) -> Result<()> {
         walk_expr(self, expr)
@@ -661,6 +704,10 @@ pub trait Visitor<'a>: Sized {
     fn visit_udf_lambda_call(&mut self, udf: &'a UDFLambdaCall) -> Result<()> {
         self.visit(&udf.scalar)
     }
+
+    fn visit_async_function_call(&mut self, _async_func: &'a AsyncFunctionCall) -> Result<()> {
+        Ok(())
+    }
 }
 
 // Any `Visitor` which needs to access parent `ScalarExpr` can implement `VisitorWithParent`
@@ -813,6 +860,15 @@ pub trait VisitorWithParent<'a>: Sized {
     ) -> Result<()> {
         self.visit_with_parent(Some(current), &udf.scalar)
     }
+
+    fn visit_async_function_call(
+        &mut self,
+        _parent: Option<&'a ScalarExpr>,
+        _current: &'a ScalarExpr,
+        _async_func: &'a AsyncFunctionCall,
+    ) -> Result<()> {
+        Ok(())
+    }
 }
 
 pub fn walk_expr_with_parent<'a, V: VisitorWithParent<'a>>(
@@ -837,6 +893,9 @@ pub fn walk_expr_with_parent<'a, V: VisitorWithParent<'a>>(
         ScalarExpr::SubqueryExpr(subquery) => visitor.visit_subquery(parent, current, subquery),
         ScalarExpr::UDFServerCall(udf) => visitor.visit_udf_server_call(parent, current, udf),
         ScalarExpr::UDFLambdaCall(udf) => visitor.visit_udf_lambda_call(parent, current, udf),
+        ScalarExpr::AsyncFunctionCall(async_func) => {
+            visitor.visit_async_function_call(parent, current, async_func)
+        }
     }
 }
 
@@ -852,6 +911,7 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expr: &'a ScalarExpr) -> R
         ScalarExpr::SubqueryExpr(expr) => visitor.visit_subquery(expr),
         ScalarExpr::UDFServerCall(expr) => visitor.visit_udf_server_call(expr),
         ScalarExpr::UDFLambdaCall(expr) => visitor.visit_udf_lambda_call(expr),
+        ScalarExpr::AsyncFunctionCall(expr) => visitor.visit_async_function_call(expr),
     }
 }
 
@@ -932,6 +992,10 @@ pub trait VisitorMut<'a>: Sized {
     fn visit_udf_lambda_call(&mut self, udf: &'a mut UDFLambdaCall) -> Result<()> {
         self.visit(&mut udf.scalar)
     }
+
+    fn visit_async_function_call(&mut self, _async_func: &'a mut AsyncFunctionCall) -> Result<()> {
+        Ok(())
+    }
 }
 
 pub fn walk_expr_mut<'a, V: VisitorMut<'a>>(
@@ -949,6 +1013,7 @@ pub fn walk_expr_mut<'a, V: VisitorMut<'a>>(
         ScalarExpr::SubqueryExpr(expr) => visitor.visit_subquery_expr(expr),
         ScalarExpr::UDFServerCall(expr) => visitor.visit_udf_server_call(expr),
         ScalarExpr::UDFLambdaCall(expr) => visitor.visit_udf_lambda_call(expr),
+        ScalarExpr::AsyncFunctionCall(expr) => visitor.visit_async_function_call(expr),
     }
 }
 
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use databend_common_catalog::plan::SampleInfo;
 use databend_common_catalog::statistics::BasicColumnStatistics;
 use databend_common_catalog::table::TableStatistics;
 use databend_common_catalog::table_context::TableContext;
@@ -97,6 +98,7 @@ pub struct Scan {
     pub prewhere: Option<Prewhere>,
     pub agg_index: Option<AggIndexInfo>,
     pub change_type: Option<ChangeType>,
+    pub sample: Option<SampleInfo>,
 
     pub statistics: Statistics,
 }
@@ -124,6 +126,7 @@ impl Scan {
             prewhere,
             agg_index: self.agg_index.clone(),
             change_type: self.change_type.clone(),
+            sample: self.sample.clone(),
         }
     }
 
@@ -253,9 +256,13 @@ impl Operator for Scan {
             (Some(precise_cardinality), None) => precise_cardinality as f64,
             (_, _) => 0.0,
         };
+        let cardinality = match &self.sample {
+            Some(sample) => cardinality * sample.probability,
+            None => cardinality,
+        };
 
-        // If prewhere is not none, we can't get precise cardinality
-        let precise_cardinality = if self.prewhere.is_none() {
+        // If prewhere or sample is not none, we can't get precise cardinality
+        let precise_cardinality = if self.prewhere.is_none() && self.sample.is_none() {
             precise_cardinality
         } else {
             None
@@ -0,0 +1,266 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::InsertSource;
+use databend_common_ast::ast::InsertStmt;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::ReplaceStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::walk_expr_mut;
+use databend_common_ast::walk_statement_mut;
+use databend_common_ast::Dialect;
+use databend_common_ast::VisitorMut;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::Span;
+use databend_common_expression::types::DataType;
+
+use crate::plans::Plan;
+use crate::MetadataRef;
+
+/// A statement with the parameter placeholders `?`, which is parsed only once when it's
+/// prepared. Each execution replaces the placeholders with the parameters, then the
+/// statement is planned by `Planner::plan_stmt`.
+#[derive(Debug, Clone)]
+pub struct PreparedStatement {
+    stmt: Statement,
+    // The start positions of the placeholders in the statement, in the order of parameters.
+    placeholders: Vec<u32>,
+    // The positions of the placeholders in the raw `VALUES` of `INSERT` and `REPLACE`,
+    // relative to the beginning of the values. They are bound after the placeholders above.
+    values_placeholders: Vec<Range<usize>>,
+    // The column indexes of the placeholders in `VALUES`, `None` if the placeholder is not a
+    // whole value of the row, such as `(? + 1)`.
+    values_columns: Vec<Option<usize>>,
+}
+
+impl PreparedStatement {
+    pub fn try_create(query: &str, dialect: Dialect) -> Result<PreparedStatement> {
+        let tokens = tokenize_sql(query)?;
+        let (mut stmt, _) = parse_sql(&tokens, dialect)?;
+
+        let mut collector = PlaceholderCollector::default();
+        walk_statement_mut(&mut collector, &mut stmt);
+        let mut placeholders = collector.positions;
+        placeholders.sort_unstable();
+
+        let values_placeholders = match values_start(&stmt) {
+            Some(start) => tokens
+                .iter()
+                .filter(|token| token.kind == TokenKind::Placeholder && token.span.start() >= start)
+                .map(|token| token.span.start() - start..token.span.end() - start)
+                .collect(),
+            None => vec![],
+        };
+        let values_columns = match &stmt {
+            Statement::Insert(InsertStmt {
+                source: InsertSource::Values { rest_str, .. },
+                ..
+            })
+            | Statement::Replace(ReplaceStmt {
+                source: InsertSource::Values { rest_str, .. },
+                ..
+            }) => values_columns(rest_str, &values_placeholders),
+            _ => vec![],
+        };
+
+        Ok(PreparedStatement {
+            stmt,
+            placeholders,
+            values_placeholders,
+            values_columns,
+        })
+    }
+
+    /// The statement with the placeholders, which can be planned by `Planner::plan_prepared_stmt`.
+    pub fn statement(&self) -> &Statement {
+        &self.stmt
+    }
+
+    pub fn num_params(&self) -> usize {
+        self.placeholders.len() + self.values_placeholders.len()
+    }
+
+    /// The types of parameters in order, inferred by `Planner::plan_prepared_stmt`.
+    /// `None` if the type of a parameter can't be inferred.
+    pub fn param_types(&self, plan: &Plan, metadata: &MetadataRef) -> Vec<Option<DataType>> {
+        let metadata = metadata.read();
+        let mut types = self
+            .placeholders
+            .iter()
+            .map(|position| metadata.placeholder_type(*position).cloned())
+            .collect::<Vec<_>>();
+
+        let schema = match plan {
+            Plan::Insert(insert) => Some(&insert.schema),
+            Plan::Replace(replace) => Some(&replace.schema),
+            _ => None,
+        };
+        types.extend(self.values_columns.iter().map(|column| {
+            let field = schema?.fields().get((*column)?)?;
+            Some(DataType::from(field.data_type()))
+        }));
+        types
+    }
+
+    /// Replace the placeholders with the parameters, returns the statement ready to be planned.
+    pub fn bind(&self, params: Vec<Expr>) -> Result<Statement> {
+        if params.len() != self.num_params() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Prepared statement expects {} parameters, but got {}",
+                self.num_params(),
+                params.len()
+            )));
+        }
+
+        let mut stmt = self.stmt.clone();
+        let values_params = params[self.placeholders.len()..].to_vec();
+        let mut replacer = PlaceholderReplacer {
+            positions: &self.placeholders,
+            params,
+        };
+        walk_statement_mut(&mut replacer, &mut stmt);
+
+        if !values_params.is_empty() {
+            let source = match &mut stmt {
+                Statement::Insert(insert) => &mut insert.source,
+                Statement::Replace(replace) => &mut replace.source,
+                _ => unreachable!(),
+            };
+            if let InsertSource::Values { rest_str, .. } = source {
+                let mut values = String::with_capacity(rest_str.len());
+                let mut last = 0;
+                for (range, param) in self.values_placeholders.iter().zip(values_params) {
+                    values.push_str(&rest_str[last..range.start]);
+                    values.push_str(&param.to_string());
+                    last = range.end;
+                }
+                values.push_str(&rest_str[last..]);
+                *rest_str = values;
+            }
+        }
+
+        Ok(stmt)
+    }
+
+    /// Bind all the parameters as `NULL`, used to describe the result schema on prepare.
+    pub fn bind_nulls(&self) -> Result<Statement> {
+        let nulls = (0..self.num_params())
+            .map(|_| Expr::Literal {
+                span: None,
+                lit: Literal::Null,
+            })
+            .collect();
+        self.bind(nulls)
+    }
+}
+
+// Find the column index of each placeholder in the rows of raw `VALUES`, e.g. `(1, ?), (?, 2)`.
+fn values_columns(values: &str, placeholders: &[Range<usize>]) -> Vec<Option<usize>> {
+    let bytes = values.as_bytes();
+    let mut columns = Vec::with_capacity(placeholders.len());
+    let mut placeholders = placeholders.iter().peekable();
+    let mut depth = 0;
+    let mut column = 0;
+    let mut quote = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        let Some(placeholder) = placeholders.peek() else {
+            break;
+        };
+        let c = bytes[i];
+        if let Some(q) = quote {
+            if c == b'\\' {
+                i += 1;
+            } else if c == q {
+                quote = None;
+            }
+        } else if i == placeholder.start {
+            let before = values[..i].trim_end().bytes().last();
+            let after = values[placeholder.end..].trim_start().bytes().next();
+            let whole_value = depth == 1
+                && matches!(before, Some(b'(' | b','))
+                && matches!(after, Some(b')' | b','));
+            columns.push(whole_value.then_some(column));
+            placeholders.next();
+            i = placeholder.end;
+            continue;
+        } else {
+            match c {
+                b'\'' | b'"' | b'`' => quote = Some(c),
+                b'(' | b'[' | b'{' => {
+                    depth += 1;
+                    if depth == 1 {
+                        column = 0;
+                    }
+                }
+                b')' | b']' | b'}' => depth -= 1,
+                b',' if depth == 1 => column += 1,
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+    columns.resize(placeholders.len() + columns.len(), None);
+    columns
+}
+
+// The start position of `VALUES` data for `INSERT` and `REPLACE` statement.
+fn values_start(stmt: &Statement) -> Option<usize> {
+    let source = match stmt {
+        Statement::Insert(insert) => &insert.source,
+        Statement::Replace(replace) => &replace.source,
+        _ => return None,
+    };
+    match source {
+        InsertSource::Values { start, .. } => Some(*start),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct PlaceholderCollector {
+    positions: Vec<u32>,
+}
+
+impl VisitorMut for PlaceholderCollector {
+    fn visit_placeholder(&mut self, span: Span) {
+        if let Some(span) = span {
+            self.positions.push(span.start);
+        }
+    }
+}
+
+struct PlaceholderReplacer<'a> {
+    positions: &'a [u32],
+    params: Vec<Expr>,
+}
+
+impl VisitorMut for PlaceholderReplacer<'_> {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Placeholder { span: Some(span) } = expr {
+            if let Ok(index) = self.positions.binary_search(&span.start) {
+                *expr = self.params[index].clone();
+            }
+            return;
+        }
+        walk_expr_mut(self, expr);
+    }
+}
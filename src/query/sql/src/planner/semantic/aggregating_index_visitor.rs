@@ -24,7 +24,7 @@ use databend_common_ast::ast::SelectStmt;
 use databend_common_ast::ast::SelectTarget;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::TableReference;
-use databend_common_ast::ast::Window;
+use databend_common_ast::ast::WindowDesc;
 use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::walk_expr;
@@ -223,7 +223,7 @@ impl<'ast> Visitor<'ast> for AggregatingIndexChecker {
         name: &'ast Identifier,
         args: &'ast [Expr],
         params: &'ast [Expr],
-        _over: &'ast Option<Window>,
+        _over: &'ast Option<WindowDesc>,
         _lambda: &'ast Option<Lambda>,
     ) {
         if self.not_support {
@@ -260,6 +260,18 @@ impl ScalarExpr {
                 let scalar = &udf.scalar;
                 scalar.as_raw_expr()
             }
+            ScalarExpr::AsyncFunctionCall(async_func) => RawExpr::ColumnRef {
+                span: None,
+                id: ColumnBindingBuilder::new(
+                    async_func.display_name.clone(),
+                    usize::MAX,
+                    Box::new((*async_func.return_type).clone()),
+                    Visibility::Visible,
+                )
+                .build(),
+                data_type: (*async_func.return_type).clone(),
+                display_name: async_func.display_name.clone(),
+            },
         }
     }
 
@@ -14,6 +14,7 @@
 
 mod aggregate_rewriter;
 mod aggregating_index_visitor;
+mod async_function_rewriter;
 mod distinct_to_groupby;
 mod grouping_check;
 mod lowering;
@@ -28,6 +29,7 @@ pub use aggregate_rewriter::AggregateRewriter;
 pub use aggregating_index_visitor::AggregatingIndexChecker;
 pub use aggregating_index_visitor::AggregatingIndexRewriter;
 pub use aggregating_index_visitor::RefreshAggregatingIndexRewriter;
+pub(crate) use async_function_rewriter::AsyncFunctionRewriter;
 pub use distinct_to_groupby::DistinctToGroupBy;
 pub use grouping_check::GroupingChecker;
 pub use lowering::*;
@@ -73,6 +73,8 @@ use databend_common_functions::GENERAL_WINDOW_FUNCTIONS;
 use databend_common_meta_app::principal::LambdaUDF;
 use databend_common_meta_app::principal::UDFDefinition;
 use databend_common_meta_app::principal::UDFServer;
+use databend_common_meta_app::schema::GetSequenceReq;
+use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_users::UserApiProvider;
 use indexmap::IndexMap;
 use itertools::Itertools;
@@ -96,6 +98,7 @@ use crate::planner::semantic::lowering::TypeCheck;
 use crate::plans::Aggregate;
 use crate::plans::AggregateFunction;
 use crate::plans::AggregateMode;
+use crate::plans::AsyncFunctionCall;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::ComparisonOp;
@@ -561,6 +564,12 @@ impl<'a> TypeChecker<'a> {
                 expr, target_type, ..
             } => {
                 let box (scalar, data_type) = self.resolve(expr).await?;
+                if let Expr::Placeholder { span: Some(span) } = expr.as_ref() {
+                    let placeholder_type = DataType::from(&resolve_type_name(target_type, true)?);
+                    self.metadata
+                        .write()
+                        .set_placeholder_type(span.start, placeholder_type);
+                }
                 if target_type == &TypeName::Variant {
                     if let Some(result) = self
                         .resolve_cast_to_variant(expr.span(), &data_type, &scalar, false)
@@ -717,6 +726,9 @@ impl<'a> TypeChecker<'a> {
                 if !is_builtin_function(func_name)
                     && !Self::all_sugar_functions().contains(&func_name)
                 {
+                    if func_name == "nextval" {
+                        return self.resolve_nextval(*span, args).await;
+                    }
                     if let Some(udf) = self.resolve_udf(*span, func_name, args).await? {
                         return Ok(udf);
                     } else {
@@ -767,6 +779,24 @@ impl<'a> TypeChecker<'a> {
                     )
                     .set_span(*span));
                 }
+                // check null treatment legal
+                if window.as_ref().is_some_and(|w| w.ignore_nulls.is_some())
+                    && !matches!(
+                        func_name,
+                        "lag"
+                            | "lead"
+                            | "first_value"
+                            | "first"
+                            | "last_value"
+                            | "last"
+                            | "nth_value"
+                    )
+                {
+                    return Err(ErrorCode::SemanticError(
+                        "only lag, lead, first_value, last_value and nth_value allow IGNORE NULLS or RESPECT NULLS",
+                    )
+                    .set_span(*span));
+                }
                 // check lambda function legal
                 if lambda.is_some() && !GENERAL_LAMBDA_FUNCTIONS.contains(&func_name) {
                     return Err(ErrorCode::SemanticError(
@@ -818,12 +848,13 @@ impl<'a> TypeChecker<'a> {
                             "window function {func_name} can only be used in window clause"
                         )));
                     }
+                    let window = window.as_ref().unwrap();
+                    let ignore_null = window.ignore_nulls.unwrap_or(false);
                     let func = self
-                        .resolve_general_window_function(*span, func_name, &args)
+                        .resolve_general_window_function(*span, func_name, &args, ignore_null)
                         .await?;
-                    let window = window.as_ref().unwrap();
                     let display_name = format!("{:#}", expr);
-                    self.resolve_window(*span, display_name, window, func)
+                    self.resolve_window(*span, display_name, &window.window, func)
                         .await?
                 } else if AggregateFunctionFactory::instance().contains(func_name) {
                     let mut new_params = Vec::with_capacity(params.len());
@@ -857,7 +888,7 @@ impl<'a> TypeChecker<'a> {
                         // aggregate window function
                         let display_name = format!("{:#}", expr);
                         let func = WindowFuncType::Aggregate(new_agg_func);
-                        self.resolve_window(*span, display_name, window, func)
+                        self.resolve_window(*span, display_name, &window.window, func)
                             .await?
                     } else {
                         // aggregate function
@@ -1005,12 +1036,7 @@ impl<'a> TypeChecker<'a> {
                 span, kind, expr, ..
             } => self.resolve_extract_expr(*span, kind, expr).await?,
 
-            Expr::Interval { span, .. } => {
-                return Err(ErrorCode::SemanticError(
-                    "Unsupported interval expression yet".to_string(),
-                )
-                .set_span(*span));
-            }
+            Expr::Interval { span, expr, unit } => self.resolve_interval(*span, expr, unit).await?,
             Expr::DateAdd {
                 span,
                 unit,
@@ -1065,6 +1091,20 @@ impl<'a> TypeChecker<'a> {
             Expr::Map { span, kvs, .. } => self.resolve_map(*span, kvs).await?,
 
             Expr::Tuple { span, exprs, .. } => self.resolve_tuple(*span, exprs).await?,
+
+            Expr::Placeholder { span } => {
+                if !self.metadata.read().placeholders_allowed() {
+                    return Err(ErrorCode::SemanticError(
+                        "placeholder `?` is only allowed in prepared statements",
+                    )
+                    .set_span(*span));
+                }
+                let scalar = ConstantExpr {
+                    span: *span,
+                    value: Scalar::Null,
+                };
+                Box::new((scalar.into(), DataType::Null))
+            }
         };
 
         Ok(Box::new((scalar, data_type)))
@@ -1313,6 +1353,27 @@ impl<'a> TypeChecker<'a> {
                     end_bound: WindowFuncFrameBound::Following(None),
                 });
             }
+            WindowFuncType::LagLead(lag_lead) if lag_lead.ignore_null && lag_lead.is_lag => {
+                // The preceding non-NULL values are collected row by row while the frame slides.
+                return Ok(WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Rows,
+                    start_bound: WindowFuncFrameBound::Preceding(Some(Scalar::Number(
+                        NumberScalar::UInt64(1),
+                    ))),
+                    end_bound: WindowFuncFrameBound::Preceding(Some(Scalar::Number(
+                        NumberScalar::UInt64(1),
+                    ))),
+                });
+            }
+            WindowFuncType::LagLead(lag_lead) if lag_lead.ignore_null => {
+                return Ok(WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Rows,
+                    start_bound: WindowFuncFrameBound::Following(Some(Scalar::Number(
+                        NumberScalar::UInt64(1),
+                    ))),
+                    end_bound: WindowFuncFrameBound::Following(None),
+                });
+            }
             WindowFuncType::LagLead(lag_lead) if lag_lead.is_lag => {
                 return Ok(WindowFuncFrame {
                     units: WindowFuncFrameUnits::Rows,
@@ -1386,6 +1447,7 @@ impl<'a> TypeChecker<'a> {
         span: Span,
         func_name: &str,
         args: &[&Expr],
+        ignore_null: bool,
     ) -> Result<WindowFuncType> {
         if matches!(
             self.bind_context.expr_context,
@@ -1430,12 +1492,22 @@ impl<'a> TypeChecker<'a> {
 
         match func_name {
             "lag" | "lead" => {
-                self.resolve_lag_lead_window_function(func_name, &arguments, &arg_types)
-                    .await
+                self.resolve_lag_lead_window_function(
+                    func_name,
+                    &arguments,
+                    &arg_types,
+                    ignore_null,
+                )
+                .await
             }
             "first_value" | "first" | "last_value" | "last" | "nth_value" => {
-                self.resolve_nth_value_window_function(func_name, &arguments, &arg_types)
-                    .await
+                self.resolve_nth_value_window_function(
+                    func_name,
+                    &arguments,
+                    &arg_types,
+                    ignore_null,
+                )
+                .await
             }
             "ntile" => self.resolve_ntile_window_function(&arguments).await,
             _ => Err(ErrorCode::UnknownFunction(format!(
@@ -1450,6 +1522,7 @@ impl<'a> TypeChecker<'a> {
         func_name: &str,
         args: &[ScalarExpr],
         arg_types: &[DataType],
+        ignore_null: bool,
     ) -> Result<WindowFuncType> {
         if args.is_empty() || args.len() > 3 {
             return Err(ErrorCode::InvalidArgument(format!(
@@ -1509,6 +1582,8 @@ impl<'a> TypeChecker<'a> {
             offset: offset.unsigned_abs(),
             default: cast_default,
             return_type: Box::new(return_type),
+            // An offset of 0 always refers to the current row.
+            ignore_null: ignore_null && offset != 0,
         }))
     }
 
@@ -1518,6 +1593,7 @@ impl<'a> TypeChecker<'a> {
         func_name: &str,
         args: &[ScalarExpr],
         arg_types: &[DataType],
+        ignore_null: bool,
     ) -> Result<WindowFuncType> {
         Ok(match func_name {
             "first_value" | "first" => {
@@ -1532,6 +1608,7 @@ impl<'a> TypeChecker<'a> {
                     n: Some(1),
                     arg: Box::new(args[0].clone()),
                     return_type: Box::new(return_type),
+                    ignore_null,
                 })
             }
             "last_value" | "last" => {
@@ -1546,6 +1623,7 @@ impl<'a> TypeChecker<'a> {
                     n: None,
                     arg: Box::new(args[0].clone()),
                     return_type: Box::new(return_type),
+                    ignore_null,
                 })
             }
             _ => {
@@ -1580,6 +1658,7 @@ impl<'a> TypeChecker<'a> {
                     n: Some(n),
                     arg: Box::new(args[0].clone()),
                     return_type: Box::new(return_type),
+                    ignore_null,
                 })
             }
         })
@@ -2136,12 +2215,70 @@ impl<'a> TypeChecker<'a> {
             }
             other => {
                 let name = other.to_func_name();
+                if let Some(result) = self
+                    .resolve_placeholder_operand(span, name.as_str(), left, right)
+                    .await?
+                {
+                    return Ok(result);
+                }
                 self.resolve_function(span, name.as_str(), vec![], &[left, right])
                     .await
             }
         }
     }
 
+    /// Resolve a binary operator with a placeholder `?` of prepared statement on one side,
+    /// the placeholder has the same type as the other side, e.g. `?` in `a = ?` has the type of `a`.
+    #[async_backtrace::framed]
+    async fn resolve_placeholder_operand(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        left: &Expr,
+        right: &Expr,
+    ) -> Result<Option<Box<(ScalarExpr, DataType)>>> {
+        if !self.metadata.read().placeholders_allowed() {
+            return Ok(None);
+        }
+        let (placeholder, other, placeholder_first) = match (left, right) {
+            (Expr::Placeholder { .. }, Expr::Placeholder { .. }) => return Ok(None),
+            (Expr::Placeholder { span }, other) => (span, other, true),
+            (other, Expr::Placeholder { span }) => (span, other, false),
+            _ => return Ok(None),
+        };
+
+        let box (other, data_type) = self.resolve(other).await?;
+        let data_type = data_type.remove_nullable();
+        if data_type == DataType::Null {
+            return Ok(None);
+        }
+        if let Some(range) = placeholder {
+            self.metadata
+                .write()
+                .set_placeholder_type(range.start, data_type.clone());
+        }
+        let param: ScalarExpr = CastExpr {
+            span: *placeholder,
+            is_try: false,
+            argument: Box::new(
+                ConstantExpr {
+                    span: *placeholder,
+                    value: Scalar::Null,
+                }
+                .into(),
+            ),
+            target_type: Box::new(data_type.wrap_nullable()),
+        }
+        .into();
+        let args = if placeholder_first {
+            vec![param, other]
+        } else {
+            vec![other, param]
+        };
+        self.resolve_scalar_function_call(span, func_name, vec![], args)
+            .map(Some)
+    }
+
     /// Resolve unary expressions.
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
@@ -2236,6 +2373,27 @@ impl<'a> TypeChecker<'a> {
         self.resolve_scalar_function_call(span, &func_name, vec![], args)
     }
 
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    pub async fn resolve_interval(
+        &mut self,
+        span: Span,
+        expr: &Expr,
+        interval_kind: &ASTIntervalKind,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        match interval_kind {
+            ASTIntervalKind::Doy | ASTIntervalKind::Dow => Err(ErrorCode::SemanticError(format!(
+                "Unsupported interval unit {interval_kind}"
+            ))
+            .set_span(span)),
+            _ => {
+                let func_name = format!("to_{}s", interval_kind.to_string().to_lowercase());
+                self.resolve_function(span, &func_name, vec![], &[expr])
+                    .await
+            }
+        }
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub async fn resolve_date_trunc(
@@ -2944,6 +3102,53 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn resolve_nextval(
+        &mut self,
+        span: Span,
+        arguments: &[Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let sequence_name = match arguments {
+            [
+                Expr::ColumnRef {
+                    database: None,
+                    table: None,
+                    column: ColumnID::Name(ident),
+                    ..
+                },
+            ] => normalize_identifier(ident, self.name_resolution_ctx).name,
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "nextval function requires exactly one argument, which is the name of a sequence",
+                )
+                .set_span(span));
+            }
+        };
+
+        let req = GetSequenceReq {
+            ident: SequenceIdent::new(self.ctx.get_tenant(), &sequence_name),
+        };
+        let catalog = self.ctx.get_default_catalog()?;
+        catalog
+            .get_sequence(req)
+            .await
+            .map_err(|err| err.set_span(span))?;
+
+        let return_type = DataType::Number(NumberDataType::UInt64);
+        self.ctx.set_cacheable(false);
+        Ok(Box::new((
+            AsyncFunctionCall {
+                span,
+                func_name: "nextval".to_string(),
+                display_name: format!("nextval({sequence_name})"),
+                return_type: Box::new(return_type.clone()),
+                arguments: vec![sequence_name],
+            }
+            .into(),
+            return_type,
+        )))
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     async fn resolve_udf_server(
@@ -3816,6 +4021,7 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
         TypeName::String => TableDataType::String,
         TypeName::Timestamp => TableDataType::Timestamp,
         TypeName::Date => TableDataType::Date,
+        TypeName::Interval => TableDataType::Interval,
         TypeName::Array(item_type) => {
             TableDataType::Array(Box::new(resolve_type_name(item_type, not_null)?))
         }
@@ -3864,6 +4070,7 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
             data_type.wrap_nullable()
         }
         TypeName::Variant => TableDataType::Variant,
+        TypeName::Geometry => TableDataType::Geometry,
         TypeName::NotNull(inner_type) => {
             let data_type = resolve_type_name(inner_type, not_null)?;
             data_type.remove_nullable()
@@ -19,6 +19,7 @@ use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Lambda;
 use databend_common_ast::ast::Window;
+use databend_common_ast::ast::WindowDesc;
 use databend_common_ast::walk_expr;
 use databend_common_ast::Visitor;
 use databend_common_exception::ErrorCode;
@@ -94,7 +95,7 @@ impl<'ast> Visitor<'ast> for UDFValidator {
         name: &'ast Identifier,
         args: &'ast [Expr],
         params: &'ast [Expr],
-        over: &'ast Option<Window>,
+        over: &'ast Option<WindowDesc>,
         lambda: &'ast Option<Lambda>,
     ) {
         let name = name.to_string();
@@ -111,7 +112,7 @@ impl<'ast> Visitor<'ast> for UDFValidator {
         }
 
         if let Some(over) = over {
-            match over {
+            match &over.window {
                 Window::WindowSpec(spec) => {
                     spec.partition_by
                         .iter()
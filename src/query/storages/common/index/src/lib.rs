@@ -26,5 +26,7 @@ pub use bloom_index::BloomIndexMeta;
 pub use bloom_index::FilterEvalResult;
 pub use index::Index;
 pub use page_index::PageIndex;
+pub use range_index::geometry_domain_to_statistics;
+pub use range_index::geometry_statistics_to_domain;
 pub use range_index::statistics_to_domain;
 pub use range_index::RangeIndex;
@@ -21,8 +21,10 @@ use databend_common_expression::types::decimal::Decimal128Type;
 use databend_common_expression::types::decimal::Decimal256Type;
 use databend_common_expression::types::decimal::DecimalDataType;
 use databend_common_expression::types::decimal::DecimalDomain;
+use databend_common_expression::types::geometry::GeometryDomain;
 use databend_common_expression::types::nullable::NullableDomain;
 use databend_common_expression::types::number::SimpleDomain;
+use databend_common_expression::types::number::F64;
 use databend_common_expression::types::string::StringDomain;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
@@ -39,6 +41,8 @@ use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::point_to_wkb;
+use databend_common_io::wkb_to_point;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 
@@ -242,6 +246,10 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                         *sz,
                     )),
                 },
+                DataType::Geometry => match geometry_statistics_to_domain(min, max) {
+                    Some(domain) => Domain::Geometry(domain),
+                    None => Domain::full(data_type),
+                },
                 // Unsupported data type
                 _ => Domain::full(data_type),
             })
@@ -249,4 +257,28 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
     }
 }
 
+/// The statistics of a geometry column is the bounding box of the geometries, `min` is
+/// the WKB point of the minimum x and y, and `max` is the WKB point of the maximum x and y.
+/// Keeping them as `Scalar::Geometry` makes the statistics carry the type of the column.
+pub fn geometry_domain_to_statistics(domain: &GeometryDomain) -> (Scalar, Scalar) {
+    (
+        Scalar::Geometry(point_to_wkb(domain.min_x.0, domain.min_y.0)),
+        Scalar::Geometry(point_to_wkb(domain.max_x.0, domain.max_y.0)),
+    )
+}
+
+pub fn geometry_statistics_to_domain(min: &Scalar, max: &Scalar) -> Option<GeometryDomain> {
+    let (Scalar::Geometry(min), Scalar::Geometry(max)) = (min, max) else {
+        return None;
+    };
+    let (min_x, min_y) = wkb_to_point(min).ok()??;
+    let (max_x, max_y) = wkb_to_point(max).ok()??;
+    Some(GeometryDomain {
+        min_x: F64::from(min_x),
+        min_y: F64::from(min_y),
+        max_x: F64::from(max_x),
+        max_y: F64::from(max_y),
+    })
+}
+
 impl Index for RangeIndex {}
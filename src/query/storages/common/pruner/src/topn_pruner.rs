@@ -76,10 +76,10 @@ impl TopNPrunner {
             return Ok(metas);
         };
 
-        // String Type min/max is truncated
+        // String Type min/max is truncated, and Geometry Type keeps the bounding box.
         if matches!(
             self.schema.field_with_name(column)?.data_type(),
-            TableDataType::String
+            TableDataType::String | TableDataType::Geometry
         ) {
             return Ok(metas);
         }
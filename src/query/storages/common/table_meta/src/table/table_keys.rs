@@ -29,6 +29,17 @@ pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
 // Read only attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_READ_ONLY: &str = "read_only_attached";
 
+// Temporary table options, the value is the id of the session which owns the table.
+pub const OPT_KEY_TEMP_PREFIX: &str = "temp_prefix";
+
+// Materialized view options.
+// The definition query of the materialized view.
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+// The ids of the tables that the materialized view reads, separated by comma.
+pub const OPT_KEY_MATERIALIZED_VIEW_SOURCES: &str = "materialized_view_sources";
+// If set, the materialized view is refreshed incrementally from a stream on its source table.
+pub const OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL: &str = "materialized_view_incremental";
+
 // the following are used in for delta and iceberg engine
 pub const OPT_KEY_LOCATION: &str = "location";
 pub const OPT_KEY_CONNECTION_NAME: &str = "connection_name";
@@ -53,6 +64,10 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCES);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL);
     r
 });
 
@@ -62,6 +77,10 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_ENGINE_META);
+    r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCES);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL);
     r
 });
 
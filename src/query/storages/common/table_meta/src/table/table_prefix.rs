@@ -23,3 +23,16 @@ pub fn table_storage_prefix(database_id: impl Display, table_id: impl Display) -
 pub fn database_storage_prefix(database_id: impl Display) -> String {
     format!("{}", database_id)
 }
+
+/// The root prefix of the data of temporary tables
+pub const TEMP_TABLE_STORAGE_PREFIX: &str = "_tmp_tbl";
+
+/// Constructs the prefix path which covers all the data of the temporary tables of a given session
+pub fn temp_session_storage_prefix(session_id: impl Display) -> String {
+    format!("{}/{}", TEMP_TABLE_STORAGE_PREFIX, session_id)
+}
+
+/// Constructs the prefix path which covers all the data of a given temporary table
+pub fn temp_table_storage_prefix(session_id: impl Display, table_id: impl Display) -> String {
+    format!("{}/{}/{}", TEMP_TABLE_STORAGE_PREFIX, session_id, table_id)
+}
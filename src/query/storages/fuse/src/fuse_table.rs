@@ -63,6 +63,7 @@ use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 use databend_storages_common_table_meta::meta::Versioned;
 use databend_storages_common_table_meta::table::table_storage_prefix;
+use databend_storages_common_table_meta::table::temp_table_storage_prefix;
 use databend_storages_common_table_meta::table::TableCompression;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
@@ -74,6 +75,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::error;
 use log::warn;
 use opendal::Operator;
@@ -278,6 +280,14 @@ impl FuseTable {
             return Ok(prefix.clone());
         }
 
+        // the data of temporary tables is put under the prefix of the session
+        if let Some(session_id) = table_info.options().get(OPT_KEY_TEMP_PREFIX) {
+            return Ok(temp_table_storage_prefix(
+                session_id,
+                table_info.ident.table_id,
+            ));
+        }
+
         // otherwise, use database id and table id as storage prefix
 
         let table_id = table_info.ident.table_id;
@@ -862,6 +872,10 @@ impl Table for FuseTable {
         true
     }
 
+    fn support_block_sample(&self) -> bool {
+        true
+    }
+
     fn result_can_be_cached(&self) -> bool {
         true
     }
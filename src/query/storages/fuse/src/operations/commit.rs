@@ -21,6 +21,7 @@ use chrono::Utc;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::temp_table::TempTblMgr;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
@@ -223,7 +224,25 @@ impl FuseTable {
             update_stream_meta: update_stream_meta.to_vec(),
         };
 
-        // 3. let's roll
+        // 3. in an explicit transaction, buffer the new table meta until COMMIT
+        {
+            let txn_mgr = ctx.txn_mgr();
+            let mut txn_mgr = txn_mgr.lock();
+            if txn_mgr.is_active() {
+                txn_mgr.update_table_meta(req, table_info);
+                TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+                return Ok(());
+            }
+        }
+
+        // 4. the meta of temporary table is kept in the session
+        if TempTblMgr::is_temp_table(table_id) {
+            ctx.temp_tbl_mgr().lock().update_table_meta(req)?;
+            TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+            return Ok(());
+        }
+
+        // 5. let's roll
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
@@ -103,6 +103,7 @@ impl FuseTable {
         segments: &[(usize, Location)],
         block_slots: Option<BlockSlotDescription>,
         io_request_semaphore: Arc<Semaphore>,
+        error_on_conflict: bool,
     ) -> Result<Vec<PipeItem>> {
         let chunks = Self::partition_segments(segments, num_partition);
         let read_settings = ReadSettings::from_ctx(&ctx)?;
@@ -120,6 +121,7 @@ impl FuseTable {
                 read_settings,
                 block_builder.clone(),
                 io_request_semaphore.clone(),
+                error_on_conflict,
             )?;
             items.push(item.into_pipe_item());
         }
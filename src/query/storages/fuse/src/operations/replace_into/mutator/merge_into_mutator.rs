@@ -89,6 +89,8 @@ struct AggregationContext {
     segment_reader: CompactSegmentInfoReader,
     block_builder: BlockBuilder,
     io_request_semaphore: Arc<Semaphore>,
+    // raise an error instead of deleting the existing rows that conflict with the input rows
+    error_on_conflict: bool,
 }
 
 // Apply MergeIntoOperations to segments
@@ -112,6 +114,7 @@ impl MergeIntoOperationAggregator {
         read_settings: ReadSettings,
         block_builder: BlockBuilder,
         io_request_semaphore: Arc<Semaphore>,
+        error_on_conflict: bool,
     ) -> Result<Self> {
         let deletion_accumulator = DeletionAccumulator::default();
         let segment_reader =
@@ -184,6 +187,7 @@ impl MergeIntoOperationAggregator {
                 segment_reader,
                 block_builder,
                 io_request_semaphore,
+                error_on_conflict,
             }),
         })
     }
@@ -436,6 +440,13 @@ impl AggregationContext {
             return Ok(None);
         }
 
+        if self.error_on_conflict {
+            return Err(ErrorCode::PrimaryKeyViolation(format!(
+                "duplicate primary key: {} of the inserted rows conflict with existing rows",
+                delete_nums
+            )));
+        }
+
         let progress_values = ProgressValues {
             rows: delete_nums,
             // ignore bytes.
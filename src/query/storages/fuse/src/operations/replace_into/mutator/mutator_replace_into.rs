@@ -59,6 +59,11 @@ pub struct ReplaceIntoMutator {
     table_range_index: HashMap<ColumnId, ColumnStatistics>,
     key_saw: HashSet<UniqueKeyDigest>,
     partitioner: Option<Partitioner>,
+    // raise an error instead of replacing, if the key of an input row conflicts
+    // with another input row or an existing row (enforced primary key)
+    error_on_conflict: bool,
+    // the keys of all the input rows, used to detect duplicated keys if `error_on_conflict`
+    key_inserted: HashSet<UniqueKeyDigest>,
 }
 
 impl ReplaceIntoMutator {
@@ -69,6 +74,7 @@ impl ReplaceIntoMutator {
         bloom_filter_column_indexes: Vec<FieldIndex>,
         table_schema: &TableSchema,
         table_range_idx: HashMap<ColumnId, ColumnStatistics>,
+        error_on_conflict: bool,
     ) -> Result<Self> {
         let partitioner = if !cluster_keys.is_empty()
             && ctx.get_settings().get_enable_replace_into_partitioning()?
@@ -88,6 +94,8 @@ impl ReplaceIntoMutator {
             table_range_index: table_range_idx,
             key_saw: Default::default(),
             partitioner,
+            error_on_conflict,
+            key_inserted: Default::default(),
         })
     }
 }
@@ -104,6 +112,10 @@ impl ReplaceIntoMutator {
         // pruning rows by using table level range index
         // rows that definitely have no conflict will be removed
         metrics_inc_replace_original_row_number(data_block.num_rows() as u64);
+        if self.error_on_conflict {
+            // rows pruned by the range index are not checked below, so check all the rows here.
+            self.check_duplicated_keys(data_block)?;
+        }
         let data_block_may_have_conflicts = self.table_level_row_prune(data_block)?;
 
         let row_number_after_pruning = data_block_may_have_conflicts.num_rows();
@@ -186,23 +198,8 @@ impl ReplaceIntoMutator {
                 Ok(MergeIntoOperation::Delete(vec![delete_action]))
             }
             ColumnHash::Conflict(conflict_row_idx) => {
-                let conflict_description = {
-                    let conflicts = column_values
-                        .iter()
-                        .zip(self.on_conflict_fields.iter())
-                        .map(|(col, field)| {
-                            let col_name = &field.table_field.name;
-                            // if col.index(conflict_row_idx) is None, an exception will already be thrown in build_column_hash
-                            let row_value = col.index(conflict_row_idx).unwrap();
-                            let row_value_message =
-                                Self::extract_col_value_for_err_message(row_value);
-                            format!("\"{}\":{}", col_name, row_value_message)
-                        })
-                        .collect::<Vec<_>>()
-                        .join(", ");
-
-                    format!("at row {}, [{}]", conflict_row_idx, conflicts)
-                };
+                let conflict_description =
+                    self.conflict_description(&column_values, conflict_row_idx);
                 Err(ErrorCode::StorageOther(format!(
                     "duplicated data detected in the values being replaced into (only the first one will be described): {}",
                     conflict_description
@@ -211,6 +208,43 @@ impl ReplaceIntoMutator {
         }
     }
 
+    fn check_duplicated_keys(&mut self, data_block: &DataBlock) -> Result<()> {
+        let num_rows = data_block.num_rows();
+        let column_values = on_conflict_key_column_values(&self.on_conflict_fields, data_block);
+        match Self::build_column_hash(&column_values, &mut self.key_inserted, num_rows)? {
+            ColumnHash::NoConflict(_) => Ok(()),
+            ColumnHash::Conflict(conflict_row_idx) => {
+                let conflict_description =
+                    self.conflict_description(&column_values, conflict_row_idx);
+                Err(ErrorCode::PrimaryKeyViolation(format!(
+                    "duplicated primary key detected in the values being inserted (only the first one will be described): {}",
+                    conflict_description
+                )))
+            }
+        }
+    }
+
+    fn conflict_description(
+        &self,
+        column_values: &[&Value<AnyType>],
+        conflict_row_idx: usize,
+    ) -> String {
+        let conflicts = column_values
+            .iter()
+            .zip(self.on_conflict_fields.iter())
+            .map(|(col, field)| {
+                let col_name = &field.table_field.name;
+                // if col.index(conflict_row_idx) is None, an exception will already be thrown in build_column_hash
+                let row_value = col.index(conflict_row_idx).unwrap();
+                let row_value_message = Self::extract_col_value_for_err_message(row_value);
+                format!("\"{}\":{}", col_name, row_value_message)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("at row {}, [{}]", conflict_row_idx, conflicts)
+    }
+
     fn build_column_hash(
         column_values: &[&Value<AnyType>],
         saw: &mut HashSet<UniqueKeyDigest>,
@@ -73,6 +73,7 @@ impl ReplaceIntoProcessor {
         target_table_empty: bool,
         table_range_idx: HashMap<ColumnId, ColumnStatistics>,
         delete_when: Option<(Expr, usize)>,
+        error_on_conflict: bool,
     ) -> Result<Self> {
         let replace_into_mutator = ReplaceIntoMutator::try_create(
             ctx.as_ref(),
@@ -81,6 +82,7 @@ impl ReplaceIntoProcessor {
             bloom_filter_column_indexes,
             table_schema,
             table_range_idx,
+            error_on_conflict,
         )?;
         let input_port = InputPort::create();
         let output_port_merge_into_action = OutputPort::create();
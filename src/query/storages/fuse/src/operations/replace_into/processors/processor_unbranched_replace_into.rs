@@ -64,6 +64,7 @@ impl UnbranchedReplaceIntoProcessor {
         target_table_empty: bool,
         table_range_idx: HashMap<ColumnId, ColumnStatistics>,
         delete_column: Option<usize>,
+        error_on_conflict: bool,
     ) -> Result<Self> {
         let replace_into_mutator = ReplaceIntoMutator::try_create(
             ctx,
@@ -72,6 +73,7 @@ impl UnbranchedReplaceIntoProcessor {
             bloom_filter_column_indexes,
             table_schema,
             table_range_idx,
+            error_on_conflict,
         )?;
         let input_port = InputPort::create();
         let output_port_merge_into_action = OutputPort::create();
@@ -15,6 +15,7 @@
 use databend_common_catalog::table::NavigationDescriptor;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::temp_table::TempTblMgr;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
@@ -57,7 +58,14 @@ impl FuseTable {
         };
 
         // 4. let's roll
-        let reply = catalog.update_table_meta(&self.table_info, req).await;
+        let reply = if TempTblMgr::is_temp_table(table_id) {
+            ctx.temp_tbl_mgr().lock().update_table_meta(req)
+        } else {
+            catalog
+                .update_table_meta(&self.table_info, req)
+                .await
+                .map(|_| ())
+        };
         if reply.is_ok() {
             // try keep the snapshot hit
             let snapshot_location = table_reverting_to.snapshot_loc().await?.ok_or_else(|| {
@@ -71,6 +79,6 @@ impl FuseTable {
             .await;
         };
 
-        reply.map(|_| ())
+        reply
     }
 }
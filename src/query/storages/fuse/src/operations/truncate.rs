@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::temp_table::TempTblMgr;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_app::schema::TruncateTableReq;
@@ -68,27 +69,43 @@ impl FuseTable {
             let table_id = self.table_info.ident.table_id;
             let table_version = self.table_info.ident.seq;
             let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+            let req = UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            };
 
-            // commit table meta to meta server.
-            // `truncate_table` is not supposed to be retry-able, thus we use
-            // `update_data_table_meta` directly.
-            catalog
-                .update_table_meta(&self.table_info, UpdateTableMetaReq {
-                    table_id,
-                    seq: MatchSeq::Exact(table_version),
-                    new_table_meta,
-                    copied_files: None,
-                    deduplicated_label: None,
-                    update_stream_meta: vec![],
-                })
-                .await?;
+            // in an explicit transaction, buffer the new table meta until COMMIT,
+            // the historical data is kept since the transaction may be rolled back.
+            {
+                let txn_mgr = ctx.txn_mgr();
+                let mut txn_mgr = txn_mgr.lock();
+                if txn_mgr.is_active() {
+                    txn_mgr.update_table_meta(req, &self.table_info);
+                    return Ok(());
+                }
+            }
 
-            catalog
-                .truncate_table(&self.table_info, TruncateTableReq {
-                    table_id,
-                    batch_size: None,
-                })
-                .await?;
+            if TempTblMgr::is_temp_table(table_id) {
+                // the meta of temporary table is kept in the session, and there is
+                // no copied files to truncate.
+                ctx.temp_tbl_mgr().lock().update_table_meta(req)?;
+            } else {
+                // commit table meta to meta server.
+                // `truncate_table` is not supposed to be retry-able, thus we use
+                // `update_data_table_meta` directly.
+                catalog.update_table_meta(&self.table_info, req).await?;
+
+                catalog
+                    .truncate_table(&self.table_info, TruncateTableReq {
+                        table_id,
+                        batch_size: None,
+                    })
+                    .await?;
+            }
 
             // try keep a hit file of last snapshot
             Self::write_last_snapshot_hint(
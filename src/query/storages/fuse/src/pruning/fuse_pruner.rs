@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::sync::Arc;
 
 use databend_common_base::base::tokio::sync::Semaphore;
@@ -40,9 +42,11 @@ use databend_storages_common_pruner::TopNPrunner;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::CompactSegmentInfo;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use log::warn;
 use opendal::Operator;
+use siphasher::sip::SipHasher13;
 
 use crate::operations::DeletedSegmentInfo;
 use crate::pruning::segment_pruner::SegmentPruner;
@@ -172,6 +176,34 @@ impl PruningContext {
     }
 }
 
+// The block level sample, the decisions are made by the hash of the locations, so they are
+// repeatable with the same seed.
+//
+// The segments are sampled first, so the blocks of the dropped segments are never decoded
+// and pruned. A segment is kept with the probability scaled by its row count, that is the
+// expected number of its sampled blocks `p * row_count / rows_per_block`. The blocks of a
+// kept segment are kept with `p / segment_probability`, so each block is kept with the
+// probability `p` overall.
+#[derive(Clone, Copy)]
+struct BlockSample {
+    probability: f64,
+    seed: u64,
+}
+
+impl BlockSample {
+    fn segment_probability(&self, info: &CompactSegmentInfo) -> f64 {
+        let row_count = info.summary.row_count;
+        let rows_per_block = (row_count / info.summary.block_count.max(1)).max(1);
+        (self.probability * row_count as f64 / rows_per_block as f64).min(1.0)
+    }
+
+    fn keep(&self, location: &str, probability: f64) -> bool {
+        let mut hasher = SipHasher13::new_with_keys(self.seed, 0);
+        location.hash(&mut hasher);
+        (hasher.finish() as f64 / u64::MAX as f64) < probability
+    }
+}
+
 pub struct FusePruner {
     max_concurrency: usize,
     pub table_schema: TableSchemaRef,
@@ -261,7 +293,7 @@ impl FusePruner {
         self.pruning(segment_locs, true).await
     }
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> sample pruner -> block pruner -> topn pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &mut self,
@@ -272,6 +304,12 @@ impl FusePruner {
         let segment_pruner =
             SegmentPruner::create(self.pruning_ctx.clone(), self.table_schema.clone())?;
         let block_pruner = Arc::new(BlockPruner::create(self.pruning_ctx.clone())?);
+        // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
+        // will sample the blocks, we can prevent other mutations like update and so on.
+        let sample = match delete_pruning {
+            true => None,
+            false => self.block_sample(),
+        };
 
         let mut remain = segment_locs.len() % self.max_concurrency;
         let batch_size = segment_locs.len() / self.max_concurrency;
@@ -349,7 +387,21 @@ impl FusePruner {
                                 }
                             } else {
                                 for (location, info) in pruned_segments {
-                                    res.extend(block_pruner.pruning(location, &info).await?);
+                                    let Some(sample) = sample else {
+                                        res.extend(block_pruner.pruning(location, &info).await?);
+                                        continue;
+                                    };
+
+                                    // Sample pruner.
+                                    let segment_probability = sample.segment_probability(&info);
+                                    if !sample.keep(&location.location.0, segment_probability) {
+                                        continue;
+                                    }
+                                    let probability = sample.probability / segment_probability;
+                                    let metas = block_pruner.pruning(location, &info).await?;
+                                    res.extend(metas.into_iter().filter(|(_, block_meta)| {
+                                        sample.keep(&block_meta.location.0, probability)
+                                    }));
                                 }
                             }
                             Result::<_, ErrorCode>::Ok((res, deleted_segments))
@@ -373,8 +425,6 @@ impl FusePruner {
                 if delete_pruning {
                     Ok(metas)
                 } else {
-                    // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
-                    // will get here, we can prevent other mutations like update and so on.
                     // TopN pruner.
                     self.topn_pruning(metas)
                 }
@@ -382,6 +432,18 @@ impl FusePruner {
         }
     }
 
+    // sample pruner:
+    // if there is a block level sample, sample the segments and then the blocks of them.
+    fn block_sample(&self) -> Option<BlockSample> {
+        match self.push_down.as_ref().and_then(|p| p.sample.as_ref()) {
+            Some(sample) if sample.block_level && sample.probability < 1.0 => Some(BlockSample {
+                probability: sample.probability,
+                seed: sample.seed.unwrap_or_else(rand::random),
+            }),
+            _ => None,
+        }
+    }
+
     // topn pruner:
     // if there are ordering + limit clause and no filters, use topn pruner
     fn topn_pruning(
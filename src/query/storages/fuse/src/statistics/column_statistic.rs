@@ -15,7 +15,9 @@
 use std::collections::HashMap;
 
 use databend_common_exception::Result;
+use databend_common_expression::types::geometry::GeometryDomain;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::GeometryType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::Column;
@@ -25,6 +27,7 @@ use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::ORIGIN_BLOCK_ROW_NUM_COLUMN_ID;
 use databend_common_functions::aggregates::eval_aggr;
+use databend_storages_common_index::geometry_domain_to_statistics;
 use databend_storages_common_index::Index;
 use databend_storages_common_index::RangeIndex;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
@@ -66,30 +69,37 @@ pub fn gen_columns_statistics(
         let mut min = Scalar::Null;
         let mut max = Scalar::Null;
 
-        let (mins, _) = eval_aggr("min", vec![], &[col.clone()], rows)?;
-        let (maxs, _) = eval_aggr("max", vec![], &[col.clone()], rows)?;
+        if data_type.remove_nullable() == DataType::Geometry {
+            // Geometry columns keep the bounding box instead of the min and max value.
+            let inner = col.remove_nullable();
+            let domain = GeometryType::column_domain(inner.as_geometry().unwrap());
+            (min, max) = geometry_domain_to_statistics(&domain);
+        } else {
+            let (mins, _) = eval_aggr("min", vec![], &[col.clone()], rows)?;
+            let (maxs, _) = eval_aggr("max", vec![], &[col.clone()], rows)?;
 
-        if mins.len() > 0 {
-            min = if let Some(v) = mins.index(0) {
-                if let Some(v) = v.to_owned().trim_min(STATS_STRING_PREFIX_LEN) {
-                    v
+            if mins.len() > 0 {
+                min = if let Some(v) = mins.index(0) {
+                    if let Some(v) = v.to_owned().trim_min(STATS_STRING_PREFIX_LEN) {
+                        v
+                    } else {
+                        continue;
+                    }
                 } else {
                     continue;
                 }
-            } else {
-                continue;
             }
-        }
 
-        if maxs.len() > 0 {
-            max = if let Some(v) = maxs.index(0) {
-                if let Some(v) = v.to_owned().trim_max(STATS_STRING_PREFIX_LEN) {
-                    v
+            if maxs.len() > 0 {
+                max = if let Some(v) = maxs.index(0) {
+                    if let Some(v) = v.to_owned().trim_max(STATS_STRING_PREFIX_LEN) {
+                        v
+                    } else {
+                        continue;
+                    }
                 } else {
                     continue;
                 }
-            } else {
-                continue;
             }
         }
 
@@ -132,6 +142,11 @@ pub fn gen_columns_statistics(
 }
 
 pub fn scalar_min_max(data_type: &DataType, scalar: Scalar) -> Option<(Scalar, Scalar)> {
+    if data_type.remove_nullable() == DataType::Geometry {
+        return scalar
+            .as_geometry()
+            .map(|wkb| geometry_domain_to_statistics(&GeometryDomain::from_wkb(wkb)));
+    }
     if RangeIndex::supported_type(data_type) {
         if let Some((min, Some(max))) = scalar
             .clone()
@@ -15,9 +15,13 @@
 use std::borrow::Borrow;
 use std::collections::HashMap;
 
+use databend_common_expression::types::geometry::GeometryDomain;
+use databend_common_expression::types::DataType;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
+use databend_storages_common_index::geometry_domain_to_statistics;
+use databend_storages_common_index::geometry_statistics_to_domain;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
@@ -62,17 +66,23 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
                 in_memory_size += col_stats.in_memory_size;
             }
 
-            let min = min_stats
-                .into_iter()
-                .filter(|s| !s.is_null())
-                .min_by(|x, y| x.cmp(y))
-                .unwrap_or(Scalar::Null);
+            let (min, max) = match reduce_geometry_statistics(&min_stats, &max_stats) {
+                Some((min, max)) => (min, max),
+                None => {
+                    let min = min_stats
+                        .into_iter()
+                        .filter(|s| !s.is_null())
+                        .min_by(|x, y| x.cmp(y))
+                        .unwrap_or(Scalar::Null);
 
-            let max = max_stats
-                .into_iter()
-                .filter(|s| !s.is_null())
-                .max_by(|x, y| x.cmp(y))
-                .unwrap_or(Scalar::Null);
+                    let max = max_stats
+                        .into_iter()
+                        .filter(|s| !s.is_null())
+                        .max_by(|x, y| x.cmp(y))
+                        .unwrap_or(Scalar::Null);
+                    (min, max)
+                }
+            };
 
             acc.insert(
                 *id,
@@ -82,6 +92,35 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
         })
 }
 
+/// The statistics of geometry columns are the corners of the bounding box,
+/// which are merged component-wise rather than by the order of the scalars.
+///
+/// The statistics of a column share the data type of the column, so the column is
+/// a geometry column iff its statistics are `Scalar::Geometry`.
+fn reduce_geometry_statistics(mins: &[Scalar], maxs: &[Scalar]) -> Option<(Scalar, Scalar)> {
+    let data_type = mins
+        .iter()
+        .find(|s| !s.is_null())?
+        .as_ref()
+        .infer_data_type();
+    if data_type != DataType::Geometry {
+        return None;
+    }
+
+    let mut domain: Option<GeometryDomain> = None;
+    for (min, max) in mins.iter().zip(maxs.iter()) {
+        if min.is_null() || max.is_null() {
+            continue;
+        }
+        let other = geometry_statistics_to_domain(min, max).unwrap_or_else(GeometryDomain::full);
+        domain = Some(match domain {
+            Some(domain) => domain.merge(&other),
+            None => other,
+        });
+    }
+    domain.map(|domain| geometry_domain_to_statistics(&domain))
+}
+
 pub fn reduce_cluster_statistics<T: Borrow<Option<ClusterStatistics>>>(
     blocks_cluster_stats: &[T],
     default_cluster_key_id: Option<u32>,
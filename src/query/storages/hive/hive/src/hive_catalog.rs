@@ -91,6 +91,7 @@ use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_types::*;
 use faststr::FastStr;
 use hive_metastore::Partition;
+use hive_metastore::ThriftHiveMetastoreAppendPartitionByNameException;
 use hive_metastore::ThriftHiveMetastoreClient;
 use hive_metastore::ThriftHiveMetastoreClientBuilder;
 use hive_metastore::ThriftHiveMetastoreGetTableException;
@@ -223,6 +224,34 @@ impl HiveCatalog {
             .collect())
     }
 
+    /// Register a partition like `p_date=20220101/p_hour=01` at the default location,
+    /// i.e. the directory of the partition under the table location.
+    ///
+    /// The partition may have been registered concurrently, which is not an error.
+    #[async_backtrace::framed]
+    pub async fn add_partition(
+        &self,
+        db: String,
+        table: String,
+        partition_name: String,
+    ) -> Result<()> {
+        match self
+            .client
+            .append_partition_by_name(
+                FastStr::new(db),
+                FastStr::new(table),
+                FastStr::new(partition_name),
+            )
+            .await
+        {
+            Ok(_)
+            | Err(volo_thrift::ResponseError::UserException(
+                ThriftHiveMetastoreAppendPartitionByNameException::O2(_),
+            )) => Ok(()),
+            Err(e) => Err(from_thrift_error(e)),
+        }
+    }
+
     fn handle_table_meta(table_meta: &hive_metastore::Table) -> Result<()> {
         if let Some(sd) = table_meta.sd.as_ref() {
             if let Some(input_format) = sd.input_format.as_ref() {
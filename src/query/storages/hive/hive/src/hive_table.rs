@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
@@ -19,6 +20,7 @@ use std::time::Instant;
 use async_recursion::async_recursion;
 use databend_common_base::base::tokio;
 use databend_common_base::base::tokio::sync::Semaphore;
+use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::catalog_kind::CATALOG_HIVE;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartStatistics;
@@ -26,6 +28,7 @@ use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::AppendMode;
 use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableStatistics;
@@ -38,6 +41,7 @@ use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -48,8 +52,10 @@ use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_core::SourcePipeBuilder;
+use databend_common_pipeline_sinks::AsyncSinker;
 use databend_common_pipeline_sources::SyncSource;
 use databend_common_pipeline_sources::SyncSourcer;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransformer;
 use databend_common_storage::init_operator;
 use databend_common_storage::DataOperator;
 use databend_storages_common_index::RangeIndex;
@@ -58,6 +64,7 @@ use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use futures::TryStreamExt;
 use log::info;
 use log::trace;
+use log::warn;
 use opendal::EntryMode;
 use opendal::Metakey;
 use opendal::Operator;
@@ -67,7 +74,10 @@ use super::hive_partition_pruner::HivePartitionPruner;
 use super::hive_table_options::HiveTableOptions;
 use crate::filter_hive_partition_from_partition_keys;
 use crate::hive_parquet_block_reader::HiveBlockReader;
+use crate::hive_table_sink::HiveCommitSink;
+use crate::hive_table_sink::HiveTableWriter;
 use crate::hive_table_source::HiveTableSource;
+use crate::utils::scalar_to_partition_value;
 use crate::HiveBlockFilter;
 use crate::HiveFileSplitter;
 
@@ -78,6 +88,8 @@ pub struct HiveTable {
     table_info: TableInfo,
     table_options: HiveTableOptions,
     dal: Operator,
+    // the static partition of INSERT OVERWRITE, like 'c_region=ASIA/c_nation=CHINA'
+    overwrite_partition: Option<String>,
 }
 
 impl HiveTable {
@@ -93,9 +105,93 @@ impl HiveTable {
             table_info,
             table_options,
             dal,
+            overwrite_partition: None,
         })
     }
 
+    pub(crate) fn dal(&self) -> &Operator {
+        &self.dal
+    }
+
+    // return the table location formatted as opendal path, like /user/hive/warehouse/a.db/b.table/
+    pub(crate) fn table_location(&self) -> Result<String> {
+        let path = self.table_options.location.as_ref().ok_or_else(|| {
+            ErrorCode::TableInfoError(format!("{}, table location is empty", self.table_info.name))
+        })?;
+        Ok(convert_hdfs_path(path, true))
+    }
+
+    // return the (database, table) names in hive metastore
+    pub(crate) fn db_and_table_name(&self) -> (String, String) {
+        let names = self.table_info.desc.split('.').collect::<Vec<&str>>();
+        (names[0].to_string(), names[1].to_string())
+    }
+
+    // return the staging directory of the query, which is hidden from the readers
+    pub(crate) fn staging_dir(&self, query_id: &str) -> Result<String> {
+        Ok(format!(
+            "{}.databend-staging-{}/",
+            self.table_location()?,
+            query_id
+        ))
+    }
+
+    // return the directory of the partition, and whether the partition is not registered in
+    // the metastore, then it's located under the table location by default
+    #[async_backtrace::framed]
+    pub(crate) async fn partition_dir(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        name: &str,
+    ) -> Result<(String, bool)> {
+        let (db_name, table_name) = self.db_and_table_name();
+        let catalog = ctx.get_catalog(CATALOG_HIVE).await?;
+        let catalog = catalog.as_any().downcast_ref::<HiveCatalog>().unwrap();
+        let partitions = catalog
+            .get_partitions(db_name, table_name, vec![name.to_string()])
+            .await?;
+        let location = partitions
+            .into_iter()
+            .next()
+            .and_then(|p| p.sd)
+            .and_then(|sd| sd.location);
+        match location {
+            Some(location) => Ok((convert_hdfs_path(&location, true), false)),
+            None => Ok((format!("{}{}/", self.table_location()?, name), true)),
+        }
+    }
+
+    // move a staged file into place, the staged file is removed along with the staging directory
+    #[async_backtrace::framed]
+    pub(crate) async fn move_file(&self, from: &str, to: &str) -> Result<()> {
+        let capability = self.dal.info().full_capability();
+        if capability.rename {
+            self.dal.rename(from, to).await?;
+        } else if capability.copy {
+            self.dal.copy(from, to).await?;
+        } else {
+            let data = self.dal.read(from).await?;
+            self.dal.write(to, data).await?;
+        }
+        Ok(())
+    }
+
+    // remove the data files in the directories
+    #[async_backtrace::framed]
+    pub(crate) async fn remove_files_in_dirs(&self, dirs: Vec<String>) -> Result<()> {
+        let dirs = dirs.into_iter().map(|dir| (dir, None)).collect();
+        let files = self
+            .list_files_from_dirs(dirs)
+            .await?
+            .into_iter()
+            .map(|file| file.filename)
+            .collect::<Vec<_>>();
+
+        info!("remove {} files of hive table {}", files.len(), self.name());
+        self.dal.remove(files).await?;
+        Ok(())
+    }
+
     fn get_block_filter(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -544,7 +640,7 @@ impl Table for HiveTable {
     }
 
     fn as_any(&self) -> &(dyn std::any::Any + 'static) {
-        todo!()
+        self
     }
 
     fn get_table_info(&self) -> &TableInfo {
@@ -559,6 +655,11 @@ impl Table for HiveTable {
         false
     }
 
+    // the loaded files are not recorded in the metastore
+    fn support_copied_files(&self) -> bool {
+        false
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
@@ -583,29 +684,110 @@ impl Table for HiveTable {
         self.do_read2(ctx, plan, pipeline)
     }
 
+    fn partition_keys(&self) -> Vec<String> {
+        self.table_options
+            .partition_keys
+            .clone()
+            .unwrap_or_default()
+    }
+
+    fn with_overwrite_partition(&self, values: Vec<(String, Scalar)>) -> Result<Arc<dyn Table>> {
+        let values = values.into_iter().collect::<HashMap<_, _>>();
+        let partition = self
+            .partition_keys()
+            .iter()
+            .map(|key| match values.get(key) {
+                Some(value) => Ok(format!(
+                    "{}={}",
+                    key,
+                    scalar_to_partition_value(value.as_ref())?
+                )),
+                None => Err(ErrorCode::BadArguments(format!(
+                    "The value of the partition column '{}' is not specified",
+                    key
+                ))),
+            })
+            .collect::<Result<Vec<_>>>()?
+            .join("/");
+
+        let mut table = HiveTable::try_create(self.table_info.clone())?;
+        table.overwrite_partition = Some(partition);
+        Ok(Arc::new(table))
+    }
+
+    fn append_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _append_mode: AppendMode,
+    ) -> Result<()> {
+        let table = Arc::new(HiveTable::try_create(self.table_info.clone())?);
+        pipeline.add_transform(|input, output| {
+            let writer = HiveTableWriter::try_create(ctx.clone(), table.clone())?;
+            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+                input, output, writer,
+            )))
+        })
+    }
+
     fn commit_insertion(
         &self,
-        _ctx: Arc<dyn TableContext>,
-        _pipeline: &mut Pipeline,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
         _copied_files: Option<UpsertTableCopiedFileReq>,
         _update_stream_meta: Vec<UpdateStreamMetaReq>,
-        _overwrite: bool,
+        overwrite: bool,
         _prev_snapshot_id: Option<SnapshotId>,
         _deduplicated_label: Option<String>,
     ) -> Result<()> {
-        Err(ErrorCode::Unimplemented(format!(
-            "commit_insertion operation for table {} is not implemented, table engine is {}",
-            self.name(),
-            self.get_table_info().meta.engine
-        )))
+        let table = Arc::new(HiveTable::try_create(self.table_info.clone())?);
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(AsyncSinker::create(
+                input,
+                ctx.clone(),
+                HiveCommitSink::create(
+                    ctx.clone(),
+                    table.clone(),
+                    overwrite,
+                    self.overwrite_partition.clone(),
+                ),
+            )))
+        })?;
+
+        // the staged files are moved into place on commit, the rest are garbage
+        let staging_dir = table.staging_dir(&ctx.get_id())?;
+        pipeline.set_on_finished(move |may_error| {
+            let dal = table.dal().clone();
+            let res = GlobalIORuntime::instance().block_on(async move {
+                dal.remove_all(&staging_dir).await?;
+                Ok(())
+            });
+            if let Err(cause) = res {
+                warn!(
+                    "failed to remove the staging directory of hive table: {}",
+                    cause
+                );
+            }
+
+            match may_error {
+                Ok(_) => Ok(()),
+                Err(error_code) => Err(error_code.clone()),
+            }
+        });
+        Ok(())
     }
 
+    // remove all the data files, the partitions are kept in the metastore like hive does
     #[async_backtrace::framed]
-    async fn truncate(&self, _ctx: Arc<dyn TableContext>) -> Result<()> {
-        Err(ErrorCode::Unimplemented(format!(
-            "truncate for table {} is not implemented",
-            self.name()
-        )))
+    async fn truncate(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        let dirs = self
+            .get_query_locations(ctx, &None)
+            .await?
+            .into_iter()
+            .map(|(dir, _)| dir)
+            .collect();
+        self.remove_files_in_dirs(dirs).await
     }
 
     #[async_backtrace::framed]
@@ -773,8 +955,10 @@ async fn do_list_files_from_dir(
         let meta = de.metadata();
 
         let path = de.path();
-        let file_offset = path.rfind('/').unwrap_or_default() + 1;
-        if path[file_offset..].starts_with('.') || path[file_offset..].starts_with('_') {
+        // the paths of directories end with '/'
+        let name = path.trim_end_matches('/');
+        let file_offset = name.rfind('/').unwrap_or_default() + 1;
+        if name[file_offset..].starts_with('.') || name[file_offset..].starts_with('_') {
             continue;
         }
 
@@ -0,0 +1,367 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use databend_common_base::base::GlobalUniqName;
+use databend_common_catalog::catalog_kind::CATALOG_HIVE;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use databend_storages_common_blocks::blocks_to_parquet;
+use databend_storages_common_table_meta::table::TableCompression;
+use log::info;
+
+use crate::hive_catalog::HiveCatalog;
+use crate::utils::scalar_to_partition_value;
+use crate::HiveTable;
+
+// the buffered blocks of a partition are written into a file once they exceed the size
+const MAX_BUFFER_SIZE: usize = 256 * 1024 * 1024;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct HiveWrittenFile {
+    // partition name like 'c_region=ASIA/c_nation=CHINA', none for unpartitioned tables
+    pub partition: Option<String>,
+    // whether the partition is not registered in the metastore yet
+    pub new_partition: bool,
+    // directory of the partition or the table, like /user/hive/warehouse/ssb.db/customer.table/c_region=ASIA/c_nation=CHINA/
+    pub dir: String,
+    // the file is written into the staging directory of the query, and moved to `path` on commit
+    pub staged: String,
+    pub path: String,
+}
+
+// Sent from the writers on every node to the commit sink on the coordinator.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct HiveWrittenFiles {
+    pub files: Vec<HiveWrittenFile>,
+}
+
+impl HiveWrittenFiles {
+    pub fn create_block(files: Vec<HiveWrittenFile>) -> DataBlock {
+        DataBlock::empty_with_meta(Box::new(HiveWrittenFiles { files }))
+    }
+}
+
+#[typetag::serde(name = "hive_written_files")]
+impl BlockMetaInfo for HiveWrittenFiles {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        Self::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Default)]
+struct PartitionBuffer {
+    blocks: Vec<DataBlock>,
+    size: usize,
+}
+
+// Write blocks into parquet files under the partition layout of the table.
+//
+// The partition columns are not written into the files, but encoded into the directories
+// like `c_region=ASIA/c_nation=CHINA`. Existing partitions are written to their locations
+// in the metastore, new partitions are written to the default locations under the table,
+// and registered by the `HiveCommitSink`.
+//
+// The files are written into the staging directory of the query first, which is invisible
+// to the readers, and moved into place by the `HiveCommitSink`.
+pub struct HiveTableWriter {
+    ctx: Arc<dyn TableContext>,
+    table: Arc<HiveTable>,
+    table_location: String,
+    staging_dir: String,
+    partition_keys: Vec<String>,
+    partition_indices: Vec<usize>,
+    data_indices: Vec<usize>,
+    data_schema: TableSchemaRef,
+    // the directory of a partition, and whether it is not registered in the metastore
+    partition_dirs: HashMap<String, (String, bool)>,
+    buffers: HashMap<Option<String>, PartitionBuffer>,
+    written_files: Vec<HiveWrittenFile>,
+}
+
+impl HiveTableWriter {
+    pub fn try_create(ctx: Arc<dyn TableContext>, table: Arc<HiveTable>) -> Result<Self> {
+        let table_location = table.table_location()?;
+        let staging_dir = table.staging_dir(&ctx.get_id())?;
+        let partition_keys = table.partition_keys();
+        let schema = table.schema();
+
+        let mut partition_indices = Vec::with_capacity(partition_keys.len());
+        for key in partition_keys.iter() {
+            partition_indices.push(schema.index_of(key)?);
+        }
+        let data_indices = (0..schema.num_fields())
+            .filter(|i| !partition_indices.contains(i))
+            .collect::<Vec<_>>();
+        let data_schema = Arc::new(TableSchema::new(
+            data_indices
+                .iter()
+                .map(|i| schema.field(*i).clone())
+                .collect(),
+        ));
+
+        Ok(HiveTableWriter {
+            ctx,
+            table,
+            table_location,
+            staging_dir,
+            partition_keys,
+            partition_indices,
+            data_indices,
+            data_schema,
+            partition_dirs: HashMap::new(),
+            buffers: HashMap::new(),
+            written_files: vec![],
+        })
+    }
+
+    // split the block by the partition names, like 'c_region=ASIA/c_nation=CHINA'
+    fn split_by_partition(&self, block: DataBlock) -> Result<Vec<(Option<String>, DataBlock)>> {
+        if self.partition_indices.is_empty() {
+            return Ok(vec![(None, block)]);
+        }
+
+        let mut rows: HashMap<String, Vec<u32>> = HashMap::new();
+        for row in 0..block.num_rows() {
+            let mut names = Vec::with_capacity(self.partition_indices.len());
+            for (key, index) in self.partition_keys.iter().zip(&self.partition_indices) {
+                let value = block.get_by_offset(*index).value.index(row).unwrap();
+                names.push(format!("{}={}", key, scalar_to_partition_value(value)?));
+            }
+            rows.entry(names.join("/")).or_default().push(row as u32);
+        }
+
+        if rows.len() == 1 {
+            let name = rows.into_keys().next().unwrap();
+            return Ok(vec![(Some(name), block)]);
+        }
+        rows.into_iter()
+            .map(|(name, indices)| Ok((Some(name), block.take(&indices, &mut None)?)))
+            .collect()
+    }
+
+    fn project_data_columns(&self, block: DataBlock) -> DataBlock {
+        let num_rows = block.num_rows();
+        let columns = self
+            .data_indices
+            .iter()
+            .map(|i| block.get_by_offset(*i).clone())
+            .collect();
+        DataBlock::new(columns, num_rows)
+    }
+
+    #[async_backtrace::framed]
+    async fn partition_dir(&mut self, partition: &Option<String>) -> Result<(String, bool)> {
+        let Some(name) = partition else {
+            return Ok((self.table_location.clone(), false));
+        };
+        if let Some(dir) = self.partition_dirs.get(name) {
+            return Ok(dir.clone());
+        }
+
+        let dir = self.table.partition_dir(self.ctx.clone(), name).await?;
+        self.partition_dirs.insert(name.clone(), dir.clone());
+        Ok(dir)
+    }
+
+    #[async_backtrace::framed]
+    async fn flush(&mut self, partition: Option<String>) -> Result<()> {
+        let Some(buffer) = self.buffers.remove(&partition) else {
+            return Ok(());
+        };
+        if buffer.blocks.is_empty() {
+            return Ok(());
+        }
+
+        let (dir, new_partition) = self.partition_dir(&partition).await?;
+        let mut data = Vec::with_capacity(buffer.size);
+        blocks_to_parquet(
+            &self.data_schema,
+            buffer.blocks,
+            &mut data,
+            TableCompression::Snappy,
+            false,
+        )?;
+
+        let filename = format!("{}_{}.parquet", self.ctx.get_id(), GlobalUniqName::unique());
+        let staged = match &partition {
+            Some(name) => format!("{}{}/{}", self.staging_dir, name, filename),
+            None => format!("{}{}", self.staging_dir, filename),
+        };
+        self.table.dal().write(&staged, data).await?;
+
+        self.written_files.push(HiveWrittenFile {
+            partition,
+            new_partition,
+            path: format!("{}{}", dir, filename),
+            dir,
+            staged,
+        });
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncAccumulatingTransform for HiveTableWriter {
+    const NAME: &'static str = "HiveTableWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        let data = data.convert_to_full();
+        for (partition, block) in self.split_by_partition(data)? {
+            let block = self.project_data_columns(block);
+            let buffer = self.buffers.entry(partition.clone()).or_default();
+            buffer.size += block.memory_size();
+            buffer.blocks.push(block);
+            if buffer.size >= MAX_BUFFER_SIZE {
+                self.flush(partition).await?;
+            }
+        }
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, output: bool) -> Result<Option<DataBlock>> {
+        let partitions = self.buffers.keys().cloned().collect::<Vec<_>>();
+        for partition in partitions {
+            self.flush(partition).await?;
+        }
+
+        let files = std::mem::take(&mut self.written_files);
+        match output {
+            true => Ok(Some(HiveWrittenFiles::create_block(files))),
+            false => Ok(None),
+        }
+    }
+}
+
+// Commit the files written by all the writers.
+//
+// For INSERT OVERWRITE, the existing files in the table, or the partitions written to, are
+// removed. Then the staged files are moved into place, and the new partitions are registered
+// to the metastore. The staging directory is removed when the pipeline finishes.
+pub struct HiveCommitSink {
+    ctx: Arc<dyn TableContext>,
+    table: Arc<HiveTable>,
+    overwrite: bool,
+    // the static partition of INSERT OVERWRITE, which is cleared even if no rows are written to it
+    overwrite_partition: Option<String>,
+    files: Vec<HiveWrittenFile>,
+}
+
+impl HiveCommitSink {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        table: Arc<HiveTable>,
+        overwrite: bool,
+        overwrite_partition: Option<String>,
+    ) -> Self {
+        HiveCommitSink {
+            ctx,
+            table,
+            overwrite,
+            overwrite_partition,
+            files: vec![],
+        }
+    }
+}
+
+#[async_trait]
+impl AsyncSink for HiveCommitSink {
+    const NAME: &'static str = "HiveCommitSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        if self.overwrite {
+            let dirs = if self.table.partition_keys().is_empty() {
+                vec![self.table.table_location()?]
+            } else {
+                let mut dirs = self
+                    .files
+                    .iter()
+                    .map(|f| f.dir.clone())
+                    .collect::<BTreeSet<_>>();
+                if let Some(partition) = &self.overwrite_partition {
+                    let (dir, _) = self
+                        .table
+                        .partition_dir(self.ctx.clone(), partition)
+                        .await?;
+                    dirs.insert(dir);
+                }
+                dirs.into_iter().collect()
+            };
+            self.table.remove_files_in_dirs(dirs).await?;
+        }
+
+        for file in self.files.iter() {
+            self.table.move_file(&file.staged, &file.path).await?;
+        }
+
+        let new_partitions = self
+            .files
+            .iter()
+            .filter(|f| f.new_partition)
+            .filter_map(|f| f.partition.clone())
+            .collect::<BTreeSet<_>>();
+        if !new_partitions.is_empty() {
+            let (db_name, table_name) = self.table.db_and_table_name();
+            let catalog = self.ctx.get_catalog(CATALOG_HIVE).await?;
+            let catalog = catalog.as_any().downcast_ref::<HiveCatalog>().unwrap();
+            for partition in new_partitions {
+                catalog
+                    .add_partition(db_name.clone(), table_name.clone(), partition)
+                    .await?;
+            }
+        }
+
+        info!(
+            "commit hive table {}, {} files written",
+            self.table.get_table_info().desc,
+            self.files.len()
+        );
+        Ok(())
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, mut data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block.take_meta() {
+            if let Some(files) = HiveWrittenFiles::downcast_from(meta) {
+                self.files.extend(files.files);
+            }
+        }
+        Ok(false)
+    }
+}
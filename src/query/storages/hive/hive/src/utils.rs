@@ -18,6 +18,7 @@ use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
 use ordered_float::OrderedFloat;
 
 use crate::hive_table::HIVE_DEFAULT_PARTITION;
@@ -80,3 +81,70 @@ pub(crate) fn str_field_to_scalar(value: &str, data_type: &DataType) -> Result<S
         ))),
     }
 }
+
+/// Convert a value of a partition column to the string in the partition name,
+/// the reverse of [`str_field_to_scalar`].
+pub(crate) fn scalar_to_partition_value(value: ScalarRef) -> Result<String> {
+    match value {
+        ScalarRef::Null => Ok(HIVE_DEFAULT_PARTITION.to_string()),
+        ScalarRef::String(v) if v.is_empty() => Ok(HIVE_DEFAULT_PARTITION.to_string()),
+        ScalarRef::String(v) => Ok(escape_partition_value(v)),
+        ScalarRef::Number(v) => Ok(v.to_string()),
+        _ => Err(ErrorCode::Unimplemented(format!(
+            "generate hive partition value failed, {:?}",
+            value
+        ))),
+    }
+}
+
+// escape the characters not allowed in paths as `%XX`, like `FileUtils.escapePathName` in hive
+fn escape_partition_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\u{01}'..='\u{1F}'
+            | '"'
+            | '#'
+            | '%'
+            | '\''
+            | '*'
+            | '/'
+            | ':'
+            | '='
+            | '?'
+            | '\\'
+            | '\u{7F}'
+            | '{'
+            | '['
+            | ']'
+            | '^' => escaped.push_str(&format!("%{:02X}", c as u32)),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// the reverse of `escape_partition_value`
+pub(crate) fn unescape_partition_value(value: &str) -> String {
+    if !value.contains('%') {
+        return value.to_string();
+    }
+    let bytes = value.as_bytes();
+    let mut unescaped = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let Some(b) = std::str::from_utf8(&bytes[i + 1..i + 3])
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                unescaped.push(b);
+                i += 3;
+                continue;
+            }
+        }
+        unescaped.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&unescaped).into_owned()
+}
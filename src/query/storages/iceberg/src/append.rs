@@ -0,0 +1,401 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::Schema as ArrowSchema;
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::NaiveTime;
+use chrono::Utc;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use databend_common_storage::DataOperator;
+use icelake::io::task_writer::TaskWriter;
+use icelake::types::AnyValue;
+use icelake::types::DataContentType;
+use icelake::types::DataFile;
+use icelake::types::DataFileFormat;
+use icelake::types::PrimitiveValue;
+use icelake::types::Struct;
+use icelake::types::StructValueBuilder;
+use uuid::Uuid;
+
+use crate::IcebergTable;
+
+/// The data files written by an [`IcebergDataFileWriter`], to be committed by the [`IcebergCommitSink`],
+/// which may be on another node.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct IcebergDataFiles {
+    pub data_files: Vec<WrittenDataFile>,
+}
+
+impl IcebergDataFiles {
+    pub fn create_block(data_files: Vec<DataFile>) -> Result<DataBlock> {
+        let data_files = data_files
+            .into_iter()
+            .map(WrittenDataFile::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataBlock::empty_with_meta(Box::new(IcebergDataFiles {
+            data_files,
+        })))
+    }
+}
+
+#[typetag::serde(name = "iceberg_data_files")]
+impl BlockMetaInfo for IcebergDataFiles {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        Self::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// A data file written by the task writer, which is always a parquet file of data.
+///
+/// The partition values are kept by field id, and rebuilt with the partition type of the table.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct WrittenDataFile {
+    file_path: String,
+    partition: Vec<(i32, Option<PartitionValue>)>,
+    record_count: i64,
+    file_size_in_bytes: i64,
+    column_sizes: Option<HashMap<i32, i64>>,
+    value_counts: Option<HashMap<i32, i64>>,
+    null_value_counts: Option<HashMap<i32, i64>>,
+    nan_value_counts: Option<HashMap<i32, i64>>,
+    distinct_counts: Option<HashMap<i32, i64>>,
+    lower_bounds: Option<HashMap<i32, Vec<u8>>>,
+    upper_bounds: Option<HashMap<i32, Vec<u8>>>,
+    key_metadata: Option<Vec<u8>>,
+    split_offsets: Option<Vec<i64>>,
+    sort_order_id: Option<i32>,
+}
+
+/// The value of a partition field, partition transforms always produce primitive values.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+enum PartitionValue {
+    Boolean(bool),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    Decimal(i128),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    Timestamp(NaiveDateTime),
+    Timestamptz(DateTime<Utc>),
+    String(String),
+    Uuid(Uuid),
+    Fixed(Vec<u8>),
+    Binary(Vec<u8>),
+}
+
+impl TryFrom<DataFile> for WrittenDataFile {
+    type Error = ErrorCode;
+
+    fn try_from(df: DataFile) -> Result<Self> {
+        let partition = df
+            .partition
+            .iter()
+            .map(|(field_id, value, _, _)| {
+                let value = value.map(PartitionValue::try_from).transpose()?;
+                Ok((field_id, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            file_path: df.file_path,
+            partition,
+            record_count: df.record_count,
+            file_size_in_bytes: df.file_size_in_bytes,
+            column_sizes: df.column_sizes,
+            value_counts: df.value_counts,
+            null_value_counts: df.null_value_counts,
+            nan_value_counts: df.nan_value_counts,
+            distinct_counts: df.distinct_counts,
+            lower_bounds: df.lower_bounds,
+            upper_bounds: df.upper_bounds,
+            key_metadata: df.key_metadata,
+            split_offsets: df.split_offsets,
+            sort_order_id: df.sort_order_id,
+        })
+    }
+}
+
+impl WrittenDataFile {
+    /// Rebuild the data file with the partition type of the spec it is written with.
+    pub fn into_data_file(self, partition_type: &Arc<Struct>) -> Result<DataFile> {
+        let err = |e: icelake::Error| {
+            ErrorCode::StorageOther(format!("Cannot rebuild iceberg partition value: {e:?}"))
+        };
+        let mut partition = StructValueBuilder::new(partition_type.clone());
+        for (field_id, value) in self.partition {
+            let value = value.map(|v| AnyValue::Primitive(v.into()));
+            partition.add_field(field_id, value).map_err(err)?;
+        }
+        Ok(DataFile {
+            content: DataContentType::Data,
+            file_path: self.file_path,
+            file_format: DataFileFormat::Parquet,
+            partition: partition.build().map_err(err)?,
+            record_count: self.record_count,
+            file_size_in_bytes: self.file_size_in_bytes,
+            column_sizes: self.column_sizes,
+            value_counts: self.value_counts,
+            null_value_counts: self.null_value_counts,
+            nan_value_counts: self.nan_value_counts,
+            distinct_counts: self.distinct_counts,
+            lower_bounds: self.lower_bounds,
+            upper_bounds: self.upper_bounds,
+            key_metadata: self.key_metadata,
+            split_offsets: self.split_offsets,
+            equality_ids: None,
+            sort_order_id: self.sort_order_id,
+        })
+    }
+}
+
+impl TryFrom<&AnyValue> for PartitionValue {
+    type Error = ErrorCode;
+
+    fn try_from(value: &AnyValue) -> Result<Self> {
+        let AnyValue::Primitive(value) = value else {
+            return Err(ErrorCode::StorageOther(format!(
+                "Unexpected iceberg partition value {value:?}"
+            )));
+        };
+        Ok(match value.clone() {
+            PrimitiveValue::Boolean(v) => PartitionValue::Boolean(v),
+            PrimitiveValue::Int(v) => PartitionValue::Int(v),
+            PrimitiveValue::Long(v) => PartitionValue::Long(v),
+            PrimitiveValue::Float(v) => PartitionValue::Float(v.0),
+            PrimitiveValue::Double(v) => PartitionValue::Double(v.0),
+            PrimitiveValue::Decimal(v) => PartitionValue::Decimal(v),
+            PrimitiveValue::Date(v) => PartitionValue::Date(v),
+            PrimitiveValue::Time(v) => PartitionValue::Time(v),
+            PrimitiveValue::Timestamp(v) => PartitionValue::Timestamp(v),
+            PrimitiveValue::Timestamptz(v) => PartitionValue::Timestamptz(v),
+            PrimitiveValue::String(v) => PartitionValue::String(v),
+            PrimitiveValue::Uuid(v) => PartitionValue::Uuid(v),
+            PrimitiveValue::Fixed(v) => PartitionValue::Fixed(v),
+            PrimitiveValue::Binary(v) => PartitionValue::Binary(v),
+        })
+    }
+}
+
+impl From<PartitionValue> for PrimitiveValue {
+    fn from(value: PartitionValue) -> Self {
+        match value {
+            PartitionValue::Boolean(v) => PrimitiveValue::Boolean(v),
+            PartitionValue::Int(v) => PrimitiveValue::Int(v),
+            PartitionValue::Long(v) => PrimitiveValue::Long(v),
+            PartitionValue::Float(v) => PrimitiveValue::Float(v.into()),
+            PartitionValue::Double(v) => PrimitiveValue::Double(v.into()),
+            PartitionValue::Decimal(v) => PrimitiveValue::Decimal(v),
+            PartitionValue::Date(v) => PrimitiveValue::Date(v),
+            PartitionValue::Time(v) => PrimitiveValue::Time(v),
+            PartitionValue::Timestamp(v) => PrimitiveValue::Timestamp(v),
+            PartitionValue::Timestamptz(v) => PrimitiveValue::Timestamptz(v),
+            PartitionValue::String(v) => PrimitiveValue::String(v),
+            PartitionValue::Uuid(v) => PrimitiveValue::Uuid(v),
+            PartitionValue::Fixed(v) => PrimitiveValue::Fixed(v),
+            PartitionValue::Binary(v) => PrimitiveValue::Binary(v),
+        }
+    }
+}
+
+/// Write the blocks into parquet data files with the task writer of icelake,
+/// which fans the rows out to data files by the partition spec of the table.
+///
+/// The data files are not visible until they are committed by the [`IcebergCommitSink`].
+pub struct IcebergDataFileWriter {
+    table: Arc<IcebergTable>,
+    data_schema: DataSchema,
+    /// The schema expected by the writer, with the field ids of the iceberg schema.
+    arrow_schema: Option<ArrowSchemaRef>,
+    writer: Option<TaskWriter>,
+}
+
+impl IcebergDataFileWriter {
+    pub fn create(table: Arc<IcebergTable>) -> Self {
+        let data_schema = DataSchema::from(table.schema());
+        Self {
+            table,
+            data_schema,
+            arrow_schema: None,
+            writer: None,
+        }
+    }
+
+    async fn writer(&mut self) -> Result<(&mut TaskWriter, ArrowSchemaRef)> {
+        if self.writer.is_none() {
+            let table = self.table.table().await?;
+            let schema = table
+                .current_table_metadata()
+                .schemas
+                .last()
+                .ok_or_else(|| ErrorCode::StorageOther("Iceberg table schema is empty"))?;
+            let arrow_schema: ArrowSchema = schema.clone().try_into().map_err(|e| {
+                ErrorCode::StorageOther(format!("Cannot convert iceberg table schema: {e:?}"))
+            })?;
+            let writer = table.task_writer().await.map_err(|e| {
+                ErrorCode::StorageOther(format!("Cannot create iceberg task writer: {e:?}"))
+            })?;
+            self.arrow_schema = Some(Arc::new(arrow_schema));
+            self.writer = Some(writer);
+        }
+        Ok((
+            self.writer.as_mut().unwrap(),
+            self.arrow_schema.clone().unwrap(),
+        ))
+    }
+
+    /// Cast the columns to the types of the iceberg schema, e.g. databend strings are written
+    /// as `LargeUtf8`, while iceberg strings are `Utf8`.
+    fn cast_batch(batch: RecordBatch, arrow_schema: ArrowSchemaRef) -> Result<RecordBatch> {
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(arrow_schema.fields())
+            .map(|(column, field)| {
+                if column.data_type() == field.data_type() {
+                    Ok(column.clone())
+                } else {
+                    arrow_cast::cast(column, field.data_type()).map_err(|e| {
+                        ErrorCode::StorageOther(format!(
+                            "Cannot cast column {} to {} for iceberg: {e}",
+                            field.name(),
+                            field.data_type()
+                        ))
+                    })
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        RecordBatch::try_new(arrow_schema, columns).map_err(|e| {
+            ErrorCode::StorageOther(format!("Cannot build record batch for iceberg: {e}"))
+        })
+    }
+}
+
+#[async_trait]
+impl AsyncAccumulatingTransform for IcebergDataFileWriter {
+    const NAME: &'static str = "IcebergDataFileWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        let batch = data.to_record_batch(&self.data_schema)?;
+        let (writer, arrow_schema) = self.writer().await?;
+        let batch = Self::cast_batch(batch, arrow_schema)?;
+        writer.write(&batch).await.map_err(|e| {
+            ErrorCode::StorageOther(format!("Cannot write iceberg data file: {e:?}"))
+        })?;
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, output: bool) -> Result<Option<DataBlock>> {
+        let Some(writer) = self.writer.take() else {
+            return Ok(None);
+        };
+        let data_files = writer.close().await.map_err(|e| {
+            ErrorCode::StorageOther(format!("Cannot close iceberg data file: {e:?}"))
+        })?;
+        match output {
+            true => Ok(Some(IcebergDataFiles::create_block(data_files)?)),
+            false => {
+                // The insertion is aborted, the data files will never be committed.
+                let op = DataOperator::try_new(self.table.get_storage_params()?)?;
+                let table = self.table.table().await?;
+                IcebergTable::remove_data_files(&op, table, &data_files).await;
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Collect the data files written by all the writers, and commit them as a new snapshot.
+///
+/// The files copied by `COPY INTO` are recorded after the snapshot is committed.
+pub struct IcebergCommitSink {
+    ctx: Arc<dyn TableContext>,
+    table: Arc<IcebergTable>,
+    copied_files: Option<UpsertTableCopiedFileReq>,
+    data_files: Vec<WrittenDataFile>,
+}
+
+impl IcebergCommitSink {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        table: Arc<IcebergTable>,
+        copied_files: Option<UpsertTableCopiedFileReq>,
+    ) -> Self {
+        Self {
+            ctx,
+            table,
+            copied_files,
+            data_files: vec![],
+        }
+    }
+}
+
+#[async_trait]
+impl AsyncSink for IcebergCommitSink {
+    const NAME: &'static str = "IcebergCommitSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        if !self.data_files.is_empty() {
+            let data_files = std::mem::take(&mut self.data_files);
+            self.table.commit_data_files(data_files).await?;
+        }
+        if let Some(copied_files) = self.copied_files.take() {
+            self.table
+                .record_copied_files(self.ctx.clone(), copied_files)
+                .await?;
+        }
+        Ok(())
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, mut data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block.take_meta() {
+            if let Some(files) = IcebergDataFiles::downcast_from(meta) {
+                self.data_files.extend(files.data_files);
+            }
+        }
+        Ok(false)
+    }
+}
@@ -53,6 +53,7 @@ use databend_common_meta_app::schema::GetIndexReply;
 use databend_common_meta_app::schema::GetIndexReq;
 use databend_common_meta_app::schema::GetTableCopiedFileReply;
 use databend_common_meta_app::schema::GetTableCopiedFileReq;
+use databend_common_meta_app::schema::IcebergCatalogOption;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
@@ -91,6 +92,7 @@ use futures::TryStreamExt;
 use opendal::Metakey;
 
 use crate::database::IcebergDatabase;
+use crate::rest_catalog::RestCatalogClient;
 use crate::IcebergTable;
 
 pub const ICEBERG_CATALOG: &str = "iceberg";
@@ -128,6 +130,10 @@ pub struct IcebergCatalog {
 
     /// underlying storage access operator
     operator: DataOperator,
+
+    /// REST catalog to discover databases and tables from, if configured,
+    /// instead of reading subdirectories.
+    rest: Option<Arc<RestCatalogClient>>,
 }
 
 impl IcebergCatalog {
@@ -147,13 +153,38 @@ impl IcebergCatalog {
     /// a `default` database will be generated directly
     #[minitrace::trace]
     pub fn try_create(info: CatalogInfo, operator: DataOperator) -> Result<Self> {
-        Ok(Self { info, operator })
+        let rest = match &info.meta.catalog_option {
+            CatalogOption::Iceberg(IcebergCatalogOption {
+                rest_catalog: Some(opt),
+                ..
+            }) => Some(Arc::new(RestCatalogClient::try_create(opt.clone())?)),
+            _ => None,
+        };
+        Ok(Self {
+            info,
+            operator,
+            rest,
+        })
     }
 
     /// list read databases
     #[minitrace::trace]
     #[async_backtrace::framed]
     pub async fn list_database_from_read(&self) -> Result<Vec<Arc<dyn Database>>> {
+        if let Some(rest) = &self.rest {
+            let mut dbs = vec![];
+            for db_name in rest.list_namespaces().await? {
+                let db: Arc<dyn Database> = Arc::new(IcebergDatabase::create_from_rest(
+                    &self.name(),
+                    &db_name,
+                    self.operator.clone(),
+                    rest.clone(),
+                ));
+                dbs.push(db);
+            }
+            return Ok(dbs);
+        }
+
         let op = self.operator.operator();
         let mut dbs = vec![];
         let mut ls = op.lister_with("/").metakey(Metakey::Mode).await?;
@@ -187,6 +218,20 @@ impl Catalog for IcebergCatalog {
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn get_database(&self, _tenant: &str, db_name: &str) -> Result<Arc<dyn Database>> {
+        if let Some(rest) = &self.rest {
+            if !rest.namespace_exists(db_name).await? {
+                return Err(ErrorCode::UnknownDatabase(format!(
+                    "Database {db_name} does not exist"
+                )));
+            }
+            return Ok(Arc::new(IcebergDatabase::create_from_rest(
+                &self.name(),
+                db_name,
+                self.operator.clone(),
+                rest.clone(),
+            )));
+        }
+
         let rel_path = format!("{db_name}/");
 
         let operator = self.operator.operator();
@@ -367,7 +412,10 @@ impl Catalog for IcebergCatalog {
         _db_name: &str,
         _req: GetTableCopiedFileReq,
     ) -> Result<GetTableCopiedFileReply> {
-        unimplemented!()
+        // Copied files are not tracked for iceberg tables, so no file is filtered out by COPY INTO.
+        Ok(GetTableCopiedFileReply {
+            file_info: Default::default(),
+        })
     }
 
     #[async_backtrace::framed]
@@ -0,0 +1,231 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::UpsertKV;
+use databend_common_users::UserApiProvider;
+use opendal::raw::Accessor;
+use opendal::raw::Layer;
+use opendal::raw::LayeredAccessor;
+use opendal::raw::OpList;
+use opendal::raw::OpRead;
+use opendal::raw::OpWrite;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpWrite;
+use opendal::Error;
+use opendal::ErrorKind;
+use opendal::Operator;
+use opendal::Result;
+
+/// The prefix of the keys claiming the metadata versions of iceberg tables in the meta service.
+const COMMIT_KEY_PREFIX: &str = "__fd_iceberg_commits";
+
+/// A claim only has to outlive the commit, the metadata file exists after that.
+const COMMIT_CLAIM_TTL: Duration = Duration::from_secs(300);
+
+/// The state of one attempt to commit an iceberg table managed by the storage.
+///
+/// The storage offers no conditional write, so the metadata file of a new version is created
+/// conditionally by claiming the version in the meta service first:
+///
+/// - the write fails if a metadata file of the version exists, which is best effort against
+///   other engines committing the table;
+/// - the write fails if the version is claimed, which is atomic among the databend nodes.
+///
+/// A failed write marks the attempt as conflicted, the commit should be retried on the latest
+/// metadata.
+pub struct MetadataCommit {
+    meta: Arc<MetaStore>,
+    conflicted: AtomicBool,
+    /// The metadata file claimed by the attempt, with the operator it is written by.
+    claimed: Mutex<Option<(Operator, String)>>,
+}
+
+impl MetadataCommit {
+    pub fn create() -> Arc<Self> {
+        Arc::new(Self {
+            meta: UserApiProvider::instance().get_meta_store_client(),
+            conflicted: AtomicBool::new(false),
+            claimed: Mutex::new(None),
+        })
+    }
+
+    /// Whether the metadata version is committed by someone else.
+    pub fn is_conflicted(&self) -> bool {
+        self.conflicted.load(Ordering::SeqCst)
+    }
+
+    /// Whether the metadata file claimed by the attempt is written, then the commit may be
+    /// visible even if the attempt fails.
+    pub async fn is_published(&self) -> databend_common_exception::Result<bool> {
+        let claimed = self.claimed.lock().unwrap().clone();
+        match claimed {
+            Some((op, path)) => Ok(op.is_exist(&path).await?),
+            None => Ok(false),
+        }
+    }
+
+    /// Layer the operator to create the metadata files conditionally.
+    ///
+    /// `key` identifies the root of the operator, e.g. the storage params.
+    pub fn layer(self: &Arc<Self>, op: Operator, key: String) -> Operator {
+        op.clone().layer(MetadataCommitLayer {
+            commit: self.clone(),
+            op,
+            key,
+        })
+    }
+
+    async fn claim(&self, op: &Operator, key: &str, path: &str, version: u64) -> Result<()> {
+        let dir = match path.rfind('/') {
+            Some(idx) => &path[..=idx],
+            None => "",
+        };
+        let exists = op
+            .list(dir)
+            .await?
+            .iter()
+            .any(|entry| metadata_version(entry.name()) == Some(version));
+        if !exists {
+            let key = format!("{COMMIT_KEY_PREFIX}/{key}/{dir}{version}");
+            let reply = self
+                .meta
+                .upsert_kv(UpsertKV::insert(key, b"").with_ttl(COMMIT_CLAIM_TTL))
+                .await
+                .map_err(|e| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        &format!("claim iceberg metadata version {version}: {e}"),
+                    )
+                })?;
+            if reply.is_changed() {
+                *self.claimed.lock().unwrap() = Some((op.clone(), path.to_string()));
+                return Ok(());
+            }
+        }
+
+        self.conflicted.store(true, Ordering::SeqCst);
+        Err(Error::new(
+            ErrorKind::ConditionNotMatch,
+            &format!("iceberg metadata version {version} is committed concurrently"),
+        ))
+    }
+}
+
+/// The version of a metadata file, named `v{version}.metadata.json`
+/// or `{version}-{uuid}.metadata.json`.
+fn metadata_version(path: &str) -> Option<u64> {
+    let name = path.rsplit('/').next()?;
+    let name = name.strip_suffix(".metadata.json")?;
+    match name.strip_prefix('v') {
+        Some(version) => version.parse().ok(),
+        None => name.split_once('-')?.0.parse().ok(),
+    }
+}
+
+#[derive(Clone)]
+struct MetadataCommitLayer {
+    commit: Arc<MetadataCommit>,
+    /// The operator without the layer, to list the existing metadata files.
+    op: Operator,
+    key: String,
+}
+
+impl<A: Accessor> Layer<A> for MetadataCommitLayer {
+    type LayeredAccessor = MetadataCommitAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        MetadataCommitAccessor {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+struct MetadataCommitAccessor<A> {
+    inner: A,
+    layer: MetadataCommitLayer,
+}
+
+impl<A: Accessor> Debug for MetadataCommitAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MetadataCommitAccessor")
+            .field("inner", &self.inner)
+            .field("key", &self.layer.key)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for MetadataCommitAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        if let Some(version) = metadata_version(path) {
+            let layer = &self.layer;
+            layer
+                .commit
+                .claim(&layer.op, &layer.key, path, version)
+                .await?;
+        }
+        self.inner.write(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        if metadata_version(path).is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "iceberg metadata files must be committed asynchronously",
+            ));
+        }
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
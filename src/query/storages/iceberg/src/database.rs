@@ -30,6 +30,7 @@ use futures::StreamExt;
 use opendal::EntryMode;
 use opendal::Metakey;
 
+use crate::rest_catalog::RestCatalogClient;
 use crate::table::IcebergTable;
 
 #[derive(Clone, Debug)]
@@ -37,7 +38,11 @@ pub struct IcebergDatabase {
     /// catalog this database belongs to
     ctl_name: String,
     /// operator pointing to the directory holding iceberg tables
+    ///
+    /// For namespaces of a REST catalog, it points to the root of the catalog instead.
     db_root: DataOperator,
+    /// REST catalog to load tables from, if this database is a namespace of it
+    rest: Option<Arc<RestCatalogClient>>,
     /// database information
     info: DatabaseInfo,
 }
@@ -61,9 +66,23 @@ impl IcebergDatabase {
         Self {
             ctl_name: ctl_name.to_string(),
             db_root,
+            rest: None,
             info,
         }
     }
+
+    /// create a new database on a namespace of the REST catalog
+    pub fn create_from_rest(
+        ctl_name: &str,
+        db_name: &str,
+        ctl_root: DataOperator,
+        rest: Arc<RestCatalogClient>,
+    ) -> Self {
+        Self {
+            rest: Some(rest),
+            ..Self::create(ctl_name, db_name, ctl_root)
+        }
+    }
 }
 
 #[async_trait]
@@ -78,6 +97,20 @@ impl Database for IcebergDatabase {
 
     #[async_backtrace::framed]
     async fn get_table(&self, table_name: &str) -> Result<Arc<dyn Table>> {
+        if let Some(rest) = &self.rest {
+            let db_name = &self.info.name_ident.db_name;
+            let loaded = rest.load_table(db_name, table_name).await?;
+            let tbl = IcebergTable::try_create_from_rest_catalog(
+                &self.ctl_name,
+                db_name,
+                table_name,
+                &self.db_root.params(),
+                loaded,
+            )
+            .await?;
+            return Ok(Arc::new(tbl));
+        }
+
         let path = format!("{table_name}/");
         let op = self.db_root.operator();
         // check existence first
@@ -106,6 +139,13 @@ impl Database for IcebergDatabase {
     #[async_backtrace::framed]
     async fn list_tables(&self) -> Result<Vec<Arc<dyn Table>>> {
         let mut tables = vec![];
+        if let Some(rest) = &self.rest {
+            for tbl_name in rest.list_tables(&self.info.name_ident.db_name).await? {
+                tables.push(self.get_table(&tbl_name).await?);
+            }
+            return Ok(tables);
+        }
+
         let op = self.db_root.operator();
         let mut lister = op.lister_with("/").metakey(Metakey::Mode).await?;
         while let Some(entry) = lister.next().await.transpose()? {
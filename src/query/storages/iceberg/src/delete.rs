@@ -0,0 +1,409 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Merge-on-read support for iceberg v2 tables.
+//!
+//! Writers such as Spark and Flink record row-level deletes in delete files instead of
+//! rewriting data files, see [Row-level Deletes](https://iceberg.apache.org/spec/#row-level-deletes):
+//!
+//! - a position delete file lists `(file_path, pos)` of the deleted rows;
+//! - an equality delete file lists the values of some columns, every row matching
+//!   one of them is deleted.
+//!
+//! The delete files applying to a data file are collected while planning ([`DeleteFileIndex`]),
+//! and the rows are filtered while reading the data file ([`IcebergDeleteReader`]).
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow_array::Array;
+use arrow_array::Int64Array;
+use arrow_array::RecordBatch;
+use arrow_array::StringArray;
+use bytes::Bytes;
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_catalog::plan::ParquetReadOptions;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_storages_parquet::ParquetRSFullReader;
+use databend_common_storages_parquet::ParquetRSReaderBuilder;
+use icelake::types::DataContentType;
+use icelake::types::DataFile;
+use icelake::types::Schema;
+use icelake::types::StructValue;
+use opendal::Operator;
+use opendal::Reader;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::async_reader::ParquetRecordBatchStream;
+
+/// A delete file which applies to a data file.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct IcebergDeleteFile {
+    /// The location relative to the table.
+    pub location: String,
+    pub content: IcebergDeleteContent,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub enum IcebergDeleteContent {
+    Position,
+    /// The names of the equality columns.
+    Equality(Vec<String>),
+}
+
+/// A delete file with the metadata used to decide which data files it applies to.
+struct IndexedDeleteFile {
+    sequence_number: i64,
+    spec_id: i32,
+    partition: StructValue,
+    /// Whether the delete file is written with an unpartitioned spec,
+    /// then it applies to the data files of all partitions.
+    global: bool,
+    file: IcebergDeleteFile,
+}
+
+/// Index the delete files of a snapshot by their scopes.
+pub struct DeleteFileIndex {
+    /// The names of the columns of the table schema, by the iceberg field id.
+    column_names: HashMap<i32, String>,
+    position_deletes: Vec<IndexedDeleteFile>,
+    equality_deletes: Vec<IndexedDeleteFile>,
+}
+
+impl DeleteFileIndex {
+    /// `schema` is converted from `iceberg_schema`, the schema the snapshot is read with.
+    ///
+    /// The equality field ids are resolved by name, as the column ids of the converted
+    /// schema do not follow the iceberg field ids.
+    pub fn create(schema: &TableSchema, iceberg_schema: &Schema) -> Self {
+        let column_names = iceberg_schema
+            .fields()
+            .iter()
+            .filter(|f| schema.fields().iter().any(|field| field.name() == &f.name))
+            .map(|f| (f.id, f.name.clone()))
+            .collect();
+        Self {
+            column_names,
+            position_deletes: vec![],
+            equality_deletes: vec![],
+        }
+    }
+
+    /// Add a live delete file of the snapshot.
+    pub fn add(
+        &mut self,
+        location: String,
+        df: DataFile,
+        sequence_number: i64,
+        spec_id: i32,
+        global: bool,
+    ) -> Result<()> {
+        let (content, deletes) = match df.content {
+            DataContentType::PositionDeletes => {
+                (IcebergDeleteContent::Position, &mut self.position_deletes)
+            }
+            DataContentType::EqualityDeletes => {
+                let field_ids = df.equality_ids.clone().unwrap_or_default();
+                let columns = field_ids
+                    .iter()
+                    .map(|id| {
+                        self.column_names.get(id).cloned().ok_or_else(|| {
+                            ErrorCode::ReadTableDataError(format!(
+                                "Equality delete file {} references unknown field id {}",
+                                df.file_path, id
+                            ))
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                if columns.is_empty() {
+                    return Err(ErrorCode::ReadTableDataError(format!(
+                        "Equality delete file {} has no equality field ids",
+                        df.file_path
+                    )));
+                }
+                (
+                    IcebergDeleteContent::Equality(columns),
+                    &mut self.equality_deletes,
+                )
+            }
+            DataContentType::Data => unreachable!("data file is not a delete file"),
+        };
+        deletes.push(IndexedDeleteFile {
+            sequence_number,
+            spec_id,
+            partition: df.partition,
+            global,
+            file: IcebergDeleteFile { location, content },
+        });
+        Ok(())
+    }
+
+    /// Collect the delete files applying to a data file.
+    ///
+    /// - a position delete file applies to the data files of the same partition,
+    ///   whose data sequence number is not greater than that of the delete file;
+    /// - an equality delete file applies to the data files of the same partition (or all the data
+    ///   files if it's global), whose data sequence number is less than that of the delete file.
+    pub fn for_data_file(
+        &self,
+        sequence_number: i64,
+        spec_id: i32,
+        partition: &StructValue,
+    ) -> Vec<IcebergDeleteFile> {
+        let same_partition =
+            |d: &IndexedDeleteFile| d.spec_id == spec_id && &d.partition == partition;
+        let position_deletes = self
+            .position_deletes
+            .iter()
+            .filter(|d| d.sequence_number >= sequence_number && same_partition(d));
+        let equality_deletes = self
+            .equality_deletes
+            .iter()
+            .filter(|d| d.sequence_number > sequence_number && (d.global || same_partition(d)));
+        position_deletes
+            .chain(equality_deletes)
+            .map(|d| d.file.clone())
+            .collect()
+    }
+}
+
+/// The rows of a data file deleted by its delete files.
+pub struct DeleteFilter {
+    /// The positions of the deleted rows.
+    positions: HashSet<u64>,
+    equality_deletes: Vec<EqualityDeletes>,
+}
+
+struct EqualityDeletes {
+    /// The offsets of the equality columns in the table schema.
+    columns: Vec<FieldIndex>,
+    keys: HashSet<Vec<Scalar>>,
+}
+
+impl DeleteFilter {
+    fn is_deleted(&self, block: &DataBlock, row: usize, pos: u64) -> bool {
+        if self.positions.contains(&pos) {
+            return true;
+        }
+        self.equality_deletes.iter().any(|deletes| {
+            let key = deletes
+                .columns
+                .iter()
+                .map(|i| {
+                    let entry = block.get_by_offset(*i);
+                    entry.value.index(row).unwrap().to_owned()
+                })
+                .collect::<Vec<_>>();
+            deletes.keys.contains(&key)
+        })
+    }
+
+    /// Filter out the deleted rows of a block, `row_offset` is the position of its first row in the file.
+    fn filter(&self, block: DataBlock, row_offset: u64) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let mut bitmap = MutableBitmap::from_len_set(num_rows);
+        for row in 0..num_rows {
+            if self.is_deleted(&block, row, row_offset + row as u64) {
+                bitmap.set(row, false);
+            }
+        }
+        block.filter_with_bitmap(&bitmap.into())
+    }
+}
+
+/// Read the data files with delete files.
+///
+/// Row positions are needed to apply position deletes, so the data files are read
+/// as a whole, without pruning or prewhere. All the columns are read as the equality
+/// columns may not be in the output, the output columns are projected after filtering.
+pub struct IcebergDeleteReader {
+    op: Operator,
+    table_schema: TableSchemaRef,
+    reader: ParquetRSFullReader,
+    /// The offsets of the output columns in the table schema.
+    ///
+    /// It's `None` if the output is an inner projection, which is not supported yet.
+    output_projection: Option<Vec<FieldIndex>>,
+}
+
+impl IcebergDeleteReader {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        op: Operator,
+        table_schema: TableSchemaRef,
+        output_schema: &DataSchema,
+    ) -> Result<Self> {
+        let arrow_schema = table_schema.as_ref().into();
+        let reader =
+            ParquetRSReaderBuilder::create(ctx, op.clone(), table_schema.clone(), &arrow_schema)?
+                .with_options(ParquetReadOptions::default().with_prune_pages(false))
+                .build_full_reader()?;
+        let output_projection = output_schema
+            .fields()
+            .iter()
+            .map(|f| table_schema.index_of(f.name()).ok())
+            .collect();
+        Ok(Self {
+            op,
+            table_schema,
+            reader,
+            output_projection,
+        })
+    }
+
+    pub async fn prepare_data_stream(
+        &self,
+        location: &str,
+    ) -> Result<ParquetRecordBatchStream<Reader>> {
+        if self.output_projection.is_none() {
+            return Err(ErrorCode::Unimplemented(
+                "Reading inner columns of iceberg table with delete files is not supported",
+            ));
+        }
+        self.reader.prepare_data_stream(location, None).await
+    }
+
+    /// Read a block from the stream and filter out the deleted rows.
+    ///
+    /// `row_offset` is the position of the next row in the file, and is advanced by the rows read.
+    pub async fn read_block_from_stream(
+        &self,
+        stream: &mut ParquetRecordBatchStream<Reader>,
+        deletes: &DeleteFilter,
+        row_offset: &mut u64,
+    ) -> Result<Option<DataBlock>> {
+        let Some(block) = self.reader.read_block_from_stream(stream).await? else {
+            return Ok(None);
+        };
+        let num_rows = block.num_rows();
+        let block = deletes.filter(block, *row_offset)?;
+        *row_offset += num_rows as u64;
+
+        let projection = self.output_projection.as_ref().unwrap();
+        let columns = projection
+            .iter()
+            .map(|i| block.get_by_offset(*i).clone())
+            .collect();
+        Ok(Some(DataBlock::new(columns, block.num_rows())))
+    }
+
+    /// Load the delete files of the data file at `file_path`.
+    #[async_backtrace::framed]
+    pub async fn load_deletes(
+        &self,
+        file_path: &str,
+        files: &[IcebergDeleteFile],
+    ) -> Result<DeleteFilter> {
+        let mut filter = DeleteFilter {
+            positions: HashSet::new(),
+            equality_deletes: vec![],
+        };
+        for file in files {
+            let batches = self.read_delete_file(&file.location).await?;
+            match &file.content {
+                IcebergDeleteContent::Position => {
+                    for batch in batches.iter() {
+                        read_positions(&file.location, batch, file_path, &mut filter.positions)?;
+                    }
+                }
+                IcebergDeleteContent::Equality(columns) => {
+                    let deletes = self.read_equality_keys(&file.location, &batches, columns)?;
+                    filter.equality_deletes.push(deletes);
+                }
+            }
+        }
+        Ok(filter)
+    }
+
+    async fn read_delete_file(&self, location: &str) -> Result<Vec<RecordBatch>> {
+        let data = self.op.read(location).await?;
+        let reader = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(data))?.build()?;
+        let batches = reader.collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(batches)
+    }
+
+    fn read_equality_keys(
+        &self,
+        location: &str,
+        batches: &[RecordBatch],
+        columns: &[String],
+    ) -> Result<EqualityDeletes> {
+        let offsets = columns
+            .iter()
+            .map(|c| self.table_schema.index_of(c))
+            .collect::<Result<Vec<_>>>()?;
+        let mut keys = HashSet::new();
+        for batch in batches {
+            let indices = columns
+                .iter()
+                .map(|c| {
+                    batch.schema().index_of(c).map_err(|_| {
+                        ErrorCode::ReadTableDataError(format!(
+                            "Equality delete file {} does not contain column {}",
+                            location, c
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let batch = batch.project(&indices)?;
+            let schema = TableSchema::try_from(batch.schema().as_ref())?;
+            let (block, _) = DataBlock::from_record_batch(&DataSchema::from(&schema), &batch)?;
+            for row in 0..block.num_rows() {
+                let key = block
+                    .columns()
+                    .iter()
+                    .map(|entry| entry.value.index(row).unwrap().to_owned())
+                    .collect();
+                keys.insert(key);
+            }
+        }
+        Ok(EqualityDeletes {
+            columns: offsets,
+            keys,
+        })
+    }
+}
+
+/// Collect the positions of the rows deleted from the data file at `file_path`.
+fn read_positions(
+    location: &str,
+    batch: &RecordBatch,
+    file_path: &str,
+    positions: &mut HashSet<u64>,
+) -> Result<()> {
+    let invalid =
+        || ErrorCode::ReadTableDataError(format!("Invalid position delete file {location}"));
+    let paths = batch
+        .column_by_name("file_path")
+        .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+        .ok_or_else(invalid)?;
+    let pos = batch
+        .column_by_name("pos")
+        .and_then(|c| c.as_any().downcast_ref::<Int64Array>())
+        .ok_or_else(invalid)?;
+    for i in 0..batch.num_rows() {
+        if paths.is_valid(i) && paths.value(i) == file_path {
+            positions.insert(pos.value(i) as u64);
+        }
+    }
+    Ok(())
+}
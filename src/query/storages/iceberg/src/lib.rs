@@ -86,19 +86,52 @@
 //! ```sql
 //! SELECT * FROM icb_ctl.default.icbg_tbl_0;
 //! ```
+//!
+//! ## REST Catalogs
+//!
+//! Databases and tables can also be discovered from an [Iceberg REST catalog](https://github.com/apache/iceberg/blob/main/open-api/rest-catalog-open-api.yaml)
+//! instead of walking the storage. Namespaces are mapped to databases, and the tables are loaded
+//! with the metadata returned by the catalog. The storage connection is still required to read the
+//! data files, the tables should be located in the bucket of the connection.
+//! ```sql
+//! CREATE CATALOG icb_ctl TYPE=ICEBERG CONNECTION=(
+//! URL='s3://warehouse/'
+//! REST_URI='http://127.0.0.1:8181'
+//! REST_WAREHOUSE='warehouse'   -- optional
+//! REST_PREFIX='prefix'         -- optional, overridden by the config of the catalog
+//! REST_CREDENTIAL='id:secret'  -- optional, or REST_TOKEN='<token>'
+//! ... -- credentials and other options
+//! )
+//! ```
+//!
+//! ## Writing
+//!
+//! Tables managed by the storage accept `INSERT INTO` and `COPY INTO`. The rows are written into
+//! parquet data files by the partition spec of the table, and committed as a new snapshot,
+//! which is retried on the latest metadata if the table is committed concurrently.
+//! `INSERT OVERWRITE` and writing to tables of REST catalogs are not supported yet.
 
 #![feature(lazy_cell)]
 #![feature(impl_trait_in_assoc_type)]
 #![allow(clippy::diverging_sub_expression)]
 
+mod append;
 mod catalog;
+mod commit;
 mod database;
+mod delete;
 mod partition;
+mod pruning;
+mod rest_catalog;
 mod stats;
 mod table;
+mod table_functions;
 mod table_source;
 
 pub use catalog::IcebergCatalog;
 pub use catalog::IcebergCreator;
 pub use catalog::ICEBERG_CATALOG;
+pub use rest_catalog::LoadTableResult;
+pub use rest_catalog::RestCatalogClient;
 pub use table::IcebergTable;
+pub use table_functions::IcebergSnapshotsTable;
@@ -20,12 +20,21 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_storages_parquet::ParquetPart;
 
+use crate::delete::IcebergDeleteFile;
+
 /// # TODO
 ///
 /// - we should support different format.
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
 pub enum IcebergPartInfo {
     Parquet(ParquetPart),
+    /// A parquet data file with the delete files applying to it.
+    ParquetWithDeletes {
+        part: ParquetPart,
+        /// The path of the data file recorded in the manifest, which position deletes refer to.
+        file_path: String,
+        deletes: Vec<IcebergDeleteFile>,
+    },
 }
 
 impl IcebergPartInfo {
@@ -51,6 +60,7 @@ impl PartInfo for IcebergPartInfo {
     fn hash(&self) -> u64 {
         match self {
             IcebergPartInfo::Parquet(p) => p.hash(),
+            IcebergPartInfo::ParquetWithDeletes { part, .. } => part.hash(),
         }
     }
 }
@@ -0,0 +1,322 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_storages_common_pruner::RangePruner;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use icelake::types::FieldSummary;
+use icelake::types::PartitionSpec;
+use icelake::types::Schema;
+use icelake::types::Transform;
+
+use crate::stats::parse_binary_value;
+
+const MICROS_PER_HOUR: i64 = 3_600_000_000;
+const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+
+/// Prune the manifests of a snapshot with the partition summaries in the manifest list.
+///
+/// The bounds of a partition field are converted to the bounds of its source column,
+/// which are checked with the range pruner of the filter. `bucket` partitions have no
+/// order relationship with the source column, they are checked with the `column = constant`
+/// predicates in the filter instead.
+pub struct ManifestPruner {
+    schema: TableSchemaRef,
+    /// The names of the top-level fields of the iceberg schema, by field id.
+    field_names: HashMap<i32, String>,
+    range_pruner: Arc<dyn RangePruner + Send + Sync>,
+    /// `column = constant` predicates in the conjunction of the filter,
+    /// with the type of the column.
+    equalities: HashMap<String, (Scalar, DataType)>,
+}
+
+impl ManifestPruner {
+    /// `schema` is converted from `iceberg_schema`, the schema the snapshot is read with.
+    pub fn create(
+        schema: TableSchemaRef,
+        iceberg_schema: &Schema,
+        range_pruner: Arc<dyn RangePruner + Send + Sync>,
+        filter: Option<&Expr<String>>,
+    ) -> Self {
+        let field_names = iceberg_schema
+            .fields()
+            .iter()
+            .map(|f| (f.id, f.name.clone()))
+            .collect();
+        let mut equalities = HashMap::new();
+        if let Some(filter) = filter {
+            collect_equalities(filter, &mut equalities);
+        }
+        Self {
+            schema,
+            field_names,
+            range_pruner,
+            equalities,
+        }
+    }
+
+    /// Returns true, if the manifest written with `spec` should NOT be pruned.
+    pub fn should_keep(&self, spec: &PartitionSpec, summaries: &[FieldSummary]) -> bool {
+        let mut stats = StatisticsOfColumns::new();
+        for (partition_field, summary) in spec.fields.iter().zip(summaries.iter()) {
+            let Some(field) = self.source_field(partition_field.source_column_id) else {
+                continue;
+            };
+            let (Some(lower), Some(upper)) = (&summary.lower_bound, &summary.upper_bound) else {
+                // All the values are null, or the bounds are not recorded.
+                continue;
+            };
+
+            if let Transform::Bucket(n) = partition_field.transform {
+                if !self.should_keep_bucket(field, n, lower, upper) {
+                    return false;
+                }
+                continue;
+            }
+
+            let Some((min, max)) = source_bounds(&partition_field.transform, field, lower, upper)
+            else {
+                continue;
+            };
+            let null_count = summary.contains_null as u64;
+            let stat = ColumnStatistics::new(min, max, null_count, 0, None);
+            let stat = match stats.remove(&field.column_id) {
+                Some(prev) => intersect(prev, stat),
+                None => stat,
+            };
+            stats.insert(field.column_id, stat);
+        }
+        stats.is_empty() || self.range_pruner.should_keep(&stats, None)
+    }
+
+    /// The field of the partition source column, which is resolved by name,
+    /// as the column ids of the converted schema do not follow the iceberg field ids.
+    fn source_field(&self, source_id: i32) -> Option<&TableField> {
+        let name = self.field_names.get(&source_id)?;
+        self.schema.fields().iter().find(|f| f.name() == name)
+    }
+
+    fn should_keep_bucket(&self, field: &TableField, n: i32, lower: &[u8], upper: &[u8]) -> bool {
+        let Some((value, data_type)) = self.equalities.get(field.name()) else {
+            return true;
+        };
+        // The bucket of a value depends on its type, e.g. an int is hashed as a long.
+        if DataType::from(&field.data_type.remove_nullable()) != *data_type {
+            return true;
+        }
+        let (Some(bucket), Ok(lower), Ok(upper)) = (
+            bucket(value, n),
+            <[u8; 4]>::try_from(lower).map(i32::from_le_bytes),
+            <[u8; 4]>::try_from(upper).map(i32::from_le_bytes),
+        ) else {
+            return true;
+        };
+        lower <= bucket && bucket <= upper
+    }
+}
+
+/// Convert the bounds of a partition field to the bounds of its source column.
+fn source_bounds(
+    transform: &Transform,
+    field: &TableField,
+    lower: &[u8],
+    upper: &[u8],
+) -> Option<(Scalar, Scalar)> {
+    let ty = field.data_type.remove_nullable();
+    match (transform, &ty) {
+        (Transform::Identity, _) => Some((
+            parse_binary_value(&ty, lower)?,
+            parse_binary_value(&ty, upper)?,
+        )),
+        // Truncating an integer rounds it down to a multiple of the width.
+        (Transform::Truncate(width), TableDataType::Number(_)) => {
+            let min = parse_binary_value(&ty, lower)?;
+            let max = match parse_binary_value(&ty, upper)? {
+                Scalar::Number(NumberScalar::Int32(v)) => {
+                    Scalar::Number(NumberScalar::Int32(v.checked_add(width - 1)?))
+                }
+                Scalar::Number(NumberScalar::Int64(v)) => {
+                    Scalar::Number(NumberScalar::Int64(v.checked_add(*width as i64 - 1)?))
+                }
+                _ => return None,
+            };
+            Some((min, max))
+        }
+        (Transform::Day, TableDataType::Date) => {
+            let (lower, upper) = int_bounds(lower, upper)?;
+            Some((Scalar::Date(lower), Scalar::Date(upper)))
+        }
+        (Transform::Day, TableDataType::Timestamp) => {
+            let (lower, upper) = int_bounds(lower, upper)?;
+            Some((
+                Scalar::Timestamp(lower as i64 * MICROS_PER_DAY),
+                Scalar::Timestamp((upper as i64 + 1) * MICROS_PER_DAY - 1),
+            ))
+        }
+        (Transform::Hour, TableDataType::Timestamp) => {
+            let (lower, upper) = int_bounds(lower, upper)?;
+            Some((
+                Scalar::Timestamp(lower as i64 * MICROS_PER_HOUR),
+                Scalar::Timestamp((upper as i64 + 1) * MICROS_PER_HOUR - 1),
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn int_bounds(lower: &[u8], upper: &[u8]) -> Option<(i32, i32)> {
+    Some((
+        i32::from_le_bytes(lower.try_into().ok()?),
+        i32::from_le_bytes(upper.try_into().ok()?),
+    ))
+}
+
+fn intersect(a: ColumnStatistics, b: ColumnStatistics) -> ColumnStatistics {
+    let min = std::cmp::max(a.min, b.min);
+    let max = std::cmp::min(a.max, b.max);
+    let null_count = std::cmp::min(a.null_count, b.null_count);
+    ColumnStatistics::new(min, max, null_count, 0, None)
+}
+
+/// Collect the `column = constant` predicates in the conjunction of `expr`.
+///
+/// Only the predicates whose constant has the type of the column are collected,
+/// a casted column may equal to values in different buckets.
+fn collect_equalities(expr: &Expr<String>, equalities: &mut HashMap<String, (Scalar, DataType)>) {
+    if let Expr::FunctionCall { function, args, .. } = expr {
+        match function.signature.name.as_str() {
+            "and" | "and_filters" => {
+                for arg in args {
+                    collect_equalities(arg, equalities);
+                }
+            }
+            "eq" if args.len() == 2 => {
+                let column_and_constant = match (&args[0], &args[1]) {
+                    (
+                        Expr::ColumnRef {
+                            id,
+                            data_type: column_type,
+                            ..
+                        },
+                        Expr::Constant {
+                            scalar, data_type, ..
+                        },
+                    )
+                    | (
+                        Expr::Constant {
+                            scalar, data_type, ..
+                        },
+                        Expr::ColumnRef {
+                            id,
+                            data_type: column_type,
+                            ..
+                        },
+                    ) if column_type.remove_nullable() == data_type.remove_nullable() => {
+                        Some((id, scalar, data_type.remove_nullable()))
+                    }
+                    _ => None,
+                };
+                if let Some((id, scalar, data_type)) = column_and_constant {
+                    equalities.insert(id.clone(), (scalar.clone(), data_type));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Compute the bucket of a value, see [Bucket Transform Details](https://iceberg.apache.org/spec/#bucket-transform-details).
+fn bucket(value: &Scalar, n: i32) -> Option<i32> {
+    let hash = match value {
+        Scalar::Number(num) => {
+            let v = match num {
+                NumberScalar::Int8(v) => *v as i64,
+                NumberScalar::Int16(v) => *v as i64,
+                NumberScalar::Int32(v) => *v as i64,
+                NumberScalar::Int64(v) => *v,
+                NumberScalar::UInt8(v) => *v as i64,
+                NumberScalar::UInt16(v) => *v as i64,
+                NumberScalar::UInt32(v) => *v as i64,
+                _ => return None,
+            };
+            murmur3_32(&v.to_le_bytes())
+        }
+        Scalar::Date(v) => murmur3_32(&(*v as i64).to_le_bytes()),
+        Scalar::Timestamp(v) => murmur3_32(&v.to_le_bytes()),
+        Scalar::String(v) => murmur3_32(v.as_bytes()),
+        Scalar::Binary(v) => murmur3_32(v),
+        _ => return None,
+    };
+    Some((hash & i32::MAX as u32) as i32 % n)
+}
+
+/// 32-bit murmur3 hash of x86 variant with seed 0, which is used by iceberg bucket transform.
+fn murmur3_32(data: &[u8]) -> u32 {
+    const C1: u32 = 0xcc9e2d51;
+    const C2: u32 = 0x1b873593;
+
+    let mut h: u32 = 0;
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes(chunk.try_into().unwrap());
+        k = k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        h ^= k;
+        h = h.rotate_left(13).wrapping_mul(5).wrapping_add(0xe6546b64);
+    }
+
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let mut k: u32 = 0;
+        for (i, b) in tail.iter().enumerate() {
+            k ^= (*b as u32) << (8 * i);
+        }
+        k = k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        h ^= k;
+    }
+
+    h ^= data.len() as u32;
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^= h >> 16;
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The test vectors are from the appendix of iceberg spec.
+    #[test]
+    fn test_bucket_hash() {
+        assert_eq!(murmur3_32(&34i64.to_le_bytes()) as i32, 2017239379);
+        assert_eq!(murmur3_32("iceberg".as_bytes()) as i32, 1210000089);
+        assert_eq!(murmur3_32(&17486i64.to_le_bytes()) as i32, -653330422);
+        assert_eq!(
+            murmur3_32(&1510871468000000i64.to_le_bytes()) as i32,
+            -2047944441
+        );
+    }
+}
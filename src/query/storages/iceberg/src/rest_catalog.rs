@@ -0,0 +1,330 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A minimal client of [Iceberg REST Catalog API](https://github.com/apache/iceberg/blob/main/open-api/rest-catalog-open-api.yaml).
+//!
+//! Only the endpoints required to discover and read tables are implemented:
+//!
+//! - `GET /v1/config`
+//! - `POST /v1/oauth/tokens`
+//! - `GET /v1/{prefix}/namespaces`
+//! - `GET /v1/{prefix}/namespaces/{namespace}`
+//! - `GET /v1/{prefix}/namespaces/{namespace}/tables`
+//! - `GET /v1/{prefix}/namespaces/{namespace}/tables/{table}`
+
+use std::collections::HashMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::IcebergRestCatalogOption;
+use reqwest::RequestBuilder;
+use reqwest::StatusCode;
+use reqwest::Url;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+
+/// The separator of the levels of a multi-level namespace in urls.
+const NAMESPACE_SEPARATOR: &str = "\u{1f}";
+
+/// Client of an iceberg REST catalog.
+///
+/// Namespaces are mapped to databases, multi-level namespaces are joined with `.`.
+#[derive(Debug)]
+pub struct RestCatalogClient {
+    option: IcebergRestCatalogOption,
+    client: reqwest::Client,
+    /// Resolved lazily on the first request, since it requires requesting the catalog.
+    session: OnceCell<RestCatalogSession>,
+}
+
+#[derive(Debug)]
+struct RestCatalogSession {
+    /// The base url of the endpoints, including the prefix.
+    base: Url,
+    token: Option<String>,
+}
+
+/// The table loaded from the catalog.
+#[derive(Debug, Deserialize)]
+pub struct LoadTableResult {
+    /// Absent if the table is staged for creation.
+    #[serde(rename = "metadata-location")]
+    pub metadata_location: Option<String>,
+    /// The table metadata, kept as json to be parsed by icelake.
+    pub metadata: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct CatalogConfig {
+    #[serde(default)]
+    defaults: HashMap<String, String>,
+    #[serde(default)]
+    overrides: HashMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+}
+
+#[derive(Deserialize)]
+struct ListNamespacesResponse {
+    namespaces: Vec<Vec<String>>,
+    #[serde(rename = "next-page-token")]
+    next_page_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TableIdentifier {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct ListTablesResponse {
+    identifiers: Vec<TableIdentifier>,
+    #[serde(rename = "next-page-token")]
+    next_page_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: ErrorModel,
+}
+
+#[derive(Deserialize)]
+struct ErrorModel {
+    message: String,
+    #[serde(rename = "type")]
+    ty: String,
+}
+
+impl RestCatalogClient {
+    pub fn try_create(option: IcebergRestCatalogOption) -> Result<Self> {
+        Url::parse(&option.uri).map_err(|e| {
+            ErrorCode::InvalidArgument(format!(
+                "Invalid iceberg REST catalog uri {}: {e}",
+                option.uri
+            ))
+        })?;
+        let client = reqwest::Client::builder().build().map_err(|e| {
+            ErrorCode::Internal(format!("Cannot build iceberg REST catalog client: {e}"))
+        })?;
+        Ok(Self {
+            option,
+            client,
+            session: OnceCell::new(),
+        })
+    }
+
+    /// List the namespaces at the top level.
+    #[async_backtrace::framed]
+    pub async fn list_namespaces(&self) -> Result<Vec<String>> {
+        let mut names = vec![];
+        let mut page_token = None;
+        loop {
+            let url = self.url(&["namespaces"]).await?;
+            let resp: ListNamespacesResponse = self.get(url, page_token.as_deref()).await?;
+            names.extend(resp.namespaces.iter().map(|ns| ns.join(".")));
+            page_token = match resp.next_page_token {
+                Some(token) if !token.is_empty() => Some(token),
+                _ => return Ok(names),
+            };
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn namespace_exists(&self, namespace: &str) -> Result<bool> {
+        let url = self
+            .url(&["namespaces", &encode_namespace(namespace)])
+            .await?;
+        match self.get::<serde_json::Value>(url, None).await {
+            Ok(_) => Ok(true),
+            Err(e) if e.code() == ErrorCode::UNKNOWN_DATABASE => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn list_tables(&self, namespace: &str) -> Result<Vec<String>> {
+        let mut names = vec![];
+        let mut page_token = None;
+        loop {
+            let url = self
+                .url(&["namespaces", &encode_namespace(namespace), "tables"])
+                .await?;
+            let resp: ListTablesResponse = self.get(url, page_token.as_deref()).await?;
+            names.extend(resp.identifiers.into_iter().map(|ident| ident.name));
+            page_token = match resp.next_page_token {
+                Some(token) if !token.is_empty() => Some(token),
+                _ => return Ok(names),
+            };
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn load_table(&self, namespace: &str, table: &str) -> Result<LoadTableResult> {
+        let url = self
+            .url(&["namespaces", &encode_namespace(namespace), "tables", table])
+            .await?;
+        self.get(url, None).await
+    }
+
+    async fn url(&self, segments: &[&str]) -> Result<Url> {
+        let session = self.session().await?;
+        let mut url = session.base.clone();
+        url.path_segments_mut()
+            .map_err(|_| ErrorCode::InvalidArgument("Invalid iceberg REST catalog uri"))?
+            .extend(segments);
+        Ok(url)
+    }
+
+    async fn session(&self) -> Result<&RestCatalogSession> {
+        self.session
+            .get_or_try_init(|| async {
+                let token = match (&self.option.token, &self.option.credential) {
+                    (Some(token), _) => Some(token.clone()),
+                    (None, Some(credential)) => Some(self.fetch_token(credential).await?),
+                    (None, None) => None,
+                };
+
+                // The prefix returned by the catalog takes precedence over the configured one.
+                let mut config_url = self.v1_url(&["config"])?;
+                if let Some(warehouse) = &self.option.warehouse {
+                    config_url
+                        .query_pairs_mut()
+                        .append_pair("warehouse", warehouse);
+                }
+                let config: CatalogConfig =
+                    Self::send(self.authorize(self.client.get(config_url), token.as_deref()))
+                        .await?;
+                let prefix = config
+                    .overrides
+                    .get("prefix")
+                    .or(self.option.prefix.as_ref())
+                    .or(config.defaults.get("prefix"))
+                    .filter(|prefix| !prefix.is_empty());
+
+                let base = match prefix {
+                    Some(prefix) => {
+                        let segments = prefix.split('/').collect::<Vec<_>>();
+                        self.v1_url(&segments)?
+                    }
+                    None => self.v1_url(&[])?,
+                };
+                Ok(RestCatalogSession { base, token })
+            })
+            .await
+    }
+
+    /// Exchange the `<client_id>:<client_secret>` credential for a token, see OAuth2 client credentials flow.
+    async fn fetch_token(&self, credential: &str) -> Result<String> {
+        let (client_id, client_secret) = match credential.split_once(':') {
+            Some((id, secret)) => (Some(id), secret),
+            None => (None, credential),
+        };
+        let mut form = vec![
+            ("grant_type", "client_credentials"),
+            ("client_secret", client_secret),
+            ("scope", "catalog"),
+        ];
+        if let Some(client_id) = client_id {
+            form.push(("client_id", client_id));
+        }
+
+        let url = self.v1_url(&["oauth", "tokens"])?;
+        let resp: TokenResponse = Self::send(self.client.post(url).form(&form)).await?;
+        Ok(resp.access_token)
+    }
+
+    fn v1_url(&self, segments: &[&str]) -> Result<Url> {
+        let mut url = Url::parse(&self.option.uri).map_err(|e| {
+            ErrorCode::InvalidArgument(format!(
+                "Invalid iceberg REST catalog uri {}: {e}",
+                self.option.uri
+            ))
+        })?;
+        url.path_segments_mut()
+            .map_err(|_| ErrorCode::InvalidArgument("Invalid iceberg REST catalog uri"))?
+            .pop_if_empty()
+            .push("v1")
+            .extend(segments);
+        Ok(url)
+    }
+
+    fn authorize(&self, req: RequestBuilder, token: Option<&str>) -> RequestBuilder {
+        match token {
+            Some(token) => req.bearer_auth(token),
+            None => req,
+        }
+    }
+
+    async fn get<T: DeserializeOwned>(&self, mut url: Url, page_token: Option<&str>) -> Result<T> {
+        if let Some(page_token) = page_token {
+            url.query_pairs_mut().append_pair("pageToken", page_token);
+        }
+        let session = self.session().await?;
+        Self::send(self.authorize(self.client.get(url), session.token.as_deref())).await
+    }
+
+    async fn send<T: DeserializeOwned>(req: RequestBuilder) -> Result<T> {
+        let resp = req.send().await.map_err(|e| {
+            ErrorCode::Internal(format!("Cannot request iceberg REST catalog: {e}"))
+        })?;
+
+        let status = resp.status();
+        let body = resp.bytes().await.map_err(|e| {
+            ErrorCode::Internal(format!("Cannot read iceberg REST catalog response: {e}"))
+        })?;
+        if !status.is_success() {
+            return Err(from_error_response(status, &body));
+        }
+
+        serde_json::from_slice(&body)
+            .map_err(|e| ErrorCode::Internal(format!("Invalid iceberg REST catalog response: {e}")))
+    }
+}
+
+fn from_error_response(status: StatusCode, body: &[u8]) -> ErrorCode {
+    let Ok(ErrorResponse { error }) = serde_json::from_slice::<ErrorResponse>(body) else {
+        return ErrorCode::Internal(format!(
+            "Iceberg REST catalog returned {status}: {}",
+            String::from_utf8_lossy(body)
+        ));
+    };
+    match (status, error.ty.as_str()) {
+        (StatusCode::NOT_FOUND, "NoSuchNamespaceException") => {
+            ErrorCode::UnknownDatabase(error.message)
+        }
+        (StatusCode::NOT_FOUND, "NoSuchTableException") => ErrorCode::UnknownTable(error.message),
+        (StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN, _) => {
+            ErrorCode::AuthenticateFailure(format!(
+                "Iceberg REST catalog rejected the request: {}",
+                error.message
+            ))
+        }
+        _ => ErrorCode::Internal(format!(
+            "Iceberg REST catalog returned {status}, {}: {}",
+            error.ty, error.message
+        )),
+    }
+}
+
+/// Encode a namespace joined with `.` as a path segment.
+fn encode_namespace(namespace: &str) -> String {
+    namespace
+        .split('.')
+        .collect::<Vec<_>>()
+        .join(NAMESPACE_SEPARATOR)
+}
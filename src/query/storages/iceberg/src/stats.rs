@@ -81,7 +81,7 @@ fn get_column_stats(
 }
 
 /// Deserialize binary value to [`Scalar`] according to [Binary single-value serialization](https://iceberg.apache.org/spec/#binary-single-value-serialization)
-fn parse_binary_value(ty: &TableDataType, data: &[u8]) -> Option<Scalar> {
+pub(crate) fn parse_binary_value(ty: &TableDataType, data: &[u8]) -> Option<Scalar> {
     let ty = ty.remove_nullable();
     match ty {
         TableDataType::Boolean => Some(Scalar::Boolean(data[0] != 0)),
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use arrow_schema::Schema as ArrowSchema;
 use async_trait::async_trait;
@@ -28,6 +30,8 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::AppendMode;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
@@ -39,8 +43,15 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_app::storage::StorageParams;
+use databend_common_meta_types::MatchSeq;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransformer;
 use databend_common_storage::init_operator;
 use databend_common_storage::DataOperator;
 use databend_common_storages_parquet::ParquetFilesPart;
@@ -48,16 +59,45 @@ use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
 use databend_storages_common_pruner::RangePrunerCreator;
+use databend_storages_common_table_meta::meta::SnapshotId;
 use icelake::catalog::Catalog;
+use icelake::transaction::Transaction;
+use icelake::types::parse_manifest_file;
+use icelake::types::parse_manifest_list;
+use icelake::types::table_metadata_from_json;
+use icelake::types::DataContentType;
+use icelake::types::DataFile;
+use icelake::types::ManifestStatus;
+use icelake::types::Snapshot;
+use icelake::types::Struct;
+use icelake::types::TableMetadata;
+use log::info;
+use log::warn;
 use opendal::Operator;
 use tokio::sync::OnceCell;
 
+use crate::append::IcebergCommitSink;
+use crate::append::IcebergDataFileWriter;
+use crate::append::WrittenDataFile;
+use crate::commit::MetadataCommit;
+use crate::delete::DeleteFileIndex;
+use crate::delete::IcebergDeleteReader;
 use crate::partition::IcebergPartInfo;
+use crate::pruning::ManifestPruner;
+use crate::rest_catalog::LoadTableResult;
 use crate::stats::get_stats_of_data_file;
 use crate::table_source::IcebergTableSource;
 
 pub const ICEBERG_ENGINE: &str = "ICEBERG";
 
+/// The option key of the snapshot id a table navigated to by time travel.
+const OPT_KEY_SNAPSHOT_ID: &str = "snapshot_id";
+/// The option key of the metadata file of a table loaded from a REST catalog, relative to the table root.
+const OPT_KEY_METADATA_LOCATION: &str = "metadata_location";
+
+/// The times to retry a commit conflicted with concurrent commits.
+const MAX_COMMIT_RETRIES: u64 = 10;
+
 /// accessor wrapper as a table
 ///
 /// TODO: we should use icelake Table instead.
@@ -84,7 +124,7 @@ impl IcebergTable {
         }
     }
 
-    fn get_storage_params(&self) -> Result<&StorageParams> {
+    pub(crate) fn get_storage_params(&self) -> Result<&StorageParams> {
         self.info.meta.storage_params.as_ref().ok_or_else(|| {
             ErrorCode::BadArguments(format!(
                 "Iceberg table {} must have storage parameters",
@@ -93,12 +133,53 @@ impl IcebergTable {
         })
     }
 
+    /// The snapshot navigated to by time travel, `None` means the current snapshot.
+    fn snapshot_id(&self) -> Result<Option<i64>> {
+        self.info
+            .meta
+            .options
+            .get(OPT_KEY_SNAPSHOT_ID)
+            .map(|id| {
+                id.parse::<i64>().map_err(|e| {
+                    ErrorCode::Internal(format!("Invalid iceberg snapshot id {id}: {e}"))
+                })
+            })
+            .transpose()
+    }
+
+    /// Find the snapshot with `snapshot_id`, or the current snapshot if it's `None`.
+    ///
+    /// Returns `None` if the table has no snapshot.
+    fn find_snapshot(
+        table: &icelake::Table,
+        snapshot_id: Option<i64>,
+    ) -> Result<Option<&Snapshot>> {
+        let meta = table.current_table_metadata();
+        let Some(id) = snapshot_id.or(meta.current_snapshot_id) else {
+            return Ok(None);
+        };
+        let snapshot = meta
+            .snapshots
+            .iter()
+            .flatten()
+            .find(|s| s.snapshot_id == id);
+        match (snapshot, snapshot_id) {
+            (Some(snapshot), _) => Ok(Some(snapshot)),
+            (None, Some(id)) => Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "Iceberg snapshot {id} not found"
+            ))),
+            // The current snapshot id may be -1 if the table is empty.
+            (None, None) => Ok(None),
+        }
+    }
+
     pub async fn load_iceberg_table(dop: DataOperator) -> Result<icelake::Table> {
+        Self::load_iceberg_table_with(OperatorCreatorWrapper { dop, commit: None }).await
+    }
+
+    async fn load_iceberg_table_with(creator: OperatorCreatorWrapper) -> Result<icelake::Table> {
         // FIXME: we should implement catalog for icelake.
-        let icelake_catalog = Arc::new(icelake::catalog::StorageCatalog::new(
-            "databend",
-            OperatorCreatorWrapper(dop.clone()),
-        ));
+        let icelake_catalog = Arc::new(icelake::catalog::StorageCatalog::new("databend", creator));
 
         let table_id = icelake::TableIdentifier::new(vec![""]).unwrap();
         icelake_catalog.load_table(&table_id).await.map_err(|err| {
@@ -108,19 +189,17 @@ impl IcebergTable {
 
     pub async fn get_schema(table: &icelake::Table) -> Result<TableSchema> {
         let meta = table.current_table_metadata();
+        let schema = meta.schemas.last().ok_or_else(|| {
+            ErrorCode::ReadTableDataError("Iceberg table schema is empty".to_string())
+        })?;
+        Self::convert_schema(schema)
+    }
 
+    fn convert_schema(schema: &icelake::types::Schema) -> Result<TableSchema> {
         // Build arrow schema from iceberg metadata.
-        let arrow_schema: ArrowSchema = meta
-            .schemas
-            .last()
-            .ok_or_else(|| {
-                ErrorCode::ReadTableDataError("Iceberg table schema is empty".to_string())
-            })?
-            .clone()
-            .try_into()
-            .map_err(|e| {
-                ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
-            })?;
+        let arrow_schema: ArrowSchema = schema.clone().try_into().map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
+        })?;
 
         // Build arrow2 schema from arrow schema.
         let fields: Vec<Arrow2Field> = arrow_schema
@@ -133,6 +212,42 @@ impl IcebergTable {
         TableSchema::try_from(&arrow2_schema)
     }
 
+    /// Load the table with the metadata file of a table loaded from a REST catalog.
+    ///
+    /// Unlike tables discovered from the storage, such tables may have no version hint.
+    async fn load_iceberg_table_from_metadata(
+        dop: DataOperator,
+        metadata_location: &str,
+    ) -> Result<icelake::Table> {
+        let metadata = dop.operator().read(metadata_location).await?;
+        let metadata = table_metadata_from_json(&metadata).map_err(|err| {
+            ErrorCode::ReadTableDataError(format!("Cannot parse iceberg table metadata: {err:?}"))
+        })?;
+        Self::build_iceberg_table(dop, metadata, metadata_location)
+    }
+
+    fn build_iceberg_table(
+        dop: DataOperator,
+        metadata: TableMetadata,
+        metadata_location: &str,
+    ) -> Result<icelake::Table> {
+        let icelake_catalog = Arc::new(icelake::catalog::StorageCatalog::new(
+            "databend",
+            OperatorCreatorWrapper {
+                dop: dop.clone(),
+                commit: None,
+            },
+        ));
+
+        let table_id = icelake::TableIdentifier::new(vec![""]).unwrap();
+        icelake::Table::builder_from_catalog(dop.operator(), icelake_catalog, metadata, table_id)
+            .with_metadata_loc(metadata_location.to_string())
+            .build()
+            .map_err(|err| {
+                ErrorCode::ReadTableDataError(format!("Iceberg table load failed: {err:?}"))
+            })
+    }
+
     /// create a new table on the table directory
     #[async_backtrace::framed]
     pub async fn try_create_from_iceberg_catalog(
@@ -142,6 +257,58 @@ impl IcebergTable {
         dop: DataOperator,
     ) -> Result<IcebergTable> {
         let table = Self::load_iceberg_table(dop.clone()).await?;
+        Self::create_with_table(catalog, database, table_name, dop, table, BTreeMap::new()).await
+    }
+
+    /// create a new table with the table loaded from a REST catalog
+    ///
+    /// The table root is the location of the table in the storage of the catalog `catalog_sp`.
+    #[async_backtrace::framed]
+    pub async fn try_create_from_rest_catalog(
+        catalog: &str,
+        database: &str,
+        table_name: &str,
+        catalog_sp: &StorageParams,
+        loaded: LoadTableResult,
+    ) -> Result<IcebergTable> {
+        let metadata_location = loaded.metadata_location.ok_or_else(|| {
+            ErrorCode::ReadTableDataError(format!(
+                "Iceberg table {database}.{table_name} has no metadata location, it may be staged for creation"
+            ))
+        })?;
+        let metadata = serde_json::to_vec(&loaded.metadata)?;
+        let metadata = table_metadata_from_json(&metadata).map_err(|err| {
+            ErrorCode::ReadTableDataError(format!("Cannot parse iceberg table metadata: {err:?}"))
+        })?;
+
+        let table_location = metadata.location.trim_end_matches('/');
+        let rel_metadata_location = metadata_location
+            .strip_prefix(table_location)
+            .map(|path| path.trim_start_matches('/').to_string())
+            .ok_or_else(|| {
+                ErrorCode::ReadTableDataError(format!(
+                    "Iceberg metadata {metadata_location} outside of the table location {table_location} is not supported"
+                ))
+            })?;
+
+        let table_sp = catalog_sp.clone().map_root(|_| table_root(table_location));
+        let table_sp = table_sp.auto_detect().await?;
+        let dop = DataOperator::try_create(&table_sp).await?;
+
+        let table = Self::build_iceberg_table(dop.clone(), metadata, &rel_metadata_location)?;
+        let options =
+            BTreeMap::from([(OPT_KEY_METADATA_LOCATION.to_string(), rel_metadata_location)]);
+        Self::create_with_table(catalog, database, table_name, dop, table, options).await
+    }
+
+    async fn create_with_table(
+        catalog: &str,
+        database: &str,
+        table_name: &str,
+        dop: DataOperator,
+        table: icelake::Table,
+        options: BTreeMap<String, String>,
+    ) -> Result<IcebergTable> {
         let table_schema = Self::get_schema(&table).await?;
 
         // construct table info
@@ -155,6 +322,7 @@ impl IcebergTable {
                 engine: "iceberg".to_string(),
                 created_on: Utc::now(),
                 storage_params: Some(dop.params()),
+                options,
                 ..Default::default()
             },
             ..Default::default()
@@ -166,27 +334,215 @@ impl IcebergTable {
         })
     }
 
-    async fn table(&self) -> Result<&icelake::Table> {
+    pub(crate) async fn table(&self) -> Result<&icelake::Table> {
         self.table
             .get_or_try_init(|| async {
                 let sp = self.get_storage_params()?;
                 let op = DataOperator::try_new(sp)?;
-                // FIXME: we should implement catalog for icelake.
-                let icelake_catalog = Arc::new(icelake::catalog::StorageCatalog::new(
-                    "databend",
-                    OperatorCreatorWrapper(op),
-                ));
-
-                let table_id = icelake::TableIdentifier::new(vec![""]).unwrap();
-                let table = icelake_catalog.load_table(&table_id).await.map_err(|err| {
-                    ErrorCode::ReadTableDataError(format!("Iceberg catalog load failed: {err:?}"))
-                })?;
-
-                Ok(table)
+                match self.info.meta.options.get(OPT_KEY_METADATA_LOCATION) {
+                    Some(metadata_location) => {
+                        Self::load_iceberg_table_from_metadata(op, metadata_location).await
+                    }
+                    None => Self::load_iceberg_table(op).await,
+                }
             })
             .await
     }
 
+    /// Read the live entries of the manifests of a snapshot, including delete files.
+    ///
+    /// Unlike `icelake::Table::current_data_files`, the data sequence numbers and partition
+    /// specs are kept, which are required to decide which data files a delete file applies to.
+    /// Manifests are pruned with the partition summaries in the manifest list.
+    #[async_backtrace::framed]
+    async fn manifest_entries(
+        table: &icelake::Table,
+        op: &Operator,
+        snapshot: &Snapshot,
+        pruner: &ManifestPruner,
+    ) -> Result<Vec<LiveManifestEntry>> {
+        let meta = table.current_table_metadata();
+        let read_err = |e: icelake::Error| {
+            ErrorCode::ReadTableDataError(format!("Cannot read manifests: {e:?}"))
+        };
+
+        let manifest_list_path = table.rel_path(&snapshot.manifest_list).map_err(read_err)?;
+        let manifest_list = op.read(&manifest_list_path).await?;
+        let manifest_list = parse_manifest_list(&manifest_list).map_err(read_err)?;
+
+        let mut entries = vec![];
+        for manifest_file in manifest_list.entries {
+            let spec = meta
+                .partition_specs
+                .iter()
+                .find(|spec| spec.spec_id == manifest_file.partition_spec_id);
+            if let (Some(spec), Some(summaries)) = (spec, &manifest_file.partitions) {
+                if !pruner.should_keep(spec, summaries) {
+                    continue;
+                }
+            }
+            let unpartitioned = spec.map_or(true, |spec| spec.fields.is_empty());
+
+            let manifest_path = table
+                .rel_path(&manifest_file.manifest_path)
+                .map_err(read_err)?;
+            let manifest = op.read(&manifest_path).await?;
+            let manifest = parse_manifest_file(&manifest).map_err(read_err)?;
+            for entry in manifest.entries {
+                if entry.status == ManifestStatus::Deleted {
+                    continue;
+                }
+                entries.push(LiveManifestEntry {
+                    // Entries added in the snapshot of the manifest inherit its sequence number.
+                    sequence_number: entry
+                        .sequence_number
+                        .unwrap_or(manifest_file.sequence_number),
+                    spec_id: manifest_file.partition_spec_id,
+                    unpartitioned,
+                    data_file: entry.data_file,
+                });
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Commit the data files written by an insertion as a new snapshot of the table.
+    ///
+    /// Tables managed by the storage are committed by writing the metadata file of the next
+    /// version, which is created conditionally with [`MetadataCommit`]. If the version is
+    /// committed concurrently, the table is reloaded and the data files are appended on the
+    /// latest metadata again, up to [`MAX_COMMIT_RETRIES`] times.
+    ///
+    /// The data files are removed if the commit fails finally.
+    #[async_backtrace::framed]
+    pub(crate) async fn commit_data_files(&self, data_files: Vec<WrittenDataFile>) -> Result<()> {
+        let op = DataOperator::try_new(self.get_storage_params()?)?;
+        // The data files are written with the default spec of the table.
+        let partition_type = Self::partition_type(self.table().await?)?;
+        let data_files = data_files
+            .into_iter()
+            .map(|df| df.into_data_file(&partition_type))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut retries = 0;
+        loop {
+            let commit = MetadataCommit::create();
+            let mut table = Self::load_iceberg_table_with(OperatorCreatorWrapper {
+                dop: op.clone(),
+                commit: Some(commit.clone()),
+            })
+            .await?;
+
+            let mut txn = Transaction::new(&mut table);
+            txn.append_data_file(data_files.iter().cloned());
+            let Err(e) = txn.commit().await else {
+                return Ok(());
+            };
+
+            if commit.is_conflicted() && retries < MAX_COMMIT_RETRIES {
+                retries += 1;
+                info!(
+                    "iceberg table {} is committed concurrently, retry {retries}",
+                    self.info.name
+                );
+                tokio::time::sleep(Duration::from_millis(100 * retries)).await;
+                continue;
+            }
+
+            // The commit may fail after the metadata file is written,
+            // then the data files may be referenced and are kept.
+            if !commit.is_published().await? {
+                Self::remove_data_files(&op, &table, &data_files).await;
+            }
+            return Err(ErrorCode::StorageOther(format!(
+                "Cannot commit iceberg table {} after {retries} retries: {e:?}",
+                self.info.name
+            )));
+        }
+    }
+
+    fn partition_type(table: &icelake::Table) -> Result<Arc<Struct>> {
+        let meta = table.current_table_metadata();
+        let spec = meta
+            .partition_specs
+            .iter()
+            .find(|spec| spec.spec_id == meta.default_spec_id);
+        let schema = meta
+            .schemas
+            .iter()
+            .find(|schema| schema.schema_id == meta.current_schema_id);
+        let (Some(spec), Some(schema)) = (spec, schema) else {
+            return Err(ErrorCode::StorageOther(format!(
+                "Iceberg table metadata has no default partition spec {} or current schema {}",
+                meta.default_spec_id, meta.current_schema_id
+            )));
+        };
+        let partition_type = spec.partition_type(schema).map_err(|e| {
+            ErrorCode::StorageOther(format!("Cannot get iceberg partition type: {e:?}"))
+        })?;
+        Ok(Arc::new(partition_type))
+    }
+
+    /// Record the files copied into the table, tables created with the engine are registered
+    /// in the meta service, which keeps the copied files like fuse tables.
+    #[async_backtrace::framed]
+    pub(crate) async fn record_copied_files(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        copied_files: UpsertTableCopiedFileReq,
+    ) -> Result<()> {
+        let catalog = ctx.get_catalog(self.info.catalog()).await?;
+        let table_id = self.info.ident.table_id;
+        let (ident, meta) = catalog.get_table_meta_by_id(table_id).await?;
+        let req = UpdateTableMetaReq {
+            table_id,
+            seq: MatchSeq::Exact(ident.seq),
+            new_table_meta: meta.as_ref().clone(),
+            copied_files: Some(copied_files),
+            update_stream_meta: vec![],
+            deduplicated_label: None,
+        };
+        catalog.update_table_meta(&self.info, req).await?;
+        Ok(())
+    }
+
+    /// Remove the data files which are not committed, the failures are only logged,
+    /// as the files are invisible to the readers anyway.
+    pub(crate) async fn remove_data_files(
+        op: &DataOperator,
+        table: &icelake::Table,
+        data_files: &[DataFile],
+    ) {
+        let paths = data_files
+            .iter()
+            .filter_map(|df| table.rel_path(&df.file_path).ok())
+            .collect::<Vec<_>>();
+        if paths.is_empty() {
+            return;
+        }
+        info!("remove {} uncommitted iceberg data files", paths.len());
+        if let Err(e) = op.operator().remove(paths).await {
+            warn!("Cannot remove uncommitted iceberg data files: {e}");
+        }
+    }
+
+    /// Only the tables managed by the storage are writable, tables of a REST catalog
+    /// must be committed through the catalog.
+    fn check_writable(&self) -> Result<()> {
+        if self
+            .info
+            .meta
+            .options
+            .contains_key(OPT_KEY_METADATA_LOCATION)
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Writing to iceberg table {} of a REST catalog is not supported",
+                self.info.name
+            )));
+        }
+        Ok(())
+    }
+
     pub fn do_read_data(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -226,16 +582,26 @@ impl IcebergTable {
 
         let sp = self.get_storage_params()?;
         let op = init_operator(sp)?;
-        let mut builder =
-            ParquetRSReaderBuilder::create(ctx.clone(), op, table_schema, &arrow_schema)?
-                .with_options(read_options)
-                .with_push_downs(plan.push_downs.as_ref())
-                .with_pruner(Some(pruner));
+        let mut builder = ParquetRSReaderBuilder::create(
+            ctx.clone(),
+            op.clone(),
+            table_schema.clone(),
+            &arrow_schema,
+        )?
+        .with_options(read_options)
+        .with_push_downs(plan.push_downs.as_ref())
+        .with_pruner(Some(pruner));
 
         let parquet_reader = Arc::new(builder.build_full_reader()?);
 
         // TODO: we need to support top_k.
         let output_schema = Arc::new(DataSchema::from(plan.schema()));
+        let delete_reader = Arc::new(IcebergDeleteReader::create(
+            ctx.clone(),
+            op,
+            table_schema,
+            &output_schema,
+        )?);
         pipeline.add_source(
             |output| {
                 IcebergTableSource::create(
@@ -243,6 +609,7 @@ impl IcebergTable {
                     output,
                     output_schema.clone(),
                     parquet_reader.clone(),
+                    delete_reader.clone(),
                 )
             },
             max_threads.max(1),
@@ -257,10 +624,7 @@ impl IcebergTable {
         push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
         let table = self.table().await?;
-
-        let data_files = table.current_data_files().await.map_err(|e| {
-            ErrorCode::ReadTableDataError(format!("Cannot get current data files: {e:?}"))
-        })?;
+        let schema = self.schema();
 
         let filter = push_downs.as_ref().and_then(|extra| {
             extra
@@ -269,40 +633,96 @@ impl IcebergTable {
                 .map(|f| f.filter.as_expr(&BUILTIN_FUNCTIONS))
         });
 
-        let schema = self.schema();
-
         let pruner =
             RangePrunerCreator::try_create(ctx.get_function_context()?, &schema, filter.as_ref())?;
 
+        let Some(snapshot) = Self::find_snapshot(table, self.snapshot_id()?)? else {
+            // The table is empty.
+            return Ok((PartStatistics::default(), Partitions::default()));
+        };
+        let op = init_operator(self.get_storage_params()?)?;
+        let meta = table.current_table_metadata();
+        let iceberg_schema = snapshot
+            .schema_id
+            .and_then(|id| meta.schemas.iter().find(|s| s.schema_id as i64 == id))
+            .or_else(|| meta.schemas.last())
+            .ok_or_else(|| {
+                ErrorCode::ReadTableDataError("Iceberg table schema is empty".to_string())
+            })?;
+        let manifest_pruner = ManifestPruner::create(
+            schema.clone(),
+            iceberg_schema,
+            pruner.clone(),
+            filter.as_ref(),
+        );
+        let entries = Self::manifest_entries(table, &op, snapshot, &manifest_pruner).await?;
+
+        let rel_path = |path: &str| {
+            table.rel_path(path).map_err(|e| {
+                ErrorCode::StorageOther(format!(
+                    "Iceberg file {path} is not in the table location: {e:?}"
+                ))
+            })
+        };
+
+        // Delete files are not read as parts, but attached to the data files they apply to.
+        let mut delete_index = DeleteFileIndex::create(&schema, iceberg_schema);
+        let mut data_files = Vec::with_capacity(entries.len());
+        for entry in entries {
+            match entry.data_file.content {
+                DataContentType::Data => data_files.push(entry),
+                _ => {
+                    let location = rel_path(&entry.data_file.file_path)?;
+                    delete_index.add(
+                        location,
+                        entry.data_file,
+                        entry.sequence_number,
+                        entry.spec_id,
+                        entry.unpartitioned,
+                    )?;
+                }
+            }
+        }
+
         // TODO: support other file formats. We only support parquet files now.
         let mut read_rows = 0;
         let mut read_bytes = 0;
         let total_files = data_files.len();
         let parts = data_files
             .into_iter()
-            .filter(|df| {
-                if let Some(stats) = get_stats_of_data_file(&schema, df) {
+            .filter(|entry| {
+                if let Some(stats) = get_stats_of_data_file(&schema, &entry.data_file) {
                     pruner.should_keep(&stats, None)
                 } else {
                     true
                 }
             })
-            .map(|v: icelake::types::DataFile| {
+            .map(|entry| {
+                let v = entry.data_file;
                 read_rows += v.record_count as usize;
                 read_bytes += v.file_size_in_bytes as usize;
                 match v.file_format {
                     icelake::types::DataFileFormat::Parquet => {
-                        let location = table
-                            .rel_path(&v.file_path)
-                            .expect("file path must be rel to table");
-                        Ok(Arc::new(
-                            Box::new(IcebergPartInfo::Parquet(ParquetPart::ParquetFiles(
-                                ParquetFilesPart {
-                                    files: vec![(location, v.file_size_in_bytes as u64)],
-                                    estimated_uncompressed_size: v.file_size_in_bytes as u64, // This field is not used here.
-                                },
-                            ))) as Box<dyn PartInfo>,
-                        ))
+                        let location = rel_path(&v.file_path)?;
+                        let part = ParquetPart::ParquetFiles(ParquetFilesPart {
+                            files: vec![(location, v.file_size_in_bytes as u64)],
+                            estimated_uncompressed_size: v.file_size_in_bytes as u64, // This field is not used here.
+                        });
+                        let deletes = delete_index.for_data_file(
+                            entry.sequence_number,
+                            entry.spec_id,
+                            &v.partition,
+                        );
+                        let part = if deletes.is_empty() {
+                            IcebergPartInfo::Parquet(part)
+                        } else {
+                            IcebergPartInfo::ParquetWithDeletes {
+                                part,
+                                file_path: v.file_path,
+                                deletes,
+                            }
+                        };
+                        Ok(Arc::new(Box::new(part) as Box<dyn PartInfo>))
                     }
                     _ => Err(ErrorCode::Unimplemented(
                         "Only parquet format is supported for iceberg table",
@@ -311,8 +731,6 @@ impl IcebergTable {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        // TODO: more precise pruning.
-
         Ok((
             PartStatistics::new_estimated(None, read_rows, read_bytes, parts.len(), total_files),
             Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
@@ -363,6 +781,112 @@ impl Table for IcebergTable {
         None
     }
 
+    #[async_backtrace::framed]
+    async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let table = self.table().await?;
+        let meta = table.current_table_metadata();
+        let snapshot = match point {
+            NavigationPoint::SnapshotID(id) => {
+                let id = id.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!("Invalid iceberg snapshot id {id}"))
+                })?;
+                Self::find_snapshot(table, Some(id))?
+            }
+            NavigationPoint::TimePoint(time_point) => {
+                let time_point = time_point.timestamp_millis();
+                meta.snapshots
+                    .iter()
+                    .flatten()
+                    .filter(|s| s.timestamp_ms <= time_point)
+                    .max_by_key(|s| s.timestamp_ms)
+            }
+        }
+        .ok_or_else(|| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "No historical data found at given point for table {}",
+                self.info.name
+            ))
+        })?;
+
+        // The schema may have evolved since the snapshot.
+        let schema = match snapshot.schema_id {
+            Some(schema_id) => match meta
+                .schemas
+                .iter()
+                .find(|s| s.schema_id as i64 == schema_id)
+            {
+                Some(schema) => Arc::new(Self::convert_schema(schema)?),
+                None => self.info.meta.schema.clone(),
+            },
+            None => self.info.meta.schema.clone(),
+        };
+
+        let mut info = self.info.clone();
+        info.meta.schema = schema;
+        info.meta.options.insert(
+            OPT_KEY_SNAPSHOT_ID.to_string(),
+            snapshot.snapshot_id.to_string(),
+        );
+        Ok(Arc::new(Self {
+            info,
+            table: OnceCell::new(),
+        }))
+    }
+
+    fn append_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _append_mode: AppendMode,
+    ) -> Result<()> {
+        self.check_writable()?;
+
+        // The writers share the table, which is loaded once.
+        let table = Arc::new(Self {
+            info: self.info.clone(),
+            table: OnceCell::new(),
+        });
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+                input,
+                output,
+                IcebergDataFileWriter::create(table.clone()),
+            )))
+        })
+    }
+
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+        _deduplicated_label: Option<String>,
+    ) -> Result<()> {
+        self.check_writable()?;
+        if overwrite {
+            return Err(ErrorCode::Unimplemented(format!(
+                "INSERT OVERWRITE iceberg table {} is not supported",
+                self.info.name
+            )));
+        }
+
+        let table = Arc::new(Self {
+            info: self.info.clone(),
+            table: OnceCell::new(),
+        });
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(AsyncSinker::create(
+                input,
+                ctx.clone(),
+                IcebergCommitSink::create(ctx.clone(), table.clone(), copied_files.clone()),
+            )))
+        })
+    }
+
     fn support_column_projection(&self) -> bool {
         true
     }
@@ -370,21 +894,57 @@ impl Table for IcebergTable {
     fn support_prewhere(&self) -> bool {
         true
     }
+
+    // Tables of iceberg catalogs have no id in the meta service to record the copied files.
+    fn support_copied_files(&self) -> bool {
+        self.info.ident.table_id != 0
+    }
+}
+
+struct OperatorCreatorWrapper {
+    dop: DataOperator,
+    /// Create the metadata files conditionally when committing the table.
+    commit: Option<Arc<MetadataCommit>>,
 }
 
-struct OperatorCreatorWrapper(DataOperator);
+impl OperatorCreatorWrapper {
+    fn operator(&self, dop: DataOperator) -> Operator {
+        match &self.commit {
+            Some(commit) => commit.layer(dop.operator(), dop.params().to_string()),
+            None => dop.operator(),
+        }
+    }
+}
 
 impl icelake::catalog::OperatorCreator for OperatorCreatorWrapper {
     fn create(&self) -> icelake::Result<Operator> {
-        Ok(self.0.operator())
+        Ok(self.operator(self.dop.clone()))
     }
 
     fn create_with_subdir(&self, path: &str) -> icelake::Result<Operator> {
-        let params = self.0.params().map_root(|v| format!("{}/{}", v, path));
+        let params = self.dop.params().map_root(|v| format!("{}/{}", v, path));
 
         // The operator used to be built successfully, change root should never returns error.
-        Ok(DataOperator::try_new(&params)
-            .expect("invalid params")
-            .operator())
+        Ok(self.operator(DataOperator::try_new(&params).expect("invalid params")))
     }
 }
+
+/// The root of a table in the storage, `s3://bucket/path/to/table` -> `/path/to/table/`.
+fn table_root(location: &str) -> String {
+    let path = location
+        .split_once("://")
+        .map_or(location, |(_, path)| path)
+        .split_once('/')
+        .map_or("", |(_, path)| path);
+    format!("/{}/", path.trim_matches('/'))
+}
+
+/// A live entry in the manifests of a snapshot.
+struct LiveManifestEntry {
+    data_file: DataFile,
+    /// The data sequence number of the file.
+    sequence_number: i64,
+    /// The partition spec the file is written with.
+    spec_id: i32,
+    unpartitioned: bool,
+}
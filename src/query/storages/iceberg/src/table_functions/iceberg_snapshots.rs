@@ -0,0 +1,267 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_function::TableFunction;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::Int64Type;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+
+use crate::IcebergTable;
+
+const ICEBERG_FUNC_SNAPSHOTS: &str = "iceberg_snapshots";
+
+/// `iceberg_snapshots('<catalog>', '<database>', '<table>')` lists the snapshots of an iceberg table.
+pub struct IcebergSnapshotsTable {
+    table_info: TableInfo,
+    arg_catalog_name: String,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl IcebergSnapshotsTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(ICEBERG_FUNC_SNAPSHOTS, Some(3))?;
+        let args = args
+            .iter()
+            .map(|arg| match arg {
+                Scalar::String(v) => Ok(v.clone()),
+                _ => Err(ErrorCode::BadArguments(format!(
+                    "expecting <catalog>, <database> and <table_name> (as string literals), but got {:?}",
+                    args
+                ))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: ICEBERG_FUNC_SNAPSHOTS.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(IcebergSnapshotsTable {
+            table_info,
+            arg_catalog_name: args[0].clone(),
+            arg_database_name: args[1].clone(),
+            arg_table_name: args[2].clone(),
+        }))
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("snapshot_id", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new(
+                "parent_snapshot_id",
+                TableDataType::Number(NumberDataType::Int64).wrap_nullable(),
+            ),
+            TableField::new(
+                "sequence_number",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new("timestamp", TableDataType::Timestamp),
+            TableField::new("operation", TableDataType::String.wrap_nullable()),
+            TableField::new("manifest_list", TableDataType::String),
+            TableField::new("is_current", TableDataType::Boolean),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for IcebergSnapshotsTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(vec![
+            Scalar::String(self.arg_catalog_name.clone()),
+            Scalar::String(self.arg_database_name.clone()),
+            Scalar::String(self.arg_table_name.clone()),
+        ]))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| {
+                IcebergSnapshotsSource::create(
+                    ctx.clone(),
+                    output,
+                    self.arg_catalog_name.to_owned(),
+                    self.arg_database_name.to_owned(),
+                    self.arg_table_name.to_owned(),
+                )
+            },
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl TableFunction for IcebergSnapshotsTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct IcebergSnapshotsSource {
+    finish: bool,
+    ctx: Arc<dyn TableContext>,
+    arg_catalog_name: String,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl IcebergSnapshotsSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        arg_catalog_name: String,
+        arg_database_name: String,
+        arg_table_name: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, IcebergSnapshotsSource {
+            ctx,
+            finish: false,
+            arg_catalog_name,
+            arg_database_name,
+            arg_table_name,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for IcebergSnapshotsSource {
+    const NAME: &'static str = ICEBERG_FUNC_SNAPSHOTS;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finish {
+            return Ok(None);
+        }
+
+        self.finish = true;
+        let tbl = self
+            .ctx
+            .get_table(
+                &self.arg_catalog_name,
+                &self.arg_database_name,
+                &self.arg_table_name,
+            )
+            .await?;
+        let tbl = tbl.as_any().downcast_ref::<IcebergTable>().ok_or_else(|| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "Invalid table engine {}, {} only supports iceberg tables",
+                tbl.engine(),
+                ICEBERG_FUNC_SNAPSHOTS
+            ))
+        })?;
+
+        let table = tbl.table().await?;
+        let meta = table.current_table_metadata();
+        let snapshots = meta.snapshots.as_deref().unwrap_or_default();
+
+        let mut snapshot_ids = Vec::with_capacity(snapshots.len());
+        let mut parent_ids = Vec::with_capacity(snapshots.len());
+        let mut sequence_numbers = Vec::with_capacity(snapshots.len());
+        let mut timestamps = Vec::with_capacity(snapshots.len());
+        let mut operations = Vec::with_capacity(snapshots.len());
+        let mut manifest_lists = Vec::with_capacity(snapshots.len());
+        let mut is_current = Vec::with_capacity(snapshots.len());
+        for s in snapshots {
+            snapshot_ids.push(s.snapshot_id);
+            parent_ids.push(s.parent_snapshot_id);
+            sequence_numbers.push(s.sequence_number);
+            timestamps.push(s.timestamp_ms * 1000);
+            operations.push(s.summary.get("operation").cloned());
+            manifest_lists.push(s.manifest_list.clone());
+            is_current.push(meta.current_snapshot_id == Some(s.snapshot_id));
+        }
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            Int64Type::from_data(snapshot_ids),
+            Int64Type::from_opt_data(parent_ids),
+            Int64Type::from_data(sequence_numbers),
+            TimestampType::from_data(timestamps),
+            StringType::from_opt_data(operations),
+            StringType::from_data(manifest_lists),
+            BooleanType::from_data(is_current),
+        ])))
+    }
+}
@@ -34,6 +34,8 @@ use databend_common_storages_parquet::ParquetRSFullReader;
 use opendal::Reader;
 use parquet::arrow::async_reader::ParquetRecordBatchStream;
 
+use crate::delete::DeleteFilter;
+use crate::delete::IcebergDeleteReader;
 use crate::partition::IcebergPartInfo;
 
 pub struct IcebergTableSource {
@@ -49,6 +51,13 @@ pub struct IcebergTableSource {
     output_schema: DataSchemaRef,
     parquet_reader: Arc<ParquetRSFullReader>,
     stream: Option<ParquetRecordBatchStream<Reader>>,
+
+    // Used to read the data files with delete files.
+    delete_reader: Arc<IcebergDeleteReader>,
+    /// The deletes of the file being read, it's `Some` if the file has delete files.
+    deletes: Option<DeleteFilter>,
+    /// The position of the next row in the file being read.
+    row_offset: u64,
 }
 
 impl IcebergTableSource {
@@ -57,6 +66,7 @@ impl IcebergTableSource {
         output: Arc<OutputPort>,
         output_schema: DataSchemaRef,
         parquet_reader: Arc<ParquetRSFullReader>,
+        delete_reader: Arc<IcebergDeleteReader>,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
         Ok(ProcessorPtr::create(Box::new(IcebergTableSource {
@@ -66,6 +76,9 @@ impl IcebergTableSource {
             parquet_reader,
             output_schema,
             stream: None,
+            delete_reader,
+            deletes: None,
+            row_offset: 0,
             generated_data: None,
             is_finished: false,
         })))
@@ -117,14 +130,26 @@ impl Processor for IcebergTableSource {
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         if let Some(mut stream) = self.stream.take() {
-            if let Some(block) = self
-                .parquet_reader
-                .read_block_from_stream(&mut stream)
-                .await?
+            let block = match &self.deletes {
+                Some(deletes) => {
+                    self.delete_reader
+                        .read_block_from_stream(&mut stream, deletes, &mut self.row_offset)
+                        .await?
+                }
+                None => {
+                    self.parquet_reader
+                        .read_block_from_stream(&mut stream)
+                        .await?
+                }
+            };
+            if let Some(block) = block
                 .map(|b| check_block_schema(&self.output_schema, b))
                 .transpose()?
             {
-                self.generated_data = Some(block);
+                // All the rows of the block may be deleted.
+                if !block.is_empty() {
+                    self.generated_data = Some(block);
+                }
                 self.stream = Some(stream);
             }
             // else:
@@ -138,6 +163,22 @@ impl Processor for IcebergTableSource {
                         .parquet_reader
                         .prepare_data_stream(&files.files[0].0, None)
                         .await?;
+                    self.deletes = None;
+                    self.stream = Some(stream);
+                }
+                IcebergPartInfo::ParquetWithDeletes {
+                    part: ParquetPart::ParquetFiles(files),
+                    file_path,
+                    deletes,
+                } => {
+                    assert_eq!(files.files.len(), 1);
+                    let deletes = self.delete_reader.load_deletes(file_path, deletes).await?;
+                    let stream = self
+                        .delete_reader
+                        .prepare_data_stream(&files.files[0].0)
+                        .await?;
+                    self.deletes = Some(deletes);
+                    self.row_offset = 0;
                     self.stream = Some(stream);
                 }
                 _ => unreachable!(),
@@ -0,0 +1,214 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::IcebergRestCatalogOption;
+use databend_common_storages_iceberg::RestCatalogClient;
+use serde_json::json;
+use wiremock::matchers::body_string_contains;
+use wiremock::matchers::header;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::matchers::query_param;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+
+const TOKEN: &str = "Bearer test-token";
+
+/// Mount the endpoints of a REST catalog with the prefix `ws`, authorized by OAuth2.
+async fn mount_catalog(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth/tokens"))
+        .and(body_string_contains("grant_type=client_credentials"))
+        .and(body_string_contains("client_id=client"))
+        .and(body_string_contains("client_secret=secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "test-token",
+            "token_type": "bearer",
+            "issued_token_type": "urn:ietf:params:oauth:token-type:access_token",
+        })))
+        // The token is requested only once.
+        .expect(1)
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/config"))
+        .and(query_param("warehouse", "wh"))
+        .and(header("Authorization", TOKEN))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "defaults": {},
+            "overrides": { "prefix": "ws" },
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+
+    // The mock mounted first takes precedence, so the second page goes before the first.
+    Mock::given(method("GET"))
+        .and(path("/v1/ws/namespaces"))
+        .and(query_param("pageToken", "p2"))
+        .and(header("Authorization", TOKEN))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "namespaces": [["db2", "sub"]],
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/ws/namespaces"))
+        .and(header("Authorization", TOKEN))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "namespaces": [["db1"]],
+            "next-page-token": "p2",
+        })))
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/ws/namespaces/db1"))
+        .and(header("Authorization", TOKEN))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "namespace": ["db1"],
+            "properties": {},
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/ws/namespaces/db3"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": {
+                "message": "Namespace does not exist: db3",
+                "type": "NoSuchNamespaceException",
+                "code": 404,
+            }
+        })))
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/ws/namespaces/db2%1Fsub/tables"))
+        .and(header("Authorization", TOKEN))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "identifiers": [
+                { "namespace": ["db2", "sub"], "name": "t1" },
+                { "namespace": ["db2", "sub"], "name": "t2" },
+            ],
+        })))
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/ws/namespaces/db1/tables/t1"))
+        .and(header("Authorization", TOKEN))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "metadata-location": "s3://bucket/wh/db1/t1/metadata/00001-a.metadata.json",
+            "metadata": {
+                "format-version": 2,
+                "location": "s3://bucket/wh/db1/t1",
+            },
+            "config": {},
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/ws/namespaces/db1/tables/t2"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": {
+                "message": "Table does not exist: db1.t2",
+                "type": "NoSuchTableException",
+                "code": 404,
+            }
+        })))
+        .mount(server)
+        .await;
+}
+
+fn new_client(server: &MockServer) -> Result<RestCatalogClient> {
+    RestCatalogClient::try_create(IcebergRestCatalogOption {
+        uri: server.uri(),
+        warehouse: Some("wh".to_string()),
+        // Overridden by the config of the catalog.
+        prefix: Some("ignored".to_string()),
+        token: None,
+        credential: Some("client:secret".to_string()),
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_rest_catalog_list() -> Result<()> {
+    let server = MockServer::start().await;
+    mount_catalog(&server).await;
+    let client = new_client(&server)?;
+
+    assert_eq!(client.list_namespaces().await?, vec![
+        "db1".to_string(),
+        "db2.sub".to_string()
+    ]);
+    assert!(client.namespace_exists("db1").await?);
+    assert!(!client.namespace_exists("db3").await?);
+    assert_eq!(client.list_tables("db2.sub").await?, vec![
+        "t1".to_string(),
+        "t2".to_string()
+    ]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_rest_catalog_load_table() -> Result<()> {
+    let server = MockServer::start().await;
+    mount_catalog(&server).await;
+    let client = new_client(&server)?;
+
+    let loaded = client.load_table("db1", "t1").await?;
+    assert_eq!(
+        loaded.metadata_location.as_deref(),
+        Some("s3://bucket/wh/db1/t1/metadata/00001-a.metadata.json")
+    );
+    assert_eq!(loaded.metadata["location"], json!("s3://bucket/wh/db1/t1"));
+
+    let err = client.load_table("db1", "t2").await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UNKNOWN_TABLE);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_rest_catalog_unauthorized() -> Result<()> {
+    let server = MockServer::start().await;
+    let client = RestCatalogClient::try_create(IcebergRestCatalogOption {
+        uri: server.uri(),
+        warehouse: Some("wh".to_string()),
+        token: Some("wrong-token".to_string()),
+        ..Default::default()
+    })?;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/config"))
+        .and(header("Authorization", "Bearer wrong-token"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error": {
+                "message": "Not authorized",
+                "type": "NotAuthorizedException",
+                "code": 401,
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let err = client.list_namespaces().await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::AUTHENTICATE_FAILURE);
+    Ok(())
+}
@@ -15,6 +15,8 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod append;
+mod read;
 mod stage_table;
 
+pub use read::StageFilePart;
 pub use stage_table::StageTable;
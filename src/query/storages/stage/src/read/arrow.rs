@@ -0,0 +1,57 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::TableSchema;
+use databend_common_storage::ArrowIpcReader;
+
+/// Decode the record batches of an arrow IPC file or stream into blocks.
+pub struct ArrowBlockReader {
+    reader: ArrowIpcReader,
+    schema: TableSchema,
+    data_schema: DataSchema,
+}
+
+impl ArrowBlockReader {
+    pub fn try_create(location: &str, data: Bytes) -> Result<Self> {
+        let reader = ArrowIpcReader::try_create(location, data)?;
+        let schema = TableSchema::try_from(reader.schema().as_ref())?;
+        let data_schema = DataSchema::from(&schema);
+        Ok(Self {
+            reader,
+            schema,
+            data_schema,
+        })
+    }
+
+    /// The schema of the decoded blocks.
+    pub fn schema(&self) -> &TableSchema {
+        &self.schema
+    }
+}
+
+impl Iterator for ArrowBlockReader {
+    type Item = Result<DataBlock>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch = self.reader.next()?;
+        Some(batch.and_then(|batch| {
+            let (block, _) = DataBlock::from_record_batch(&self.data_schema, &batch)?;
+            Ok(block)
+        }))
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use apache_avro::types::Value;
+use apache_avro::Reader;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableSchema;
+use databend_common_storage::avro_to_table_schema;
+use ethnum::i256;
+
+/// Decode the records of an avro object container file into blocks.
+pub struct AvroBlockReader {
+    location: String,
+    reader: Reader<'static, Cursor<Vec<u8>>>,
+    schema: TableSchema,
+    data_types: Vec<DataType>,
+    batch_size: usize,
+}
+
+impl AvroBlockReader {
+    pub fn try_create(location: &str, data: Vec<u8>, batch_size: usize) -> Result<Self> {
+        let reader = Reader::new(Cursor::new(data))
+            .map_err(|e| ErrorCode::BadBytes(format!("Invalid avro file {}: {}", location, e)))?;
+        let schema = avro_to_table_schema(reader.writer_schema())?;
+        let data_types = schema
+            .fields()
+            .iter()
+            .map(|f| DataType::from(f.data_type()))
+            .collect();
+        Ok(Self {
+            location: location.to_string(),
+            reader,
+            schema,
+            data_types,
+            batch_size,
+        })
+    }
+
+    /// The schema of the decoded blocks.
+    pub fn schema(&self) -> &TableSchema {
+        &self.schema
+    }
+
+    fn read_block(&mut self) -> Result<Option<DataBlock>> {
+        let mut columns = self
+            .data_types
+            .iter()
+            .map(|ty| ColumnBuilder::with_capacity(ty, self.batch_size))
+            .collect::<Vec<_>>();
+        let mut num_rows = 0;
+        while num_rows < self.batch_size {
+            let value = match self.reader.next() {
+                Some(value) => value.map_err(|e| {
+                    ErrorCode::BadBytes(format!(
+                        "Invalid avro record in file {}: {}",
+                        self.location, e
+                    ))
+                })?,
+                None => break,
+            };
+            let fields = match value {
+                Value::Record(fields) => fields,
+                other => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Expect avro record in file {}, but got {:?}",
+                        self.location, other
+                    )));
+                }
+            };
+            for ((_, value), (column, ty)) in fields
+                .into_iter()
+                .zip(columns.iter_mut().zip(self.data_types.iter()))
+            {
+                let scalar = avro_value_to_scalar(value, ty)?;
+                column.push(scalar.as_ref());
+            }
+            num_rows += 1;
+        }
+        if num_rows == 0 {
+            return Ok(None);
+        }
+        let columns = columns.into_iter().map(|c| c.build()).collect();
+        Ok(Some(DataBlock::new_from_columns(columns)))
+    }
+}
+
+impl Iterator for AvroBlockReader {
+    type Item = Result<DataBlock>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_block().transpose()
+    }
+}
+
+/// Convert an avro value to a scalar of `ty`, which is inferred from the writer schema of the value.
+fn avro_value_to_scalar(value: Value, ty: &DataType) -> Result<Scalar> {
+    let scalar = match (value, ty) {
+        (Value::Null, _) => Scalar::Null,
+        (Value::Union(_, value), DataType::Variant) => to_variant(*value)?,
+        (Value::Union(_, value), ty) => avro_value_to_scalar(*value, ty)?,
+        (value, DataType::Nullable(ty)) => avro_value_to_scalar(value, ty)?,
+        (value, DataType::Variant) => to_variant(value)?,
+        (Value::Boolean(v), DataType::Boolean) => Scalar::Boolean(v),
+        (Value::Int(v), DataType::Number(NumberDataType::Int32)) => {
+            Scalar::Number(NumberScalar::Int32(v))
+        }
+        (Value::TimeMillis(v), DataType::Number(NumberDataType::Int32)) => {
+            Scalar::Number(NumberScalar::Int32(v))
+        }
+        (Value::Long(v), DataType::Number(NumberDataType::Int64)) => {
+            Scalar::Number(NumberScalar::Int64(v))
+        }
+        (Value::TimeMicros(v), DataType::Number(NumberDataType::Int64)) => {
+            Scalar::Number(NumberScalar::Int64(v))
+        }
+        (Value::Float(v), DataType::Number(NumberDataType::Float32)) => {
+            Scalar::Number(NumberScalar::Float32(v.into()))
+        }
+        (Value::Double(v), DataType::Number(NumberDataType::Float64)) => {
+            Scalar::Number(NumberScalar::Float64(v.into()))
+        }
+        (Value::Bytes(v), DataType::Binary) | (Value::Fixed(_, v), DataType::Binary) => {
+            Scalar::Binary(v)
+        }
+        (Value::String(v), DataType::String) | (Value::Enum(_, v), DataType::String) => {
+            Scalar::String(v)
+        }
+        (Value::Uuid(v), DataType::String) => Scalar::String(v.to_string()),
+        (Value::Date(v), DataType::Date) => Scalar::Date(v),
+        (Value::TimestampMillis(v), DataType::Timestamp)
+        | (Value::LocalTimestampMillis(v), DataType::Timestamp) => Scalar::Timestamp(v * 1000),
+        (Value::TimestampMicros(v), DataType::Timestamp)
+        | (Value::LocalTimestampMicros(v), DataType::Timestamp) => Scalar::Timestamp(v),
+        (Value::Duration(v), DataType::Interval) => {
+            let months = u32::from(v.months()) as i32;
+            let days = u32::from(v.days()) as i32;
+            let micros = u32::from(v.millis()) as i64 * 1000;
+            Scalar::Interval(months_days_micros::new(months, days, micros))
+        }
+        (Value::Decimal(v), DataType::Decimal(decimal_type)) => {
+            let bytes = Vec::<u8>::try_from(&v)
+                .map_err(|e| ErrorCode::BadBytes(format!("Invalid avro decimal: {}", e)))?;
+            match decimal_type {
+                DecimalDataType::Decimal128(size) => Scalar::Decimal(DecimalScalar::Decimal128(
+                    i128::from_be_bytes(sign_extend::<16>(&bytes)?),
+                    *size,
+                )),
+                DecimalDataType::Decimal256(size) => Scalar::Decimal(DecimalScalar::Decimal256(
+                    i256::from_be_bytes(sign_extend::<32>(&bytes)?),
+                    *size,
+                )),
+            }
+        }
+        (Value::Array(values), DataType::Array(item_type)) => {
+            let mut builder = ColumnBuilder::with_capacity(item_type, values.len());
+            for value in values {
+                builder.push(avro_value_to_scalar(value, item_type)?.as_ref());
+            }
+            Scalar::Array(builder.build())
+        }
+        (Value::Map(values), DataType::Map(kv_type)) => {
+            let value_type = match kv_type.as_ref() {
+                DataType::Tuple(fields) if fields.len() == 2 => &fields[1],
+                _ => unreachable!("map of avro must have key and value"),
+            };
+            // Keep the output stable, the entries of avro map are unordered.
+            let mut values = values.into_iter().collect::<Vec<_>>();
+            values.sort_by(|a, b| a.0.cmp(&b.0));
+            let mut builder = ColumnBuilder::with_capacity(kv_type, values.len());
+            for (key, value) in values {
+                let value = avro_value_to_scalar(value, value_type)?;
+                builder.push(Scalar::Tuple(vec![Scalar::String(key), value]).as_ref());
+            }
+            Scalar::Map(builder.build())
+        }
+        (Value::Record(fields), DataType::Tuple(fields_type)) => {
+            let values = fields
+                .into_iter()
+                .zip(fields_type.iter())
+                .map(|((_, value), ty)| avro_value_to_scalar(value, ty))
+                .collect::<Result<Vec<_>>>()?;
+            Scalar::Tuple(values)
+        }
+        (value, ty) => {
+            return Err(ErrorCode::BadBytes(format!(
+                "Cannot convert avro value {:?} to {}",
+                value, ty
+            )));
+        }
+    };
+    Ok(scalar)
+}
+
+fn to_variant(value: Value) -> Result<Scalar> {
+    let json = serde_json::Value::try_from(value)
+        .map_err(|e| ErrorCode::BadBytes(format!("Cannot convert avro value to json: {}", e)))?;
+    let value = jsonb::parse_value(json.to_string().as_bytes())
+        .map_err(|e| ErrorCode::BadBytes(format!("Invalid json value: {}", e)))?;
+    Ok(Scalar::Variant(value.to_vec()))
+}
+
+/// Sign extend the big-endian two's-complement bytes of an avro decimal to `N` bytes.
+fn sign_extend<const N: usize>(bytes: &[u8]) -> Result<[u8; N]> {
+    if bytes.len() > N {
+        return Err(ErrorCode::BadBytes(format!(
+            "avro decimal with {} bytes is too large",
+            bytes.len()
+        )));
+    }
+    let negative = bytes.first().is_some_and(|b| b & 0x80 != 0);
+    let mut buf = if negative { [0xff; N] } else { [0; N] };
+    buf[N - bytes.len()..].copy_from_slice(bytes);
+    Ok(buf)
+}
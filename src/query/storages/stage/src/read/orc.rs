@@ -0,0 +1,351 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::io::ErrorKind;
+use std::ops::Range;
+use std::sync::Arc;
+
+use bytes::buf::Reader;
+use bytes::Buf;
+use bytes::Bytes;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_storages_common_pruner::RangePruner;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use log::debug;
+use opendal::Operator;
+use orc_arrow::datatypes::SchemaRef as OrcArrowSchemaRef;
+use orc_arrow::error::ArrowError as OrcArrowError;
+use orc_arrow::ipc::writer::StreamWriter;
+use orc_arrow::record_batch::RecordBatch as OrcRecordBatch;
+use orc_rust::reader::metadata::FileMetadata;
+use orc_rust::reader::ChunkReader;
+use orc_rust::statistics::TypeStatistics;
+use orc_rust::stripe::StripeMetadata;
+use orc_rust::ArrowReader;
+use orc_rust::ArrowReaderBuilder;
+
+use crate::read::arrow::ArrowBlockReader;
+
+/// Prune the stripes of an orc file with the range pruner built from the push down filter.
+pub struct OrcStripePruner {
+    pub pruner: Arc<dyn RangePruner + Send + Sync>,
+    /// The schema the pruner is built with.
+    pub schema: TableSchemaRef,
+}
+
+/// The bytes read from the end of an orc file at first, the metadata of most files fits in it.
+const ORC_TAIL_READ_SIZE: u64 = 256 * 1024;
+
+/// The byte ranges of an orc file read from the storage.
+///
+/// Only the tail with the metadata and the stripe being decoded are read, instead of the whole file.
+#[derive(Clone)]
+struct OrcFileChunks {
+    len: u64,
+    chunks: Vec<(u64, Bytes)>,
+}
+
+impl OrcFileChunks {
+    fn chunk(&self, start: u64, end: u64) -> std::io::Result<(u64, &Bytes)> {
+        self.chunks
+            .iter()
+            .find(|(offset, data)| *offset <= start && end <= offset + data.len() as u64)
+            .map(|(offset, data)| (*offset, data))
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    ErrorKind::UnexpectedEof,
+                    format!("range {}..{} of the orc file is not read", start, end),
+                )
+            })
+    }
+}
+
+impl ChunkReader for OrcFileChunks {
+    type T = Reader<Bytes>;
+
+    fn len(&self) -> u64 {
+        self.len
+    }
+
+    fn get_read(&self, offset_from_start: u64) -> std::io::Result<Self::T> {
+        let (offset, data) = self.chunk(offset_from_start, offset_from_start + 1)?;
+        Ok(data.slice((offset_from_start - offset) as usize..).reader())
+    }
+
+    fn get_bytes(&self, offset_from_start: u64, length: u64) -> std::io::Result<Bytes> {
+        let end = offset_from_start + length;
+        let (offset, data) = self.chunk(offset_from_start, end)?;
+        Ok(data.slice((offset_from_start - offset) as usize..(end - offset) as usize))
+    }
+}
+
+/// Decode the stripes of an orc file into blocks, skipping the stripes pruned by statistics.
+///
+/// The stripes are read from the storage one by one with ranged reads by [`Self::read_next_stripe`].
+pub struct OrcBlockReader {
+    operator: Operator,
+    location: String,
+    tail: OrcFileChunks,
+    schema: TableSchema,
+    orc_arrow_schema: OrcArrowSchemaRef,
+    stripes: VecDeque<Range<u64>>,
+    batch_size: usize,
+    current: Option<ArrowReader<OrcFileChunks>>,
+}
+
+impl OrcBlockReader {
+    #[async_backtrace::framed]
+    pub async fn try_create(
+        operator: Operator,
+        location: &str,
+        size: u64,
+        batch_size: usize,
+        pruner: Option<&OrcStripePruner>,
+    ) -> Result<Self> {
+        let (tail, builder) = Self::read_tail(&operator, location, size).await?;
+        let file_metadata = builder.file_metadata();
+        let orc_arrow_schema = Arc::new(
+            file_metadata
+                .root_data_type()
+                .create_arrow_schema(&HashMap::new()),
+        );
+        let schema = ArrowBlockReader::try_create(
+            location,
+            orc_batches_to_ipc(location, &orc_arrow_schema, None)?,
+        )?
+        .schema()
+        .clone();
+
+        let stripe_metas = file_metadata.stripe_metadatas();
+        let mut stripes = VecDeque::with_capacity(stripe_metas.len());
+        for stripe in stripe_metas {
+            if let Some(pruner) = pruner {
+                let stats = stripe_statistics(file_metadata, stripe, &pruner.schema);
+                if !pruner.pruner.should_keep(&stats, None) {
+                    continue;
+                }
+            }
+            let start = stripe.offset();
+            let end = start + stripe.index_length() + stripe.data_length() + stripe.footer_length();
+            stripes.push_back(start..end);
+        }
+        debug!(
+            "read orc file {}: {} of {} stripes kept after pruning",
+            location,
+            stripes.len(),
+            stripe_metas.len()
+        );
+
+        Ok(Self {
+            operator,
+            location: location.to_string(),
+            tail,
+            schema,
+            orc_arrow_schema,
+            stripes,
+            batch_size,
+            current: None,
+        })
+    }
+
+    /// Read the tail of the file with the metadata, more bytes are read if the metadata is larger.
+    async fn read_tail(
+        operator: &Operator,
+        location: &str,
+        size: u64,
+    ) -> Result<(OrcFileChunks, ArrowReaderBuilder<OrcFileChunks>)> {
+        let mut tail_size = ORC_TAIL_READ_SIZE.min(size);
+        loop {
+            let start = size - tail_size;
+            let data = operator.read_with(location).range(start..size).await?;
+            let tail = OrcFileChunks {
+                len: size,
+                chunks: vec![(start, Bytes::from(data))],
+            };
+            match ArrowReaderBuilder::try_new(tail.clone()) {
+                Ok(builder) => return Ok((tail, builder)),
+                Err(_) if tail_size < size => tail_size = (tail_size * 4).min(size),
+                Err(e) => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Invalid orc file {}: {}",
+                        location, e
+                    )));
+                }
+            }
+        }
+    }
+
+    /// The schema of the decoded blocks.
+    pub fn schema(&self) -> &TableSchema {
+        &self.schema
+    }
+
+    pub fn has_next_stripe(&self) -> bool {
+        !self.stripes.is_empty()
+    }
+
+    /// Read the next stripe to decode, the previous stripe is released.
+    #[async_backtrace::framed]
+    pub async fn read_next_stripe(&mut self) -> Result<()> {
+        let Some(range) = self.stripes.pop_front() else {
+            return Ok(());
+        };
+        let data = self
+            .operator
+            .read_with(&self.location)
+            .range(range.clone())
+            .await?;
+        let mut chunks = self.tail.clone();
+        chunks.chunks.push((range.start, Bytes::from(data)));
+
+        let reader = ArrowReaderBuilder::try_new(chunks)
+            .map_err(|e| ErrorCode::BadBytes(format!("Invalid orc file {}: {}", self.location, e)))?
+            .with_batch_size(self.batch_size)
+            .with_file_byte_range(range.start as usize..range.end as usize)
+            .build();
+        self.current = Some(reader);
+        Ok(())
+    }
+
+    /// Decode the next block of the current stripe, `None` if the stripe is finished.
+    pub fn read_block(&mut self) -> Result<Option<DataBlock>> {
+        let Some(reader) = self.current.as_mut() else {
+            return Ok(None);
+        };
+        match reader.next() {
+            Some(batch) => {
+                let batch = batch.map_err(|e| {
+                    ErrorCode::BadBytes(format!(
+                        "Invalid orc stripe in file {}: {}",
+                        self.location, e
+                    ))
+                })?;
+                let data =
+                    orc_batches_to_ipc(&self.location, &self.orc_arrow_schema, Some(&batch))?;
+                ArrowBlockReader::try_create(&self.location, data)?
+                    .next()
+                    .transpose()
+            }
+            None => {
+                self.current = None;
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// orc-rust is built with a newer arrow than the one of the workspace,
+/// its schema and record batches are moved over through the arrow IPC stream format,
+/// which is stable across the arrow versions.
+fn orc_batches_to_ipc(
+    location: &str,
+    schema: &OrcArrowSchemaRef,
+    batch: Option<&OrcRecordBatch>,
+) -> Result<Bytes> {
+    let to_error =
+        |e: OrcArrowError| ErrorCode::BadBytes(format!("Invalid orc file {}: {}", location, e));
+    let mut writer = StreamWriter::try_new(Vec::new(), schema).map_err(to_error)?;
+    if let Some(batch) = batch {
+        writer.write(batch).map_err(to_error)?;
+    }
+    writer.finish().map_err(to_error)?;
+    Ok(Bytes::from(writer.into_inner().map_err(to_error)?))
+}
+
+/// Collect the min/max statistics of the top-level columns in a stripe,
+/// keyed by the column ids of `schema`.
+fn stripe_statistics(
+    file_metadata: &FileMetadata,
+    stripe: &StripeMetadata,
+    schema: &TableSchema,
+) -> StatisticsOfColumns {
+    let mut stats = StatisticsOfColumns::new();
+    let column_stats = stripe.column_statistics();
+    let num_rows = stripe.number_of_rows();
+    for child in file_metadata.root_data_type().children() {
+        let Some(field) = schema.fields().iter().find(|f| f.name() == child.name()) else {
+            continue;
+        };
+        let Some(column_stat) = column_stats.get(child.data_type().column_index()) else {
+            continue;
+        };
+        let Some(type_stat) = column_stat.type_statistics() else {
+            continue;
+        };
+        let Some((min, max)) = type_statistics_to_scalar(type_stat, &field.data_type) else {
+            continue;
+        };
+        let null_count = if column_stat.has_null() {
+            num_rows.saturating_sub(column_stat.number_of_values())
+        } else {
+            0
+        };
+        stats.insert(
+            field.column_id(),
+            ColumnStatistics::new(min, max, null_count, 0, None),
+        );
+    }
+    stats
+}
+
+fn type_statistics_to_scalar(
+    stat: &TypeStatistics,
+    data_type: &TableDataType,
+) -> Option<(Scalar, Scalar)> {
+    match (stat, data_type.remove_nullable()) {
+        (TypeStatistics::Integer { min, max, .. }, TableDataType::Number(ty)) => {
+            Some((int_scalar(*min, &ty)?, int_scalar(*max, &ty)?))
+        }
+        (TypeStatistics::Double { min, max, .. }, TableDataType::Number(ty)) => match ty {
+            NumberDataType::Float32 => Some((
+                Scalar::Number(NumberScalar::Float32((*min as f32).into())),
+                Scalar::Number(NumberScalar::Float32((*max as f32).into())),
+            )),
+            NumberDataType::Float64 => Some((
+                Scalar::Number(NumberScalar::Float64((*min).into())),
+                Scalar::Number(NumberScalar::Float64((*max).into())),
+            )),
+            _ => None,
+        },
+        (TypeStatistics::String { min, max, .. }, TableDataType::String) => {
+            Some((Scalar::String(min.clone()), Scalar::String(max.clone())))
+        }
+        (TypeStatistics::Date { min, max }, TableDataType::Date) => {
+            Some((Scalar::Date(*min), Scalar::Date(*max)))
+        }
+        _ => None,
+    }
+}
+
+fn int_scalar(v: i64, ty: &NumberDataType) -> Option<Scalar> {
+    let scalar = match ty {
+        NumberDataType::Int8 => NumberScalar::Int8(v.try_into().ok()?),
+        NumberDataType::Int16 => NumberScalar::Int16(v.try_into().ok()?),
+        NumberDataType::Int32 => NumberScalar::Int32(v.try_into().ok()?),
+        NumberDataType::Int64 => NumberScalar::Int64(v),
+        _ => return None,
+    };
+    Some(Scalar::Number(scalar))
+}
@@ -0,0 +1,117 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableSchema;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::NullAs;
+
+/// Build the expressions which map the columns decoded from a file (`file_schema`)
+/// to the columns of `output_schema`.
+///
+/// Columns are matched by name, exact matches win over case-insensitive ones.
+/// Columns missing in the file are handled according to `missing_field_as`.
+pub fn build_output_projection(
+    location: &str,
+    file_schema: &TableSchema,
+    output_schema: &TableSchema,
+    default_values: Option<&[Scalar]>,
+    missing_field_as: &NullAs,
+) -> Result<Vec<Expr>> {
+    let mut output_projection = Vec::with_capacity(output_schema.num_fields());
+    let mut num_inputs = 0;
+    for (i, to_field) in output_schema.fields().iter().enumerate() {
+        let field_name = to_field.name();
+        let pos = file_schema
+            .fields()
+            .iter()
+            .position(|f| f.name() == field_name)
+            .or_else(|| {
+                file_schema
+                    .fields()
+                    .iter()
+                    .position(|f| f.name().eq_ignore_ascii_case(field_name))
+            });
+        let expr = match pos {
+            Some(pos) => {
+                num_inputs += 1;
+                let from_field = file_schema.field(pos);
+                let expr = Expr::ColumnRef {
+                    span: None,
+                    id: pos,
+                    data_type: from_field.data_type().into(),
+                    display_name: from_field.name().clone(),
+                };
+                if from_field.data_type == to_field.data_type {
+                    expr
+                } else {
+                    check_cast(
+                        None,
+                        false,
+                        expr,
+                        &to_field.data_type().into(),
+                        &BUILTIN_FUNCTIONS,
+                    )
+                    .map_err(|e| {
+                        ErrorCode::BadDataValueType(format!(
+                            "Cannot cast column {} from {:?} to {:?} in file {}: {}",
+                            field_name,
+                            from_field.data_type(),
+                            to_field.data_type(),
+                            location,
+                            e.message()
+                        ))
+                    })?
+                }
+            }
+            None => {
+                let scalar = match missing_field_as {
+                    NullAs::Error => {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "column {} is missing in file {}, consider setting MISSING_FIELD_AS to FIELD_DEFAULT or NULL",
+                            field_name, location
+                        )));
+                    }
+                    NullAs::Null if to_field.is_nullable_or_null() => Scalar::Null,
+                    NullAs::Null => {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "column {} is missing in file {}, and it is not nullable",
+                            field_name, location
+                        )));
+                    }
+                    NullAs::FieldDefault => match default_values {
+                        Some(values) => values[i].clone(),
+                        None => Scalar::default_value(&to_field.data_type().into()),
+                    },
+                };
+                Expr::Constant {
+                    span: None,
+                    scalar,
+                    data_type: to_field.data_type().into(),
+                }
+            }
+        };
+        output_projection.push(expr);
+    }
+    if num_inputs == 0 {
+        return Err(ErrorCode::BadBytes(format!(
+            "not column name match in file {location}",
+        )));
+    }
+    Ok(output_projection)
+}
@@ -0,0 +1,308 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use databend_common_base::base::Progress;
+use databend_common_base::base::ProgressValues;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableSchemaRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::processors::Profile;
+use databend_common_pipeline_core::processors::ProfileStatisticsName;
+use databend_common_storage::FileStatus;
+use opendal::Operator;
+
+use crate::read::arrow::ArrowBlockReader;
+use crate::read::avro::AvroBlockReader;
+use crate::read::orc::OrcBlockReader;
+use crate::read::orc::OrcStripePruner;
+use crate::read::projection::build_output_projection;
+use crate::read::stage_file_part::StageFilePart;
+
+type BlockIterator = Box<dyn Iterator<Item = Result<DataBlock>> + Send>;
+
+enum FileBlocks {
+    /// The blocks decoded from the whole file.
+    Iter(BlockIterator),
+    /// The blocks decoded from the stripes read one by one.
+    Orc(OrcBlockReader),
+}
+
+struct FileReader {
+    location: String,
+    blocks: FileBlocks,
+    output_projection: Vec<Expr>,
+    num_rows: usize,
+}
+
+enum State {
+    Init,
+    ReadFile(FileReader),
+    /// The current stripe of the orc file is finished, the next stripe needs to be read.
+    ReadStripe(FileReader),
+}
+
+/// Read the files of the formats which can not be split, such as avro, orc and arrow.
+///
+/// Avro and arrow files are read as a whole, orc files are read stripe by stripe.
+pub struct StageFileSource {
+    // Source processor related fields.
+    output: Arc<OutputPort>,
+    scan_progress: Arc<Progress>,
+
+    // Used for event transforming.
+    ctx: Arc<dyn TableContext>,
+    generated_data: Option<DataBlock>,
+    is_finished: bool,
+
+    operator: Operator,
+    format_params: FileFormatParams,
+    output_schema: TableSchemaRef,
+    schema: DataSchemaRef,
+    default_values: Option<Vec<Scalar>>,
+    stripe_pruner: Option<Arc<OrcStripePruner>>,
+    func_ctx: FunctionContext,
+
+    state: State,
+    batch_size: usize,
+}
+
+impl StageFileSource {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        operator: Operator,
+        format_params: FileFormatParams,
+        output_schema: TableSchemaRef,
+        default_values: Option<Vec<Scalar>>,
+        stripe_pruner: Option<Arc<OrcStripePruner>>,
+    ) -> Result<ProcessorPtr> {
+        let scan_progress = ctx.get_scan_progress();
+        let batch_size = ctx.get_settings().get_max_block_size()? as usize;
+        let func_ctx = ctx.get_function_context()?;
+        let schema = Arc::new(DataSchema::from(&output_schema));
+
+        Ok(ProcessorPtr::create(Box::new(Self {
+            output,
+            scan_progress,
+            ctx,
+            generated_data: None,
+            is_finished: false,
+            operator,
+            format_params,
+            output_schema,
+            schema,
+            default_values,
+            stripe_pruner,
+            func_ctx,
+            state: State::Init,
+            batch_size,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    async fn open_file(&self, part: &StageFilePart) -> Result<FileReader> {
+        let (blocks, file_schema, missing_field_as) = match &self.format_params {
+            FileFormatParams::Avro(params) => {
+                let data = self.operator.read(&part.location).await?;
+                let reader = AvroBlockReader::try_create(&part.location, data, self.batch_size)?;
+                let schema = reader.schema().clone();
+                let blocks = FileBlocks::Iter(Box::new(reader));
+                (blocks, schema, &params.missing_field_as)
+            }
+            FileFormatParams::Orc(params) => {
+                let reader = OrcBlockReader::try_create(
+                    self.operator.clone(),
+                    &part.location,
+                    part.size,
+                    self.batch_size,
+                    self.stripe_pruner.as_deref(),
+                )
+                .await?;
+                let schema = reader.schema().clone();
+                (FileBlocks::Orc(reader), schema, &params.missing_field_as)
+            }
+            FileFormatParams::Arrow(params) => {
+                let data = self.operator.read(&part.location).await?;
+                let reader = ArrowBlockReader::try_create(&part.location, Bytes::from(data))?;
+                let schema = reader.schema().clone();
+                let blocks = FileBlocks::Iter(Box::new(reader));
+                (blocks, schema, &params.missing_field_as)
+            }
+            other => {
+                return Err(ErrorCode::Internal(format!(
+                    "bug: StageFileSource does not support format {:?}",
+                    other.get_type()
+                )));
+            }
+        };
+        let output_projection = build_output_projection(
+            &part.location,
+            &file_schema,
+            &self.output_schema,
+            self.default_values.as_deref(),
+            missing_field_as,
+        )?;
+        Ok(FileReader {
+            location: part.location.clone(),
+            blocks,
+            output_projection,
+            num_rows: 0,
+        })
+    }
+}
+
+impl FileReader {
+    fn next_block(&mut self) -> Result<Option<DataBlock>> {
+        match &mut self.blocks {
+            FileBlocks::Iter(blocks) => blocks.next().transpose(),
+            FileBlocks::Orc(reader) => reader.read_block(),
+        }
+    }
+
+    fn has_next_stripe(&self) -> bool {
+        match &self.blocks {
+            FileBlocks::Iter(_) => false,
+            FileBlocks::Orc(reader) => reader.has_next_stripe(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for StageFileSource {
+    fn name(&self) -> String {
+        "StageFileSource".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.is_finished {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        if self.output.is_finished() {
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.generated_data.take() {
+            None => match &self.state {
+                State::Init => Ok(Event::Async),
+                State::ReadFile(_) => Ok(Event::Sync),
+                State::ReadStripe(_) => Ok(Event::Async),
+            },
+            Some(data_block) => {
+                let progress_values = ProgressValues {
+                    rows: data_block.num_rows(),
+                    bytes: data_block.memory_size(),
+                };
+                self.scan_progress.incr(&progress_values);
+                Profile::record_usize_profile(
+                    ProfileStatisticsName::ScanBytes,
+                    data_block.memory_size(),
+                );
+                self.output.push_data(Ok(data_block));
+                Ok(Event::NeedConsume)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Init) {
+            State::ReadFile(mut reader) => match reader.next_block()? {
+                Some(block) => {
+                    let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                    let mut columns = Vec::with_capacity(reader.output_projection.len());
+                    for (field, expr) in self
+                        .schema
+                        .fields()
+                        .iter()
+                        .zip(reader.output_projection.iter())
+                    {
+                        let value = evaluator.run(expr)?;
+                        let column = BlockEntry::new(field.data_type().clone(), value);
+                        columns.push(column);
+                    }
+                    reader.num_rows += block.num_rows();
+                    self.generated_data = Some(DataBlock::new(columns, block.num_rows()));
+                    self.state = State::ReadFile(reader);
+                }
+                None if reader.has_next_stripe() => {
+                    self.state = State::ReadStripe(reader);
+                }
+                None => {
+                    // The file is finished. We should try to read another file.
+                    self.ctx
+                        .get_copy_status()
+                        .add_chunk(reader.location.as_str(), FileStatus {
+                            num_rows_loaded: reader.num_rows,
+                            error: None,
+                        });
+                }
+            },
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Init) {
+            State::Init => {
+                if let Some(part) = self.ctx.get_partition() {
+                    let part = StageFilePart::from_part(&part)?;
+                    let reader = self.open_file(part).await?;
+                    self.state = State::ReadFile(reader);
+                } else {
+                    self.is_finished = true;
+                }
+            }
+            State::ReadStripe(mut reader) => {
+                if let FileBlocks::Orc(orc_reader) = &mut reader.blocks {
+                    orc_reader.read_next_stripe().await?;
+                }
+                self.state = State::ReadFile(reader);
+            }
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourceInfo;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_storage::init_stage_operator;
+use databend_storages_common_pruner::RangePrunerCreator;
+
+use crate::read::orc::OrcStripePruner;
+use crate::read::source::StageFileSource;
+use crate::read::stage_file_part::StageFilePart;
+use crate::StageTable;
+
+/// Read the stage files of the formats that carry their own schema and can not be split,
+/// each file is read as a whole by one [`StageFileSource`].
+pub struct StageFileTable {}
+
+impl StageFileTable {
+    #[async_backtrace::framed]
+    pub async fn do_read_partitions(
+        stage_table_info: &StageTableInfo,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        // User set the files.
+        let files = if let Some(files) = &stage_table_info.files_to_copy {
+            files.clone()
+        } else {
+            StageTable::list_files(stage_table_info, None).await?
+        };
+
+        let mut stats = PartStatistics::default();
+        let parts = files
+            .into_iter()
+            .map(|f| {
+                stats.read_bytes += f.size as usize;
+                let part: Box<dyn PartInfo> = Box::new(StageFilePart {
+                    location: f.path,
+                    size: f.size,
+                });
+                Arc::new(part)
+            })
+            .collect::<Vec<_>>();
+        stats.partitions_scanned = parts.len();
+        stats.partitions_total = parts.len();
+
+        Ok((
+            stats,
+            Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+
+    pub fn do_read_data(
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let stage_table_info =
+            if let DataSourceInfo::StageSource(stage_table_info) = &plan.source_info {
+                stage_table_info
+            } else {
+                return Err(ErrorCode::Internal(
+                    "bug: StageFileTable::read_data must be called with StageSource",
+                ));
+            };
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let operator = init_stage_operator(&stage_table_info.stage_info)?;
+        let format_params = stage_table_info.stage_info.file_format_params.clone();
+
+        // Only orc has statistics to prune with, at the granularity of stripes.
+        let stripe_pruner = match (&format_params, &plan.push_downs) {
+            (
+                FileFormatParams::Orc(_),
+                Some(PushDownInfo {
+                    filters: Some(filters),
+                    ..
+                }),
+            ) => {
+                let filter = filters.filter.as_expr(&BUILTIN_FUNCTIONS);
+                let pruner = RangePrunerCreator::try_create(
+                    ctx.get_function_context()?,
+                    &stage_table_info.schema,
+                    Some(&filter),
+                )?;
+                Some(Arc::new(OrcStripePruner {
+                    pruner,
+                    schema: stage_table_info.schema.clone(),
+                }))
+            }
+            _ => None,
+        };
+
+        ctx.set_partitions(plan.parts.clone())?;
+
+        let output_schema = plan.schema();
+        let default_values = if stage_table_info.is_select {
+            None
+        } else {
+            stage_table_info.default_values.clone()
+        };
+        pipeline.add_source(
+            |output| {
+                StageFileSource::try_create(
+                    ctx.clone(),
+                    output,
+                    operator.clone(),
+                    format_params.clone(),
+                    output_schema.clone(),
+                    default_values.clone(),
+                    stripe_pruner.clone(),
+                )
+            },
+            max_threads,
+        )?;
+        Ok(())
+    }
+}
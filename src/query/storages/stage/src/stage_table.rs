@@ -49,6 +49,8 @@ use opendal::Operator;
 use opendal::Scheme;
 use parking_lot::Mutex;
 
+use crate::read::StageFileTable;
+
 /// TODO: we need to track the data metrics in stage table.
 pub struct StageTable {
     pub(crate) table_info: StageTableInfo,
@@ -127,6 +129,12 @@ impl Table for StageTable {
         ) {
             return ParquetTableForCopy::do_read_partitions(stage_info, ctx, _push_downs).await;
         }
+        if matches!(
+            stage_info.stage_info.file_format_params,
+            FileFormatParams::Avro(_) | FileFormatParams::Orc(_) | FileFormatParams::Arrow(_)
+        ) {
+            return StageFileTable::do_read_partitions(stage_info, ctx, _push_downs).await;
+        }
         // User set the files.
         let files = if let Some(files) = &stage_info.files_to_copy {
             files.clone()
@@ -177,6 +185,12 @@ impl Table for StageTable {
         ) {
             return ParquetTableForCopy::do_read_data(ctx, plan, pipeline, _put_cache);
         }
+        if matches!(
+            stage_table_info.stage_info.file_format_params,
+            FileFormatParams::Avro(_) | FileFormatParams::Orc(_) | FileFormatParams::Arrow(_)
+        ) {
+            return StageFileTable::do_read_data(ctx, plan, pipeline);
+        }
 
         let projection = if let Some(PushDownInfo {
             projection: Some(Projection::Columns(columns)),
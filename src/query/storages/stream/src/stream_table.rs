@@ -329,6 +329,16 @@ impl StreamTable {
         }
     }
 
+    /// Whether some blocks of the source table have been removed since the offset of the
+    /// stream, e.g. by update, delete or compaction, so the changes are not only appends.
+    #[async_backtrace::framed]
+    pub async fn has_removed_blocks(&self, ctx: Arc<dyn TableContext>) -> Result<bool> {
+        let table = self.source_table(ctx.clone()).await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let (del_blocks, _) = self.collect_incremental_blocks(ctx, fuse_table).await?;
+        Ok(!del_blocks.is_empty())
+    }
+
     #[minitrace::trace]
     pub async fn check_stream_status(&self, ctx: Arc<dyn TableContext>) -> Result<StreamStatus> {
         let base_table = self.source_table(ctx).await?;
@@ -43,6 +43,7 @@ mod malloc_stats_totals_table;
 mod metrics_table;
 mod one_table;
 mod password_policies_table;
+mod pipes_table;
 mod processes_table;
 mod processor_profile_table;
 mod query_cache_table;
@@ -90,6 +91,7 @@ pub use malloc_stats_totals_table::MallocStatsTotalsTable;
 pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
 pub use password_policies_table::PasswordPoliciesTable;
+pub use pipes_table::PipesTable;
 pub use processes_table::ProcessesTable;
 pub use processor_profile_table::ProcessorProfileTable;
 pub use query_cache_table::QueryCacheTable;
@@ -16,11 +16,13 @@ use std::sync::Arc;
 
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::materialized_view::is_materialized_view_stale;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::types::number::UInt64Type;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
@@ -161,6 +163,10 @@ where TablesTable<T>: HistoryAware
                 "owner",
                 TableDataType::Nullable(Box::new(TableDataType::String)),
             ),
+            TableField::new(
+                "is_stale",
+                TableDataType::Nullable(Box::new(TableDataType::Boolean)),
+            ),
         ])
     }
 
@@ -184,6 +190,7 @@ where TablesTable<T>: HistoryAware
 
         let mut database_tables = vec![];
         let mut owner: Vec<Option<String>> = Vec::new();
+        let mut is_stale: Vec<Option<bool>> = Vec::new();
         let user_api = UserApiProvider::instance();
 
         for (ctl_name, ctl) in ctls.into_iter() {
@@ -292,6 +299,21 @@ where TablesTable<T>: HistoryAware
                                     .map(|role| role.to_string()),
                             );
                         }
+                        // only materialized views can be stale.
+                        match is_materialized_view_stale(ctl.as_ref(), table.as_ref()).await {
+                            Ok(stale) => is_stale.push(stale),
+                            Err(err) => {
+                                let msg = format!(
+                                    "Failed to check staleness of materialized view {}: {}",
+                                    table.name(),
+                                    err
+                                );
+                                warn!("{}", msg);
+                                ctx.push_warning(msg);
+
+                                is_stale.push(None);
+                            }
+                        }
                     }
                 }
             }
@@ -397,6 +419,7 @@ where TablesTable<T>: HistoryAware
             UInt64Type::from_opt_data(number_of_segments),
             UInt64Type::from_opt_data(number_of_blocks),
             StringType::from_opt_data(owner),
+            BooleanType::from_opt_data(is_stale),
         ])
     }
 
@@ -26,7 +26,6 @@ use databend_common_expression::types::TimestampType;
 use databend_common_expression::BlockEntry;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
-use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRefExt;
@@ -36,6 +35,7 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_storage::DataOperator;
+use databend_common_storage::LocalSpillDisk;
 use futures::StreamExt;
 use futures::TryStreamExt;
 use opendal::Metakey;
@@ -64,6 +64,9 @@ impl AsyncSystemTable for TempFilesTable {
         let tenant = ctx.get_tenant();
         let operator = DataOperator::instance().operator();
 
+        let limit = push_downs.and_then(|x| x.limit).unwrap_or(usize::MAX);
+
+        let mut temp_files_type: Vec<String> = vec![];
         let mut temp_files_name: Vec<String> = vec![];
         let mut temp_files_content_length = vec![];
         let mut temp_files_last_modified = vec![];
@@ -74,13 +77,13 @@ impl AsyncSystemTable for TempFilesTable {
             .metakey(Metakey::LastModified | Metakey::ContentLength)
             .await
         {
-            let limit = push_downs.and_then(|x| x.limit).unwrap_or(usize::MAX);
             let mut lister = lister.take(limit);
 
             while let Some(entry) = lister.try_next().await? {
                 let metadata = entry.metadata();
 
                 if metadata.is_file() {
+                    temp_files_type.push("Spill".to_string());
                     temp_files_name.push(entry.name().to_string());
 
                     temp_files_last_modified
@@ -90,12 +93,24 @@ impl AsyncSystemTable for TempFilesTable {
             }
         }
 
+        // The files spilled to the local disk of this node.
+        let local_files = LocalSpillDisk::instance().list_files()?;
+        for file in local_files
+            .into_iter()
+            .take(limit.saturating_sub(temp_files_name.len()))
+        {
+            temp_files_type.push("LocalSpill".to_string());
+            temp_files_name.push(file.name);
+            temp_files_content_length.push(file.content_length);
+            temp_files_last_modified.push(file.last_modified);
+        }
+
         let num_rows = temp_files_name.len();
         let data_block = DataBlock::new(
             vec![
                 BlockEntry::new(
                     DataType::String,
-                    Value::Scalar(Scalar::String("Spill".to_string())),
+                    Value::Column(StringType::from_data(temp_files_type)),
                 ),
                 BlockEntry::new(
                     DataType::String,
@@ -32,6 +32,8 @@ mod visibility_checker;
 pub mod connection;
 pub mod file_format;
 pub mod idm_config;
+pub mod pipe;
+pub mod procedure;
 pub mod role_cache_mgr;
 pub mod role_util;
 
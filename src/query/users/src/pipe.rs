@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::PipeApi;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// pipe operations.
+impl UserApiProvider {
+    // Add a new pipe.
+    #[async_backtrace::framed]
+    pub async fn add_pipe(&self, tenant: &str, pipe: PipeInfo, if_not_exists: bool) -> Result<u64> {
+        let client = self.get_pipe_api_client(tenant)?;
+        match client.add_pipe(pipe).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::PIPE_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back(" (while create pipe)"))
+                }
+            }
+        }
+    }
+
+    // Update a pipe with the given function, the pipe is updated only if it is not
+    // changed by others since it is read. Return `None` if it does not exist and `if_exists`.
+    #[async_backtrace::framed]
+    pub async fn update_pipe(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+        f: impl FnOnce(&mut PipeInfo) + Send,
+    ) -> Result<Option<u64>> {
+        let client = self.get_pipe_api_client(tenant)?;
+        let seq_pipe = match client.get_pipe(name, MatchSeq::GE(0)).await {
+            Ok(seq_pipe) => seq_pipe,
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PIPE {
+                    return Ok(None);
+                } else {
+                    return Err(e.add_message_back(" (while alter pipe)"));
+                }
+            }
+        };
+
+        let seq = seq_pipe.seq;
+        let mut pipe = seq_pipe.data;
+        f(&mut pipe);
+
+        match client.update_pipe(pipe, MatchSeq::Exact(seq)).await {
+            Ok(res) => Ok(Some(res)),
+            Err(e) => Err(e.add_message_back(" (while alter pipe)")),
+        }
+    }
+
+    // Drop a pipe by name.
+    #[async_backtrace::framed]
+    pub async fn drop_pipe(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.get_pipe_api_client(tenant)?;
+        match client.drop_pipe(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PIPE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop pipe)"))
+                }
+            }
+        }
+    }
+
+    // Get a pipe by name.
+    #[async_backtrace::framed]
+    pub async fn get_pipe(&self, tenant: &str, name: &str) -> Result<PipeInfo> {
+        let client = self.get_pipe_api_client(tenant)?;
+        Ok(client.get_pipe(name, MatchSeq::GE(0)).await?.data)
+    }
+
+    // Get all pipes of the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_pipes(&self, tenant: &str) -> Result<Vec<PipeInfo>> {
+        let client = self.get_pipe_api_client(tenant)?;
+        client
+            .get_pipes()
+            .await
+            .map_err(|e| e.add_message_back(" (while get pipes)"))
+    }
+}
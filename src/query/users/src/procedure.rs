@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::ProcedureApi;
+use databend_common_meta_app::principal::UserDefinedProcedure;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// procedure operations.
+impl UserApiProvider {
+    // Add a new procedure, or replace the existing one if `OR REPLACE` is specified.
+    #[async_backtrace::framed]
+    pub async fn add_procedure(
+        &self,
+        tenant: &str,
+        procedure: UserDefinedProcedure,
+        create_option: &CreateOption,
+    ) -> Result<u64> {
+        let client = self.get_procedure_api_client(tenant)?;
+        let res = match create_option {
+            CreateOption::CreateOrReplace => {
+                client.update_procedure(procedure, MatchSeq::GE(0)).await
+            }
+            CreateOption::CreateIfNotExists(_) => client.add_procedure(procedure).await,
+        };
+        match res {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if matches!(create_option, CreateOption::CreateIfNotExists(true))
+                    && e.code() == ErrorCode::PROCEDURE_ALREADY_EXISTS
+                {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back(" (while create procedure)"))
+                }
+            }
+        }
+    }
+
+    // Drop a procedure by name.
+    #[async_backtrace::framed]
+    pub async fn drop_procedure(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.get_procedure_api_client(tenant)?;
+        match client.drop_procedure(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PROCEDURE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop procedure)"))
+                }
+            }
+        }
+    }
+
+    // Get a procedure by name.
+    #[async_backtrace::framed]
+    pub async fn get_procedure(&self, tenant: &str, name: &str) -> Result<UserDefinedProcedure> {
+        let client = self.get_procedure_api_client(tenant)?;
+        Ok(client.get_procedure(name, MatchSeq::GE(0)).await?.data)
+    }
+
+    // Get all procedures of the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_procedures(&self, tenant: &str) -> Result<Vec<UserDefinedProcedure>> {
+        let client = self.get_procedure_api_client(tenant)?;
+        client
+            .get_procedures()
+            .await
+            .map_err(|e| e.add_message_back(" (while get procedures)"))
+    }
+}
@@ -26,6 +26,10 @@ use databend_common_management::NetworkPolicyApi;
 use databend_common_management::NetworkPolicyMgr;
 use databend_common_management::PasswordPolicyApi;
 use databend_common_management::PasswordPolicyMgr;
+use databend_common_management::PipeApi;
+use databend_common_management::PipeMgr;
+use databend_common_management::ProcedureApi;
+use databend_common_management::ProcedureMgr;
 use databend_common_management::QuotaApi;
 use databend_common_management::QuotaMgr;
 use databend_common_management::RoleApi;
@@ -181,6 +185,14 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_pipe_api_client(&self, tenant: &str) -> Result<Arc<impl PipeApi>> {
+        Ok(Arc::new(PipeMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_procedure_api_client(&self, tenant: &str) -> Result<Arc<impl ProcedureApi>> {
+        Ok(Arc::new(ProcedureMgr::create(self.client.clone(), tenant)?))
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
@@ -84,6 +84,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 table_options: BTreeMap::new(),
                 as_query: None,
                 transient: false,
+                temporary: false,
             };
             tables.push((drop_table, create_table));
         }
@@ -200,7 +201,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             };
             column_defs.push(column_def);
         }
-        CreateTableSource::Columns(column_defs)
+        CreateTableSource::Columns(column_defs, vec![])
     }
 }
 
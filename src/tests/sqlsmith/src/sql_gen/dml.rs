@@ -82,6 +82,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             catalog: None,
             database: None,
             table: table_name,
+            partition: vec![],
             // TODO
             columns: vec![],
             source,
@@ -298,6 +299,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
         (table, table_reference)
     }
@@ -482,6 +484,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 catalog: None,
                 database: None,
                 table: table_name,
+                partition: vec![],
                 columns,
                 source,
                 overwrite: false,
@@ -499,6 +502,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
         Some((
             AlterTableStmt {
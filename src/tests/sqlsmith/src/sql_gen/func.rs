@@ -20,6 +20,7 @@ use databend_common_ast::ast::Lambda;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::OrderByExpr;
 use databend_common_ast::ast::Window;
+use databend_common_ast::ast::WindowDesc;
 use databend_common_ast::ast::WindowFrame;
 use databend_common_ast::ast::WindowFrameBound;
 use databend_common_ast::ast::WindowFrameUnits;
@@ -774,7 +775,10 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             name,
             args,
             params,
-            window,
+            window: window.map(|window| WindowDesc {
+                ignore_nulls: None,
+                window,
+            }),
             lambda,
         }
     }
@@ -479,6 +479,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             pivot: None,
             // TODO
             unpivot: None,
+            // TODO
+            sample: None,
         };
         (table_ref, schema)
     }
@@ -666,6 +668,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             condition,
             left: Box::new(left_table),
             right: Box::new(right_table),
+            match_condition: None,
         };
         TableReference::Join { span: None, join }
     }
@@ -0,0 +1,647 @@
+#!/usr/bin/env python3
+# Copyright 2021 Datafuse Labs
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+"""Generate the iceberg v2 tables `iceberg_db.iceberg_tbl_v2` and `iceberg_db.iceberg_tbl_v2_struct`
+with row-level deletes.
+
+Spark only writes position deletes, so the tables are written by this script
+(without third-party dependencies) to get both kinds of delete files.
+
+`iceberg_tbl_v2`:
+
+1. snapshot 1 appends `data-1.parquet`: (1, 'a') .. (6, 'f') in 3 row groups of 2 rows;
+2. snapshot 2 adds a position delete file, deleting positions 1 and 4 of `data-1.parquet`
+   (ids 2 and 5, in the first and the last row group);
+3. snapshot 3 appends `data-3.parquet`: (3, 'x'), (7, 'g'), and adds an equality delete
+   file on `id` deleting id 3, which only applies to the older `data-1.parquet`.
+
+The rows of the current snapshot are (1, 'a'), (3, 'x'), (4, 'd'), (6, 'f'), (7, 'g').
+
+`iceberg_tbl_v2_struct` has a struct column `info` before the equality key `id`, the field id of
+`id` (2) differs from its position in the leaf columns (3):
+
+1. snapshot 1 appends `data-1.parquet`: ((1, 'a'), 1), ((2, 'b'), 2), ((3, 'c'), 3);
+2. snapshot 2 adds an equality delete file on `id` deleting id 2.
+
+The rows of the current snapshot are ((1, 'a'), 1), ((3, 'c'), 3).
+
+Run it from this directory to regenerate the tables:
+
+    python3 gen_iceberg_v2_deletes.py
+"""
+
+import json
+import os
+import struct
+
+
+
+class Table:
+    def __init__(self, name, uuid, schema, last_column_id):
+        self.dir = f"iceberg_ctl/iceberg_db/{name}"
+        self.location = f"s3://warehouse/iceberg_ctl/iceberg_db/{name}"
+        self.uuid = uuid
+        self.schema = schema
+        self.last_column_id = last_column_id
+
+
+TBL_V2 = Table(
+    "iceberg_tbl_v2",
+    "8c4b5c3e-7a0e-4c55-9d0c-4b7c0c0d2f11",
+    {
+        "type": "struct",
+        "schema-id": 0,
+        "fields": [
+            {"id": 1, "name": "id", "required": True, "type": "int"},
+            {"id": 2, "name": "data", "required": True, "type": "string"},
+        ],
+    },
+    2,
+)
+
+TBL_V2_STRUCT = Table(
+    "iceberg_tbl_v2_struct",
+    "5f0d7c1a-2b3e-4d5f-8a9b-0c1d2e3f4a5b",
+    {
+        "type": "struct",
+        "schema-id": 0,
+        "fields": [
+            {
+                "id": 1,
+                "name": "info",
+                "required": True,
+                "type": {
+                    "type": "struct",
+                    "fields": [
+                        {"id": 3, "name": "a", "required": True, "type": "int"},
+                        {"id": 4, "name": "b", "required": True, "type": "string"},
+                    ],
+                },
+            },
+            {"id": 2, "name": "id", "required": True, "type": "int"},
+        ],
+    },
+    4,
+)
+
+# ---------------------------------------------------------------------------
+# Parquet, plain encoded and uncompressed, required columns only.
+# ---------------------------------------------------------------------------
+
+INT32, INT64, BYTE_ARRAY = 1, 2, 6
+
+
+class CompactWriter:
+    """A minimal thrift compact protocol writer."""
+
+    def __init__(self):
+        self.buf = bytearray()
+        self.last_field = [0]
+
+    def varint(self, n):
+        while True:
+            b = n & 0x7F
+            n >>= 7
+            if n:
+                self.buf.append(b | 0x80)
+            else:
+                self.buf.append(b)
+                return
+
+    def zigzag(self, n):
+        self.varint((n << 1) ^ (n >> 63))
+
+    def field(self, fid, ftype):
+        delta = fid - self.last_field[-1]
+        if 0 < delta <= 15:
+            self.buf.append((delta << 4) | ftype)
+        else:
+            self.buf.append(ftype)
+            self.zigzag(fid)
+        self.last_field[-1] = fid
+
+    def i32(self, fid, v):
+        self.field(fid, 5)
+        self.zigzag(v)
+
+    def i64(self, fid, v):
+        self.field(fid, 6)
+        self.zigzag(v)
+
+    def binary(self, fid, v):
+        self.field(fid, 8)
+        self.raw_binary(v)
+
+    def raw_binary(self, v):
+        if isinstance(v, str):
+            v = v.encode()
+        self.varint(len(v))
+        self.buf += v
+
+    def begin_struct(self, fid):
+        self.field(fid, 12)
+        self.last_field.append(0)
+
+    def end_struct(self):
+        self.buf.append(0)
+        self.last_field.pop()
+
+    def begin_list(self, fid, etype, size):
+        self.field(fid, 9)
+        if size < 15:
+            self.buf.append((size << 4) | etype)
+        else:
+            self.buf.append(0xF0 | etype)
+            self.varint(size)
+
+
+def plain_encode(ptype, values):
+    if ptype == INT32:
+        return b"".join(struct.pack("<i", v) for v in values)
+    if ptype == INT64:
+        return b"".join(struct.pack("<q", v) for v in values)
+    return b"".join(struct.pack("<I", len(v.encode())) + v.encode() for v in values)
+
+
+def leaf_columns(columns, path=()):
+    """The leaf columns as (path, parquet type), in the order of the flattened row values."""
+    for name, ptype, _, *children in columns:
+        if children:
+            yield from leaf_columns(children[0], path + (name,))
+        else:
+            yield path + (name,), ptype
+
+
+def schema_elements(columns):
+    for name, ptype, field_id, *children in columns:
+        element = CompactWriter()
+        if children:
+            element.i32(3, 0)  # REQUIRED
+            element.binary(4, name)
+            element.i32(5, len(children[0]))
+        else:
+            element.i32(1, ptype)
+            element.i32(3, 0)  # REQUIRED
+            element.binary(4, name)
+            if ptype == BYTE_ARRAY:
+                element.i32(6, 0)  # UTF8
+        element.i32(9, field_id)
+        yield element.buf + b"\x00"
+        if children:
+            yield from schema_elements(children[0])
+
+
+def write_parquet(path, columns, row_groups):
+    """`columns` is a list of (name, parquet type, field id) or (name, None, field id, columns)
+    of a struct, `row_groups` is a list of row lists with the flattened leaf values."""
+    leaves = list(leaf_columns(columns))
+    out = bytearray(b"PAR1")
+    groups = []
+    for rows in row_groups:
+        chunks = []
+        for i, (name, ptype) in enumerate(leaves):
+            data = plain_encode(ptype, [row[i] for row in rows])
+            header = CompactWriter()
+            header.i32(1, 0)  # DATA_PAGE
+            header.i32(2, len(data))
+            header.i32(3, len(data))
+            header.begin_struct(5)
+            header.i32(1, len(rows))
+            header.i32(2, 0)  # PLAIN
+            header.i32(3, 3)  # RLE
+            header.i32(4, 3)  # RLE
+            header.end_struct()
+            header.buf.append(0)
+            offset = len(out)
+            out += header.buf + data
+            chunks.append((name, ptype, offset, len(out) - offset))
+        groups.append((chunks, len(rows)))
+
+    elements = list(schema_elements(columns))
+    meta = CompactWriter()
+    meta.i32(1, 1)
+    meta.begin_list(2, 12, len(elements) + 1)
+    root = CompactWriter()
+    root.binary(4, "table")
+    root.i32(5, len(columns))
+    meta.buf += root.buf + b"\x00"
+    for element in elements:
+        meta.buf += element
+    meta.i64(3, sum(num_rows for _, num_rows in groups))
+    meta.begin_list(4, 12, len(groups))
+    for chunks, num_rows in groups:
+        group = CompactWriter()
+        group.begin_list(1, 12, len(chunks))
+        for name, ptype, offset, size in chunks:
+            chunk = CompactWriter()
+            chunk.i64(2, offset)
+            chunk.begin_struct(3)
+            chunk.i32(1, ptype)
+            chunk.begin_list(2, 5, 1)
+            chunk.zigzag(0)  # PLAIN
+            chunk.begin_list(3, 8, len(name))
+            for part in name:
+                chunk.raw_binary(part)
+            chunk.i32(4, 0)  # UNCOMPRESSED
+            chunk.i64(5, num_rows)
+            chunk.i64(6, size)
+            chunk.i64(7, size)
+            chunk.i64(9, offset)
+            chunk.end_struct()
+            group.buf += chunk.buf + b"\x00"
+        group.i64(2, sum(size for *_, size in chunks))
+        group.i64(3, num_rows)
+        meta.buf += group.buf + b"\x00"
+    meta.binary(6, "gen_iceberg_v2_deletes.py")
+    meta.buf.append(0)
+
+    out += meta.buf + struct.pack("<I", len(meta.buf)) + b"PAR1"
+    write_file(path, out)
+    return len(out)
+
+
+# ---------------------------------------------------------------------------
+# Avro object container files with the null codec.
+# ---------------------------------------------------------------------------
+
+
+def avro_long(n):
+    n = (n << 1) ^ (n >> 63)
+    out = bytearray()
+    while True:
+        b = n & 0x7F
+        n >>= 7
+        if n:
+            out.append(b | 0x80)
+        else:
+            out.append(b)
+            return bytes(out)
+
+
+def avro_bytes(v):
+    if isinstance(v, str):
+        v = v.encode()
+    return avro_long(len(v)) + v
+
+
+def avro_encode(schema, value):
+    if isinstance(schema, list):
+        # Unions are always ["null", T].
+        if value is None:
+            return avro_long(0)
+        return avro_long(1) + avro_encode(schema[1], value)
+    if isinstance(schema, dict):
+        if schema["type"] == "record":
+            return b"".join(avro_encode(f["type"], value[f["name"]]) for f in schema["fields"])
+        if schema["type"] == "array":
+            if not value:
+                return avro_long(0)
+            items = b"".join(avro_encode(schema["items"], v) for v in value)
+            return avro_long(len(value)) + items + avro_long(0)
+        return avro_encode(schema["type"], value)
+    if schema in ("int", "long"):
+        return avro_long(value)
+    if schema in ("string", "bytes"):
+        return avro_bytes(value)
+    if schema == "boolean":
+        return b"\x01" if value else b"\x00"
+    raise ValueError(schema)
+
+
+def write_avro(path, schema, metadata, records):
+    sync = (os.path.basename(path).encode() * 16)[:16]
+    metadata = dict(metadata, **{"avro.schema": json.dumps(schema), "avro.codec": "null"})
+    out = bytearray(b"Obj\x01")
+    out += avro_long(len(metadata))
+    for k, v in metadata.items():
+        out += avro_bytes(k) + avro_bytes(v)
+    out += avro_long(0) + sync
+    data = b"".join(avro_encode(schema, r) for r in records)
+    out += avro_long(len(records)) + avro_long(len(data)) + data + sync
+    write_file(path, out)
+    return len(out)
+
+
+def optional(t):
+    return ["null", t]
+
+
+def int_map(name, key_id, value_id, value_type):
+    return {
+        "type": "array",
+        "logicalType": "map",
+        "items": {
+            "type": "record",
+            "name": name,
+            "fields": [
+                {"name": "key", "type": "int", "field-id": key_id},
+                {"name": "value", "type": value_type, "field-id": value_id},
+            ],
+        },
+    }
+
+
+DATA_FILE_SCHEMA = {
+    "type": "record",
+    "name": "r2",
+    "fields": [
+        {"name": "content", "type": "int", "field-id": 134},
+        {"name": "file_path", "type": "string", "field-id": 100},
+        {"name": "file_format", "type": "string", "field-id": 101},
+        {"name": "partition", "type": {"type": "record", "name": "r102", "fields": []}, "field-id": 102},
+        {"name": "record_count", "type": "long", "field-id": 103},
+        {"name": "file_size_in_bytes", "type": "long", "field-id": 104},
+        {"name": "column_sizes", "type": optional(int_map("k117_v118", 117, 118, "long")), "default": None, "field-id": 108},
+        {"name": "value_counts", "type": optional(int_map("k119_v120", 119, 120, "long")), "default": None, "field-id": 109},
+        {"name": "null_value_counts", "type": optional(int_map("k121_v122", 121, 122, "long")), "default": None, "field-id": 110},
+        {"name": "nan_value_counts", "type": optional(int_map("k138_v139", 138, 139, "long")), "default": None, "field-id": 137},
+        {"name": "lower_bounds", "type": optional(int_map("k126_v127", 126, 127, "bytes")), "default": None, "field-id": 125},
+        {"name": "upper_bounds", "type": optional(int_map("k129_v130", 129, 130, "bytes")), "default": None, "field-id": 128},
+        {"name": "key_metadata", "type": optional("bytes"), "default": None, "field-id": 131},
+        {"name": "split_offsets", "type": optional({"type": "array", "items": "long", "element-id": 133}), "default": None, "field-id": 132},
+        {"name": "equality_ids", "type": optional({"type": "array", "items": "int", "element-id": 136}), "default": None, "field-id": 135},
+        {"name": "sort_order_id", "type": optional("int"), "default": None, "field-id": 140},
+    ],
+}
+
+MANIFEST_ENTRY_SCHEMA = {
+    "type": "record",
+    "name": "manifest_entry",
+    "fields": [
+        {"name": "status", "type": "int", "field-id": 0},
+        {"name": "snapshot_id", "type": optional("long"), "default": None, "field-id": 1},
+        {"name": "sequence_number", "type": optional("long"), "default": None, "field-id": 3},
+        {"name": "file_sequence_number", "type": optional("long"), "default": None, "field-id": 4},
+        {"name": "data_file", "type": DATA_FILE_SCHEMA, "field-id": 2},
+    ],
+}
+
+MANIFEST_FILE_SCHEMA = {
+    "type": "record",
+    "name": "manifest_file",
+    "fields": [
+        {"name": "manifest_path", "type": "string", "field-id": 500},
+        {"name": "manifest_length", "type": "long", "field-id": 501},
+        {"name": "partition_spec_id", "type": "int", "field-id": 502},
+        {"name": "content", "type": "int", "field-id": 517},
+        {"name": "sequence_number", "type": "long", "field-id": 515},
+        {"name": "min_sequence_number", "type": "long", "field-id": 516},
+        {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+        {"name": "added_files_count", "type": "int", "field-id": 504},
+        {"name": "existing_files_count", "type": "int", "field-id": 505},
+        {"name": "deleted_files_count", "type": "int", "field-id": 506},
+        {"name": "added_rows_count", "type": "long", "field-id": 512},
+        {"name": "existing_rows_count", "type": "long", "field-id": 513},
+        {"name": "deleted_rows_count", "type": "long", "field-id": 514},
+        {
+            "name": "partitions",
+            "type": optional(
+                {
+                    "type": "array",
+                    "items": {
+                        "type": "record",
+                        "name": "r508",
+                        "fields": [
+                            {"name": "contains_null", "type": "boolean", "field-id": 509},
+                            {"name": "contains_nan", "type": optional("boolean"), "default": None, "field-id": 518},
+                            {"name": "lower_bound", "type": optional("bytes"), "default": None, "field-id": 510},
+                            {"name": "upper_bound", "type": optional("bytes"), "default": None, "field-id": 511},
+                        ],
+                    },
+                    "element-id": 508,
+                }
+            ),
+            "default": None,
+            "field-id": 507,
+        },
+        {"name": "key_metadata", "type": optional("bytes"), "default": None, "field-id": 519},
+    ],
+}
+
+
+def write_file(path, data):
+    os.makedirs(os.path.dirname(path), exist_ok=True)
+    with open(path, "wb") as f:
+        f.write(data)
+
+
+def data_file(table, content, path, record_count, size, equality_ids=None):
+    return {
+        "content": content,
+        "file_path": f"{table.location}/{path}",
+        "file_format": "PARQUET",
+        "partition": {},
+        "record_count": record_count,
+        "file_size_in_bytes": size,
+        "column_sizes": None,
+        "value_counts": None,
+        "null_value_counts": None,
+        "nan_value_counts": None,
+        "lower_bounds": None,
+        "upper_bounds": None,
+        "key_metadata": None,
+        "split_offsets": None,
+        "equality_ids": equality_ids,
+        "sort_order_id": None,
+    }
+
+
+def write_manifest(table, name, snapshot_id, sequence_number, content, files):
+    entries = [
+        {
+            "status": 1,  # ADDED
+            "snapshot_id": snapshot_id,
+            "sequence_number": sequence_number,
+            "file_sequence_number": sequence_number,
+            "data_file": f,
+        }
+        for f in files
+    ]
+    metadata = {
+        "schema": json.dumps(table.schema),
+        "schema-id": "0",
+        "partition-spec": "[]",
+        "partition-spec-id": "0",
+        "format-version": "2",
+        "content": "data" if content == 0 else "deletes",
+    }
+    path = f"metadata/{name}"
+    size = write_avro(f"{table.dir}/{path}", MANIFEST_ENTRY_SCHEMA, metadata, entries)
+    return {
+        "manifest_path": f"{table.location}/{path}",
+        "manifest_length": size,
+        "partition_spec_id": 0,
+        "content": content,
+        "sequence_number": sequence_number,
+        "min_sequence_number": sequence_number,
+        "added_snapshot_id": snapshot_id,
+        "added_files_count": len(files),
+        "existing_files_count": 0,
+        "deleted_files_count": 0,
+        "added_rows_count": sum(f["record_count"] for f in files),
+        "existing_rows_count": 0,
+        "deleted_rows_count": 0,
+        "partitions": [],
+        "key_metadata": None,
+    }
+
+
+def write_snapshots(table, snapshot_manifests, operations):
+    """Write the snapshots 1001, 1002, ... with the manifests of every snapshot."""
+    snapshots = []
+    metadata_log = []
+    for i, manifests in enumerate(snapshot_manifests):
+        snapshot_id = 1001 + i
+        timestamp = 1700000001000 + i * 1000
+        manifest_list = f"metadata/snap-{snapshot_id}-1-manifest-list.avro"
+        metadata = {
+            "snapshot-id": str(snapshot_id),
+            "sequence-number": str(i + 1),
+            "format-version": "2",
+        }
+        if i > 0:
+            metadata["parent-snapshot-id"] = str(snapshot_id - 1)
+        write_avro(
+            f"{table.dir}/{manifest_list}",
+            MANIFEST_FILE_SCHEMA,
+            metadata,
+            manifests,
+        )
+        snapshot = {
+            "sequence-number": i + 1,
+            "snapshot-id": snapshot_id,
+            "timestamp-ms": timestamp,
+            "summary": {"operation": operations[i]},
+            "manifest-list": f"{table.location}/{manifest_list}",
+            "schema-id": 0,
+        }
+        if i > 0:
+            snapshot["parent-snapshot-id"] = snapshot_id - 1
+        snapshots.append(snapshot)
+
+        table_metadata = {
+            "format-version": 2,
+            "table-uuid": table.uuid,
+            "location": table.location,
+            "last-sequence-number": i + 1,
+            "last-updated-ms": timestamp,
+            "last-column-id": table.last_column_id,
+            "current-schema-id": 0,
+            "schemas": [table.schema],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": []}],
+            "last-partition-id": 999,
+            "default-sort-order-id": 0,
+            "sort-orders": [{"order-id": 0, "fields": []}],
+            "properties": {"write.delete.mode": "merge-on-read"},
+            "current-snapshot-id": snapshot_id,
+            "refs": {"main": {"snapshot-id": snapshot_id, "type": "branch"}},
+            "snapshots": list(snapshots),
+            "snapshot-log": [
+                {"timestamp-ms": s["timestamp-ms"], "snapshot-id": s["snapshot-id"]}
+                for s in snapshots
+            ],
+            "metadata-log": list(metadata_log),
+        }
+        metadata_file = f"metadata/0000{i + 1}-{table.uuid}.metadata.json"
+        write_file(
+            f"{table.dir}/{metadata_file}",
+            json.dumps(table_metadata, indent=2).encode(),
+        )
+        metadata_log.append(
+            {"timestamp-ms": timestamp, "metadata-file": f"{table.location}/{metadata_file}"}
+        )
+
+
+def write_tbl_v2():
+    table = TBL_V2
+    id_data = [("id", INT32, 1), ("data", BYTE_ARRAY, 2)]
+
+    # Snapshot 1: a data file of 3 row groups.
+    size = write_parquet(
+        f"{table.dir}/data/data-1.parquet",
+        id_data,
+        [[(1, "a"), (2, "b")], [(3, "c"), (4, "d")], [(5, "e"), (6, "f")]],
+    )
+    data_1 = data_file(table, 0, "data/data-1.parquet", 6, size)
+
+    # Snapshot 2: position deletes of `data-1.parquet`, the row of another file must be ignored.
+    data_1_path = data_1["file_path"]
+    size = write_parquet(
+        f"{table.dir}/data/pos-delete-2.parquet",
+        [("file_path", BYTE_ARRAY, 2147483546), ("pos", INT64, 2147483545)],
+        [[(data_1_path, 1), (data_1_path, 4), (f"{table.location}/data/unknown.parquet", 0)]],
+    )
+    pos_delete = data_file(table, 1, "data/pos-delete-2.parquet", 3, size)
+
+    # Snapshot 3: a new data file and equality deletes on `id`.
+    size = write_parquet(
+        f"{table.dir}/data/data-3.parquet", id_data, [[(3, "x"), (7, "g")]]
+    )
+    data_3 = data_file(table, 0, "data/data-3.parquet", 2, size)
+    size = write_parquet(
+        f"{table.dir}/data/eq-delete-3.parquet", [("id", INT32, 1)], [[(3,)]]
+    )
+    eq_delete = data_file(table, 2, "data/eq-delete-3.parquet", 1, size, equality_ids=[1])
+
+    manifests = [
+        write_manifest(table, "manifest-1-m0.avro", 1001, 1, 0, [data_1]),
+        write_manifest(table, "manifest-2-m0.avro", 1002, 2, 1, [pos_delete]),
+        write_manifest(table, "manifest-3-m0.avro", 1003, 3, 0, [data_3]),
+        write_manifest(table, "manifest-3-m1.avro", 1003, 3, 1, [eq_delete]),
+    ]
+    # The manifests of every snapshot, the last snapshot adds 2 manifests.
+    write_snapshots(
+        table,
+        [manifests[:1], manifests[:2], manifests],
+        ["append", "delete", "overwrite"],
+    )
+
+
+def write_tbl_v2_struct():
+    table = TBL_V2_STRUCT
+    columns = [
+        ("info", None, 1, [("a", INT32, 3), ("b", BYTE_ARRAY, 4)]),
+        ("id", INT32, 2),
+    ]
+
+    # Snapshot 1: a data file with the struct column before `id`.
+    size = write_parquet(
+        f"{table.dir}/data/data-1.parquet",
+        columns,
+        [[(1, "a", 1), (2, "b", 2), (3, "c", 3)]],
+    )
+    data_1 = data_file(table, 0, "data/data-1.parquet", 3, size)
+
+    # Snapshot 2: equality deletes on `id`, whose field id is 2.
+    size = write_parquet(
+        f"{table.dir}/data/eq-delete-2.parquet", [("id", INT32, 2)], [[(2,)]]
+    )
+    eq_delete = data_file(table, 2, "data/eq-delete-2.parquet", 1, size, equality_ids=[2])
+
+    manifests = [
+        write_manifest(table, "manifest-1-m0.avro", 1001, 1, 0, [data_1]),
+        write_manifest(table, "manifest-2-m0.avro", 1002, 2, 1, [eq_delete]),
+    ]
+    write_snapshots(table, [manifests[:1], manifests], ["append", "delete"])
+
+
+def main():
+    write_tbl_v2()
+    write_tbl_v2_struct()
+
+
+if __name__ == "__main__":
+    main()
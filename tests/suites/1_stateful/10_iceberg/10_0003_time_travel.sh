@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "DROP CATALOG IF EXISTS iceberg_ctl" | $BENDSQL_CLIENT_CONNECT
+
+## Create iceberg catalog
+cat <<EOF | $BENDSQL_CLIENT_CONNECT
+CREATE CATALOG iceberg_ctl
+TYPE=ICEBERG
+CONNECTION=(
+    URL='s3://testbucket/iceberg_ctl/'
+    access_key_id ='minioadmin'
+    secret_access_key ='minioadmin'
+    ENDPOINT_URL='${STORAGE_S3_ENDPOINT_URL}'
+);
+EOF
+
+echo "SELECT snapshot_id, parent_snapshot_id, sequence_number, timestamp, operation, is_current FROM iceberg_snapshots('iceberg_ctl', 'iceberg_db', 'iceberg_tbl') ORDER BY timestamp;" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT count(*) FROM iceberg_ctl.iceberg_db.iceberg_tbl AT (SNAPSHOT => '1620235913653295893');" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT id, data FROM iceberg_ctl.iceberg_db.iceberg_tbl AT (TIMESTAMP => '2023-08-08 01:35:02'::TIMESTAMP) ORDER BY id;" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT count(*) FROM iceberg_ctl.iceberg_db.iceberg_tbl AT (TIMESTAMP => '2023-08-08 01:35:04'::TIMESTAMP);" | $BENDSQL_CLIENT_CONNECT
+
+## No snapshot before the table is created
+echo "SELECT count(*) FROM iceberg_ctl.iceberg_db.iceberg_tbl AT (TIMESTAMP => '2023-08-08 01:35:00'::TIMESTAMP);" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -c "No historical data found"
@@ -0,0 +1,37 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+## Write to a copy of the table to keep the test data untouched
+ROOT=$(mktemp -d)
+cp -r "$CURDIR"/../../../data/iceberg/iceberg_ctl/iceberg_db/iceberg_tbl/. "$ROOT"/
+
+stmt "drop table if exists test_iceberg_insert;"
+
+echo ">>>> create table test_iceberg_insert engine = iceberg location = 'fs://\${ROOT}/';"
+echo "create table test_iceberg_insert engine = iceberg location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+stmt "insert into test_iceberg_insert values (7, 'f'), (8, 'g');"
+stmt "insert into test_iceberg_insert select number + 9, 'h' from numbers(3);"
+
+query "select * from test_iceberg_insert order by id, data;"
+query "select count(*) from test_iceberg_insert where id > 6;"
+
+## The copied files are recorded, copying them again loads nothing
+CSV_DIR=$(mktemp -d)
+printf '20,x\n21,y\n' > "$CSV_DIR"/ids.csv
+for i in 1 2; do
+	echo ">>>> copy into test_iceberg_insert from 'fs://\${CSV_DIR}/' file_format = (type = CSV);"
+	echo "copy into test_iceberg_insert from 'fs://${CSV_DIR}/' file_format = (type = CSV);" | $BENDSQL_CLIENT_CONNECT > /dev/null
+done
+query "select count(*) from test_iceberg_insert where id >= 20;"
+
+echo ">>>> insert overwrite test_iceberg_insert values (1, 'a');"
+echo "insert overwrite test_iceberg_insert values (1, 'a');" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -c "INSERT OVERWRITE iceberg table"
+
+query "select count(*) from test_iceberg_insert;"
+
+stmt "drop table test_iceberg_insert;"
+
+rm -rf "$ROOT" "$CSV_DIR"
@@ -0,0 +1,38 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+## An iceberg v2 table with position and equality delete files, see `tests/data/iceberg/gen_iceberg_v2_deletes.py`
+ROOT=$(realpath "$CURDIR"/../../../data/iceberg/iceberg_ctl/iceberg_db/iceberg_tbl_v2/)
+
+stmt "drop table if exists test_iceberg_deletes;"
+
+echo ">>>> create table test_iceberg_deletes engine = iceberg location = 'fs://\${ROOT}/';"
+echo "create table test_iceberg_deletes engine = iceberg location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+query "select * from test_iceberg_deletes order by id, data;"
+query "select count(*) from test_iceberg_deletes;"
+
+## The equality column is not in the output
+query "select data from test_iceberg_deletes order by data;"
+query "select data from test_iceberg_deletes where id > 3 order by id;"
+
+## Only the position deletes are committed in snapshot 1002
+query "select * from test_iceberg_deletes at (snapshot => '1002') order by id;"
+query "select count(*) from test_iceberg_deletes at (snapshot => '1001');"
+
+stmt "drop table test_iceberg_deletes;"
+
+## The equality key `id` follows a struct column, its field id differs from its column id
+ROOT=$(realpath "$CURDIR"/../../../data/iceberg/iceberg_ctl/iceberg_db/iceberg_tbl_v2_struct/)
+
+stmt "drop table if exists test_iceberg_struct_deletes;"
+
+echo ">>>> create table test_iceberg_struct_deletes engine = iceberg location = 'fs://\${ROOT}/';"
+echo "create table test_iceberg_struct_deletes engine = iceberg location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+query "select id from test_iceberg_struct_deletes order by id;"
+query "select count(*) from test_iceberg_struct_deletes at (snapshot => '1001');"
+
+stmt "drop table test_iceberg_struct_deletes;"
@@ -0,0 +1,8 @@
+-- static and dynamic partitions
+insert into hive.default.customer_p3 partition (c_region = 'ASIA', c_nation) values ('a', 'r1', 'n1', 'CHINA'), ('b', 'r2', 'n2', 'JAPAN');
+insert into hive.default.customer_p3 partition (c_region, c_nation) values ('c', 'r3', 'n3', 'EUROPE', 'FRANCE');
+select foo, c_region2, c_nation2, c_region, c_nation from hive.default.customer_p3 order by foo;
+-- overwrite only the touched partition
+insert overwrite hive.default.customer_p3 partition (c_region = 'ASIA', c_nation = 'CHINA') values ('d', 'r4', 'n4');
+select foo, c_region, c_nation from hive.default.customer_p3 order by foo;
+select foo from hive.default.customer_p3 where c_nation = 'CHINA';